@@ -198,7 +198,7 @@ fn layout_of_struct_or_enum<
         };
 
         // take the struct path if it is an actual struct
-        if !is_enum ||
+        let mut layout = if !is_enum ||
             // or for optimizing univariant enums
             (present_second.is_none() && !repr.inhibit_enum_layout_opt())
         {
@@ -224,10 +224,15 @@ fn layout_of_struct_or_enum<
                 variants,
                 discr_range_of_repr,
                 discriminants,
-                dont_niche_optimize_enum,
+                dont_niche_optimize_enum || repr.hide_niche(),
                 dl,
             )
+        }?;
+
+        if repr.hide_niche() {
+            hide_niches(&mut layout, dl);
         }
+        Some(layout)
     }
 
     fn layout_of_union<
@@ -336,6 +341,32 @@ fn layout_of_union<
 }
 
 /// single-variant enums are just structs, if you think about it
+/// Widens the valid ranges of the scalars in `layout`'s ABI and forgets its
+/// largest niche, so that enclosing types cannot store anything in it.
+fn hide_niches<FieldIdx: Idx, VariantIdx: Idx>(
+    layout: &mut LayoutS<FieldIdx, VariantIdx>,
+    dl: &TargetDataLayout,
+) {
+    let hide_niches = |scalar: &mut _| match scalar {
+        Scalar::Initialized { value, valid_range } => {
+            *valid_range = WrappingRange::full(value.size(dl))
+        }
+        // Already doesn't have any niches
+        Scalar::Union { .. } => {}
+    };
+    match &mut layout.abi {
+        Abi::Uninhabited => {}
+        Abi::Scalar(scalar) => hide_niches(scalar),
+        Abi::ScalarPair(a, b) => {
+            hide_niches(a);
+            hide_niches(b);
+        }
+        Abi::Vector { element, count: _ } => hide_niches(element),
+        Abi::Aggregate { sized: _ } => {}
+    }
+    layout.largest_niche = None;
+}
+
 fn layout_of_struct<'a, LC, FieldIdx: Idx, VariantIdx: Idx, F>(
     layout_calc: &LC,
     repr: &ReprOptions,
@@ -365,24 +396,7 @@ fn layout_of_struct<'a, LC, FieldIdx: Idx, VariantIdx: Idx, F>(
     st.variants = Variants::Single { index: v };
 
     if is_unsafe_cell {
-        let hide_niches = |scalar: &mut _| match scalar {
-            Scalar::Initialized { value, valid_range } => {
-                *valid_range = WrappingRange::full(value.size(dl))
-            }
-            // Already doesn't have any niches
-            Scalar::Union { .. } => {}
-        };
-        match &mut st.abi {
-            Abi::Uninhabited => {}
-            Abi::Scalar(scalar) => hide_niches(scalar),
-            Abi::ScalarPair(a, b) => {
-                hide_niches(a);
-                hide_niches(b);
-            }
-            Abi::Vector { element, count: _ } => hide_niches(element),
-            Abi::Aggregate { sized: _ } => {}
-        }
-        st.largest_niche = None;
+        hide_niches(&mut st, dl);
         return Some(st);
     }
 
@@ -43,6 +43,9 @@ impl ReprFlags: u8 {
         // If true, the type's layout can be randomized using
         // the seed stored in `ReprOptions.layout_seed`
         const RANDOMIZE_LAYOUT   = 1 << 4;
+        // If true, the niches of the type are not exposed to enclosing types,
+        // and an enum with this flag never stores its tag in a niche.
+        const HIDE_NICHE         = 1 << 5;
         // Any of these flags being set prevent field reordering optimisation.
         const IS_UNOPTIMISABLE   = ReprFlags::IS_C.bits()
                                  | ReprFlags::IS_SIMD.bits()
@@ -122,6 +125,11 @@ pub fn linear(&self) -> bool {
         self.flags.contains(ReprFlags::IS_LINEAR)
     }
 
+    #[inline]
+    pub fn hide_niche(&self) -> bool {
+        self.flags.contains(ReprFlags::HIDE_NICHE)
+    }
+
     /// Returns the discriminant type, given these `repr` options.
     /// This must only be called on enums!
     pub fn discr_type(&self) -> IntegerType {
@@ -53,6 +53,9 @@ borrowck_closure_moved_twice =
 borrowck_consider_borrow_type_contents =
     help: consider calling `.as_ref()` or `.as_mut()` to borrow the type's contents
 
+borrowck_couldnt_dump_region_inference =
+    unexpected error occurred while dumping the region inference context: {$error}
+
 borrowck_could_not_normalize =
     could not normalize `{$value}`
 
@@ -5,8 +5,8 @@
 use rustc_index::bit_set::BitSet;
 use rustc_middle::mir::traversal;
 use rustc_middle::mir::visit::{MutatingUseContext, NonUseContext, PlaceContext, Visitor};
-use rustc_middle::mir::{self, Body, Local, Location};
-use rustc_middle::ty::{RegionVid, TyCtxt};
+use rustc_middle::mir::{self, Body, Local, LocalKind, Location};
+use rustc_middle::ty::{self, RegionVid, TyCtxt};
 use rustc_mir_dataflow::move_paths::MoveData;
 use std::fmt;
 use std::ops::Index;
@@ -63,6 +63,18 @@ pub struct BorrowData<'tcx> {
     pub borrowed_place: mir::Place<'tcx>,
     /// Place to which the borrow was stored
     pub assigned_place: mir::Place<'tcx>,
+    /// If the borrow is only passed as the receiver of a method with the unstable
+    /// `#[view(..)]` attribute, the fields of the borrowed place in that view. The method
+    /// can't access anything else, so the borrow is only considered to cover these places.
+    pub view: Option<Vec<mir::Place<'tcx>>>,
+}
+
+impl<'tcx> BorrowData<'tcx> {
+    /// Returns the places covered by this borrow: the fields in its view if there is one,
+    /// or else the borrowed place.
+    pub(crate) fn viewed_places(&self) -> &[mir::Place<'tcx>] {
+        self.view.as_deref().unwrap_or(std::slice::from_ref(&self.borrowed_place))
+    }
 }
 
 impl<'tcx> fmt::Display for BorrowData<'tcx> {
@@ -142,6 +154,10 @@ pub fn build(
             visitor.visit_basic_block_data(block, block_data);
         }
 
+        if tcx.features().view_types {
+            visitor.gather_views();
+        }
+
         BorrowSet {
             location_map: visitor.location_map,
             activation_map: visitor.activation_map,
@@ -213,6 +229,7 @@ fn visit_assign(
                 activation_location: TwoPhaseActivation::NotTwoPhase,
                 borrowed_place,
                 assigned_place: *assigned_place,
+                view: None,
             };
             let (idx, _) = self.location_map.insert_full(location, borrow);
             let idx = BorrowIndex::from(idx);
@@ -288,6 +305,54 @@ fn visit_rvalue(&mut self, rvalue: &mir::Rvalue<'tcx>, location: mir::Location)
 }
 
 impl<'a, 'tcx> GatherBorrows<'a, 'tcx> {
+    /// Restricts the borrows that are only passed as the receiver of a method with a
+    /// `#[view(..)]` attribute to the fields of the borrowed place listed in the attribute.
+    fn gather_views(&mut self) {
+        for block_data in self.body.basic_blocks.iter() {
+            let mir::TerminatorKind::Call { func, args, .. } = &block_data.terminator().kind else {
+                continue;
+            };
+            let Some(fields) = func
+                .const_fn_def()
+                .and_then(|(def_id, _)| def_id.as_local())
+                .and_then(|def_id| self.tcx.fn_self_view(def_id))
+            else {
+                continue;
+            };
+            // Only temporaries are known to be used for nothing but this call.
+            let Some(mir::Operand::Move(receiver)) = args.first().map(|arg| &arg.node) else {
+                continue;
+            };
+            let Some(receiver) = receiver.as_local() else { continue };
+            if self.body.local_kind(receiver) != LocalKind::Temp
+                || self.body.local_decls[receiver].is_user_variable()
+            {
+                continue;
+            }
+            let Some(borrow) = self
+                .location_map
+                .values_mut()
+                .find(|borrow| borrow.assigned_place.as_local() == Some(receiver))
+            else {
+                continue;
+            };
+
+            let ty::Adt(adt_def, args) = *borrow.borrowed_place.ty(self.body, self.tcx).ty.kind()
+            else {
+                continue;
+            };
+            let variant = adt_def.non_enum_variant();
+            let view = fields
+                .iter()
+                .map(|&field| {
+                    let field_ty = variant.fields[field].ty(self.tcx, args);
+                    self.tcx.mk_place_field(borrow.borrowed_place, field, field_ty)
+                })
+                .collect();
+            borrow.view = Some(view);
+        }
+    }
+
     /// If this is a two-phase borrow, then we will record it
     /// as "pending" until we find the activating use.
     fn insert_as_pending_if_two_phase(
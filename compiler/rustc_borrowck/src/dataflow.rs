@@ -480,14 +480,19 @@ fn kill_borrows_on_place(&self, trans: &mut impl GenKill<BorrowIndex>, place: Pl
         // pair of array indices are not equal, so that when `places_conflict` returns true, we
         // will be assured that two places being compared definitely denotes the same sets of
         // locations.
+        //
+        // A borrow restricted to the view of a method is only killed when all of the fields in
+        // the view are overwritten.
         let definitely_conflicting_borrows = other_borrows_of_local.filter(|&i| {
-            places_conflict(
-                self.tcx,
-                self.body,
-                self.borrow_set[i].borrowed_place,
-                place,
-                PlaceConflictBias::NoOverlap,
-            )
+            self.borrow_set[i].viewed_places().iter().all(|&borrowed_place| {
+                places_conflict(
+                    self.tcx,
+                    self.body,
+                    borrowed_place,
+                    place,
+                    PlaceConflictBias::NoOverlap,
+                )
+            })
         });
 
         trans.kill_all(definitely_conflicting_borrows);
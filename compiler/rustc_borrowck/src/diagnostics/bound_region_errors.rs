@@ -55,27 +55,24 @@ pub(crate) fn report_error(
         placeholder: ty::PlaceholderRegion,
         error_element: RegionElement,
         cause: ObligationCause<'tcx>,
-    ) {
+    ) -> DiagnosticBuilder<'tcx> {
         match self.0 {
             UniverseInfoInner::RelateTys { expected, found } => {
-                let err = mbcx.infcx.err_ctxt().report_mismatched_types(
+                mbcx.infcx.err_ctxt().report_mismatched_types(
                     &cause,
                     expected,
                     found,
                     TypeError::RegionsPlaceholderMismatch,
-                );
-                mbcx.buffer_error(err);
+                )
             }
             UniverseInfoInner::TypeOp(ref type_op_info) => {
-                type_op_info.report_error(mbcx, placeholder, error_element, cause);
+                type_op_info.report_error(mbcx, placeholder, error_element, cause)
             }
             UniverseInfoInner::Other => {
                 // FIXME: This error message isn't great, but it doesn't show
                 // up in the existing UI tests. Consider investigating this
                 // some more.
-                mbcx.buffer_error(
-                    mbcx.dcx().create_err(HigherRankedSubtypeError { span: cause.span }),
-                );
+                mbcx.dcx().create_err(HigherRankedSubtypeError { span: cause.span })
             }
         }
     }
@@ -163,7 +160,7 @@ fn report_error(
         placeholder: ty::PlaceholderRegion,
         error_element: RegionElement,
         cause: ObligationCause<'tcx>,
-    ) {
+    ) -> DiagnosticBuilder<'tcx> {
         let tcx = mbcx.infcx.tcx;
         let base_universe = self.base_universe();
         debug!(?base_universe);
@@ -171,8 +168,7 @@ fn report_error(
         let Some(adjusted_universe) =
             placeholder.universe.as_u32().checked_sub(base_universe.as_u32())
         else {
-            mbcx.buffer_error(self.fallback_error(tcx, cause.span));
-            return;
+            return self.fallback_error(tcx, cause.span);
         };
 
         let placeholder_region = ty::Region::new_placeholder(
@@ -202,11 +198,7 @@ fn report_error(
 
         debug!(?nice_error);
 
-        if let Some(nice_error) = nice_error {
-            mbcx.buffer_error(nice_error);
-        } else {
-            mbcx.buffer_error(self.fallback_error(tcx, span));
-        }
+        nice_error.unwrap_or_else(|| self.fallback_error(tcx, span))
     }
 }
 
@@ -26,13 +26,13 @@
 use rustc_middle::ty::{self, suggest_constraining_type_params, PredicateKind, Ty, TyCtxt};
 use rustc_middle::util::CallKind;
 use rustc_mir_dataflow::move_paths::{InitKind, MoveOutIndex, MovePathIndex};
-use rustc_span::def_id::LocalDefId;
+use rustc_span::def_id::{DefId, LocalDefId};
 use rustc_span::hygiene::DesugaringKind;
 use rustc_span::symbol::{kw, sym, Ident};
 use rustc_span::{BytePos, Span, Symbol};
 use rustc_trait_selection::infer::InferCtxtExt;
 use rustc_trait_selection::traits::error_reporting::FindExprBySpan;
-use rustc_trait_selection::traits::ObligationCtxt;
+use rustc_trait_selection::traits::{elaborate_with_provenance, ObligationCtxt};
 use std::iter;
 
 use crate::borrow_set::TwoPhaseActivation;
@@ -203,6 +203,7 @@ pub(crate) fn report_use_of_moved_or_uninitialized(
                         self.suggest_ref_or_clone(
                             mpi,
                             move_span,
+                            span,
                             &mut err,
                             &mut in_pattern,
                             move_spans,
@@ -336,6 +337,7 @@ fn suggest_ref_or_clone(
         &mut self,
         mpi: MovePathIndex,
         move_span: Span,
+        use_span: Span,
         err: &mut DiagnosticBuilder<'tcx>,
         in_pattern: &mut bool,
         move_spans: UseSpans<'_>,
@@ -466,20 +468,122 @@ fn visit_pat(&mut self, p: &'hir hir::Pat<'hir>) {
                 }
             }
             if let Some(pat) = finder.pat {
+                // Only suggest alternatives to `ref` once, for the first binding moving the value.
+                let suggest_alternatives = !*in_pattern;
                 *in_pattern = true;
                 let mut sugg = vec![(pat.span.shrink_to_lo(), "ref ".to_string())];
                 if let Some(pat) = finder.parent_pat {
                     sugg.insert(0, (pat.span.shrink_to_lo(), "ref ".to_string()));
                 }
-                err.multipart_suggestion_verbose(
-                    "borrow this binding in the pattern to avoid moving the value",
+                self.suggest_fixes_for_move_in_pattern(
+                    err,
+                    pat,
                     sugg,
-                    Applicability::MachineApplicable,
+                    use_span,
+                    suggest_alternatives,
                 );
             }
         }
     }
 
+    /// Suggests borrowing the binding `pat` with `ref` to avoid moving out of the matched value.
+    ///
+    /// When the value is moved in one of the arms of a `match` and used after it, and
+    /// `suggest_alternatives` is set, also suggests matching on a reference to the value, or on a
+    /// clone of it. These are ranked by an estimate of their cost: how many bindings would have to
+    /// change, and how big the cloned value is.
+    fn suggest_fixes_for_move_in_pattern(
+        &self,
+        err: &mut Diagnostic,
+        pat: &hir::Pat<'_>,
+        ref_sugg: Vec<(Span, String)>,
+        use_span: Span,
+        suggest_alternatives: bool,
+    ) {
+        let ref_msg = "borrow this binding in the pattern to avoid moving the value";
+        let tcx = self.infcx.tcx;
+        let arm = tcx.hir().parent_iter(pat.hir_id).find_map(|(_, node)| match node {
+            hir::Node::Arm(arm) => Some(arm),
+            _ => None,
+        });
+        let alternatives = if let Some(arm) = arm
+            && suggest_alternatives
+            && arm.pat.span.contains(pat.span)
+            && let hir::Node::Expr(match_expr) = tcx.parent_hir_node(arm.hir_id)
+            && let hir::ExprKind::Match(scrutinee, arms, hir::MatchSource::Normal) = match_expr.kind
+            && arms.len() > 1
+            && !match_expr.span.contains(use_span)
+            && scrutinee.is_syntactic_place_expr()
+            && !scrutinee.span.from_expansion()
+            && let Ok(snippet) = tcx.sess.source_map().span_to_snippet(scrutinee.span)
+        {
+            Some((arm, scrutinee, arms, snippet))
+        } else {
+            None
+        };
+        let Some((arm, scrutinee, arms, snippet)) = alternatives else {
+            err.multipart_suggestion_verbose(ref_msg, ref_sugg, Applicability::MachineApplicable);
+            return;
+        };
+
+        let typeck = tcx.typeck(self.mir_def_id());
+        let scrutinee_ty = typeck.expr_ty_adjusted(scrutinee);
+        let is_moving_binding = |hir_id| {
+            matches!(typeck.pat_binding_modes().get(hir_id), Some(ty::BindByValue(_)))
+                && !self.infcx.type_is_copy_modulo_regions(self.param_env, typeck.node_type(hir_id))
+        };
+
+        // Adding `ref` has to be done for every binding moving out of the value in this arm.
+        let mut moving_bindings = 0;
+        arm.pat.each_binding(|_, hir_id, _, _| {
+            if is_moving_binding(hir_id) {
+                moving_bindings += 1;
+            }
+        });
+        let mut suggestions = vec![(moving_bindings, ref_msg.to_string(), ref_sugg)];
+
+        // Matching on a reference changes the type of all the other bindings of the `match`.
+        let mut other_bindings = 0;
+        for arm in arms {
+            arm.pat.each_binding(|_, hir_id, _, _| {
+                if !is_moving_binding(hir_id) {
+                    other_bindings += 1;
+                }
+            });
+        }
+        if !scrutinee_ty.is_ref() {
+            suggestions.push((
+                1 + other_bindings,
+                format!("match on a reference to `{snippet}` to avoid moving out of it"),
+                vec![(scrutinee.span.shrink_to_lo(), "&".to_string())],
+            ));
+        }
+
+        // Cloning is only worth it for small values, and `.clone()` can't be appended to a deref.
+        if !matches!(scrutinee.kind, hir::ExprKind::Unary(hir::UnOp::Deref, _))
+            && let Some(clone_trait_def) = tcx.lang_items().clone_trait()
+            && self
+                .infcx
+                .type_implements_trait(clone_trait_def, [scrutinee_ty], self.param_env)
+                .must_apply_modulo_regions()
+        {
+            let is_small = tcx
+                .layout_of(self.param_env.and(scrutinee_ty))
+                .is_ok_and(|layout| layout.size <= tcx.data_layout.pointer_size * 2);
+            suggestions.push((
+                if is_small { 2 } else { 3 },
+                format!("consider cloning `{snippet}` if the performance cost is acceptable"),
+                vec![(scrutinee.span.shrink_to_hi(), ".clone()".to_string())],
+            ));
+        }
+
+        suggestions.sort_by_key(|&(cost, ..)| cost);
+        for (i, (_, msg, sugg)) in suggestions.into_iter().enumerate() {
+            let msg = if i == 0 { msg } else { format!("alternatively, {msg}") };
+            err.multipart_suggestion_verbose(msg, sugg, Applicability::MachineApplicable);
+        }
+    }
+
     fn report_use_of_uninitialized(
         &self,
         mpi: MovePathIndex,
@@ -769,6 +873,9 @@ fn suggest_cloning(
             } else {
                 "consider cloning the value if the performance cost is acceptable"
             };
+            if let ty::Param(_) = ty.kind() {
+                self.explain_implied_trait_bound(err, ty, clone_trait_def);
+            }
             err.span_suggestion_verbose(
                 span.shrink_to_hi(),
                 msg,
@@ -778,6 +885,44 @@ fn suggest_cloning(
         }
     }
 
+    /// Points at the bound in scope which implies `ty: trait_def_id` through supertraits, and at
+    /// the supertrait bounds along the way, if the bound is not written out directly.
+    fn explain_implied_trait_bound(&self, err: &mut Diagnostic, ty: Ty<'tcx>, trait_def_id: DefId) {
+        let tcx = self.infcx.tcx;
+        let def_id = tcx.typeck_root_def_id(self.mir_def_id().to_def_id());
+        let predicates = tcx.predicates_of(def_id).instantiate_identity(tcx);
+        let Some(info) =
+            elaborate_with_provenance(tcx, iter::zip(predicates.predicates, predicates.spans))
+                .find(|info| match info.clause().kind().skip_binder() {
+                    ty::ClauseKind::Trait(trait_pred) => {
+                        trait_pred.def_id() == trait_def_id && trait_pred.self_ty() == ty
+                    }
+                    _ => false,
+                })
+        else {
+            return;
+        };
+        if info.path.len() < 2 {
+            return;
+        }
+        let trait_path = |clause: ty::Clause<'tcx>| {
+            clause.as_trait_clause().unwrap().print_modifiers_and_trait_path().to_string()
+        };
+        let mut span = MultiSpan::from_span(info.bottom().1);
+        for step in info.path.windows(2) {
+            if !tcx.sess.source_map().is_imported(step[1].1) {
+                span.push_span_label(
+                    step[1].1,
+                    format!("`{}` implies `{}`", trait_path(step[0].0), trait_path(step[1].0)),
+                );
+            }
+        }
+        err.span_note(
+            span,
+            format!("`{ty}` implements `{}` because of this bound", tcx.def_path_str(trait_def_id)),
+        );
+    }
+
     fn suggest_adding_copy_bounds(&self, err: &mut Diagnostic, ty: Ty<'tcx>, span: Span) {
         let tcx = self.infcx.tcx;
         let generics = tcx.generics_of(self.mir_def_id());
@@ -1303,6 +1448,36 @@ fn suggest_using_local_if_applicable(
             );
             return;
         }
+
+        if let BorrowKind::Mut { kind: MutBorrowKind::TwoPhaseBorrow } = issued_borrow.kind {
+            // Two-phase borrows only come from autoref and reborrow adjustments, so the
+            // conflicting borrow isn't written out in the source.
+            err.span_note(
+                self.body.source_info(issued_borrow.reserve_location).span,
+                format!(
+                    "{} is implicitly borrowed as `&mut` here, before {} evaluated",
+                    self.describe_any_place(issued_borrow.borrowed_place.as_ref()),
+                    if use_span.is_some() {
+                        "the rest of the expression is"
+                    } else {
+                        "the arguments of the call are"
+                    },
+                ),
+            );
+        }
+
+        if let Some(sugg) = self.suggest_hoisting_into_local(inner_call_span, outer_call_span) {
+            err.multipart_suggestion_verbose(
+                format!(
+                    "try adding a local storing this{}",
+                    if use_span.is_some() { "" } else { " argument" }
+                ),
+                sugg,
+                Applicability::MachineApplicable,
+            );
+            return;
+        }
+
         err.span_help(
             inner_call_span,
             format!(
@@ -1319,6 +1494,72 @@ fn suggest_using_local_if_applicable(
         );
     }
 
+    /// Builds the suggestion to evaluate the expression at `inner_span` into a new local right
+    /// before the statement containing `outer_span`, if doing so doesn't change whether or how
+    /// many times it is evaluated.
+    fn suggest_hoisting_into_local(
+        &self,
+        inner_span: Span,
+        outer_span: Span,
+    ) -> Option<Vec<(Span, String)>> {
+        let tcx = self.infcx.tcx;
+        let hir = tcx.hir();
+        let sm = tcx.sess.source_map();
+        if inner_span.from_expansion() {
+            return None;
+        }
+
+        let body_id = tcx.hir_node(self.mir_hir_id()).body_id()?;
+        let mut expr_finder = FindExprBySpan::new(inner_span);
+        expr_finder.visit_expr(hir.body(body_id).value);
+        let inner_expr = expr_finder.result?;
+
+        // Find the statement, or the trailing expression of a block, the expression belongs to.
+        let mut stmt_span = inner_expr.span;
+        for (_, node) in hir.parent_iter(inner_expr.hir_id) {
+            match node {
+                hir::Node::Expr(expr) => match expr.kind {
+                    hir::ExprKind::Closure(..)
+                    | hir::ExprKind::Loop(..)
+                    | hir::ExprKind::If(..)
+                    | hir::ExprKind::Let(..) => return None,
+                    hir::ExprKind::Binary(op, ..) if op.node.is_lazy() => return None,
+                    hir::ExprKind::Match(_, _, source)
+                        if !matches!(source, hir::MatchSource::TryDesugar(_)) =>
+                    {
+                        return None;
+                    }
+                    _ => stmt_span = expr.span,
+                },
+                hir::Node::Local(_) => {}
+                hir::Node::Stmt(stmt) => {
+                    stmt_span = stmt.span;
+                    break;
+                }
+                hir::Node::Block(_) => break,
+                _ => return None,
+            }
+        }
+        if stmt_span.from_expansion() || !stmt_span.contains(outer_span) {
+            return None;
+        }
+
+        let is_taken =
+            |name: &str| self.local_names.iter().flatten().any(|local| local.as_str() == name);
+        let name = (0..)
+            .map(|i| if i == 0 { "arg".to_string() } else { format!("arg{i}") })
+            .find(|name| !is_taken(name))?;
+        let snippet = sm.span_to_snippet(inner_span).ok()?;
+        let indent = match sm.indentation_before(stmt_span) {
+            Some(indent) => format!("\n{indent}"),
+            None => " ".to_string(),
+        };
+        Some(vec![
+            (stmt_span.shrink_to_lo(), format!("let {name} = {snippet};{indent}")),
+            (inner_span, name),
+        ])
+    }
+
     fn suggest_slice_method_if_applicable(
         &self,
         err: &mut Diagnostic,
@@ -2055,7 +2296,7 @@ pub(crate) fn report_borrowed_value_does_not_live_long_enough(
 
         debug!(?place_desc, ?explanation);
 
-        let err = match (place_desc, explanation) {
+        let mut err = match (place_desc, explanation) {
             // If the outlives constraint comes from inside the closure,
             // for example:
             //
@@ -2135,6 +2376,7 @@ pub(crate) fn report_borrowed_value_does_not_live_long_enough(
             ),
         };
 
+        self.explain_polonius_loan_liveness(&mut err, location, borrow);
         self.buffer_error(err);
     }
 
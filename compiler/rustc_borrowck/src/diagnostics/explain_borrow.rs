@@ -3,6 +3,9 @@
 #![allow(rustc::diagnostic_outside_of_impl)]
 #![allow(rustc::untranslatable_diagnostic)]
 
+use std::collections::VecDeque;
+
+use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::{Applicability, Diagnostic};
 use rustc_hir as hir;
 use rustc_hir::intravisit::Visitor;
@@ -516,6 +519,94 @@ pub(crate) fn explain_why_borrow_contains_point(
         }
     }
 
+    /// When the location-sensitive analysis (`-Z polonius`) has been run, explains where
+    /// `borrow`, invalidated at `location`, is last live: starting from `location`, follows the
+    /// points where polonius computed the loan to be live, up to the first point after which it
+    /// isn't live anymore, and points at it and at the branches taken to reach it.
+    pub(crate) fn explain_polonius_loan_liveness(
+        &self,
+        err: &mut Diagnostic,
+        location: Location,
+        borrow: &BorrowData<'tcx>,
+    ) {
+        let Some(polonius) = &self.polonius_output else {
+            return;
+        };
+        let Some(borrow_index) = self.borrow_set.get_index_of(&borrow.reserve_location) else {
+            return;
+        };
+        let is_live = |location: Location| {
+            polonius
+                .loans_in_scope_at(self.location_table.start_index(location))
+                .contains(&borrow_index)
+        };
+        if !is_live(location) {
+            return;
+        }
+
+        // Breadth-first search, so that the path we point at is the shortest one.
+        let mut predecessors = FxHashMap::default();
+        let mut queue = VecDeque::from([location]);
+        let mut last_live = None;
+        while let Some(current) = queue.pop_front() {
+            let block = &self.body[current.block];
+            let successors: Vec<Location> = if current.statement_index < block.statements.len() {
+                vec![current.successor_within_block()]
+            } else {
+                block
+                    .terminator()
+                    .successors()
+                    .filter(|&bb| !self.body[bb].is_cleanup)
+                    .map(|bb| bb.start_location())
+                    .collect()
+            };
+
+            let mut stays_live = false;
+            for successor in successors {
+                if !is_live(successor) {
+                    continue;
+                }
+                stays_live = true;
+                if successor != location && !predecessors.contains_key(&successor) {
+                    predecessors.insert(successor, current);
+                    queue.push_back(successor);
+                }
+            }
+            if !stays_live {
+                last_live = Some(current);
+                break;
+            }
+        }
+        let Some(last_live) = last_live else {
+            // The loan stays live around a loop, there is no single point to blame.
+            return;
+        };
+
+        let last_live_span = self.body.source_info(last_live).span;
+        err.span_note(last_live_span, "the borrow is last live here");
+
+        // Point at the terminators we went through, to show the branches taken from the point
+        // where the borrow is invalidated to the one where it is last live.
+        let mut path_spans = vec![];
+        let mut current = last_live;
+        while let Some(&predecessor) = predecessors.get(&current) {
+            if predecessor.block != current.block {
+                let span = self.body.source_info(predecessor).span;
+                if !span.is_dummy() && span != last_live_span && !path_spans.contains(&span) {
+                    path_spans.push(span);
+                }
+            }
+            current = predecessor;
+        }
+        if !path_spans.is_empty() {
+            path_spans.reverse();
+            err.span_note(
+                path_spans,
+                "the borrow is still live when going through here after being invalidated",
+            );
+        }
+    }
+
     /// Determine how the borrow was later used.
     /// First span returned points to the location of the conflicting use
     /// Second span if `Some` is returned in the case of closures and points
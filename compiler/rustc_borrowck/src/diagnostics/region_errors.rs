@@ -1,7 +1,7 @@
 //! Error reporting machinery for lifetime errors.
 
 use rustc_data_structures::fx::FxIndexSet;
-use rustc_errors::{Applicability, Diagnostic, DiagnosticBuilder, MultiSpan};
+use rustc_errors::{pluralize, Applicability, Diagnostic, DiagnosticBuilder, MultiSpan};
 use rustc_hir as hir;
 use rustc_hir::def::Res::Def;
 use rustc_hir::def_id::DefId;
@@ -24,6 +24,7 @@
 use rustc_middle::ty::TypeVisitor;
 use rustc_middle::ty::{self, RegionVid, Ty};
 use rustc_middle::ty::{Region, TyCtxt};
+use rustc_session::config::ConstraintPathFormat;
 use rustc_span::symbol::{kw, Ident};
 use rustc_span::Span;
 
@@ -382,7 +383,10 @@ pub(crate) fn report_region_errors(&mut self, nll_errors: RegionErrors<'tcx>) {
                     let universe = placeholder.universe;
                     let universe_info = self.regioncx.universe_info(universe);
 
-                    universe_info.report_error(self, placeholder, error_element, cause);
+                    let mut diag =
+                        universe_info.report_error(self, placeholder, error_element, cause);
+                    self.add_constraint_path_note(&mut diag, longer_fr, error_vid);
+                    self.buffer_error(diag);
                 }
 
                 RegionErrorKind::RegionError { fr_origin, longer_fr, shorter_fr, is_reported } => {
@@ -442,7 +446,8 @@ pub(crate) fn report_region_error(
         if let (Some(f), Some(o)) = (self.to_error_region(fr), self.to_error_region(outlived_fr)) {
             let infer_err = self.infcx.err_ctxt();
             let nice = NiceRegionError::new_from_span(&infer_err, cause.span, o, f);
-            if let Some(diag) = nice.try_report_from_nll() {
+            if let Some(mut diag) = nice.try_report_from_nll() {
+                self.add_constraint_path_note(&mut diag, fr, outlived_fr);
                 self.buffer_error(diag);
                 return;
             }
@@ -551,9 +556,62 @@ pub(crate) fn report_region_error(
             }
         }
 
+        self.add_constraint_path_note(&mut diag, fr, outlived_fr);
         self.buffer_error(diag);
     }
 
+    /// With `-Z borrowck-graphviz-errors`, attaches the path of outlives constraints that
+    /// requires `fr` to outlive `outlived_fr` to `diag`, either as a graphviz graph or as one
+    /// note per constraint, pointing at the span it originates from.
+    fn add_constraint_path_note(
+        &self,
+        diag: &mut Diagnostic,
+        fr: RegionVid,
+        outlived_fr: RegionVid,
+    ) {
+        let Some(format) = self.infcx.tcx.sess.opts.unstable_opts.borrowck_graphviz_errors else {
+            return;
+        };
+        let Some((path, _)) = self.regioncx.find_constraint_paths_between_regions(fr, |r| {
+            self.regioncx.provides_universal_region(r, fr, outlived_fr)
+        }) else {
+            return;
+        };
+
+        let fr_label = self.regioncx.region_debug_label(fr);
+        let outlived_fr_label = self.regioncx.region_debug_label(outlived_fr);
+        match format {
+            ConstraintPathFormat::Graphviz => {
+                let graph =
+                    self.regioncx.constraint_path_graphviz(self.infcx.tcx.sess.source_map(), &path);
+                diag.note(format!(
+                    "outlives constraints requiring `{fr_label}: {outlived_fr_label}`:\n{graph}"
+                ));
+            }
+            ConstraintPathFormat::Text => {
+                diag.note(format!(
+                    "`{fr_label}: {outlived_fr_label}` is required by {} outlives constraint{}",
+                    path.len(),
+                    pluralize!(path.len()),
+                ));
+                for constraint in &path {
+                    let msg = format!(
+                        "`{}: {}` due to {:?}{}",
+                        self.regioncx.region_debug_label(constraint.sup),
+                        self.regioncx.region_debug_label(constraint.sub),
+                        constraint.category,
+                        if constraint.from_closure { ", from closure requirements" } else { "" },
+                    );
+                    if constraint.span.is_dummy() {
+                        diag.note(msg);
+                    } else {
+                        diag.span_note(constraint.span, msg);
+                    }
+                }
+            }
+        }
+    }
+
     /// Report a specialized error when `FnMut` closures return a reference to a captured variable.
     /// This function expects `fr` to be local and `outlived_fr` to not be local.
     ///
@@ -773,6 +831,16 @@ fn report_general_error(&self, errci: &ErrorConstraintInfo<'tcx>) -> DiagnosticB
         let outlived_fr_name = self.give_region_a_name(*outlived_fr).unwrap();
         outlived_fr_name.highlight_region_name(&mut diag);
 
+        let explained_elided_self_lifetime =
+            matches!((category, outlived_fr_is_local), (ConstraintCategory::Return(_), true))
+                && self.explain_elided_self_lifetime(
+                    &mut diag,
+                    *fr,
+                    fr_name,
+                    *outlived_fr,
+                    outlived_fr_name,
+                );
+
         let err_category = match (category, outlived_fr_is_local, fr_is_local) {
             (ConstraintCategory::Return(_), true, _) => LifetimeReturnCategoryErr::WrongReturn {
                 span: *span,
@@ -791,7 +859,9 @@ fn report_general_error(&self, errci: &ErrorConstraintInfo<'tcx>) -> DiagnosticB
         diag.subdiagnostic(self.dcx(), err_category);
 
         self.add_static_impl_trait_suggestion(&mut diag, *fr, fr_name, *outlived_fr);
-        self.suggest_adding_lifetime_params(&mut diag, *fr, *outlived_fr);
+        if !explained_elided_self_lifetime {
+            self.suggest_adding_lifetime_params(&mut diag, *fr, *outlived_fr);
+        }
         self.suggest_move_on_borrowing_closure(&mut diag);
 
         diag
@@ -4,10 +4,12 @@
 use std::fmt::{self, Display};
 use std::iter;
 
-use rustc_data_structures::fx::IndexEntry;
-use rustc_errors::Diagnostic;
+use rustc_data_structures::fx::{FxHashSet, FxIndexMap, IndexEntry};
+use rustc_errors::{pluralize, Applicability, Diagnostic};
 use rustc_hir as hir;
 use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::{self, Visitor};
 use rustc_middle::ty::print::RegionHighlightMode;
 use rustc_middle::ty::{self, RegionVid, Ty};
 use rustc_middle::ty::{GenericArgKind, GenericArgsRef};
@@ -1019,4 +1021,224 @@ fn any_param_predicate_mentions(
             }
         })
     }
+
+    /// Explains a "lifetime may not live long enough" error where `outlived_fr` is the elided
+    /// lifetime of a `&self` or `&mut self` receiver, which the elision rules also gave to the
+    /// return type: points at the elided lifetimes of the return type, and shows the signature
+    /// with all of its elided lifetimes written out. When there is a single such lifetime, also
+    /// suggests the explicit signature returning data with the lifetime of `fr` instead.
+    ///
+    /// Returns whether that signature was suggested.
+    pub(crate) fn explain_elided_self_lifetime(
+        &self,
+        diag: &mut Diagnostic,
+        fr: RegionVid,
+        fr_name: RegionName,
+        outlived_fr: RegionVid,
+        outlived_fr_name: RegionName,
+    ) -> bool {
+        let tcx = self.infcx.tcx;
+        let mir_def_id = self.mir_def_id();
+        let node = tcx.hir_node_by_def_id(mir_def_id);
+        let (Some(sig), Some(generics)) = (node.fn_sig(), node.generics()) else {
+            return false;
+        };
+        if !matches!(
+            sig.decl.implicit_self,
+            hir::ImplicitSelfKind::ImmRef | hir::ImplicitSelfKind::MutRef
+        ) || sig.span.from_expansion()
+        {
+            return false;
+        }
+        let hir::TyKind::Ref(self_lifetime, _) = sig.decl.inputs[0].kind else {
+            return false;
+        };
+        let hir::LifetimeName::Param(self_param) = self_lifetime.res else {
+            return false;
+        };
+        let param_of = |fr| {
+            let region = self.to_error_region(fr)?;
+            tcx.is_suitable_region(region)?.bound_region.get_id()?.as_local()
+        };
+        if !self_lifetime.is_anonymous() || param_of(outlived_fr) != Some(self_param) {
+            return false;
+        }
+
+        let mut inputs = SignatureLifetimes::default();
+        for ty in sig.decl.inputs {
+            inputs.visit_ty(ty);
+        }
+        let mut output = SignatureLifetimes::default();
+        if let hir::FnRetTy::Return(ty) = sig.decl.output {
+            output.visit_ty(ty);
+        }
+        let elision_sites: Vec<_> = output
+            .lifetimes
+            .iter()
+            .filter(|(lifetime, _)| lifetime.res == self_lifetime.res && lifetime.is_anonymous())
+            .map(|&(_, span)| span)
+            .collect();
+        if inputs.is_unsupported || output.is_unsupported || elision_sites.is_empty() {
+            return false;
+        }
+
+        // Lifetimes we introduce must not shadow the ones already in scope, early- or late-bound.
+        let mut used_names: FxHashSet<Symbol> =
+            generics.params.iter().map(|param| param.name.ident().name).collect();
+        let mut parent = Some(tcx.local_parent(mir_def_id).to_def_id());
+        while let Some(def_id) = parent {
+            let parent_generics = tcx.generics_of(def_id);
+            used_names.extend(parent_generics.params.iter().map(|param| param.name));
+            parent = parent_generics.parent;
+        }
+        let used_names = &used_names;
+        let fresh_names = || {
+            ('a'..='z')
+                .map(|c| Symbol::intern(&format!("'{c}")))
+                .filter(move |name| !used_names.contains(name))
+        };
+
+        // Writes the given lifetimes out in the source of the signature.
+        let add_params = |names: &[Symbol]| {
+            let names = names.iter().map(|name| name.as_str()).collect::<Vec<_>>().join(", ");
+            match generics.span_for_lifetime_suggestion() {
+                Some(span) => (span, format!("{names}, ")),
+                None => (generics.span, format!("<{names}>")),
+            }
+        };
+        let write_out = |mut edits: Vec<(Span, String)>| -> Option<String> {
+            let mut snippet = tcx.sess.source_map().span_to_snippet(sig.span).ok()?;
+            edits.sort_by_key(|(span, _)| span.lo());
+            for (span, sugg) in edits.into_iter().rev() {
+                if !sig.span.contains(span) {
+                    return None;
+                }
+                let lo = (span.lo() - sig.span.lo()).to_usize();
+                let hi = (span.hi() - sig.span.lo()).to_usize();
+                snippet.replace_range(lo..hi, &sugg);
+            }
+            Some(snippet)
+        };
+
+        let mut names = FxIndexMap::<LocalDefId, Symbol>::default();
+        let mut fresh = fresh_names();
+        let mut desugared_edits = vec![];
+        for (lifetime, _) in inputs.lifetimes.iter().chain(&output.lifetimes) {
+            if !lifetime.is_anonymous() {
+                continue;
+            }
+            let hir::LifetimeName::Param(param) = lifetime.res else {
+                return false;
+            };
+            let name = match names.entry(param) {
+                IndexEntry::Occupied(entry) => *entry.get(),
+                IndexEntry::Vacant(entry) => {
+                    let Some(name) = fresh.next() else {
+                        return false;
+                    };
+                    *entry.insert(name)
+                }
+            };
+            desugared_edits.push(lifetime.suggestion(name.as_str()));
+        }
+        desugared_edits.push(add_params(&names.values().copied().collect::<Vec<_>>()));
+
+        diag.span_note(
+            elision_sites.clone(),
+            format!(
+                "the elided lifetime{s} of the return type {is} the lifetime `{outlived_fr_name}` \
+                 of `self`",
+                s = pluralize!(elision_sites.len()),
+                is = if elision_sites.len() == 1 { "is" } else { "are" },
+            ),
+        );
+        if let Some(desugared) = write_out(desugared_edits) {
+            diag.note(format!(
+                "with its elided lifetimes written out, the signature of this {} is `{desugared}`",
+                tcx.def_descr(mir_def_id.to_def_id()),
+            ));
+        }
+
+        // Changing the signature of a trait method's implementation is not an option, and which
+        // of several elided lifetimes should change is anyone's guess.
+        let in_trait_impl = tcx
+            .impl_of_method(mir_def_id.to_def_id())
+            .is_some_and(|impl_def_id| tcx.trait_id_of_impl(impl_def_id).is_some());
+        let Some(fr_region) = self.to_error_region(fr) else {
+            return false;
+        };
+        if in_trait_impl || elision_sites.len() != 1 || fr_region.is_static() {
+            return false;
+        }
+
+        let (suggestion, msg) = if let Some(name) = fr_region.get_name() {
+            let edits = output
+                .lifetimes
+                .iter()
+                .filter(|(lifetime, _)| lifetime.res == self_lifetime.res)
+                .map(|(lifetime, _)| lifetime.suggestion(name.as_str()))
+                .collect::<Vec<_>>();
+            (edits, format!("to return data with lifetime `{fr_name}`, use it in the return type"))
+        } else {
+            let Some(fr_param) = param_of(fr) else {
+                return false;
+            };
+            let fr_lifetime = hir::LifetimeName::Param(fr_param);
+            if !inputs
+                .lifetimes
+                .iter()
+                .any(|(lifetime, _)| lifetime.res == fr_lifetime && lifetime.is_anonymous())
+            {
+                return false;
+            }
+            let Some(name) = fresh_names().next() else {
+                return false;
+            };
+            let mut edits = inputs
+                .lifetimes
+                .iter()
+                .filter(|(lifetime, _)| lifetime.res == fr_lifetime)
+                .chain(
+                    output
+                        .lifetimes
+                        .iter()
+                        .filter(|(lifetime, _)| lifetime.res == self_lifetime.res),
+                )
+                .map(|(lifetime, _)| lifetime.suggestion(name.as_str()))
+                .collect::<Vec<_>>();
+            edits.push(add_params(&[name]));
+            (edits, format!("to return data with lifetime `{fr_name}`, name it in the signature"))
+        };
+        diag.multipart_suggestion_verbose(msg, suggestion, Applicability::MaybeIncorrect);
+        true
+    }
+}
+
+/// Collects the lifetimes appearing in the types of a signature, along with the span to point
+/// at for each of them: the whole reference type for the lifetime of a reference.
+#[derive(Default)]
+struct SignatureLifetimes<'hir> {
+    lifetimes: Vec<(&'hir hir::Lifetime, Span)>,
+    /// Whether there are trait objects or opaque types, whose lifetimes are defaulted or
+    /// captured rather than elided.
+    is_unsupported: bool,
+}
+
+impl<'hir> Visitor<'hir> for SignatureLifetimes<'hir> {
+    fn visit_ty(&mut self, ty: &'hir hir::Ty<'hir>) {
+        match ty.kind {
+            hir::TyKind::Ref(lifetime, mut_ty) => {
+                self.lifetimes.push((lifetime, ty.span));
+                self.visit_ty(mut_ty.ty);
+            }
+            hir::TyKind::OpaqueDef(..) | hir::TyKind::TraitObject(..) => {
+                self.is_unsupported = true;
+            }
+            _ => intravisit::walk_ty(self, ty),
+        }
+    }
+
+    fn visit_lifetime(&mut self, lifetime: &'hir hir::Lifetime) {
+        self.lifetimes.push((lifetime, lifetime.ident.span));
+    }
 }
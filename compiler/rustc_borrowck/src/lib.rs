@@ -79,6 +79,7 @@
 mod region_infer;
 mod renumber;
 mod session_diagnostics;
+mod trivial;
 mod type_check;
 mod universal_regions;
 mod used_muts;
@@ -125,11 +126,22 @@ fn mir_borrowck(tcx: TyCtxt<'_>, def: LocalDefId) -> &BorrowCheckResult<'_> {
         return tcx.arena.alloc(result);
     }
 
+    let promoted: &IndexSlice<_, _> = &promoted.borrow();
+    if trivial::is_trivially_accepted(tcx, input_body, promoted) {
+        debug!("Skipping borrowck because the body is trivially accepted");
+        let result = BorrowCheckResult {
+            concrete_opaque_types: FxIndexMap::default(),
+            closure_requirements: None,
+            used_mut_upvars: SmallVec::new(),
+            tainted_by_errors: None,
+        };
+        return tcx.arena.alloc(result);
+    }
+
     let hir_owner = tcx.local_def_id_to_hir_id(def).owner;
 
     let infcx =
         tcx.infer_ctxt().with_opaque_type_inference(DefiningAnchor::Bind(hir_owner.def_id)).build();
-    let promoted: &IndexSlice<_, _> = &promoted.borrow();
     let opt_closure_req = do_mir_borrowck(&infcx, input_body, promoted, None).0;
     debug!("mir_borrowck done");
 
@@ -236,6 +248,7 @@ fn do_mir_borrowck<'tcx>(
     // Dump MIR results into a file, if that is enabled. This let us
     // write unit-tests, as well as helping with debugging.
     nll::dump_mir_results(&infcx, body, &regioncx, &opt_closure_req);
+    nll::dump_borrowck(&infcx, body, &regioncx);
 
     // We also have a `#[rustc_regions]` annotation that causes us to dump
     // information.
@@ -705,6 +718,12 @@ fn visit_terminator_before_primary_effect(
                 }
                 self.mutate_place(loc, (*destination, span), Deep, flow_state);
             }
+            TerminatorKind::TailCall { func, args, fn_span: _ } => {
+                self.consume_operand(loc, (func, span), flow_state);
+                for arg in args {
+                    self.consume_operand(loc, (&arg.node, arg.span), flow_state);
+                }
+            }
             TerminatorKind::Assert { cond, expected: _, msg, target: _, unwind: _ } => {
                 self.consume_operand(loc, (cond, span), flow_state);
                 if let AssertKind::BoundsCheck { len, index } = &**msg {
@@ -790,6 +809,7 @@ fn visit_terminator_after_primary_effect(
 
             TerminatorKind::UnwindResume
             | TerminatorKind::Return
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::CoroutineDrop => {
                 // Returning from the function implicitly kills storage for all locals and statics.
                 // Often, the storage will already have been killed by an explicit
@@ -1191,13 +1211,21 @@ fn consume_rvalue(
                     }
                 };
 
-                self.access_place(
-                    location,
-                    (place, span),
-                    access_kind,
-                    LocalMutationIsAllowed::No,
-                    flow_state,
-                );
+                // A borrow restricted to the view of a method only accesses the fields in it.
+                let borrow_set = self.borrow_set.clone();
+                let viewed_places = match borrow_set.get_index_of(&location) {
+                    Some(index) => borrow_set[index].viewed_places(),
+                    None => std::slice::from_ref(&place),
+                };
+                for &place in viewed_places {
+                    self.access_place(
+                        location,
+                        (place, span),
+                        access_kind,
+                        LocalMutationIsAllowed::No,
+                        flow_state,
+                    );
+                }
 
                 let action = if bk == BorrowKind::Fake {
                     InitializationRequiringAction::MatchOn
@@ -1562,13 +1590,15 @@ fn check_activations(&mut self, location: Location, span: Span, flow_state: &Flo
                 BorrowKind::Mut { .. } => true,
             });
 
-            self.access_place(
-                location,
-                (borrow.borrowed_place, span),
-                (Deep, Activation(WriteKind::MutableBorrow(borrow.kind), borrow_index)),
-                LocalMutationIsAllowed::No,
-                flow_state,
-            );
+            for &place in borrow.viewed_places() {
+                self.access_place(
+                    location,
+                    (place, span),
+                    (Deep, Activation(WriteKind::MutableBorrow(borrow.kind), borrow_index)),
+                    LocalMutationIsAllowed::No,
+                    flow_state,
+                );
+            }
             // We do not need to call `check_if_path_or_subpath_is_moved`
             // again, as we already called it when we made the
             // initial reservation.
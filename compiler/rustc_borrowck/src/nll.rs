@@ -12,9 +12,11 @@
 use rustc_mir_dataflow::move_paths::MoveData;
 use rustc_mir_dataflow::points::DenseLocationMap;
 use rustc_mir_dataflow::ResultsCursor;
+use rustc_session::config::DumpBorrowckFormat;
 use rustc_span::symbol::sym;
 use std::env;
-use std::io;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::str::FromStr;
@@ -28,6 +30,7 @@
     polonius,
     region_infer::RegionInferenceContext,
     renumber,
+    session_diagnostics::CouldntDumpRegionInference,
     type_check::{self, MirTypeckRegionConstraints, MirTypeckResults},
     universal_regions::UniversalRegions,
     BorrowckInferCtxt,
@@ -254,6 +257,32 @@ pub(super) fn dump_mir_results<'tcx>(
     };
 }
 
+/// Dumps the region inference context of `body` into `-Zdump-borrowck-dir`, if
+/// `-Zdump-borrowck` is enabled.
+pub(super) fn dump_borrowck<'tcx>(
+    infcx: &BorrowckInferCtxt<'_, 'tcx>,
+    body: &Body<'tcx>,
+    regioncx: &RegionInferenceContext<'tcx>,
+) {
+    let tcx = infcx.tcx;
+    let Some(DumpBorrowckFormat::Json) = tcx.sess.opts.unstable_opts.dump_borrowck else {
+        return;
+    };
+
+    let result: Result<(), Box<dyn std::error::Error>> = try {
+        let dir_path = PathBuf::from(&tcx.sess.opts.unstable_opts.dump_borrowck_dir);
+        fs::create_dir_all(&dir_path)?;
+        let def_path = tcx.def_path(body.source.def_id());
+        let file_path = dir_path.join(format!("{}.json", def_path.to_filename_friendly_no_crate()));
+        let mut file = BufWriter::new(File::create(file_path)?);
+        regioncx.dump_json(infcx, body, &mut file)?;
+        file.flush()?;
+    };
+    if let Err(err) = result {
+        tcx.dcx().emit_fatal(CouldntDumpRegionInference { error: err.to_string() });
+    }
+}
+
 #[allow(rustc::diagnostic_outside_of_impl)]
 #[allow(rustc::untranslatable_diagnostic)]
 pub(super) fn dump_annotation<'tcx>(
@@ -49,15 +49,17 @@ pub(super) fn each_borrow_involving_path<'tcx, F, I, S>(
         }
         let borrowed = &borrow_set[i];
 
-        if places_conflict::borrow_conflicts_with_place(
-            tcx,
-            body,
-            borrowed.borrowed_place,
-            borrowed.kind,
-            place.as_ref(),
-            access,
-            places_conflict::PlaceConflictBias::Overlap,
-        ) {
+        if borrowed.viewed_places().iter().any(|&borrowed_place| {
+            places_conflict::borrow_conflicts_with_place(
+                tcx,
+                body,
+                borrowed_place,
+                borrowed.kind,
+                place.as_ref(),
+                access,
+                places_conflict::PlaceConflictBias::Overlap,
+            )
+        }) {
             debug!(
                 "each_borrow_involving_path: {:?} @ {:?} vs. {:?}/{:?}",
                 i, borrowed, place, access
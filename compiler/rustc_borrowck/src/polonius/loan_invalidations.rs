@@ -122,6 +122,21 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                 }
                 self.mutate_place(location, *destination, Deep);
             }
+            TerminatorKind::TailCall { func, args, fn_span: _ } => {
+                self.consume_operand(location, func);
+                for arg in args {
+                    self.consume_operand(location, &arg.node);
+                }
+
+                // Invalidate all borrows of local places
+                let borrow_set = self.borrow_set;
+                let start = self.location_table.start_index(location);
+                for (i, data) in borrow_set.iter_enumerated() {
+                    if borrow_of_local_data(data.borrowed_place) {
+                        self.all_facts.loan_invalidated_at.push((start, i));
+                    }
+                }
+            }
             TerminatorKind::Assert { cond, expected: _, msg, target: _, unwind: _ } => {
                 self.consume_operand(location, cond);
                 use rustc_middle::mir::AssertKind;
@@ -252,7 +267,15 @@ fn consume_rvalue(&mut self, location: Location, rvalue: &Rvalue<'tcx>) {
                     }
                 };
 
-                self.access_place(location, place, access_kind, LocalMutationIsAllowed::No);
+                // A borrow restricted to the view of a method only accesses the fields in it.
+                let borrow_set = self.borrow_set;
+                let viewed_places = match borrow_set.get_index_of(&location) {
+                    Some(index) => borrow_set[index].viewed_places(),
+                    None => std::slice::from_ref(&place),
+                };
+                for &place in viewed_places {
+                    self.access_place(location, place, access_kind, LocalMutationIsAllowed::No);
+                }
             }
 
             &Rvalue::AddressOf(mutability, place) => {
@@ -406,12 +429,14 @@ fn check_activations(&mut self, location: Location) {
                 BorrowKind::Mut { .. } => true,
             });
 
-            self.access_place(
-                location,
-                borrow.borrowed_place,
-                (Deep, Activation(WriteKind::MutableBorrow(borrow.kind), borrow_index)),
-                LocalMutationIsAllowed::No,
-            );
+            for &place in borrow.viewed_places() {
+                self.access_place(
+                    location,
+                    place,
+                    (Deep, Activation(WriteKind::MutableBorrow(borrow.kind), borrow_index)),
+                    LocalMutationIsAllowed::No,
+                );
+            }
 
             // We do not need to call `check_if_path_or_subpath_is_moved`
             // again, as we already called it when we made the
@@ -114,13 +114,20 @@ fn record_killed_borrows_for_place(&mut self, place: Place<'tcx>, location: Loca
 
                 if let Some(borrow_indices) = self.borrow_set.local_map.get(&local) {
                     for &borrow_index in borrow_indices {
-                        let places_conflict = places_conflict::places_conflict(
-                            self.tcx,
-                            self.body,
-                            self.borrow_set[borrow_index].borrowed_place,
-                            place,
-                            places_conflict::PlaceConflictBias::NoOverlap,
-                        );
+                        // A borrow restricted to a view is only killed when all of it is
+                        // overwritten.
+                        let places_conflict = self.borrow_set[borrow_index]
+                            .viewed_places()
+                            .iter()
+                            .all(|&borrowed_place| {
+                                places_conflict::places_conflict(
+                                    self.tcx,
+                                    self.body,
+                                    borrowed_place,
+                                    place,
+                                    places_conflict::PlaceConflictBias::NoOverlap,
+                                )
+                            });
 
                         if places_conflict {
                             let location_index = self.location_table.mid_index(location);
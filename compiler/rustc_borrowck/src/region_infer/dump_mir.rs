@@ -2,10 +2,15 @@
 //! we will generate an annotated copy of the MIR that includes the
 //! state of region inference. This code handles emitting the region
 //! context internal state.
+//!
+//! `-Zdump-borrowck=json` dumps the same state as a JSON document per body instead, for
+//! consumption by external tools.
 
 use super::{OutlivesConstraint, RegionInferenceContext};
 use crate::type_check::Locations;
-use rustc_infer::infer::NllRegionVariableOrigin;
+use rustc_infer::infer::{InferCtxt, NllRegionVariableOrigin};
+use rustc_middle::mir::Body;
+use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::TyCtxt;
 use std::io::{self, Write};
 
@@ -55,6 +60,100 @@ pub(crate) fn dump_mir(&self, tcx: TyCtxt<'tcx>, out: &mut dyn Write) -> io::Res
         Ok(())
     }
 
+    /// Write out the region variables with their origins, the outlives constraints with the
+    /// spans they arise from, and the inferred value of every region as JSON.
+    pub(crate) fn dump_json(
+        &self,
+        infcx: &InferCtxt<'tcx>,
+        body: &Body<'tcx>,
+        out: &mut dyn Write,
+    ) -> serde_json::Result<()> {
+        #[derive(serde::Serialize)]
+        struct RegionVariable {
+            region: String,
+            origin: &'static str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            classification: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            name: Option<String>,
+            universe: u32,
+            span: String,
+            live_at: String,
+            value: String,
+        }
+
+        #[derive(serde::Serialize)]
+        struct Constraint {
+            sup: String,
+            sub: String,
+            category: String,
+            locations: String,
+            span: String,
+        }
+
+        #[derive(serde::Serialize)]
+        struct RegionInferenceDump {
+            def_path: String,
+            regions: Vec<RegionVariable>,
+            constraints: Vec<Constraint>,
+        }
+
+        let tcx = infcx.tcx;
+        let source_map = tcx.sess.source_map();
+
+        let regions = self
+            .definitions
+            .iter_enumerated()
+            .map(|(region, definition)| {
+                let origin = match definition.origin {
+                    NllRegionVariableOrigin::FreeRegion => "free",
+                    NllRegionVariableOrigin::Placeholder(_) => "placeholder",
+                    NllRegionVariableOrigin::Existential { from_forall: false } => "existential",
+                    NllRegionVariableOrigin::Existential { from_forall: true } => {
+                        "existential_from_forall"
+                    }
+                };
+                RegionVariable {
+                    region: format!("{region:?}"),
+                    origin,
+                    classification: self
+                        .universal_regions
+                        .region_classification(region)
+                        .map(|classification| format!("{classification:?}")),
+                    name: definition.external_name.map(|name| name.to_string()),
+                    universe: self.region_universe(region).as_u32(),
+                    span: source_map
+                        .span_to_embeddable_string(infcx.region_var_origin(region).span()),
+                    live_at: self.liveness_constraints.pretty_print_live_points(region),
+                    value: self.region_value_str(region),
+                }
+            })
+            .collect();
+
+        let mut constraints: Vec<_> = self.constraints.outlives().iter().collect();
+        constraints.sort_by_key(|c| (c.sup, c.sub));
+        let constraints = constraints
+            .into_iter()
+            .map(|OutlivesConstraint { sup, sub, locations, category, span, .. }| Constraint {
+                sup: format!("{sup:?}"),
+                sub: format!("{sub:?}"),
+                category: format!("{category:?}"),
+                locations: match locations {
+                    Locations::All(_) => "All".to_string(),
+                    Locations::Single(loc) => format!("{loc:?}"),
+                },
+                span: source_map.span_to_embeddable_string(*span),
+            })
+            .collect();
+
+        let dump = RegionInferenceDump {
+            def_path: with_no_trimmed_paths!(tcx.def_path_str(body.source.def_id())),
+            regions,
+            constraints,
+        };
+        serde_json::to_writer(out, &dump)
+    }
+
     /// Debugging aid: Invokes the `with_msg` callback repeatedly with
     /// our internal region constraints. These are dumped into the
     /// -Zdump-mir file so that we can figure out why the region
@@ -7,6 +7,7 @@
 
 use super::*;
 use rustc_graphviz as dot;
+use rustc_span::source_map::SourceMap;
 
 impl<'tcx> RegionInferenceContext<'tcx> {
     /// Write out the region constraint graph.
@@ -26,6 +27,37 @@ pub(crate) fn dump_graphviz_scc_constraints(&self, mut w: &mut dyn Write) -> io:
 
         dot::render(&SccConstraints { regioncx: self, nodes_per_scc }, &mut w)
     }
+
+    /// Renders a path of outlives constraints, as found by
+    /// `find_constraint_paths_between_regions`, for `-Z borrowck-graphviz-errors`. Each edge is
+    /// labelled with its category and the location of the span it originates from.
+    pub(crate) fn constraint_path_graphviz(
+        &self,
+        source_map: &SourceMap,
+        path: &[OutlivesConstraint<'tcx>],
+    ) -> String {
+        let mut nodes = vec![];
+        for constraint in path {
+            for r in [constraint.sup, constraint.sub] {
+                if !nodes.contains(&r) {
+                    nodes.push(r);
+                }
+            }
+        }
+
+        let mut buf = Vec::new();
+        dot::render(&ConstraintPath { regioncx: self, source_map, nodes, path }, &mut buf)
+            .expect("writing to a `Vec` cannot fail");
+        String::from_utf8(buf).unwrap()
+    }
+
+    /// Describes `r` along with the universal region it stands for, if any.
+    pub(crate) fn region_debug_label(&self, r: RegionVid) -> String {
+        match self.definitions[r].external_name {
+            Some(name) => format!("{r:?} ({name})"),
+            None => format!("{r:?}"),
+        }
+    }
 }
 
 struct RawConstraints<'a, 'tcx> {
@@ -137,3 +169,52 @@ fn target(&'this self, edge: &(ConstraintSccIndex, ConstraintSccIndex)) -> Const
         edge.1
     }
 }
+
+struct ConstraintPath<'a, 'tcx> {
+    regioncx: &'a RegionInferenceContext<'tcx>,
+    source_map: &'a SourceMap,
+    nodes: Vec<RegionVid>,
+    path: &'a [OutlivesConstraint<'tcx>],
+}
+
+impl<'a, 'this, 'tcx> dot::Labeller<'this> for ConstraintPath<'a, 'tcx> {
+    type Node = RegionVid;
+    type Edge = OutlivesConstraint<'tcx>;
+
+    fn graph_id(&'this self) -> dot::Id<'this> {
+        dot::Id::new("ConstraintPath").unwrap()
+    }
+    fn node_id(&'this self, n: &RegionVid) -> dot::Id<'this> {
+        dot::Id::new(format!("r{}", n.index())).unwrap()
+    }
+    fn node_shape(&'this self, _node: &RegionVid) -> Option<dot::LabelText<'this>> {
+        Some(dot::LabelText::LabelStr(Cow::Borrowed("box")))
+    }
+    fn node_label(&'this self, n: &RegionVid) -> dot::LabelText<'this> {
+        dot::LabelText::LabelStr(self.regioncx.region_debug_label(*n).into())
+    }
+    fn edge_label(&'this self, e: &OutlivesConstraint<'tcx>) -> dot::LabelText<'this> {
+        let location = self.source_map.span_to_embeddable_string(e.span);
+        dot::LabelText::LabelStr(format!("{:?} at {location}", e.category).into())
+    }
+}
+
+impl<'a, 'this, 'tcx> dot::GraphWalk<'this> for ConstraintPath<'a, 'tcx> {
+    type Node = RegionVid;
+    type Edge = OutlivesConstraint<'tcx>;
+
+    fn nodes(&'this self) -> dot::Nodes<'this, RegionVid> {
+        (&self.nodes[..]).into()
+    }
+    fn edges(&'this self) -> dot::Edges<'this, OutlivesConstraint<'tcx>> {
+        self.path.into()
+    }
+
+    fn source(&'this self, edge: &OutlivesConstraint<'tcx>) -> RegionVid {
+        edge.sup
+    }
+
+    fn target(&'this self, edge: &OutlivesConstraint<'tcx>) -> RegionVid {
+        edge.sub
+    }
+}
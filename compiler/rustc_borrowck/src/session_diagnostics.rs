@@ -459,3 +459,9 @@ pub(crate) struct SimdShuffleLastConst {
     #[primary_span]
     pub span: Span,
 }
+
+#[derive(Diagnostic)]
+#[diag(borrowck_couldnt_dump_region_inference)]
+pub(crate) struct CouldntDumpRegionInference {
+    pub error: String,
+}
@@ -0,0 +1,216 @@
+//! A cheap pre-scan recognizing bodies that borrowck is guaranteed to accept without reporting
+//! anything, so that `mir_borrowck` can skip the dataflow analyses and region inference for them.
+//!
+//! Crates of generated code often consist of thousands of tiny functions shuffling plain values
+//! around, and running the full borrow checker on each of them dominates their check builds. Such
+//! a body can't have any borrowck error or lint when:
+//! - it doesn't borrow, take the address of, or dereference anything, and its MIR mentions no
+//!   regions, opaque types, or generic parameters, so there are no region constraints to solve;
+//! - it has no drops, user type annotations, closures or coroutines, and no `mut` user variables,
+//!   which could only end up triggering the `unused_mut` lint;
+//! - its control-flow graph is acyclic, its arguments are never assigned, every user variable is
+//!   assigned exactly once before all of its uses, and no local is used after being moved.
+
+use rustc_index::bit_set::BitSet;
+use rustc_index::{IndexSlice, IndexVec};
+use rustc_middle::mir::visit::{MutatingUseContext, NonMutatingUseContext, PlaceContext, Visitor};
+use rustc_middle::mir::{
+    dump_enabled, AggregateKind, BasicBlock, Body, Local, Location, Place, ProjectionElem,
+    Promoted, Rvalue, Statement, StatementKind, Terminator, TerminatorKind, RETURN_PLACE,
+};
+use rustc_middle::ty::{TyCtxt, TypeFlags, TypeVisitableExt};
+use rustc_span::symbol::sym;
+
+/// Returns `true` if borrowck would accept `body` without emitting any diagnostic, and it can be
+/// skipped. This is conservative: it returns `false` for anything it doesn't fully understand.
+pub(crate) fn is_trivially_accepted<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    body: &Body<'tcx>,
+    promoted: &IndexSlice<Promoted, Body<'tcx>>,
+) -> bool {
+    let def_id = body.source.def_id();
+    let opts = &tcx.sess.opts.unstable_opts;
+    // The debugging outputs of borrowck are expected to be produced for every body.
+    if !opts.borrowck_skip_trivial_bodies
+        || opts.nll_facts
+        || opts.dump_borrowck.is_some()
+        || dump_enabled(tcx, "nll", def_id)
+        || tcx.has_attr(tcx.typeck_root_def_id(def_id), sym::rustc_regions)
+    {
+        return false;
+    }
+
+    // Closures are checked as part of their parent body, which needs their region requirements.
+    if tcx.is_typeck_child(def_id)
+        || tcx.generics_of(def_id).requires_monomorphization(tcx)
+        || !promoted.is_empty()
+        || !body.user_type_annotations.is_empty()
+        || body.basic_blocks.is_cfg_cyclic()
+        || body.has_type_flags(
+            TypeFlags::HAS_FREE_REGIONS | TypeFlags::HAS_RE_ERASED | TypeFlags::HAS_TY_OPAQUE,
+        )
+    {
+        return false;
+    }
+
+    let param_env = tcx.param_env(def_id);
+    if body.local_decls.iter().any(|decl| {
+        (decl.is_user_variable() && decl.mutability.is_mut()) || decl.ty.needs_drop(tcx, param_env)
+    }) {
+        return false;
+    }
+
+    let mut prescan = Prescan {
+        is_trivial: true,
+        locals: IndexVec::from_elem(LocalUses::default(), &body.local_decls),
+    };
+    prescan.visit_body(body);
+    if !prescan.is_trivial {
+        return false;
+    }
+
+    let dominators = body.basic_blocks.dominators();
+    for (local, uses) in prescan.locals.iter_enumerated() {
+        if local == RETURN_PLACE {
+            continue;
+        }
+
+        // Arguments are initialized on entry, user variables by their only assignment. Other
+        // locals are temporaries, that MIR building always initializes before using them.
+        let is_arg = local.as_usize() <= body.arg_count;
+        if is_arg && !uses.defs.is_empty() {
+            return false;
+        }
+        if body.local_decls[local].is_user_variable() {
+            let [def] = uses.defs[..] else { return false };
+            if !uses
+                .uses
+                .iter()
+                .chain(&uses.moves)
+                .all(|&u| u != def && def.dominates(u, dominators))
+            {
+                return false;
+            }
+        }
+
+        match uses.moves[..] {
+            [] => {}
+            [move_location] => {
+                let reachable = reachable_from(body, move_location.block);
+                let is_after_move = |location: Location| {
+                    if location.block == move_location.block {
+                        location.statement_index > move_location.statement_index
+                    } else {
+                        reachable.contains(location.block)
+                    }
+                };
+                if uses.uses.iter().chain(&uses.defs).any(|&u| is_after_move(u)) {
+                    return false;
+                }
+            }
+            _ => return false,
+        }
+    }
+
+    true
+}
+
+/// The blocks reachable from the successors of `block`.
+fn reachable_from(body: &Body<'_>, block: BasicBlock) -> BitSet<BasicBlock> {
+    let mut reachable = BitSet::new_empty(body.basic_blocks.len());
+    let mut stack: Vec<_> = body.basic_blocks[block].terminator().successors().collect();
+    while let Some(block) = stack.pop() {
+        if reachable.insert(block) {
+            stack.extend(body.basic_blocks[block].terminator().successors());
+        }
+    }
+    reachable
+}
+
+#[derive(Clone, Default)]
+struct LocalUses {
+    /// Assignments to the whole local.
+    defs: Vec<Location>,
+    /// Moves out of the local, or out of a part of it.
+    moves: Vec<Location>,
+    /// Copies and inspections of the local, or of a part of it.
+    uses: Vec<Location>,
+}
+
+struct Prescan {
+    is_trivial: bool,
+    locals: IndexVec<Local, LocalUses>,
+}
+
+impl<'tcx> Visitor<'tcx> for Prescan {
+    fn visit_statement(&mut self, statement: &Statement<'tcx>, location: Location) {
+        if let StatementKind::AscribeUserType(..) = statement.kind {
+            self.is_trivial = false;
+        }
+        self.super_statement(statement, location);
+    }
+
+    fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location) {
+        match terminator.kind {
+            TerminatorKind::Drop { .. }
+            | TerminatorKind::InlineAsm { .. }
+            | TerminatorKind::Yield { .. }
+            | TerminatorKind::CoroutineDrop => self.is_trivial = false,
+            _ => {}
+        }
+        self.super_terminator(terminator, location);
+    }
+
+    fn visit_rvalue(&mut self, rvalue: &Rvalue<'tcx>, location: Location) {
+        match rvalue {
+            Rvalue::ThreadLocalRef(_) => self.is_trivial = false,
+            Rvalue::Aggregate(kind, _) => {
+                if let AggregateKind::Closure(..)
+                | AggregateKind::CoroutineClosure(..)
+                | AggregateKind::Coroutine(..) = **kind
+                {
+                    self.is_trivial = false;
+                }
+            }
+            _ => {}
+        }
+        self.super_rvalue(rvalue, location);
+    }
+
+    fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, location: Location) {
+        if place.is_indirect() {
+            self.is_trivial = false;
+        }
+
+        let uses = &mut self.locals[place.local];
+        match context {
+            PlaceContext::NonUse(_) => {}
+            PlaceContext::MutatingUse(MutatingUseContext::Store | MutatingUseContext::Call)
+                if place.projection.is_empty() =>
+            {
+                uses.defs.push(location)
+            }
+            // Moving out of an array by index is an error.
+            PlaceContext::NonMutatingUse(NonMutatingUseContext::Move)
+                if !place
+                    .projection
+                    .iter()
+                    .any(|elem| matches!(elem, ProjectionElem::Index(_))) =>
+            {
+                uses.moves.push(location)
+            }
+            PlaceContext::NonMutatingUse(
+                NonMutatingUseContext::Copy
+                | NonMutatingUseContext::Inspect
+                | NonMutatingUseContext::PlaceMention,
+            ) => uses.uses.push(location),
+            _ => self.is_trivial = false,
+        }
+
+        for elem in place.projection {
+            if let ProjectionElem::Index(index) = elem {
+                self.locals[index].uses.push(location);
+            }
+        }
+    }
+}
@@ -5,13 +5,10 @@
 use rustc_infer::infer::region_constraints::{GenericKind, VerifyBound};
 use rustc_infer::infer::{self, InferCtxt, SubregionOrigin};
 use rustc_middle::mir::{ClosureOutlivesSubject, ClosureRegionRequirements, ConstraintCategory};
-use rustc_middle::traits::query::NoSolution;
 use rustc_middle::traits::ObligationCause;
 use rustc_middle::ty::{self, GenericArgKind, Ty, TyCtxt, TypeFoldable, TypeVisitableExt};
 use rustc_span::{Span, DUMMY_SP};
-use rustc_trait_selection::solve::deeply_normalize;
-use rustc_trait_selection::traits::query::type_op::custom::CustomTypeOp;
-use rustc_trait_selection::traits::query::type_op::{TypeOp, TypeOpOutput};
+use rustc_trait_selection::traits::DeeplyNormalizeExt;
 
 use crate::{
     constraints::OutlivesConstraint,
@@ -279,31 +276,24 @@ fn normalize_and_add_type_outlives_constraints(
             ConstraintCategory<'tcx>,
         )>,
     ) -> Ty<'tcx> {
-        let result = CustomTypeOp::new(
-            |ocx| {
-                deeply_normalize(
-                    ocx.infcx.at(&ObligationCause::dummy_with_span(self.span), self.param_env),
-                    ty,
-                )
-                .map_err(|_| NoSolution)
-            },
-            "normalize type outlives obligation",
-        )
-        .fully_perform(self.infcx, self.span);
-
-        match result {
-            Ok(TypeOpOutput { output: ty, constraints, .. }) => {
-                if let Some(constraints) = constraints {
-                    assert!(
-                        constraints.member_constraints.is_empty(),
-                        "no member constraints expected from normalizing: {:#?}",
-                        constraints.member_constraints
-                    );
-                    next_outlives_predicates.extend(constraints.outlives.iter().copied());
-                }
+        let cause = ObligationCause::dummy_with_span(self.span);
+        match self.infcx.deeply_normalize_with_constraints(&cause, self.param_env, ty) {
+            Ok((ty, constraints)) => {
+                assert!(
+                    constraints.member_constraints.is_empty(),
+                    "no member constraints expected from normalizing: {:#?}",
+                    constraints.member_constraints
+                );
+                next_outlives_predicates.extend(constraints.outlives);
+                ty
+            }
+            Err(errors) => {
+                self.infcx.dcx().span_delayed_bug(
+                    self.span,
+                    format!("errors normalizing type outlives obligation: {errors:?}"),
+                );
                 ty
             }
-            Err(_) => ty,
         }
     }
 }
@@ -441,6 +441,12 @@ fn codegen_fn_body(fx: &mut FunctionCx<'_, '_, '_>, start_block: Block) {
                     )
                 });
             }
+            TerminatorKind::TailCall { .. } => {
+                fx.tcx.dcx().span_fatal(
+                    source_info.span,
+                    "cranelift doesn't support guaranteed tail calls.",
+                );
+            }
             TerminatorKind::InlineAsm {
                 template,
                 operands,
@@ -1398,6 +1398,19 @@ fn call(
         call
     }
 
+    fn tail_call(
+        &mut self,
+        _typ: Type<'gcc>,
+        _fn_attrs: Option<&CodegenFnAttrs>,
+        _fn_abi: &FnAbi<'tcx, Ty<'tcx>>,
+        _func: RValue<'gcc>,
+        _args: &[RValue<'gcc>],
+        _funclet: Option<&Funclet>,
+    ) {
+        // FIXME: use `gcc_jit_rvalue_set_bool_require_tail_call` once gccjit exposes it.
+        self.cx.tcx.dcx().fatal("rustc_codegen_gcc doesn't support guaranteed tail calls.")
+    }
+
     fn zext(&mut self, value: RValue<'gcc>, dest_typ: Type<'gcc>) -> RValue<'gcc> {
         // FIXME(antoyo): this does not zero-extend.
         if value.get_type().is_bool() && dest_typ.is_i8(&self.cx) {
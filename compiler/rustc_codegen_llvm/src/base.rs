@@ -26,7 +26,7 @@
 use rustc_data_structures::small_c_str::SmallCStr;
 use rustc_middle::dep_graph;
 use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrs;
-use rustc_middle::mir::mono::{Linkage, Visibility};
+use rustc_middle::mir::mono::{Linkage, MonoItem, Visibility};
 use rustc_middle::ty::TyCtxt;
 use rustc_session::config::DebugInfo;
 use rustc_span::symbol::Symbol;
@@ -84,13 +84,31 @@ fn module_codegen(tcx: TyCtxt<'_>, cgu_name: Symbol) -> ModuleCodegen<ModuleLlvm
         {
             let cx = CodegenCx::new(tcx, cgu, &llvm_module);
             let mono_items = cx.codegen_unit.items_in_deterministic_order(cx.tcx);
+            let alias_of = |mono_item: &MonoItem<'_>| match mono_item {
+                MonoItem::Fn(_) if cx.supports_aliases() => cx.codegen_unit.alias_of(mono_item),
+                _ => None,
+            };
             for &(mono_item, data) in &mono_items {
-                mono_item.predefine::<Builder<'_, '_, '_>>(&cx, data.linkage, data.visibility);
+                if alias_of(&mono_item).is_none() {
+                    mono_item.predefine::<Builder<'_, '_, '_>>(&cx, data.linkage, data.visibility);
+                }
+            }
+
+            // Items deduplicated by `-Zdedup-mono-items` become aliases of the
+            // definitions predefined above...
+            for &(mono_item, data) in &mono_items {
+                if let (MonoItem::Fn(instance), Some(MonoItem::Fn(aliasee))) =
+                    (mono_item, alias_of(&mono_item))
+                {
+                    cx.define_alias(instance, aliasee, data.linkage, data.visibility);
+                }
             }
 
             // ... and now that we have everything pre-defined, fill out those definitions.
             for &(mono_item, _) in &mono_items {
-                mono_item.define::<Builder<'_, '_, '_>>(&cx);
+                if alias_of(&mono_item).is_none() {
+                    mono_item.define::<Builder<'_, '_, '_>>(&cx);
+                }
             }
 
             // If this codegen unit contains the main function, also create the
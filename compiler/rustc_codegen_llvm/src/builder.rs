@@ -22,7 +22,8 @@
 use rustc_middle::ty::{self, Ty, TyCtxt};
 use rustc_span::Span;
 use rustc_symbol_mangling::typeid::{kcfi_typeid_for_fnabi, typeid_for_fnabi, TypeIdOptions};
-use rustc_target::abi::{self, call::FnAbi, Align, Size, WrappingRange};
+use rustc_target::abi::call::{FnAbi, PassMode};
+use rustc_target::abi::{self, Align, Size, WrappingRange};
 use rustc_target::spec::{HasTargetSpec, SanitizerSet, Target};
 use smallvec::SmallVec;
 use std::borrow::Cow;
@@ -1232,6 +1233,25 @@ fn call(
         call
     }
 
+    fn tail_call(
+        &mut self,
+        llty: &'ll Type,
+        fn_attrs: Option<&CodegenFnAttrs>,
+        fn_abi: &FnAbi<'tcx, Ty<'tcx>>,
+        llfn: &'ll Value,
+        args: &[&'ll Value],
+        funclet: Option<&Funclet<'ll>>,
+    ) {
+        let call = self.call(llty, fn_attrs, Some(fn_abi), llfn, args, funclet);
+        unsafe { llvm::LLVMRustSetTailCallKind(call, llvm::TailCallKind::MustTail) };
+
+        // A `musttail` call must be immediately followed by a return of its result.
+        match &fn_abi.ret.mode {
+            PassMode::Ignore | PassMode::Indirect { .. } => self.ret_void(),
+            PassMode::Direct(_) | PassMode::Pair(..) | PassMode::Cast { .. } => self.ret(call),
+        }
+    }
+
     fn zext(&mut self, val: &'ll Value, dest_ty: &'ll Type) -> &'ll Value {
         unsafe { llvm::LLVMBuildZExt(self.llbuilder, val, dest_ty, UNNAMED) }
     }
@@ -1463,6 +1483,106 @@ fn call_lifetime_intrinsic(&mut self, intrinsic: &str, ptr: &'ll Value, size: Si
         self.call_intrinsic(intrinsic, &[self.cx.const_u64(size), ptr]);
     }
 
+    /// Emits `llvm.instrprof.mcdc.parameters`, and allocates the condition
+    /// bitmap of the function, initialized to 0.
+    pub(crate) fn mcdc_parameters(
+        &mut self,
+        fn_name: &'ll Value,
+        hash: &'ll Value,
+        bitmap_bytes: &'ll Value,
+    ) -> &'ll Value {
+        debug!("mcdc_parameters() with args ({:?}, {:?}, {:?})", fn_name, hash, bitmap_bytes);
+
+        let llfn = unsafe { llvm::LLVMRustGetInstrProfMCDCParametersIntrinsic(self.cx().llmod) };
+        let arg_tys = &[self.cx.type_ptr(), self.cx.type_i64(), self.cx.type_i32()];
+        self.call_instrprof_intrinsic(llfn, arg_tys, &[fn_name, hash, bitmap_bytes]);
+
+        let align = self.tcx.data_layout.i32_align.abi;
+        let cond_bitmap = self.alloca(self.cx.type_i32(), align);
+        self.store(self.const_i32(0), cond_bitmap, align);
+        cond_bitmap
+    }
+
+    /// Emits `llvm.instrprof.mcdc.tvbitmap.update`, which records the test vector
+    /// held by the condition bitmap, and resets the condition bitmap.
+    pub(crate) fn mcdc_tvbitmap_update(
+        &mut self,
+        fn_name: &'ll Value,
+        hash: &'ll Value,
+        bitmap_bytes: &'ll Value,
+        bitmap_index: &'ll Value,
+        cond_bitmap: &'ll Value,
+    ) {
+        debug!(
+            "mcdc_tvbitmap_update() with args ({:?}, {:?}, {:?}, {:?}, {:?})",
+            fn_name, hash, bitmap_bytes, bitmap_index, cond_bitmap
+        );
+
+        let llfn =
+            unsafe { llvm::LLVMRustGetInstrProfMCDCTVBitmapUpdateIntrinsic(self.cx().llmod) };
+        let arg_tys = &[
+            self.cx.type_ptr(),
+            self.cx.type_i64(),
+            self.cx.type_i32(),
+            self.cx.type_i32(),
+            self.cx.type_ptr(),
+        ];
+        let args = &[fn_name, hash, bitmap_bytes, bitmap_index, cond_bitmap];
+        self.call_instrprof_intrinsic(llfn, arg_tys, args);
+
+        let align = self.tcx.data_layout.i32_align.abi;
+        self.store(self.const_i32(0), cond_bitmap, align);
+    }
+
+    /// Emits `llvm.instrprof.mcdc.condbitmap.update`, which records the value of
+    /// a condition in the condition bitmap.
+    pub(crate) fn mcdc_condbitmap_update(
+        &mut self,
+        fn_name: &'ll Value,
+        hash: &'ll Value,
+        cond_loc: &'ll Value,
+        cond_bitmap: &'ll Value,
+        bool_value: &'ll Value,
+    ) {
+        debug!(
+            "mcdc_condbitmap_update() with args ({:?}, {:?}, {:?}, {:?}, {:?})",
+            fn_name, hash, cond_loc, cond_bitmap, bool_value
+        );
+
+        let llfn = unsafe { llvm::LLVMRustGetInstrProfMCDCCondBitmapIntrinsic(self.cx().llmod) };
+        let arg_tys = &[
+            self.cx.type_ptr(),
+            self.cx.type_i64(),
+            self.cx.type_i32(),
+            self.cx.type_ptr(),
+            self.cx.type_i1(),
+        ];
+        let args = &[fn_name, hash, cond_loc, cond_bitmap, bool_value];
+        self.call_instrprof_intrinsic(llfn, arg_tys, args);
+    }
+
+    fn call_instrprof_intrinsic(
+        &mut self,
+        llfn: &'ll Value,
+        arg_tys: &[&'ll Type],
+        args: &[&'ll Value],
+    ) {
+        let llty = self.cx.type_func(arg_tys, self.cx.type_void());
+        let args = self.check_call("call", llty, llfn, args);
+
+        unsafe {
+            let _ = llvm::LLVMRustBuildCall(
+                self.llbuilder,
+                llty,
+                llfn,
+                args.as_ptr() as *const &llvm::Value,
+                args.len() as c_uint,
+                [].as_ptr(),
+                0 as c_uint,
+            );
+        }
+    }
+
     pub(crate) fn phi(
         &mut self,
         ty: &'ll Type,
@@ -1017,7 +1017,8 @@ impl<'tcx> LayoutOfHelpers<'tcx> for CodegenCx<'_, 'tcx> {
     #[inline]
     fn handle_layout_err(&self, err: LayoutError<'tcx>, span: Span, ty: Ty<'tcx>) -> ! {
         if let LayoutError::SizeOverflow(_) | LayoutError::ReferencesError(_) = err {
-            self.tcx.dcx().emit_fatal(Spanned { span, node: err.into_diagnostic() })
+            let node = err.into_diagnostic_with_layout_table(self.tcx, self.param_env());
+            self.tcx.dcx().emit_fatal(Spanned { span, node })
         } else {
             self.tcx.dcx().emit_fatal(ssa_errors::FailedToGetLayout { span, ty, err })
         }
@@ -1,4 +1,6 @@
-use rustc_middle::mir::coverage::{CodeRegion, CounterId, CovTerm, ExpressionId, MappingKind};
+use rustc_middle::mir::coverage::{
+    CodeRegion, ConditionInfo, CounterId, CovTerm, DecisionInfo, ExpressionId, MappingKind,
+};
 
 /// Must match the layout of `LLVMRustCounterKind`.
 #[derive(Copy, Clone, Debug)]
@@ -99,6 +101,52 @@ pub enum RegionKind {
     /// associated with two counters, each representing the number of times the
     /// expression evaluates to true or false.
     BranchRegion = 4,
+
+    /// A DecisionRegion represents a top-level boolean expression and is
+    /// associated with a variable length bitmap index and condition number.
+    MCDCDecisionRegion = 5,
+
+    /// A Branch Region can be extended to include IDs to facilitate MC/DC.
+    MCDCBranchRegion = 6,
+}
+
+/// Corresponds to struct `llvm::coverage::CounterMappingRegion::MCDCParameters`
+/// of LLVM 18.
+///
+/// Must match the layout of `LLVMRustMCDCParameters`.
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(C)]
+pub struct MCDCParameters {
+    /// Byte index of the decision's test vector bitmap, for decision regions.
+    bitmap_idx: u32,
+    /// Number of conditions of the decision, for decision regions.
+    num_conditions: u32,
+    /// ID of the condition, and of the conditions evaluated after it depending
+    /// on its value, for MC/DC branch regions. 0 means "no condition".
+    id: u32,
+    true_id: u32,
+    false_id: u32,
+}
+
+impl From<ConditionInfo> for MCDCParameters {
+    fn from(info: ConditionInfo) -> Self {
+        Self {
+            id: info.condition_id.as_u32(),
+            true_id: info.true_next_id.as_u32(),
+            false_id: info.false_next_id.as_u32(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<DecisionInfo> for MCDCParameters {
+    fn from(info: DecisionInfo) -> Self {
+        Self {
+            bitmap_idx: info.bitmap_idx,
+            num_conditions: info.conditions_num as u32,
+            ..Default::default()
+        }
+    }
 }
 
 /// This struct provides LLVM's representation of a "CoverageMappingRegion", encoded into the
@@ -122,6 +170,10 @@ pub struct CounterMappingRegion {
     /// for the false branch of the region.
     false_counter: Counter,
 
+    /// If the `RegionKind` is an MC/DC region, this describes the decision or
+    /// the condition the region represents.
+    mcdc_params: MCDCParameters,
+
     /// An indirect reference to the source filename. In the LLVM Coverage Mapping Format, the
     /// file_id is an index into a function-specific `virtual_file_mapping` array of indexes
     /// that, in turn, are used to look up the filename for this region.
@@ -164,6 +216,35 @@ pub(crate) fn from_mapping(
                 end_line,
                 end_col,
             ),
+            MappingKind::Branch { true_term, false_term } => Self::branch_region(
+                Counter::from_term(true_term),
+                Counter::from_term(false_term),
+                local_file_id,
+                start_line,
+                start_col,
+                end_line,
+                end_col,
+            ),
+            MappingKind::MCDCBranch { true_term, false_term, condition_info } => {
+                Self::mcdc_branch_region(
+                    Counter::from_term(true_term),
+                    Counter::from_term(false_term),
+                    condition_info,
+                    local_file_id,
+                    start_line,
+                    start_col,
+                    end_line,
+                    end_col,
+                )
+            }
+            MappingKind::MCDCDecision(decision_info) => Self::decision_region(
+                decision_info,
+                local_file_id,
+                start_line,
+                start_col,
+                end_line,
+                end_col,
+            ),
         }
     }
 
@@ -178,6 +259,7 @@ pub(crate) fn code_region(
         Self {
             counter,
             false_counter: Counter::ZERO,
+            mcdc_params: MCDCParameters::default(),
             file_id,
             expanded_file_id: 0,
             start_line,
@@ -188,9 +270,6 @@ pub(crate) fn code_region(
         }
     }
 
-    // This function might be used in the future; the LLVM API is still evolving, as is coverage
-    // support.
-    #[allow(dead_code)]
     pub(crate) fn branch_region(
         counter: Counter,
         false_counter: Counter,
@@ -203,6 +282,7 @@ pub(crate) fn branch_region(
         Self {
             counter,
             false_counter,
+            mcdc_params: MCDCParameters::default(),
             file_id,
             expanded_file_id: 0,
             start_line,
@@ -213,6 +293,52 @@ pub(crate) fn branch_region(
         }
     }
 
+    pub(crate) fn mcdc_branch_region(
+        counter: Counter,
+        false_counter: Counter,
+        condition_info: ConditionInfo,
+        file_id: u32,
+        start_line: u32,
+        start_col: u32,
+        end_line: u32,
+        end_col: u32,
+    ) -> Self {
+        Self {
+            counter,
+            false_counter,
+            mcdc_params: MCDCParameters::from(condition_info),
+            file_id,
+            expanded_file_id: 0,
+            start_line,
+            start_col,
+            end_line,
+            end_col,
+            kind: RegionKind::MCDCBranchRegion,
+        }
+    }
+
+    pub(crate) fn decision_region(
+        decision_info: DecisionInfo,
+        file_id: u32,
+        start_line: u32,
+        start_col: u32,
+        end_line: u32,
+        end_col: u32,
+    ) -> Self {
+        Self {
+            counter: Counter::ZERO,
+            false_counter: Counter::ZERO,
+            mcdc_params: MCDCParameters::from(decision_info),
+            file_id,
+            expanded_file_id: 0,
+            start_line,
+            start_col,
+            end_line,
+            end_col,
+            kind: RegionKind::MCDCDecisionRegion,
+        }
+    }
+
     // This function might be used in the future; the LLVM API is still evolving, as is coverage
     // support.
     #[allow(dead_code)]
@@ -227,6 +353,7 @@ pub(crate) fn expansion_region(
         Self {
             counter: Counter::ZERO,
             false_counter: Counter::ZERO,
+            mcdc_params: MCDCParameters::default(),
             file_id,
             expanded_file_id,
             start_line,
@@ -250,6 +377,7 @@ pub(crate) fn skipped_region(
         Self {
             counter: Counter::ZERO,
             false_counter: Counter::ZERO,
+            mcdc_params: MCDCParameters::default(),
             file_id,
             expanded_file_id: 0,
             start_line,
@@ -274,6 +402,7 @@ pub(crate) fn gap_region(
         Self {
             counter,
             false_counter: Counter::ZERO,
+            mcdc_params: MCDCParameters::default(),
             file_id,
             expanded_file_id: 0,
             start_line,
@@ -23,7 +23,7 @@
 use back::owned_target_machine::OwnedTargetMachine;
 use back::write::{create_informational_target_machine, create_target_machine};
 
-use errors::ParseTargetMachineConfig;
+use errors::{McdcRequiresLlvm18, ParseTargetMachineConfig};
 pub use llvm_util::target_features;
 use rustc_ast::expand::allocator::AllocatorKind;
 use rustc_codegen_ssa::back::lto::{LtoModuleCodegen, SerializedModule, ThinModule};
@@ -266,6 +266,12 @@ fn locale_resource(&self) -> &'static str {
 
     fn init(&self, sess: &Session) {
         llvm_util::init(sess); // Make sure llvm is inited
+
+        // The MC/DC parameters of coverage mapping regions changed in LLVM 19.
+        let (llvm_version, _, _) = llvm_util::get_version();
+        if sess.instrument_coverage_mcdc() && llvm_version != 18 {
+            sess.dcx().emit_err(McdcRequiresLlvm18 { llvm_version });
+        }
     }
 
     fn provide(&self, providers: &mut Providers) {
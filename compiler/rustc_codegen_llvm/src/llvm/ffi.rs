@@ -881,6 +881,7 @@ pub fn LLVMStructTypeInContext<'a>(
 
     // Operations on array, pointer, and vector types (sequence types)
     pub fn LLVMPointerTypeInContext(C: &Context, AddressSpace: c_uint) -> &Type;
+    pub fn LLVMGetPointerAddressSpace(PointerTy: &Type) -> c_uint;
     pub fn LLVMVectorType(ElementType: &Type, ElementCount: c_uint) -> &Type;
 
     pub fn LLVMGetElementType(Ty: &Type) -> &Type;
@@ -970,6 +971,16 @@ pub fn LLVMConstInBoundsGEP2<'a>(
     pub fn LLVMGetAlignment(Global: &Value) -> c_uint;
     pub fn LLVMSetAlignment(Global: &Value, Bytes: c_uint);
     pub fn LLVMSetDLLStorageClass(V: &Value, C: DLLStorageClass);
+    pub fn LLVMGlobalGetValueType(Global: &Value) -> &Type;
+
+    // Operations on aliases
+    pub fn LLVMAddAlias2<'a>(
+        M: &'a Module,
+        ValueTy: &'a Type,
+        AddrSpace: c_uint,
+        Aliasee: &'a Value,
+        Name: *const c_char,
+    ) -> &'a Value;
 
     // Operations on global variables
     pub fn LLVMIsAGlobalVariable(GlobalVar: &Value) -> Option<&Value>;
@@ -1621,6 +1632,9 @@ pub fn LLVMRustBuildInvoke<'a>(
 
     // Miscellaneous instructions
     pub fn LLVMRustGetInstrProfIncrementIntrinsic(M: &Module) -> &Value;
+    pub fn LLVMRustGetInstrProfMCDCParametersIntrinsic(M: &Module) -> &Value;
+    pub fn LLVMRustGetInstrProfMCDCTVBitmapUpdateIntrinsic(M: &Module) -> &Value;
+    pub fn LLVMRustGetInstrProfMCDCCondBitmapIntrinsic(M: &Module) -> &Value;
     pub fn LLVMRustBuildCall<'a>(
         B: &Builder<'a>,
         Ty: &'a Type,
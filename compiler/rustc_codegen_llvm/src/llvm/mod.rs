@@ -205,6 +205,16 @@ pub fn add_global<'a>(llmod: &'a Module, ty: &'a Type, name: &str) -> &'a Value
     unsafe { LLVMAddGlobal(llmod, ty, name_cstr.as_ptr()) }
 }
 
+/// Adds an alias named `name` for the global `aliasee`.
+pub fn add_alias<'a>(llmod: &'a Module, aliasee: &'a Value, name: &str) -> &'a Value {
+    let name_cstr = CString::new(name).expect("unexpected CString error");
+    unsafe {
+        let ty = LLVMGlobalGetValueType(aliasee);
+        let address_space = LLVMGetPointerAddressSpace(LLVMTypeOf(aliasee));
+        LLVMAddAlias2(llmod, ty, address_space, aliasee, name_cstr.as_ptr())
+    }
+}
+
 pub fn set_initializer(llglobal: &Value, constant_val: &Value) {
     unsafe {
         LLVMSetInitializer(llglobal, constant_val);
@@ -90,6 +90,46 @@ fn predefine_fn(
     }
 }
 
+impl<'tcx> CodegenCx<'_, 'tcx> {
+    /// Whether this module can emit items of `-Zdedup-mono-items` as aliases.
+    /// Otherwise they are codegened like any other item.
+    pub(crate) fn supports_aliases(&self) -> bool {
+        let target = &self.tcx.sess.target;
+        !target.is_like_aix && !target.is_like_wasm
+    }
+
+    /// Defines `instance` as an alias of `aliasee`, which must have been
+    /// predefined in this module already.
+    pub(crate) fn define_alias(
+        &self,
+        instance: Instance<'tcx>,
+        aliasee: Instance<'tcx>,
+        linkage: Linkage,
+        visibility: Visibility,
+    ) {
+        let symbol_name = self.tcx.symbol_name(instance).name;
+        if self.get_declared_value(symbol_name).is_some() {
+            self.sess().dcx().emit_fatal(SymbolAlreadyDefined {
+                span: self.tcx.def_span(instance.def_id()),
+                symbol_name,
+            });
+        }
+
+        let llaliasee = self.instances.borrow()[&aliasee];
+        let llalias = llvm::add_alias(self.llmod, llaliasee, symbol_name);
+
+        unsafe {
+            llvm::LLVMRustSetLinkage(llalias, base::linkage_to_llvm(linkage));
+            llvm::LLVMRustSetVisibility(llalias, base::visibility_to_llvm(visibility));
+            if self.should_assume_dso_local(llalias, false) {
+                llvm::LLVMRustSetDSOLocal(llalias, true);
+            }
+        }
+
+        self.instances.borrow_mut().insert(instance, llalias);
+    }
+}
+
 impl CodegenCx<'_, '_> {
     /// Whether a definition or declaration can be assumed to be local to a group of
     /// libraries that form a single DSO or executable.
@@ -327,6 +327,11 @@ codegen_ssa_unsupported_arch = unsupported arch `{$arch}` for os `{$os}`
 
 codegen_ssa_unsupported_link_self_contained = option `-C link-self-contained` is not supported on this target
 
+codegen_ssa_unsupported_tail_call_argument = cannot guarantee a tail call passing `{$ty}` as an argument
+    .note = on this target, this argument is passed in a way that cannot be forwarded to the callee of a tail call
+
+codegen_ssa_unsupported_tail_call_target = guaranteed tail calls are not supported on the `{$arch}` target
+
 codegen_ssa_use_cargo_directive = use the `cargo:rustc-link-lib` directive to specify the native libraries to link with Cargo (see https://doc.rust-lang.org/cargo/reference/build-scripts.html#rustc-link-lib)
 
 codegen_ssa_version_script_write_failure = failed to write version script: {$error}
@@ -358,6 +358,15 @@ pub fn wants_new_eh_instructions(sess: &Session) -> bool {
     wants_wasm_eh(sess) || wants_msvc_seh(sess)
 }
 
+/// Returns `true` if calls can be guaranteed to reuse the stack frame of the caller on this
+/// session's target, as required by `become`.
+pub fn supports_tail_calls(sess: &Session) -> bool {
+    matches!(
+        &*sess.target.arch,
+        "x86" | "x86_64" | "aarch64" | "arm" | "riscv32" | "riscv64" | "loongarch64" | "s390x"
+    )
+}
+
 pub fn memcpy_ty<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
     bx: &mut Bx,
     dst: Bx::Value,
@@ -1039,3 +1039,20 @@ pub struct FailedToGetLayout<'tcx> {
 pub struct ErrorCreatingRemarkDir {
     pub error: std::io::Error,
 }
+
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_unsupported_tail_call_target)]
+pub struct UnsupportedTailCallTarget<'a> {
+    #[primary_span]
+    pub span: Span,
+    pub arch: &'a str,
+}
+
+#[derive(Diagnostic)]
+#[diag(codegen_ssa_unsupported_tail_call_argument)]
+#[note]
+pub struct UnsupportedTailCallArgument<'tcx> {
+    #[primary_span]
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+}
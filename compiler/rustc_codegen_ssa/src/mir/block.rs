@@ -5,6 +5,7 @@
 
 use crate::base;
 use crate::common::{self, IntPredicate};
+use crate::errors;
 use crate::meth;
 use crate::traits::*;
 use crate::MemFlags;
@@ -23,6 +24,7 @@
 use rustc_target::spec::abi::Abi;
 
 use std::cmp;
+use std::iter;
 
 // Indicates if we are in the middle of merging a BB's successor into it. This
 // can happen when BB jumps directly to its successor and the successor has no
@@ -33,6 +35,13 @@ enum MergingSucc {
     True,
 }
 
+/// Whether a call returns to the current function, or replaces it as a tail call.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum CallKind {
+    Normal,
+    Tail,
+}
+
 /// Used by `FunctionCx::codegen_terminator` for emitting common patterns
 /// e.g., creating a basic block, calling a function, etc.
 struct TerminatorCodegenHelper<'tcx> {
@@ -243,6 +252,28 @@ fn do_call<Bx: BuilderMethods<'a, 'tcx>>(
         }
     }
 
+    /// Call `fn_ptr` of `fn_abi` with the arguments `llargs` as a tail call, which returns
+    /// directly to the caller of the current function.
+    fn do_tail_call<Bx: BuilderMethods<'a, 'tcx>>(
+        &self,
+        fx: &mut FunctionCx<'a, 'tcx, Bx>,
+        bx: &mut Bx,
+        fn_abi: &'tcx FnAbi<'tcx, Ty<'tcx>>,
+        fn_ptr: Bx::Value,
+        llargs: &[Bx::Value],
+    ) -> MergingSucc {
+        let fn_ty = bx.fn_decl_backend_type(fn_abi);
+
+        let fn_attrs = if bx.tcx().def_kind(fx.instance.def_id()).has_codegen_attrs() {
+            Some(bx.tcx().codegen_fn_attrs(fx.instance.def_id()))
+        } else {
+            None
+        };
+
+        bx.tail_call(fn_ty, fn_attrs, fn_abi, fn_ptr, llargs, self.funclet(fx));
+        MergingSucc::False
+    }
+
     /// Generates inline assembly with optional `destination` and `unwind`.
     fn do_inlineasm<Bx: BuilderMethods<'a, 'tcx>>(
         &self,
@@ -748,10 +779,16 @@ fn codegen_call_terminator(
         unwind: mir::UnwindAction,
         fn_span: Span,
         mergeable_succ: bool,
+        kind: CallKind,
     ) -> MergingSucc {
         let source_info = terminator.source_info;
         let span = source_info.span;
 
+        if kind == CallKind::Tail && !base::supports_tail_calls(self.cx.sess()) {
+            let arch = &*self.cx.sess().target.arch;
+            bx.tcx().dcx().emit_fatal(errors::UnsupportedTailCallTarget { span, arch });
+        }
+
         // Create the callee. This is a fn ptr or zero-sized and hence a kind of scalar.
         let callee = self.codegen_operand(bx, func);
 
@@ -775,6 +812,10 @@ fn codegen_call_terminator(
 
         if let Some(ty::InstanceDef::DropGlue(_, None)) = def {
             // Empty drop glue; a no-op.
+            if kind == CallKind::Tail {
+                self.codegen_return_terminator(bx);
+                return MergingSucc::False;
+            }
             let target = target.unwrap();
             return helper.funclet_br(self, bx, target, mergeable_succ);
         }
@@ -790,6 +831,11 @@ fn codegen_call_terminator(
             Some(ty::InstanceDef::Intrinsic(def_id)) => Some(bx.tcx().intrinsic(def_id).unwrap()),
             _ => None,
         };
+        if kind == CallKind::Tail
+            && let Some(intrinsic) = intrinsic
+        {
+            span_bug!(span, "tail call to intrinsic `{intrinsic}`");
+        }
 
         let extra_args = &args[sig.inputs().skip_binder().len()..];
         let extra_args = bx.tcx().mk_type_list_from_iter(extra_args.iter().map(|op_arg| {
@@ -905,6 +951,14 @@ fn codegen_call_terminator(
         let destination = target.as_ref().map(|&target| {
             (self.make_return_dest(bx, destination, &fn_abi.ret, &mut llargs, false, true), target)
         });
+        if kind == CallKind::Tail && fn_abi.ret.is_indirect() {
+            // The callee has the same signature as this function, so it can write its return
+            // value to where our caller expects ours.
+            match self.locals[mir::RETURN_PLACE] {
+                LocalRef::Place(ret) => llargs.push(ret.llval),
+                _ => bug!("indirect return place must be a place"),
+            }
+        }
 
         // Split the rust-call tupled arguments off.
         let (first_args, untuple) = if abi == Abi::RustCall && !args.is_empty() {
@@ -915,6 +969,8 @@ fn codegen_call_terminator(
         };
 
         let mut copied_constant_arguments = vec![];
+        // The arguments of a tail call that are passed by reference, with their index in `llargs`.
+        let mut tail_call_indirect_args = vec![];
         'make_args: for (i, arg) in first_args.iter().enumerate() {
             let mut op = self.codegen_operand(bx, &arg.node);
 
@@ -1006,6 +1062,21 @@ fn codegen_call_terminator(
                 _ => {}
             }
 
+            if kind == CallKind::Tail {
+                match fn_abi.args[i].mode {
+                    PassMode::Indirect { meta_attrs: None, on_stack: false, .. } => {
+                        tail_call_indirect_args.push((llargs.len(), op.layout));
+                    }
+                    PassMode::Indirect { .. } => {
+                        bx.tcx().dcx().emit_fatal(errors::UnsupportedTailCallArgument {
+                            span: arg.span,
+                            ty: op.layout.ty,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+
             self.codegen_argument(bx, op, &mut llargs, &fn_abi.args[i]);
         }
         let num_untupled = untuple.map(|tup| {
@@ -1046,6 +1117,28 @@ fn codegen_call_terminator(
             (_, Some(llfn)) => llfn,
             _ => span_bug!(span, "no instance or llfn for call"),
         };
+
+        if kind == CallKind::Tail {
+            // The callee reuses the stack frame of this function, so the arguments passed by
+            // reference must not point into it. As the callee has the same signature as this
+            // function, they are moved to the memory that our caller passed for our own arguments
+            // instead. They are all copied out first, as they may be read from that memory.
+            let mut temps = Vec::with_capacity(tail_call_indirect_args.len());
+            for &(llarg, layout) in &tail_call_indirect_args {
+                let tmp = PlaceRef::alloca(bx, layout);
+                let (src, align) = (llargs[llarg], layout.align.abi);
+                base::memcpy_ty(bx, tmp.llval, tmp.align, src, align, layout, MemFlags::empty());
+                temps.push(tmp);
+            }
+            for (&(llarg, layout), tmp) in iter::zip(&tail_call_indirect_args, temps) {
+                let param = bx.get_param(llarg);
+                let align = layout.align.abi;
+                base::memcpy_ty(bx, param, align, tmp.llval, tmp.align, layout, MemFlags::empty());
+                llargs[llarg] = param;
+            }
+            return helper.do_tail_call(self, bx, fn_abi, fn_ptr, &llargs);
+        }
+
         helper.do_call(
             self,
             bx,
@@ -1273,7 +1366,22 @@ fn codegen_terminator(
                 unwind,
                 fn_span,
                 mergeable_succ(),
+                CallKind::Normal,
             ),
+            mir::TerminatorKind::TailCall { ref func, ref args, fn_span } => self
+                .codegen_call_terminator(
+                    helper,
+                    bx,
+                    terminator,
+                    func,
+                    args,
+                    mir::Place::return_place(),
+                    None,
+                    mir::UnwindAction::Continue,
+                    fn_span,
+                    mergeable_succ(),
+                    CallKind::Tail,
+                ),
             mir::TerminatorKind::CoroutineDrop | mir::TerminatorKind::Yield { .. } => {
                 bug!("coroutine ops in codegen")
             }
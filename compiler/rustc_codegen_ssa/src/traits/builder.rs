@@ -330,6 +330,17 @@ fn call(
         args: &[Self::Value],
         funclet: Option<&Self::Funclet>,
     ) -> Self::Value;
+    /// Emits a call that is guaranteed to reuse the stack frame of the current function, and
+    /// returns the result of the call from it.
+    fn tail_call(
+        &mut self,
+        llty: Self::Type,
+        fn_attrs: Option<&CodegenFnAttrs>,
+        fn_abi: &FnAbi<'tcx, Ty<'tcx>>,
+        llfn: Self::Value,
+        args: &[Self::Value],
+        funclet: Option<&Self::Funclet>,
+    );
     fn zext(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value;
 
     fn apply_attrs_to_cleanup_callsite(&mut self, llret: Self::Value);
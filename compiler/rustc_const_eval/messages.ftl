@@ -41,6 +41,10 @@ const_eval_const_context = {$kind ->
     *[other] {""}
 }
 
+const_eval_const_eval_select_mismatch =
+    `{$const_fn}` and `{$rt_fn}` return different values for the same arguments
+    .note = `const_eval_select` uses `{$const_fn}` at compile-time, which returns `{$const_value}`, but the runtime implementation `{$rt_fn}` returns `{$rt_value}`
+
 const_eval_copy_nonoverlapping_overlapping =
     `copy_nonoverlapping` called on overlapping ranges
 
@@ -215,14 +219,24 @@ const_eval_memory_access_test = memory access failed
 const_eval_memory_exhausted =
     tried to allocate more memory than available to compiler
 
+const_eval_memory_limit_reached =
+    allocating {$requested} more bytes exceeds the limit of {$limit} bytes set by `-Z const-eval-memory-limit`
+
 const_eval_modified_global =
     modifying a static's initial value from another static's initializer
 
 const_eval_mut_deref =
     mutation through a reference is not allowed in {const_eval_const_context}s
 
+const_eval_mutable_heap_ptr_in_final_help =
+    this pointer refers to a heap allocation made during evaluation, which can only be part of the final value through shared references, e.g. `&*Box::leak(b)`
+
 const_eval_mutable_ptr_in_final = encountered mutable pointer in final value of {const_eval_intern_kind}
 
+const_eval_nan_result =
+    this floating-point operation produces a NaN during const evaluation
+    .note = the sign and payload of the NaN may be different when the operation is evaluated at runtime
+
 const_eval_non_const_fmt_macro_call =
     cannot call non-const formatting macro in {const_eval_const_context}s
 
@@ -327,6 +341,9 @@ const_eval_size_overflow =
 const_eval_stack_frame_limit_reached =
     reached the configured maximum number of stack frames
 
+const_eval_step_limit_reached =
+    reached the limit of {$limit} evaluation steps set by `-Z const-eval-step-limit`
+
 const_eval_thread_local_access =
     thread-local statics cannot be accessed at compile-time
 
@@ -377,6 +394,9 @@ const_eval_unallowed_mutable_refs =
 const_eval_unallowed_op_in_const_context =
     {$msg}
 
+const_eval_unallowed_tail_call =
+    tail calls are not allowed in {const_eval_const_context}s
+
 const_eval_unavailable_target_features_for_fn =
     calling a function that requires unavailable target features: {$unavailable_feats}
 
@@ -396,7 +416,6 @@ const_eval_unreachable_unwind =
 
 const_eval_unsigned_offset_from_overflow =
     `ptr_offset_from_unsigned` called when first pointer has smaller offset than second: {$a_offset} < {$b_offset}
-const_eval_unsized_local = unsized locals are not supported
 const_eval_unstable_const_fn = `{$def_path}` is not yet stable as a const fn
 
 const_eval_unstable_in_stable =
@@ -466,6 +485,8 @@ const_eval_validation_pointer_as_int = {$front_matter}: encountered a pointer, b
 const_eval_validation_ptr_out_of_range = {$front_matter}: encountered a pointer, but expected something that cannot possibly fail to be {$in_range}
 const_eval_validation_ref_to_static = {$front_matter}: encountered a reference pointing to a static variable in a constant
 const_eval_validation_ref_to_uninhabited = {$front_matter}: encountered a reference pointing to uninhabited type {$ty}
+const_eval_validation_report_human = the invalid value is stored in {$alloc} at offset {$offset} (size: {$size}):{$bytes}
+const_eval_validation_report_json = validation report: {$report}
 const_eval_validation_unaligned_box = {$front_matter}: encountered an unaligned box (required {$required_bytes} byte alignment but found {$found_bytes})
 const_eval_validation_unaligned_ref = {$front_matter}: encountered an unaligned reference (required {$required_bytes} byte alignment but found {$found_bytes})
 const_eval_validation_uninhabited_enum_variant = {$front_matter}: encountered an uninhabited enum variant
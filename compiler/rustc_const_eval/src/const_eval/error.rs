@@ -8,6 +8,7 @@
 use rustc_middle::query::TyCtxtAt;
 use rustc_middle::ty::TyCtxt;
 use rustc_middle::ty::{layout::LayoutError, ConstInt};
+use rustc_session::config::ConstEvalBacktrace;
 use rustc_span::{Span, Symbol, DUMMY_SP};
 
 use super::{CompileTimeInterpreter, InterpCx};
@@ -69,9 +70,20 @@ pub fn get_span_and_frames<'tcx, 'mir>(
         InterpCx::<CompileTimeInterpreter<'mir, 'tcx>>::generate_stacktrace_from_stack(
             &machine.stack,
         );
+    let span = stacktrace
+        .iter()
+        .find(|frame| !frame.instance.def.requires_caller_location(*tcx))
+        .map(|f| f.span)
+        .unwrap_or(tcx.span);
+
+    // With `-Z const-eval-backtrace=full`, show every frame as is.
+    if tcx.sess.opts.unstable_opts.const_eval_backtrace == ConstEvalBacktrace::Full {
+        let frames = stacktrace.iter().map(|frame_info| frame_info.as_note(*tcx)).collect();
+        return (span, frames);
+    }
+
     // Filter out `requires_caller_location` frames.
     stacktrace.retain(|frame| !frame.instance.def.requires_caller_location(*tcx));
-    let span = stacktrace.first().map(|f| f.span).unwrap_or(tcx.span);
 
     let mut frames = Vec::new();
 
@@ -172,6 +184,10 @@ pub(super) fn lint<'tcx, 'mir, L>(
 ) where
     L: for<'a> rustc_errors::DecorateLint<'a, ()>,
 {
+    if machine.evaluating_shared_call {
+        machine.suppressed_lint.set(true);
+        return;
+    }
     let (span, frames) = get_span_and_frames(tcx, machine);
 
     tcx.emit_node_span_lint(
@@ -1,15 +1,20 @@
+use std::fmt::Write;
+
 use either::{Left, Right};
 
 use rustc_hir::def::DefKind;
-use rustc_middle::mir::interpret::{AllocId, ErrorHandled, InterpErrorInfo};
+use rustc_middle::mir::interpret::{
+    alloc_range, AllocId, ErrorHandled, InterpErrorInfo, UndefinedBehaviorInfo, ValidationErrorInfo,
+};
 use rustc_middle::mir::{self, ConstAlloc, ConstValue};
 use rustc_middle::traits::Reveal;
 use rustc_middle::ty::layout::LayoutOf;
 use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::{self, TyCtxt};
+use rustc_session::config::ConstEvalUbReport;
 use rustc_span::def_id::LocalDefId;
 use rustc_span::Span;
-use rustc_target::abi::{self, Abi};
+use rustc_target::abi::{self, Abi, Size};
 
 use super::{CanAccessMutGlobal, CompileTimeEvalContext, CompileTimeInterpreter};
 use crate::const_eval::CheckAlignment;
@@ -423,11 +428,103 @@ pub fn const_report_error<'mir, 'tcx>(
     let (size, align, _) = ecx.get_alloc_info(alloc_id);
     let raw_bytes = errors::RawBytesNote { size: size.bytes(), align: align.bytes(), bytes };
 
+    let validation_report = match (ecx.tcx.sess.opts.unstable_opts.const_eval_ub_report, &error) {
+        (
+            Some(format),
+            InterpError::UndefinedBehavior(UndefinedBehaviorInfo::ValidationError(info)),
+        ) => validation_report(ecx, format, info),
+        _ => None,
+    };
+
     crate::const_eval::report(
         *ecx.tcx,
         error,
         None,
         || crate::const_eval::get_span_and_frames(ecx.tcx, &ecx.machine),
-        move |span, frames| errors::UndefinedBehavior { span, ub_note, frames, raw_bytes },
+        move |span, frames| errors::UndefinedBehavior {
+            span,
+            ub_note,
+            frames,
+            raw_bytes,
+            validation_report,
+        },
     )
 }
+
+/// Describes the bytes around the invalid value of a validation error, for
+/// `-Zconst-eval-ub-report`. Returns `None` if the value does not live in memory.
+fn validation_report<'mir, 'tcx>(
+    ecx: &InterpCx<'mir, 'tcx, CompileTimeInterpreter<'mir, 'tcx>>,
+    format: ConstEvalUbReport,
+    info: &ValidationErrorInfo<'tcx>,
+) -> Option<errors::ValidationReport> {
+    const LINE: u64 = 16;
+
+    let (alloc_id, range) = info.location?;
+    let (alloc_size, _, _) = ecx.get_alloc_info(alloc_id);
+    // Show whole lines, including one line before and one line after the invalid value.
+    let last = range.end().bytes().saturating_sub(1).max(range.start.bytes());
+    let start = (range.start.bytes() / LINE).saturating_sub(1) * LINE;
+    let end = ((last / LINE + 2) * LINE).min(alloc_size.bytes());
+    let window = alloc_range(Size::from_bytes(start), Size::from_bytes(end - start));
+    let bytes = ecx.describe_alloc_bytes_for_diagnostics(alloc_id, window);
+    let is_invalid = |offset: u64| range.start.bytes() <= offset && offset < range.end().bytes();
+
+    Some(match format {
+        ConstEvalUbReport::Human => {
+            let width = format!("{end:x}").len().max(4);
+            let mut report = String::new();
+            for (line, line_start) in
+                bytes.chunks(LINE as usize).zip((start..).step_by(LINE as usize))
+            {
+                write!(report, "\n    0x{line_start:0width$x} │ {}", line.join(" ")).unwrap();
+                let markers = (line_start..line_start + line.len() as u64)
+                    .map(|offset| if is_invalid(offset) { "^^" } else { "  " })
+                    .collect::<Vec<_>>();
+                if markers.contains(&"^^") {
+                    let markers = markers.join(" ");
+                    write!(report, "\n    {:width$}   │ {}", "", markers.trim_end()).unwrap();
+                }
+            }
+            errors::ValidationReport::Human {
+                alloc: format!("{alloc_id:?}"),
+                offset: range.start.bytes(),
+                size: range.size.bytes(),
+                bytes: report,
+            }
+        }
+        ConstEvalUbReport::Json => {
+            let path = info.path.iter().map(|elem| json_string(elem)).collect::<Vec<_>>();
+            let bytes = bytes.iter().map(|byte| json_string(byte)).collect::<Vec<_>>();
+            let report = format!(
+                concat!(
+                    r#"{{"alloc":{},"offset":{},"size":{},"path":[{}],"#,
+                    r#""window":{{"offset":{},"bytes":[{}]}}}}"#,
+                ),
+                json_string(&format!("{alloc_id:?}")),
+                range.start.bytes(),
+                range.size.bytes(),
+                path.join(","),
+                start,
+                bytes.join(","),
+            );
+            errors::ValidationReport::Json { report }
+        }
+    })
+}
+
+/// Quotes `s` as a JSON string.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,246 @@
+//! Sharing the evaluation of `const fn` calls between constants.
+//!
+//! Many constants often call the same expensive `const fn` with the same arguments, e.g. to
+//! build lookup tables. If the arguments and the return value of such a call are plain data
+//! (integers, floats, `bool`s and `char`s, and arrays, tuples and structs or enums of those),
+//! the call cannot observe anything besides its arguments, and its result does not depend on
+//! where it lives in memory. Such calls are evaluated by the `eval_const_fn_call` query, which
+//! is keyed on the instance, the argument values and the `ParamEnv`, and persisted in the
+//! incremental cache, so that every constant making the same call shares its result. As this
+//! caches every such call, including the ones made by the functions called, it is only done
+//! with `-Z share-const-fn-calls`.
+//!
+//! Calls made while evaluating a shared call are evaluated in place. That way, a recursive
+//! `const fn` does not lead to nested queries, and in particular not to query cycles.
+//!
+//! Nothing is shared if `-Z const-eval-step-limit` or `-Z const-eval-memory-limit` is set, as
+//! those limits apply to the whole evaluation of a constant, including the calls it makes.
+//!
+//! The same query also lets us compare the two implementations passed to `const_eval_select`,
+//! if both of them are `const fn`s.
+
+use rustc_hir::def::DefKind;
+use rustc_middle::mir;
+use rustc_middle::ty::layout::{FnAbiOf, LayoutOf};
+use rustc_middle::ty::{self, Ty, TyCtxt, TypeVisitableExt};
+use rustc_session::lint::builtin::CONST_EVAL_SELECT_MISMATCH;
+use rustc_target::spec::abi::Abi;
+
+use super::valtrees::plain_data_to_valtree;
+use super::{
+    CanAccessMutGlobal, CheckAlignment, CompileTimeEvalContext, CompileTimeInterpreter,
+    VALTREE_MAX_NODES,
+};
+use crate::errors;
+use crate::interpret::{
+    FnArg, FnVal, InterpCx, InterpResult, MemoryKind, OpTy, PlaceTy, StackPopCleanup,
+};
+
+impl<'mir, 'tcx> CompileTimeEvalContext<'mir, 'tcx> {
+    /// Evaluates a call of `instance` through the `eval_const_fn_call` query, if all of its
+    /// arguments and its return value are plain data.
+    /// Returns `false` if the call has to be evaluated in place instead.
+    pub(super) fn try_shared_const_fn_call(
+        &mut self,
+        instance: ty::Instance<'tcx>,
+        args: &[FnArg<'tcx>],
+        dest: &PlaceTy<'tcx>,
+        ret: Option<mir::BasicBlock>,
+    ) -> InterpResult<'tcx, bool> {
+        if !self.tcx.sess.opts.unstable_opts.share_const_fn_calls || ret.is_none() {
+            return Ok(false);
+        }
+        let Some((ret_ty, result)) = self.eval_shared_const_fn_call(instance, args)? else {
+            return Ok(false);
+        };
+        if dest.layout.ty != ret_ty {
+            return Ok(false);
+        }
+        let result = self.tcx.valtree_to_const_val((ret_ty, result));
+        let result = self.const_val_to_op(result, ret_ty, Some(dest.layout))?;
+        self.copy_op(&result, dest)?;
+        self.return_to_block(ret)?;
+        Ok(true)
+    }
+
+    /// Evaluates a call of `instance` through the `eval_const_fn_call` query, and returns the
+    /// return type and the result. Returns `None` if the call cannot be shared, or failed.
+    fn eval_shared_const_fn_call(
+        &mut self,
+        instance: ty::Instance<'tcx>,
+        args: &[FnArg<'tcx>],
+    ) -> InterpResult<'tcx, Option<(Ty<'tcx>, ty::ValTree<'tcx>)>> {
+        let tcx = *self.tcx;
+        let ty::InstanceDef::Item(def_id) = instance.def else {
+            return Ok(None);
+        };
+        // A shared call is evaluated on a machine of its own, so its steps and allocations would
+        // not count towards the limits of the constant making the call.
+        let opts = &tcx.sess.opts.unstable_opts;
+        if opts.const_eval_step_limit.is_some() || opts.const_eval_memory_limit.is_some() {
+            return Ok(None);
+        }
+        if self.machine.evaluating_shared_call
+            || !matches!(tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+            || instance.def.requires_caller_location(tcx)
+            || instance.args.has_param()
+        {
+            return Ok(None);
+        }
+
+        let sig = instance.ty(tcx, self.param_env).fn_sig(tcx);
+        let sig = tcx.normalize_erasing_late_bound_regions(self.param_env, sig);
+        if sig.abi != Abi::Rust
+            || sig.c_variadic
+            || sig.inputs().len() != args.len()
+            || !self.is_plain_data(sig.output())
+            // Values this large would most likely have too many nodes for a valtree, so we would
+            // evaluate the call twice.
+            || self.layout_of(sig.output())?.size.bytes() > VALTREE_MAX_NODES as u64
+        {
+            return Ok(None);
+        }
+
+        let mut arg_valtrees = Vec::with_capacity(args.len());
+        for (arg, &ty) in args.iter().zip(sig.inputs()) {
+            let arg = self.copy_fn_arg(arg)?;
+            if arg.layout.ty != ty || !self.is_plain_data(ty) {
+                return Ok(None);
+            }
+            let Some(valtree) = plain_data_to_valtree(self, &arg)? else {
+                return Ok(None);
+            };
+            arg_valtrees.push(valtree);
+        }
+        let args = ty::ValTree::Branch(tcx.arena.alloc_from_iter(arg_valtrees));
+
+        let result = tcx.const_eval_fn_call(self.param_env, instance, args);
+        Ok(result.map(|result| (sig.output(), result)))
+    }
+
+    /// Evaluates a call of `const_eval_select` whose runtime implementation is a `const fn` as
+    /// well, by calling the compile-time implementation. If both implementations can be
+    /// evaluated on their own, we also compare their results, and lint if they differ.
+    pub(super) fn eval_const_eval_select(
+        &mut self,
+        args: &[OpTy<'tcx>],
+        dest: &PlaceTy<'tcx>,
+        target: Option<mir::BasicBlock>,
+        unwind: mir::UnwindAction,
+    ) -> InterpResult<'tcx> {
+        let tcx = *self.tcx;
+        let [tupled_args, called_in_const, called_at_rt] = args else {
+            bug!("`const_eval_select` takes three arguments");
+        };
+        let resolve = |f: &OpTy<'tcx>| {
+            let ty::FnDef(def_id, args) = *f.layout.ty.kind() else {
+                bug!("`const_eval_select` takes function items, not `{}`", f.layout.ty)
+            };
+            self.resolve(def_id, args)
+        };
+        let const_fn = resolve(called_in_const)?;
+        let rt_fn = resolve(called_at_rt)?;
+        let fn_args = (0..tupled_args.layout.fields.count())
+            .map(|i| Ok(FnArg::Copy(self.project_field(tupled_args, i)?)))
+            .collect::<InterpResult<'tcx, Vec<_>>>()?;
+
+        if self.machine.evaluating_shared_call {
+            // Give up, so that the constant making the call compares the implementations.
+            self.machine.suppressed_lint.set(true);
+            throw_unsup_format!("`const_eval_select` is only checked in place");
+        }
+        if let Some((ty, const_value)) = self.eval_shared_const_fn_call(const_fn, &fn_args)?
+            && let Some((_, rt_value)) = self.eval_shared_const_fn_call(rt_fn, &fn_args)?
+            && const_value != rt_value
+        {
+            super::lint(self.tcx, &self.machine, CONST_EVAL_SELECT_MISMATCH, |frames| {
+                errors::ConstEvalSelectMismatch {
+                    frames,
+                    const_fn: tcx.def_path_str(const_fn.def_id()),
+                    rt_fn: tcx.def_path_str(rt_fn.def_id()),
+                    const_value: ty::Const::new_value(tcx, const_value, ty).to_string(),
+                    rt_value: ty::Const::new_value(tcx, rt_value, ty).to_string(),
+                }
+            });
+        }
+
+        let abi = const_fn.ty(tcx, self.param_env).fn_sig(tcx).abi();
+        let fn_abi = self.fn_abi_of_instance(const_fn, ty::List::empty())?;
+        self.eval_fn_call(
+            FnVal::Instance(const_fn),
+            (abi, fn_abi),
+            &fn_args,
+            const_fn.def.requires_caller_location(tcx),
+            dest,
+            target,
+            unwind,
+        )
+    }
+
+    /// Whether values of `ty` only consist of plain data, i.e. do not contain any pointers,
+    /// references, unions or generic parameters.
+    fn is_plain_data(&self, ty: Ty<'tcx>) -> bool {
+        let tcx = *self.tcx;
+        match *ty.kind() {
+            ty::Bool | ty::Char | ty::Int(_) | ty::Uint(_) | ty::Float(_) => true,
+            ty::Array(elem_ty, _) => self.is_plain_data(elem_ty),
+            ty::Tuple(tys) => tys.iter().all(|ty| self.is_plain_data(ty)),
+            ty::Adt(def, args) if !def.is_union() => def.all_fields().all(|field| {
+                tcx.try_normalize_erasing_regions(self.param_env, field.ty(tcx, args))
+                    .is_ok_and(|ty| self.is_plain_data(ty))
+            }),
+            _ => false,
+        }
+    }
+}
+
+/// Evaluates a call of the `const fn` `instance`, where `args` is a branch holding the value of
+/// every argument. Returns `None` if the call fails. The caller then evaluates the call in
+/// place, which reports the error.
+pub(crate) fn eval_const_fn_call_provider<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    key: ty::ParamEnvAnd<'tcx, (ty::Instance<'tcx>, ty::ValTree<'tcx>)>,
+) -> Option<ty::ValTree<'tcx>> {
+    let (param_env, (instance, args)) = key.into_parts();
+    let mut ecx = InterpCx::new(
+        tcx,
+        tcx.def_span(instance.def_id()),
+        param_env,
+        CompileTimeInterpreter::new(CanAccessMutGlobal::No, CheckAlignment::Error),
+    );
+    ecx.machine.evaluating_shared_call = true;
+    let result = eval_shared_call(&mut ecx, instance, args).ok().flatten();
+    if ecx.machine.suppressed_lint.get() { None } else { result }
+}
+
+fn eval_shared_call<'tcx>(
+    ecx: &mut CompileTimeEvalContext<'tcx, 'tcx>,
+    instance: ty::Instance<'tcx>,
+    args: ty::ValTree<'tcx>,
+) -> InterpResult<'tcx, Option<ty::ValTree<'tcx>>> {
+    let tcx = *ecx.tcx;
+    let body = ecx.load_mir(instance.def, None)?;
+    let sig = instance.ty(tcx, ecx.param_env).fn_sig(tcx);
+    let sig = tcx.normalize_erasing_late_bound_regions(ecx.param_env, sig);
+
+    let ret = ecx.allocate(ecx.layout_of(sig.output())?, MemoryKind::Stack)?;
+    ecx.push_stack_frame(
+        instance,
+        body,
+        &ret.clone().into(),
+        StackPopCleanup::Root { cleanup: false },
+    )?;
+    for ((local, &ty), &arg) in body.args_iter().zip(sig.inputs()).zip(args.unwrap_branch()) {
+        ecx.storage_live(local)?;
+        let dest = ecx.local_to_place(ecx.frame_idx(), local)?;
+        let arg = tcx.valtree_to_const_val((ty, arg));
+        let arg = ecx.const_val_to_op(arg, ty, Some(dest.layout))?;
+        ecx.copy_op(&arg, &dest)?;
+    }
+    ecx.storage_live_for_always_live_locals()?;
+
+    // The main interpreter loop.
+    while ecx.step()? {}
+
+    plain_data_to_valtree(ecx, &ret.into())
+}
@@ -0,0 +1,167 @@
+//! Rendering of panic messages with arguments during const eval.
+//!
+//! `const_format_args!` (which backs the panic macros) can build `fmt::Arguments` for `Display`
+//! and `Debug` arguments at compile time, but the formatting machinery in `core::fmt` cannot run
+//! in const eval. So instead of calling the formatters, we render the message here. This only
+//! supports arguments of primitive types, and no formatting options besides explicit positions.
+
+use rustc_apfloat::Float;
+use rustc_middle::ty::{self, FloatTy};
+use rustc_span::sym;
+
+use super::CompileTimeEvalContext;
+use crate::interpret::{CtfeProvenance, InterpResult, MPlaceTy, OpTy, Projectable};
+
+impl<'mir, 'tcx> CompileTimeEvalContext<'mir, 'tcx> {
+    /// Renders the message of the `fmt::Arguments` in `args`.
+    /// Returns `None` if the message does not have any arguments.
+    pub(super) fn format_panic_message(
+        &self,
+        args: &OpTy<'tcx>,
+    ) -> InterpResult<'tcx, Option<String>> {
+        let pieces = self.deref_pointer(&self.project_field_named(args, "pieces")?)?;
+        let arguments = self.deref_pointer(&self.project_field_named(args, "args")?)?;
+        if arguments.len(self)? == 0 {
+            return Ok(None);
+        }
+
+        // `fmt` is `None` if all arguments are used in order, without any formatting options.
+        let fmt = self.project_field_named(args, "fmt")?;
+        let fmt = self.project_downcast(&fmt, self.read_discriminant(&fmt)?)?;
+        let placeholders = if fmt.layout.fields.count() == 0 {
+            None
+        } else {
+            Some(self.deref_pointer(&self.project_field(&fmt, 0)?)?)
+        };
+
+        let num_placeholders = match &placeholders {
+            Some(placeholders) => placeholders.len(self)?,
+            None => arguments.len(self)?,
+        };
+        let mut msg = String::new();
+        for i in 0..num_placeholders {
+            let piece = self.deref_pointer(&self.project_index(&pieces, i)?)?;
+            msg.push_str(self.read_str(&piece)?);
+            let position = match &placeholders {
+                Some(placeholders) => {
+                    self.placeholder_position(&self.project_index(placeholders, i)?)?
+                }
+                None => i,
+            };
+            self.format_argument(&self.project_index(&arguments, position)?, &mut msg)?;
+        }
+        // There may be one more piece after the last argument.
+        if num_placeholders < pieces.len(self)? {
+            let piece = self.deref_pointer(&self.project_index(&pieces, num_placeholders)?)?;
+            msg.push_str(self.read_str(&piece)?);
+        }
+        Ok(Some(msg))
+    }
+
+    /// Returns the index of the argument a `fmt::rt::Placeholder` refers to, making sure it
+    /// does not ask for any formatting options we do not support.
+    fn placeholder_position(&self, placeholder: &MPlaceTy<'tcx>) -> InterpResult<'tcx, u64> {
+        let fill = self.read_scalar(&self.project_field_named(placeholder, "fill")?)?.to_char()?;
+        let flags = self.read_scalar(&self.project_field_named(placeholder, "flags")?)?.to_u32()?;
+        let mut is_default = fill == ' ' && flags == 0;
+        for (field, default) in
+            [("align", "Unknown"), ("precision", "Implied"), ("width", "Implied")]
+        {
+            let field = self.project_field_named(placeholder, field)?;
+            let variant = self.read_discriminant(&field)?;
+            let ty::Adt(adt, _) = field.layout.ty.kind() else {
+                bug!("unexpected type of a formatting option: {}", field.layout.ty)
+            };
+            is_default &= adt.variant(variant).name.as_str() == default;
+        }
+        if !is_default {
+            throw_unsup_format!("formatting options are not supported in const panic messages");
+        }
+        let position = self.project_field_named(placeholder, "position")?;
+        self.read_target_usize(&position)
+    }
+
+    /// Renders a single `fmt::rt::Argument` into `msg`.
+    fn format_argument(&self, arg: &MPlaceTy<'tcx>, msg: &mut String) -> InterpResult<'tcx> {
+        let tcx = *self.tcx;
+        let formatter = self.read_pointer(&self.project_field_named(arg, "formatter")?)?;
+        let formatter = self.get_ptr_fn(formatter)?.as_instance()?;
+
+        // The formatter is `<T as Display>::fmt` or `<T as Debug>::fmt`.
+        let trait_def_id = tcx
+            .trait_of_item(formatter.def_id())
+            .or_else(|| tcx.trait_id_of_impl(tcx.impl_of_method(formatter.def_id())?));
+        let debug = match trait_def_id {
+            Some(def_id) if tcx.is_diagnostic_item(sym::Debug, def_id) => true,
+            Some(def_id) if tcx.is_diagnostic_item(sym::Display, def_id) => false,
+            _ => throw_unsup_format!(
+                "formatting with `{formatter}` is not supported in const panic messages"
+            ),
+        };
+        let sig = formatter.ty(tcx, self.param_env).fn_sig(tcx);
+        let ty = tcx.instantiate_bound_regions_with_erased(sig).inputs()[0].builtin_deref(true);
+        let ty = ty.expect("formatters take their value by reference").ty;
+
+        // Both traits are implemented for references by forwarding to the referent.
+        let value = self.read_pointer(&self.project_field_named(arg, "value")?)?;
+        let mut value = self.ptr_to_mplace(value, self.layout_of(ty)?);
+        while value.layout.ty.is_ref() {
+            value = self.deref_pointer(&value)?;
+        }
+
+        match *value.layout.ty.kind() {
+            ty::Bool => msg.push_str(&self.read_scalar(&value)?.to_bool()?.to_string()),
+            ty::Char => {
+                let c = self.read_scalar(&value)?.to_char()?;
+                msg.push_str(&if debug { format!("{c:?}") } else { c.to_string() });
+            }
+            ty::Int(_) => {
+                let int = self.read_scalar(&value)?.to_int(value.layout.size)?;
+                msg.push_str(&int.to_string());
+            }
+            ty::Uint(_) => {
+                let uint = self.read_scalar(&value)?.to_uint(value.layout.size)?;
+                msg.push_str(&uint.to_string());
+            }
+            ty::Float(FloatTy::F32) => {
+                let f = self.read_scalar(&value)?.to_f32()?;
+                let f = f32::from_bits(f.to_bits().try_into().unwrap());
+                msg.push_str(&if debug { format!("{f:?}") } else { f.to_string() });
+            }
+            ty::Float(FloatTy::F64) => {
+                let f = self.read_scalar(&value)?.to_f64()?;
+                let f = f64::from_bits(f.to_bits().try_into().unwrap());
+                msg.push_str(&if debug { format!("{f:?}") } else { f.to_string() });
+            }
+            ty::Str => {
+                let s = self.read_str(&value)?;
+                if debug {
+                    msg.push_str(&format!("{s:?}"));
+                } else {
+                    msg.push_str(s);
+                }
+            }
+            _ => throw_unsup_format!(
+                "formatting values of type `{}` is not supported in const panic messages",
+                value.layout.ty
+            ),
+        }
+        Ok(())
+    }
+
+    /// Projects to the field of a `core::fmt` struct called `name`.
+    fn project_field_named<P: Projectable<'tcx, CtfeProvenance>>(
+        &self,
+        base: &P,
+        name: &str,
+    ) -> InterpResult<'tcx, P> {
+        let ty = base.layout().ty;
+        let ty::Adt(adt, _) = ty.kind() else { bug!("expected a struct, found `{ty}`") };
+        let Some(field) =
+            adt.non_enum_variant().fields.iter().position(|f| f.name.as_str() == name)
+        else {
+            bug!("no field `{name}` in `{ty}`")
+        };
+        self.project_field(base, field)
+    }
+}
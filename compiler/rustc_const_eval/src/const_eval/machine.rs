@@ -1,8 +1,10 @@
 use std::borrow::Borrow;
+use std::cell::Cell;
 use std::fmt;
 use std::hash::Hash;
 use std::ops::ControlFlow;
 
+use rustc_apfloat::{Float, FloatConvert};
 use rustc_ast::Mutability;
 use rustc_data_structures::fx::FxIndexMap;
 use rustc_data_structures::fx::IndexEntry;
@@ -14,7 +16,7 @@
 use rustc_middle::query::TyCtxtAt;
 use rustc_middle::ty;
 use rustc_middle::ty::layout::{FnAbiOf, TyAndLayout};
-use rustc_session::lint::builtin::WRITES_THROUGH_IMMUTABLE_POINTER;
+use rustc_session::lint::builtin::{CONST_EVAL_NAN_RESULT, WRITES_THROUGH_IMMUTABLE_POINTER};
 use rustc_span::symbol::{sym, Symbol};
 use rustc_span::Span;
 use rustc_target::abi::{Align, Size};
@@ -48,6 +50,10 @@ pub struct CompileTimeInterpreter<'mir, 'tcx> {
     /// Set to `usize::MAX` to never report anything.
     pub(super) num_evaluated_steps: usize,
 
+    /// The total size of the live allocations of the evaluation, checked against
+    /// `-Z const-eval-memory-limit`.
+    pub(super) live_bytes: u64,
+
     /// The virtual call stack.
     pub(super) stack: Vec<Frame<'mir, 'tcx>>,
 
@@ -61,6 +67,14 @@ pub struct CompileTimeInterpreter<'mir, 'tcx> {
 
     /// Used to prevent reads from a static's base allocation, as that may allow for self-initialization.
     pub(crate) static_root_alloc_id: Option<AllocId>,
+
+    /// Whether this machine evaluates a call for the `eval_const_fn_call` query. Nested calls
+    /// are then evaluated in place, so that recursive calls cannot form query cycles.
+    pub(super) evaluating_shared_call: bool,
+
+    /// Set if a lint was not emitted because of `evaluating_shared_call`. The shared evaluation
+    /// is then discarded, and the constant making the call evaluates it in place and lints.
+    pub(super) suppressed_lint: Cell<bool>,
 }
 
 #[derive(Copy, Clone)]
@@ -91,10 +105,13 @@ pub(crate) fn new(
     ) -> Self {
         CompileTimeInterpreter {
             num_evaluated_steps: 0,
+            live_bytes: 0,
             stack: Vec::new(),
             can_access_mut_global,
             check_alignment,
             static_root_alloc_id: None,
+            evaluating_shared_call: false,
+            suppressed_lint: Cell::new(false),
         }
     }
 }
@@ -241,6 +258,17 @@ fn hook_special_const_fn(
             let (file, line, col) = self.location_triple_for_span(span);
             return Err(ConstEvalErrKind::Panic { msg, file, line, col }.into());
         } else if Some(def_id) == self.tcx.lang_items().panic_fmt() {
+            // Messages with arguments cannot be formatted by `core::fmt` at compile time,
+            // so we render them ourselves.
+            let fn_args = self.copy_fn_args(args)?;
+            assert!(fn_args.len() == 1);
+            if let Some(msg) = self.format_panic_message(&fn_args[0])? {
+                let msg = Symbol::intern(&msg);
+                let span = self.find_closest_untracked_caller_location();
+                let (file, line, col) = self.location_triple_for_span(span);
+                return Err(ConstEvalErrKind::Panic { msg, file, line, col }.into());
+            }
+
             // For panic_fmt, call const_panic_fmt instead.
             let const_def_id = self.tcx.require_lang_item(LangItem::ConstPanicFmt, None);
             let new_instance = ty::Instance::resolve(
@@ -260,10 +288,86 @@ fn hook_special_const_fn(
                 ControlFlow::Continue(()) => return Ok(Some(instance)),
                 ControlFlow::Break(()) => return Ok(None),
             }
+        } else if Some(def_id) == self.tcx.lang_items().exchange_malloc_fn() {
+            // `Box` allocations go to the compile-time heap, like `const_allocate`.
+            let args = self.copy_fn_args(args)?;
+            assert_eq!(args.len(), 2);
+
+            let size = self.read_scalar(&args[0])?.to_target_usize(self)?;
+            let align = self.read_scalar(&args[1])?.to_target_usize(self)?;
+            let align = Self::heap_align(align, "exchange_malloc")?;
+            let ptr = self.allocate_ptr(
+                Size::from_bytes(size),
+                align,
+                interpret::MemoryKind::Machine(MemoryKind::Heap),
+            )?;
+            self.write_pointer(ptr, dest)?;
+            self.return_to_block(ret)?;
+            return Ok(None);
+        } else if self.is_box_global_drop(def_id, instance) {
+            // The contents have already been dropped, we only have to free the allocation.
+            let args = self.copy_fn_args(args)?;
+            assert_eq!(args.len(), 1);
+
+            let box_place = self.deref_pointer(&args[0])?;
+            let contents = self.deref_pointer(&box_place)?;
+            let (size, align) = self
+                .size_and_align_of_mplace(&contents)?
+                .expect("`Box` contents cannot be extern types in const eval");
+            self.heap_deallocate(contents.ptr(), size, align)?;
+            self.return_to_block(ret)?;
+            return Ok(None);
         }
         Ok(Some(instance))
     }
 
+    /// Whether `def_id` is the `Drop::drop` method of a `Box` using the global allocator.
+    fn is_box_global_drop(&self, def_id: DefId, instance: ty::Instance<'tcx>) -> bool {
+        let tcx = *self.tcx;
+        let Some(impl_def_id) = tcx.impl_of_method(def_id) else {
+            return false;
+        };
+        tcx.trait_id_of_impl(impl_def_id) == tcx.lang_items().drop_trait()
+            && tcx.type_of(impl_def_id).instantiate(tcx, instance.args).is_box_global(tcx)
+    }
+
+    /// Checks the alignment `name` was asked to use for a compile-time heap allocation.
+    fn heap_align(align: u64, name: &'static str) -> InterpResult<'tcx, Align> {
+        match Align::from_bytes(align) {
+            Ok(a) => Ok(a),
+            Err(err) => throw_ub_custom!(
+                fluent::const_eval_invalid_align_details,
+                name = name,
+                err_kind = err.diag_ident(),
+                align = err.align()
+            ),
+        }
+    }
+
+    /// Frees a compile-time heap allocation, unless it was leaked into the final value of
+    /// another const: that memory is interned and has to stay around.
+    fn heap_deallocate(
+        &mut self,
+        ptr: Pointer<Option<CtfeProvenance>>,
+        size: Size,
+        align: Align,
+    ) -> InterpResult<'tcx> {
+        let (alloc_id, _, _) = self.ptr_get_alloc_id(ptr)?;
+        let is_allocated_in_another_const = matches!(
+            self.tcx.try_get_global_alloc(alloc_id),
+            Some(interpret::GlobalAlloc::Memory(_))
+        );
+
+        if !is_allocated_in_another_const {
+            self.deallocate_ptr(
+                ptr,
+                Some((size, align)),
+                interpret::MemoryKind::Machine(MemoryKind::Heap),
+            )?;
+        }
+        Ok(())
+    }
+
     /// `align_offset(ptr, target_align)` needs special handling in const eval, because the pointer
     /// may not have an address.
     ///
@@ -442,6 +546,12 @@ fn find_mir_or_eval_fn(
             }
         }
 
+        // With `-Z share-const-fn-calls`, calls with plain-data arguments are shared with all
+        // constants making the same call.
+        if ecx.try_shared_const_fn_call(instance, args, dest, ret)? {
+            return Ok(None);
+        }
+
         // This is a const fn. Call it.
         // In case of replacement, we return the *original* instance to make backtraces work out
         // (and we hope this does not confuse the FnAbi checks too much).
@@ -461,7 +571,7 @@ fn call_intrinsic(
         args: &[OpTy<'tcx>],
         dest: &PlaceTy<'tcx, Self::Provenance>,
         target: Option<mir::BasicBlock>,
-        _unwind: mir::UnwindAction,
+        unwind: mir::UnwindAction,
     ) -> InterpResult<'tcx> {
         // Shared intrinsics.
         if ecx.emulate_intrinsic(instance, args, dest, target)? {
@@ -469,6 +579,12 @@ fn call_intrinsic(
         }
         let intrinsic_name = ecx.tcx.item_name(instance.def_id());
 
+        // Calls of `const_eval_select` are usually replaced by calls of the compile-time
+        // implementation in the MIR. They are only kept if both implementations are `const fn`s.
+        if intrinsic_name == sym::const_eval_select {
+            return ecx.eval_const_eval_select(args, dest, target, unwind);
+        }
+
         // CTFE-specific intrinsics.
         let Some(ret) = target else {
             throw_unsup_format!("intrinsic `{intrinsic_name}` is not supported at compile-time");
@@ -483,16 +599,7 @@ fn call_intrinsic(
             sym::const_allocate => {
                 let size = ecx.read_scalar(&args[0])?.to_target_usize(ecx)?;
                 let align = ecx.read_scalar(&args[1])?.to_target_usize(ecx)?;
-
-                let align = match Align::from_bytes(align) {
-                    Ok(a) => a,
-                    Err(err) => throw_ub_custom!(
-                        fluent::const_eval_invalid_align_details,
-                        name = "const_allocate",
-                        err_kind = err.diag_ident(),
-                        align = err.align()
-                    ),
-                };
+                let align = CompileTimeEvalContext::heap_align(align, "const_allocate")?;
 
                 let ptr = ecx.allocate_ptr(
                     Size::from_bytes(size),
@@ -507,31 +614,8 @@ fn call_intrinsic(
                 let align = ecx.read_scalar(&args[2])?.to_target_usize(ecx)?;
 
                 let size = Size::from_bytes(size);
-                let align = match Align::from_bytes(align) {
-                    Ok(a) => a,
-                    Err(err) => throw_ub_custom!(
-                        fluent::const_eval_invalid_align_details,
-                        name = "const_deallocate",
-                        err_kind = err.diag_ident(),
-                        align = err.align()
-                    ),
-                };
-
-                // If an allocation is created in an another const,
-                // we don't deallocate it.
-                let (alloc_id, _, _) = ecx.ptr_get_alloc_id(ptr)?;
-                let is_allocated_in_another_const = matches!(
-                    ecx.tcx.try_get_global_alloc(alloc_id),
-                    Some(interpret::GlobalAlloc::Memory(_))
-                );
-
-                if !is_allocated_in_another_const {
-                    ecx.deallocate_ptr(
-                        ptr,
-                        Some((size, align)),
-                        interpret::MemoryKind::Machine(MemoryKind::Heap),
-                    )?;
-                }
+                let align = CompileTimeEvalContext::heap_align(align, "const_deallocate")?;
+                ecx.heap_deallocate(ptr, size, align)?;
             }
             // The intrinsic represents whether the value is known to the optimizer (LLVM).
             // We're not doing any optimizations here, so there is no optimizer that could know the value.
@@ -589,10 +673,31 @@ fn binary_ptr_op(
         throw_unsup_format!("pointer arithmetic or comparison is not supported at compile-time");
     }
 
+    fn generate_nan<F1: Float + FloatConvert<F2>, F2: Float>(
+        ecx: &InterpCx<'mir, 'tcx, Self>,
+        _inputs: &[F1],
+    ) -> F2 {
+        // Const eval is deterministic: we always return the preferred NaN and ignore the payloads
+        // of NaN inputs. At runtime, the same operation may return a NaN with a different sign or
+        // propagate one of those payloads, so we let users opt into hearing about this.
+        super::lint(ecx.tcx, &ecx.machine, CONST_EVAL_NAN_RESULT, |frames| {
+            crate::errors::NanResult { frames }
+        });
+        F2::NAN
+    }
+
     fn increment_const_eval_counter(ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx> {
         // The step limit has already been hit in a previous call to `increment_const_eval_counter`.
 
         if let Some(new_steps) = ecx.machine.num_evaluated_steps.checked_add(1) {
+            // Unlike the lint below, `-Z const-eval-step-limit` is a hard error that cannot be
+            // allowed, so that builds can bound the time spent in const-eval.
+            if let Some(limit) = ecx.tcx.sess.opts.unstable_opts.const_eval_step_limit
+                && new_steps > limit
+            {
+                throw_exhaust!(StepLimitReached { limit });
+            }
+
             let (limit, start) = if ecx.tcx.sess.opts.unstable_opts.tiny_const_eval_limit {
                 (TINY_LINT_TERMINATOR_LIMIT, TINY_LINT_TERMINATOR_LIMIT)
             } else {
@@ -605,6 +710,11 @@ fn increment_const_eval_counter(ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpR
             // In case that lint got reduced, in particular for `--cap-lint` situations, we also
             // have a hard warning shown every now and then for really long executions.
             if new_steps == limit {
+                if ecx.machine.evaluating_shared_call {
+                    // Give up, so that the lint is emitted for the constant making the call.
+                    ecx.machine.suppressed_lint.set(true);
+                    throw_exhaust!(StepLimitReached { limit });
+                }
                 // By default, we stop after a million steps, but the user can disable this lint
                 // to be able to run until the heat death of the universe or power loss, whichever
                 // comes first.
@@ -730,6 +840,32 @@ fn retag_ptr_value(
         }
     }
 
+    fn before_memory_allocation(
+        ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        size: Size,
+        _kind: interpret::MemoryKind<Self::MemoryKind>,
+    ) -> InterpResult<'tcx> {
+        let live_bytes = ecx.machine.live_bytes.saturating_add(size.bytes());
+        if let Some(limit) = ecx.tcx.sess.opts.unstable_opts.const_eval_memory_limit
+            && live_bytes > limit
+        {
+            throw_exhaust!(MemoryLimitReached { limit, requested: size.bytes() });
+        }
+        ecx.machine.live_bytes = live_bytes;
+        Ok(())
+    }
+
+    fn before_memory_deallocation(
+        _tcx: TyCtxtAt<'tcx>,
+        machine: &mut Self,
+        _alloc_extra: &mut Self::AllocExtra,
+        _prov: (AllocId, Self::ProvenanceExtra),
+        range: AllocRange,
+    ) -> InterpResult<'tcx> {
+        machine.live_bytes = machine.live_bytes.saturating_sub(range.size.bytes());
+        Ok(())
+    }
+
     fn before_memory_write(
         tcx: TyCtxtAt<'tcx>,
         machine: &mut Self,
@@ -9,12 +9,15 @@
 
 mod error;
 mod eval_queries;
+mod fn_calls;
 mod fn_queries;
+mod format;
 mod machine;
 mod valtrees;
 
 pub use error::*;
 pub use eval_queries::*;
+pub(crate) use fn_calls::eval_const_fn_call_provider;
 pub use fn_queries::*;
 pub use machine::*;
 pub(crate) use valtrees::{eval_to_valtree, valtree_to_const_value};
@@ -1,3 +1,5 @@
+use either::{Left, Right};
+
 use rustc_middle::mir;
 use rustc_middle::mir::interpret::{EvalToValTreeResult, GlobalId};
 use rustc_middle::ty::layout::{LayoutCx, LayoutOf, TyAndLayout};
@@ -12,13 +14,13 @@
 use crate::errors::MaxNumNodesInConstErr;
 use crate::interpret::MPlaceTy;
 use crate::interpret::{
-    intern_const_alloc_recursive, ImmTy, Immediate, InternKind, MemPlaceMeta, MemoryKind, PlaceTy,
-    Projectable, Scalar,
+    intern_const_alloc_recursive, ImmTy, Immediate, InternKind, InterpResult, MemPlaceMeta,
+    MemoryKind, OpTy, PlaceTy, Projectable, Scalar,
 };
 
 #[instrument(skip(ecx), level = "debug")]
 fn branches<'tcx>(
-    ecx: &CompileTimeEvalContext<'tcx, 'tcx>,
+    ecx: &CompileTimeEvalContext<'_, 'tcx>,
     place: &MPlaceTy<'tcx>,
     n: usize,
     variant: Option<VariantIdx>,
@@ -56,7 +58,7 @@ fn branches<'tcx>(
 
 #[instrument(skip(ecx), level = "debug")]
 fn slice_branches<'tcx>(
-    ecx: &CompileTimeEvalContext<'tcx, 'tcx>,
+    ecx: &CompileTimeEvalContext<'_, 'tcx>,
     place: &MPlaceTy<'tcx>,
     num_nodes: &mut usize,
 ) -> ValTreeCreationResult<'tcx> {
@@ -74,7 +76,7 @@ fn slice_branches<'tcx>(
 
 #[instrument(skip(ecx), level = "debug")]
 fn const_to_valtree_inner<'tcx>(
-    ecx: &CompileTimeEvalContext<'tcx, 'tcx>,
+    ecx: &CompileTimeEvalContext<'_, 'tcx>,
     place: &MPlaceTy<'tcx>,
     num_nodes: &mut usize,
 ) -> ValTreeCreationResult<'tcx> {
@@ -93,9 +95,13 @@ fn const_to_valtree_inner<'tcx>(
         ty::Bool | ty::Int(_) | ty::Uint(_) | ty::Float(_) | ty::Char => {
             let val = ecx.read_immediate(place)?;
             let val = val.to_scalar();
+            // Pointers that were transmuted to integers have no integer value at compile-time.
+            let Ok(val) = val.try_to_int() else {
+                return Err(ValTreeCreationError::NonSupportedType);
+            };
             *num_nodes += 1;
 
-            Ok(ty::ValTree::Leaf(val.assert_int()))
+            Ok(ty::ValTree::Leaf(val))
         }
 
         ty::RawPtr(_) => {
@@ -171,6 +177,29 @@ fn const_to_valtree_inner<'tcx>(
     }
 }
 
+/// Converts a value of a type that only consists of plain data (see `is_plain_data`) to a valtree.
+/// Returns `None` if the value is not valid for its type, or has too many nodes.
+pub(super) fn plain_data_to_valtree<'tcx>(
+    ecx: &mut CompileTimeEvalContext<'_, 'tcx>,
+    op: &OpTy<'tcx>,
+) -> InterpResult<'tcx, Option<ty::ValTree<'tcx>>> {
+    // This rules out uninitialized bytes, which we could not read below.
+    if ecx.validate_operand(op).is_err() {
+        return Ok(None);
+    }
+    let mut num_nodes = 0;
+    match op.as_mplace_or_imm() {
+        Left(place) => Ok(const_to_valtree_inner(ecx, &place, &mut num_nodes).ok()),
+        Right(_) => {
+            let place = ecx.allocate(op.layout, MemoryKind::Stack)?;
+            ecx.copy_op(op, &place)?;
+            let valtree = const_to_valtree_inner(ecx, &place, &mut num_nodes).ok();
+            ecx.deallocate_ptr(place.ptr(), None, MemoryKind::Stack)?;
+            Ok(valtree)
+        }
+    }
+}
+
 /// Valtrees don't store the `MemPlaceMeta` that all dynamically sized values have in the interpreter.
 /// This function reconstructs it.
 fn reconstruct_place_meta<'tcx>(
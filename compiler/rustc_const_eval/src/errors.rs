@@ -1,8 +1,9 @@
 use std::borrow::Cow;
 
 use rustc_errors::{
-    codes::*, DiagCtxt, DiagnosticArgValue, DiagnosticBuilder, DiagnosticMessage,
-    EmissionGuarantee, IntoDiagnostic, Level,
+    codes::*, AddToDiagnostic, BacktraceFrame, DiagCtxt, Diagnostic, DiagnosticArgValue,
+    DiagnosticBuilder, DiagnosticMessage, EmissionGuarantee, IntoDiagnostic, Level,
+    SubdiagnosticMessageOp,
 };
 use rustc_hir::ConstContext;
 use rustc_macros::{Diagnostic, LintDiagnostic, Subdiagnostic};
@@ -33,6 +34,8 @@ pub(crate) struct MutablePtrInFinal {
     #[primary_span]
     pub span: Span,
     pub kind: InternKind,
+    #[help(const_eval_mutable_heap_ptr_in_final_help)]
+    pub to_heap: Option<()>,
 }
 
 #[derive(Diagnostic)]
@@ -196,6 +199,14 @@ pub(crate) struct UnallowedInlineAsm {
     pub kind: ConstContext,
 }
 
+#[derive(Diagnostic)]
+#[diag(const_eval_unallowed_tail_call, code = E0015)]
+pub(crate) struct UnallowedTailCall {
+    #[primary_span]
+    pub span: Span,
+    pub kind: ConstContext,
+}
+
 #[derive(Diagnostic)]
 #[diag(const_eval_interior_mutable_data_refer, code = E0492)]
 pub(crate) struct InteriorMutableDataRefer {
@@ -241,14 +252,28 @@ pub(crate) struct NonConstImplNote {
     pub span: Span,
 }
 
-#[derive(Subdiagnostic, PartialEq, Eq, Clone)]
-#[note(const_eval_frame_note)]
+#[derive(PartialEq, Eq, Clone)]
 pub struct FrameNote {
-    #[primary_span]
     pub span: Span,
     pub times: i32,
     pub where_: &'static str,
     pub instance: String,
+    pub promoted: bool,
+}
+
+impl AddToDiagnostic for FrameNote {
+    fn add_to_diagnostic_with<F: SubdiagnosticMessageOp>(self, diag: &mut Diagnostic, f: F) {
+        diag.arg("times", self.times);
+        diag.arg("where_", self.where_);
+        diag.arg("instance", self.instance.clone());
+        let msg = f(diag, crate::fluent_generated::const_eval_frame_note.into());
+        let frame = BacktraceFrame {
+            instance: (self.where_ == "instance").then_some(self.instance),
+            promoted: self.promoted,
+            times: self.times as usize,
+        };
+        diag.span_frame_note(self.span, msg, frame);
+    }
 }
 
 #[derive(Subdiagnostic)]
@@ -259,6 +284,15 @@ pub struct RawBytesNote {
     pub bytes: String,
 }
 
+/// The `-Zconst-eval-ub-report` description of the bytes a validation error was found in.
+#[derive(Subdiagnostic)]
+pub enum ValidationReport {
+    #[note(const_eval_validation_report_human)]
+    Human { alloc: String, offset: u64, size: u64, bytes: String },
+    #[note(const_eval_validation_report_json)]
+    Json { report: String },
+}
+
 // FIXME(fee1-dead) do not use stringly typed `ConstContext`
 
 #[derive(Diagnostic)]
@@ -403,6 +437,26 @@ pub struct WriteThroughImmutablePointer {
     pub frames: Vec<FrameNote>,
 }
 
+#[derive(LintDiagnostic)]
+#[diag(const_eval_const_eval_select_mismatch)]
+#[note]
+pub struct ConstEvalSelectMismatch {
+    #[subdiagnostic]
+    pub frames: Vec<FrameNote>,
+    pub const_fn: String,
+    pub rt_fn: String,
+    pub const_value: String,
+    pub rt_value: String,
+}
+
+#[derive(LintDiagnostic)]
+#[diag(const_eval_nan_result)]
+#[note]
+pub struct NanResult {
+    #[subdiagnostic]
+    pub frames: Vec<FrameNote>,
+}
+
 #[derive(Diagnostic)]
 #[diag(const_eval_nullary_intrinsic_fail)]
 pub struct NullaryIntrinsicError {
@@ -421,6 +475,8 @@ pub struct UndefinedBehavior {
     pub frames: Vec<FrameNote>,
     #[subdiagnostic]
     pub raw_bytes: RawBytesNote,
+    #[subdiagnostic]
+    pub validation_report: Option<ValidationReport>,
 }
 
 pub trait ReportErrorExt {
@@ -680,7 +736,8 @@ fn add_args<G: EmissionGuarantee>(self, err: &mut DiagnosticBuilder<'_, G>) {
             err.help(fluent::const_eval_ptr_as_bytes_2);
         }
 
-        let message = if let Some(path) = self.path {
+        let message = if !self.path.is_empty() {
+            let path = self.path.concat();
             err.dcx.eagerly_translate_to_string(
                 fluent::const_eval_validation_front_matter_invalid_value_with_path,
                 [("path".into(), DiagnosticArgValue::Str(path.into()))].iter().map(|(a, b)| (a, b)),
@@ -790,7 +847,6 @@ fn diagnostic_message(&self) -> DiagnosticMessage {
         use crate::fluent_generated::*;
         match self {
             UnsupportedOpInfo::Unsupported(s) => s.clone().into(),
-            UnsupportedOpInfo::UnsizedLocal => const_eval_unsized_local,
             UnsupportedOpInfo::OverwritePartialPointer(_) => const_eval_partial_pointer_overwrite,
             UnsupportedOpInfo::ReadPartialPointer(_) => const_eval_partial_pointer_copy,
             UnsupportedOpInfo::ReadPointerAsInt(_) => const_eval_read_pointer_as_int,
@@ -810,7 +866,7 @@ fn add_args<G: EmissionGuarantee>(self, diag: &mut DiagnosticBuilder<'_, G>) {
             // `ReadPointerAsInt(Some(info))` is never printed anyway, it only serves as an error to
             // be further processed by validity checking which then turns it into something nice to
             // print. So it's not worth the effort of having diagnostics that can print the `info`.
-            UnsizedLocal | Unsupported(_) | ReadPointerAsInt(_) => {}
+            Unsupported(_) | ReadPointerAsInt(_) => {}
             OverwritePartialPointer(ptr) | ReadPartialPointer(ptr) => {
                 diag.arg("ptr", ptr);
             }
@@ -886,9 +942,24 @@ fn diagnostic_message(&self) -> DiagnosticMessage {
             ResourceExhaustionInfo::StackFrameLimitReached => const_eval_stack_frame_limit_reached,
             ResourceExhaustionInfo::MemoryExhausted => const_eval_memory_exhausted,
             ResourceExhaustionInfo::AddressSpaceFull => const_eval_address_space_full,
+            ResourceExhaustionInfo::StepLimitReached { .. } => const_eval_step_limit_reached,
+            ResourceExhaustionInfo::MemoryLimitReached { .. } => const_eval_memory_limit_reached,
+        }
+    }
+    fn add_args<G: EmissionGuarantee>(self, diag: &mut DiagnosticBuilder<'_, G>) {
+        match self {
+            ResourceExhaustionInfo::StackFrameLimitReached
+            | ResourceExhaustionInfo::MemoryExhausted
+            | ResourceExhaustionInfo::AddressSpaceFull => {}
+            ResourceExhaustionInfo::StepLimitReached { limit } => {
+                diag.arg("limit", limit);
+            }
+            ResourceExhaustionInfo::MemoryLimitReached { limit, requested } => {
+                diag.arg("limit", limit);
+                diag.arg("requested", requested);
+            }
         }
     }
-    fn add_args<G: EmissionGuarantee>(self, _: &mut DiagnosticBuilder<'_, G>) {}
 }
 
 impl rustc_errors::IntoDiagnosticArg for InternKind {
@@ -140,6 +140,8 @@ pub struct Frame<'mir, 'tcx, Prov: Provenance = CtfeProvenance, Extra = ()> {
 pub struct FrameInfo<'tcx> {
     pub instance: ty::Instance<'tcx>,
     pub span: Span,
+    /// Whether this frame evaluates a promoted constant of `instance`.
+    pub promoted: bool,
 }
 
 #[derive(Clone, Copy, Eq, PartialEq, Debug)] // Miri debug-prints these
@@ -300,14 +302,21 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 impl<'tcx> FrameInfo<'tcx> {
     pub fn as_note(&self, tcx: TyCtxt<'tcx>) -> errors::FrameNote {
         let span = self.span;
+        let promoted = self.promoted;
         if tcx.def_key(self.instance.def_id()).disambiguated_data.data == DefPathData::Closure {
-            errors::FrameNote { where_: "closure", span, instance: String::new(), times: 0 }
+            errors::FrameNote {
+                where_: "closure",
+                span,
+                instance: String::new(),
+                times: 0,
+                promoted,
+            }
         } else {
             let instance = format!("{}", self.instance);
             // Note: this triggers a `must_produce_diag` state, which means that if we ever get
             // here we must emit a diagnostic. We should never display a `FrameInfo` unless we
             // actually want to emit a warning or error to the user.
-            errors::FrameNote { where_: "instance", span, instance, times: 0 }
+            errors::FrameNote { where_: "instance", span, instance, times: 0, promoted }
         }
     }
 }
@@ -583,7 +592,7 @@ pub(super) fn instantiate_from_frame_and_normalize_erasing_regions<
     }
 
     /// The `args` are assumed to already be in our interpreter "universe" (param_env).
-    pub(super) fn resolve(
+    pub(crate) fn resolve(
         &self,
         def: DefId,
         args: GenericArgsRef<'tcx>,
@@ -1056,11 +1065,14 @@ fn is_very_trivially_sized(ty: Ty<'_>) -> bool {
 
         let local_val = LocalValue::Live(if let Some(layout) = unsized_ {
             if !meta.has_meta() {
-                throw_unsup!(UnsizedLocal);
+                // An unsized local without metadata yet. It gets its memory when it is first
+                // assigned, see `eval_rvalue_into_place`. Until then, it stays uninit.
+                Operand::Immediate(Immediate::Uninit)
+            } else {
+                // Need to allocate some memory, since `Immediate::Uninit` cannot be unsized.
+                let dest_place = self.allocate_dyn(layout, MemoryKind::Stack, meta)?;
+                Operand::Indirect(*dest_place.mplace())
             }
-            // Need to allocate some memory, since `Immediate::Uninit` cannot be unsized.
-            let dest_place = self.allocate_dyn(layout, MemoryKind::Stack, meta)?;
-            Operand::Indirect(*dest_place.mplace())
         } else {
             assert!(!meta.has_meta()); // we're dropping the metadata
             // Just make this an efficient immediate.
@@ -1086,6 +1098,31 @@ pub fn storage_live(&mut self, local: mir::Local) -> InterpResult<'tcx> {
         self.storage_live_dyn(local, MemPlaceMeta::None)
     }
 
+    /// If `local` is an unsized local that has not been assigned yet, allocates its memory with
+    /// the metadata of `operand` and moves `operand` there. Returns whether that happened.
+    pub(super) fn allocate_unsized_local(
+        &mut self,
+        local: mir::Local,
+        operand: &mir::Operand<'tcx>,
+    ) -> InterpResult<'tcx, bool> {
+        let layout = self.layout_of_local(self.frame(), local, None)?;
+        if layout.is_sized()
+            || !matches!(self.frame().locals[local].access()?, Operand::Immediate(_))
+        {
+            return Ok(false);
+        }
+        // Unsized values are always in memory, and that is where we get the metadata from.
+        let op = self.eval_operand(operand, None)?;
+        let meta = op.assert_mem_place().meta();
+        let dest = self.allocate_dyn(layout, MemoryKind::Stack, meta)?;
+        let frame = self.frame_idx();
+        M::before_access_local_mut(self, frame, local)?;
+        M::after_local_allocated(self, frame, local, &dest)?;
+        *self.frame_mut().locals[local].access_mut()? = Operand::Indirect(*dest.mplace());
+        self.copy_op(&op, &dest)?;
+        Ok(true)
+    }
+
     pub fn storage_dead(&mut self, local: mir::Local) -> InterpResult<'tcx> {
         assert!(local != mir::RETURN_PLACE, "Cannot make return place dead");
         trace!("{:?} is now dead", local);
@@ -1180,7 +1217,7 @@ pub fn generate_stacktrace_from_stack(
                     let mir::SourceInfo { mut span, scope } = *frame.body.source_info(loc);
                     let mut scope_data = &frame.body.source_scopes[scope];
                     while let Some((instance, call_span)) = scope_data.inlined {
-                        frames.push(FrameInfo { span, instance });
+                        frames.push(FrameInfo { span, instance, promoted: false });
                         span = call_span;
                         scope_data = &frame.body.source_scopes[scope_data.parent_scope.unwrap()];
                     }
@@ -1188,7 +1225,8 @@ pub fn generate_stacktrace_from_stack(
                 }
                 Right(span) => span,
             };
-            frames.push(FrameInfo { span, instance: frame.instance });
+            let promoted = frame.body.source.promoted.is_some();
+            frames.push(FrameInfo { span, instance: frame.instance, promoted });
         }
         trace!("generate stacktrace: {:#?}", frames);
         frames
@@ -149,6 +149,8 @@ pub fn intern_const_alloc_recursive<
     // We want to first report "dangling" and then "mutable", so we need to delay reporting these
     // errors.
     let mut found_bad_mutable_pointer = false;
+    // Whether one of those bad mutable pointers points to a heap allocation, e.g. of a `Box`.
+    let mut found_bad_mutable_heap_pointer = false;
 
     // Keep interning as long as there are things to intern.
     // We show errors if there are dangling pointers, or mutable pointers in immutable contexts
@@ -199,6 +201,11 @@ pub fn intern_const_alloc_recursive<
             // promoteds as immutable.
             trace!("found bad mutable pointer");
             found_bad_mutable_pointer = true;
+            if let Some((kind, _)) = ecx.memory.alloc_map.get(&alloc_id)
+                && matches!(kind, MemoryKind::Machine(const_eval::MemoryKind::Heap))
+            {
+                found_bad_mutable_heap_pointer = true;
+            }
         }
         if ecx.tcx.try_get_global_alloc(alloc_id).is_some() {
             // Already interned.
@@ -221,10 +228,11 @@ pub fn intern_const_alloc_recursive<
         })?);
     }
     if found_bad_mutable_pointer {
-        return Err(ecx
-            .tcx
-            .dcx()
-            .emit_err(MutablePtrInFinal { span: ecx.tcx.span, kind: intern_kind }));
+        return Err(ecx.tcx.dcx().emit_err(MutablePtrInFinal {
+            span: ecx.tcx.span,
+            kind: intern_kind,
+            to_heap: found_bad_mutable_heap_pointer.then_some(()),
+        }));
     }
 
     Ok(())
@@ -426,6 +426,17 @@ fn before_memory_write(
         Ok(())
     }
 
+    /// Hook for performing extra checks before the interpreted program allocates `size` bytes
+    /// of memory.
+    #[inline(always)]
+    fn before_memory_allocation(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _size: Size,
+        _kind: MemoryKind<Self::MemoryKind>,
+    ) -> InterpResult<'tcx> {
+        Ok(())
+    }
+
     /// Hook for performing extra operations on a memory deallocation.
     #[inline(always)]
     fn before_memory_deallocation(
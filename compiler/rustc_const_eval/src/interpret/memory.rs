@@ -225,12 +225,13 @@ pub fn allocate_raw_ptr(
         alloc: Allocation,
         kind: MemoryKind<M::MemoryKind>,
     ) -> InterpResult<'tcx, Pointer<M::Provenance>> {
-        let id = self.tcx.reserve_alloc_id();
         debug_assert_ne!(
             Some(kind),
             M::GLOBAL_KIND.map(MemoryKind::Machine),
             "dynamically allocating global memory"
         );
+        M::before_memory_allocation(self, alloc.size(), kind)?;
+        let id = self.tcx.reserve_alloc_id();
         let alloc = M::adjust_allocation(self, id, Cow::Owned(alloc), Some(kind))?;
         self.memory.alloc_map.insert(id, (kind, alloc.into_owned()));
         M::adjust_alloc_base_pointer(self, Pointer::from(id))
@@ -871,6 +872,30 @@ pub fn print_alloc_bytes_for_diagnostics(&self, id: AllocId) -> String {
         bytes
     }
 
+    /// Describe each byte of `range` in the allocation: as two hex digits, as `__` if it is
+    /// uninitialized, or as `##` if it is part of a pointer.
+    pub fn describe_alloc_bytes_for_diagnostics(
+        &self,
+        id: AllocId,
+        range: AllocRange,
+    ) -> Vec<String> {
+        // Same as above, we want to be able to read all memory for diagnostics.
+        let alloc = self.get_alloc_raw(id).unwrap();
+        (range.start.bytes_usize()..range.end().bytes_usize())
+            .map(|i| {
+                let byte = alloc_range(Size::from_bytes(i), Size::from_bytes(1));
+                if !alloc.provenance().range_empty(byte, self) {
+                    "##".to_string()
+                } else if alloc.init_mask().is_range_initialized(byte).is_err() {
+                    "__".to_string()
+                } else {
+                    let bytes = alloc.inspect_with_uninit_and_ptr_outside_interpreter(i..i + 1);
+                    format!("{:02x}", bytes[0])
+                }
+            })
+            .collect()
+    }
+
     /// Find leaked allocations. Allocations reachable from `static_roots` or a `Global` allocation
     /// are not considered leaked, as well as leaks whose kind's `may_leak()` returns true.
     pub fn find_leaked_allocations(
@@ -137,11 +137,19 @@ pub fn eval_rvalue_into_place(
         rvalue: &mir::Rvalue<'tcx>,
         place: mir::Place<'tcx>,
     ) -> InterpResult<'tcx> {
+        use rustc_middle::mir::Rvalue::*;
+
+        if let Some(local) = place.as_local()
+            && let Use(ref operand) = *rvalue
+            && self.allocate_unsized_local(local, operand)?
+        {
+            return Ok(());
+        }
+
         let dest = self.eval_place(place)?;
         // FIXME: ensure some kind of non-aliasing between LHS and RHS?
         // Also see https://github.com/rust-lang/rust/issues/68364.
 
-        use rustc_middle::mir::Rvalue::*;
         match *rvalue {
             ThreadLocalRef(did) => {
                 let ptr = M::thread_local_static_base_pointer(self, did)?;
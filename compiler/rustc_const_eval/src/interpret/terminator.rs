@@ -170,6 +170,12 @@ pub(super) fn eval_terminator(
                 }
             }
 
+            TailCall { .. } => {
+                // Evaluating a tail call would need to replace the current frame by the one of
+                // the callee, while the arguments may still point into the current frame.
+                throw_unsup_format!("tail calls are not supported by the interpreter")
+            }
+
             Drop { place, target, unwind, replace: _ } => {
                 let frame = self.frame();
                 let ty = place.ty(&frame.body.local_decls, *self.tcx).ty;
@@ -749,80 +755,105 @@ pub(crate) fn eval_fn_call(
             // codegen'd / interpreted as virtual calls through the vtable.
             ty::InstanceDef::Virtual(def_id, idx) => {
                 let mut args = args.to_vec();
-                // We have to implement all "object safe receivers". So we have to go search for a
-                // pointer or `dyn Trait` type, but it could be wrapped in newtypes. So recursively
-                // unwrap those newtypes until we are there.
-                // An `InPlace` does nothing here, we keep the original receiver intact. We can't
-                // really pass the argument in-place anyway, and we are constructing a new
-                // `Immediate` receiver.
                 let mut receiver = self.copy_fn_arg(&args[0])?;
-                let receiver_place = loop {
-                    match receiver.layout.ty.kind() {
-                        ty::Ref(..) | ty::RawPtr(..) => {
-                            // We do *not* use `deref_pointer` here: we don't want to conceptually
-                            // create a place that must be dereferenceable, since the receiver might
-                            // be a raw pointer and (for `*const dyn Trait`) we don't need to
-                            // actually access memory to resolve this method.
-                            // Also see <https://github.com/rust-lang/miri/issues/2786>.
-                            let val = self.read_immediate(&receiver)?;
-                            break self.ref_to_mplace(&val)?;
-                        }
-                        ty::Dynamic(.., ty::Dyn) => break receiver.assert_mem_place(), // no immediate unsized values
-                        ty::Dynamic(.., ty::DynStar) => {
-                            // Not clear how to handle this, so far we assume the receiver is always a pointer.
-                            span_bug!(
-                                self.cur_span(),
-                                "by-value calls on a `dyn*`... are those a thing?"
-                            );
-                        }
-                        _ => {
-                            // Not there yet, search for the only non-ZST field.
-                            // (The rules for `DispatchFromDyn` ensure there's exactly one such field.)
-                            let (idx, _) = receiver.layout.non_1zst_field(self).expect(
-                                "not exactly one non-1-ZST field in a `DispatchFromDyn` type",
-                            );
-                            receiver = self.project_field(&receiver, idx)?;
-                        }
-                    }
-                };
-
+                let by_value_dyn_star = receiver.layout.ty.is_dyn_star();
                 // Obtain the underlying trait we are working on, and the adjusted receiver argument.
                 let (vptr, dyn_ty, adjusted_receiver) = if let ty::Dynamic(data, _, ty::DynStar) =
-                    receiver_place.layout.ty.kind()
+                    receiver.layout.ty.kind()
                 {
-                    let (recv, vptr) = self.unpack_dyn_star(&receiver_place)?;
+                    // A by-value call on a `dyn*`. The concrete method takes the data of the
+                    // `dyn*` by value, so that is what we pass on.
+                    let (recv, vptr) = self.unpack_dyn_star(&receiver)?;
                     let (dyn_ty, dyn_trait) = self.get_ptr_vtable(vptr)?;
                     if dyn_trait != data.principal() {
                         throw_ub_custom!(fluent::const_eval_dyn_star_call_vtable_mismatch);
                     }
 
-                    (vptr, dyn_ty, recv.ptr())
+                    (vptr, dyn_ty, recv)
                 } else {
-                    // Doesn't have to be a `dyn Trait`, but the unsized tail must be `dyn Trait`.
-                    // (For that reason we also cannot use `unpack_dyn_trait`.)
-                    let receiver_tail = self
-                        .tcx
-                        .struct_tail_erasing_lifetimes(receiver_place.layout.ty, self.param_env);
-                    let ty::Dynamic(data, _, ty::Dyn) = receiver_tail.kind() else {
-                        span_bug!(
-                            self.cur_span(),
-                            "dynamic call on non-`dyn` type {}",
-                            receiver_tail
-                        )
+                    // We have to implement all "object safe receivers". So we have to go search
+                    // for a pointer or `dyn Trait` type, but it could be wrapped in newtypes. So
+                    // recursively unwrap those newtypes until we are there.
+                    // An `InPlace` does nothing here, we keep the original receiver intact. We
+                    // can't really pass the argument in-place anyway, and we are constructing a
+                    // new `Immediate` receiver.
+                    let receiver_place = loop {
+                        match receiver.layout.ty.kind() {
+                            ty::Ref(..) | ty::RawPtr(..) => {
+                                // We do *not* use `deref_pointer` here: we don't want to
+                                // conceptually create a place that must be dereferenceable, since
+                                // the receiver might be a raw pointer and (for `*const dyn Trait`)
+                                // we don't need to actually access memory to resolve this method.
+                                // Also see <https://github.com/rust-lang/miri/issues/2786>.
+                                let val = self.read_immediate(&receiver)?;
+                                break self.ref_to_mplace(&val)?;
+                            }
+                            // No immediate unsized values.
+                            ty::Dynamic(.., ty::Dyn) => break receiver.assert_mem_place(),
+                            ty::Dynamic(.., ty::DynStar) => {
+                                // By-value `dyn*` receivers are handled above, and
+                                // `DispatchFromDyn` types cannot wrap a `dyn*` directly.
+                                span_bug!(self.cur_span(), "`dyn*` receiver inside a newtype")
+                            }
+                            _ => {
+                                // Not there yet, search for the only non-ZST field.
+                                // (The rules for `DispatchFromDyn` ensure there's exactly one
+                                // such field.)
+                                let (idx, _) = receiver.layout.non_1zst_field(self).expect(
+                                    "not exactly one non-1-ZST field in a `DispatchFromDyn` type",
+                                );
+                                receiver = self.project_field(&receiver, idx)?;
+                            }
+                        }
                     };
-                    assert!(receiver_place.layout.is_unsized());
 
-                    // Get the required information from the vtable.
-                    let vptr = receiver_place.meta().unwrap_meta().to_pointer(self)?;
-                    let (dyn_ty, dyn_trait) = self.get_ptr_vtable(vptr)?;
-                    if dyn_trait != data.principal() {
-                        throw_ub_custom!(fluent::const_eval_dyn_call_vtable_mismatch);
-                    }
+                    let (vptr, dyn_ty, receiver_ptr) = if let ty::Dynamic(data, _, ty::DynStar) =
+                        receiver_place.layout.ty.kind()
+                    {
+                        let (recv, vptr) = self.unpack_dyn_star(&receiver_place)?;
+                        let (dyn_ty, dyn_trait) = self.get_ptr_vtable(vptr)?;
+                        if dyn_trait != data.principal() {
+                            throw_ub_custom!(fluent::const_eval_dyn_star_call_vtable_mismatch);
+                        }
+
+                        (vptr, dyn_ty, recv.ptr())
+                    } else {
+                        // Doesn't have to be a `dyn Trait`, but the unsized tail must be
+                        // `dyn Trait`.
+                        // (For that reason we also cannot use `unpack_dyn_trait`.)
+                        let receiver_tail = self.tcx.struct_tail_erasing_lifetimes(
+                            receiver_place.layout.ty,
+                            self.param_env,
+                        );
+                        let ty::Dynamic(data, _, ty::Dyn) = receiver_tail.kind() else {
+                            span_bug!(
+                                self.cur_span(),
+                                "dynamic call on non-`dyn` type {}",
+                                receiver_tail
+                            )
+                        };
+                        assert!(receiver_place.layout.is_unsized());
+
+                        // Get the required information from the vtable.
+                        let vptr = receiver_place.meta().unwrap_meta().to_pointer(self)?;
+                        let (dyn_ty, dyn_trait) = self.get_ptr_vtable(vptr)?;
+                        if dyn_trait != data.principal() {
+                            throw_ub_custom!(fluent::const_eval_dyn_call_vtable_mismatch);
+                        }
 
-                    // It might be surprising that we use a pointer as the receiver even if this
-                    // is a by-val case; this works because by-val passing of an unsized `dyn
-                    // Trait` to a function is actually desugared to a pointer.
-                    (vptr, dyn_ty, receiver_place.ptr())
+                        // It might be surprising that we use a pointer as the receiver even if
+                        // this is a by-val case; this works because by-val passing of an unsized
+                        // `dyn Trait` to a function is actually desugared to a pointer.
+                        (vptr, dyn_ty, receiver_place.ptr())
+                    };
+
+                    // Adjust receiver argument. Layout can be any (thin) ptr.
+                    let receiver_ty = Ty::new_mut_ptr(self.tcx.tcx, dyn_ty);
+                    let receiver = ImmTy::from_immediate(
+                        Scalar::from_maybe_pointer(receiver_ptr, self).into(),
+                        self.layout_of(receiver_ty)?,
+                    );
+                    (vptr, dyn_ty, receiver.into())
                 };
 
                 // Now determine the actual method to call. We can do that in two different ways and
@@ -854,22 +885,21 @@ pub(crate) fn eval_fn_call(
                     assert_eq!(fn_inst, concrete_method);
                 }
 
-                // Adjust receiver argument. Layout can be any (thin) ptr.
-                let receiver_ty = Ty::new_mut_ptr(self.tcx.tcx, dyn_ty);
-                args[0] = FnArg::Copy(
-                    ImmTy::from_immediate(
-                        Scalar::from_maybe_pointer(adjusted_receiver, self).into(),
-                        self.layout_of(receiver_ty)?,
-                    )
-                    .into(),
-                );
+                args[0] = FnArg::Copy(adjusted_receiver.clone());
                 trace!("Patched receiver operand to {:#?}", args[0]);
-                // Need to also adjust the type in the ABI. Strangely, the layout there is actually
-                // already fine! Just the type is bogus. This is due to what `force_thin_self_ptr`
-                // does in `fn_abi_new_uncached`; supposedly, codegen relies on having the bogus
-                // type, so we just patch this up locally.
                 let mut caller_fn_abi = caller_fn_abi.clone();
-                caller_fn_abi.args[0].layout.ty = receiver_ty;
+                if by_value_dyn_star {
+                    // The caller passes the entire `dyn*`, but the callee only receives its data.
+                    // There is no caller-side ABI for that, so we use the one of the callee.
+                    caller_fn_abi.args[0] =
+                        self.fn_abi_of_instance(fn_inst, ty::List::empty())?.args[0].clone();
+                } else {
+                    // Need to also adjust the type in the ABI. Strangely, the layout there is
+                    // actually already fine! Just the type is bogus. This is due to what
+                    // `force_thin_self_ptr` does in `fn_abi_new_uncached`; supposedly, codegen
+                    // relies on having the bogus type, so we just patch this up locally.
+                    caller_fn_abi.args[0].layout.ty = adjusted_receiver.layout.ty;
+                }
 
                 // recurse with concrete function
                 self.eval_fn_call(
@@ -4,7 +4,6 @@
 //! That's useful because it means other passes (e.g. promotion) can rely on `const`s
 //! to be const-safe.
 
-use std::fmt::Write;
 use std::num::NonZero;
 
 use either::{Left, Right};
@@ -27,9 +26,9 @@
 use std::hash::Hash;
 
 use super::{
-    format_interp_error, machine::AllocMap, AllocId, CheckInAllocMsg, GlobalAlloc, ImmTy,
-    Immediate, InterpCx, InterpResult, MPlaceTy, Machine, MemPlaceMeta, OpTy, Pointer, Projectable,
-    Scalar, ValueVisitor,
+    alloc_range, format_interp_error, machine::AllocMap, AllocId, CheckInAllocMsg, GlobalAlloc,
+    ImmTy, Immediate, InterpCx, InterpErrorInfo, InterpResult, MPlaceTy, Machine, MemPlaceMeta,
+    OpTy, Pointer, Projectable, Scalar, ValueVisitor,
 };
 
 // for the validation errors
@@ -40,16 +39,9 @@
 
 macro_rules! throw_validation_failure {
     ($where:expr, $kind: expr) => {{
-        let where_ = &$where;
-        let path = if !where_.is_empty() {
-            let mut path = String::new();
-            write_path(&mut path, where_);
-            Some(path)
-        } else {
-            None
-        };
-
-        throw_ub!(ValidationError(ValidationErrorInfo { path, kind: $kind }))
+        let path = $where.iter().map(path_elem_to_string).collect();
+        // The location is filled in by `ValidityVisitor::locate_error` on the way up.
+        throw_ub!(ValidationError(ValidationErrorInfo { path, location: None, kind: $kind }))
     }};
 }
 
@@ -187,27 +179,24 @@ pub fn track(&mut self, op: T, path: impl FnOnce() -> PATH) {
 
 // FIXME make this translatable as well?
 /// Format a path
-fn write_path(out: &mut String, path: &[PathElem]) {
+fn path_elem_to_string(elem: &PathElem) -> String {
     use self::PathElem::*;
 
-    for elem in path.iter() {
-        match elem {
-            Field(name) => write!(out, ".{name}"),
-            EnumTag => write!(out, ".<enum-tag>"),
-            Variant(name) => write!(out, ".<enum-variant({name})>"),
-            CoroutineTag => write!(out, ".<coroutine-tag>"),
-            CoroutineState(idx) => write!(out, ".<coroutine-state({})>", idx.index()),
-            CapturedVar(name) => write!(out, ".<captured-var({name})>"),
-            TupleElem(idx) => write!(out, ".{idx}"),
-            ArrayElem(idx) => write!(out, "[{idx}]"),
-            // `.<deref>` does not match Rust syntax, but it is more readable for long paths -- and
-            // some of the other items here also are not Rust syntax. Actually we can't
-            // even use the usual syntax because we are just showing the projections,
-            // not the root.
-            Deref => write!(out, ".<deref>"),
-            DynDowncast => write!(out, ".<dyn-downcast>"),
-        }
-        .unwrap()
+    match elem {
+        Field(name) => format!(".{name}"),
+        EnumTag => ".<enum-tag>".to_string(),
+        Variant(name) => format!(".<enum-variant({name})>"),
+        CoroutineTag => ".<coroutine-tag>".to_string(),
+        CoroutineState(idx) => format!(".<coroutine-state({})>", idx.index()),
+        CapturedVar(name) => format!(".<captured-var({name})>"),
+        TupleElem(idx) => format!(".{idx}"),
+        ArrayElem(idx) => format!("[{idx}]"),
+        // `.<deref>` does not match Rust syntax, but it is more readable for long paths -- and
+        // some of the other items here also are not Rust syntax. Actually we can't
+        // even use the usual syntax because we are just showing the projections,
+        // not the root.
+        Deref => ".<deref>".to_string(),
+        DynDowncast => ".<dyn-downcast>".to_string(),
     }
 }
 
@@ -314,6 +303,25 @@ fn with_elem<R>(
         Ok(r)
     }
 
+    /// If `err` is a validation error that does not know where the invalid value is stored yet,
+    /// records that it is `op`. Errors bubble up from the innermost value, so the first location
+    /// recorded is the most precise one.
+    fn locate_error(
+        &self,
+        mut err: InterpErrorInfo<'tcx>,
+        op: &OpTy<'tcx, M::Provenance>,
+    ) -> InterpErrorInfo<'tcx> {
+        if let err_ub!(ValidationError(info)) = err.kind_mut()
+            && info.location.is_none()
+            && op.layout.is_sized()
+            && let Left(mplace) = op.as_mplace_or_imm()
+            && let Ok((alloc_id, offset, _)) = self.ecx.ptr_try_get_alloc_id(mplace.ptr())
+        {
+            info.location = Some((alloc_id, alloc_range(offset, op.layout.size)));
+        }
+        err
+    }
+
     fn read_immediate(
         &self,
         op: &OpTy<'tcx, M::Provenance>,
@@ -756,6 +764,7 @@ fn visit_field(
     ) -> InterpResult<'tcx> {
         let elem = self.aggregate_field_path_elem(old_op.layout, field);
         self.with_elem(elem, move |this| this.visit_value(new_op))
+            .map_err(|err| self.locate_error(err, new_op))
     }
 
     #[inline]
@@ -772,6 +781,7 @@ fn visit_variant(
             _ => bug!("Unexpected type with variant: {:?}", old_op.layout.ty),
         };
         self.with_elem(name, move |this| this.visit_value(new_op))
+            .map_err(|err| self.locate_error(err, new_op))
     }
 
     #[inline(always)]
@@ -887,12 +897,21 @@ fn visit_value(&mut self, op: &OpTy<'tcx, M::Provenance>) -> InterpResult<'tcx>
                                 )
                                 .unwrap();
                                 self.path.push(PathElem::ArrayElem(i));
+                                let elem = self.ecx.project_index(op, u64::try_from(i).unwrap())?;
 
-                                if matches!(err.kind(), Ub(InvalidUninitBytes(_))) {
-                                    throw_validation_failure!(self.path, Uninit { expected })
+                                let kind = if matches!(err.kind(), Ub(InvalidUninitBytes(_))) {
+                                    Uninit { expected }
                                 } else {
-                                    throw_validation_failure!(self.path, PointerAsInt { expected })
-                                }
+                                    PointerAsInt { expected }
+                                };
+                                // Point at the element, not at the entire array.
+                                let path = self.path.iter().map(path_elem_to_string).collect();
+                                let err = err_ub!(ValidationError(ValidationErrorInfo {
+                                    path,
+                                    location: None,
+                                    kind,
+                                }));
+                                return Err(self.locate_error(err.into(), &elem));
                             }
 
                             // Propagate upwards (that will also check for unexpected errors).
@@ -976,7 +995,7 @@ fn validate_operand_internal(
         let mut visitor = ValidityVisitor { path, ref_tracking, ctfe_mode, ecx: self };
 
         // Run it.
-        match visitor.visit_value(op) {
+        match visitor.visit_value(op).map_err(|err| visitor.locate_error(err, op)) {
             Ok(()) => Ok(()),
             // Pass through validation failures and "invalid program" issues.
             Err(err)
@@ -173,9 +173,27 @@ fn walk_value(&mut self, v: &Self::V) -> InterpResult<'tcx> {
                 self.visit_union(v, fields)?;
             }
             FieldsShape::Arbitrary { offsets, memory_index } => {
+                // The upvars of a coroutine which are only used until it is first resumed share
+                // their bytes with the fields of its other states, so they are skipped where the
+                // current state has its own fields.
+                let mut state_fields = vec![];
+                if let ty::Coroutine(..) = v.layout().ty.kind()
+                    && let Variants::Multiple { .. } = v.layout().variants
+                {
+                    let state = self.read_discriminant(v)?;
+                    let state = v.layout().for_variant(self.ecx(), state);
+                    for i in 0..state.fields.count() {
+                        let field = state.field(self.ecx(), i);
+                        state_fields.push((state.fields.offset(i), field.size));
+                    }
+                }
                 for idx in 0..offsets.len() {
                     let idx = Self::aggregate_field_order(memory_index, idx);
                     let field = self.ecx().project_field(v, idx)?;
+                    let (offset, size) = (v.layout().fields.offset(idx), field.layout().size);
+                    if state_fields.iter().any(|&(o, s)| o < offset + size && offset < o + s) {
+                        continue;
+                    }
                     self.visit_field(v, idx, &field)?;
                 }
             }
@@ -934,6 +934,8 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
 
             TerminatorKind::InlineAsm { .. } => self.check_op(ops::InlineAsm),
 
+            TerminatorKind::TailCall { .. } => self.check_op(ops::TailCall),
+
             TerminatorKind::Yield { .. } => self.check_op(ops::Coroutine(
                 self.tcx
                     .coroutine_kind(self.body.source.def_id())
@@ -368,6 +368,15 @@ fn build_error(&self, ccx: &ConstCx<'_, 'tcx>, span: Span) -> DiagnosticBuilder<
 #[derive(Debug)]
 pub struct HeapAllocation;
 impl<'tcx> NonConstOp<'tcx> for HeapAllocation {
+    fn status_in_item(&self, ccx: &ConstCx<'_, 'tcx>) -> Status {
+        // `const_heap` is a library feature, so it cannot be checked through `Status::Unstable`.
+        if ccx.tcx.features().declared(sym::const_heap) {
+            Status::Allowed
+        } else {
+            Status::Forbidden
+        }
+    }
+
     fn build_error(&self, ccx: &ConstCx<'_, 'tcx>, span: Span) -> DiagnosticBuilder<'tcx> {
         ccx.dcx().create_err(errors::UnallowedHeapAllocations {
             span,
@@ -385,6 +394,14 @@ fn build_error(&self, ccx: &ConstCx<'_, 'tcx>, span: Span) -> DiagnosticBuilder<
     }
 }
 
+#[derive(Debug)]
+pub struct TailCall;
+impl<'tcx> NonConstOp<'tcx> for TailCall {
+    fn build_error(&self, ccx: &ConstCx<'_, 'tcx>, span: Span) -> DiagnosticBuilder<'tcx> {
+        ccx.dcx().create_err(errors::UnallowedTailCall { span, kind: ccx.const_kind() })
+    }
+}
+
 #[derive(Debug)]
 pub struct LiveDrop<'tcx> {
     pub dropped_at: Option<Span>,
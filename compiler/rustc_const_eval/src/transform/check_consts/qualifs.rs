@@ -9,6 +9,7 @@
 use rustc_middle::mir::*;
 use rustc_middle::traits::BuiltinImplSource;
 use rustc_middle::ty::{self, AdtDef, GenericArgsRef, Ty};
+use rustc_span::symbol::sym;
 use rustc_trait_selection::traits::{
     ImplSource, Obligation, ObligationCause, ObligationCtxt, SelectionContext,
 };
@@ -168,6 +169,12 @@ fn in_any_value_of_ty<'tcx>(cx: &ConstCx<'_, 'tcx>, ty: Ty<'tcx>) -> bool {
             return false;
         }
 
+        // With `const_heap`, the interpreter frees the allocation of a `Box` using the global
+        // allocator itself, so such a box can be dropped whenever its contents can.
+        if ty.is_box_global(cx.tcx) && cx.tcx.features().declared(sym::const_heap) {
+            return Self::in_any_value_of_ty(cx, ty.boxed_ty());
+        }
+
         // FIXME(effects): If `destruct` is not a `const_trait`,
         // or effects are disabled in this crate, then give up.
         let destruct_def_id = cx.tcx.require_lang_item(LangItem::Destruct, Some(cx.body.span));
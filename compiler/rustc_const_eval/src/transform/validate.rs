@@ -5,11 +5,15 @@
 use rustc_index::IndexVec;
 use rustc_infer::traits::Reveal;
 use rustc_middle::mir::interpret::Scalar;
-use rustc_middle::mir::visit::{NonUseContext, PlaceContext, Visitor};
+use rustc_middle::mir::visit::{NonMutatingUseContext, NonUseContext, PlaceContext, Visitor};
 use rustc_middle::mir::*;
 use rustc_middle::ty::{self, InstanceDef, ParamEnv, Ty, TyCtxt, TypeVisitableExt, Variance};
+use rustc_mir_dataflow::impls::{borrowed_locals, MaybeInitializedLocal, MaybeStorageLive};
+use rustc_mir_dataflow::storage::always_storage_live_locals;
+use rustc_mir_dataflow::{Analysis, OnDemandAnalysis, ResultsCursor};
 use rustc_target::abi::{Size, FIRST_VARIANT};
 use rustc_target::spec::abi::Abi;
+use std::borrow::Cow;
 
 use crate::util::is_within_packed;
 
@@ -88,6 +92,12 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
             cfg_checker.fail(location, msg);
         }
 
+        if tcx.sess.opts.unstable_opts.validate_mir.is_full() {
+            for (location, msg) in validate_dataflow(tcx, param_env, body) {
+                cfg_checker.fail(location, msg);
+            }
+        }
+
         if let MirPhase::Runtime(_) = body.phase {
             if let ty::InstanceDef::Item(_) = body.source.instance {
                 if body.has_free_regions() {
@@ -435,6 +445,30 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                     }
                 }
             }
+            TerminatorKind::TailCall { args, .. } => {
+                let bb = location.block;
+                if self.body.basic_blocks[bb].is_cleanup {
+                    self.fail(location, "Cannot `TailCall` from cleanup basic block")
+                }
+                if self.body.coroutine.is_some() {
+                    self.fail(location, "`TailCall` cannot appear in coroutine bodies");
+                }
+
+                for arg in args {
+                    if let Operand::Move(place) = &arg.node {
+                        if is_within_packed(self.tcx, &self.body.local_decls, *place).is_some() {
+                            // This is bad! The callee will expect the memory to be aligned.
+                            self.fail(
+                                location,
+                                format!(
+                                    "encountered `Move` of a packed place in `TailCall` terminator: {:?}",
+                                    terminator.kind,
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
             TerminatorKind::Assert { target, unwind, .. } => {
                 self.check_edge(location, *target, EdgeKind::Normal);
                 self.check_unwind_edge(location, *unwind);
@@ -587,7 +621,7 @@ fn mir_assign_valid_types(&self, src: Ty<'tcx>, dest: Ty<'tcx>) -> bool {
 impl<'a, 'tcx> Visitor<'tcx> for TypeChecker<'a, 'tcx> {
     fn visit_operand(&mut self, operand: &Operand<'tcx>, location: Location) {
         // This check is somewhat expensive, so only run it when -Zvalidate-mir is passed.
-        if self.tcx.sess.opts.unstable_opts.validate_mir
+        if self.tcx.sess.opts.unstable_opts.validate_mir.is_enabled()
             && self.mir_phase < MirPhase::Runtime(RuntimePhase::Initial)
         {
             // `Operand::Copy` is only supposed to be used with `Copy` types.
@@ -1311,13 +1345,16 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                     }
                 }
             }
-            TerminatorKind::Call { func, .. } => {
+            TerminatorKind::Call { func, .. } | TerminatorKind::TailCall { func, .. } => {
                 let func_ty = func.ty(&self.body.local_decls, self.tcx);
                 match func_ty.kind() {
                     ty::FnPtr(..) | ty::FnDef(..) => {}
                     _ => self.fail(
                         location,
-                        format!("encountered non-callable type {func_ty} in `Call` terminator"),
+                        format!(
+                            "encountered non-callable type {func_ty} in `{}` terminator",
+                            terminator.kind.name(),
+                        ),
                     ),
                 }
             }
@@ -1348,3 +1385,88 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
         self.super_terminator(terminator, location);
     }
 }
+
+/// The checks of `-Zvalidate-mir=full`, which need dataflow analyses over the whole body.
+///
+/// To avoid false positives, only definite violations are reported: a local which is used while
+/// it has no storage along every path, or read while it is uninitialized along every path.
+fn validate_dataflow<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    body: &Body<'tcx>,
+) -> Vec<(Location, String)> {
+    let always_live_locals = always_storage_live_locals(body);
+    let maybe_storage_live = MaybeStorageLive::new(Cow::Owned(always_live_locals))
+        .into_engine(tcx, body)
+        .iterate_to_fixpoint()
+        .into_results_cursor(body);
+
+    let mut checker = DataflowChecker {
+        body,
+        tcx,
+        param_env,
+        maybe_storage_live,
+        borrowed_locals: borrowed_locals(body),
+        failures: Vec::new(),
+    };
+    for (bb, data) in traversal::reachable(body) {
+        checker.visit_basic_block_data(bb, data);
+    }
+    checker.failures
+}
+
+struct DataflowChecker<'a, 'tcx> {
+    body: &'a Body<'tcx>,
+    tcx: TyCtxt<'tcx>,
+    param_env: ParamEnv<'tcx>,
+    maybe_storage_live: ResultsCursor<'a, 'tcx, MaybeStorageLive<'a>>,
+    /// Borrowed locals may be initialized through a pointer, which `MaybeInitializedLocal`
+    /// does not see, so we do not check their reads.
+    borrowed_locals: BitSet<Local>,
+    failures: Vec<(Location, String)>,
+}
+
+impl<'a, 'tcx> DataflowChecker<'a, 'tcx> {
+    fn fail(&mut self, location: Location, msg: impl Into<String>) {
+        self.failures.push((location, msg.into()));
+    }
+
+    /// Whether reading `local` at `location` may observe an initialized value.
+    fn may_be_initialized(&self, local: Local, location: Location) -> bool {
+        if self.borrowed_locals.contains(local) {
+            return true;
+        }
+
+        // Passes are allowed to remove assignments to ZST locals, as they carry no data.
+        let ty = self.body.local_decls[local].ty;
+        if self.tcx.layout_of(self.param_env.and(ty)).map_or(true, |layout| layout.is_zst()) {
+            return true;
+        }
+
+        MaybeInitializedLocal::new(local).is_set_before(self.body, location)
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for DataflowChecker<'a, 'tcx> {
+    fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, location: Location) {
+        // Reading a part of the local, or through a pointer stored in it, also reads the local.
+        if let PlaceContext::NonMutatingUse(
+            NonMutatingUseContext::Copy | NonMutatingUseContext::Move,
+        ) = context
+            && !self.may_be_initialized(place.local, location)
+        {
+            self.fail(location, format!("read of {place:?}, whose local is uninitialized here"));
+        }
+
+        self.super_place(place, context, location);
+    }
+
+    fn visit_local(&mut self, local: Local, context: PlaceContext, location: Location) {
+        if context.is_use() {
+            self.maybe_storage_live.seek_before_primary_effect(location);
+            if !self.maybe_storage_live.get().contains(local) {
+                self.fail(location, format!("use of local {local:?}, which has no storage here"));
+            }
+        }
+    }
+}
@@ -114,6 +114,16 @@ fn process_obligation(
         obligation: &mut Self::Obligation,
     ) -> ProcessResult<Self::Obligation, Self::Error>;
 
+    /// Like `process_obligation`, but also given the tree `obligation` belongs
+    /// to. This lets processors keep track of state per root obligation.
+    fn process_obligation_in_tree(
+        &mut self,
+        obligation: &mut Self::Obligation,
+        _tree: ObligationTreeId,
+    ) -> ProcessResult<Self::Obligation, Self::Error> {
+        self.process_obligation(obligation)
+    }
+
     /// As we do the cycle check, we invoke this callback when we
     /// encounter an actual cycle. `cycle` is an iterator that starts
     /// at the start of the cycle in the stack and walks **toward the
@@ -143,8 +153,10 @@ pub enum ProcessResult<O, E> {
     Error(E),
 }
 
+/// Identifies the tree of obligations which all descend from the same root
+/// obligation.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-struct ObligationTreeId(usize);
+pub struct ObligationTreeId(usize);
 
 type ObligationTreeIdGenerator = impl Iterator<Item = ObligationTreeId>;
 
@@ -394,6 +406,25 @@ pub fn to_errors<E: Clone>(&mut self, error: E) -> Vec<Error<O, E>> {
         errors
     }
 
+    /// Removes all remaining obligations of the given trees, without reporting them as errors
+    /// or successes. This is for processors which already reported an error for these trees.
+    pub fn remove_trees(&mut self, trees: &[ObligationTreeId]) {
+        if trees.is_empty() {
+            return;
+        }
+        for node in &self.nodes {
+            if trees.contains(&node.obligation_tree_id) {
+                node.state.set(NodeState::Error);
+            }
+        }
+        self.compress(|_| assert!(false));
+    }
+
+    /// Returns the trees which still have obligations in the forest.
+    pub fn remaining_trees(&self) -> FxHashSet<ObligationTreeId> {
+        self.nodes.iter().map(|node| node.obligation_tree_id).collect()
+    }
+
     /// Returns the set of obligations that are in a pending state.
     pub fn map_pending_obligations<P, F>(&self, f: F) -> Vec<P>
     where
@@ -454,7 +485,9 @@ pub fn process_obligations<P>(&mut self, processor: &mut P) -> P::OUT
                 // happen, and code in `compress` has to allow for it.
 
                 // This code is much less hot.
-                match processor.process_obligation(&mut node.obligation) {
+                match processor
+                    .process_obligation_in_tree(&mut node.obligation, node.obligation_tree_id)
+                {
                     ProcessResult::Unchanged => {
                         // No change in state.
                     }
@@ -262,6 +262,31 @@ fn to_errors_no_throw() {
     assert_eq!(errors.len(), 3);
 }
 
+#[test]
+fn remove_trees() {
+    // check that removing a tree drops all of its obligations, but
+    // leaves the other trees alone.
+    let mut forest = ObligationForest::new();
+    forest.register_obligation("A");
+    forest.register_obligation("B");
+    let TestOutcome { completed: ok, errors: err, .. } = forest.process_obligations(&mut C(
+        |obligation| match *obligation {
+            "A" => ProcessResult::Changed(vec!["A.1", "A.2"]),
+            "B" => ProcessResult::Changed(vec!["B.1"]),
+            "A.1" | "A.2" | "B.1" => ProcessResult::Unchanged,
+            _ => unreachable!(),
+        },
+        |_| {},
+    ));
+    assert_eq!(ok.len(), 0);
+    assert_eq!(err.len(), 0);
+    let tree_a = forest.nodes[0].obligation_tree_id;
+    assert_eq!(forest.remaining_trees().len(), 2);
+    forest.remove_trees(&[tree_a]);
+    assert_eq!(forest.map_pending_obligations(|obligation| *obligation), vec!["B.1"]);
+    assert!(!forest.remaining_trees().contains(&tree_a));
+}
+
 #[test]
 fn diamond() {
     // check that diamond dependencies are handled correctly
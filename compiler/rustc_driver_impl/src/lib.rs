@@ -326,6 +326,7 @@ fn run_compiler(
         parse_sess_created: None,
         hash_untracked_state: None,
         register_lints: None,
+        register_mir_passes: None,
         override_queries: None,
         make_codegen_backend,
         registry: diagnostics_registry(),
@@ -452,6 +453,10 @@ fn run_compiler(
                 sess.code_stats.print_type_sizes();
             }
 
+            if sess.opts.unstable_opts.print_niche_decisions {
+                sess.code_stats.print_niche_decisions();
+            }
+
             if sess.opts.unstable_opts.print_vtable_sizes {
                 let crate_name = queries.global_ctxt()?.enter(|tcx| tcx.crate_name(LOCAL_CRATE));
 
@@ -468,6 +473,10 @@ fn run_compiler(
             linker.link(sess, codegen_backend)?
         }
 
+        if sess.opts.unstable_opts.selection_cache_stats {
+            sess.code_stats.print_solver_stats();
+        }
+
         if sess.opts.unstable_opts.print_fuel.is_some() {
             eprintln!(
                 "Fuel used by {}: {}",
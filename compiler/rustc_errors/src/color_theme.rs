@@ -0,0 +1,122 @@
+//! User-provided colors for the human readable output of diagnostics.
+//!
+//! A theme is read from the file given to `-Z diagnostic-theme`, or from the one named by the
+//! `RUSTC_COLOR_THEME` environment variable. It is a TOML file made of `key = "color"` pairs,
+//! limited to that: no tables, no other kinds of values. See the `diagnostic-theme` chapter of
+//! the unstable book for the keys and the syntax of colors.
+
+use termcolor::{Color, ColorSpec};
+
+use crate::Level;
+
+#[cfg(test)]
+mod tests;
+
+/// The colors of the elements of a diagnostic, replacing the default colors when set.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColorTheme {
+    /// The `error` headers, and the labels of the primary spans of errors.
+    pub error: Option<ColorSpec>,
+    /// The `warning` headers, and the labels of the primary spans of warnings.
+    pub warning: Option<ColorSpec>,
+    /// The `note` headers, and the labels of the primary spans of notes.
+    pub note: Option<ColorSpec>,
+    /// The `help` headers, and the labels of the primary spans of help messages.
+    pub help: Option<ColorSpec>,
+    /// The labels of the secondary spans.
+    pub label: Option<ColorSpec>,
+    /// The line numbers, the margin, and the `-->` arrows pointing at the source.
+    pub line_number: Option<ColorSpec>,
+    /// The code added by suggestions.
+    pub addition: Option<ColorSpec>,
+    /// The code removed by suggestions.
+    pub removal: Option<ColorSpec>,
+    /// The highlighted parts of messages, like the differences between two types.
+    pub highlight: Option<ColorSpec>,
+}
+
+impl ColorTheme {
+    /// Parses the contents of a theme file.
+    pub fn parse(src: &str) -> Result<ColorTheme, String> {
+        let mut theme = ColorTheme::default();
+        for (idx, line) in src.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let err = |msg: String| format!("line {}: {msg}", idx + 1);
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(err(format!("expected `key = \"color\"`, found `{line}`")));
+            };
+            let value = value.trim();
+            let Some((color, rest)) =
+                value.strip_prefix('"').and_then(|value| value.split_once('"'))
+            else {
+                return Err(err(format!("expected a quoted color, found `{value}`")));
+            };
+            let rest = rest.trim();
+            if !rest.is_empty() && !rest.starts_with('#') {
+                return Err(err(format!("unexpected `{rest}` after the color")));
+            }
+
+            let slot = match key.trim() {
+                "error" => &mut theme.error,
+                "warning" => &mut theme.warning,
+                "note" => &mut theme.note,
+                "help" => &mut theme.help,
+                "label" => &mut theme.label,
+                "line-number" => &mut theme.line_number,
+                "addition" => &mut theme.addition,
+                "removal" => &mut theme.removal,
+                "highlight" => &mut theme.highlight,
+                key => return Err(err(format!("unknown key `{key}`"))),
+            };
+            *slot = Some(parse_color_spec(color).map_err(err)?);
+        }
+        Ok(theme)
+    }
+
+    /// The color of the headers and primary labels of diagnostics of level `lvl`, if the theme
+    /// sets one.
+    pub(crate) fn level(&self, lvl: Level) -> Option<&ColorSpec> {
+        match lvl {
+            Level::Bug | Level::Fatal | Level::Error | Level::DelayedBug => self.error.as_ref(),
+            Level::ForceWarning(_) | Level::Warning => self.warning.as_ref(),
+            Level::Note | Level::OnceNote => self.note.as_ref(),
+            Level::Help | Level::OnceHelp => self.help.as_ref(),
+            Level::FailureNote | Level::Allow | Level::Expect(_) => None,
+        }
+    }
+}
+
+fn parse_color_spec(s: &str) -> Result<ColorSpec, String> {
+    let mut spec = ColorSpec::new();
+    for word in s.split_whitespace() {
+        match word {
+            "bold" => {
+                spec.set_bold(true);
+            }
+            "italic" => {
+                spec.set_italic(true);
+            }
+            "underline" => {
+                spec.set_underline(true);
+            }
+            "dimmed" => {
+                spec.set_dimmed(true);
+            }
+            "bright" => {
+                spec.set_intense(true);
+            }
+            color => {
+                if spec.fg().is_some() {
+                    return Err(format!("more than one color in `{s}`"));
+                }
+                let color = color.parse::<Color>().map_err(|e| e.to_string())?;
+                spec.set_fg(Some(color));
+            }
+        }
+    }
+    Ok(spec)
+}
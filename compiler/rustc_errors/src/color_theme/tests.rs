@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+fn parse_theme() {
+    let theme = ColorTheme::parse(
+        r#"
+# Avoid red and green.
+error = "bold magenta"
+warning = "bright yellow" # trailing comment
+line-number = "0,135,255"
+"#,
+    )
+    .unwrap();
+
+    let mut error = ColorSpec::new();
+    error.set_bold(true).set_fg(Some(Color::Magenta));
+    let mut warning = ColorSpec::new();
+    warning.set_intense(true).set_fg(Some(Color::Yellow));
+    let mut line_number = ColorSpec::new();
+    line_number.set_fg(Some(Color::Rgb(0, 135, 255)));
+    assert_eq!(
+        theme,
+        ColorTheme {
+            error: Some(error),
+            warning: Some(warning),
+            line_number: Some(line_number),
+            ..ColorTheme::default()
+        }
+    );
+}
+
+#[test]
+fn parse_theme_errors() {
+    assert_eq!(
+        ColorTheme::parse("error"),
+        Err("line 1: expected `key = \"color\"`, found `error`".to_string())
+    );
+    assert_eq!(
+        ColorTheme::parse("error = red"),
+        Err("line 1: expected a quoted color, found `red`".to_string())
+    );
+    assert_eq!(
+        ColorTheme::parse("\nerror = \"red\" blue"),
+        Err("line 2: unexpected `blue` after the color".to_string())
+    );
+    assert_eq!(
+        ColorTheme::parse("fatal = \"red\""),
+        Err("line 1: unknown key `fatal`".to_string())
+    );
+    assert_eq!(
+        ColorTheme::parse("note = \"red blue\""),
+        Err("line 1: more than one color in `red blue`".to_string())
+    );
+    assert!(ColorTheme::parse("help = \"reddish\"").is_err());
+}
@@ -1,7 +1,8 @@
 use crate::snippet::Style;
 use crate::{
     CodeSuggestion, DiagnosticBuilder, DiagnosticMessage, EmissionGuarantee, ErrCode, Level,
-    MultiSpan, SubdiagnosticMessage, Substitution, SubstitutionPart, SuggestionStyle,
+    MultiSpan, SubdiagnosticMessage, Substitution, SubstitutionPart, SuggestionRank,
+    SuggestionStyle,
 };
 use rustc_data_structures::fx::FxIndexMap;
 use rustc_error_messages::fluent_value_from_str_list_sep_by_and;
@@ -104,6 +105,9 @@ pub struct Diagnostic {
     pub code: Option<ErrCode>,
     pub span: MultiSpan,
     pub children: Vec<SubDiagnostic>,
+    /// Notes and help messages explaining the error in depth, which `-Zteach` would add to
+    /// `children`. Only collected for `-Zjson-explanations`, to be embedded in the JSON output.
+    pub teaching: Vec<SubDiagnostic>,
     pub suggestions: Result<Vec<CodeSuggestion>, SuggestionsDisabled>,
     args: FxIndexMap<DiagnosticArgName, DiagnosticArgValue>,
 
@@ -144,6 +148,8 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 pub struct IsLint {
     /// The lint name.
     pub(crate) name: String,
+    /// Where the lint is documented, if known.
+    pub(crate) doc_url: Option<String>,
     /// Indicates whether this lint should show up in cargo's future breakage report.
     has_future_breakage: bool,
 }
@@ -155,6 +161,44 @@ pub struct SubDiagnostic {
     pub level: Level,
     pub messages: Vec<(DiagnosticMessage, Style)>,
     pub span: MultiSpan,
+    /// The backtrace frame this note displays, if any, for the JSON output.
+    pub frame: Option<BacktraceFrame>,
+    /// The layout table this note displays, if any, for the JSON output.
+    pub layout: Option<LayoutTable>,
+}
+
+/// A frame of an evaluation backtrace (e.g. of const-eval), carried by the note displaying it.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Encodable, Decodable)]
+pub struct BacktraceFrame {
+    /// The function evaluated in this frame, `None` for closures.
+    pub instance: Option<String>,
+    /// Whether this frame evaluates a promoted constant.
+    pub promoted: bool,
+    /// How many repetitions of the previous frame this note stands for, 0 if it isn't collapsed.
+    pub times: usize,
+}
+
+/// The layout of the fields of a type, carried by the note displaying it. All the sizes and
+/// offsets are in bytes.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Encodable, Decodable)]
+pub struct LayoutTable {
+    /// The size of the type, `None` if it is too big for the current architecture.
+    pub size: Option<u64>,
+    pub align: u64,
+    /// The fields, in memory order, or in declaration order if the type is too big.
+    pub fields: Vec<LayoutTableField>,
+}
+
+/// A field of a [`LayoutTable`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Encodable, Decodable)]
+pub struct LayoutTableField {
+    pub name: String,
+    /// The offset of the field, `None` if it doesn't fit in the current architecture.
+    pub offset: Option<u64>,
+    pub size: u64,
+    pub align: u64,
+    /// The padding between the previous field (or the start of the type) and this one.
+    pub padding: u64,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -204,6 +248,14 @@ pub fn normal<S: Into<String>>(content: S) -> StringPart {
     pub fn highlighted<S: Into<String>>(content: S) -> StringPart {
         StringPart { content: content.into(), style: Style::Highlight }
     }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn is_highlighted(&self) -> bool {
+        self.style == Style::Highlight
+    }
 }
 
 impl Diagnostic {
@@ -220,6 +272,7 @@ pub fn new_with_messages(level: Level, messages: Vec<(DiagnosticMessage, Style)>
             code: None,
             span: MultiSpan::new(),
             children: vec![],
+            teaching: vec![],
             suggestions: Ok(vec![]),
             args: Default::default(),
             sort_span: DUMMY_SP,
@@ -443,6 +496,33 @@ pub fn span_note<S: Into<MultiSpan>>(
         self
     }
 
+    /// Prints the span with a note above it, describing a frame of an evaluation backtrace.
+    /// This is like [`Diagnostic::span_note()`], but the frame is also exported to JSON.
+    #[rustc_lint_diagnostics]
+    pub fn span_frame_note(
+        &mut self,
+        sp: Span,
+        msg: impl Into<SubdiagnosticMessage>,
+        frame: BacktraceFrame,
+    ) -> &mut Self {
+        self.sub(Level::Note, msg, sp.into());
+        self.children.last_mut().unwrap().frame = Some(frame);
+        self
+    }
+
+    /// Add a note describing the layout of the fields of a type.
+    /// This is like [`Diagnostic::note()`], but the layout is also exported to JSON.
+    #[rustc_lint_diagnostics]
+    pub fn layout_note(
+        &mut self,
+        msg: impl Into<SubdiagnosticMessage>,
+        layout: LayoutTable,
+    ) -> &mut Self {
+        self.sub(Level::Note, msg, MultiSpan::new());
+        self.children.last_mut().unwrap().layout = Some(layout);
+        self
+    }
+
     /// Prints the span with a note above it.
     /// This is like [`Diagnostic::note()`], but it gets its own span.
     pub fn span_note_once<S: Into<MultiSpan>>(
@@ -531,6 +611,32 @@ fn push_suggestion(&mut self, suggestion: CodeSuggestion) {
         }
     }
 
+    /// Marks the suggestions added by `f` as alternative fixes for the same problem,
+    /// ranked in the order they are added, most confident first. The JSON output
+    /// exposes the ranking, so that tools can offer a choice between them rather than
+    /// only the first suggestion.
+    ///
+    /// Less likely alternatives can be added with [`SuggestionStyle::CompletelyHidden`]
+    /// to keep them out of the human readable output.
+    pub fn ranked_suggestions(&mut self, f: impl FnOnce(&mut Self)) -> &mut Self {
+        let start = self.suggestions.as_ref().map_or(0, |suggestions| suggestions.len());
+        f(self);
+        if let Ok(suggestions) = &mut self.suggestions
+            && suggestions.len() > start + 1
+        {
+            let group = suggestions
+                .iter()
+                .filter_map(|suggestion| suggestion.alternative)
+                .map(|alternative| alternative.group + 1)
+                .max()
+                .unwrap_or(0);
+            for (rank, suggestion) in suggestions[start..].iter_mut().enumerate() {
+                suggestion.alternative = Some(SuggestionRank { group, rank });
+            }
+        }
+        self
+    }
+
     /// Show a suggestion that has multiple parts to it.
     /// In other words, multiple changes need to be applied as part of this suggestion.
     pub fn multipart_suggestion(
@@ -595,6 +701,7 @@ pub fn multipart_suggestion_with_style(
             msg: self.subdiagnostic_message_to_diagnostic_message(msg),
             style,
             applicability,
+            alternative: None,
         });
         self
     }
@@ -673,6 +780,7 @@ pub fn span_suggestion_with_style(
             msg: self.subdiagnostic_message_to_diagnostic_message(msg),
             style,
             applicability,
+            alternative: None,
         });
         self
     }
@@ -737,6 +845,7 @@ pub fn span_suggestions_with_style(
             msg: self.subdiagnostic_message_to_diagnostic_message(msg),
             style,
             applicability,
+            alternative: None,
         });
         self
     }
@@ -781,6 +890,7 @@ pub fn multipart_suggestions(
             msg: self.subdiagnostic_message_to_diagnostic_message(msg),
             style: SuggestionStyle::ShowCode,
             applicability,
+            alternative: None,
         });
         self
     }
@@ -876,8 +986,13 @@ pub fn span<S: Into<MultiSpan>>(&mut self, sp: S) -> &mut Self {
         self
     }
 
-    pub fn is_lint(&mut self, name: String, has_future_breakage: bool) -> &mut Self {
-        self.is_lint = Some(IsLint { name, has_future_breakage });
+    pub fn is_lint(
+        &mut self,
+        name: String,
+        doc_url: Option<String>,
+        has_future_breakage: bool,
+    ) -> &mut Self {
+        self.is_lint = Some(IsLint { name, doc_url, has_future_breakage });
         self
     }
 
@@ -932,6 +1047,8 @@ pub fn sub(&mut self, level: Level, message: impl Into<SubdiagnosticMessage>, sp
                 Style::NoStyle,
             )],
             span,
+            frame: None,
+            layout: None,
         };
         self.children.push(sub);
     }
@@ -943,7 +1060,7 @@ fn sub_with_highlights(&mut self, level: Level, messages: Vec<StringPart>, span:
             .into_iter()
             .map(|m| (self.subdiagnostic_message_to_diagnostic_message(m.content), m.style))
             .collect();
-        let sub = SubDiagnostic { level, messages, span };
+        let sub = SubDiagnostic { level, messages, span, frame: None, layout: None };
         self.children.push(sub);
     }
 
@@ -966,6 +1083,7 @@ fn keys(
             &self.code,
             &self.span,
             &self.children,
+            // omit self.teaching
             &self.suggestions,
             self.args().collect(),
             // omit self.sort_span
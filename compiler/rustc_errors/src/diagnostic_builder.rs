@@ -395,7 +395,7 @@ pub fn delay_as_bug(mut self) -> G::EmitResult {
         sp: impl Into<MultiSpan>,
     ));
     forward!((is_lint, with_is_lint)(
-        name: String, has_future_breakage: bool,
+        name: String, doc_url: Option<String>, has_future_breakage: bool,
     ));
     forward!((code, with_code)(
         code: ErrCode,
@@ -10,6 +10,7 @@
 use rustc_span::source_map::SourceMap;
 use rustc_span::{FileLines, FileName, SourceFile, Span};
 
+use crate::color_theme::ColorTheme;
 use crate::error::TranslateError;
 use crate::snippet::{
     Annotation, AnnotationColumn, AnnotationType, Line, MultilineAnnotation, Style, StyledString,
@@ -17,9 +18,10 @@
 use crate::styled_buffer::StyledBuffer;
 use crate::translation::{to_fluent_args, Translate};
 use crate::{
-    diagnostic::DiagnosticLocation, CodeSuggestion, DiagCtxt, Diagnostic, DiagnosticMessage,
-    ErrCode, FluentBundle, LazyFallbackBundle, Level, MultiSpan, SubDiagnostic,
-    SubstitutionHighlight, SuggestionStyle, TerminalUrl,
+    diagnostic::{DiagnosticLocation, IsLint},
+    CodeSuggestion, DiagCtxt, Diagnostic, DiagnosticMessage, ErrCode, FluentBundle,
+    LazyFallbackBundle, Level, MultiSpan, SubDiagnostic, SubstitutionHighlight, SuggestionStyle,
+    TerminalUrl,
 };
 use rustc_lint_defs::pluralize;
 
@@ -354,6 +356,8 @@ fn fix_multispans_in_extern_macros_and_render_macro_backtrace(
                     level: Level::Note,
                     messages: vec![(DiagnosticMessage::from(msg), Style::NoStyle)],
                     span: MultiSpan::new(),
+                    frame: None,
+                    layout: None,
                 });
             }
         }
@@ -519,6 +523,10 @@ fn source_map(&self) -> Option<&Lrc<SourceMap>> {
     }
 
     fn emit_diagnostic(&mut self, mut diag: Diagnostic) {
+        if self.type_aliases && !self.short_message {
+            self.alias_long_types(&mut diag);
+        }
+
         let fluent_args = to_fluent_args(diag.args());
 
         let mut suggestions = diag.suggestions.unwrap_or(vec![]);
@@ -531,6 +539,19 @@ fn emit_diagnostic(&mut self, mut diag: Diagnostic) {
             self.macro_backtrace,
         );
 
+        // The lint name is only mentioned by the notes explaining where its level comes from.
+        if let TerminalUrl::Yes = self.terminal_url
+            && let Some(IsLint { name, doc_url: Some(url), .. }) = &diag.is_lint
+        {
+            for child in &mut diag.children {
+                for (msg, _) in &mut child.messages {
+                    if let DiagnosticMessage::Str(text) = msg {
+                        *text = self.hyperlink_lint_name(text, name, url).into();
+                    }
+                }
+            }
+        }
+
         self.emit_messages_default(
             &diag.level,
             &diag.messages,
@@ -640,6 +661,10 @@ fn suggests_using_colors(self) -> bool {
     }
 }
 
+/// Types at least this long are referred to by an alias when a diagnostic
+/// mentions them more than once, see `HumanEmitter::alias_long_types`.
+const MIN_ALIASED_TYPE_LEN: usize = 300;
+
 /// Handles the writing of `HumanReadableErrorType::Default` and `HumanReadableErrorType::Short`
 #[derive(Setters)]
 pub struct HumanEmitter {
@@ -658,6 +683,12 @@ pub struct HumanEmitter {
     macro_backtrace: bool,
     track_diagnostics: bool,
     terminal_url: TerminalUrl,
+    /// Refer to long types mentioned several times by a diagnostic with an alias.
+    /// (rustc: see `-Z diagnostic-type-aliases`)
+    type_aliases: bool,
+    /// The colors to use instead of the default ones.
+    /// (rustc: see `-Z diagnostic-theme`)
+    color_theme: ColorTheme,
 }
 
 #[derive(Debug)]
@@ -687,6 +718,8 @@ fn create(dst: Destination, fallback_bundle: LazyFallbackBundle) -> HumanEmitter
             macro_backtrace: false,
             track_diagnostics: false,
             terminal_url: TerminalUrl::No,
+            type_aliases: false,
+            color_theme: ColorTheme::default(),
         }
     }
 
@@ -697,6 +730,167 @@ pub fn new(
         Self::create(dst, fallback_bundle)
     }
 
+    /// Wraps `text` in an OSC 8 hyperlink to `url`, if enabled with `-Z terminal-urls`.
+    fn hyperlink(&self, url: &str, text: &str) -> String {
+        match self.terminal_url {
+            TerminalUrl::Yes => format!("\x1b]8;;{url}\x07{text}\x1b]8;;\x07"),
+            TerminalUrl::No | TerminalUrl::Auto => text.to_string(),
+        }
+    }
+
+    /// The name of `file` as shown in the output, linked to the file itself if it is on disk.
+    fn file_name_for_diagnostics(&self, sm: &SourceMap, file: &FileName) -> String {
+        let name = sm.filename_for_diagnostics(file).to_string();
+        if let TerminalUrl::Yes = self.terminal_url
+            && let FileName::Real(real) = file
+            && let Some(path) = real.local_path()
+            && let Ok(path) = std::env::current_dir().map(|dir| dir.join(path))
+        {
+            self.hyperlink(&format!("file://{}", path.display()), &name)
+        } else {
+            name
+        }
+    }
+
+    /// Links every mention of the lint `name` in `text`, in either its
+    /// attribute or its command line spelling, to the documentation of the lint.
+    fn hyperlink_lint_name(&self, text: &str, name: &str, url: &str) -> String {
+        let is_name_char = |c: char| c.is_alphanumeric() || matches!(c, '_' | '-' | ':');
+        let hyphen_case_name = name.replace('_', "-");
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+        while !rest.is_empty() {
+            let start = text.len() - rest.len();
+            let at_boundary = !text[..start].ends_with(is_name_char);
+            let found = [name, hyphen_case_name.as_str()].into_iter().find(|name| {
+                at_boundary
+                    && rest.starts_with(name)
+                    && !rest[name.len()..].starts_with(is_name_char)
+            });
+            if let Some(found) = found {
+                result.push_str(&self.hyperlink(url, found));
+                rest = &rest[found.len()..];
+            } else {
+                let c = rest.chars().next().unwrap();
+                result.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        result
+    }
+
+    /// Replaces the long types mentioned more than once by `diag` with short
+    /// aliases, each spelled out once in a note:
+    ///
+    /// ```text
+    /// error[E0369]: cannot add `T1` to `T1`
+    ///  --> src/main.rs:4:15
+    ///   |
+    /// 4 |     let _ = x + y;
+    ///   |             - ^ - T1
+    ///   |             |
+    ///   |             T1
+    ///   |
+    ///   = note: `T1` is `((u8, u16, u32, u64), (u8, u16, u32, u64), (u8, u16, u32, u64), ...)`
+    /// ```
+    ///
+    /// Types are only known by being quoted as code in the translated messages (or
+    /// by making up a whole label), so every message of `diag` ends up translated.
+    fn alias_long_types(&self, diag: &mut Diagnostic) {
+        let args = to_fluent_args(diag.args());
+        let translate = |msg: &DiagnosticMessage| -> String {
+            self.translate_message(msg, &args).map_err(Report::new).unwrap().into_owned()
+        };
+        let translate_labels = |span: &MultiSpan| -> Vec<(Span, String)> {
+            span.span_labels()
+                .into_iter()
+                .filter_map(|label| Some((label.span, translate(&label.label?))))
+                .collect()
+        };
+
+        let mut messages: Vec<String> =
+            diag.messages.iter().map(|(msg, _)| translate(msg)).collect();
+        let mut labels = translate_labels(&diag.span);
+        let mut children: Vec<(Vec<String>, Vec<(Span, String)>)> = diag
+            .children
+            .iter()
+            .map(|child| {
+                (
+                    child.messages.iter().map(|(msg, _)| translate(msg)).collect(),
+                    translate_labels(&child.span),
+                )
+            })
+            .collect();
+
+        let texts = || {
+            messages.iter().chain(labels.iter().map(|(_, label)| label)).chain(
+                children.iter().flat_map(|(messages, labels)| {
+                    messages.iter().chain(labels.iter().map(|(_, label)| label))
+                }),
+            )
+        };
+        let mut mentions: FxIndexMap<&str, usize> = FxIndexMap::default();
+        for text in texts() {
+            // Code is quoted with backticks, so every other part is code. Labels
+            // without any code, like the ones of binary operators, can be a type.
+            let codes: Vec<&str> = if text.contains('`') {
+                text.split('`').skip(1).step_by(2).collect()
+            } else {
+                vec![text]
+            };
+            for code in codes {
+                if code.len() >= MIN_ALIASED_TYPE_LEN {
+                    *mentions.entry(code).or_default() += 1;
+                }
+            }
+        }
+        let mut alias_names = (1..).map(|i| format!("T{i}")).filter(|name| {
+            let quoted = format!("`{name}`");
+            !texts().any(|text| text.contains(&quoted))
+        });
+        let aliases: Vec<(String, String)> = mentions
+            .into_iter()
+            .filter(|&(_, count)| count > 1)
+            .map(|(ty, _)| (alias_names.next().unwrap(), ty.to_string()))
+            .collect();
+        if aliases.is_empty() {
+            return;
+        }
+
+        let replace = |text: &mut String| {
+            for (alias, ty) in &aliases {
+                if text == ty {
+                    *text = alias.clone();
+                } else {
+                    *text = text.replace(&format!("`{ty}`"), &format!("`{alias}`"));
+                }
+            }
+        };
+        let relabel = |span: &mut MultiSpan, labels: &mut Vec<(Span, String)>| {
+            *span = span.clone_ignoring_labels();
+            for (label_span, label) in labels {
+                replace(label);
+                span.push_span_label(*label_span, std::mem::take(label));
+            }
+        };
+
+        for ((msg, _), text) in diag.messages.iter_mut().zip(&mut messages) {
+            replace(text);
+            *msg = DiagnosticMessage::from(std::mem::take(text));
+        }
+        relabel(&mut diag.span, &mut labels);
+        for (child, (messages, labels)) in diag.children.iter_mut().zip(&mut children) {
+            for ((msg, _), text) in child.messages.iter_mut().zip(messages) {
+                replace(text);
+                *msg = DiagnosticMessage::from(std::mem::take(text));
+            }
+            relabel(&mut child.span, labels);
+        }
+        for (alias, ty) in aliases {
+            diag.note(format!("`{alias}` is `{ty}`"));
+        }
+    }
+
     fn maybe_anonymized(&self, line_num: usize) -> Cow<'static, str> {
         if self.ui_testing {
             Cow::Borrowed(ANONYMIZED_LINE_NUM)
@@ -1356,13 +1550,9 @@ fn emit_messages_default_inner(
             }
             if let Some(code) = code {
                 buffer.append(0, "[", Style::Level(*level));
-                let code = if let TerminalUrl::Yes = self.terminal_url {
-                    let path = "https://doc.rust-lang.org/error_codes";
-                    format!("\x1b]8;;{path}/{code}.html\x07{code}\x1b]8;;\x07")
-                } else {
-                    code.to_string()
-                };
-                buffer.append(0, &code, Style::Level(*level));
+                let code = code.to_string();
+                let url = format!("https://doc.rust-lang.org/error_codes/{code}.html");
+                buffer.append(0, &self.hyperlink(&url, &code), Style::Level(*level));
                 buffer.append(0, "]", Style::Level(*level));
                 label_width += 2 + code.len();
             }
@@ -1401,7 +1591,13 @@ fn emit_messages_default_inner(
         let primary_span = msp.primary_span().unwrap_or_default();
         let (Some(sm), false) = (self.sm.as_ref(), primary_span.is_dummy()) else {
             // If we don't have span information, emit and exit
-            return emit_to_destination(&buffer.render(), level, &mut self.dst, self.short_message);
+            return emit_to_destination(
+                &buffer.render(),
+                level,
+                &mut self.dst,
+                self.short_message,
+                &self.color_theme,
+            );
         };
         let primary_lo = sm.lookup_char_pos(primary_span.lo());
         if let Ok(pos) =
@@ -1436,7 +1632,7 @@ fn emit_messages_default_inner(
                                 line_idx,
                                 &format!(
                                     "{}:{}:{}",
-                                    sm.filename_for_diagnostics(&annotated_file.file.name),
+                                    self.file_name_for_diagnostics(sm, &annotated_file.file.name),
                                     sm.doctest_offset_line(
                                         &annotated_file.file.name,
                                         line.line_index
@@ -1492,7 +1688,7 @@ fn emit_messages_default_inner(
                         buffer_msg_line_offset,
                         &format!(
                             "{}:{}:{}",
-                            sm.filename_for_diagnostics(&loc.file.name),
+                            self.file_name_for_diagnostics(sm, &loc.file.name),
                             sm.doctest_offset_line(&loc.file.name, loc.line),
                             loc.col.0 + 1,
                         ),
@@ -1506,7 +1702,7 @@ fn emit_messages_default_inner(
                         0,
                         &format!(
                             "{}:{}:{}: ",
-                            sm.filename_for_diagnostics(&loc.file.name),
+                            self.file_name_for_diagnostics(sm, &loc.file.name),
                             sm.doctest_offset_line(&loc.file.name, loc.line),
                             loc.col.0 + 1,
                         ),
@@ -1534,7 +1730,7 @@ fn emit_messages_default_inner(
                     };
                     format!(
                         "{}:{}{}",
-                        sm.filename_for_diagnostics(&annotated_file.file.name),
+                        self.file_name_for_diagnostics(sm, &annotated_file.file.name),
                         sm.doctest_offset_line(&annotated_file.file.name, first_line.line_index),
                         col
                     )
@@ -1733,7 +1929,13 @@ fn emit_messages_default_inner(
         }
 
         // final step: take our styled buffer, render it, then output it
-        emit_to_destination(&buffer.render(), level, &mut self.dst, self.short_message)?;
+        emit_to_destination(
+            &buffer.render(),
+            level,
+            &mut self.dst,
+            self.short_message,
+            &self.color_theme,
+        )?;
 
         Ok(())
     }
@@ -1800,7 +2002,7 @@ fn emit_suggestion_default(
                 if loc.file.name != sm.span_to_filename(span) && loc.file.name.is_real() {
                     let arrow = "--> ";
                     buffer.puts(row_num - 1, 0, arrow, Style::LineNumber);
-                    let filename = sm.filename_for_diagnostics(&loc.file.name);
+                    let filename = self.file_name_for_diagnostics(sm, &loc.file.name);
                     let offset = sm.doctest_offset_line(&loc.file.name, loc.line);
                     let message = format!("{}:{}:{}", filename, offset, loc.col.0 + 1);
                     if row_num == 2 {
@@ -2086,7 +2288,13 @@ fn emit_suggestion_default(
             let msg = format!("and {} other candidate{}", others, pluralize!(others));
             buffer.puts(row_num, max_line_num_len + 3, &msg, Style::NoStyle);
         }
-        emit_to_destination(&buffer.render(), level, &mut self.dst, self.short_message)?;
+        emit_to_destination(
+            &buffer.render(),
+            level,
+            &mut self.dst,
+            self.short_message,
+            &self.color_theme,
+        )?;
         Ok(())
     }
 
@@ -2132,6 +2340,7 @@ fn emit_messages_default(
                         level,
                         &mut self.dst,
                         self.short_message,
+                        &self.color_theme,
                     ) {
                         panic!("failed to emit error: {e}")
                     }
@@ -2605,6 +2814,7 @@ fn emit_to_destination(
     lvl: &Level,
     dst: &mut Destination,
     short_message: bool,
+    color_theme: &ColorTheme,
 ) -> io::Result<()> {
     use crate::lock;
 
@@ -2623,7 +2833,7 @@ fn emit_to_destination(
     let _buffer_lock = lock::acquire_global_lock("rustc_errors");
     for (pos, line) in rendered_buffer.iter().enumerate() {
         for part in line {
-            let style = part.style.color_spec(*lvl);
+            let style = part.style.color_spec(*lvl, color_theme);
             dst.set_color(&style)?;
             write!(dst, "{}", part.text)?;
             dst.reset()?;
@@ -2701,20 +2911,32 @@ fn from_stderr(color: ColorConfig) -> Destination {
 const BRIGHT_BLUE: Color = if cfg!(windows) { Color::Cyan } else { Color::Blue };
 
 impl Style {
-    fn color_spec(&self, lvl: Level) -> ColorSpec {
+    fn color_spec(&self, lvl: Level, theme: &ColorTheme) -> ColorSpec {
         let mut spec = ColorSpec::new();
         match self {
             Style::Addition => {
-                spec.set_fg(Some(Color::Green)).set_intense(true);
+                if let Some(addition) = &theme.addition {
+                    spec = addition.clone();
+                } else {
+                    spec.set_fg(Some(Color::Green)).set_intense(true);
+                }
             }
             Style::Removal => {
-                spec.set_fg(Some(Color::Red)).set_intense(true);
+                if let Some(removal) = &theme.removal {
+                    spec = removal.clone();
+                } else {
+                    spec.set_fg(Some(Color::Red)).set_intense(true);
+                }
             }
             Style::LineAndColumn => {}
             Style::LineNumber => {
+                if let Some(line_number) = &theme.line_number {
+                    spec = line_number.clone();
+                } else {
+                    spec.set_intense(true);
+                    spec.set_fg(Some(BRIGHT_BLUE));
+                }
                 spec.set_bold(true);
-                spec.set_intense(true);
-                spec.set_fg(Some(BRIGHT_BLUE));
             }
             Style::Quotation => {}
             Style::MainHeaderMsg => {
@@ -2724,20 +2946,30 @@ fn color_spec(&self, lvl: Level) -> ColorSpec {
                 }
             }
             Style::UnderlinePrimary | Style::LabelPrimary => {
-                spec = lvl.color();
+                spec = lvl.color(theme);
                 spec.set_bold(true);
             }
             Style::UnderlineSecondary | Style::LabelSecondary => {
-                spec.set_bold(true).set_intense(true);
-                spec.set_fg(Some(BRIGHT_BLUE));
+                if let Some(label) = &theme.label {
+                    spec = label.clone();
+                } else {
+                    spec.set_intense(true);
+                    spec.set_fg(Some(BRIGHT_BLUE));
+                }
+                spec.set_bold(true);
             }
             Style::HeaderMsg | Style::NoStyle => {}
             Style::Level(lvl) => {
-                spec = lvl.color();
+                spec = lvl.color(theme);
                 spec.set_bold(true);
             }
             Style::Highlight => {
-                spec.set_bold(true).set_fg(Some(Color::Magenta));
+                if let Some(highlight) = &theme.highlight {
+                    spec = highlight.clone();
+                } else {
+                    spec.set_fg(Some(Color::Magenta));
+                }
+                spec.set_bold(true);
             }
         }
         spec
@@ -0,0 +1,165 @@
+//! An emitter printing diagnostics as [GitHub Actions workflow commands], so that they
+//! show up as annotations on the lines of code they point at.
+//!
+//! Annotations only have a single location and a plain text message, so everything
+//! else a diagnostic carries (span labels, notes, helps and suggestions) is folded into
+//! the message.
+//!
+//! [GitHub Actions workflow commands]: https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions
+
+use rustc_data_structures::sync::{IntoDynSyncSend, Lrc};
+use rustc_span::source_map::{FilePathMapping, SourceMap};
+
+use crate::emitter::Emitter;
+use crate::translation::{to_fluent_args, Translate};
+use crate::{diagnostic::IsLint, FluentBundle, LazyFallbackBundle, Level};
+
+use std::io::{self, Write};
+
+pub struct GithubEmitter {
+    dst: IntoDynSyncSend<Box<dyn Write + Send>>,
+    sm: Lrc<SourceMap>,
+    fluent_bundle: Option<Lrc<FluentBundle>>,
+    fallback_bundle: LazyFallbackBundle,
+}
+
+impl GithubEmitter {
+    pub fn stderr(
+        source_map: Lrc<SourceMap>,
+        fluent_bundle: Option<Lrc<FluentBundle>>,
+        fallback_bundle: LazyFallbackBundle,
+    ) -> GithubEmitter {
+        GithubEmitter::new(
+            Box::new(io::BufWriter::new(io::stderr())),
+            source_map,
+            fluent_bundle,
+            fallback_bundle,
+        )
+    }
+
+    pub fn basic(
+        fluent_bundle: Option<Lrc<FluentBundle>>,
+        fallback_bundle: LazyFallbackBundle,
+    ) -> GithubEmitter {
+        let file_path_mapping = FilePathMapping::empty();
+        GithubEmitter::stderr(
+            Lrc::new(SourceMap::new(file_path_mapping)),
+            fluent_bundle,
+            fallback_bundle,
+        )
+    }
+
+    pub fn new(
+        dst: Box<dyn Write + Send>,
+        source_map: Lrc<SourceMap>,
+        fluent_bundle: Option<Lrc<FluentBundle>>,
+        fallback_bundle: LazyFallbackBundle,
+    ) -> GithubEmitter {
+        GithubEmitter { dst: IntoDynSyncSend(dst), sm: source_map, fluent_bundle, fallback_bundle }
+    }
+
+    fn emit(&mut self, command: &str) -> io::Result<()> {
+        self.dst.write_all(command.as_bytes())?;
+        self.dst.write_all(b"\n")?;
+        self.dst.flush()
+    }
+}
+
+/// Escapes the message of a workflow command.
+fn escape_data(s: &str) -> String {
+    s.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escapes the value of a property of a workflow command.
+fn escape_property(s: &str) -> String {
+    escape_data(s).replace(':', "%3A").replace(',', "%2C")
+}
+
+impl Translate for GithubEmitter {
+    fn fluent_bundle(&self) -> Option<&Lrc<FluentBundle>> {
+        self.fluent_bundle.as_ref()
+    }
+
+    fn fallback_fluent_bundle(&self) -> &FluentBundle {
+        &self.fallback_bundle
+    }
+}
+
+impl Emitter for GithubEmitter {
+    fn emit_diagnostic(&mut self, diag: crate::Diagnostic) {
+        let command = match diag.level {
+            Level::Bug | Level::DelayedBug | Level::Fatal | Level::Error => "error",
+            Level::ForceWarning(_) | Level::Warning => "warning",
+            Level::Note | Level::OnceNote | Level::Help | Level::OnceHelp => "notice",
+            // `For more information about this error...` and the like aren't annotations.
+            Level::FailureNote => return,
+            Level::Allow | Level::Expect(_) => unreachable!(),
+        };
+        let args = to_fluent_args(diag.args());
+
+        let mut properties = vec![];
+        if let Some(span) = diag.span.primary_span()
+            && !span.is_dummy()
+        {
+            let start = self.sm.lookup_char_pos(span.lo());
+            let end = self.sm.lookup_char_pos(span.hi());
+            let file = self.sm.filename_for_diagnostics(&start.file.name).to_string();
+            properties.push(format!("file={}", escape_property(&file)));
+            properties.push(format!("line={}", start.line));
+            properties.push(format!("col={}", start.col.0 + 1));
+            properties.push(format!("endLine={}", end.line));
+            properties.push(format!("endColumn={}", end.col.0 + 1));
+        }
+        if let Some(code) = diag.code {
+            properties.push(format!("title={code}"));
+        } else if let Some(IsLint { name, .. }) = &diag.is_lint {
+            properties.push(format!("title={}", escape_property(name)));
+        }
+
+        let mut message = self.translate_messages(&diag.messages, &args).into_owned();
+        for span_label in diag.span.span_labels() {
+            if let Some(label) = &span_label.label
+                && !span_label.span.is_dummy()
+            {
+                let label = self.translate_message(label, &args).unwrap();
+                let loc = self.sm.lookup_char_pos(span_label.span.lo());
+                let file = self.sm.filename_for_diagnostics(&loc.file.name);
+                message.push_str(&format!("\n{file}:{}:{}: {label}", loc.line, loc.col.0 + 1));
+            }
+        }
+        for child in &diag.children {
+            let child_message = self.translate_messages(&child.messages, &args);
+            message.push_str(&format!("\n{}: {child_message}", child.level.to_str()));
+        }
+        for sugg in diag.suggestions.iter().flatten() {
+            let sugg_message = self.translate_message(&sugg.msg, &args).unwrap();
+            message.push_str(&format!("\nhelp: {sugg_message}"));
+            // Only show the code of the first substitution, like the human emitter does.
+            if let Some((snippet, parts, ..)) = sugg.splice_lines(&self.sm).into_iter().next()
+                && let Some(part) = parts.first()
+            {
+                let first_line = self.sm.lookup_char_pos(part.span.lo()).line;
+                for (line, code) in snippet.lines().enumerate() {
+                    message.push_str(&format!("\n{} | {code}", first_line + line));
+                }
+            }
+        }
+
+        let command = if properties.is_empty() {
+            format!("::{command}::{}", escape_data(&message))
+        } else {
+            format!("::{command} {}::{}", properties.join(","), escape_data(&message))
+        };
+        if let Err(e) = self.emit(&command) {
+            panic!("failed to print diagnostics: {e:?}");
+        }
+    }
+
+    fn source_map(&self) -> Option<&Lrc<SourceMap>> {
+        Some(&self.sm)
+    }
+
+    fn should_show_explain(&self) -> bool {
+        false
+    }
+}
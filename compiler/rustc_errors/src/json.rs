@@ -17,12 +17,12 @@
 use crate::translation::{to_fluent_args, Translate};
 use crate::{
     diagnostic::IsLint, CodeSuggestion, FluentBundle, LazyFallbackBundle, MultiSpan, SpanLabel,
-    SubDiagnostic, TerminalUrl,
+    SubDiagnostic, SuggestionRank, TerminalUrl,
 };
 use rustc_lint_defs::Applicability;
 
 use rustc_data_structures::sync::{IntoDynSyncSend, Lrc};
-use rustc_error_messages::FluentArgs;
+use rustc_error_messages::{DiagnosticMessage, FluentArgs};
 use rustc_span::hygiene::ExpnData;
 use rustc_span::Span;
 use std::error::Report;
@@ -44,6 +44,8 @@ pub struct JsonEmitter {
     fallback_bundle: LazyFallbackBundle,
     pretty: bool,
     ui_testing: bool,
+    type_aliases: bool,
+    explanations: bool,
     ignored_directories_in_source_blocks: Vec<String>,
     json_rendered: HumanReadableErrorType,
     diagnostic_width: Option<usize>,
@@ -73,6 +75,8 @@ pub fn stderr(
             fallback_bundle,
             pretty,
             ui_testing: false,
+            type_aliases: false,
+            explanations: false,
             ignored_directories_in_source_blocks: Vec::new(),
             json_rendered,
             diagnostic_width,
@@ -128,6 +132,8 @@ pub fn new(
             fallback_bundle,
             pretty,
             ui_testing: false,
+            type_aliases: false,
+            explanations: false,
             ignored_directories_in_source_blocks: Vec::new(),
             json_rendered,
             diagnostic_width,
@@ -141,6 +147,14 @@ pub fn ui_testing(self, ui_testing: bool) -> Self {
         Self { ui_testing, ..self }
     }
 
+    pub fn type_aliases(self, type_aliases: bool) -> Self {
+        Self { type_aliases, ..self }
+    }
+
+    pub fn explanations(self, explanations: bool) -> Self {
+        Self { explanations, ..self }
+    }
+
     pub fn ignored_directories_in_source_blocks(self, value: Vec<String>) -> Self {
         Self { ignored_directories_in_source_blocks: value, ..self }
     }
@@ -238,13 +252,29 @@ struct Diagnostic {
     /// The primary error message.
     message: String,
     code: Option<DiagnosticCode>,
+    /// The Fluent identifier of the message, followed by its attribute if it has one
+    /// (e.g. "passes_unused_variable_try_prefix.label"). Identifies the kind of
+    /// diagnostic, even for diagnostics without a code.
+    slug: Option<String>,
     /// "error: internal compiler error", "error", "warning", "note", "help".
     level: &'static str,
+    /// Set on the suggestions which are one of several alternative fixes for the
+    /// same problem.
+    alternative: Option<DiagnosticAlternative>,
     spans: Vec<DiagnosticSpan>,
     /// Associated diagnostic messages.
     children: Vec<Diagnostic>,
     /// The message as rustc would render it.
     rendered: Option<String>,
+    /// The in-depth explanation of the error, only included with `-Zjson-explanations`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    explanation: Option<DiagnosticExplanation>,
+    /// The evaluation backtrace frame this note describes, if it is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frame: Option<DiagnosticFrame>,
+    /// The layout table this note describes, if it is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layout: Option<DiagnosticLayout>,
 }
 
 #[derive(Serialize)]
@@ -274,6 +304,51 @@ struct DiagnosticSpan {
     expansion: Option<Box<DiagnosticSpanMacroExpansion>>,
 }
 
+#[derive(Serialize)]
+struct DiagnosticAlternative {
+    /// Identifies the set of alternatives within the diagnostic.
+    group: usize,
+    /// The position of the suggestion in its set, 0 being the most confident one.
+    rank: usize,
+}
+
+#[derive(Serialize)]
+struct DiagnosticExplanation {
+    /// The long-form explanation of the error code, as printed by `rustc --explain`.
+    long: Option<&'static str>,
+    /// The notes and help messages `-Zteach` adds to the diagnostic.
+    teach: Vec<Diagnostic>,
+}
+
+#[derive(Serialize)]
+struct DiagnosticFrame {
+    /// The function evaluated in this frame, `None` for closures.
+    instance: Option<String>,
+    /// Whether this frame evaluates a promoted constant.
+    promoted: bool,
+    /// How many repetitions of the previous frame this note stands for.
+    times: usize,
+}
+
+#[derive(Serialize)]
+struct DiagnosticLayout {
+    /// The size of the type, `None` if it is too big for the current architecture.
+    size: Option<u64>,
+    align: u64,
+    fields: Vec<DiagnosticLayoutField>,
+}
+
+#[derive(Serialize)]
+struct DiagnosticLayoutField {
+    name: String,
+    /// The offset of the field, `None` if it doesn't fit in the current architecture.
+    offset: Option<u64>,
+    size: u64,
+    align: u64,
+    /// The padding between the previous field (or the start of the type) and this one.
+    padding: u64,
+}
+
 #[derive(Serialize)]
 struct DiagnosticSpanLine {
     text: String,
@@ -348,10 +423,17 @@ fn from_errors_diagnostic(diag: crate::Diagnostic, je: &JsonEmitter) -> Diagnost
             Diagnostic {
                 message: translated_message.to_string(),
                 code: None,
+                slug: diagnostic_slug(&sugg.msg),
                 level: "help",
+                alternative: sugg
+                    .alternative
+                    .map(|SuggestionRank { group, rank }| DiagnosticAlternative { group, rank }),
                 spans: DiagnosticSpan::from_suggestion(sugg, &args, je),
                 children: vec![],
                 rendered: None,
+                explanation: None,
+                frame: None,
+                layout: None,
             }
         });
 
@@ -395,6 +477,18 @@ fn reset(&mut self) -> io::Result<()> {
         } else {
             None
         };
+        let explanation = match diag.code {
+            Some(code) if je.explanations => Some(DiagnosticExplanation {
+                long: je.registry.as_ref().and_then(|r| r.try_find_description(code).ok()),
+                teach: diag
+                    .teaching
+                    .iter()
+                    .map(|c| Diagnostic::from_sub_diagnostic(c, &args, je))
+                    .collect(),
+            }),
+            _ => None,
+        };
+        let slug = diag.messages.first().and_then(|(msg, _)| diagnostic_slug(msg));
         let level = diag.level.to_str();
         let spans = DiagnosticSpan::from_multispan(&diag.span, &args, je);
         let children = diag
@@ -415,6 +509,7 @@ fn reset(&mut self) -> io::Result<()> {
             .track_diagnostics(je.track_diagnostics)
             .terminal_url(je.terminal_url)
             .ui_testing(je.ui_testing)
+            .type_aliases(je.type_aliases)
             .ignored_directories_in_source_blocks(je.ignored_directories_in_source_blocks.clone())
             .emit_diagnostic(diag);
         let output = Arc::try_unwrap(output.0).unwrap().into_inner().unwrap();
@@ -423,10 +518,15 @@ fn reset(&mut self) -> io::Result<()> {
         Diagnostic {
             message: translated_message.to_string(),
             code,
+            slug,
             level,
+            alternative: None,
             spans,
             children,
             rendered: Some(output),
+            explanation,
+            frame: None,
+            layout: None,
         }
     }
 
@@ -439,14 +539,47 @@ fn from_sub_diagnostic(
         Diagnostic {
             message: translated_message.to_string(),
             code: None,
+            slug: diag.messages.first().and_then(|(msg, _)| diagnostic_slug(msg)),
             level: diag.level.to_str(),
+            alternative: None,
             spans: DiagnosticSpan::from_multispan(&diag.span, args, je),
             children: vec![],
             rendered: None,
+            explanation: None,
+            frame: diag.frame.as_ref().map(|frame| DiagnosticFrame {
+                instance: frame.instance.clone(),
+                promoted: frame.promoted,
+                times: frame.times,
+            }),
+            layout: diag.layout.as_ref().map(|layout| DiagnosticLayout {
+                size: layout.size,
+                align: layout.align,
+                fields: layout
+                    .fields
+                    .iter()
+                    .map(|field| DiagnosticLayoutField {
+                        name: field.name.clone(),
+                        offset: field.offset,
+                        size: field.size,
+                        align: field.align,
+                        padding: field.padding,
+                    })
+                    .collect(),
+            }),
         }
     }
 }
 
+/// Returns the Fluent identifier of `msg`, and its attribute if it has one, as they
+/// would be written in a Fluent resource (`slug` or `slug.attr`).
+fn diagnostic_slug(msg: &DiagnosticMessage) -> Option<String> {
+    match msg {
+        DiagnosticMessage::FluentIdentifier(id, None) => Some(id.to_string()),
+        DiagnosticMessage::FluentIdentifier(id, Some(attr)) => Some(format!("{id}.{attr}")),
+        DiagnosticMessage::Str(_) | DiagnosticMessage::Translated(_) => None,
+    }
+}
+
 impl DiagnosticSpan {
     fn from_span_label(
         span: SpanLabel,
@@ -37,9 +37,9 @@
 
 pub use codes::*;
 pub use diagnostic::{
-    AddToDiagnostic, DecorateLint, Diagnostic, DiagnosticArg, DiagnosticArgName,
-    DiagnosticArgValue, DiagnosticStyledString, IntoDiagnosticArg, StringPart, SubDiagnostic,
-    SubdiagnosticMessageOp,
+    AddToDiagnostic, BacktraceFrame, DecorateLint, Diagnostic, DiagnosticArg, DiagnosticArgName,
+    DiagnosticArgValue, DiagnosticStyledString, IntoDiagnosticArg, LayoutTable, LayoutTableField,
+    StringPart, SubDiagnostic, SubdiagnosticMessageOp,
 };
 pub use diagnostic_builder::{
     BugAbort, DiagnosticBuilder, EmissionGuarantee, FatalAbort, IntoDiagnostic,
@@ -49,6 +49,7 @@
     IndicateAnonymousLifetime, InvalidFlushedDelayedDiagnosticLevel, SingleLabelManySpans,
 };
 pub use emitter::ColorConfig;
+pub use macro_call_site::MacroCallSite;
 pub use rustc_error_messages::{
     fallback_fluent_bundle, fluent_bundle, DelayDm, DiagnosticMessage, FluentBundle,
     LanguageIdentifier, LazyFallbackBundle, MultiSpan, SpanLabel, SubdiagnosticMessage,
@@ -63,6 +64,7 @@
 pub use termcolor::{Color, ColorSpec, WriteColor};
 
 use crate::diagnostic_impls::{DelayedAtWithNewline, DelayedAtWithoutNewline};
+use color_theme::ColorTheme;
 use emitter::{is_case_difference, DynEmitter, Emitter, HumanEmitter};
 use registry::Registry;
 use rustc_data_structures::fx::{FxHashSet, FxIndexMap, FxIndexSet};
@@ -71,7 +73,7 @@
 use rustc_data_structures::AtomicRef;
 use rustc_lint_defs::LintExpectationId;
 use rustc_span::source_map::SourceMap;
-use rustc_span::{Loc, Span, DUMMY_SP};
+use rustc_span::{Loc, Span, SyntaxContext, DUMMY_SP};
 use std::backtrace::{Backtrace, BacktraceStatus};
 use std::borrow::Cow;
 use std::error::Report;
@@ -87,15 +89,19 @@
 
 pub mod annotate_snippet_emitter_writer;
 pub mod codes;
+pub mod color_theme;
 mod diagnostic;
 mod diagnostic_builder;
 mod diagnostic_impls;
 pub mod emitter;
 pub mod error;
+pub mod github;
 pub mod json;
 mod lock;
+mod macro_call_site;
 pub mod markdown;
 pub mod registry;
+pub mod sarif;
 mod snippet;
 mod styled_buffer;
 #[cfg(test)]
@@ -168,6 +174,18 @@ pub struct CodeSuggestion {
     /// which are useful for users but not useful for
     /// tools like rustfix
     pub applicability: Applicability,
+    /// Set when this suggestion is one of several alternative fixes for the same
+    /// problem, see `Diagnostic::ranked_suggestions`.
+    pub alternative: Option<SuggestionRank>,
+}
+
+/// Where a suggestion stands among alternative fixes for the same problem.
+#[derive(Clone, Copy, Debug, PartialEq, Hash, Encodable, Decodable)]
+pub struct SuggestionRank {
+    /// Identifies the set of alternatives within the diagnostic.
+    pub group: usize,
+    /// The position of the suggestion in its set, `0` being the most confident one.
+    pub rank: usize,
 }
 
 #[derive(Clone, Debug, PartialEq, Hash, Encodable, Decodable)]
@@ -475,6 +493,16 @@ struct DiagCtxtInner {
     /// twice.
     emitted_diagnostics: FxHashSet<Hash128>,
 
+    /// The errors collapsed by `collapse_cascaded_errors`, keyed by the hash
+    /// of their code and message. Holds the primary span of the first
+    /// occurrence, which was emitted, and how many more were not.
+    cascaded_errors: FxIndexMap<Hash128, (Option<Span>, usize)>,
+
+    /// The diagnostics deduplicated by `is_repeated_diagnostic`, keyed by their
+    /// fingerprint. Holds the level and primary span of the first occurrence,
+    /// which was emitted, and how many more were not.
+    repeated_diagnostics: FxIndexMap<Hash128, (Level, Option<Span>, usize)>,
+
     /// Stashed diagnostics emitted in one stage of the compiler that may be
     /// stolen by other stages (e.g. to improve them and add more information).
     /// The stashed diagnostics count towards the total error count.
@@ -555,10 +583,15 @@ pub struct DiagCtxtFlags {
     /// Show macro backtraces.
     /// (rustc: see `-Z macro-backtrace`)
     pub macro_backtrace: bool,
-    /// If true, identical diagnostics are reported only once.
+    /// If true, identical diagnostics are reported only once, and so are those
+    /// which only differ by the macro expansions they come from.
     pub deduplicate_diagnostics: bool,
     /// Track where errors are created. Enabled with `-Ztrack-diagnostics`.
     pub track_diagnostics: bool,
+    /// If true, errors which are repeated because the same name failed to
+    /// resolve are only reported once, along with a count of the others.
+    /// (rustc: see `-Z show-cascaded-errors`)
+    pub collapse_cascaded_errors: bool,
 }
 
 impl Drop for DiagCtxtInner {
@@ -627,6 +660,8 @@ pub fn with_emitter(emitter: Box<DynEmitter>) -> Self {
                 taught_diagnostics: Default::default(),
                 emitted_diagnostic_codes: Default::default(),
                 emitted_diagnostics: Default::default(),
+                cascaded_errors: Default::default(),
+                repeated_diagnostics: Default::default(),
                 stashed_diagnostics: Default::default(),
                 future_breakage_diagnostics: Vec::new(),
                 check_unstable_expect_diagnostics: false,
@@ -688,6 +723,8 @@ pub fn reset_err_count(&self) {
             taught_diagnostics,
             emitted_diagnostic_codes,
             emitted_diagnostics,
+            cascaded_errors,
+            repeated_diagnostics,
             stashed_diagnostics,
             future_breakage_diagnostics,
             check_unstable_expect_diagnostics,
@@ -710,6 +747,8 @@ pub fn reset_err_count(&self) {
         *taught_diagnostics = Default::default();
         *emitted_diagnostic_codes = Default::default();
         *emitted_diagnostics = Default::default();
+        *cascaded_errors = Default::default();
+        *repeated_diagnostics = Default::default();
         *stashed_diagnostics = Default::default();
         *future_breakage_diagnostics = Default::default();
         *check_unstable_expect_diagnostics = false;
@@ -800,6 +839,50 @@ pub fn print_error_count(&self, registry: &Registry) {
             return;
         }
 
+        let cascaded_errors = std::mem::take(&mut inner.cascaded_errors);
+        let mut any_cascaded = false;
+        for (span, count) in cascaded_errors.into_values() {
+            if count == 0 {
+                continue;
+            }
+            any_cascaded = true;
+            let msg = if count == 1 {
+                Cow::from("1 more error like this was not shown")
+            } else {
+                Cow::from(format!("{count} more errors like this were not shown"))
+            };
+            let mut note = Diagnostic::new(Note, DiagnosticMessage::Str(msg));
+            if let Some(span) = span {
+                note.span(span);
+            }
+            inner.emit_diagnostic(note);
+        }
+        if any_cascaded {
+            inner.emit_diagnostic(Diagnostic::new(
+                Note,
+                "errors caused by the same unresolved name were collapsed; \
+                 pass `-Z show-cascaded-errors` to show all of them",
+            ));
+        }
+
+        let repeated_diagnostics = std::mem::take(&mut inner.repeated_diagnostics);
+        for (level, span, count) in repeated_diagnostics.into_values() {
+            if count == 0 {
+                continue;
+            }
+            let msg = format!(
+                "this {} was emitted {} times, by different macro expansions or instantiations; \
+                 only the first one was shown",
+                level.to_str(),
+                count + 1,
+            );
+            let mut note = Diagnostic::new(Note, msg);
+            if let Some(span) = span {
+                note.span(span);
+            }
+            inner.emit_diagnostic(note);
+        }
+
         let warnings = match inner.deduplicated_warn_count {
             0 => Cow::from(""),
             1 => Cow::from("1 warning emitted"),
@@ -1375,10 +1458,12 @@ fn emit_diagnostic(&mut self, mut diagnostic: Diagnostic) -> Option<ErrorGuarant
 
             let is_error = diagnostic.is_error();
             let is_lint = diagnostic.is_lint.is_some();
+            let cascaded = is_error && !is_lint && self.is_cascaded_error(&diagnostic);
+            let repeated = !cascaded && self.is_repeated_diagnostic(&diagnostic, already_emitted);
 
             // Only emit the diagnostic if we've been asked to deduplicate or
             // haven't already emitted an equivalent diagnostic.
-            if !(self.flags.deduplicate_diagnostics && already_emitted) {
+            if !(self.flags.deduplicate_diagnostics && already_emitted) && !cascaded && !repeated {
                 debug!(?diagnostic);
                 debug!(?self.emitted_diagnostics);
 
@@ -1438,6 +1523,96 @@ fn emit_diagnostic(&mut self, mut diagnostic: Diagnostic) -> Option<ErrorGuarant
         guaranteed
     }
 
+    /// Returns `true` if `diagnostic` repeats an error about a name which
+    /// failed to resolve, and so should not be emitted again, and records it
+    /// otherwise. Each use of an unresolved name gets its own error, which
+    /// quickly buries the few errors that matter under identical ones.
+    fn is_cascaded_error(&mut self, diagnostic: &Diagnostic) -> bool {
+        if !self.flags.collapse_cascaded_errors {
+            return false;
+        }
+        let Some(code) = diagnostic.code else { return false };
+        if ![E0405, E0412, E0422, E0425, E0433, E0531].contains(&code) {
+            return false;
+        }
+        let key = {
+            let mut hasher = StableHasher::new();
+            code.hash(&mut hasher);
+            diagnostic.messages.hash(&mut hasher);
+            diagnostic.args().collect::<Vec<_>>().hash(&mut hasher);
+            hasher.finish()
+        };
+        if let Some((_, count)) = self.cascaded_errors.get_mut(&key) {
+            *count += 1;
+            true
+        } else {
+            self.cascaded_errors.insert(key, (diagnostic.span.primary_span(), 0));
+            false
+        }
+    }
+
+    /// Returns `true` if `diagnostic` only differs from one which was already
+    /// emitted by the macro expansions its spans come from, and so should not
+    /// be emitted again, and records it otherwise. This is common when a
+    /// `macro_rules!` macro expanding to erroneous code is invoked repeatedly,
+    /// or when a lint fires for each instantiation of a generic item.
+    ///
+    /// Spans pointing into other crates are kept as they are, as the emitter
+    /// replaces them with the locations of the macro invocations.
+    fn is_repeated_diagnostic(&mut self, diagnostic: &Diagnostic, already_emitted: bool) -> bool {
+        if !self.flags.deduplicate_diagnostics {
+            return false;
+        }
+        let sm = self.emitter.source_map().cloned();
+        let hash_span = |span: Span, hasher: &mut StableHasher| {
+            if sm.as_ref().is_some_and(|sm| sm.is_imported(span)) {
+                span.hash(hasher);
+            } else {
+                (span.lo(), span.hi()).hash(hasher);
+            }
+        };
+        let hash_multispan = |msp: &MultiSpan, hasher: &mut StableHasher| {
+            for SpanLabel { span, is_primary, label } in msp.span_labels() {
+                hash_span(span, hasher);
+                (is_primary, label).hash(hasher);
+            }
+        };
+        let key = {
+            let mut hasher = StableHasher::new();
+            diagnostic.level.hash(&mut hasher);
+            diagnostic.messages.hash(&mut hasher);
+            diagnostic.code.hash(&mut hasher);
+            diagnostic.args().collect::<Vec<_>>().hash(&mut hasher);
+            diagnostic.is_lint.hash(&mut hasher);
+            hash_multispan(&diagnostic.span, &mut hasher);
+            for child in &diagnostic.children {
+                (child.level, &child.messages).hash(&mut hasher);
+                hash_multispan(&child.span, &mut hasher);
+            }
+            for suggestion in diagnostic.suggestions.iter().flatten() {
+                (&suggestion.msg, suggestion.style, suggestion.applicability).hash(&mut hasher);
+                for part in suggestion.substitutions.iter().flat_map(|sub| &sub.parts) {
+                    hash_span(part.span, &mut hasher);
+                    part.snippet.hash(&mut hasher);
+                }
+            }
+            hasher.finish()
+        };
+        if let Some((_, _, count)) = self.repeated_diagnostics.get_mut(&key) {
+            // Exact duplicates are dropped silently, without being counted.
+            if !already_emitted {
+                *count += 1;
+            }
+            true
+        } else {
+            // The count is reported at the code itself, rather than at any of
+            // the expansions it was emitted for.
+            let span = diagnostic.span.primary_span().map(|sp| sp.with_ctxt(SyntaxContext::root()));
+            self.repeated_diagnostics.insert(key, (diagnostic.level, span, 0));
+            false
+        }
+    }
+
     fn treat_err_as_bug(&self) -> bool {
         self.flags
             .treat_err_as_bug
@@ -1686,7 +1861,10 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 }
 
 impl Level {
-    fn color(self) -> ColorSpec {
+    fn color(self, theme: &ColorTheme) -> ColorSpec {
+        if let Some(spec) = theme.level(self) {
+            return spec.clone();
+        }
         let mut spec = ColorSpec::new();
         match self {
             Bug | Fatal | Error | DelayedBug => {
@@ -0,0 +1,68 @@
+//! Placement of suggestions about code produced by function-like macros.
+//!
+//! When the expression a suggestion is about is the output of a macro invocation, the
+//! suggestion is naturally computed against spans pointing into the macro definition. Those
+//! locations are either not shown at all (for macros from other crates) or are not where the
+//! user should make the change. If the suggestion only touches the edges of the expression,
+//! it can be applied to the macro invocation the user wrote instead.
+
+use rustc_span::hygiene::{ExpnKind, MacroKind};
+use rustc_span::Span;
+
+/// An expression produced by a function-like macro, together with the invocation of that macro
+/// as written in the surrounding code.
+#[derive(Clone, Copy, Debug)]
+pub struct MacroCallSite {
+    expansion: Span,
+    call_site: Span,
+}
+
+impl MacroCallSite {
+    /// Finds the macro invocation that `expansion`, the span of an expression, was produced by,
+    /// as written in the syntax context of `parent`, the span of the code containing it.
+    ///
+    /// Returns `None` if `expansion` is already in the context of `parent`, e.g. if it is a macro
+    /// argument, or if getting there means going through something other than a function-like
+    /// macro, like a desugaring or an attribute macro.
+    pub fn find(expansion: Span, parent: Span) -> Option<Self> {
+        if expansion.eq_ctxt(parent) {
+            return None;
+        }
+        let mut call_site = expansion;
+        while !call_site.eq_ctxt(parent) {
+            let expn_data = call_site.ctxt().outer_expn_data();
+            if !matches!(expn_data.kind, ExpnKind::Macro(MacroKind::Bang, _)) {
+                return None;
+            }
+            call_site = expn_data.call_site;
+        }
+        Some(MacroCallSite { expansion, call_site })
+    }
+
+    /// The span of the macro invocation.
+    pub fn call_site(&self) -> Span {
+        self.call_site
+    }
+
+    /// Maps a span computed against the macro output onto the macro invocation. Only spans
+    /// covering the whole output, or empty spans at its start or end, can be mapped.
+    pub fn remap_span(&self, span: Span) -> Option<Span> {
+        let (lo, hi) = (span.lo(), span.hi());
+        if lo == self.expansion.lo() && hi == self.expansion.hi() {
+            Some(self.call_site)
+        } else if lo == hi && lo == self.expansion.lo() {
+            Some(self.call_site.shrink_to_lo())
+        } else if lo == hi && lo == self.expansion.hi() {
+            Some(self.call_site.shrink_to_hi())
+        } else {
+            None
+        }
+    }
+
+    /// Rewrites the parts of a suggestion computed against the macro output to apply to the
+    /// macro invocation instead. Returns `None` if any part edits code inside the macro output,
+    /// in which case the suggestion can't be expressed in terms of the invocation.
+    pub fn remap_parts(&self, parts: Vec<(Span, String)>) -> Option<Vec<(Span, String)>> {
+        parts.into_iter().map(|(span, snippet)| Some((self.remap_span(span)?, snippet))).collect()
+    }
+}
@@ -0,0 +1,411 @@
+//! A [SARIF 2.1.0] emitter for errors, so that code scanning dashboards can ingest
+//! rustc's diagnostics directly.
+//!
+//! Unlike the JSON emitter, which prints every diagnostic as soon as it is emitted,
+//! SARIF describes a whole compilation as a single log. Results are therefore
+//! collected as diagnostics come in, and the log is written out when the emitter
+//! is dropped.
+//!
+//! Each result is mapped as follows:
+//! - its rule is the error code of the diagnostic, or the name of the lint,
+//! - its locations are the primary spans, its related locations the other labelled spans,
+//! - its fixes are the machine-applicable suggestions.
+//!
+//! [SARIF 2.1.0]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_data_structures::sync::{IntoDynSyncSend, Lrc};
+use rustc_error_messages::FluentArgs;
+use rustc_lint_defs::Applicability;
+use rustc_span::source_map::{FilePathMapping, SourceMap};
+use rustc_span::Span;
+
+use crate::emitter::Emitter;
+use crate::translation::{to_fluent_args, Translate};
+use crate::{diagnostic::IsLint, FluentBundle, LazyFallbackBundle, Level, MultiSpan};
+
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+pub struct SarifEmitter {
+    dst: IntoDynSyncSend<Box<dyn Write + Send>>,
+    sm: Lrc<SourceMap>,
+    fluent_bundle: Option<Lrc<FluentBundle>>,
+    fallback_bundle: LazyFallbackBundle,
+    /// Whether to write a log even if no diagnostics were emitted. Emitters for early
+    /// errors are created and replaced several times during startup, and only the ones
+    /// which actually saw a diagnostic should print a log.
+    always_write: bool,
+    /// The index of each rule in `rules`, by rule ID.
+    rule_indices: FxIndexMap<String, usize>,
+    rules: Vec<ReportingDescriptor>,
+    results: Vec<SarifResult>,
+}
+
+impl SarifEmitter {
+    pub fn stderr(
+        source_map: Lrc<SourceMap>,
+        fluent_bundle: Option<Lrc<FluentBundle>>,
+        fallback_bundle: LazyFallbackBundle,
+    ) -> SarifEmitter {
+        SarifEmitter::new(
+            Box::new(io::BufWriter::new(io::stderr())),
+            source_map,
+            fluent_bundle,
+            fallback_bundle,
+        )
+    }
+
+    /// Creates an emitter for early errors, which only prints a log if anything was emitted.
+    pub fn basic(
+        fluent_bundle: Option<Lrc<FluentBundle>>,
+        fallback_bundle: LazyFallbackBundle,
+    ) -> SarifEmitter {
+        let file_path_mapping = FilePathMapping::empty();
+        let mut emitter = SarifEmitter::stderr(
+            Lrc::new(SourceMap::new(file_path_mapping)),
+            fluent_bundle,
+            fallback_bundle,
+        );
+        emitter.always_write = false;
+        emitter
+    }
+
+    pub fn new(
+        dst: Box<dyn Write + Send>,
+        source_map: Lrc<SourceMap>,
+        fluent_bundle: Option<Lrc<FluentBundle>>,
+        fallback_bundle: LazyFallbackBundle,
+    ) -> SarifEmitter {
+        SarifEmitter {
+            dst: IntoDynSyncSend(dst),
+            sm: source_map,
+            fluent_bundle,
+            fallback_bundle,
+            always_write: true,
+            rule_indices: Default::default(),
+            rules: Vec::new(),
+            results: Vec::new(),
+        }
+    }
+
+    fn rule_index(&mut self, id: String, is_error_code: bool) -> usize {
+        let rules = &mut self.rules;
+        *self.rule_indices.entry(id).or_insert_with_key(|id| {
+            rules.push(ReportingDescriptor {
+                help_uri: is_error_code
+                    .then(|| format!("https://doc.rust-lang.org/error_codes/{id}.html")),
+                id: id.clone(),
+            });
+            rules.len() - 1
+        })
+    }
+
+    fn physical_location(&self, span: Span) -> Option<PhysicalLocation> {
+        if span.is_dummy() {
+            return None;
+        }
+        let start = self.sm.lookup_char_pos(span.lo());
+        let end = self.sm.lookup_char_pos(span.hi());
+        Some(PhysicalLocation {
+            artifact_location: ArtifactLocation {
+                uri: self
+                    .sm
+                    .filename_for_diagnostics(&start.file.name)
+                    .to_string()
+                    .replace('\\', "/"),
+            },
+            region: Region {
+                start_line: start.line,
+                start_column: start.col.0 + 1,
+                end_line: end.line,
+                end_column: end.col.0 + 1,
+            },
+        })
+    }
+
+    /// Splits the labelled spans of `msp` into the primary locations of a result and
+    /// its related locations.
+    fn locations(
+        &self,
+        msp: &MultiSpan,
+        args: &FluentArgs<'_>,
+        locations: &mut Vec<Location>,
+        related_locations: &mut Vec<Location>,
+    ) {
+        for span_label in msp.span_labels() {
+            let Some(physical_location) = self.physical_location(span_label.span) else {
+                continue;
+            };
+            let message = span_label.label.as_ref().map(|label| Message {
+                text: self.translate_message(label, args).unwrap().to_string(),
+            });
+            let location = Location { physical_location, message };
+            if span_label.is_primary {
+                locations.push(location);
+            } else if location.message.is_some() {
+                related_locations.push(location);
+            }
+        }
+    }
+
+    fn write_log(&mut self) -> io::Result<()> {
+        let log = SarifLog {
+            schema: "https://json.schemastore.org/sarif-2.1.0.json",
+            version: "2.1.0",
+            runs: [Run {
+                tool: Tool {
+                    driver: ToolComponent {
+                        name: "rustc",
+                        information_uri: "https://www.rust-lang.org/",
+                        rules: &self.rules,
+                    },
+                },
+                column_kind: "unicodeCodePoints",
+                results: &self.results,
+            }],
+        };
+        serde_json::to_writer(&mut *self.dst, &log)?;
+        self.dst.write_all(b"\n")?;
+        self.dst.flush()
+    }
+}
+
+impl Drop for SarifEmitter {
+    fn drop(&mut self) {
+        if !self.always_write && self.results.is_empty() {
+            return;
+        }
+        if let Err(e) = self.write_log()
+            && !std::thread::panicking()
+        {
+            panic!("failed to print diagnostics: {e:?}");
+        }
+    }
+}
+
+impl Translate for SarifEmitter {
+    fn fluent_bundle(&self) -> Option<&Lrc<FluentBundle>> {
+        self.fluent_bundle.as_ref()
+    }
+
+    fn fallback_fluent_bundle(&self) -> &FluentBundle {
+        &self.fallback_bundle
+    }
+}
+
+impl Emitter for SarifEmitter {
+    fn emit_diagnostic(&mut self, diag: crate::Diagnostic) {
+        let level = match diag.level {
+            Level::Bug | Level::DelayedBug | Level::Fatal | Level::Error => "error",
+            Level::ForceWarning(_) | Level::Warning => "warning",
+            Level::Note | Level::OnceNote | Level::Help | Level::OnceHelp => "note",
+            // `For more information about this error...` and the like aren't results.
+            Level::FailureNote => return,
+            Level::Allow | Level::Expect(_) => unreachable!(),
+        };
+        let args = to_fluent_args(diag.args());
+
+        let mut text = self.translate_messages(&diag.messages, &args).into_owned();
+        let mut locations = vec![];
+        let mut related_locations = vec![];
+        self.locations(&diag.span, &args, &mut locations, &mut related_locations);
+        // Notes and helps don't have a SARIF equivalent, so they are appended to the
+        // message, and whatever they point at becomes a related location.
+        for child in &diag.children {
+            let child_text = self.translate_messages(&child.messages, &args);
+            text.push_str(&format!("\n{}: {child_text}", child.level.to_str()));
+            let mut child_locations = vec![];
+            self.locations(&child.span, &args, &mut child_locations, &mut related_locations);
+            related_locations.extend(child_locations.into_iter().map(|location| Location {
+                message: Some(Message { text: child_text.to_string() }),
+                ..location
+            }));
+        }
+
+        let fixes = diag
+            .suggestions
+            .iter()
+            .flatten()
+            .filter(|sugg| sugg.applicability == Applicability::MachineApplicable)
+            .flat_map(|sugg| {
+                let description = self.translate_message(&sugg.msg, &args).unwrap().to_string();
+                sugg.substitutions
+                    .iter()
+                    .map(move |substitution| (description.clone(), substitution))
+            })
+            .filter_map(|(description, substitution)| {
+                let mut artifact_changes: Vec<ArtifactChange> = vec![];
+                for part in &substitution.parts {
+                    let PhysicalLocation { artifact_location, region } =
+                        self.physical_location(part.span)?;
+                    let replacement = Replacement {
+                        deleted_region: region,
+                        inserted_content: Message { text: part.snippet.clone() },
+                    };
+                    match artifact_changes
+                        .iter_mut()
+                        .find(|change| change.artifact_location.uri == artifact_location.uri)
+                    {
+                        Some(change) => change.replacements.push(replacement),
+                        None => artifact_changes.push(ArtifactChange {
+                            artifact_location,
+                            replacements: vec![replacement],
+                        }),
+                    }
+                }
+                Some(Fix { description: Message { text: description }, artifact_changes })
+            })
+            .collect();
+
+        let rule = if let Some(code) = diag.code {
+            Some((code.to_string(), true))
+        } else if let Some(IsLint { name, .. }) = &diag.is_lint {
+            Some((name.clone(), false))
+        } else {
+            None
+        };
+        let (rule_id, rule_index) = match rule {
+            Some((id, is_error_code)) => {
+                let index = self.rule_index(id.clone(), is_error_code);
+                (Some(id), Some(index))
+            }
+            None => (None, None),
+        };
+
+        self.results.push(SarifResult {
+            rule_id,
+            rule_index,
+            level,
+            message: Message { text },
+            locations,
+            related_locations,
+            fixes,
+        });
+    }
+
+    fn source_map(&self) -> Option<&Lrc<SourceMap>> {
+        Some(&self.sm)
+    }
+
+    fn should_show_explain(&self) -> bool {
+        false
+    }
+}
+
+// The following data types are provided just for serialisation, and are named after
+// the SARIF objects they represent.
+
+#[derive(Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [Run<'a>; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run<'a> {
+    tool: Tool<'a>,
+    /// How the columns of regions are counted.
+    column_kind: &'static str,
+    results: &'a [SarifResult],
+}
+
+#[derive(Serialize)]
+struct Tool<'a> {
+    driver: ToolComponent<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ToolComponent<'a> {
+    name: &'static str,
+    information_uri: &'static str,
+    rules: &'a [ReportingDescriptor],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReportingDescriptor {
+    /// The error code (e.g. "E1234") or the lint name.
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    /// "error", "warning" or "note".
+    level: &'static str,
+    message: Message,
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<Message>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    /// 1-based.
+    start_line: usize,
+    /// 1-based, in characters.
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    description: Message,
+    artifact_changes: Vec<ArtifactChange>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: Region,
+    inserted_content: Message,
+}
@@ -319,6 +319,10 @@ pub struct BuiltinAttribute {
         deny, Normal, template!(List: r#"lint1, lint2, ..., /*opt*/ reason = "...""#),
         DuplicatesOk, @only_local: true,
     ),
+    gated!(
+        lint_config, CrateLevel, template!(List: "lint_name::option = value, ..."), DuplicatesOk,
+        experimental!(lint_config)
+    ),
     ungated!(must_use, Normal, template!(Word, NameValueStr: "reason"), FutureWarnFollowing),
     gated!(
         must_not_suspend, Normal, template!(Word, NameValueStr: "reason"), WarnFollowing,
@@ -362,6 +366,10 @@ pub struct BuiltinAttribute {
         move_size_limit, CrateLevel, template!(NameValueStr: "N"), ErrorFollowing,
         large_assignments, experimental!(move_size_limit)
     ),
+    gated!(
+        future_size_limit, CrateLevel, template!(NameValueStr: "N"), ErrorFollowing,
+        experimental!(future_size_limit)
+    ),
 
     // Entry point:
     gated!(unix_sigpipe, Normal, template!(Word, NameValueStr: "inherit|sig_ign|sig_dfl"), ErrorFollowing, experimental!(unix_sigpipe)),
@@ -429,6 +437,18 @@ pub struct BuiltinAttribute {
         marker, Normal, template!(Word), WarnFollowing, @only_local: true,
         marker_trait_attr, experimental!(marker)
     ),
+    gated!(
+        coinductive, Normal, template!(Word), WarnFollowing, @only_local: true,
+        coinductive_traits, experimental!(coinductive)
+    ),
+    gated!(
+        view, Normal, template!(List: "field1, field2, ..."), ErrorFollowing, @only_local: true,
+        view_types, experimental!(view)
+    ),
+    gated!(
+        rustc_pure_deref, Normal, template!(Word), WarnFollowing,
+        deref_patterns_borrowck, experimental!(rustc_pure_deref)
+    ),
     gated!(
         thread_local, Normal, template!(Word), WarnFollowing,
         "`#[thread_local]` is an experimental feature, and does not currently handle destructors",
@@ -211,6 +211,9 @@ pub fn internal(&self, feature: Symbol) -> bool {
     (unstable, multiple_supertrait_upcastable, "1.69.0", None),
     /// Allow negative trait bounds. This is an internal-only feature for testing the trait solver!
     (internal, negative_bounds, "1.71.0", None),
+    /// Allows negative impls to rule out impl candidates in trait selection, and to
+    /// show impls disjoint in coherence.
+    (unstable, negative_bounds_coherence, "CURRENT_RUSTC_VERSION", None),
     /// Allows using `#[omit_gdb_pretty_printer_section]`.
     (internal, omit_gdb_pretty_printer_section, "1.5.0", None),
     /// Allows using `#[prelude_import]` on glob `use` items.
@@ -365,6 +368,8 @@ pub fn internal(&self, feature: Symbol) -> bool {
     (unstable, c_unwind, "1.52.0", Some(74990)),
     /// Allows using C-variadics.
     (unstable, c_variadic, "1.34.0", Some(44930)),
+    /// Allows closures to capture disjoint elements of an array indexed by integer literals.
+    (unstable, capture_disjoint_array_elements, "CURRENT_RUSTC_VERSION", None),
     /// Allows the use of `#[cfg(overflow_checks)` to check if integer overflow behaviour.
     (unstable, cfg_overflow_checks, "1.71.0", Some(111466)),
     /// Provides the relocation model information as cfg entry
@@ -393,6 +398,8 @@ pub fn internal(&self, feature: Symbol) -> bool {
     (unstable, closure_track_caller, "1.57.0", Some(87417)),
     /// Allows to use the `#[cmse_nonsecure_entry]` attribute.
     (unstable, cmse_nonsecure_entry, "1.48.0", Some(75835)),
+    /// Allows `#[coinductive]` on traits, letting the trait solver accept cycles through them.
+    (incomplete, coinductive_traits, "CURRENT_RUSTC_VERSION", None),
     /// Allows use of the `#[collapse_debuginfo]` attribute.
     (unstable, collapse_debuginfo, "1.65.0", Some(100758)),
     /// Allows `async {}` expressions in const contexts.
@@ -438,6 +445,11 @@ pub fn internal(&self, feature: Symbol) -> bool {
     (unstable, deprecated_safe, "1.61.0", Some(94978)),
     /// Allows having using `suggestion` in the `#[deprecated]` attribute.
     (unstable, deprecated_suggestion, "1.61.0", Some(94785)),
+    /// Allows `#[rustc_pure_deref]` on smart pointers, making borrowck see through their `Deref`
+    /// and `DerefMut` impls as if they were reborrows of the pointer they wrap.
+    (unstable, deref_patterns_borrowck, "CURRENT_RUSTC_VERSION", None),
+    /// Allows `#[diagnostic::blocking]` on functions that block the current thread.
+    (unstable, diagnostic_blocking, "CURRENT_RUSTC_VERSION", None),
     /// Allows using the `#[diagnostic]` attribute tool namespace
     (unstable, diagnostic_namespace, "1.73.0", Some(111996)),
     /// Controls errors in trait implementations.
@@ -473,6 +485,8 @@ pub fn internal(&self, feature: Symbol) -> bool {
     (unstable, fn_align, "1.53.0", Some(82232)),
     /// Support delegating implementation of functions to other already implemented functions.
     (incomplete, fn_delegation, "1.76.0", Some(118212)),
+    /// Allows setting the threshold for the `large_future` lint.
+    (unstable, future_size_limit, "CURRENT_RUSTC_VERSION", None),
     /// Allows defining gen blocks and `gen fn`.
     (unstable, gen_blocks, "1.75.0", Some(117078)),
     /// Infer generic args for both consts and types.
@@ -510,6 +524,8 @@ pub fn internal(&self, feature: Symbol) -> bool {
     /// Allows using `#[link(kind = "link-arg", name = "...")]`
     /// to pass custom arguments to the linker.
     (unstable, link_arg_attribute, "1.76.0", Some(99427)),
+    /// Allows setting the options of lints with `#![lint_config(lint::option = value)]`.
+    (unstable, lint_config, "CURRENT_RUSTC_VERSION", None),
     /// Allows using `reason` in lint attributes and the `#[expect(lint)]` lint check.
     (unstable, lint_reasons, "1.31.0", Some(54503)),
     /// Give access to additional metadata about declarative macro meta-variables.
@@ -541,6 +557,8 @@ pub fn internal(&self, feature: Symbol) -> bool {
     (unstable, never_type_fallback, "1.41.0", Some(65992)),
     /// Allows `#![no_core]`.
     (unstable, no_core, "1.3.0", Some(29639)),
+    /// Allows `#[repr(no_niche)]`, which hides the niches of a type from enclosing types.
+    (unstable, no_niche, "CURRENT_RUSTC_VERSION", None),
     /// Allows the use of `no_sanitize` attribute.
     (unstable, no_sanitize, "1.42.0", Some(39699)),
     /// Allows using the `non_exhaustive_omitted_patterns` lint.
@@ -619,6 +637,8 @@ pub fn internal(&self, feature: Symbol) -> bool {
     (unstable, unsized_tuple_coercion, "1.20.0", Some(42877)),
     /// Allows using the `#[used(linker)]` (or `#[used(compiler)]`) attribute.
     (unstable, used_with_arg, "1.60.0", Some(93798)),
+    /// Allows `#[view(..)]` on methods to declare the fields of `self` they borrow.
+    (unstable, view_types, "CURRENT_RUSTC_VERSION", None),
     /// Allows `extern "wasm" fn`
     (unstable, wasm_abi, "1.53.0", Some(83788)),
     /// Allows `do yeet` expressions
@@ -163,6 +163,19 @@ pub fn suggestion_position(&self) -> (LifetimeSuggestionPosition, Span) {
             (LifetimeSuggestionPosition::Normal, self.ident.span)
         }
     }
+
+    /// The span and the snippet to replace it with, to write this lifetime as `new_lifetime`.
+    pub fn suggestion(&self, new_lifetime: &str) -> (Span, String) {
+        let (pos, span) = self.suggestion_position();
+        let sugg = match pos {
+            LifetimeSuggestionPosition::Normal => new_lifetime.to_string(),
+            LifetimeSuggestionPosition::Ampersand => format!("{new_lifetime} "),
+            LifetimeSuggestionPosition::ElidedPath => format!("<{new_lifetime}>"),
+            LifetimeSuggestionPosition::ElidedPathArgument => format!("{new_lifetime}, "),
+            LifetimeSuggestionPosition::ObjectDefault => format!("+ {new_lifetime}"),
+        };
+        (span, sugg)
+    }
 }
 
 /// A `Path` is essentially Rust's notion of a name; for instance,
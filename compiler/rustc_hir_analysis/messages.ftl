@@ -51,6 +51,10 @@ hir_analysis_cannot_capture_late_bound_ty =
 
 hir_analysis_cast_thin_pointer_to_fat_pointer = cannot cast thin pointer `{$expr_ty}` to fat pointer `{$cast_ty}`
 
+hir_analysis_cast_thin_pointer_to_fat_pointer_dyn_sugg = cast through a reference to `{$pointee}` to create the trait object pointer
+
+hir_analysis_cast_thin_pointer_to_fat_pointer_slice_sugg = use `std::ptr::{$func}` to create a slice pointer from a thin pointer and a length
+
 hir_analysis_closure_implicit_hrtb = implicit types in closure signatures are forbidden when `for<...>` is present
     .label = `for<...>` is here
 
@@ -65,6 +69,15 @@ hir_analysis_coercion_between_struct_same_note = expected coercion between the s
 
 hir_analysis_coercion_between_struct_single_note = expected a single field to be coerced, none found
 
+hir_analysis_coinductive_trait_assoc_item = coinductive traits cannot have associated items
+    .label = the trait is made coinductive here
+
+hir_analysis_coinductive_trait_supertraits = coinductive traits cannot have supertraits
+    .label = the trait is made coinductive here
+
+hir_analysis_coinductive_trait_where_clauses = coinductive traits cannot have where-clauses
+    .label = the trait is made coinductive here
+
 hir_analysis_const_bound_for_non_const_trait =
     `{$modifier}` can only be applied to `#[const_trait]` traits
 
@@ -88,6 +101,9 @@ hir_analysis_copy_impl_on_type_with_dtor =
     the trait `Copy` cannot be implemented for this type; the type has a destructor
     .label = `Copy` not allowed on types with destructors
 
+hir_analysis_couldnt_dump_intercrate_ambiguities =
+    unexpected error occurred while dumping intercrate ambiguities: {$error}
+
 hir_analysis_cross_crate_traits = cross-crate traits with a default impl, like `{$traits}`, can only be implemented for a struct/enum type, not `{$self_ty}`
     .label = can't implement cross-crate trait with a default impl for non-struct/enum type
 
@@ -327,6 +343,10 @@ hir_analysis_pass_to_variadic_function = can't pass `{$ty}` to variadic function
 hir_analysis_placeholder_not_allowed_item_signatures = the placeholder `_` is not allowed within types on item signatures for {$kind}
     .label = not allowed in type signatures
 
+hir_analysis_relaxed_bound_in_trait_object = `?Trait` is not permitted in trait object types
+    .label = this trait alias relaxes `{$trait_name}`
+    .relaxed_label = `{$trait_name}` is relaxed here
+
 hir_analysis_requires_note = the `{$trait_name}` impl for `{$ty}` requires that `{$error_predicate}`
 
 hir_analysis_return_type_notation_equality_bound =
@@ -381,13 +401,13 @@ hir_analysis_static_mut_ref = creating a {$shared} reference to a mutable static
         [shared] this shared reference has lifetime `'static`, but if the static ever gets mutated, or a mutable reference is created, then any further use of this shared reference is Undefined Behavior
         *[mutable] this mutable reference has lifetime `'static`, but if the static gets accessed (read or written) by any other means, or any other reference is created, then any further use of this mutable reference is Undefined Behavior
     }
-    .suggestion = use `addr_of!` instead to create a raw pointer
-    .suggestion_mut = use `addr_of_mut!` instead to create a raw pointer
+    .suggestion = use `addr_of!` to create a raw pointer and borrow through it instead
+    .suggestion_mut = use `addr_of_mut!` to create a raw pointer and borrow through it instead
 
 hir_analysis_static_mut_refs_lint = creating a {$shared} reference to mutable static is discouraged
     .label = {$shared} reference to mutable static
-    .suggestion = use `addr_of!` instead to create a raw pointer
-    .suggestion_mut = use `addr_of_mut!` instead to create a raw pointer
+    .suggestion = use `addr_of!` to create a raw pointer and borrow through it instead
+    .suggestion_mut = use `addr_of_mut!` to create a raw pointer and borrow through it instead
     .note = this will be a hard error in the 2024 edition
     .why_note = {$shared ->
         [shared] this shared reference has lifetime `'static`, but if the static ever gets mutated, or a mutable reference is created, then any further use of this shared reference is Undefined Behavior
@@ -396,6 +416,8 @@ hir_analysis_static_mut_refs_lint = creating a {$shared} reference to mutable st
 
 hir_analysis_static_specialize = cannot specialize on `'static` lifetime
 
+hir_analysis_sync_unsafe_cell_sugg = alternatively, make `{$var}` a `SyncUnsafeCell` and access it through `SyncUnsafeCell::get`, updating its other uses the same way
+
 hir_analysis_tait_forward_compat = item constrains opaque type that is not in its signature
     .note = this item must mention the opaque type in its signature in order to be able to register hidden types
 
@@ -497,6 +519,14 @@ hir_analysis_variadic_function_compatible_convention = C-variadic function must
 
 hir_analysis_variances_of = {$variances_of}
 
+hir_analysis_view_expected_field = expected the name of a field of `self`
+
+hir_analysis_view_invalid_receiver = `#[view]` can only be applied to methods taking `self` by reference to a struct
+    .label = `self` is not a reference to a struct
+
+hir_analysis_view_unknown_field = no field `{$field}` on type `{$ty}`
+    .label = unknown field
+
 hir_analysis_where_clause_on_main = `main` function is not allowed to have a `where` clause
     .label = `main` cannot have a `where` clause
 
@@ -3,15 +3,16 @@
 use rustc_hir as hir;
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::def_id::{DefId, LocalDefId};
-use rustc_middle::ty::{self as ty, Ty};
+use rustc_middle::ty::{self as ty, Ty, TyCtxt};
 use rustc_span::symbol::Ident;
-use rustc_span::{ErrorGuaranteed, Span};
+use rustc_span::{ErrorGuaranteed, Span, DUMMY_SP};
 use rustc_trait_selection::traits;
 use smallvec::SmallVec;
 
 use crate::astconv::{AstConv, OnlySelfBounds, PredicateFilter};
 use crate::bounds::Bounds;
 use crate::errors;
+use crate::structured_errors;
 
 impl<'tcx> dyn AstConv<'tcx> + '_ {
     /// Sets `implicitly_sized` to true on `Bounds` if necessary
@@ -27,6 +28,7 @@ pub(crate) fn add_implicitly_sized(
         let sized_def_id = tcx.lang_items().sized_trait();
         let mut seen_negative_sized_bound = false;
         let mut seen_positive_sized_bound = false;
+        let mut seen_sized_unbound_in_alias = false;
 
         // Try to find an unbound in bounds.
         let mut unbounds: SmallVec<[_; 1]> = SmallVec::new();
@@ -45,10 +47,17 @@ pub(crate) fn add_implicitly_sized(
                         }
                     }
                     hir::TraitBoundModifier::None => {
-                        if let Some(sized_def_id) = sized_def_id
-                            && ptr.trait_ref.path.res == Res::Def(DefKind::Trait, sized_def_id)
-                        {
-                            seen_positive_sized_bound = true;
+                        let Some(sized_def_id) = sized_def_id else { continue };
+                        match ptr.trait_ref.path.res {
+                            Res::Def(DefKind::Trait, def_id) if def_id == sized_def_id => {
+                                seen_positive_sized_bound = true;
+                            }
+                            Res::Def(DefKind::TraitAlias, alias_def_id)
+                                if trait_alias_relaxes(tcx, alias_def_id, sized_def_id) =>
+                            {
+                                seen_sized_unbound_in_alias = true;
+                            }
+                            _ => {}
                         }
                     }
                     _ => {}
@@ -67,12 +76,14 @@ pub(crate) fn add_implicitly_sized(
         }
 
         if unbounds.len() > 1 {
-            tcx.dcx().emit_err(errors::MultipleRelaxedDefaultBounds {
+            let mut err = tcx.dcx().create_err(errors::MultipleRelaxedDefaultBounds {
                 spans: unbounds.iter().map(|ptr| ptr.span).collect(),
             });
+            structured_errors::teach(tcx.sess, &mut err);
+            err.emit();
         }
 
-        let mut seen_sized_unbound = false;
+        let mut seen_sized_unbound = seen_sized_unbound_in_alias;
         for unbound in unbounds {
             if let Some(sized_def_id) = sized_def_id
                 && unbound.trait_ref.path.res == Res::Def(DefKind::Trait, sized_def_id)
@@ -519,3 +530,18 @@ pub(super) fn add_predicates_for_ast_type_binding(
         Ok(())
     }
 }
+
+/// Returns whether using the trait alias `alias_def_id` as a bound relaxes the
+/// trait `relaxed_def_id`, e.g. `Sized` for `T: Alias` with `trait Alias = Foo + ?Sized;`.
+fn trait_alias_relaxes(tcx: TyCtxt<'_>, alias_def_id: DefId, relaxed_def_id: DefId) -> bool {
+    let relaxes = |relaxed_bounds: &[(DefId, Span)]| {
+        relaxed_bounds.iter().any(|&(def_id, _)| def_id == relaxed_def_id)
+    };
+    // An alias might only consist of relaxed bounds, in which case its expansion is empty.
+    relaxes(tcx.trait_alias_relaxed_bounds(alias_def_id))
+        || traits::expand_trait_aliases(
+            tcx,
+            [(ty::Binder::dummy(ty::TraitRef::identity(tcx, alias_def_id)), DUMMY_SP)].into_iter(),
+        )
+        .any(|info| info.relaxes(relaxed_def_id))
+}
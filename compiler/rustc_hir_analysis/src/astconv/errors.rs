@@ -20,7 +20,9 @@
 use rustc_span::edit_distance::find_best_match_for_name;
 use rustc_span::symbol::{sym, Ident};
 use rustc_span::{Span, Symbol, DUMMY_SP};
-use rustc_trait_selection::traits::object_safety_violations_for_assoc_item;
+use rustc_trait_selection::traits::{
+    explain_object_safety_violation, object_safety_violations_for_assoc_item,
+};
 
 impl<'o, 'tcx> dyn AstConv<'tcx> + 'o {
     /// On missing type parameters, emit an E0393 error and provide a structured suggestion using
@@ -626,7 +628,14 @@ pub(crate) fn complain_about_missing_associated_types(
                 let violations =
                     object_safety_violations_for_assoc_item(tcx, trait_def_id, *assoc_item);
                 if !violations.is_empty() {
-                    report_object_safety_error(tcx, *span, None, trait_def_id, &violations).emit();
+                    let explanations: Vec<_> = violations
+                        .into_iter()
+                        .map(|violation| {
+                            explain_object_safety_violation(tcx, trait_def_id, violation)
+                        })
+                        .collect();
+                    report_object_safety_error(tcx, *span, None, trait_def_id, &explanations)
+                        .emit();
                     object_safety_violations = true;
                 }
             }
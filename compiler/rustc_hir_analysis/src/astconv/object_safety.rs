@@ -1,6 +1,6 @@
 use crate::astconv::{GenericArgCountMismatch, GenericArgCountResult, OnlySelfBounds};
 use crate::bounds::Bounds;
-use crate::errors::TraitObjectDeclaredWithNoTraits;
+use crate::errors::{RelaxedBoundInTraitObject, TraitObjectDeclaredWithNoTraits};
 use rustc_data_structures::fx::{FxHashSet, FxIndexMap, FxIndexSet};
 use rustc_errors::{codes::*, struct_span_code_err};
 use rustc_hir as hir;
@@ -80,12 +80,37 @@ pub(super) fn conv_object_ty_poly_trait_ref(
 
         // Expand trait aliases recursively and check that only one regular (non-auto) trait
         // is used and no 'maybe' bounds are used.
-        let expanded_traits =
+        let mut expanded_traits =
             traits::expand_trait_aliases(tcx, trait_bounds.iter().map(|&(a, b)| (a, b)));
 
         let (mut auto_traits, regular_traits): (Vec<_>, Vec<_>) = expanded_traits
+            .by_ref()
             .filter(|i| i.trait_ref().self_ty().skip_binder() == dummy_self)
             .partition(|i| tcx.trait_is_auto(i.trait_ref().def_id()));
+        if let Some(guar) = expanded_traits.tainted_by_errors() {
+            self.set_tainted_by_errors(guar);
+            return Ty::new_error(tcx, guar);
+        }
+
+        // Trait objects are never `Sized`, so a trait alias relaxing `Sized` doesn't
+        // change anything. Relaxing any other trait is as meaningless as `dyn Trait + ?Trait`.
+        let sized_def_id = tcx.lang_items().sized_trait();
+        let relaxed_bounds: FxIndexSet<_> = regular_traits
+            .iter()
+            .chain(&auto_traits)
+            .flat_map(|info| {
+                info.relaxed_bounds.iter().map(|&(def_id, span)| (def_id, span, info.bottom().1))
+            })
+            .filter(|&(def_id, ..)| Some(def_id) != sized_def_id)
+            .collect();
+        for (def_id, relaxed_span, span) in relaxed_bounds {
+            let reported = tcx.dcx().emit_err(RelaxedBoundInTraitObject {
+                span,
+                relaxed_span,
+                trait_name: tcx.def_path_str(def_id),
+            });
+            self.set_tainted_by_errors(reported);
+        }
         if regular_traits.len() > 1 {
             let first_trait = &regular_traits[0];
             let additional_trait = &regular_traits[1];
@@ -135,15 +160,21 @@ trait here instead: `trait NewTrait: {} {{}}`",
         // most importantly, that the supertraits don't contain `Self`,
         // to avoid ICEs.
         for item in &regular_traits {
-            let object_safety_violations =
-                astconv_object_safety_violations(tcx, item.trait_ref().def_id());
+            let trait_def_id = item.trait_ref().def_id();
+            let object_safety_violations = astconv_object_safety_violations(tcx, trait_def_id);
             if !object_safety_violations.is_empty() {
+                let explanations: Vec<_> = object_safety_violations
+                    .into_iter()
+                    .map(|violation| {
+                        traits::explain_object_safety_violation(tcx, trait_def_id, violation)
+                    })
+                    .collect();
                 let reported = report_object_safety_error(
                     tcx,
                     span,
                     Some(hir_id),
-                    item.trait_ref().def_id(),
-                    &object_safety_violations,
+                    trait_def_id,
+                    &explanations,
                 )
                 .emit();
                 return Ty::new_error(tcx, reported);
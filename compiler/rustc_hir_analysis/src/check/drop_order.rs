@@ -0,0 +1,275 @@
+//! Computes the `DropOrder` of a body: which values are dropped at the end of which scope, and
+//! in which order.
+//!
+//! Bindings are dropped at the end of the scope recorded for them by region resolution, and
+//! temporaries at the end of the temporary scope recorded for them during typeck (which takes
+//! temporary lifetime extension into account). Both are collected in evaluation order, so that
+//! the values dropped at the end of a given scope can then simply be listed in reverse.
+
+use rustc_data_structures::fx::{FxHashSet, FxIndexMap};
+use rustc_hir as hir;
+use rustc_hir::def_id::{DefId, LOCAL_CRATE};
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_hir::HirId;
+use rustc_middle::middle::drop_order::{DropOrder, ScopeDrops, ScopedDrop, ScopedDropKind};
+use rustc_middle::middle::region::{Scope, ScopeTree};
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::{self, Ty, TyCtxt, TypeVisitableExt};
+use rustc_span::Span;
+
+use std::cmp::Reverse;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+/// Per-body `DropOrder`. The `DefId` should be the owner `DefId` for the body;
+/// in the case of closures, this will be redirected to the enclosing function.
+pub fn drop_order(tcx: TyCtxt<'_>, def_id: DefId) -> &DropOrder<'_> {
+    let typeck_root_def_id = tcx.typeck_root_def_id(def_id);
+    if typeck_root_def_id != def_id {
+        return tcx.drop_order(typeck_root_def_id);
+    }
+
+    let local_def_id = def_id.expect_local();
+    let Some(body_id) = tcx.hir().maybe_body_owned_by(local_def_id) else {
+        return tcx.arena.alloc(DropOrder::default());
+    };
+
+    let scope_tree = tcx.region_scope_tree(def_id);
+    let mut collector = DropCollector {
+        tcx,
+        param_env: tcx.param_env(def_id),
+        typeck_results: tcx.typeck(local_def_id),
+        scope_tree,
+        temporaries: Default::default(),
+        drops: Default::default(),
+    };
+    collector.visit_body(tcx.hir().body(body_id));
+
+    let source_map = tcx.sess.source_map();
+    let mut scopes: Vec<_> = collector
+        .drops
+        .into_iter()
+        .map(|(scope, mut drops)| {
+            // Values are collected in the order they are created, and dropped in reverse.
+            drops.reverse();
+            let span = scope.span(tcx, scope_tree);
+            ScopeDrops { scope, span: source_map.end_point(span), drops }
+        })
+        .collect();
+    // Of two scopes ending at the same point, the innermost one is left first.
+    scopes.sort_by_key(|scope| (scope.span.hi(), Reverse(scope.scope.span(tcx, scope_tree).lo())));
+
+    tcx.arena.alloc(DropOrder { scopes })
+}
+
+struct DropCollector<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    typeck_results: &'a ty::TypeckResults<'tcx>,
+    scope_tree: &'a ScopeTree,
+    /// Expressions whose value is stored in a temporary, which is recorded once the whole
+    /// expression has been visited.
+    temporaries: FxHashSet<HirId>,
+    drops: FxIndexMap<Scope, Vec<ScopedDrop<'tcx>>>,
+}
+
+impl<'a, 'tcx> DropCollector<'a, 'tcx> {
+    fn record(
+        &mut self,
+        scope: Option<Scope>,
+        hir_id: HirId,
+        kind: ScopedDropKind,
+        span: Span,
+        ty: Ty<'tcx>,
+    ) {
+        // Values without a scope live until the end of the program, e.g. in a `static`.
+        let Some(scope) = scope else { return };
+        if ty.references_error() {
+            return;
+        }
+
+        let needs_drop = ty.needs_drop(self.tcx, self.param_env);
+        let is_significant = needs_drop && ty.has_significant_drop(self.tcx, self.param_env);
+        self.drops.entry(scope).or_default().push(ScopedDrop {
+            hir_id,
+            kind,
+            span,
+            ty,
+            needs_drop,
+            is_significant,
+        });
+    }
+
+    /// Marks `expr` as stored in a temporary if it is a value expression, i.e. if it doesn't
+    /// already denote a place.
+    fn use_as_place(&mut self, expr: &hir::Expr<'_>) {
+        if !expr.is_place_expr(|_| true) {
+            self.temporaries.insert(expr.hir_id);
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for DropCollector<'a, 'tcx> {
+    fn visit_stmt(&mut self, stmt: &'tcx hir::Stmt<'tcx>) {
+        // The value of an expression statement is dropped at the end of the statement.
+        if let hir::StmtKind::Semi(expr) = stmt.kind {
+            self.use_as_place(expr);
+        }
+        intravisit::walk_stmt(self, stmt);
+    }
+
+    fn visit_local(&mut self, local: &'tcx hir::Local<'tcx>) {
+        // As is the value of the initializer of `let _ = ...;`, which isn't moved anywhere.
+        if let hir::PatKind::Wild = local.pat.kind
+            && let Some(init) = local.init
+        {
+            self.use_as_place(init);
+        }
+        intravisit::walk_local(self, local);
+    }
+
+    fn visit_pat(&mut self, pat: &'tcx hir::Pat<'tcx>) {
+        intravisit::walk_pat(self, pat);
+
+        if let hir::PatKind::Binding(_, hir_id, ident, _) = pat.kind {
+            let scope = self.scope_tree.var_scope(hir_id.local_id);
+            let ty = self.typeck_results.node_type(hir_id);
+            self.record(scope, hir_id, ScopedDropKind::Binding(ident.name), ident.span, ty);
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        match expr.kind {
+            hir::ExprKind::AddrOf(_, _, inner)
+            | hir::ExprKind::Field(inner, _)
+            | hir::ExprKind::Index(inner, _, _)
+            | hir::ExprKind::Match(inner, _, hir::MatchSource::Normal) => {
+                self.use_as_place(inner);
+            }
+            hir::ExprKind::Unary(hir::UnOp::Deref, inner)
+                if self.typeck_results.is_method_call(expr) =>
+            {
+                self.use_as_place(inner);
+            }
+            hir::ExprKind::Let(let_expr) => self.use_as_place(let_expr.init),
+            _ => {}
+        }
+        // Method receivers and operands of overloaded operators may be auto-borrowed.
+        if self
+            .typeck_results
+            .expr_adjustments(expr)
+            .iter()
+            .any(|adjustment| matches!(adjustment.kind, Adjust::Borrow(_)))
+        {
+            self.use_as_place(expr);
+        }
+
+        intravisit::walk_expr(self, expr);
+
+        // Manually recurse over closures and inline consts, because they are the only
+        // case of nested bodies that share the parent environment.
+        if let hir::ExprKind::Closure(&hir::Closure { body, .. })
+        | hir::ExprKind::ConstBlock(hir::ConstBlock { body, .. }) = expr.kind
+        {
+            self.visit_body(self.tcx.hir().body(body));
+        }
+
+        if self.temporaries.remove(&expr.hir_id) {
+            let scope = self
+                .typeck_results
+                .rvalue_scopes
+                .temporary_scope(self.scope_tree, expr.hir_id.local_id);
+            let ty = self.typeck_results.expr_ty(expr);
+            self.record(scope, expr.hir_id, ScopedDropKind::Temporary, expr.span, ty);
+        }
+    }
+}
+
+/// Outputs the drop order of every body of the crate as JSON, to a file in the given output
+/// directory.
+///
+/// Only the values that actually need to be dropped are listed, together with where they are
+/// dropped and whether their destructor is significant.
+pub fn dump_drop_order(
+    tcx: TyCtxt<'_>,
+    output_directory: &Option<PathBuf>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let output_directory = if let Some(ref directory) = output_directory {
+        fs::create_dir_all(directory)?;
+        directory
+    } else {
+        Path::new(".")
+    };
+
+    let filename = format!("{}.drop_order.json", tcx.crate_name(LOCAL_CRATE));
+    let output_path = output_directory.join(&filename);
+    let file = File::create(&output_path)?;
+    let file = BufWriter::new(file);
+
+    #[derive(serde::Serialize)]
+    struct DroppedValue {
+        kind: &'static str,
+        name: Option<String>,
+        span: String,
+        ty: String,
+        significant: bool,
+    }
+
+    #[derive(serde::Serialize)]
+    struct ScopeDrops {
+        dropped_at: String,
+        drops: Vec<DroppedValue>,
+    }
+
+    #[derive(serde::Serialize)]
+    struct BodyDropOrder {
+        body: String,
+        scopes: Vec<ScopeDrops>,
+    }
+
+    let source_map = tcx.sess.source_map();
+    let bodies: Vec<_> = tcx
+        .hir()
+        .body_owners()
+        .filter(|&def_id| !tcx.is_typeck_child(def_id.to_def_id()))
+        .map(|def_id| {
+            let scopes = tcx
+                .drop_order(def_id)
+                .scopes
+                .iter()
+                .filter_map(|scope| {
+                    let drops: Vec<_> = scope
+                        .drops
+                        .iter()
+                        .filter(|drop| drop.needs_drop)
+                        .map(|drop| DroppedValue {
+                            kind: match drop.kind {
+                                ScopedDropKind::Binding(_) => "binding",
+                                ScopedDropKind::Temporary => "temporary",
+                            },
+                            name: match drop.kind {
+                                ScopedDropKind::Binding(name) => Some(name.to_string()),
+                                ScopedDropKind::Temporary => None,
+                            },
+                            span: source_map.span_to_embeddable_string(drop.span),
+                            ty: with_no_trimmed_paths!(drop.ty.to_string()),
+                            significant: drop.is_significant,
+                        })
+                        .collect();
+                    (!drops.is_empty()).then(|| ScopeDrops {
+                        dropped_at: source_map.span_to_embeddable_string(scope.span),
+                        drops,
+                    })
+                })
+                .collect();
+
+            BodyDropOrder { body: with_no_trimmed_paths!(tcx.def_path_str(def_id)), scopes }
+        })
+        .collect();
+
+    serde_json::to_writer(file, &bodies)?;
+
+    Ok(())
+}
@@ -1,8 +1,9 @@
 use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
 use rustc_hir_pretty::qpath_to_string;
 use rustc_lint_defs::builtin::STATIC_MUT_REFS;
 use rustc_middle::ty::TyCtxt;
-use rustc_span::Span;
+use rustc_span::{sym, Span};
 use rustc_type_ir::Mutability;
 
 use crate::errors;
@@ -13,14 +14,17 @@ pub fn maybe_expr_static_mut(tcx: TyCtxt<'_>, expr: hir::Expr<'_>) {
     let hir_id = expr.hir_id;
     if let hir::ExprKind::AddrOf(borrow_kind, m, expr) = expr.kind
         && matches!(borrow_kind, hir::BorrowKind::Ref)
-        && let Some(var) = is_path_static_mut(*expr)
+        && let Some((var, def_id)) = is_path_static_mut(*expr)
     {
+        let mutable = matches!(m, Mutability::Mut);
         handle_static_mut_ref(
             tcx,
             span,
             var,
+            def_id,
+            if mutable { "&mut " } else { "&" },
             span.edition().at_least_rust_2024(),
-            matches!(m, Mutability::Mut),
+            mutable,
             hir_id,
         );
     }
@@ -32,12 +36,15 @@ pub fn maybe_stmt_static_mut(tcx: TyCtxt<'_>, stmt: hir::Stmt<'_>) {
         && let hir::PatKind::Binding(ba, _, _, _) = loc.pat.kind
         && matches!(ba.0, rustc_ast::ByRef::Yes)
         && let Some(init) = loc.init
-        && let Some(var) = is_path_static_mut(*init)
+        && let Some((var, def_id)) = is_path_static_mut(*init)
     {
+        // The `ref` binding already takes the reference, the suggestions only replace the place.
         handle_static_mut_ref(
             tcx,
             init.span,
             var,
+            def_id,
+            "",
             loc.span.edition().at_least_rust_2024(),
             matches!(ba.1, Mutability::Mut),
             stmt.hir_id,
@@ -45,45 +52,89 @@ pub fn maybe_stmt_static_mut(tcx: TyCtxt<'_>, stmt: hir::Stmt<'_>) {
     }
 }
 
-fn is_path_static_mut(expr: hir::Expr<'_>) -> Option<String> {
+fn is_path_static_mut(expr: hir::Expr<'_>) -> Option<(String, DefId)> {
     if let hir::ExprKind::Path(qpath) = expr.kind
         && let hir::QPath::Resolved(_, path) = qpath
-        && let hir::def::Res::Def(def_kind, _) = path.res
+        && let hir::def::Res::Def(def_kind, def_id) = path.res
         && let hir::def::DefKind::Static(mt) = def_kind
         && matches!(mt, Mutability::Mut)
     {
-        return Some(qpath_to_string(&qpath));
+        return Some((qpath_to_string(&qpath), def_id));
     }
     None
 }
 
+/// Suggests turning the `static mut` into a `static` of type `SyncUnsafeCell`, if it is
+/// defined in this crate and the unstable `SyncUnsafeCell` is available.
+fn sync_unsafe_cell_sugg(
+    tcx: TyCtxt<'_>,
+    span: Span,
+    var: &str,
+    def_id: DefId,
+    prefix: &'static str,
+) -> Option<errors::StaticMutSyncUnsafeCellSugg> {
+    if !tcx.features().declared(sym::sync_unsafe_cell) {
+        return None;
+    }
+    let hir::Node::Item(item) = tcx.hir().get_if_local(def_id)? else {
+        return None;
+    };
+    let hir::ItemKind::Static(ty, Mutability::Mut, body_id) = item.kind else {
+        return None;
+    };
+    if item.span.from_expansion() {
+        return None;
+    }
+    // Remove the `mut ` between `static` and the name of the item.
+    let kw_span = item.vis_span.between(item.ident.span);
+    let snippet = tcx.sess.source_map().span_to_snippet(kw_span).ok()?;
+    if !snippet.split_whitespace().eq(["static", "mut"]) {
+        return None;
+    }
+    let mut_pos = snippet.rfind("mut")?;
+    let init = tcx.hir().body(body_id).value;
+    Some(errors::StaticMutSyncUnsafeCellSugg {
+        mut_kw: kw_span.with_lo(kw_span.lo() + rustc_span::BytePos(mut_pos as u32)),
+        ty_lo: ty.span.shrink_to_lo(),
+        ty_hi: ty.span.shrink_to_hi(),
+        init_lo: init.span.shrink_to_lo(),
+        init_hi: init.span.shrink_to_hi(),
+        reference: span,
+        prefix,
+        var: var.to_string(),
+    })
+}
+
 fn handle_static_mut_ref(
     tcx: TyCtxt<'_>,
     span: Span,
     var: String,
+    def_id: DefId,
+    prefix: &'static str,
     e2024: bool,
     mutable: bool,
     hir_id: hir::HirId,
 ) {
+    let cell_sugg = sync_unsafe_cell_sugg(tcx, span, &var, def_id, prefix);
     if e2024 {
         let (sugg, shared) = if mutable {
-            (errors::StaticMutRefSugg::Mut { span, var }, "mutable")
+            (errors::StaticMutRefSugg::Mut { span, prefix, var }, "mutable")
         } else {
-            (errors::StaticMutRefSugg::Shared { span, var }, "shared")
+            (errors::StaticMutRefSugg::Shared { span, prefix, var }, "shared")
         };
-        tcx.sess.parse_sess.dcx.emit_err(errors::StaticMutRef { span, sugg, shared });
+        tcx.sess.parse_sess.dcx.emit_err(errors::StaticMutRef { span, sugg, cell_sugg, shared });
         return;
     }
 
     let (sugg, shared) = if mutable {
-        (errors::RefOfMutStaticSugg::Mut { span, var }, "mutable")
+        (errors::RefOfMutStaticSugg::Mut { span, prefix, var }, "mutable")
     } else {
-        (errors::RefOfMutStaticSugg::Shared { span, var }, "shared")
+        (errors::RefOfMutStaticSugg::Shared { span, prefix, var }, "shared")
     };
     tcx.emit_node_span_lint(
         STATIC_MUT_REFS,
         hir_id,
         span,
-        errors::RefOfMutStatic { span, sugg, shared },
+        errors::RefOfMutStatic { span, sugg, cell_sugg, shared },
     );
 }
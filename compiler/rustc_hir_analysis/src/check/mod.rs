@@ -64,6 +64,7 @@
 
 mod check;
 mod compare_impl_item;
+mod drop_order;
 pub mod dropck;
 mod entry;
 mod errs;
@@ -73,6 +74,7 @@
 pub mod wfcheck;
 
 pub use check::check_abi;
+pub use drop_order::dump_drop_order;
 
 use std::num::NonZero;
 
@@ -104,6 +106,7 @@
 use crate::util::common::indenter;
 
 use self::compare_impl_item::collect_return_position_impl_trait_in_trait_tys;
+use self::drop_order::drop_order;
 use self::region::region_scope_tree;
 
 pub fn provide(providers: &mut Providers) {
@@ -111,6 +114,7 @@ pub fn provide(providers: &mut Providers) {
     *providers = Providers {
         adt_destructor,
         region_scope_tree,
+        drop_order,
         collect_return_position_impl_trait_in_trait_tys,
         compare_impl_const: compare_impl_item::compare_impl_const_raw,
         check_coroutine_obligations: check::check_coroutine_obligations,
@@ -1179,6 +1179,34 @@ fn check_trait(tcx: TyCtxt<'_>, item: &hir::Item<'_>) -> Result<(), ErrorGuarant
         }
     }
 
+    // Cycles through coinductive traits are always accepted, so allowing associated
+    // items would let them be defined in terms of themselves.
+    if let Some(attr) = tcx.get_attr(def_id, sym::coinductive) {
+        for associated_def_id in &*tcx.associated_item_def_ids(def_id) {
+            tcx.dcx().emit_err(errors::CoinductiveTraitAssocItem {
+                span: tcx.def_span(*associated_def_id),
+                attr_span: attr.span,
+            });
+        }
+
+        // Supertraits and where-clauses are implied by every `T: Trait` bound, but a cycle
+        // through the trait could then be used to prove them without ever checking them.
+        if let hir::ItemKind::Trait(_, _, generics, supertraits, _) = item.kind {
+            if !supertraits.is_empty() {
+                tcx.dcx().emit_err(errors::CoinductiveTraitSupertraits {
+                    spans: supertraits.iter().map(|bound| bound.span()).collect(),
+                    attr_span: attr.span,
+                });
+            }
+            if !generics.predicates.is_empty() {
+                tcx.dcx().emit_err(errors::CoinductiveTraitWhereClauses {
+                    spans: generics.predicates.iter().map(|pred| pred.span()).collect(),
+                    attr_span: attr.span,
+                });
+            }
+        }
+    }
+
     let res = enter_wf_checking_ctxt(tcx, item.span, def_id, |wfcx| {
         check_where_clauses(wfcx, item.span, def_id);
         Ok(())
@@ -72,6 +72,7 @@ pub fn provide(providers: &mut Providers) {
         explicit_predicates_of: predicates_of::explicit_predicates_of,
         super_predicates_of: predicates_of::super_predicates_of,
         implied_predicates_of: predicates_of::implied_predicates_of,
+        trait_alias_relaxed_bounds: predicates_of::trait_alias_relaxed_bounds,
         super_predicates_that_define_assoc_item:
             predicates_of::super_predicates_that_define_assoc_item,
         trait_explicit_predicates_and_bounds: predicates_of::trait_explicit_predicates_and_bounds,
@@ -85,6 +86,7 @@ pub fn provide(providers: &mut Providers) {
         collect_mod_item_types,
         is_type_alias_impl_trait,
         find_field,
+        fn_self_view,
         ..*providers
     };
 }
@@ -802,6 +804,59 @@ fn find_field(tcx: TyCtxt<'_>, (def_id, ident): (DefId, Ident)) -> Option<FieldI
     })
 }
 
+/// Resolves the fields listed in the `#[view(..)]` attribute of an inherent method, which are
+/// the only fields of `self` the method is allowed to access.
+fn fn_self_view(tcx: TyCtxt<'_>, def_id: LocalDefId) -> Option<&[FieldIdx]> {
+    let attr = tcx.get_attr(def_id, sym::view)?;
+    // Other targets are rejected when checking attributes.
+    let impl_def_id = tcx.impl_of_method(def_id.to_def_id())?;
+    if tcx.trait_id_of_impl(impl_def_id).is_some() {
+        return None;
+    }
+
+    let self_ty = tcx.type_of(impl_def_id).instantiate_identity();
+    let sig = tcx.fn_sig(def_id).instantiate_identity().skip_binder();
+    let has_self = tcx.associated_item(def_id).fn_has_self_parameter;
+    let adt_def = match (sig.inputs().first().map(|ty| ty.kind()), self_ty.ty_adt_def()) {
+        (Some(&ty::Ref(_, referent, _)), Some(adt_def))
+            if has_self && referent == self_ty && adt_def.is_struct() =>
+        {
+            adt_def
+        }
+        _ => {
+            let receiver_span = match tcx.hir_node_by_def_id(def_id).fn_decl() {
+                Some(decl) if let Some(input) = decl.inputs.first() => input.span,
+                _ => tcx.def_span(def_id),
+            };
+            tcx.dcx().emit_err(errors::ViewInvalidReceiver { attr_span: attr.span, receiver_span });
+            return None;
+        }
+    };
+
+    let variant = adt_def.non_enum_variant();
+    let mut fields = Vec::new();
+    for item in attr.meta_item_list().unwrap_or_default() {
+        let Some(ident) = item.ident().filter(|_| item.is_word()) else {
+            tcx.dcx().emit_err(errors::ViewExpectedField { span: item.span() });
+            continue;
+        };
+        match variant.fields.iter_enumerated().find(|(_, field)| field.name == ident.name) {
+            Some((idx, _)) => fields.push(idx),
+            None => {
+                tcx.dcx().emit_err(errors::ViewUnknownField {
+                    span: ident.span,
+                    field: ident.name,
+                    ty: self_ty,
+                });
+            }
+        }
+    }
+    fields.sort();
+    fields.dedup();
+
+    Some(tcx.arena.alloc_from_iter(fields))
+}
+
 #[derive(Clone, Copy)]
 struct NestedSpan {
     span: Span,
@@ -1101,6 +1156,7 @@ fn trait_def(tcx: TyCtxt<'_>, def_id: LocalDefId) -> ty::TraitDef {
 
     let is_marker = tcx.has_attr(def_id, sym::marker);
     let rustc_coinductive = tcx.has_attr(def_id, sym::rustc_coinductive);
+    let coinductive = tcx.has_attr(def_id, sym::coinductive);
     let skip_array_during_method_dispatch =
         tcx.has_attr(def_id, sym::rustc_skip_array_during_method_dispatch);
     let specialization_kind = if tcx.has_attr(def_id, sym::rustc_unsafe_specialization_marker) {
@@ -1235,12 +1291,16 @@ fn trait_def(tcx: TyCtxt<'_>, def_id: LocalDefId) -> ty::TraitDef {
         paren_sugar,
         has_auto_impl: is_auto,
         is_marker,
-        is_coinductive: rustc_coinductive || is_auto,
+        is_coinductive: rustc_coinductive || coinductive || is_auto,
         skip_array_during_method_dispatch,
         specialization_kind,
         must_implement_one_of,
         implement_via_object,
         deny_explicit_impl,
+        // Agrees with `OverlapMode::get`, which only relies on negative impls for coherence
+        // when `negative_impls` is enabled as well.
+        negative_bounds_coherence: tcx.features().negative_impls
+            && tcx.features().negative_bounds_coherence,
     }
 }
 
@@ -594,6 +594,25 @@ pub(super) fn implied_predicates_of(
     )
 }
 
+/// Returns the `?Trait` bounds of a trait alias. These are dropped when computing
+/// the [`implied_predicates_of`] the alias, but users of the alias have to know
+/// about them, e.g. to not add an implicit `Sized` bound for `T: Alias`.
+pub(super) fn trait_alias_relaxed_bounds(
+    tcx: TyCtxt<'_>,
+    alias_def_id: LocalDefId,
+) -> &[(DefId, Span)] {
+    let hir::ItemKind::TraitAlias(_, bounds) = tcx.hir().expect_item(alias_def_id).kind else {
+        span_bug!(tcx.def_span(alias_def_id), "trait_alias_relaxed_bounds invoked on non-alias");
+    };
+
+    tcx.arena.alloc_from_iter(bounds.iter().filter_map(|bound| match bound {
+        hir::GenericBound::Trait(ptr, hir::TraitBoundModifier::Maybe) => {
+            Some((ptr.trait_ref.trait_def_id()?, ptr.span))
+        }
+        _ => None,
+    }))
+}
+
 /// Ensures that the super-predicates of the trait with a `DefId`
 /// of `trait_def_id` are converted and stored. This also ensures that
 /// the transitive super-predicates are converted.
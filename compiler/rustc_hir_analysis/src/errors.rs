@@ -269,6 +269,12 @@ pub struct ConstParamTyImplOnNonAdt {
     pub span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(hir_analysis_couldnt_dump_intercrate_ambiguities)]
+pub struct CouldntDumpIntercrateAmbiguities {
+    pub error: String,
+}
+
 #[derive(Diagnostic)]
 #[diag(hir_analysis_trait_object_declared_with_no_traits, code = E0224)]
 pub struct TraitObjectDeclaredWithNoTraits {
@@ -278,6 +284,17 @@ pub struct TraitObjectDeclaredWithNoTraits {
     pub trait_alias_span: Option<Span>,
 }
 
+#[derive(Diagnostic)]
+#[diag(hir_analysis_relaxed_bound_in_trait_object)]
+pub struct RelaxedBoundInTraitObject {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    #[label(hir_analysis_relaxed_label)]
+    pub relaxed_span: Span,
+    pub trait_name: String,
+}
+
 #[derive(Diagnostic)]
 #[diag(hir_analysis_ambiguous_lifetime_bound, code = E0227)]
 pub struct AmbiguousLifetimeBound {
@@ -648,7 +665,37 @@ pub(crate) struct CastThinPointerToFatPointer<'tcx> {
     #[primary_span]
     pub span: Span,
     pub expr_ty: Ty<'tcx>,
-    pub cast_ty: String,
+    pub cast_ty: Ty<'tcx>,
+    #[subdiagnostic]
+    pub sugg: Option<CastThinPointerToFatPointerSugg<'tcx>>,
+}
+
+#[derive(Subdiagnostic)]
+pub(crate) enum CastThinPointerToFatPointerSugg<'tcx> {
+    #[multipart_suggestion(
+        hir_analysis_cast_thin_pointer_to_fat_pointer_slice_sugg,
+        applicability = "has-placeholders"
+    )]
+    Slice {
+        #[suggestion_part(code = "std::ptr::{func}(")]
+        lo: Span,
+        #[suggestion_part(code = "{cast}, /* len */)")]
+        hi: Span,
+        func: &'static str,
+        cast: String,
+    },
+    #[multipart_suggestion(
+        hir_analysis_cast_thin_pointer_to_fat_pointer_dyn_sugg,
+        applicability = "maybe-incorrect"
+    )]
+    Dyn {
+        #[suggestion_part(code = "unsafe {{ &*")]
+        lo: Span,
+        #[suggestion_part(code = " }} as &{pointee} as *const {pointee}{cast}")]
+        hi: Span,
+        pointee: Ty<'tcx>,
+        cast: String,
+    },
 }
 
 #[derive(Diagnostic)]
@@ -744,6 +791,59 @@ pub(crate) struct EnumDiscriminantOverflowed {
     pub wrapped_discr: String,
 }
 
+#[derive(Diagnostic)]
+#[diag(hir_analysis_coinductive_trait_assoc_item)]
+pub(crate) struct CoinductiveTraitAssocItem {
+    #[primary_span]
+    pub span: Span,
+    #[label]
+    pub attr_span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(hir_analysis_coinductive_trait_supertraits)]
+pub(crate) struct CoinductiveTraitSupertraits {
+    #[primary_span]
+    pub spans: Vec<Span>,
+    #[label]
+    pub attr_span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(hir_analysis_coinductive_trait_where_clauses)]
+pub(crate) struct CoinductiveTraitWhereClauses {
+    #[primary_span]
+    pub spans: Vec<Span>,
+    #[label]
+    pub attr_span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(hir_analysis_view_invalid_receiver)]
+pub(crate) struct ViewInvalidReceiver {
+    #[primary_span]
+    pub attr_span: Span,
+    #[label]
+    pub receiver_span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(hir_analysis_view_unknown_field)]
+pub(crate) struct ViewUnknownField<'tcx> {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    pub field: Symbol,
+    pub ty: Ty<'tcx>,
+}
+
+#[derive(Diagnostic)]
+#[diag(hir_analysis_view_expected_field)]
+pub(crate) struct ViewExpectedField {
+    #[primary_span]
+    pub span: Span,
+}
+
 #[derive(Diagnostic)]
 #[diag(hir_analysis_paren_sugar_attribute)]
 #[help]
@@ -1469,6 +1569,8 @@ pub struct StaticMutRef<'a> {
     pub span: Span,
     #[subdiagnostic]
     pub sugg: StaticMutRefSugg,
+    #[subdiagnostic]
+    pub cell_sugg: Option<StaticMutSyncUnsafeCellSugg>,
     pub shared: &'a str,
 }
 
@@ -1477,23 +1579,25 @@ pub enum StaticMutRefSugg {
     #[suggestion(
         hir_analysis_suggestion,
         style = "verbose",
-        code = "addr_of!({var})",
-        applicability = "maybe-incorrect"
+        code = "{prefix}*core::ptr::addr_of!({var})",
+        applicability = "machine-applicable"
     )]
     Shared {
         #[primary_span]
         span: Span,
+        prefix: &'static str,
         var: String,
     },
     #[suggestion(
         hir_analysis_suggestion_mut,
         style = "verbose",
-        code = "addr_of_mut!({var})",
-        applicability = "maybe-incorrect"
+        code = "{prefix}*core::ptr::addr_of_mut!({var})",
+        applicability = "machine-applicable"
     )]
     Mut {
         #[primary_span]
         span: Span,
+        prefix: &'static str,
         var: String,
     },
 }
@@ -1508,6 +1612,8 @@ pub struct RefOfMutStatic<'a> {
     pub span: Span,
     #[subdiagnostic]
     pub sugg: RefOfMutStaticSugg,
+    #[subdiagnostic]
+    pub cell_sugg: Option<StaticMutSyncUnsafeCellSugg>,
     pub shared: &'a str,
 }
 
@@ -1516,27 +1622,50 @@ pub enum RefOfMutStaticSugg {
     #[suggestion(
         hir_analysis_suggestion,
         style = "verbose",
-        code = "addr_of!({var})",
-        applicability = "maybe-incorrect"
+        code = "{prefix}*core::ptr::addr_of!({var})",
+        applicability = "machine-applicable"
     )]
     Shared {
         #[primary_span]
         span: Span,
+        prefix: &'static str,
         var: String,
     },
     #[suggestion(
         hir_analysis_suggestion_mut,
         style = "verbose",
-        code = "addr_of_mut!({var})",
-        applicability = "maybe-incorrect"
+        code = "{prefix}*core::ptr::addr_of_mut!({var})",
+        applicability = "machine-applicable"
     )]
     Mut {
         #[primary_span]
         span: Span,
+        prefix: &'static str,
         var: String,
     },
 }
 
+/// Turning a `static mut` into a `static` holding a `SyncUnsafeCell`, and accessing it through
+/// `SyncUnsafeCell::get` at the reference being linted.
+#[derive(Subdiagnostic)]
+#[multipart_suggestion(hir_analysis_sync_unsafe_cell_sugg, applicability = "maybe-incorrect")]
+pub struct StaticMutSyncUnsafeCellSugg {
+    #[suggestion_part(code = "")]
+    pub mut_kw: Span,
+    #[suggestion_part(code = "core::cell::SyncUnsafeCell<")]
+    pub ty_lo: Span,
+    #[suggestion_part(code = ">")]
+    pub ty_hi: Span,
+    #[suggestion_part(code = "core::cell::SyncUnsafeCell::new(")]
+    pub init_lo: Span,
+    #[suggestion_part(code = ")")]
+    pub init_hi: Span,
+    #[suggestion_part(code = "{prefix}*{var}.get()")]
+    pub reference: Span,
+    pub prefix: &'static str,
+    pub var: String,
+}
+
 #[derive(Diagnostic)]
 #[diag(hir_analysis_not_supported_delegation)]
 pub struct NotSupportedDelegation<'a> {
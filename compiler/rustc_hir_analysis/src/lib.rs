@@ -104,6 +104,7 @@
 use rustc_middle::query::Providers;
 use rustc_middle::ty::{self, Ty, TyCtxt};
 use rustc_middle::util;
+use rustc_session::config::SwitchWithOptPath;
 use rustc_session::parse::feature_err;
 use rustc_span::{symbol::sym, Span, DUMMY_SP};
 use rustc_target::spec::abi::Abi;
@@ -181,6 +182,16 @@ pub fn check_crate(tcx: TyCtxt<'_>) -> Result<(), ErrorGuaranteed> {
         // these queries are executed for side-effects (error reporting):
         let _ = tcx.ensure().crate_inherent_impls(());
         let _ = tcx.ensure().crate_inherent_impls_overlap_check(());
+
+        if let SwitchWithOptPath::Enabled(ref path) =
+            tcx.sess.opts.unstable_opts.dump_intercrate_ambiguities
+        {
+            if let Err(err) = traits::dump_intercrate_ambiguities(tcx, path) {
+                tcx.dcx().emit_fatal(errors::CouldntDumpIntercrateAmbiguities {
+                    error: err.to_string(),
+                });
+            }
+        }
         res
     })?;
 
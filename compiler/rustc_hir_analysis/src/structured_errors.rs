@@ -6,7 +6,7 @@
     missing_cast_for_variadic_arg::*, sized_unsized_cast::*, wrong_number_of_generic_args::*,
 };
 
-use rustc_errors::{DiagnosticBuilder, ErrCode};
+use rustc_errors::{codes::*, Diagnostic, DiagnosticBuilder};
 use rustc_session::Session;
 
 pub trait StructuredDiagnostic<'tcx> {
@@ -18,9 +18,21 @@ fn diagnostic(&self) -> DiagnosticBuilder<'tcx> {
         let err = self.diagnostic_common();
 
         if self.session().teach(self.code()) {
-            self.diagnostic_extended(err)
+            let mut err = self.diagnostic_extended(err);
+            explain(&mut err, self.code());
+            err
         } else {
-            self.diagnostic_regular(err)
+            let mut err = self.diagnostic_regular(err);
+            if self.session().opts.unstable_opts.json_explanations {
+                // Build the extended diagnostic on the side, and keep what it adds.
+                let extended = self.diagnostic_common();
+                let common_children = extended.children.len();
+                let mut extended = self.diagnostic_extended(extended);
+                explain(&mut extended, self.code());
+                err.teaching = extended.children.split_off(common_children);
+                extended.cancel();
+            }
+            err
         }
     }
 
@@ -34,3 +46,80 @@ fn diagnostic_extended(&self, err: DiagnosticBuilder<'tcx>) -> DiagnosticBuilder
         err
     }
 }
+
+/// The extended explanation of an error, shown the first time it is emitted
+/// with `-Zteach`.
+pub struct Teaching {
+    pub code: ErrCode,
+    /// Notes explaining what went wrong in more detail than the primary message.
+    pub notes: &'static [&'static str],
+    /// The usual ways of fixing the error, each shown as a separate help message.
+    pub fixes: &'static [&'static str],
+}
+
+/// The registry of extended explanations for errors emitted by this crate.
+///
+/// Errors not listed here only get the extra information their
+/// [`StructuredDiagnostic::diagnostic_extended`] adds, if any.
+pub static TEACHINGS: &[Teaching] = &[
+    Teaching {
+        code: E0107,
+        notes: &["every generic parameter of an item must be given an argument when the item is \
+             named, except for lifetimes and parameters with defaults, which can be omitted"],
+        fixes: &[
+            "remove the generic arguments that the item does not declare parameters for",
+            "add arguments for the generic parameters that are missing, or declare the \
+             missing parameters on the item",
+        ],
+    },
+    Teaching {
+        code: E0203,
+        notes: &["`?Sized` is the only relaxed bound, and it only removes the implicit `Sized` \
+             bound of the type parameter, so relaxing bounds more than once has no effect"],
+        fixes: &["remove all but one `?Sized` bound on the type parameter"],
+    },
+    Teaching {
+        code: E0607,
+        notes: &[
+            "thin pointers only hold a memory address, while pointers to dynamically sized \
+             types also carry metadata: the length of a slice, or the vtable of a trait object",
+            "this metadata cannot be made up by a cast, so a thin pointer cannot be cast \
+             directly to a fat pointer",
+        ],
+        fixes: &[
+            "build a slice pointer out of a thin pointer and a length with \
+             `std::ptr::slice_from_raw_parts`",
+            "build a trait object pointer by unsizing a pointer or reference to a type \
+             implementing the trait",
+        ],
+    },
+];
+
+/// Adds the registered explanation for `code`, if there is one, to `err`.
+fn explain(err: &mut Diagnostic, code: ErrCode) {
+    let Some(teaching) = TEACHINGS.iter().find(|teaching| teaching.code == code) else {
+        return;
+    };
+    for &note in teaching.notes {
+        err.note(note);
+    }
+    for &fix in teaching.fixes {
+        err.help(fix);
+    }
+}
+
+/// Adds the registered explanation for the code of `err` when `-Zteach` is
+/// enabled, or records it for `-Zjson-explanations`, for errors which are not
+/// emitted through [`StructuredDiagnostic`].
+pub fn teach(sess: &Session, err: &mut Diagnostic) {
+    let Some(code) = err.code else {
+        return;
+    };
+    if sess.teach(code) {
+        explain(err, code);
+    } else if sess.opts.unstable_opts.json_explanations {
+        let children = err.children.len();
+        explain(err, code);
+        err.teaching = err.children.split_off(children);
+    }
+}
@@ -1,14 +1,73 @@
 use crate::{errors, structured_errors::StructuredDiagnostic};
 use rustc_errors::{codes::*, DiagnosticBuilder};
-use rustc_middle::ty::{Ty, TypeVisitableExt};
+use rustc_middle::ty::{self, Ty, TypeVisitableExt};
 use rustc_session::Session;
 use rustc_span::Span;
 
 pub struct SizedUnsizedCast<'tcx> {
     pub sess: &'tcx Session,
     pub span: Span,
+    pub expr_span: Span,
     pub expr_ty: Ty<'tcx>,
-    pub cast_ty: String,
+    pub cast_ty: Ty<'tcx>,
+}
+
+impl<'tcx> SizedUnsizedCast<'tcx> {
+    /// Looks at the pointee of the target type to find a way to actually build
+    /// the fat pointer from the thin one.
+    fn suggestion(&self) -> Option<errors::CastThinPointerToFatPointerSugg<'tcx>> {
+        let ty::RawPtr(ty::TypeAndMut { ty: expr_pointee, mutbl: expr_mutbl }) =
+            *self.expr_ty.kind()
+        else {
+            return None;
+        };
+        let ty::RawPtr(ty::TypeAndMut { ty: cast_pointee, mutbl: cast_mutbl }) =
+            *self.cast_ty.kind()
+        else {
+            return None;
+        };
+        // Only the cast itself is replaced, the expression is kept as written.
+        if self.expr_span.from_expansion() || !self.span.contains(self.expr_span) {
+            return None;
+        }
+        let lo = self.span.until(self.expr_span);
+        let hi = self.span.with_lo(self.expr_span.hi());
+
+        match *cast_pointee.kind() {
+            ty::Slice(elem) => {
+                let cast = if elem == expr_pointee && expr_mutbl >= cast_mutbl {
+                    String::new()
+                } else if !elem.has_non_region_infer() {
+                    let ptr_kind = if cast_mutbl.is_mut() { "mut" } else { "const" };
+                    format!(" as *{ptr_kind} {elem}")
+                } else {
+                    return None;
+                };
+                let func = if cast_mutbl.is_mut() {
+                    "slice_from_raw_parts_mut"
+                } else {
+                    "slice_from_raw_parts"
+                };
+                Some(errors::CastThinPointerToFatPointerSugg::Slice { lo, hi, func, cast })
+            }
+            // A thin pointer to a type implementing the trait would have been unsized by
+            // coercion already, unless the cast also changes the mutability of the pointer.
+            ty::Dynamic(_, _, ty::Dyn) if !self.cast_ty.has_non_region_infer() => {
+                let cast = if cast_mutbl.is_mut() {
+                    format!(" as {}", self.cast_ty)
+                } else {
+                    String::new()
+                };
+                Some(errors::CastThinPointerToFatPointerSugg::Dyn {
+                    lo,
+                    hi,
+                    pointee: cast_pointee,
+                    cast,
+                })
+            }
+            _ => None,
+        }
+    }
 }
 
 impl<'tcx> StructuredDiagnostic<'tcx> for SizedUnsizedCast<'tcx> {
@@ -24,7 +83,8 @@ fn diagnostic_common(&self) -> DiagnosticBuilder<'tcx> {
         let mut err = self.sess.dcx().create_err(errors::CastThinPointerToFatPointer {
             span: self.span,
             expr_ty: self.expr_ty,
-            cast_ty: self.cast_ty.to_owned(),
+            cast_ty: self.cast_ty,
+            sugg: self.suggestion(),
         });
 
         if self.expr_ty.references_error() {
@@ -33,24 +93,4 @@ fn diagnostic_common(&self) -> DiagnosticBuilder<'tcx> {
 
         err
     }
-
-    fn diagnostic_extended(&self, mut err: DiagnosticBuilder<'tcx>) -> DiagnosticBuilder<'tcx> {
-        err.help(
-            "Thin pointers are \"simple\" pointers: they are purely a reference to a
-memory address.
-
-Fat pointers are pointers referencing \"Dynamically Sized Types\" (also
-called DST). DST don't have a statically known size, therefore they can
-only exist behind some kind of pointers that contain additional
-information. Slices and trait objects are DSTs. In the case of slices,
-the additional information the fat pointer holds is their size.
-
-To fix this error, don't try to cast directly between thin and fat
-pointers.
-
-For more information about casts, take a look at The Book:
-https://doc.rust-lang.org/reference/expressions/operator-expr.html#type-cast-expressions",
-        );
-        err
-    }
 }
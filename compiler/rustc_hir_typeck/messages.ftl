@@ -38,6 +38,15 @@ hir_typeck_const_select_must_be_fn = this argument must be a function item
     .note = expected a function item, found {$ty}
     .help = consult the documentation on `const_eval_select` for more information
 
+hir_typeck_const_select_signature_mismatch = the functions passed to `const_eval_select` must have identical signatures
+    .const_label = compile-time implementation has signature `{$const_sig}`
+    .rt_label = runtime implementation has signature `{$rt_sig}`
+    .help = consult the documentation on `const_eval_select` for more information
+
+hir_typeck_const_select_track_caller_mismatch = only one of the functions passed to `const_eval_select` is `#[track_caller]`
+    .label = this function is not `#[track_caller]`
+    .note = caller locations and panic locations will differ between compile-time and runtime
+
 hir_typeck_convert_to_str = try converting the passed type into a `&str`
 
 hir_typeck_convert_using_method = try using `{$sugg}` to convert `{$found}` to `{$expected}`
@@ -54,6 +63,11 @@ hir_typeck_explicit_destructor = explicit use of destructor method
     .label = explicit destructor calls not allowed
     .suggestion = consider using `drop` function
 
+hir_typeck_field_outside_of_view =
+    field `{$field}` is not part of the view of `self`
+    .label = field not listed in `#[view]`
+    .view_label = the view of `self` is declared here
+
 hir_typeck_field_multiply_specified_in_initializer =
     field `{$ident}` specified more than once
     .label = used more than once
@@ -123,6 +137,11 @@ hir_typeck_return_stmt_outside_of_fn_body =
 hir_typeck_rustcall_incorrect_args =
     functions with the "rust-call" ABI must take a single non-self tuple argument
 
+hir_typeck_self_outside_of_view =
+    `self` can only be used to access the fields listed in `#[view]`
+    .label = `self` is used here
+    .view_label = the view of `self` is declared here
+
 hir_typeck_struct_expr_non_exhaustive =
     cannot create non-exhaustive {$what} using struct expression
 
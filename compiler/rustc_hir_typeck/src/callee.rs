@@ -22,6 +22,7 @@
 };
 use rustc_middle::ty::GenericArgsRef;
 use rustc_middle::ty::{self, Ty, TyCtxt, TypeVisitableExt};
+use rustc_session::lint;
 use rustc_span::def_id::LocalDefId;
 use rustc_span::symbol::{sym, Ident};
 use rustc_span::Span;
@@ -592,11 +593,73 @@ fn confirm_builtin_call(
                     self.dcx().emit_err(errors::ConstSelectMustBeFn { span, ty: arg_ty });
                 }
             }
+
+            let fn_sig = self.resolve_vars_if_possible(fn_sig);
+            if let ty::FnDef(const_def_id, const_args) = *fn_sig.inputs()[1].kind()
+                && let ty::FnDef(rt_def_id, rt_args) = *fn_sig.inputs()[2].kind()
+            {
+                self.check_const_eval_select_fns(
+                    call_expr,
+                    arg_exprs,
+                    (const_def_id, const_args),
+                    (rt_def_id, rt_args),
+                );
+            }
         }
 
         fn_sig.output()
     }
 
+    /// Checks that the two functions passed to `const_eval_select` have identical signatures,
+    /// and lints if only one of them is `#[track_caller]`, since `Location::caller` and panic
+    /// locations would then differ between compile-time and runtime.
+    fn check_const_eval_select_fns(
+        &self,
+        call_expr: &'tcx hir::Expr<'tcx>,
+        arg_exprs: &'tcx [hir::Expr<'tcx>],
+        const_fn: (DefId, GenericArgsRef<'tcx>),
+        rt_fn: (DefId, GenericArgsRef<'tcx>),
+    ) {
+        let tcx = self.tcx;
+        let [const_sig, rt_sig] = [const_fn, rt_fn]
+            .map(|(def_id, args)| tcx.erase_regions(tcx.fn_sig(def_id).instantiate(tcx, args)));
+        let const_span = arg_exprs.get(1).map_or(call_expr.span, |arg| arg.span);
+        let rt_span = arg_exprs.get(2).map_or(call_expr.span, |arg| arg.span);
+
+        // Differences in the types themselves are already reported by the `FnOnce` bounds of
+        // `const_eval_select`, so we only check the lifetimes here. Whether the functions are
+        // `unsafe` only matters to their callers, and `const_eval_select` is `unsafe` anyway.
+        let types = |sig: ty::PolyFnSig<'tcx>| {
+            tcx.instantiate_bound_regions_with_erased(sig).inputs_and_output
+        };
+        let lifetimes = |sig: ty::PolyFnSig<'tcx>| sig.map_bound(|sig| sig.inputs_and_output);
+        if types(const_sig) == types(rt_sig)
+            && lifetimes(const_sig) != lifetimes(rt_sig)
+            && !const_sig.has_infer()
+            && !rt_sig.has_infer()
+        {
+            self.dcx().emit_err(errors::ConstSelectSignatureMismatch {
+                const_span,
+                rt_span,
+                const_sig: Ty::new_fn_ptr(tcx, const_sig),
+                rt_sig: Ty::new_fn_ptr(tcx, rt_sig),
+            });
+        }
+
+        let const_track_caller = tcx.has_attr(const_fn.0, sym::track_caller);
+        let rt_track_caller = tcx.has_attr(rt_fn.0, sym::track_caller);
+        if const_track_caller != rt_track_caller {
+            let (span, other_span) =
+                if const_track_caller { (const_span, rt_span) } else { (rt_span, const_span) };
+            tcx.emit_node_span_lint(
+                lint::builtin::CONST_EVAL_SELECT_MISMATCH,
+                call_expr.hir_id,
+                span,
+                errors::ConstSelectTrackCallerMismatch { other_span },
+            );
+        }
+    }
+
     /// Attempts to reinterpret `method(rcvr, args...)` as `rcvr.method(args...)`
     /// and suggesting the fix if the method probe is successful.
     fn suggest_call_as_method(
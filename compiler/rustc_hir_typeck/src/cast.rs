@@ -493,8 +493,9 @@ fn report_cast_error(&self, fcx: &FnCtxt<'a, 'tcx>, e: CastError) {
                 SizedUnsizedCast {
                     sess: fcx.tcx.sess,
                     span: self.span,
+                    expr_span: self.expr_span,
                     expr_ty: self.expr_ty,
-                    cast_ty: fcx.ty_to_string(self.cast_ty),
+                    cast_ty: fcx.resolve_vars_if_possible(self.cast_ty),
                 }
                 .diagnostic()
                 .emit();
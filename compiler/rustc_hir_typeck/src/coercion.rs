@@ -716,6 +716,24 @@ fn coerce_unsized(&self, mut source: Ty<'tcx>, mut target: Ty<'tcx>) -> CoerceRe
                 format!("cannot cast `{sub}` to `{sup}`, trait upcasting coercion is experimental"),
             );
             err.note(format!("required when coercing `{source}` into `{target}`"));
+            if let ty::Dynamic(sub_preds, ..) = sub.kind()
+                && let ty::Dynamic(sup_preds, ..) = sup.kind()
+                && let Some(sub_def_id) = sub_preds.principal_def_id()
+                && let Some(sup_def_id) = sup_preds.principal_def_id()
+                && let Some((_, _, path)) = traits::supertrait_def_ids_bfs(self.tcx, sub_def_id)
+                    .find(|&(def_id, ..)| def_id == sup_def_id)
+                && !path.is_empty()
+            {
+                let bounds = if path.len() == 1 { "this bound" } else { "these bounds" };
+                err.span_note(
+                    path,
+                    format!(
+                        "`{}` is a supertrait of `{}` through {bounds}",
+                        self.tcx.def_path_str(sup_def_id),
+                        self.tcx.def_path_str(sub_def_id),
+                    ),
+                );
+            }
             err.emit();
         }
 
@@ -96,6 +96,7 @@ pub fn emit_coerce_suggestions(
         }
         self.emit_type_mismatch_suggestions(err, expr, expr_ty, expected, expected_ty_expr, error);
         self.note_type_is_not_clone(err, expected, expr_ty, expr);
+        self.note_ambiguous_trait_upcast(err, expr, expr_ty, expected);
         self.note_internal_mutation_in_method(err, expr, Some(expected), expr_ty);
         self.suggest_method_call_on_range_literal(err, expr, expr_ty, expected);
         self.suggest_return_binding_for_missing_tail_expr(err, expr, expr_ty, expected);
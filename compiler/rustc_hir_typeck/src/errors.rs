@@ -404,6 +404,27 @@ pub struct ConstSelectMustBeFn<'a> {
     pub ty: Ty<'a>,
 }
 
+#[derive(Diagnostic)]
+#[diag(hir_typeck_const_select_signature_mismatch)]
+#[help]
+pub struct ConstSelectSignatureMismatch<'a> {
+    #[primary_span]
+    #[label(hir_typeck_const_label)]
+    pub const_span: Span,
+    #[label(hir_typeck_rt_label)]
+    pub rt_span: Span,
+    pub const_sig: Ty<'a>,
+    pub rt_sig: Ty<'a>,
+}
+
+#[derive(LintDiagnostic)]
+#[diag(hir_typeck_const_select_track_caller_mismatch)]
+#[note]
+pub struct ConstSelectTrackCallerMismatch {
+    #[label]
+    pub other_span: Span,
+}
+
 #[derive(Diagnostic)]
 #[diag(hir_typeck_union_pat_multiple_fields)]
 pub struct UnionPatMultipleFields {
@@ -602,3 +623,24 @@ pub struct SuggestConvertViaMethod<'tcx> {
     pub expected: Ty<'tcx>,
     pub found: Ty<'tcx>,
 }
+
+#[derive(Diagnostic)]
+#[diag(hir_typeck_self_outside_of_view)]
+pub struct SelfOutsideOfView {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    #[label(hir_typeck_view_label)]
+    pub view_span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(hir_typeck_field_outside_of_view)]
+pub struct FieldOutsideOfView {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    #[label(hir_typeck_view_label)]
+    pub view_span: Span,
+    pub field: Ident,
+}
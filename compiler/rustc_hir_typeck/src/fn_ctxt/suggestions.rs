@@ -12,7 +12,7 @@
 use core::iter;
 use rustc_ast::util::parser::{ExprPrecedence, PREC_POSTFIX};
 use rustc_data_structures::packed::Pu128;
-use rustc_errors::{Applicability, Diagnostic, MultiSpan};
+use rustc_errors::{Applicability, Diagnostic, MacroCallSite, MultiSpan};
 use rustc_hir as hir;
 use rustc_hir::def::Res;
 use rustc_hir::def::{CtorKind, CtorOf, DefKind};
@@ -48,6 +48,20 @@ pub(crate) fn body_fn_sig(&self) -> Option<ty::FnSig<'tcx>> {
             .copied()
     }
 
+    /// If `expr` is the output of a function-like macro invoked in the surrounding code, returns
+    /// that invocation, which is where suggestions about the expression as a whole should go.
+    pub(crate) fn macro_call_site(&self, expr: &hir::Expr<'tcx>) -> Option<MacroCallSite> {
+        // Look through the blocks `expr` is the tail of, as macros like `format!` expand to one.
+        let (parent_id, _) = self.tcx.hir().parent_iter(expr.hir_id).find(|(_, node)| {
+            !matches!(
+                node,
+                hir::Node::Block(_)
+                    | hir::Node::Expr(hir::Expr { kind: hir::ExprKind::Block(..), .. })
+            )
+        })?;
+        MacroCallSite::find(expr.span, self.tcx.hir().span(parent_id))
+    }
+
     pub(in super::super) fn suggest_semicolon_at_end(&self, span: Span, err: &mut Diagnostic) {
         // This suggestion is incorrect for
         // fn foo() -> bool { match () { () => true } || match () { () => true } }
@@ -280,6 +294,50 @@ pub fn suggest_remove_last_method_call(
         false
     }
 
+    /// Suggests changing the type annotation of the binding `expr` is assigned to, so that
+    /// it expects the reference `expr` evaluates to.
+    fn suggest_ref_type_annotation(&self, err: &mut Diagnostic, expr: &hir::Expr<'tcx>) {
+        let suggest_annotation = match expr.peel_drop_temps().kind {
+            hir::ExprKind::AddrOf(hir::BorrowKind::Ref, mutbl, _) => mutbl.ref_prefix_str(),
+            _ => return,
+        };
+        let mut tuple_indexes = Vec::new();
+        let mut expr_id = expr.hir_id;
+        for (parent_id, node) in self.tcx.hir().parent_iter(expr.hir_id) {
+            match node {
+                Node::Expr(&Expr { kind: ExprKind::Tup(subs), .. }) => {
+                    tuple_indexes.push(
+                        subs.iter()
+                            .enumerate()
+                            .find(|(_, sub_expr)| sub_expr.hir_id == expr_id)
+                            .unwrap()
+                            .0,
+                    );
+                    expr_id = parent_id;
+                }
+                Node::Local(local) => {
+                    if let Some(mut ty) = local.ty {
+                        while let Some(index) = tuple_indexes.pop() {
+                            match ty.kind {
+                                TyKind::Tup(tys) => ty = &tys[index],
+                                _ => return,
+                            }
+                        }
+                        let annotation_span = ty.span;
+                        err.span_suggestion(
+                            annotation_span.with_hi(annotation_span.lo()),
+                            "alternatively, consider changing the type annotation",
+                            suggest_annotation,
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                    break;
+                }
+                _ => break,
+            }
+        }
+    }
+
     pub fn suggest_deref_ref_or_into(
         &self,
         err: &mut Diagnostic,
@@ -294,52 +352,17 @@ pub fn suggest_deref_ref_or_into(
         if let Some((suggestion, msg, applicability, verbose, annotation)) =
             self.suggest_deref_or_ref(expr, found, expected)
         {
-            if verbose {
-                err.multipart_suggestion_verbose(msg, suggestion, applicability);
-            } else {
-                err.multipart_suggestion(msg, suggestion, applicability);
-            }
-            if annotation {
-                let suggest_annotation = match expr.peel_drop_temps().kind {
-                    hir::ExprKind::AddrOf(hir::BorrowKind::Ref, mutbl, _) => mutbl.ref_prefix_str(),
-                    _ => return true,
-                };
-                let mut tuple_indexes = Vec::new();
-                let mut expr_id = expr.hir_id;
-                for (parent_id, node) in self.tcx.hir().parent_iter(expr.hir_id) {
-                    match node {
-                        Node::Expr(&Expr { kind: ExprKind::Tup(subs), .. }) => {
-                            tuple_indexes.push(
-                                subs.iter()
-                                    .enumerate()
-                                    .find(|(_, sub_expr)| sub_expr.hir_id == expr_id)
-                                    .unwrap()
-                                    .0,
-                            );
-                            expr_id = parent_id;
-                        }
-                        Node::Local(local) => {
-                            if let Some(mut ty) = local.ty {
-                                while let Some(index) = tuple_indexes.pop() {
-                                    match ty.kind {
-                                        TyKind::Tup(tys) => ty = &tys[index],
-                                        _ => return true,
-                                    }
-                                }
-                                let annotation_span = ty.span;
-                                err.span_suggestion(
-                                    annotation_span.with_hi(annotation_span.lo()),
-                                    "alternatively, consider changing the type annotation",
-                                    suggest_annotation,
-                                    Applicability::MaybeIncorrect,
-                                );
-                            }
-                            break;
-                        }
-                        _ => break,
-                    }
+            // Changing the type annotation instead is an alternative fix for the same mismatch.
+            err.ranked_suggestions(|err| {
+                if verbose {
+                    err.multipart_suggestion_verbose(msg, suggestion, applicability);
+                } else {
+                    err.multipart_suggestion(msg, suggestion, applicability);
                 }
-            }
+                if annotation {
+                    self.suggest_ref_type_annotation(err, expr);
+                }
+            });
             return true;
         }
 
@@ -376,6 +399,7 @@ pub fn suggest_deref_ref_or_into(
         }
 
         if !methods.is_empty() {
+            let call_site = self.macro_call_site(expr);
             let mut suggestions = methods
                 .iter()
                 .filter_map(|conversion_method| {
@@ -396,7 +420,9 @@ pub fn suggest_deref_ref_or_into(
                     // so we remove the user's `clone` call.
                     {
                         vec![(receiver_method.ident.span, conversion_method.name.to_string())]
-                    } else if expr.precedence().order() < ExprPrecedence::MethodCall.order() {
+                    } else if call_site.is_none()
+                        && expr.precedence().order() < ExprPrecedence::MethodCall.order()
+                    {
                         vec![
                             (expr.span.shrink_to_lo(), "(".to_string()),
                             (expr.span.shrink_to_hi(), format!(").{}()", conversion_method.name)),
@@ -409,7 +435,10 @@ pub fn suggest_deref_ref_or_into(
                     if let Some(name) = struct_pat_shorthand_field {
                         sugg.insert(0, (expr.span.shrink_to_lo(), format!("{name}: ")));
                     }
-                    Some(sugg)
+                    match call_site {
+                        Some(call_site) => call_site.remap_parts(sugg),
+                        None => Some(sugg),
+                    }
                 })
                 .peekable();
             if suggestions.peek().is_some() {
@@ -1607,6 +1636,107 @@ fn is_local_statement(&self, id: hir::HirId) -> bool {
         matches!(node, Node::Stmt(Stmt { kind: StmtKind::Local(..), .. }))
     }
 
+    /// When a trait object pointer cannot be coerced to a supertrait object pointer
+    /// because the supertrait is reachable through several bounds with different
+    /// generic arguments, point at each of them and suggest casting to one of them.
+    pub(crate) fn note_ambiguous_trait_upcast(
+        &self,
+        err: &mut Diagnostic,
+        expr: &hir::Expr<'_>,
+        found: Ty<'tcx>,
+        expected: Ty<'tcx>,
+    ) {
+        let tcx = self.tcx;
+        let found = self.resolve_vars_if_possible(found);
+        let expected = self.resolve_vars_if_possible(expected);
+        let pointee = |ty: Ty<'tcx>| match *ty.kind() {
+            ty::Ref(_, ty, _) | ty::RawPtr(TypeAndMut { ty, .. }) => Some(ty),
+            ty::Adt(def, args) if def.is_box() => Some(args.type_at(0)),
+            _ => None,
+        };
+        let (Some(found_pointee), Some(expected_pointee)) = (pointee(found), pointee(expected))
+        else {
+            return;
+        };
+        let (
+            &ty::Dynamic(found_preds, ..),
+            &ty::Dynamic(expected_preds, expected_region, expected_repr),
+        ) = (found_pointee.kind(), expected_pointee.kind())
+        else {
+            return;
+        };
+        let (Some(found_principal), Some(expected_principal)) =
+            (found_preds.principal(), expected_preds.principal())
+        else {
+            return;
+        };
+
+        let source_trait_ref = found_principal.with_self_ty(tcx, tcx.types.trait_object_dummy_self);
+        let choices: Vec<_> = rustc_trait_selection::traits::upcast_choices_with_spans(
+            tcx,
+            source_trait_ref,
+            expected_principal.def_id(),
+        )
+        .into_iter()
+        .map(|(choice, span)| {
+            (choice.map_bound(|choice| ty::ExistentialTraitRef::erase_self_ty(tcx, choice)), span)
+        })
+        .filter(|&(choice, _)| self.can_eq(self.param_env, choice, expected_principal))
+        .collect();
+        if choices.len() < 2 {
+            return;
+        }
+
+        err.note(format!(
+            "`{found_pointee}` can be upcast to `{expected_pointee}` in more than one way"
+        ));
+        for &(choice, span) in &choices {
+            err.span_label(
+                span,
+                format!(
+                    "`{found_pointee}` can be upcast to `dyn {choice}` through this supertrait"
+                ),
+            );
+        }
+
+        let needs_parens = expr.precedence().order() < ExprPrecedence::Cast.order();
+        let suggestions = choices.iter().map(|&(choice, _)| {
+            let preds =
+                tcx.mk_poly_existential_predicates_from_iter(expected_preds.iter().map(|pred| {
+                    match pred.skip_binder() {
+                        ty::ExistentialPredicate::Trait(_) => {
+                            choice.map_bound(ty::ExistentialPredicate::Trait)
+                        }
+                        _ => pred,
+                    }
+                }));
+            let target = Ty::new_dynamic(tcx, preds, expected_region, expected_repr);
+            let target = match *expected.kind() {
+                ty::Ref(region, _, mutbl) => {
+                    Ty::new_ref(tcx, region, TypeAndMut { ty: target, mutbl })
+                }
+                ty::RawPtr(TypeAndMut { mutbl, .. }) => {
+                    Ty::new_ptr(tcx, TypeAndMut { ty: target, mutbl })
+                }
+                _ => Ty::new_box(tcx, target),
+            };
+            let target = tcx.erase_regions(target);
+            if needs_parens {
+                vec![
+                    (expr.span.shrink_to_lo(), "(".to_string()),
+                    (expr.span.shrink_to_hi(), format!(") as {target}")),
+                ]
+            } else {
+                vec![(expr.span.shrink_to_hi(), format!(" as {target}"))]
+            }
+        });
+        err.multipart_suggestions(
+            "cast to the supertrait object you want to upcast to",
+            suggestions,
+            Applicability::MaybeIncorrect,
+        );
+    }
+
     /// Suggest that `&T` was cloned instead of `T` because `T` does not implement `Clone`,
     /// which is a side-effect of autoref.
     pub(crate) fn note_type_is_not_clone(
@@ -1928,12 +2058,11 @@ pub(crate) fn suggest_missing_unwrap_expect(
             None => sugg.to_string(),
         };
 
-        err.span_suggestion_verbose(
-            expr.span.shrink_to_hi(),
-            msg,
-            sugg,
-            Applicability::HasPlaceholders,
-        );
+        let span = match self.macro_call_site(expr) {
+            Some(call_site) => call_site.call_site(),
+            None => expr.span,
+        };
+        err.span_suggestion_verbose(span.shrink_to_hi(), msg, sugg, Applicability::HasPlaceholders);
         return true;
     }
 
@@ -2334,14 +2463,36 @@ pub(crate) fn suggest_deref_or_ref(
         bool, /* verbose */
         bool, /* suggest `&` or `&mut` type annotation */
     )> {
-        let sess = self.sess();
-        let sp = expr.span;
+        // If the expression is the output of a macro, suggest changing the macro invocation
+        // instead, as long as the suggestion only adds code around the expression.
+        if let Some(call_site) = self.macro_call_site(expr) {
+            let (sugg, msg, applicability, verbose, annotation) =
+                self.deref_or_ref_suggestion(expr, checked_ty, expected)?;
+            return Some((call_site.remap_parts(sugg)?, msg, applicability, verbose, annotation));
+        }
 
         // If the span is from an external macro, there's no suggestion we can make.
-        if in_external_macro(sess, sp) {
+        if in_external_macro(self.sess(), expr.span) {
             return None;
         }
 
+        self.deref_or_ref_suggestion(expr, checked_ty, expected)
+    }
+
+    fn deref_or_ref_suggestion(
+        &self,
+        expr: &hir::Expr<'tcx>,
+        checked_ty: Ty<'tcx>,
+        expected: Ty<'tcx>,
+    ) -> Option<(
+        Vec<(Span, String)>,
+        String,
+        Applicability,
+        bool, /* verbose */
+        bool, /* suggest `&` or `&mut` type annotation */
+    )> {
+        let sess = self.sess();
+        let sp = expr.span;
         let sm = sess.source_map();
 
         let replace_prefix = |s: &str, old: &str, new: &str| {
@@ -2846,6 +2997,21 @@ pub(crate) fn suggest_cast(
 
         let in_const_context = self.tcx.hir().is_inside_const_context(expr.hir_id);
 
+        // Casting with `as` is never the preferred fix, but it is still offered as a lower
+        // ranked alternative to the tools consuming the JSON output.
+        let suggest_cast_alternative = |err: &mut Diagnostic, is_lossy: bool| {
+            let msg = if is_lossy {
+                format!("{cast_msg}, truncating the value if it doesn't fit")
+            } else {
+                cast_msg.clone()
+            };
+            err.tool_only_multipart_suggestion(
+                msg,
+                cast_suggestion.clone(),
+                Applicability::MaybeIncorrect,
+            );
+        };
+
         let suggest_fallible_into_or_lhs_from =
             |err: &mut Diagnostic, exp_to_found_is_fallible: bool| {
                 // If we know the expression the expected type is derived from, we might be able
@@ -2883,7 +3049,14 @@ pub(crate) fn suggest_cast(
                     ));
                     (msg, suggestion)
                 };
-                err.multipart_suggestion_verbose(msg, suggestion, Applicability::MachineApplicable);
+                err.ranked_suggestions(|err| {
+                    err.multipart_suggestion_verbose(
+                        msg,
+                        suggestion,
+                        Applicability::MachineApplicable,
+                    );
+                    suggest_cast_alternative(err, true);
+                });
             };
 
         let suggest_to_change_suffix_or_into =
@@ -2915,12 +3088,22 @@ pub(crate) fn suggest_cast(
                 } else {
                     msg.clone()
                 };
-                let suggestion = if literal_is_ty_suffixed(expr) {
-                    suffix_suggestion.clone()
+                if literal_is_ty_suffixed(expr) {
+                    err.multipart_suggestion_verbose(
+                        msg,
+                        suffix_suggestion.clone(),
+                        Applicability::MachineApplicable,
+                    );
                 } else {
-                    into_suggestion.clone()
-                };
-                err.multipart_suggestion_verbose(msg, suggestion, Applicability::MachineApplicable);
+                    err.ranked_suggestions(|err| {
+                        err.multipart_suggestion_verbose(
+                            msg,
+                            into_suggestion.clone(),
+                            Applicability::MachineApplicable,
+                        );
+                        suggest_cast_alternative(err, false);
+                    });
+                }
             };
 
         match (&expected_ty.kind(), &checked_ty.kind()) {
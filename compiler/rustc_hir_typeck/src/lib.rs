@@ -41,10 +41,12 @@
 mod place_op;
 mod rvalue_scopes;
 mod upvar;
+mod view;
 mod writeback;
 
 pub use fn_ctxt::FnCtxt;
 pub use inherited::Inherited;
+pub use upvar::dump_closure_captures;
 
 use crate::check::check_fn;
 use crate::coercion::DynamicCoerceMany;
@@ -302,6 +304,8 @@ fn typeck_with_fallback<'tcx>(
 
     fcx.check_asms();
 
+    fcx.check_self_view(def_id, body);
+
     let typeck_results = fcx.resolve_type_vars_in_body(body);
 
     // We clone the defined opaque types during writeback in the new solver
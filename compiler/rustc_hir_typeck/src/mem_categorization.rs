@@ -53,7 +53,9 @@
 use rustc_middle::ty::fold::TypeFoldable;
 use rustc_middle::ty::{self, Ty, TyCtxt, TypeVisitableExt};
 
+use rustc_ast::LitKind;
 use rustc_data_structures::fx::FxIndexMap;
+use rustc_data_structures::packed::Pu128;
 use rustc_hir as hir;
 use rustc_hir::def::{CtorOf, DefKind, Res};
 use rustc_hir::def_id::LocalDefId;
@@ -325,7 +327,7 @@ pub(crate) fn cat_expr_unadjusted(
                 ))
             }
 
-            hir::ExprKind::Index(base, _, _) => {
+            hir::ExprKind::Index(base, index, _) => {
                 if self.typeck_results.is_method_call(expr) {
                     // If this is an index implemented by a method call, then it
                     // will include an implicit deref of the result.
@@ -335,7 +337,11 @@ pub(crate) fn cat_expr_unadjusted(
                     self.cat_overloaded_place(expr, base)
                 } else {
                     let base = self.cat_expr(base)?;
-                    Ok(self.cat_projection(expr, base, expr_ty, ProjectionKind::Index))
+                    let kind = match self.constant_array_index(&base, index) {
+                        Some(i) => ProjectionKind::ConstantIndex(i),
+                        None => ProjectionKind::Index,
+                    };
+                    Ok(self.cat_projection(expr, base, expr_ty, kind))
                 }
             }
 
@@ -437,6 +443,25 @@ pub(crate) fn cat_rvalue(&self, hir_id: hir::HirId, expr_ty: Ty<'tcx>) -> PlaceW
         PlaceWithHirId::new(hir_id, expr_ty, PlaceBase::Rvalue, Vec::new())
     }
 
+    /// Returns the value of `index` if it is an integer literal that is in bounds for the
+    /// array `base`. Such indexes are kept precise when `capture_disjoint_array_elements`
+    /// is enabled so that closures can capture disjoint elements of the same array.
+    fn constant_array_index(
+        &self,
+        base: &PlaceWithHirId<'tcx>,
+        index: &hir::Expr<'_>,
+    ) -> Option<u64> {
+        if !self.tcx().features().capture_disjoint_array_elements {
+            return None;
+        }
+        let hir::ExprKind::Lit(lit) = index.kind else { return None };
+        let LitKind::Int(Pu128(value), _) = lit.node else { return None };
+        let ty::Array(_, len) = base.place.ty().kind() else { return None };
+        let len = len.try_eval_target_usize(self.tcx(), self.param_env)?;
+        let value = u64::try_from(value).ok()?;
+        (value < len).then_some(value)
+    }
+
     #[instrument(level = "debug", skip(self, node), ret)]
     pub(crate) fn cat_projection<N: HirNode>(
         &self,
@@ -861,6 +861,12 @@ pub fn report_no_match_method_error(
                             span,
                             "unsatisfied trait bound introduced in this `derive` macro",
                         ));
+                        if !cause_span.from_expansion() {
+                            entry.1.insert((
+                                cause_span,
+                                "unsatisfied trait bound introduced by this field",
+                            ));
+                        }
                         entry.2.push(p);
                         skip_list.insert(p);
                     }
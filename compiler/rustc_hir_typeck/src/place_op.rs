@@ -133,6 +133,25 @@ fn try_index_step(
             }
         }
 
+        // With `capture_disjoint_array_elements`, indexing an array with an integer literal is
+        // resolved as builtin indexing right away instead of going through `Index` on the
+        // unsized slice, so that closure capture analysis sees which element is accessed.
+        if self.tcx.features().capture_disjoint_array_elements
+            && let ty::Array(element_ty, _) = adjusted_ty.kind()
+            && let hir::ExprKind::Lit(hir::Lit {
+                node:
+                    ast::LitKind::Int(
+                        _,
+                        ast::LitIntType::Unsuffixed | ast::LitIntType::Unsigned(ast::UintTy::Usize),
+                    ),
+                ..
+            }) = index_expr.kind
+        {
+            let adjustments = self.adjust_steps(autoderef);
+            self.apply_adjustments(base_expr, adjustments);
+            return Some((self.tcx.types.usize, *element_ty));
+        }
+
         for unsize in [false, true] {
             let mut self_ty = adjusted_ty;
             if unsize {
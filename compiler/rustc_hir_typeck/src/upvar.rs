@@ -36,12 +36,13 @@
 use rustc_data_structures::unord::{ExtendUnord, UnordSet};
 use rustc_errors::{Applicability, MultiSpan};
 use rustc_hir as hir;
-use rustc_hir::def_id::LocalDefId;
+use rustc_hir::def_id::{LocalDefId, LOCAL_CRATE};
 use rustc_hir::intravisit::{self, Visitor};
 use rustc_infer::infer::UpvarRegion;
 use rustc_middle::hir::place::{Place, PlaceBase, PlaceWithHirId, Projection, ProjectionKind};
 use rustc_middle::mir::FakeReadCause;
 use rustc_middle::traits::ObligationCauseCode;
+use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::{
     self, ClosureSizeProfileData, Ty, TyCtxt, TypeckResults, UpvarArgs, UpvarCapture,
 };
@@ -53,7 +54,10 @@
 use rustc_data_structures::fx::{FxIndexMap, FxIndexSet};
 use rustc_target::abi::FIRST_VARIANT;
 
+use std::fs::{self, File};
+use std::io::BufWriter;
 use std::iter;
+use std::path::{Path, PathBuf};
 
 /// Describe the relationship between the paths of two places
 /// eg:
@@ -567,6 +571,9 @@ fn process_collected_capture_information(
                 let (place, capture_kind) =
                     restrict_precision_for_drop_types(self, place, capture_kind);
 
+                let (place, capture_kind) =
+                    restrict_precision_for_array_moves(self, place, capture_kind);
+
                 capture_info.capture_kind = capture_kind;
                 (place, capture_info)
             })
@@ -814,7 +821,7 @@ fn compute_min_captures(
             captures.sort_by(|capture1, capture2| {
                 fn is_field<'a>(p: &&Projection<'a>) -> bool {
                     match p.kind {
-                        ProjectionKind::Field(_, _) => true,
+                        ProjectionKind::Field(_, _) | ProjectionKind::ConstantIndex(_) => true,
                         ProjectionKind::Deref | ProjectionKind::OpaqueCast => false,
                         p @ (ProjectionKind::Subslice | ProjectionKind::Index) => {
                             bug!("ProjectionKind {:?} was unexpected", p)
@@ -841,6 +848,11 @@ fn is_field<'a>(p: &&Projection<'a>) -> bool {
                                 return i1.cmp(&i2);
                             }
                         }
+                        (ProjectionKind::ConstantIndex(i1), ProjectionKind::ConstantIndex(i2)) => {
+                            if i1 != i2 {
+                                return i1.cmp(&i2);
+                            }
+                        }
                         // Given the filter above, this arm should never be hit
                         (l, r) => bug!("ProjectionKinds {:?} or {:?} were unexpected", l, r),
                     }
@@ -1581,6 +1593,10 @@ fn has_significant_drop_outside_of_captures(
                 })
             }
 
+            // Individual array elements are only moved into the closure when they are `Copy`
+            // (see `restrict_precision_for_array_moves`), so they can't have a significant drop.
+            ty::Array(..) => false,
+
             // Anything else would be completely captured and therefore handled already.
             _ => unreachable!(),
         }
@@ -1941,6 +1957,31 @@ fn restrict_precision_for_drop_types<'a, 'tcx>(
     (place, curr_mode)
 }
 
+/// Truncate `place` so that we never move an individual element out of an array.
+///
+/// Elements of an array can only be captured separately when they are borrowed, or
+/// when they are `Copy`. Moving a non-`Copy` element into the closure captures the
+/// entire array instead.
+fn restrict_precision_for_array_moves<'a, 'tcx>(
+    fcx: &'a FnCtxt<'a, 'tcx>,
+    mut place: Place<'tcx>,
+    mut curr_mode: ty::UpvarCapture,
+) -> (Place<'tcx>, ty::UpvarCapture) {
+    let is_copy_type = fcx.infcx.type_is_copy_modulo_regions(fcx.param_env, place.ty());
+
+    if let (false, UpvarCapture::ByValue) = (is_copy_type, curr_mode) {
+        let pos = place
+            .projections
+            .iter()
+            .position(|proj| matches!(proj.kind, ProjectionKind::ConstantIndex(_)));
+        if let Some(pos) = pos {
+            truncate_place_to_len_and_update_capture_kind(&mut place, &mut curr_mode, pos);
+        }
+    }
+
+    (place, curr_mode)
+}
+
 /// Truncate `place` so that an `unsafe` block isn't required to capture it.
 /// - No projections are applied to raw pointers, since these require unsafe blocks. We capture
 ///   them completely.
@@ -1998,6 +2039,8 @@ fn restrict_capture_precision(
             }
             ProjectionKind::Deref => {}
             ProjectionKind::OpaqueCast => {}
+            // Only produced when `capture_disjoint_array_elements` is enabled
+            ProjectionKind::ConstantIndex(_) => {}
             ProjectionKind::Field(..) => {} // ignore
         }
     }
@@ -2053,6 +2096,7 @@ fn construct_place_string<'tcx>(tcx: TyCtxt<'_>, place: &Place<'tcx>) -> String
             ProjectionKind::Field(a, b) => format!("({a:?}, {b:?})"),
             ProjectionKind::Deref => String::from("Deref"),
             ProjectionKind::Index => String::from("Index"),
+            ProjectionKind::ConstantIndex(i) => format!("ConstantIndex({i})"),
             ProjectionKind::Subslice => String::from("Subslice"),
             ProjectionKind::OpaqueCast => String::from("OpaqueCast"),
         };
@@ -2357,3 +2401,74 @@ fn enable_precise_capture(span: Span) -> bool {
     // edition.
     span.at_least_rust_2021()
 }
+
+/// Outputs the places captured by every closure and coroutine of the crate as JSON, to a file in
+/// the given output directory.
+///
+/// Every capture is reported together with its capture kind, the use of the place that caused it
+/// to be captured, and the use that required this capture kind.
+pub fn dump_closure_captures(
+    tcx: TyCtxt<'_>,
+    output_directory: &Option<PathBuf>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let output_directory = if let Some(ref directory) = output_directory {
+        fs::create_dir_all(directory)?;
+        directory
+    } else {
+        Path::new(".")
+    };
+
+    let filename = format!("{}.closure_captures.json", tcx.crate_name(LOCAL_CRATE));
+    let output_path = output_directory.join(&filename);
+    let file = File::create(&output_path)?;
+    let file = BufWriter::new(file);
+
+    #[derive(serde::Serialize)]
+    struct Capture {
+        place: String,
+        kind: &'static str,
+        path_span: String,
+        kind_span: String,
+    }
+
+    #[derive(serde::Serialize)]
+    struct ClosureCaptures {
+        closure: String,
+        span: String,
+        captures: Vec<Capture>,
+    }
+
+    let source_map = tcx.sess.source_map();
+    let closures: Vec<_> = tcx
+        .hir()
+        .body_owners()
+        .filter(|&def_id| tcx.is_closure_like(def_id.to_def_id()))
+        .map(|def_id| {
+            let captures = tcx
+                .inferred_closure_captures(def_id)
+                .iter()
+                .map(|capture| Capture {
+                    place: capture.place.to_string(),
+                    kind: match capture.kind {
+                        ty::UpvarCapture::ByValue => "by_value",
+                        ty::UpvarCapture::ByRef(ty::ImmBorrow) => "by_ref",
+                        ty::UpvarCapture::ByRef(ty::UniqueImmBorrow) => "by_unique_ref",
+                        ty::UpvarCapture::ByRef(ty::MutBorrow) => "by_mut_ref",
+                    },
+                    path_span: source_map.span_to_embeddable_string(capture.path_span),
+                    kind_span: source_map.span_to_embeddable_string(capture.kind_span),
+                })
+                .collect();
+
+            ClosureCaptures {
+                closure: with_no_trimmed_paths!(tcx.def_path_str(def_id)),
+                span: source_map.span_to_embeddable_string(tcx.def_span(def_id)),
+                captures,
+            }
+        })
+        .collect();
+
+    serde_json::to_writer(file, &closures)?;
+
+    Ok(())
+}
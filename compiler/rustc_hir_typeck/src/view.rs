@@ -0,0 +1,94 @@
+//! Checks the bodies of methods annotated with the unstable `#[view(..)]` attribute.
+//!
+//! Such a method declares which fields of `self` it borrows, and the borrow checker relies on
+//! this to allow calling it while other fields of the receiver are borrowed. This is only sound
+//! if the body never uses `self` for anything other than accessing the fields in its view.
+
+use hir::def::Res;
+use hir::intravisit::{self, Visitor};
+use rustc_hir as hir;
+use rustc_hir::def_id::LocalDefId;
+use rustc_middle::hir::nested_filter;
+use rustc_span::symbol::sym;
+use rustc_span::Span;
+use rustc_target::abi::FieldIdx;
+
+use crate::errors;
+use crate::FnCtxt;
+
+impl<'a, 'tcx> FnCtxt<'a, 'tcx> {
+    pub(crate) fn check_self_view(&self, def_id: LocalDefId, body: &'tcx hir::Body<'tcx>) {
+        let Some(fields) = self.tcx.fn_self_view(def_id) else { return };
+        let Some(hir::PatKind::Binding(_, self_hir_id, ..)) =
+            body.params.first().map(|param| param.pat.kind)
+        else {
+            return;
+        };
+        let view_span = self.tcx.get_attr(def_id, sym::view).unwrap().span;
+
+        let mut visitor = SelfViewVisitor { fcx: self, self_hir_id, fields, view_span };
+        visitor.visit_body(body);
+    }
+}
+
+struct SelfViewVisitor<'a, 'tcx> {
+    fcx: &'a FnCtxt<'a, 'tcx>,
+    self_hir_id: hir::HirId,
+    fields: &'tcx [FieldIdx],
+    view_span: Span,
+}
+
+impl<'a, 'tcx> SelfViewVisitor<'a, 'tcx> {
+    /// Checks that a use of `self` is, up to explicit derefs, the base of an access to a field
+    /// in the view.
+    fn check_use_of_self(&self, expr: &'tcx hir::Expr<'tcx>) {
+        let tcx = self.fcx.tcx;
+        let is_deref = |node: &hir::Node<'_>| {
+            matches!(
+                node,
+                hir::Node::Expr(hir::Expr { kind: hir::ExprKind::Unary(hir::UnOp::Deref, _), .. })
+            )
+        };
+        let parent =
+            tcx.hir().parent_iter(expr.hir_id).map(|(_, node)| node).find(|n| !is_deref(n));
+
+        if let Some(hir::Node::Expr(parent)) = parent
+            && let hir::ExprKind::Field(_, field) = parent.kind
+        {
+            let typeck_results = self.fcx.typeck_results.borrow();
+            match typeck_results.field_indices().get(parent.hir_id) {
+                Some(idx) if self.fields.contains(idx) => {}
+                Some(_) => {
+                    tcx.dcx().emit_err(errors::FieldOutsideOfView {
+                        span: field.span,
+                        view_span: self.view_span,
+                        field,
+                    });
+                }
+                // Errors have been reported when checking the field access.
+                None => {}
+            }
+        } else {
+            tcx.dcx()
+                .emit_err(errors::SelfOutsideOfView { span: expr.span, view_span: self.view_span });
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for SelfViewVisitor<'a, 'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.fcx.tcx.hir()
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        if let hir::ExprKind::Path(hir::QPath::Resolved(None, path)) = expr.kind
+            && let Res::Local(hir_id) = path.res
+            && hir_id == self.self_hir_id
+        {
+            self.check_use_of_self(expr);
+        }
+        intravisit::walk_expr(self, expr);
+    }
+}
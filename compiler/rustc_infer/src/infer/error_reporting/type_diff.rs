@@ -0,0 +1,139 @@
+//! Shortening of the expected and found types of large type mismatches.
+//!
+//! [`TypeErrCtxt::cmp`] already replaces the type arguments which are the same in both
+//! types with `_`, but only for the direct arguments of the same nominal type. The other
+//! matching parts, like the elements of a tuple or the arguments of an unrelated type, are
+//! printed in full, which for large types buries the differences in noise. For those, every
+//! argument which doesn't contain a difference is elided as `…` when one of its siblings does,
+//! so that only the path to the highlighted differences remains:
+//!
+//! ```text
+//! HashMap<…, Vec<(…, Option<Box<Beta>>, …)>>
+//! HashMap<…, Vec<(…, Option<Box<Alpha>>, …)>>
+//! ```
+//!
+//! [`TypeErrCtxt::cmp`]: super::TypeErrCtxt::cmp
+
+use rustc_errors::DiagnosticStyledString;
+
+/// Printed in place of the arguments which are the same in both types.
+const ELIDED: char = '…';
+
+/// A character of a type diff, and whether it is highlighted as part of a difference.
+type DiffChar = (char, bool);
+
+/// Elides the arguments of `diff` that are the same in both types, see the module docs.
+pub(super) fn elide_matching_arguments(diff: DiagnosticStyledString) -> DiagnosticStyledString {
+    let chars: Vec<DiffChar> = diff
+        .0
+        .iter()
+        .flat_map(|part| {
+            let highlighted = part.is_highlighted();
+            part.content().chars().map(move |c| (c, highlighted))
+        })
+        .collect();
+    let mut elided = vec![];
+    elide(&chars, &mut elided);
+
+    let mut diff = DiagnosticStyledString::new();
+    let mut run = String::new();
+    for (i, &(c, highlighted)) in elided.iter().enumerate() {
+        run.push(c);
+        if elided.get(i + 1).map_or(true, |&(_, next)| next != highlighted) {
+            diff.push(std::mem::take(&mut run), highlighted);
+        }
+    }
+    diff
+}
+
+fn is_open(chars: &[DiffChar], i: usize) -> bool {
+    matches!(chars[i].0, '<' | '(' | '[' | '{')
+}
+
+fn is_close(chars: &[DiffChar], i: usize) -> bool {
+    match chars[i].0 {
+        // The arrow of a function signature's return type doesn't close anything.
+        '>' => i == 0 || chars[i - 1].0 != '-',
+        ')' | ']' | '}' => true,
+        _ => false,
+    }
+}
+
+/// Finds the bracket closing the one opened at `open`.
+fn matching_close(chars: &[DiffChar], open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for i in open + 1..chars.len() {
+        if is_open(chars, i) {
+            depth += 1;
+        } else if is_close(chars, i) {
+            if depth == 0 {
+                return Some(i);
+            }
+            depth -= 1;
+        }
+    }
+    None
+}
+
+fn elide(chars: &[DiffChar], out: &mut Vec<DiffChar>) {
+    let mut i = 0;
+    while i < chars.len() {
+        out.push(chars[i]);
+        if is_open(chars, i)
+            && let Some(close) = matching_close(chars, i)
+        {
+            elide_arguments(&chars[i + 1..close], out);
+            out.push(chars[close]);
+            i = close + 1;
+        } else {
+            i += 1;
+        }
+    }
+}
+
+/// Elides the comma separated arguments between a pair of brackets.
+fn elide_arguments(chars: &[DiffChar], out: &mut Vec<DiffChar>) {
+    let mut args = vec![];
+    let mut start = 0;
+    let mut depth = 0;
+    for i in 0..chars.len() {
+        if is_open(chars, i) {
+            depth += 1;
+        } else if is_close(chars, i) {
+            depth -= 1;
+        } else if chars[i].0 == ',' && depth == 0 {
+            args.push(&chars[start..i]);
+            start = i + 1;
+        }
+    }
+    args.push(&chars[start..]);
+
+    let is_different = |arg: &[DiffChar]| arg.iter().any(|&(_, highlighted)| highlighted);
+    if args.len() == 1 || !args.iter().any(|arg| is_different(arg)) {
+        elide(chars, out);
+        return;
+    }
+
+    let mut previous_elided = false;
+    for (i, arg) in args.into_iter().enumerate() {
+        // Keep the trailing comma of single element tuples.
+        if arg.iter().all(|(c, _)| c.is_whitespace()) {
+            out.push((',', false));
+            continue;
+        }
+        let elided = !is_different(arg);
+        if elided && previous_elided {
+            continue;
+        }
+        if i > 0 {
+            out.extend([(',', false), (' ', false)]);
+        }
+        if elided {
+            out.push((ELIDED, false));
+        } else {
+            let leading_whitespace = arg.iter().take_while(|(c, _)| c.is_whitespace()).count();
+            elide(&arg[leading_whitespace..], out);
+        }
+        previous_elided = elided;
+    }
+}
@@ -40,6 +40,7 @@
 use rustc_middle::ty::{self, GenericParamDefKind, InferConst, InferTy, Ty, TyCtxt};
 use rustc_middle::ty::{ConstVid, EffectVid, FloatVid, IntVid, TyVid};
 use rustc_middle::ty::{GenericArg, GenericArgKind, GenericArgs, GenericArgsRef};
+use rustc_session::code_stats::SolverCacheKind;
 use rustc_span::symbol::Symbol;
 use rustc_span::Span;
 
@@ -1563,6 +1564,17 @@ pub fn closure_kind(&self, closure_ty: Ty<'tcx>) -> Option<ty::ClosureKind> {
     /// repeatedly attempting to select an `Obligation` while changing only
     /// its `ParamEnv`, since `FulfillmentContext` doesn't use probing.
     pub fn clear_caches(&self) {
+        if self.tcx.sess.opts.unstable_opts.selection_cache_stats {
+            let code_stats = &self.tcx.sess.code_stats;
+            code_stats.record_solver_cache_evictions(
+                SolverCacheKind::Selection,
+                self.selection_cache.len(),
+            );
+            code_stats.record_solver_cache_evictions(
+                SolverCacheKind::Evaluation,
+                self.evaluation_cache.len(),
+            );
+        }
         self.selection_cache.clear();
         self.evaluation_cache.clear();
         self.inner.borrow_mut().projection_cache().clear();
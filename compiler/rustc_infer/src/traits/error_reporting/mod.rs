@@ -1,4 +1,4 @@
-use super::ObjectSafetyViolation;
+use super::{ObjectSafetyExplanation, ObjectSafetyViolation};
 
 use crate::infer::InferCtxt;
 use rustc_data_structures::fx::FxIndexSet;
@@ -44,7 +44,7 @@ pub fn report_object_safety_error<'tcx>(
     span: Span,
     hir_id: Option<hir::HirId>,
     trait_def_id: DefId,
-    violations: &[ObjectSafetyViolation],
+    explanations: &[ObjectSafetyExplanation],
 ) -> DiagnosticBuilder<'tcx> {
     let trait_str = tcx.def_path_str(trait_def_id);
     let trait_span = tcx.hir().get_if_local(trait_def_id).and_then(|node| match node {
@@ -81,7 +81,7 @@ pub fn report_object_safety_error<'tcx>(
     let mut reported_violations = FxIndexSet::default();
     let mut multi_span = vec![];
     let mut messages = vec![];
-    for violation in violations {
+    for ObjectSafetyExplanation { violation, solutions: _ } in explanations {
         if let ObjectSafetyViolation::SizedSelf(sp) = &violation
             && !sp.is_empty()
         {
@@ -123,14 +123,13 @@ pub fn report_object_safety_error<'tcx>(
 
     // Only provide the help if its a local trait, otherwise it's not actionable.
     if trait_span.is_some() {
-        let mut reported_violations: Vec<_> = reported_violations.into_iter().collect();
-        reported_violations.sort();
-
-        let mut potential_solutions: Vec<_> =
-            reported_violations.into_iter().map(|violation| violation.solution()).collect();
-        potential_solutions.sort();
-        // Allows us to skip suggesting that the same item should be moved to another trait multiple times.
-        potential_solutions.dedup();
+        // Keep the solutions in the order of the violations, but skip suggesting
+        // that the same item should be moved to another trait multiple times.
+        let potential_solutions: FxIndexSet<_> = explanations
+            .iter()
+            .filter(|explanation| reported_violations.contains(&explanation.violation))
+            .flat_map(|explanation| explanation.solutions.iter().cloned())
+            .collect();
         for solution in potential_solutions {
             solution.add_to(&mut err);
         }
@@ -146,6 +146,9 @@ pub enum FulfillmentErrorCode<'tcx> {
         /// be reported as an regular error as opposed to a fatal error.
         overflow: bool,
     },
+    /// The tree of obligations of the root obligation took more fulfillment
+    /// steps than allowed by `-Zobligation-fuel`.
+    ExceededFuel,
 }
 
 impl<'tcx, O> Obligation<'tcx, O> {
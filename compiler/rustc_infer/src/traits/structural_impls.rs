@@ -50,6 +50,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             Ambiguity { overflow: false } => write!(f, "Ambiguity"),
             Ambiguity { overflow: true } => write!(f, "Overflow"),
             Cycle(ref cycle) => write!(f, "Cycle({cycle:?})"),
+            ExceededFuel => write!(f, "ExceededFuel"),
         }
     }
 }
@@ -1,4 +1,4 @@
-use smallvec::smallvec;
+use smallvec::{smallvec, SmallVec};
 
 use crate::infer::outlives::components::{push_outlives_components, Component};
 use crate::traits::{self, Obligation, PredicateObligation};
@@ -225,6 +225,61 @@ fn child_with_derived_cause(
     }
 }
 
+/// A clause implied by a user-written bound, along with the path of bounds it
+/// was elaborated through.
+#[derive(Debug, Clone)]
+pub struct ElaborationInfo<'tcx> {
+    /// The user-written bound first, followed by every clause it was elaborated
+    /// into, ending with this clause. Each span after the first one points at the
+    /// supertrait bound through which the clause is implied.
+    pub path: SmallVec<[(ty::Clause<'tcx>, Span); 4]>,
+}
+
+impl<'tcx> ElaborationInfo<'tcx> {
+    pub fn new(clause: ty::Clause<'tcx>, span: Span) -> Self {
+        Self { path: smallvec![(clause, span)] }
+    }
+
+    pub fn clause(&self) -> ty::Clause<'tcx> {
+        self.top().0
+    }
+
+    pub fn top(&self) -> &(ty::Clause<'tcx>, Span) {
+        self.path.last().unwrap()
+    }
+
+    /// The user-written bound this clause was elaborated from.
+    pub fn bottom(&self) -> &(ty::Clause<'tcx>, Span) {
+        self.path.first().unwrap()
+    }
+
+    fn clone_and_push(&self, clause: ty::Clause<'tcx>, span: Span) -> Self {
+        let mut path = self.path.clone();
+        path.push((clause, span));
+        Self { path }
+    }
+}
+
+impl<'tcx> Elaboratable<'tcx> for ElaborationInfo<'tcx> {
+    fn predicate(&self) -> ty::Predicate<'tcx> {
+        self.clause().as_predicate()
+    }
+
+    fn child(&self, clause: ty::Clause<'tcx>) -> Self {
+        self.clone_and_push(clause, self.top().1)
+    }
+
+    fn child_with_derived_cause(
+        &self,
+        clause: ty::Clause<'tcx>,
+        span: Span,
+        _parent_trait_pred: ty::PolyTraitPredicate<'tcx>,
+        _index: usize,
+    ) -> Self {
+        self.clone_and_push(clause, span)
+    }
+}
+
 pub fn elaborate<'tcx, O: Elaboratable<'tcx>>(
     tcx: TyCtxt<'tcx>,
     obligations: impl IntoIterator<Item = O>,
@@ -412,6 +467,18 @@ pub fn supertraits<'tcx>(
     elaborate(tcx, [trait_ref.to_predicate(tcx)]).filter_only_self().filter_to_traits()
 }
 
+/// Like [`elaborate`], but keeps track of the path through which each clause is
+/// implied by one of the user-written `bounds`, like [`TraitAliasExpansionInfo`] does
+/// for trait aliases.
+///
+/// [`TraitAliasExpansionInfo`]: ../../../rustc_trait_selection/traits/util/struct.TraitAliasExpansionInfo.html
+pub fn elaborate_with_provenance<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    bounds: impl IntoIterator<Item = (ty::Clause<'tcx>, Span)>,
+) -> Elaborator<'tcx, ElaborationInfo<'tcx>> {
+    elaborate(tcx, bounds.into_iter().map(|(clause, span)| ElaborationInfo::new(clause, span)))
+}
+
 pub fn transitive_bounds<'tcx>(
     tcx: TyCtxt<'tcx>,
     trait_refs: impl Iterator<Item = ty::PolyTraitRef<'tcx>>,
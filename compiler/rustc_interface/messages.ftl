@@ -1,6 +1,12 @@
 interface_cant_emit_mir =
     could not emit MIR: {$error}
 
+interface_couldnt_dump_closure_captures =
+    unexpected error occurred while dumping closure captures: {$error}
+
+interface_couldnt_dump_drop_order =
+    unexpected error occurred while dumping the drop order: {$error}
+
 interface_emoji_identifier =
     identifiers cannot contain emoji: `{$ident}`
 
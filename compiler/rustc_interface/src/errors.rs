@@ -112,3 +112,15 @@ pub struct UnsupportedCrateTypeForTarget<'a> {
 #[derive(Diagnostic)]
 #[diag(interface_multiple_output_types_to_stdout)]
 pub struct MultipleOutputTypesToStdout;
+
+#[derive(Diagnostic)]
+#[diag(interface_couldnt_dump_closure_captures)]
+pub struct CouldntDumpClosureCaptures {
+    pub error: String,
+}
+
+#[derive(Diagnostic)]
+#[diag(interface_couldnt_dump_drop_order)]
+pub struct CouldntDumpDropOrder {
+    pub error: String,
+}
@@ -12,6 +12,7 @@
 use rustc_lint::LintStore;
 use rustc_middle::ty;
 use rustc_middle::util::Providers;
+use rustc_mir_transform::MirPassStore;
 use rustc_parse::maybe_new_parser_from_source_str;
 use rustc_query_impl::QueryCtxt;
 use rustc_query_system::query::print_query_stack;
@@ -292,6 +293,10 @@ pub struct Config {
     /// function being registered.
     pub register_lints: Option<Box<dyn Fn(&Session, &mut LintStore) + Send + Sync>>,
 
+    /// This is a callback from the driver that is called when we're registering MIR passes, e.g.
+    /// to add out-of-tree passes to the optimizations of `optimized_mir`.
+    pub register_mir_passes: Option<Box<dyn Fn(&Session, &mut MirPassStore) + Send + Sync>>,
+
     /// This is a callback from the driver that is called just after we have populated
     /// the list of queries.
     pub override_queries: Option<fn(&Session, &mut Providers)>,
@@ -419,6 +424,12 @@ pub fn run_compiler<R: Send>(config: Config, f: impl FnOnce(&Compiler) -> R + Se
             }
             sess.lint_store = Some(Lrc::new(lint_store));
 
+            let mut mir_pass_store = MirPassStore::default();
+            if let Some(register_mir_passes) = config.register_mir_passes.as_deref() {
+                register_mir_passes(&sess, &mut mir_pass_store);
+            }
+            sess.mir_pass_store = Some(Lrc::new(mir_pass_store));
+
             let compiler =
                 Compiler { sess, codegen_backend, override_queries: config.override_queries };
 
@@ -25,7 +25,9 @@
 use rustc_passes::{abi_test, hir_stats, layout_test};
 use rustc_resolve::Resolver;
 use rustc_session::code_stats::VTableSizeInfo;
-use rustc_session::config::{CrateType, Input, OutFileName, OutputFilenames, OutputType};
+use rustc_session::config::{
+    CrateType, Input, OutFileName, OutputFilenames, OutputType, SwitchWithOptPath,
+};
 use rustc_session::cstore::Untracked;
 use rustc_session::output::filename_for_input;
 use rustc_session::search_paths::PathKind;
@@ -729,6 +731,18 @@ fn analysis(tcx: TyCtxt<'_>, (): ()) -> Result<()> {
     // passes are timed inside typeck
     rustc_hir_analysis::check_crate(tcx)?;
 
+    if let SwitchWithOptPath::Enabled(ref path) = sess.opts.unstable_opts.dump_closure_captures {
+        if let Err(err) = rustc_hir_typeck::dump_closure_captures(tcx, path) {
+            tcx.dcx().emit_fatal(errors::CouldntDumpClosureCaptures { error: err.to_string() });
+        }
+    }
+
+    if let SwitchWithOptPath::Enabled(ref path) = sess.opts.unstable_opts.dump_drop_order {
+        if let Err(err) = rustc_hir_analysis::check::dump_drop_order(tcx, path) {
+            tcx.dcx().emit_fatal(errors::CouldntDumpDropOrder { error: err.to_string() });
+        }
+    }
+
     sess.time("MIR_borrow_checking", || {
         tcx.hir().par_body_owners(|def_id| {
             // Run unsafety check because it's responsible for stealing and
@@ -822,6 +836,10 @@ fn analysis(tcx: TyCtxt<'_>, (): ()) -> Result<()> {
         let _ = tcx.all_diagnostic_items(());
     });
 
+    if sess.opts.unstable_opts.cross_crate_dead_code.is_some() {
+        sess.time("writing_usage_manifests", || rustc_passes::dead::write_usage_manifests(tcx));
+    }
+
     if sess.opts.unstable_opts.print_vtable_sizes {
         let traits = tcx.traits(LOCAL_CRATE);
 
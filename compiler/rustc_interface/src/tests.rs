@@ -3,12 +3,14 @@
 use rustc_data_structures::profiling::TimePassesFormat;
 use rustc_errors::{emitter::HumanReadableErrorType, registry, ColorConfig};
 use rustc_session::config::{
-    build_configuration, build_session_options, rustc_optgroups, BranchProtection, CFGuard, Cfg,
-    CollapseMacroDebuginfo, DebugInfo, DumpMonoStatsFormat, ErrorOutputType, ExternEntry,
-    ExternLocation, Externs, FunctionReturn, InliningThreshold, Input, InstrumentCoverage,
-    InstrumentXRay, LinkSelfContained, LinkerPluginLto, LocationDetail, LtoCli, NextSolverConfig,
-    OomStrategy, Options, OutFileName, OutputType, OutputTypes, PAuthKey, PacRet, Passes, Polonius,
-    ProcMacroExecutionStrategy, Strip, SwitchWithOptPath, SymbolManglingVersion, WasiExecModel,
+    build_configuration, build_session_options, rustc_optgroups, BranchProtection, CFGuard,
+    CandidatePreference, Cfg, CollapseMacroDebuginfo, ConstEvalBacktrace, ConstEvalUbReport,
+    ConstraintPathFormat, CoverageOptions, DebugInfo, DumpBorrowckFormat, DumpMonoStatsFormat,
+    ErrorOutputType, ExternEntry, ExternLocation, Externs, FunctionReturn, InliningThreshold,
+    Input, InstrumentCoverage, InstrumentXRay, LinkSelfContained, LinkerPluginLto, LocationDetail,
+    LtoCli, MirValidation, NextSolverConfig, OomStrategy, Options, OutFileName, OutputType,
+    OutputTypes, PAuthKey, PacRet, Passes, Polonius, ProcMacroExecutionStrategy, Strip,
+    SwitchWithOptPath, SymbolManglingVersion, WasiExecModel,
 };
 use rustc_session::lint::Level;
 use rustc_session::search_paths::SearchPath;
@@ -659,8 +661,21 @@ macro_rules! untracked {
     // Make sure that changing an [UNTRACKED] option leaves the hash unchanged.
     // tidy-alphabetical-start
     untracked!(assert_incr_state, Some(String::from("loaded")));
+    untracked!(borrowck_graphviz_errors, Some(ConstraintPathFormat::Text));
+    untracked!(borrowck_skip_trivial_bodies, false);
+    untracked!(const_eval_ub_report, Some(ConstEvalUbReport::Json));
     untracked!(deduplicate_diagnostics, false);
+    untracked!(diagnostic_theme, Some(PathBuf::from("theme.toml")));
+    untracked!(diagnostic_type_aliases, false);
+    untracked!(dump_borrowck, Some(DumpBorrowckFormat::Json));
+    untracked!(dump_borrowck_dir, String::from("abc"));
+    untracked!(dump_closure_captures, SwitchWithOptPath::Enabled(Some("captures-dir/".into())));
     untracked!(dump_dep_graph, true);
+    untracked!(dump_drop_order, SwitchWithOptPath::Enabled(Some("drop-order-dir/".into())));
+    untracked!(
+        dump_intercrate_ambiguities,
+        SwitchWithOptPath::Enabled(Some("ambiguities-dir/".into()))
+    );
     untracked!(dump_mir, Some(String::from("abc")));
     untracked!(dump_mir_dataflow, true);
     untracked!(dump_mir_dir, String::from("abc"));
@@ -668,14 +683,19 @@ macro_rules! untracked {
     untracked!(dump_mir_graphviz, true);
     untracked!(dump_mono_stats, SwitchWithOptPath::Enabled(Some("mono-items-dir/".into())));
     untracked!(dump_mono_stats_format, DumpMonoStatsFormat::Json);
+    untracked!(dump_vtable_layouts, SwitchWithOptPath::Enabled(Some("vtables-dir/".into())));
     untracked!(dylib_lto, true);
     untracked!(emit_stack_sizes, true);
+    untracked!(explain_obligation, true);
+    untracked!(explain_promotion, true);
     untracked!(future_incompat_test, true);
     untracked!(hir_stats, true);
     untracked!(identify_regions, true);
     untracked!(incremental_info, true);
     untracked!(incremental_verify_ich, true);
     untracked!(input_stats, true);
+    untracked!(json_explanations, true);
+    untracked!(layout_tables, true);
     untracked!(link_native_libraries, false);
     untracked!(llvm_time_trace, true);
     untracked!(ls, vec!["all".to_owned()]);
@@ -692,14 +712,17 @@ macro_rules! untracked {
     untracked!(print_codegen_stats, true);
     untracked!(print_llvm_passes, true);
     untracked!(print_mono_items, Some(String::from("abc")));
+    untracked!(print_niche_decisions, true);
     untracked!(print_type_sizes, true);
     untracked!(proc_macro_backtrace, true);
     untracked!(proc_macro_execution_strategy, ProcMacroExecutionStrategy::CrossThread);
     untracked!(profile_closures, true);
     untracked!(query_dep_graph, true);
+    untracked!(selection_cache_stats, true);
     untracked!(self_profile, SwitchWithOptPath::Enabled(None));
     untracked!(self_profile_events, Some(vec![String::new()]));
     untracked!(shell_argfiles, true);
+    untracked!(show_cascaded_errors, true);
     untracked!(span_debug, true);
     untracked!(span_free_formats, true);
     untracked!(temps_dir, Some(String::from("abc")));
@@ -713,7 +736,7 @@ macro_rules! untracked {
     untracked!(ui_testing, true);
     untracked!(unpretty, Some("expanded".to_string()));
     untracked!(unstable_options, true);
-    untracked!(validate_mir, true);
+    untracked!(validate_mir, MirValidation::Full);
     untracked!(write_long_types_to_disk, false);
     // tidy-alphabetical-end
 
@@ -741,12 +764,19 @@ macro_rules! tracked {
             pac_ret: Some(PacRet { leaf: true, key: PAuthKey::B })
         })
     );
+    tracked!(candidate_preference, CandidatePreference::WhereClause);
     tracked!(codegen_backend, Some("abc".to_string()));
     tracked!(collapse_macro_debuginfo, CollapseMacroDebuginfo::Yes);
+    tracked!(const_eval_backtrace, ConstEvalBacktrace::Full);
+    tracked!(const_eval_memory_limit, Some(1 << 20));
+    tracked!(const_eval_step_limit, Some(1_000_000));
+    tracked!(coverage_options, CoverageOptions { branch: true, mcdc: true });
     tracked!(crate_attr, vec!["abc".to_string()]);
+    tracked!(cross_crate_dead_code, Some(PathBuf::from("usage")));
     tracked!(cross_crate_inline_threshold, InliningThreshold::Always);
     tracked!(debug_info_for_profiling, true);
     tracked!(debug_macros, true);
+    tracked!(dedup_mono_items, true);
     tracked!(default_hidden_visibility, Some(true));
     tracked!(dep_info_omit_d_target, true);
     tracked!(direct_access_external_data, Some(true));
@@ -760,16 +790,19 @@ macro_rules! tracked {
     tracked!(fuel, Some(("abc".to_string(), 99)));
     tracked!(function_return, FunctionReturn::ThunkExtern);
     tracked!(function_sections, Some(false));
+    tracked!(future_size_limit, Some(4096));
     tracked!(human_readable_cgu_names, true);
     tracked!(incremental_ignore_spans, true);
     tracked!(inline_in_all_cgus, Some(true));
     tracked!(inline_mir, Some(true));
     tracked!(inline_mir_hint_threshold, Some(123));
+    tracked!(inline_mir_profile_threshold, Some(1000));
     tracked!(inline_mir_threshold, Some(123));
     tracked!(instrument_mcount, true);
     tracked!(instrument_xray, Some(InstrumentXRay::default()));
     tracked!(link_directives, false);
     tracked!(link_only, true);
+    tracked!(lint_mir, true);
     tracked!(llvm_module_flag, vec![("bar".to_string(), 123, "max".to_string())]);
     tracked!(llvm_plugins, vec![String::from("plugin_name")]);
     tracked!(location_detail, LocationDetail { file: true, line: false, column: false });
@@ -778,7 +811,10 @@ macro_rules! tracked {
     tracked!(mir_emit_retag, true);
     tracked!(mir_enable_passes, vec![("DestProp".to_string(), false)]);
     tracked!(mir_keep_place_mention, true);
+    tracked!(mir_only_rlibs, true);
+    tracked!(mir_outline_panics, true);
     tracked!(mir_opt_level, Some(4));
+    tracked!(mir_passes, Some(vec!["GVN".to_string()]));
     tracked!(move_size_limit, Some(4096));
     tracked!(mutable_noalias, false);
     tracked!(
@@ -791,6 +827,7 @@ macro_rules! tracked {
     tracked!(no_profiler_runtime, true);
     tracked!(no_trait_vptr, true);
     tracked!(no_unique_section_names, true);
+    tracked!(obligation_fuel, Some(1000));
     tracked!(oom, OomStrategy::Panic);
     tracked!(osx_rpath_install_name, true);
     tracked!(packed_bundled_libs, true);
@@ -799,6 +836,7 @@ macro_rules! tracked {
     tracked!(plt, Some(true));
     tracked!(polonius, Polonius::Legacy);
     tracked!(precise_enum_drop_elaboration, false);
+    tracked!(preview_edition_migrations, Some(2027));
     tracked!(print_fuel, Some("abc".to_string()));
     tracked!(profile, true);
     tracked!(profile_emit, Some(PathBuf::from("abc")));
@@ -814,6 +852,7 @@ macro_rules! tracked {
     tracked!(sanitizer_memory_track_origins, 2);
     tracked!(sanitizer_recover, SanitizerSet::ADDRESS);
     tracked!(saturating_float_casts, Some(true));
+    tracked!(share_const_fn_calls, true);
     tracked!(share_generics, Some(true));
     tracked!(show_span, Some(String::from("abc")));
     tracked!(simulate_remapped_rust_src_base, Some(PathBuf::from("/rustc/abc")));
@@ -30,6 +30,13 @@ lint_bad_attribute_argument = bad attribute argument
 
 lint_bad_opt_access = {$msg}
 
+lint_blocking_in_async = call to blocking function `{$callee}` in an `async` body
+    .note = the thread polling this future can't make progress on other futures until the call returns
+
+lint_blocking_in_async_alternative = consider using `{$alternative}` instead, which doesn't block the thread
+
+lint_blocking_in_async_through = `{$callee}` blocks by calling `{$blocking}`
+
 lint_builtin_allow_internal_unsafe =
     `allow_internal_unsafe` allows defining macros using unsafe without triggering the `unsafe_code` lint at their call site
 
@@ -188,6 +195,14 @@ lint_diag_out_of_impl =
 lint_drop_glue =
     types that do not implement `Drop` can still have drop glue, consider instead using `{$needs_drop}` to detect whether a type is trivially dropped
 
+lint_drop_order_dependence = this temporary in the tail expression of a block is dropped after `{$name}`
+    .label = this value has a significant drop implementation
+    .local_label = `{$name}` has a significant drop implementation as well
+    .local_drop_label = `{$name}` is dropped here
+    .drop_note = the temporary is dropped here, after `{$name}`
+    .rust_2024_note = with the Rust 2024 temporary scoping rules, it would be dropped before `{$name}`
+    .suggestion = to drop the temporary first in every edition, bind the value of the tail expression and drop `{$name}` explicitly
+
 lint_drop_trait_constraints =
     bounds on `{$predicate}` are most likely incorrect, consider instead using `{$needs_drop}` to detect whether a type can be trivially dropped
 
@@ -245,6 +260,12 @@ lint_identifier_uncommon_codepoints = identifier contains {$codepoints_len ->
     *[other] uncommon Unicode codepoints
 }: {$codepoints}
 
+lint_if_let_temporary_lifetime = this temporary in the scrutinee of `if let` lives until after the `else` block
+    .label = this value has a significant drop implementation
+    .else_label = the temporary is still alive while the `else` block runs
+    .drop_note = it is dropped here, after the `else` block
+    .rust_2024_note = with the Rust 2024 temporary scoping rules, it would be dropped before entering the `else` block
+
 lint_ignored_unless_crate_specified = {$level}({$name}) is ignored unless specified at crate level
 
 lint_improper_ctypes = `extern` {$desc} uses type `{$ty}`, which is not FFI-safe
@@ -331,9 +352,39 @@ lint_invalid_reference_casting_note_book = for more information, visit <https://
 
 lint_invalid_reference_casting_note_ty_has_interior_mutability = even for types with interior mutability, the only legal way to obtain a mutable pointer from a shared reference is through `UnsafeCell::get`
 
+lint_large_future = this future is {$size} bytes, which is larger than the limit of {$limit} bytes
+    .note = its largest state holds:{$fields}
+    .suggestion = box the awaited future, so that only a pointer to it is stored in this one
+
+lint_let_else_extended_temporary_lifetime = this temporary in the initializer of `let ... else` has its lifetime extended to the end of the enclosing block
+    .label = this value has a significant drop implementation
+    .drop_note = it is dropped here, at the end of the enclosing block
+
+lint_let_else_temporary_lifetime = this temporary in the initializer of `let ... else` lives until after the `else` block
+    .label = this value has a significant drop implementation
+    .drop_note = it is dropped here, at the end of the `let` statement
+
+lint_lint_config_duplicate = `{$lint}::{$option}` is configured more than once
+    .note = it is first configured here
+
+lint_lint_config_invalid_value = invalid value for `{$lint}::{$option}`
+    .label = expected {$expected}
+
+lint_lint_config_malformed = malformed `lint_config` attribute input
+    .label = expected `lint_name::option = value`
+
+lint_lint_config_unknown_lint = unknown lint in `lint_config`: `{$lint}`
+    .note = only individual lints can be configured, not lint groups
+
+lint_lint_config_unknown_option = lint `{$lint}` has no configuration option `{$option}`
+
 lint_lintpass_by_hand = implementing `LintPass` by hand
     .help = try using `declare_lint_pass!` or `impl_lint_pass!` instead
 
+lint_lossy_integer_cast = casting `{$from}` to `{$to}` with `as` may truncate the value or change its sign
+    .help = use `{$to}::try_from` to handle the values that don't fit in `{$to}`
+    .suggestion = use `{$to}::try_from` and return the error of the conversion
+
 lint_malformed_attribute = malformed lint attribute input
 
 lint_map_unit_fn = `Iterator::map` call that discard the iterator's values
@@ -485,6 +536,19 @@ lint_reason_must_be_string_literal = reason must be a string literal
 
 lint_reason_must_come_last = reason in lint attribute must come last
 
+lint_redundant_bound = bound `{$bound}` is redundant
+    .label = it is already implied by `{$implied_by}`
+    .suggestion = remove the redundant bound
+
+lint_redundant_bound_chain = `{$parent}` implies `{$implied}`
+
+lint_redundant_clone_borrowed = redundant clone of `{$ty}`
+    .suggestion = remove this call to `.clone()`
+    .note = the clone is only borrowed, so the original value can be used instead
+
+lint_redundant_clone_copy = call to `.clone()` on `{$ty}`, which implements `Copy`
+    .suggestion = copy the value instead
+
 lint_redundant_semicolons =
     unnecessary trailing {$multiple ->
         [true] semicolons
@@ -584,6 +648,18 @@ lint_unused_def = unused {$pre}`{$def}`{$post} that must be used
 lint_unused_delim = unnecessary {$delim} around {$item}
     .suggestion = remove these {$delim}
 
+lint_unused_generic_parameter = {$count ->
+        [one] generic parameter {$names} is
+        *[other] generic parameters {$names} are
+    } never used
+    .suggestion = remove the unused {$count ->
+        [one] parameter
+        *[other] parameters
+    } and {$count ->
+        [one] its arguments
+        *[other] their arguments
+    }
+
 lint_unused_import_braces = braces around {$node} is unnecessary
 
 lint_unused_op = unused {$op} that must be used
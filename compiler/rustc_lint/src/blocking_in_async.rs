@@ -0,0 +1,159 @@
+use crate::lints::{BlockingInAsyncAlternative, BlockingInAsyncDiag, BlockingInAsyncThrough};
+use crate::{LateContext, LateLintPass, Level, LintContext};
+use rustc_ast as ast;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir as hir;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::DefId;
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::mir;
+use rustc_middle::ty::TyCtxt;
+use rustc_span::symbol::sym;
+
+declare_lint! {
+    /// The `blocking_in_async` lint detects calls to functions that block the current thread,
+    /// such as most of `std::fs`, `std::net`, `Mutex::lock` and `thread::sleep`, inside `async`
+    /// functions, blocks and closures.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,edition2021
+    /// #![warn(blocking_in_async)]
+    /// async fn load() -> std::io::Result<String> {
+    ///     std::fs::read_to_string("config.toml")
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Executors usually poll many futures on a few threads. A future that blocks its thread
+    /// keeps the others from making progress until the call returns. Blocking work should be
+    /// done with the asynchronous counterpart of the function, or moved to a thread that is
+    /// allowed to block.
+    ///
+    /// Functions are marked as blocking with the unstable `#[diagnostic::blocking]` attribute,
+    /// which can name an asynchronous alternative with `alternative = "path"`. Only the calls
+    /// written in the `async` body are checked, unless `-Z lint-mir` is set, in which case the
+    /// lint also looks through the MIR of the local functions it calls, and of the functions
+    /// of other crates that can be inlined, for calls to blocking functions.
+    ///
+    /// This lint is "allow" by default, as calling blocking functions is fine in futures that
+    /// are run on a thread of their own, such as with `block_on`.
+    pub BLOCKING_IN_ASYNC,
+    Allow,
+    "detects calls to blocking functions in `async` bodies"
+}
+
+declare_lint_pass!(BlockingInAsync => [BLOCKING_IN_ASYNC]);
+
+impl<'tcx> LateLintPass<'tcx> for BlockingInAsync {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let (callee, path) = match expr.kind {
+            hir::ExprKind::Call(path, _) => {
+                let hir::ExprKind::Path(qpath) = &path.kind else { return };
+                (cx.qpath_res(qpath, path.hir_id).opt_def_id(), Some(path))
+            }
+            hir::ExprKind::MethodCall(..) => {
+                (cx.typeck_results().type_dependent_def_id(expr.hir_id), None)
+            }
+            _ => return,
+        };
+        let Some(callee) = callee else { return };
+
+        let tcx = cx.tcx;
+        // Closures in `async` bodies are not checked, as they are often the ones given to
+        // `spawn_blocking`.
+        let Some(body) = cx.enclosing_body else { return };
+        if !matches!(tcx.def_kind(callee), DefKind::Fn | DefKind::AssocFn)
+            || !tcx.coroutine_is_async(tcx.hir().body_owner_def_id(body).to_def_id())
+        {
+            return;
+        }
+        // Looking through the MIR of the callees is expensive, so skip the work when the lint is
+        // off.
+        let (level, _) = tcx.lint_level_at_node(BLOCKING_IN_ASYNC, expr.hir_id);
+        if level == Level::Allow || in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+
+        let (blocking, attr, through) = if let Some(attr) = blocking_attr(tcx, callee) {
+            (callee, attr, false)
+        } else if cx.sess().opts.unstable_opts.lint_mir
+            && let Some((blocking, attr)) = find_blocking_call(tcx, callee)
+        {
+            (blocking, attr, true)
+        } else {
+            return;
+        };
+
+        let alternative = attr
+            .meta_item_list()
+            .and_then(|items| {
+                items.iter().find(|item| item.has_name(sym::alternative))?.value_str()
+            })
+            .map(|alternative| match path {
+                Some(path) if !through && !path.span.from_expansion() => {
+                    BlockingInAsyncAlternative::Suggestion {
+                        path: path.span,
+                        end: expr.span.shrink_to_hi(),
+                        alternative,
+                    }
+                }
+                _ => BlockingInAsyncAlternative::Help { alternative },
+            });
+        cx.emit_span_lint(
+            BLOCKING_IN_ASYNC,
+            expr.span,
+            BlockingInAsyncDiag {
+                callee: tcx.def_path_str(callee),
+                through: through
+                    .then(|| BlockingInAsyncThrough { blocking: tcx.def_path_str(blocking) }),
+                alternative,
+            },
+        );
+    }
+}
+
+/// Returns the `#[diagnostic::blocking]` attribute of a function. The attribute is only honored
+/// on local functions if the `diagnostic_blocking` feature is enabled, like other unstable
+/// diagnostic attributes.
+fn blocking_attr(tcx: TyCtxt<'_>, def_id: DefId) -> Option<&ast::Attribute> {
+    if def_id.is_local() && !tcx.features().diagnostic_blocking {
+        return None;
+    }
+    tcx.get_attrs_by_path(def_id, &[sym::diagnostic, sym::blocking]).next()
+}
+
+/// Looks through the MIR of `callee`, and of the functions it calls in turn, for a call to a
+/// blocking function. Only the MIR of local functions and of the functions of other crates that
+/// can be inlined is visible. The calls that were inlined in a body are found through the source
+/// scopes they were inlined into.
+fn find_blocking_call(tcx: TyCtxt<'_>, callee: DefId) -> Option<(DefId, &ast::Attribute)> {
+    let mut visited = FxHashSet::default();
+    let mut stack = vec![callee];
+    while let Some(def_id) = stack.pop() {
+        if !visited.insert(def_id)
+            || !matches!(tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn)
+            || !(def_id.is_local() || tcx.cross_crate_inlinable(def_id))
+            || !tcx.is_mir_available(def_id)
+        {
+            continue;
+        }
+
+        let body = tcx.optimized_mir(def_id);
+        let inlined = body.source_scopes.iter().filter_map(|scope| Some(scope.inlined?.0.def_id()));
+        let called = body.basic_blocks.iter().filter_map(|block| match &block.terminator().kind {
+            mir::TerminatorKind::Call { func, .. } => Some(func.const_fn_def()?.0),
+            _ => None,
+        });
+        for called in inlined.chain(called) {
+            if let Some(attr) = blocking_attr(tcx, called) {
+                return Some((called, attr));
+            }
+            stack.push(called);
+        }
+    }
+    None
+}
@@ -32,9 +32,12 @@
 use rustc_middle::ty::print::{with_no_trimmed_paths, PrintError};
 use rustc_middle::ty::{self, print::Printer, GenericArg, RegisteredTools, Ty, TyCtxt};
 use rustc_session::lint::{BuiltinLintDiagnostics, LintExpectationId};
-use rustc_session::lint::{FutureIncompatibleInfo, Level, Lint, LintBuffer, LintId};
+use rustc_session::lint::{
+    FutureIncompatibleInfo, Level, Lint, LintBuffer, LintConfigValue, LintId,
+};
 use rustc_session::{LintStoreMarker, Session};
 use rustc_span::edit_distance::find_best_match_for_names;
+use rustc_span::edition::Edition;
 use rustc_span::symbol::{sym, Ident, Symbol};
 use rustc_span::Span;
 use rustc_target::abi;
@@ -74,6 +77,9 @@ pub struct LintStore {
 
     /// Map of registered lint groups to what lints they expand to.
     lint_groups: FxIndexMap<&'static str, LintGroup>,
+
+    /// Map of future editions, by year, to the lints preparing the migration to them.
+    edition_migrations: FxIndexMap<u32, Vec<LintId>>,
 }
 
 impl LintStoreMarker for LintStore {}
@@ -143,6 +149,7 @@ pub fn new() -> LintStore {
             late_module_passes: vec![],
             by_name: Default::default(),
             lint_groups: Default::default(),
+            edition_migrations: Default::default(),
         }
     }
 
@@ -212,6 +219,10 @@ pub fn register_lints(&mut self, lints: &[&'static Lint]) {
                 bug!("duplicate specification of lint {}", lint.name_lower())
             }
 
+            if let Some(edition) = lint.edition_migration {
+                self.register_edition_migration(edition, id);
+            }
+
             if let Some(FutureIncompatibleInfo { reason, .. }) = lint.future_incompatible {
                 if let Some(edition) = reason.edition() {
                     self.lint_groups
@@ -252,6 +263,31 @@ pub fn register_group_alias(&mut self, lint_name: &'static str, alias: &'static
         );
     }
 
+    /// Registers `lint` as part of the migration to the edition of the given year, which may not
+    /// exist yet. Such lints are enabled by `-Z preview-edition-migrations`.
+    pub fn register_edition_migration(&mut self, edition: u32, lint: LintId) {
+        let lints = self.edition_migrations.entry(edition).or_default();
+        if !lints.contains(&lint) {
+            lints.push(lint);
+        }
+    }
+
+    /// Returns the lints preparing the migration to the edition of the given year. If that
+    /// edition already exists, this includes its compatibility lints.
+    pub fn edition_migration_lints(&self, edition: u32) -> Vec<LintId> {
+        let mut lints = self.edition_migrations.get(&edition).cloned().unwrap_or_default();
+        if let Ok(edition) = edition.to_string().parse::<Edition>()
+            && let Some(group) = self.lint_groups.get(edition.lint_name())
+        {
+            for &id in &group.lint_ids {
+                if !lints.contains(&id) {
+                    lints.push(id);
+                }
+            }
+        }
+        lints
+    }
+
     pub fn register_group(
         &mut self,
         is_loaded: bool,
@@ -729,6 +765,16 @@ pub fn typeck_results(&self) -> &'tcx ty::TypeckResults<'tcx> {
         self.maybe_typeck_results().expect("`LateContext::typeck_results` called outside of body")
     }
 
+    /// Returns the value of the configuration option `option` of `lint`, if it is set with
+    /// `#![lint_config(lint::option = value)]`.
+    ///
+    /// The option must be declared with `@config` in the `declare_lint!` of the lint, which
+    /// ensures the value has the declared type.
+    pub fn lint_config(&self, lint: &'static Lint, option: &str) -> Option<LintConfigValue> {
+        debug_assert!(lint.config.iter().any(|declared| declared.name == option));
+        self.tcx.lint_config(()).get(lint, Symbol::intern(option))
+    }
+
     /// Returns the final resolution of a `QPath`, or `Res::Err` if unavailable.
     /// Unlike `.typeck_results().qpath_res(qpath, id)`, this can be used even outside
     /// bodies (e.g. for paths in `hir::Ty`), without any risk of ICE-ing.
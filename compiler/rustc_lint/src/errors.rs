@@ -104,3 +104,52 @@ pub struct CheckNameUnknownTool<'a> {
     #[subdiagnostic]
     pub sub: RequestedLevel<'a>,
 }
+
+#[derive(Diagnostic)]
+#[diag(lint_lint_config_malformed)]
+pub struct LintConfigMalformed {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(lint_lint_config_unknown_lint)]
+pub struct LintConfigUnknownLint {
+    #[primary_span]
+    pub span: Span,
+    pub lint: String,
+    #[note]
+    pub is_group: Option<()>,
+}
+
+#[derive(Diagnostic)]
+#[diag(lint_lint_config_unknown_option)]
+pub struct LintConfigUnknownOption {
+    #[primary_span]
+    pub span: Span,
+    pub lint: String,
+    pub option: Symbol,
+}
+
+#[derive(Diagnostic)]
+#[diag(lint_lint_config_invalid_value)]
+pub struct LintConfigInvalidValue {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    pub lint: String,
+    pub option: Symbol,
+    pub expected: &'static str,
+}
+
+#[derive(Diagnostic)]
+#[diag(lint_lint_config_duplicate)]
+pub struct LintConfigDuplicate {
+    #[primary_span]
+    pub span: Span,
+    #[note]
+    pub first: Span,
+    pub lint: String,
+    pub option: Symbol,
+}
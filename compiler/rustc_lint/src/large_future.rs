@@ -0,0 +1,182 @@
+use crate::lints::{LargeFutureAwaitee, LargeFutureDiag};
+use crate::{LateContext, LateLintPass, Level, LintContext};
+use rustc_errors::pluralize;
+use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_middle::ty::layout::{LayoutOf, TyAndLayout};
+use rustc_session::lint::LintConfigValue;
+use rustc_span::symbol::sym;
+use rustc_target::abi::FieldIdx;
+
+use std::cmp::Reverse;
+
+declare_lint! {
+    /// The `large_future` lint detects `async` functions, blocks and closures whose future is
+    /// larger than a given size.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,edition2021
+    /// #![warn(large_future)]
+    /// async fn read() -> usize {
+    ///     let buf = [0u8; 20_000];
+    ///     std::future::ready(()).await;
+    ///     buf.len()
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// The state of an `async` function that is kept across an `.await`, including the futures
+    /// it awaits, is stored in the future it returns. Large futures are costly to move around,
+    /// and may overflow the stack of the thread polling them. Awaiting a large future is usually
+    /// better done by first boxing it with `Box::pin`, so that only a pointer to it is stored.
+    ///
+    /// The size above which futures are linted is 16384 bytes by default. It can be changed with
+    /// the unstable `#![lint_config(large_future::max_size = N)]` or
+    /// `#![future_size_limit = "N"]` crate attributes, or with the `-Z future-size-limit=N`
+    /// compiler flag.
+    ///
+    /// This lint is "allow" by default, as computing the layout of every future is not free.
+    pub LARGE_FUTURE,
+    Allow,
+    "detects futures larger than the `future_size_limit`",
+    @config = [max_size: Int];
+}
+
+declare_lint_pass!(LargeFuture => [LARGE_FUTURE]);
+
+impl<'tcx> LateLintPass<'tcx> for LargeFuture {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let hir::ExprKind::Closure(&hir::Closure {
+            def_id,
+            body,
+            kind:
+                hir::ClosureKind::Coroutine(hir::CoroutineKind::Desugared(
+                    hir::CoroutineDesugaring::Async,
+                    source,
+                )),
+            ..
+        }) = expr.kind
+        else {
+            return;
+        };
+
+        let tcx = cx.tcx;
+        // Computing the layout of a future requires its optimized MIR, so skip the work when the
+        // lint is off.
+        let (level, _) = tcx.lint_level_at_node(LARGE_FUTURE, expr.hir_id);
+        if level == Level::Allow || in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+
+        let ty = cx.typeck_results().node_type(expr.hir_id);
+        let ty::Coroutine(_, args) = *ty.kind() else { return };
+        // Futures of generic functions may not have a layout until they are instantiated.
+        let Ok(layout) = cx.layout_of(ty) else { return };
+        let limit = match cx.lint_config(LARGE_FUTURE, "max_size") {
+            Some(LintConfigValue::Int(max_size)) => u64::try_from(max_size).unwrap_or(u64::MAX),
+            _ => tcx.future_size_limit().0 as u64,
+        };
+        if layout.size.bytes() <= limit {
+            return;
+        }
+
+        let span = match source {
+            hir::CoroutineSource::Fn => tcx.def_span(tcx.local_parent(def_id)),
+            hir::CoroutineSource::Block | hir::CoroutineSource::Closure => expr.span,
+        };
+
+        let mut collector = AwaiteeCollector { cx, limit, awaitees: vec![] };
+        collector.visit_body(tcx.hir().body(body));
+
+        cx.emit_span_lint(
+            LARGE_FUTURE,
+            span,
+            LargeFutureDiag {
+                size: layout.size.bytes(),
+                limit,
+                fields: largest_state(cx, def_id.to_def_id(), layout, args).unwrap_or_default(),
+                awaitees: collector.awaitees,
+            },
+        );
+    }
+}
+
+/// Renders the sizes of the values stored in the largest state of the future `layout`, from the
+/// largest to the smallest, each on its own line. Returns `None` if the coroutine has no layout.
+fn largest_state<'tcx>(
+    cx: &LateContext<'tcx>,
+    def_id: DefId,
+    layout: TyAndLayout<'tcx>,
+    args: ty::GenericArgsRef<'tcx>,
+) -> Option<String> {
+    let coroutine = cx.tcx.coroutine_layout(def_id)?;
+    let upvar_names = cx.tcx.closure_saved_names_of_captured_variables(def_id);
+    let upvar_count = args.as_coroutine().upvar_tys().len();
+
+    let (variant, variant_layout) = coroutine
+        .variant_fields
+        .indices()
+        .map(|variant| (variant, layout.for_variant(cx, variant)))
+        .max_by_key(|(_, variant_layout)| variant_layout.size)?;
+
+    // Upvars that aren't used after a suspension point are only stored before the first one.
+    let unresumed = variant.as_usize() == ty::CoroutineArgs::UNRESUMED;
+    let upvars = (0..upvar_count)
+        .map(FieldIdx::from_usize)
+        .filter(|&field| unresumed || coroutine.upvar_conflicts[field].is_none())
+        .map(|field| (Some(upvar_names[field]), layout.field(cx, field.as_usize()).size));
+    let locals = coroutine.variant_fields[variant].iter().enumerate().map(|(field, &local)| {
+        (coroutine.field_names[local], variant_layout.field(cx, field).size)
+    });
+    let mut fields: Vec<_> = upvars.chain(locals).collect();
+    fields.sort_by_key(|&(_, size)| Reverse(size));
+
+    let mut rendered = String::new();
+    for (name, size) in fields {
+        let name = match name {
+            Some(sym::__awaitee) => "the awaited future".to_string(),
+            Some(name) => format!("`{name}`"),
+            None => "a temporary value".to_string(),
+        };
+        let size = size.bytes();
+        rendered.push_str(&format!("\n{name}: {size} byte{}", pluralize!(size)));
+    }
+    Some(rendered)
+}
+
+/// Collects the operands of the `.await`s of a body whose future is larger than the limit.
+struct AwaiteeCollector<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    limit: u64,
+    awaitees: Vec<LargeFutureAwaitee>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for AwaiteeCollector<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        if let hir::ExprKind::Match(scrutinee, [arm], hir::MatchSource::AwaitDesugar) = expr.kind
+            && let hir::ExprKind::Call(_, [operand]) = scrutinee.kind
+            && !operand.span.from_expansion()
+        {
+            let typeck_results = self.cx.typeck_results();
+            let awaitee_ty = typeck_results.node_type(arm.pat.hir_id);
+            // `Box::pin` only works on futures, not on other types implementing `IntoFuture`.
+            if typeck_results.expr_ty(operand) == awaitee_ty
+                && let Ok(awaitee) = self.cx.layout_of(awaitee_ty)
+                && awaitee.size.bytes() > self.limit
+            {
+                self.awaitees.push(LargeFutureAwaitee {
+                    start: operand.span.shrink_to_lo(),
+                    end: operand.span.shrink_to_hi(),
+                });
+            }
+        }
+        intravisit::walk_expr(self, expr);
+    }
+}
@@ -442,6 +442,9 @@ fn late_lint_crate_inner<'tcx, T: LateLintPass<'tcx>>(
 
 /// Performs lint checking on a crate.
 pub fn check_crate<'tcx>(tcx: TyCtxt<'tcx>) {
+    // Validate the `#![lint_config]` attributes, even if no lint reads the options they set.
+    tcx.ensure().lint_config(());
+
     join(
         || {
             tcx.sess.time("crate_lints", || {
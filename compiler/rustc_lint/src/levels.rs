@@ -556,6 +556,16 @@ fn insert(&mut self, id: LintId, lvl: LevelAndSource) {
     }
 
     fn add_command_line(&mut self) {
+        // Lints enabled by `-Z preview-edition-migrations` can be overridden by the other flags.
+        if let Some(edition) = self.sess.opts.unstable_opts.preview_edition_migrations {
+            for id in self.store.edition_migration_lints(edition) {
+                if self.check_gated_lint(id, DUMMY_SP, true) {
+                    let src = LintLevelSource::EditionMigrationPreview(edition);
+                    self.insert(id, (Level::Warn, src));
+                }
+            }
+        }
+
         for &(ref lint_name, level) in &self.sess.opts.lint_opts {
             // Checks the validity of lint names derived from the command line.
             let (tool_name, lint_name_only) = parse_lint_and_tool_name(lint_name);
@@ -656,6 +666,7 @@ fn insert_spec(&mut self, id: LintId, (mut level, src): LevelAndSource) {
                     LintLevelSource::Default => false,
                     LintLevelSource::Node { name, .. } => self.store.is_lint_group(name),
                     LintLevelSource::CommandLine(symbol, _) => self.store.is_lint_group(symbol),
+                    LintLevelSource::EditionMigrationPreview(_) => true,
                 };
                 debug!(
                     "fcw_warning={:?}, specs.get(&id) = {:?}, old_src={:?}, id_name={:?}",
@@ -671,7 +682,10 @@ fn insert_spec(&mut self, id: LintId, (mut level, src): LevelAndSource) {
                     LintLevelSource::Node { span, reason, .. } => {
                         OverruledAttributeSub::NodeSource { span, reason }
                     }
-                    LintLevelSource::CommandLine(_, _) => OverruledAttributeSub::CommandLineSource,
+                    LintLevelSource::CommandLine(_, _)
+                    | LintLevelSource::EditionMigrationPreview(_) => {
+                        OverruledAttributeSub::CommandLineSource
+                    }
                 };
                 if !fcw_warning {
                     self.sess.dcx().emit_err(OverruledAttribute {
@@ -48,6 +48,7 @@
 
 mod array_into_iter;
 mod async_fn_in_trait;
+mod blocking_in_async;
 pub mod builtin;
 mod context;
 mod deref_into_dyn_supertrait;
@@ -61,9 +62,11 @@
 pub mod hidden_unicode_codepoints;
 mod internal;
 mod invalid_from_utf8;
+mod large_future;
 mod late;
 mod let_underscore;
 mod levels;
+mod lint_config;
 mod lints;
 mod map_unit_fn;
 mod methods;
@@ -76,12 +79,16 @@
 mod pass_by_value;
 mod passes;
 mod ptr_nulls;
+mod redundant_bounds;
+mod redundant_clone;
 mod redundant_semicolon;
 mod reference_casting;
+mod temporary_lifetime;
 mod traits;
 mod types;
 mod unit_bindings;
 mod unused;
+mod unused_generic_params;
 
 pub use array_into_iter::ARRAY_INTO_ITER;
 
@@ -91,6 +98,7 @@
 
 use array_into_iter::ArrayIntoIter;
 use async_fn_in_trait::AsyncFnInTrait;
+use blocking_in_async::*;
 use builtin::*;
 use deref_into_dyn_supertrait::*;
 use drop_forget_useless::*;
@@ -99,6 +107,7 @@
 use hidden_unicode_codepoints::*;
 use internal::*;
 use invalid_from_utf8::*;
+use large_future::*;
 use let_underscore::*;
 use map_unit_fn::*;
 use methods::*;
@@ -110,12 +119,16 @@
 use opaque_hidden_inferred_bound::*;
 use pass_by_value::*;
 use ptr_nulls::*;
+use redundant_bounds::*;
+use redundant_clone::*;
 use redundant_semicolon::*;
 use reference_casting::*;
+use temporary_lifetime::*;
 use traits::*;
 use types::*;
 use unit_bindings::*;
 use unused::*;
+use unused_generic_params::*;
 
 /// Useful for other parts of the compiler / Clippy.
 pub use builtin::{MissingDoc, SoftLints};
@@ -132,6 +145,7 @@
 
 pub fn provide(providers: &mut Providers) {
     levels::provide(providers);
+    lint_config::provide(providers);
     expect::provide(providers);
     foreign_modules::provide(providers);
     *providers = Providers { lint_mod, ..*providers };
@@ -191,6 +205,7 @@ fn lint_mod(tcx: TyCtxt<'_>, module_def_id: LocalModDefId) {
             BoxPointers: BoxPointers,
             PathStatements: PathStatements,
             LetUnderscore: LetUnderscore,
+            TemporaryLifetime: TemporaryLifetime,
             InvalidReferenceCasting: InvalidReferenceCasting,
             // Depends on referenced function signatures in expressions
             UnusedResults: UnusedResults,
@@ -227,10 +242,15 @@ fn lint_mod(tcx: TyCtxt<'_>, module_def_id: LocalModDefId) {
             NamedAsmLabels: NamedAsmLabels,
             OpaqueHiddenInferredBound: OpaqueHiddenInferredBound,
             MultipleSupertraitUpcastable: MultipleSupertraitUpcastable,
+            RedundantBounds: RedundantBounds,
+            RedundantClone: RedundantClone,
             MapUnitFn: MapUnitFn,
             MissingDebugImplementations: MissingDebugImplementations,
             MissingDoc: MissingDoc,
             AsyncFnInTrait: AsyncFnInTrait,
+            UnusedGenericParameter: UnusedGenericParameter,
+            LargeFuture: LargeFuture,
+            BlockingInAsync: BlockingInAsync,
         ]
     ]
 );
@@ -296,6 +316,15 @@ macro_rules! add_lint_group {
 
     add_lint_group!("let_underscore", LET_UNDERSCORE_DROP, LET_UNDERSCORE_LOCK);
 
+    add_lint_group!(
+        "temporary_lifetime",
+        IF_LET_TEMPORARY_LIFETIME,
+        LET_ELSE_TEMPORARY_LIFETIME,
+        DROP_ORDER_DEPENDENCE
+    );
+
+    add_lint_group!("perf", REDUNDANT_CLONE);
+
     add_lint_group!(
         "rust_2018_idioms",
         BARE_TRAIT_OBJECTS,
@@ -0,0 +1,114 @@
+//! Collects the values of the lint options set with `#![lint_config(lint::option = value)]`.
+//!
+//! Lints declare their options with `@config = [option: Kind]` in `declare_lint!`, and read
+//! them with [`LateContext::lint_config`](crate::LateContext::lint_config). The values are the
+//! result of a query, so that changing them only invalidates the lints reading them.
+
+use crate::errors::{
+    LintConfigDuplicate, LintConfigInvalidValue, LintConfigMalformed, LintConfigUnknownLint,
+    LintConfigUnknownOption,
+};
+use crate::late::unerased_lint_store;
+use rustc_ast as ast;
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_middle::lint::LintConfig;
+use rustc_middle::query::Providers;
+use rustc_middle::ty::TyCtxt;
+use rustc_session::lint::{LintConfigKind, LintConfigValue};
+use rustc_span::symbol::{sym, Ident, Symbol};
+
+pub(crate) fn provide(providers: &mut Providers) {
+    *providers = Providers { lint_config, ..*providers };
+}
+
+fn lint_config(tcx: TyCtxt<'_>, (): ()) -> LintConfig {
+    let store = unerased_lint_store(tcx.sess);
+    let dcx = tcx.dcx();
+
+    let mut config = LintConfig::default();
+    let mut spans = FxIndexMap::default();
+    for attr in tcx.hir().krate_attrs() {
+        if !attr.has_name(sym::lint_config) {
+            continue;
+        }
+        // Attributes without a list are reported when checking the attribute template.
+        for item in attr.meta_item_list().unwrap_or_default() {
+            let Some((lint_segments, option, lit)) = split_item(&item) else {
+                dcx.emit_err(LintConfigMalformed { span: item.span() });
+                continue;
+            };
+
+            let lint_name = lint_segments
+                .iter()
+                .map(|segment| segment.ident.as_str())
+                .collect::<Vec<_>>()
+                .join("::");
+            // Groups can't be configured, only the lints they contain.
+            let is_group = store.is_lint_group(Symbol::intern(&lint_name));
+            let lint = match store.find_lints(&lint_name).as_deref() {
+                Ok(&[lint]) if !is_group => lint,
+                _ => {
+                    let last = lint_segments[lint_segments.len() - 1].ident.span;
+                    let span = lint_segments[0].ident.span.to(last);
+                    dcx.emit_err(LintConfigUnknownLint {
+                        span,
+                        lint: lint_name,
+                        is_group: is_group.then_some(()),
+                    });
+                    continue;
+                }
+            };
+
+            let Some(declared) = lint.lint.config.iter().find(|o| o.name == option.as_str()) else {
+                dcx.emit_err(LintConfigUnknownOption {
+                    span: option.span,
+                    lint: lint.to_string(),
+                    option: option.name,
+                });
+                continue;
+            };
+
+            let value = match (declared.kind, &lit.kind) {
+                (LintConfigKind::Int, ast::LitKind::Int(value, _)) => {
+                    LintConfigValue::Int(value.get())
+                }
+                (LintConfigKind::Bool, &ast::LitKind::Bool(value)) => LintConfigValue::Bool(value),
+                (LintConfigKind::Str, &ast::LitKind::Str(value, _)) => LintConfigValue::Str(value),
+                _ => {
+                    dcx.emit_err(LintConfigInvalidValue {
+                        span: lit.span,
+                        lint: lint.to_string(),
+                        option: option.name,
+                        expected: declared.kind.descr(),
+                    });
+                    continue;
+                }
+            };
+
+            if let Some(&first) = spans.get(&(lint, option.name)) {
+                dcx.emit_err(LintConfigDuplicate {
+                    span: item.span(),
+                    first,
+                    lint: lint.to_string(),
+                    option: option.name,
+                });
+                continue;
+            }
+            spans.insert((lint, option.name), item.span());
+            config.values.insert((lint, option.name), value);
+        }
+    }
+    config
+}
+
+/// Splits `lint::option = value` into the path of the lint, the option and the value.
+fn split_item(
+    item: &ast::NestedMetaItem,
+) -> Option<(&[ast::PathSegment], Ident, &ast::MetaItemLit)> {
+    let meta = item.meta_item()?;
+    let ast::MetaItemKind::NameValue(lit) = &meta.kind else { return None };
+    match &meta.path.segments[..] {
+        [lint @ .., option] if !lint.is_empty() => Some((lint, option.ident, lit)),
+        _ => None,
+    }
+}
@@ -906,6 +906,59 @@ pub struct BadOptAccessDiag<'a> {
     pub msg: &'a str,
 }
 
+// blocking_in_async.rs
+#[derive(LintDiagnostic)]
+#[diag(lint_blocking_in_async)]
+#[note]
+pub struct BlockingInAsyncDiag {
+    pub callee: String,
+    #[subdiagnostic]
+    pub through: Option<BlockingInAsyncThrough>,
+    #[subdiagnostic]
+    pub alternative: Option<BlockingInAsyncAlternative>,
+}
+
+#[derive(Subdiagnostic)]
+#[note(lint_blocking_in_async_through)]
+pub struct BlockingInAsyncThrough {
+    pub blocking: String,
+}
+
+#[derive(Subdiagnostic)]
+pub enum BlockingInAsyncAlternative {
+    #[multipart_suggestion(lint_blocking_in_async_alternative, applicability = "maybe-incorrect")]
+    Suggestion {
+        #[suggestion_part(code = "{alternative}")]
+        path: Span,
+        #[suggestion_part(code = ".await")]
+        end: Span,
+        alternative: Symbol,
+    },
+    #[help(lint_blocking_in_async_alternative)]
+    Help { alternative: Symbol },
+}
+
+// large_future.rs
+#[derive(LintDiagnostic)]
+#[diag(lint_large_future)]
+#[note]
+pub struct LargeFutureDiag {
+    pub size: u64,
+    pub limit: u64,
+    pub fields: String,
+    #[subdiagnostic]
+    pub awaitees: Vec<LargeFutureAwaitee>,
+}
+
+#[derive(Subdiagnostic)]
+#[multipart_suggestion(lint_suggestion, applicability = "machine-applicable")]
+pub struct LargeFutureAwaitee {
+    #[suggestion_part(code = "Box::pin(")]
+    pub start: Span,
+    #[suggestion_part(code = ")")]
+    pub end: Span,
+}
+
 // let_underscore.rs
 #[derive(LintDiagnostic)]
 pub enum NonBindingLet {
@@ -1302,6 +1355,65 @@ pub struct PassByValueDiag {
     pub suggestion: Span,
 }
 
+// redundant_clone.rs
+#[derive(LintDiagnostic)]
+#[diag(lint_redundant_clone_copy)]
+pub struct RedundantCloneCopyDiag<'a> {
+    pub ty: Ty<'a>,
+    #[subdiagnostic]
+    pub sugg: RedundantCloneCopySugg,
+}
+
+#[derive(Subdiagnostic)]
+pub enum RedundantCloneCopySugg {
+    #[suggestion(lint_suggestion, code = "", applicability = "machine-applicable")]
+    Remove {
+        #[primary_span]
+        span: Span,
+    },
+    #[multipart_suggestion(lint_suggestion, applicability = "machine-applicable")]
+    Deref {
+        #[suggestion_part(code = "{prefix}")]
+        start: Span,
+        #[suggestion_part(code = "{suffix}")]
+        end: Span,
+        prefix: String,
+        suffix: String,
+    },
+}
+
+#[derive(LintDiagnostic)]
+#[diag(lint_redundant_clone_borrowed)]
+#[note]
+pub struct RedundantCloneBorrowedDiag<'a> {
+    pub ty: Ty<'a>,
+    #[suggestion(code = "", applicability = "machine-applicable")]
+    pub suggestion: Span,
+}
+
+// redundant_bounds.rs
+#[derive(LintDiagnostic)]
+#[diag(lint_redundant_bound)]
+pub struct RedundantBoundDiag {
+    pub bound: String,
+    pub implied_by: String,
+    #[label]
+    pub implied_by_span: Span,
+    #[suggestion(code = "", applicability = "machine-applicable")]
+    pub suggestion: Span,
+    #[subdiagnostic]
+    pub chain: Vec<RedundantBoundChainLabel>,
+}
+
+#[derive(Subdiagnostic)]
+#[label(lint_redundant_bound_chain)]
+pub struct RedundantBoundChainLabel {
+    #[primary_span]
+    pub span: Span,
+    pub parent: String,
+    pub implied: String,
+}
+
 // redundant_semicolon.rs
 #[derive(LintDiagnostic)]
 #[diag(lint_redundant_semicolons)]
@@ -1311,6 +1423,65 @@ pub struct RedundantSemicolonsDiag {
     pub suggestion: Span,
 }
 
+// temporary_lifetime.rs
+#[derive(LintDiagnostic)]
+#[diag(lint_if_let_temporary_lifetime)]
+#[note(lint_rust_2024_note)]
+pub struct IfLetTemporaryLifetime {
+    #[label]
+    pub temporary: Span,
+    #[label(lint_else_label)]
+    pub else_block: Span,
+    #[note(lint_drop_note)]
+    pub drop: Span,
+}
+
+#[derive(LintDiagnostic)]
+pub enum LetElseTemporaryLifetime {
+    #[diag(lint_let_else_temporary_lifetime)]
+    Statement {
+        #[label]
+        temporary: Span,
+        #[note(lint_drop_note)]
+        drop: Span,
+    },
+    #[diag(lint_let_else_extended_temporary_lifetime)]
+    Extended {
+        #[label]
+        temporary: Span,
+        #[note(lint_drop_note)]
+        drop: Span,
+    },
+}
+
+#[derive(LintDiagnostic)]
+#[diag(lint_drop_order_dependence)]
+#[note(lint_rust_2024_note)]
+pub struct DropOrderDependence {
+    pub name: Symbol,
+    #[label]
+    pub temporary: Span,
+    #[label(lint_local_label)]
+    pub local: Span,
+    #[label(lint_local_drop_label)]
+    pub local_drop: Span,
+    #[note(lint_drop_note)]
+    pub temporary_drop: Span,
+    #[subdiagnostic]
+    pub suggestion: Option<DropOrderDependenceSuggestion>,
+}
+
+#[derive(Subdiagnostic)]
+#[multipart_suggestion(lint_suggestion, applicability = "maybe-incorrect")]
+pub struct DropOrderDependenceSuggestion {
+    #[suggestion_part(code = "let result = ")]
+    pub start: Span,
+    #[suggestion_part(code = ";\n{indent}drop({name});\n{indent}result")]
+    pub end: Span,
+    pub indent: String,
+    pub name: Symbol,
+}
+
 // traits.rs
 pub struct DropTraitConstraintsDiag<'a> {
     pub predicate: Clause<'a>,
@@ -1472,6 +1643,29 @@ pub struct OnlyCastu8ToChar {
     pub literal: u128,
 }
 
+#[derive(LintDiagnostic)]
+#[diag(lint_lossy_integer_cast)]
+pub struct LossyIntegerCast<'a> {
+    pub from: Ty<'a>,
+    pub to: Ty<'a>,
+    #[help]
+    pub help: Option<()>,
+    #[subdiagnostic]
+    pub suggestion: Option<LossyIntegerCastSuggestion<'a>>,
+}
+
+#[derive(Subdiagnostic)]
+#[multipart_suggestion(lint_suggestion)]
+pub struct LossyIntegerCastSuggestion<'a> {
+    #[suggestion_part(code = "{to}::try_from(")]
+    pub start: Span,
+    #[suggestion_part(code = ")?")]
+    pub end: Span,
+    pub to: Ty<'a>,
+    #[applicability]
+    pub applicability: Applicability,
+}
+
 #[derive(LintDiagnostic)]
 #[diag(lint_overflowing_uint)]
 #[note]
@@ -1849,3 +2043,22 @@ pub struct UnitBindingsDiag {
     #[label]
     pub label: Span,
 }
+
+// unused_generic_params.rs
+#[derive(LintDiagnostic)]
+#[diag(lint_unused_generic_parameter)]
+pub struct UnusedGenericParameterDiag {
+    pub count: usize,
+    pub names: String,
+    #[subdiagnostic]
+    pub suggestion: Option<UnusedGenericParameterSuggestion>,
+}
+
+#[derive(Subdiagnostic)]
+#[multipart_suggestion(lint_suggestion)]
+pub struct UnusedGenericParameterSuggestion {
+    #[suggestion_part(code = "")]
+    pub spans: Vec<Span>,
+    #[applicability]
+    pub applicability: Applicability,
+}
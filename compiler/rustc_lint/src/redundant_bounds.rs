@@ -0,0 +1,151 @@
+use crate::lints::{RedundantBoundChainLabel, RedundantBoundDiag};
+use crate::{LateContext, LateLintPass, Level, LintContext};
+
+use rustc_hir as hir;
+use rustc_hir::def_id::LocalDefId;
+use rustc_middle::ty;
+use rustc_trait_selection::traits::elaborate_with_provenance;
+
+declare_lint! {
+    /// The `redundant_bounds` lint detects trait bounds that are already implied
+    /// by another bound on the same item, either because they are repeated or
+    /// because they are a supertrait of another bound.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![warn(redundant_bounds)]
+    /// fn max<T: Ord + PartialOrd>(a: T, b: T) -> T {
+    ///     if a > b { a } else { b }
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// `PartialOrd` is a supertrait of `Ord`, so requiring `T: Ord` already
+    /// requires `T: PartialOrd`. The redundant bound can be removed without
+    /// changing the meaning of the program.
+    pub REDUNDANT_BOUNDS,
+    Allow,
+    "detects trait bounds that are implied by other bounds"
+}
+
+declare_lint_pass!(RedundantBounds => [REDUNDANT_BOUNDS]);
+
+impl<'tcx> LateLintPass<'tcx> for RedundantBounds {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        match item.kind {
+            // Bounds on type aliases are not enforced, `type_alias_bounds` handles them.
+            hir::ItemKind::TyAlias(..) => {}
+            _ => {
+                if let Some(generics) = item.kind.generics() {
+                    check_generics(cx, item.owner_id.def_id, generics);
+                }
+            }
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitItem<'tcx>) {
+        check_generics(cx, item.owner_id.def_id, item.generics);
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'tcx>) {
+        check_generics(cx, item.owner_id.def_id, item.generics);
+    }
+}
+
+fn trait_path(clause: ty::Clause<'_>) -> String {
+    clause.as_trait_clause().unwrap().print_modifiers_and_trait_path().to_string()
+}
+
+/// Lints every trait bound written in `generics` that is equal to an earlier
+/// bound of `def_id`, or that is reachable by elaborating another one.
+fn check_generics<'tcx>(
+    cx: &LateContext<'tcx>,
+    def_id: LocalDefId,
+    generics: &'tcx hir::Generics<'tcx>,
+) {
+    let tcx = cx.tcx;
+    // Elaborating every bound is not free, so skip the work when the lint is off.
+    let (level, _) = tcx.lint_level_at_node(REDUNDANT_BOUNDS, tcx.local_def_id_to_hir_id(def_id));
+    if level == Level::Allow {
+        return;
+    }
+
+    let clauses: Vec<_> = tcx
+        .explicit_predicates_of(def_id)
+        .predicates
+        .iter()
+        .filter(|(clause, _)| clause.as_trait_clause().is_some())
+        .copied()
+        .collect();
+    if clauses.len() < 2 {
+        return;
+    }
+
+    for (predicate_pos, predicate) in generics.predicates.iter().enumerate() {
+        let hir::WherePredicate::BoundPredicate(predicate) = predicate else { continue };
+        for (bound_pos, bound) in predicate.bounds.iter().enumerate() {
+            let hir::GenericBound::Trait(_, hir::TraitBoundModifier::None) = bound else {
+                continue;
+            };
+            let span = bound.span();
+            if span.from_expansion() {
+                continue;
+            }
+            let Some(pos) = clauses.iter().position(|&(_, clause_span)| clause_span == span) else {
+                continue;
+            };
+            let bound_clause = clauses[pos].0;
+            let bound_kind = tcx.anonymize_bound_vars(bound_clause.kind());
+
+            let mut chain = vec![];
+            let implied_by =
+                clauses.iter().enumerate().find(|&(other_pos, &(other, other_span))| {
+                    if other_pos == pos {
+                        return false;
+                    }
+                    if tcx.anonymize_bound_vars(other.kind()) == bound_kind {
+                        // Of two identical bounds, only the later one is redundant.
+                        return other_pos < pos;
+                    }
+                    let Some(info) = elaborate_with_provenance(tcx, [(other, other_span)])
+                        .filter_only_self()
+                        .skip(1)
+                        .find(|info| tcx.anonymize_bound_vars(info.clause().kind()) == bound_kind)
+                    else {
+                        return false;
+                    };
+                    // Point at each local supertrait bound through which the redundant
+                    // bound is implied.
+                    chain = info
+                        .path
+                        .windows(2)
+                        .filter(|step| !tcx.sess.source_map().is_imported(step[1].1))
+                        .map(|step| RedundantBoundChainLabel {
+                            span: step[1].1,
+                            parent: trait_path(step[0].0),
+                            implied: trait_path(step[1].0),
+                        })
+                        .collect();
+                    true
+                });
+
+            if let Some((_, &(implied_by, implied_by_span))) = implied_by {
+                cx.emit_span_lint(
+                    REDUNDANT_BOUNDS,
+                    span,
+                    RedundantBoundDiag {
+                        bound: trait_path(bound_clause),
+                        implied_by: trait_path(implied_by),
+                        implied_by_span,
+                        suggestion: generics.span_for_bound_removal(predicate_pos, bound_pos),
+                        chain,
+                    },
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::context::LintContext;
+use crate::lints::{RedundantCloneBorrowedDiag, RedundantCloneCopyDiag, RedundantCloneCopySugg};
+use crate::LateContext;
+use crate::LateLintPass;
+use rustc_ast::util::parser::PREC_PREFIX;
+use rustc_hir::def::DefKind;
+use rustc_hir::{Expr, ExprKind};
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability, PointerCoercion};
+use rustc_middle::ty::{self, Ty};
+use rustc_span::symbol::sym;
+
+declare_lint! {
+    /// The `redundant_clone` lint detects calls to `.clone()` that can be removed without
+    /// changing the behavior of the program, because the cloned value is `Copy` or because the
+    /// clone is only borrowed right away.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![warn(redundant_clone)]
+    /// fn f(x: u32, s: &String) -> usize {
+    ///     x.clone() as usize + s.clone().len()
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Calling `.clone()` on a `Copy` type does the same as copying the value, and is harder
+    /// to read. Cloning a value only to borrow the clone, e.g. to call a method taking `&self`
+    /// on it, needlessly allocates and copies the value, as the method can be called on the
+    /// original value instead.
+    ///
+    /// This lint is part of the `perf` lint group, and is "allow" by default, as such clones
+    /// are harmless and common in existing code.
+    pub REDUNDANT_CLONE,
+    Allow,
+    "detects calls to `.clone()` that can be removed"
+}
+
+declare_lint_pass!(RedundantClone => [REDUNDANT_CLONE]);
+
+impl<'tcx> LateLintPass<'tcx> for RedundantClone {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let Some((receiver, self_ty)) = clone_call(cx, expr) else { return };
+        let span = expr.span.with_lo(receiver.span.hi());
+
+        // References are handled by `noop_method_call` and `suspicious_double_ref_op`.
+        if let ty::Ref(..) = self_ty.kind() {
+            return;
+        }
+
+        if self_ty.is_copy_modulo_regions(cx.tcx, cx.param_env) {
+            let adjustments = cx.typeck_results().expr_adjustments(receiver);
+            // The receiver is either auto-referenced to get a `&Self`, or is a `&Self` after
+            // dereferencing it, in which case we need one more dereference to copy the value.
+            let autoref =
+                adjustments.last().is_some_and(|adj| matches!(adj.kind, Adjust::Borrow(_)));
+            let mut derefs = usize::from(!autoref);
+            for adj in adjustments {
+                match adj.kind {
+                    Adjust::Deref(None) => derefs += 1,
+                    Adjust::Borrow(_) => {}
+                    // Overloaded dereferences cannot be replaced by `*`.
+                    _ => return,
+                }
+            }
+
+            let sugg = if derefs == 0 {
+                RedundantCloneCopySugg::Remove { span }
+            } else {
+                let parens = receiver.precedence().order() < PREC_PREFIX;
+                RedundantCloneCopySugg::Deref {
+                    start: receiver.span.shrink_to_lo(),
+                    end: span,
+                    prefix: format!("{}{}", "*".repeat(derefs), if parens { "(" } else { "" }),
+                    suffix: if parens { ")" } else { "" }.to_string(),
+                }
+            };
+            cx.emit_span_lint(REDUNDANT_CLONE, span, RedundantCloneCopyDiag { ty: self_ty, sugg });
+            return;
+        }
+
+        // The clone is only borrowed if it is immediately auto-referenced, e.g. to call a method
+        // taking `&self` on it.
+        let adjustments = cx.typeck_results().expr_adjustments(expr);
+        let only_borrowed = adjustments.iter().any(|adj| {
+            matches!(
+                adj.kind,
+                Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Not))
+                    | Adjust::Deref(Some(_))
+            )
+        }) && adjustments.iter().all(|adj| match adj.kind {
+            Adjust::Deref(None)
+            | Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Not))
+            | Adjust::Pointer(PointerCoercion::Unsize) => true,
+            Adjust::Deref(Some(deref)) => deref.mutbl.is_not(),
+            _ => false,
+        });
+        if only_borrowed {
+            cx.emit_span_lint(
+                REDUNDANT_CLONE,
+                span,
+                RedundantCloneBorrowedDiag { ty: self_ty, suggestion: span },
+            );
+        }
+    }
+}
+
+/// If `expr` is a call of `Clone::clone`, returns the receiver and the type being cloned.
+fn clone_call<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<(&'tcx Expr<'tcx>, Ty<'tcx>)> {
+    let ExprKind::MethodCall(call, receiver, [], call_span) = expr.kind else { return None };
+    if call.ident.name != sym::clone || call_span.from_expansion() {
+        return None;
+    }
+    let Some((DefKind::AssocFn, did)) = cx.typeck_results().type_dependent_def(expr.hir_id) else {
+        return None;
+    };
+    let trait_id = cx.tcx.trait_of_item(did)?;
+    if !cx.tcx.is_diagnostic_item(sym::Clone, trait_id) {
+        return None;
+    }
+    let args = cx.typeck_results().node_args(expr.hir_id);
+    let self_ty = cx.tcx.normalize_erasing_regions(cx.param_env, args.type_at(0));
+    Some((receiver, self_ty))
+}
@@ -0,0 +1,249 @@
+use crate::{
+    lints::{
+        DropOrderDependence, DropOrderDependenceSuggestion, IfLetTemporaryLifetime,
+        LetElseTemporaryLifetime,
+    },
+    LateContext, LateLintPass, LintContext,
+};
+use rustc_hir as hir;
+use rustc_middle::middle::drop_order::ScopedDropKind;
+
+declare_lint! {
+    /// The `if_let_temporary_lifetime` lint checks for temporaries with a
+    /// significant drop implementation, created in the scrutinee of an
+    /// `if let` with an `else` block, which are kept alive until after the
+    /// `else` block.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![warn(if_let_temporary_lifetime)]
+    /// use std::sync::Mutex;
+    ///
+    /// fn pop(queue: &Mutex<Vec<u32>>) -> u32 {
+    ///     if let Some(value) = queue.lock().unwrap().pop() {
+    ///         value
+    ///     } else {
+    ///         // The lock is still held here.
+    ///         0
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Temporaries created in the scrutinee of an `if let` are dropped at the
+    /// end of the statement containing the whole `if let` expression, after
+    /// the `else` block has run. This is easy to overlook for values like
+    /// `MutexGuard`, as locking a mutex in the scrutinee then keeps it locked
+    /// in the `else` block.
+    ///
+    /// Starting with Rust 2024, these temporaries are meant to be dropped
+    /// before entering the `else` block instead. This lint can be used to
+    /// audit the code whose behavior is affected by this change.
+    pub IF_LET_TEMPORARY_LIFETIME,
+    Allow,
+    "temporaries in the scrutinee of `if let` that are dropped after the `else` block"
+}
+
+declare_lint! {
+    /// The `let_else_temporary_lifetime` lint checks for temporaries with a
+    /// significant drop implementation, created in the initializer of a
+    /// `let ... else` statement, which outlive the initializer.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![warn(let_else_temporary_lifetime)]
+    /// use std::sync::Mutex;
+    ///
+    /// fn pop(queue: &Mutex<Vec<u32>>) -> u32 {
+    ///     let Some(value) = queue.lock().unwrap().pop() else {
+    ///         // The lock is still held here.
+    ///         return 0;
+    ///     };
+    ///     value
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Temporaries created in the initializer of a `let ... else` statement
+    /// are dropped at the end of the statement, which means they are still
+    /// alive while the `else` block runs. Temporaries whose lifetime is
+    /// extended, such as the operand of a borrow bound by the pattern, even
+    /// live until the end of the enclosing block.
+    ///
+    /// This lint reports which of these decisions was made for every such
+    /// temporary with a significant drop implementation.
+    pub LET_ELSE_TEMPORARY_LIFETIME,
+    Allow,
+    "temporaries in the initializer of `let ... else` that outlive it"
+}
+
+declare_lint! {
+    /// The `drop_order_dependence` lint checks for temporaries with a
+    /// significant drop implementation, created in the tail expression of a
+    /// block, which are dropped after a local variable of that block that has
+    /// a significant drop implementation as well.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![warn(drop_order_dependence)]
+    /// use std::cell::RefCell;
+    /// use std::sync::Mutex;
+    ///
+    /// fn total(lock: &Mutex<Vec<u32>>, cell: &RefCell<Vec<u32>>) -> usize {
+    ///     let guard = lock.lock().unwrap();
+    ///     guard.len() + cell.borrow().len()
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Temporaries created in the tail expression of a block live until the
+    /// end of the statement enclosing the block, or until the end of the
+    /// function for its body. They are therefore dropped after the local
+    /// variables of the block, e.g. the `RefCell` borrow above is released
+    /// after the mutex is unlocked.
+    ///
+    /// Starting with Rust 2024, these temporaries are meant to be dropped
+    /// before the local variables of the block instead. This lint can be used
+    /// to find the code whose behavior depends on which of the two orders is
+    /// used, and to make that order explicit.
+    pub DROP_ORDER_DEPENDENCE,
+    Allow,
+    "temporaries in the tail expression of a block that are dropped after its local variables"
+}
+
+declare_lint_pass!(TemporaryLifetime => [
+    IF_LET_TEMPORARY_LIFETIME,
+    LET_ELSE_TEMPORARY_LIFETIME,
+    DROP_ORDER_DEPENDENCE,
+]);
+
+impl<'tcx> LateLintPass<'tcx> for TemporaryLifetime {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let hir::ExprKind::If(cond, _, Some(else_block)) = expr.kind else { return };
+        let hir::ExprKind::Let(let_expr) = cond.kind else { return };
+        if expr.span.from_expansion() {
+            return;
+        }
+
+        let body_owner = cx.tcx.hir().enclosing_body_owner(expr.hir_id);
+        let drop_order = cx.tcx.drop_order(body_owner.to_def_id());
+        for (scope, temporary) in drop_order.temporaries_outliving(let_expr.init.span) {
+            // Temporaries dropped within the `if let`, like in a block of the scrutinee, don't
+            // outlive the `else` block.
+            if !temporary.is_significant || scope.span.lo() < else_block.span.hi() {
+                continue;
+            }
+            cx.emit_span_lint(
+                IF_LET_TEMPORARY_LIFETIME,
+                temporary.span,
+                IfLetTemporaryLifetime {
+                    temporary: temporary.span,
+                    else_block: else_block.span,
+                    drop: scope.span,
+                },
+            );
+        }
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx hir::Local<'tcx>) {
+        let Some(init) = local.init else { return };
+        if local.els.is_none() || local.span.from_expansion() {
+            return;
+        }
+
+        let body_owner = cx.tcx.hir().enclosing_body_owner(local.hir_id);
+        let drop_order = cx.tcx.drop_order(body_owner.to_def_id());
+        for (scope, temporary) in drop_order.temporaries_outliving(init.span) {
+            if !temporary.is_significant {
+                continue;
+            }
+            let diag = if scope.span.lo() > local.span.hi() {
+                LetElseTemporaryLifetime::Extended { temporary: temporary.span, drop: scope.span }
+            } else {
+                LetElseTemporaryLifetime::Statement { temporary: temporary.span, drop: scope.span }
+            };
+            cx.emit_span_lint(LET_ELSE_TEMPORARY_LIFETIME, temporary.span, diag);
+        }
+    }
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx hir::Block<'tcx>) {
+        let Some(tail) = block.expr else { return };
+        if block.span.from_expansion() || tail.span.from_expansion() {
+            return;
+        }
+
+        let body_owner = cx.tcx.hir().enclosing_body_owner(block.hir_id);
+        let drop_order = cx.tcx.drop_order(body_owner.to_def_id());
+        let source_map = cx.sess().source_map();
+        let block_end = source_map.end_point(block.span);
+        // The variables declared in the block, which are dropped when leaving it, together with
+        // the position of the scope they are dropped in.
+        let locals: Vec<_> = drop_order
+            .scopes
+            .iter()
+            .enumerate()
+            .filter(|(_, scope)| scope.span == block_end)
+            .flat_map(|(index, scope)| scope.drops.iter().map(move |drop| (index, scope, drop)))
+            .filter_map(|(index, scope, drop)| match drop.kind {
+                ScopedDropKind::Binding(name)
+                    if drop.is_significant
+                        && block.span.contains(drop.span)
+                        && !tail.span.contains(drop.span) =>
+                {
+                    Some((index, scope, drop, name))
+                }
+                _ => None,
+            })
+            .collect();
+        if locals.is_empty() {
+            return;
+        }
+
+        for (scope, temporary) in drop_order.temporaries_outliving(tail.span) {
+            if !temporary.is_significant {
+                continue;
+            }
+            let Some(temporary_index) =
+                drop_order.scopes.iter().position(|other| std::ptr::eq(other, scope))
+            else {
+                continue;
+            };
+            for &(local_index, local_scope, local, name) in &locals {
+                if local_index >= temporary_index {
+                    continue;
+                }
+                let suggestion = source_map.indentation_before(tail.span).map(|indent| {
+                    DropOrderDependenceSuggestion {
+                        start: tail.span.shrink_to_lo(),
+                        end: tail.span.shrink_to_hi(),
+                        indent,
+                        name,
+                    }
+                });
+                cx.emit_span_lint(
+                    DROP_ORDER_DEPENDENCE,
+                    temporary.span,
+                    DropOrderDependence {
+                        name,
+                        temporary: temporary.span,
+                        local: local.span,
+                        local_drop: local_scope.span,
+                        temporary_drop: scope.span,
+                        suggestion,
+                    },
+                );
+            }
+        }
+    }
+}
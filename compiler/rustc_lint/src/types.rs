@@ -4,19 +4,23 @@
         AmbiguousWidePointerComparisons, AmbiguousWidePointerComparisonsAddrMetadataSuggestion,
         AmbiguousWidePointerComparisonsAddrSuggestion, AtomicOrderingFence, AtomicOrderingLoad,
         AtomicOrderingStore, ImproperCTypes, InvalidAtomicOrderingDiag, InvalidNanComparisons,
-        InvalidNanComparisonsSuggestion, OnlyCastu8ToChar, OverflowingBinHex,
-        OverflowingBinHexSign, OverflowingBinHexSignBitSub, OverflowingBinHexSub, OverflowingInt,
-        OverflowingIntHelp, OverflowingLiteral, OverflowingUInt, RangeEndpointOutOfRange,
-        UnusedComparisons, UseInclusiveRange, VariantSizeDifferencesDiag,
+        InvalidNanComparisonsSuggestion, LossyIntegerCast, LossyIntegerCastSuggestion,
+        OnlyCastu8ToChar, OverflowingBinHex, OverflowingBinHexSign, OverflowingBinHexSignBitSub,
+        OverflowingBinHexSub, OverflowingInt, OverflowingIntHelp, OverflowingLiteral,
+        OverflowingUInt, RangeEndpointOutOfRange, UnusedComparisons, UseInclusiveRange,
+        VariantSizeDifferencesDiag,
     },
 };
 use crate::{LateContext, LateLintPass, LintContext};
 use rustc_ast as ast;
 use rustc_attr as attr;
 use rustc_data_structures::fx::FxHashSet;
-use rustc_errors::DiagnosticMessage;
+use rustc_errors::{Applicability, DiagnosticMessage};
 use rustc_hir as hir;
+use rustc_hir::def::DefKind;
 use rustc_hir::{is_range_literal, Expr, ExprKind, Node};
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::layout::{IntegerExt, LayoutOf, SizeSkeleton};
 use rustc_middle::ty::{
     self, AdtKind, Ty, TyCtxt, TypeSuperVisitable, TypeVisitable, TypeVisitableExt,
@@ -29,6 +33,7 @@
 use rustc_target::abi::{Abi, Size, WrappingRange};
 use rustc_target::abi::{Integer, TagEncoding, Variants};
 use rustc_target::spec::abi::Abi as SpecAbi;
+use rustc_trait_selection::infer::InferCtxtExt;
 
 use std::iter;
 use std::ops::ControlFlow;
@@ -168,6 +173,40 @@
     "detects ambiguous wide pointer comparisons"
 }
 
+declare_lint! {
+    /// The `lossy_integer_cast` lint detects `as` casts between integer types
+    /// which may truncate the value or change its sign.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![warn(lossy_integer_cast)]
+    /// fn header_len(len: usize) -> Result<u16, std::num::TryFromIntError> {
+    ///     Ok(len as u16)
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// An `as` cast to an integer type which can't hold all the values of
+    /// the original type silently wraps the values that don't fit. Converting
+    /// with `TryFrom` instead handles those values explicitly. When the cast
+    /// is in a function returning a `Result` whose error can be converted
+    /// from a `TryFromIntError`, the lint suggests returning the error with
+    /// `?`.
+    ///
+    /// For `isize` and `usize`, the lint assumes a 64-bit target, so that
+    /// its warnings are consistent between 32- and 64-bit targets.
+    ///
+    /// This lint is "allow" by default because such casts are often
+    /// intended, for example when only the low bits of a value are needed.
+    pub LOSSY_INTEGER_CAST,
+    Allow,
+    "detects `as` casts between integer types which may change the value"
+}
+
 #[derive(Copy, Clone)]
 pub struct TypeLimits {
     /// Id of the last visited negated expression
@@ -180,7 +219,8 @@ pub struct TypeLimits {
     UNUSED_COMPARISONS,
     OVERFLOWING_LITERALS,
     INVALID_NAN_COMPARISONS,
-    AMBIGUOUS_WIDE_POINTER_COMPARISONS
+    AMBIGUOUS_WIDE_POINTER_COMPARISONS,
+    LOSSY_INTEGER_CAST
 ]);
 
 impl TypeLimits {
@@ -288,6 +328,22 @@ fn uint_ty_range(uint_ty: ty::UintTy) -> (u128, u128) {
     (0, max)
 }
 
+/// The smallest and largest values of an integer type, assuming a 64-bit
+/// target for `isize` and `usize` like `int_ty_range` and `uint_ty_range`.
+fn integer_range(ty: Ty<'_>) -> Option<(i128, u128)> {
+    match *ty.kind() {
+        ty::Int(int_ty) => {
+            let (min, max) = int_ty_range(int_ty);
+            Some((min, max as u128))
+        }
+        ty::Uint(uint_ty) => {
+            let (min, max) = uint_ty_range(uint_ty);
+            Some((min as i128, max))
+        }
+        _ => None,
+    }
+}
+
 fn get_bin_hex_repr(cx: &LateContext<'_>, lit: &hir::Lit) -> Option<String> {
     let src = cx.sess().source_map().span_to_snippet(lit.span).ok()?;
     let firstch = src.chars().next()?;
@@ -754,6 +810,92 @@ fn lint_wide_pointer<'tcx>(
     );
 }
 
+fn lint_lossy_integer_cast<'tcx>(
+    cx: &LateContext<'tcx>,
+    e: &'tcx hir::Expr<'tcx>,
+    operand: &'tcx hir::Expr<'tcx>,
+) {
+    let typeck_results = cx.typeck_results();
+    let from = typeck_results.expr_ty(operand);
+    let to = typeck_results.expr_ty(e);
+    let (Some((from_min, from_max)), Some((to_min, to_max))) =
+        (integer_range(from), integer_range(to))
+    else {
+        return;
+    };
+    if to_min <= from_min && from_max <= to_max {
+        return;
+    }
+    // The values of literals are checked by `overflowing_literals`.
+    let literal = match operand.kind {
+        hir::ExprKind::Unary(hir::UnOp::Neg, inner) => inner,
+        _ => operand,
+    };
+    if matches!(literal.kind, hir::ExprKind::Lit(_)) || in_external_macro(cx.sess(), e.span) {
+        return;
+    }
+
+    let suggestion = try_from_suggestion(cx, e, operand, to);
+    cx.emit_span_lint(
+        LOSSY_INTEGER_CAST,
+        e.span,
+        LossyIntegerCast { from, to, help: suggestion.is_none().then_some(()), suggestion },
+    );
+}
+
+/// Suggests replacing the cast `e` with `to::try_from(operand)?`, if it is in a
+/// function returning a `Result` whose error can be converted from a
+/// `TryFromIntError`.
+fn try_from_suggestion<'tcx>(
+    cx: &LateContext<'tcx>,
+    e: &'tcx hir::Expr<'tcx>,
+    operand: &'tcx hir::Expr<'tcx>,
+    to: Ty<'tcx>,
+) -> Option<LossyIntegerCastSuggestion<'tcx>> {
+    if e.span.from_expansion() || !e.span.eq_ctxt(operand.span) {
+        return None;
+    }
+
+    let tcx = cx.tcx;
+    // In closures and `async` blocks, `?` would return from them rather than
+    // from the function, and their return type is only known after typeck.
+    let owner = tcx.hir().enclosing_body_owner(e.hir_id);
+    if !matches!(tcx.def_kind(owner), DefKind::Fn | DefKind::AssocFn)
+        || tcx.hir().body_const_context(owner).is_some()
+    {
+        return None;
+    }
+    let output =
+        tcx.instantiate_bound_regions_with_erased(tcx.fn_sig(owner).instantiate_identity());
+    let ty::Adt(result, args) = *output.output().kind() else { return None };
+    if !tcx.is_diagnostic_item(sym::Result, result.did()) {
+        return None;
+    }
+
+    let from_trait = tcx.get_diagnostic_item(sym::From)?;
+    let int_error =
+        tcx.type_of(tcx.get_diagnostic_item(sym::TryFromIntError)?).instantiate_identity();
+    let infcx = tcx.infer_ctxt().build();
+    if !infcx
+        .type_implements_trait(from_trait, [args.type_at(1), int_error], cx.param_env)
+        .must_apply_modulo_regions()
+    {
+        return None;
+    }
+
+    Some(LossyIntegerCastSuggestion {
+        start: operand.span.shrink_to_lo(),
+        end: operand.span.shrink_to_hi().to(e.span.shrink_to_hi()),
+        to,
+        // `TryFrom` is only in the prelude since Rust 2021.
+        applicability: if e.span.at_least_rust_2021() {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        },
+    })
+}
+
 impl<'tcx> LateLintPass<'tcx> for TypeLimits {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx hir::Expr<'tcx>) {
         match e.kind {
@@ -775,6 +917,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx hir::Expr<'tcx>) {
                 }
             }
             hir::ExprKind::Lit(lit) => lint_literal(cx, self, e, lit),
+            hir::ExprKind::Cast(operand, _) => lint_lossy_integer_cast(cx, e, operand),
             hir::ExprKind::Call(path, [l, r])
                 if let ExprKind::Path(ref qpath) = path.kind
                     && let Some(def_id) = cx.qpath_res(qpath, path.hir_id).opt_def_id()
@@ -0,0 +1,423 @@
+use crate::lints::{UnusedGenericParameterDiag, UnusedGenericParameterSuggestion};
+use crate::{LateContext, LateLintPass, Level, LintContext};
+
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_middle::hir::nested_filter;
+use rustc_middle::ty::{self, Ty, TyCtxt, TypeSuperVisitable, TypeVisitable, TypeVisitor};
+use rustc_span::Span;
+use std::ops::ControlFlow;
+
+declare_lint! {
+    /// The `unused_generic_parameter` lint detects type and const parameters of functions, and
+    /// const parameters of structs, enums and unions, that are never used.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![warn(unused_generic_parameter)]
+    /// fn zero<T>() -> u32 {
+    ///     0
+    /// }
+    ///
+    /// fn main() {
+    ///     zero::<u8>();
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// A parameter that is not used in the signature, the body or the fields of an item has to
+    /// be specified at every use of the item, but does not affect it. The suggestion removes
+    /// the parameter, and the corresponding argument at every use in the crate.
+    ///
+    /// Parameters of items that are reachable from other crates are not linted, as removing them
+    /// is a breaking change. Unused type and lifetime parameters of types are already an error,
+    /// and unused lifetime parameters of functions are detected by the `unused_lifetimes` lint.
+    /// This lint is "allow" by default, as finding the uses of every item walks the whole crate.
+    pub UNUSED_GENERIC_PARAMETER,
+    Allow,
+    "detects generic parameters that are never used"
+}
+
+declare_lint_pass!(UnusedGenericParameter => [UNUSED_GENERIC_PARAMETER]);
+
+impl<'tcx> LateLintPass<'tcx> for UnusedGenericParameter {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
+        match item.kind {
+            hir::ItemKind::Fn(_, generics, _)
+            | hir::ItemKind::Struct(_, generics)
+            | hir::ItemKind::Enum(_, generics)
+            | hir::ItemKind::Union(_, generics) => {
+                check_generics(cx, item.owner_id.def_id, generics)
+            }
+            _ => {}
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<'tcx>) {
+        // Methods of trait impls have to keep the parameters of the trait method.
+        let impl_id = cx.tcx.local_parent(item.owner_id.def_id);
+        if let hir::ImplItemKind::Fn(..) = item.kind
+            && cx.tcx.trait_id_of_impl(impl_id.to_def_id()).is_none()
+        {
+            check_generics(cx, item.owner_id.def_id, item.generics);
+        }
+    }
+}
+
+fn check_generics<'tcx>(
+    cx: &LateContext<'tcx>,
+    def_id: LocalDefId,
+    generics: &'tcx hir::Generics<'tcx>,
+) {
+    let tcx = cx.tcx;
+    // Finding the uses of the item walks the whole crate, so skip the work when the lint is off.
+    let (level, _) =
+        tcx.lint_level_at_node(UNUSED_GENERIC_PARAMETER, tcx.local_def_id_to_hir_id(def_id));
+    if level == Level::Allow
+        || generics.span.from_expansion()
+        || cx.effective_visibilities.is_reachable(def_id)
+    {
+        return;
+    }
+
+    let is_adt = matches!(tcx.def_kind(def_id), DefKind::Struct | DefKind::Enum | DefKind::Union);
+    let own_params = &tcx.generics_of(def_id).params;
+    // The parameters that can be passed explicitly, in the order of the arguments.
+    let explicit_params: Vec<_> = own_params
+        .iter()
+        .filter(|param| match param.kind {
+            ty::GenericParamDefKind::Lifetime => false,
+            ty::GenericParamDefKind::Type { synthetic, .. } => !synthetic,
+            ty::GenericParamDefKind::Const { is_host_effect, .. } => !is_host_effect,
+        })
+        .collect();
+    // Unused type parameters of types are an error already.
+    let candidates: Vec<_> = explicit_params
+        .iter()
+        .filter(|param| !is_adt || matches!(param.kind, ty::GenericParamDefKind::Const { .. }))
+        .collect();
+    if candidates.is_empty() {
+        return;
+    }
+
+    let mut used = ParamCollector::new(tcx);
+    if is_adt {
+        for field in tcx.adt_def(def_id).all_fields() {
+            let _ = tcx.type_of(field.did).instantiate_identity().visit_with(&mut used);
+        }
+    } else {
+        let _ = tcx.fn_sig(def_id).instantiate_identity().visit_with(&mut used);
+        if let Some(body_id) = tcx.hir().maybe_body_owned_by(def_id) {
+            let typeck_results = tcx.typeck(def_id);
+            let mut visitor = BodyParamCollector { tcx, typeck_results, params: &mut used };
+            visitor.visit_body(tcx.hir().body(body_id));
+        }
+    }
+
+    // Parameters that are mentioned in a bound together with a used parameter, like `T` in
+    // `U: Into<T>`, are used as well.
+    let predicates: Vec<_> = tcx
+        .predicates_of(def_id)
+        .predicates
+        .iter()
+        .map(|&(clause, span)| {
+            let mut params = ParamCollector::new(tcx);
+            let _ = clause.visit_with(&mut params);
+            (params.params, span)
+        })
+        .collect();
+    loop {
+        let len = used.params.len();
+        for (params, _) in &predicates {
+            if params.iter().any(|param| used.params.contains(param)) {
+                used.params.extend(params.iter().copied());
+            }
+        }
+        if used.params.len() == len {
+            break;
+        }
+    }
+
+    let unused: Vec<_> =
+        candidates.into_iter().filter(|param| !used.params.contains(&param.index)).collect();
+    if unused.is_empty() {
+        return;
+    }
+    let is_unused = |def_id: DefId| unused.iter().any(|param| param.def_id == def_id);
+
+    // The parameters written between the angle brackets.
+    let hir_params: Vec<_> =
+        generics.params.iter().filter(|param| generics.span.contains(param.span)).collect();
+    let removed_params: Vec<_> =
+        hir_params.iter().filter(|param| is_unused(param.def_id.to_def_id())).collect();
+    let spans: Vec<_> = removed_params.iter().map(|param| param.span).collect();
+    let names = removed_params
+        .iter()
+        .map(|param| format!("`{}`", param.name.ident()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    // Bounds in the where clause would have to be removed as well, so don't suggest anything if
+    // there are any.
+    let removed_spans: Vec<_> =
+        removed_params.iter().map(|param| param_span(generics, param)).collect();
+    let has_other_bounds = predicates.iter().any(|(params, span)| {
+        params.iter().any(|&index| unused.iter().any(|param| param.index == index))
+            && !removed_spans.iter().any(|removed| removed.contains(*span))
+    });
+    let suggestion = if has_other_bounds {
+        None
+    } else {
+        let removed: Vec<_> =
+            hir_params.iter().map(|param| is_unused(param.def_id.to_def_id())).collect();
+        let positions: Vec<_> = explicit_params
+            .iter()
+            .enumerate()
+            .filter(|(_, param)| is_unused(param.def_id))
+            .map(|(pos, _)| pos)
+            .collect();
+        suggest_removal(tcx, def_id, generics, &hir_params, &removed, &positions).map(|spans| {
+            UnusedGenericParameterSuggestion {
+                spans,
+                // Impls of a type may still need the parameters we remove from its uses.
+                applicability: if is_adt {
+                    Applicability::MaybeIncorrect
+                } else {
+                    Applicability::MachineApplicable
+                },
+            }
+        })
+    };
+
+    cx.emit_span_lint(
+        UNUSED_GENERIC_PARAMETER,
+        spans,
+        UnusedGenericParameterDiag { count: removed_params.len(), names, suggestion },
+    );
+}
+
+/// The span of a parameter, including the bounds written next to it.
+fn param_span(generics: &hir::Generics<'_>, param: &hir::GenericParam<'_>) -> Span {
+    generics
+        .bounds_for_param(param.def_id)
+        .filter(|bound| bound.origin == hir::PredicateOrigin::GenericParam)
+        .fold(param.span, |span, bound| span.to(bound.span))
+}
+
+/// Returns the spans to remove to remove the `removed` parameters from `generics`, and the
+/// arguments at the given `positions` from every use of `def_id` in the crate, or `None` if
+/// some uses cannot be updated.
+fn suggest_removal<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: LocalDefId,
+    generics: &hir::Generics<'_>,
+    hir_params: &[&hir::GenericParam<'_>],
+    removed: &[bool],
+    positions: &[usize],
+) -> Option<Vec<Span>> {
+    let elems: Vec<_> = hir_params.iter().map(|param| param_span(generics, param)).collect();
+    let mut spans = list_removal_spans(&elems, removed, generics.span);
+
+    let mut collector =
+        UseCollector { tcx, def_id: def_id.to_def_id(), typeck_results: None, uses: vec![] };
+    tcx.hir().visit_all_item_likes_in_crate(&mut collector);
+    for (segment, args) in collector.uses {
+        // We cannot update uses in macros.
+        if args.span_ext.from_expansion() {
+            return None;
+        }
+        let mut elems = vec![];
+        let mut removed = vec![];
+        let mut pos = 0;
+        for arg in args.args {
+            elems.push(arg.span());
+            if let hir::GenericArg::Lifetime(_) = arg {
+                removed.push(false);
+            } else {
+                removed.push(positions.contains(&pos));
+                pos += 1;
+            }
+        }
+        if !removed.contains(&true) {
+            continue;
+        }
+        for binding in args.bindings {
+            elems.push(binding.span);
+            removed.push(false);
+        }
+        // Remove `::<..>` as well as `<..>`.
+        let all = segment.shrink_to_hi().to(args.span_ext);
+        spans.extend(list_removal_spans(&elems, &removed, all));
+    }
+    Some(spans)
+}
+
+/// Returns the spans to remove from the comma-separated list of `elems` to remove the `removed`
+/// elements, or `all` if no element remains.
+fn list_removal_spans(elems: &[Span], removed: &[bool], all: Span) -> Vec<Span> {
+    let Some(last_kept) = removed.iter().rposition(|&removed| !removed) else {
+        return vec![all];
+    };
+    let mut spans = vec![];
+    for (pos, &span) in elems[..last_kept].iter().enumerate() {
+        if removed[pos] {
+            // `A, B` becomes `B`
+            spans.push(span.until(elems[pos + 1]));
+        }
+    }
+    if last_kept + 1 < elems.len() {
+        // `A, B, C` becomes `A`
+        spans.push(elems[last_kept].shrink_to_hi().to(elems[elems.len() - 1]));
+    }
+    spans
+}
+
+/// Collects the indices of the generic parameters mentioned in types.
+struct ParamCollector<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    params: FxHashSet<u32>,
+    visited_opaques: FxHashSet<DefId>,
+}
+
+impl<'tcx> ParamCollector<'tcx> {
+    fn new(tcx: TyCtxt<'tcx>) -> Self {
+        ParamCollector { tcx, params: Default::default(), visited_opaques: Default::default() }
+    }
+}
+
+impl<'tcx> TypeVisitor<TyCtxt<'tcx>> for ParamCollector<'tcx> {
+    fn visit_ty(&mut self, ty: Ty<'tcx>) -> ControlFlow<Self::BreakTy> {
+        match *ty.kind() {
+            ty::Param(param) => {
+                self.params.insert(param.index);
+            }
+            // Opaque types capture every parameter in scope, so only look at their bounds.
+            ty::Alias(ty::Opaque, alias) => {
+                if self.visited_opaques.insert(alias.def_id) {
+                    let bounds = self.tcx.explicit_item_bounds(alias.def_id);
+                    for (clause, _) in bounds.iter_instantiated_copied(self.tcx, alias.args) {
+                        clause.visit_with(self)?;
+                    }
+                }
+                return ControlFlow::Continue(());
+            }
+            // The same goes for closures and coroutines. The types they use show up elsewhere
+            // in the body.
+            ty::Closure(..)
+            | ty::CoroutineClosure(..)
+            | ty::Coroutine(..)
+            | ty::CoroutineWitness(..) => return ControlFlow::Continue(()),
+            _ => {}
+        }
+        ty.super_visit_with(self)
+    }
+
+    fn visit_const(&mut self, ct: ty::Const<'tcx>) -> ControlFlow<Self::BreakTy> {
+        if let ty::ConstKind::Param(param) = ct.kind() {
+            self.params.insert(param.index);
+        }
+        ct.super_visit_with(self)
+    }
+}
+
+/// Collects the generic parameters mentioned in the types and generic arguments of a body.
+struct BodyParamCollector<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    typeck_results: &'tcx ty::TypeckResults<'tcx>,
+    params: &'a mut ParamCollector<'tcx>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for BodyParamCollector<'a, 'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.tcx.hir()
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        if let Some(ty) = self.typeck_results.node_type_opt(expr.hir_id) {
+            let _ = ty.visit_with(self.params);
+        }
+        if let Some(args) = self.typeck_results.node_args_opt(expr.hir_id) {
+            let _ = args.visit_with(self.params);
+        }
+        intravisit::walk_expr(self, expr);
+    }
+
+    fn visit_pat(&mut self, pat: &'tcx hir::Pat<'tcx>) {
+        if let Some(ty) = self.typeck_results.node_type_opt(pat.hir_id) {
+            let _ = ty.visit_with(self.params);
+        }
+        intravisit::walk_pat(self, pat);
+    }
+}
+
+/// Collects the explicit generic arguments of every use of `def_id` in the crate, along with
+/// the span of the name they follow.
+struct UseCollector<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    def_id: DefId,
+    typeck_results: Option<&'tcx ty::TypeckResults<'tcx>>,
+    uses: Vec<(Span, &'tcx hir::GenericArgs<'tcx>)>,
+}
+
+impl<'tcx> UseCollector<'tcx> {
+    /// Whether generic arguments for a path segment resolved to `res` are passed to `def_id`.
+    fn refers_to_item(&self, res: Res) -> bool {
+        let Res::Def(kind, def_id) = res else { return false };
+        match kind {
+            // `Struct::<3>(..)`, `Enum::Variant::<3>` and `Enum::Variant::<3>(..)`
+            DefKind::Ctor(..) | DefKind::Variant => {
+                let parent = self.tcx.parent(def_id);
+                parent == self.def_id
+                    || (self.tcx.def_kind(parent) == DefKind::Variant
+                        && self.tcx.parent(parent) == self.def_id)
+            }
+            _ => def_id == self.def_id,
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for UseCollector<'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.tcx.hir()
+    }
+
+    fn visit_nested_body(&mut self, body_id: hir::BodyId) {
+        let old_typeck_results = self.typeck_results.replace(self.tcx.typeck_body(body_id));
+        self.visit_body(self.tcx.hir().body(body_id));
+        self.typeck_results = old_typeck_results;
+    }
+
+    fn visit_path_segment(&mut self, segment: &'tcx hir::PathSegment<'tcx>) {
+        if let Some(args) = segment.args
+            && self.refers_to_item(segment.res)
+        {
+            self.uses.push((segment.ident.span, args));
+        }
+        intravisit::walk_path_segment(self, segment);
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        // Method calls and associated functions are resolved during type checking.
+        if let hir::ExprKind::MethodCall(segment, ..)
+        | hir::ExprKind::Path(hir::QPath::TypeRelative(_, segment)) = expr.kind
+            && let Some(args) = segment.args
+            && let Some(typeck_results) = self.typeck_results
+            && typeck_results.type_dependent_def_id(expr.hir_id) == Some(self.def_id)
+        {
+            self.uses.push((segment.ident.span, args));
+        }
+        intravisit::walk_expr(self, expr);
+    }
+}
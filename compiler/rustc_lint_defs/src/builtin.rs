@@ -31,6 +31,8 @@
         COHERENCE_LEAK_CHECK,
         CONFLICTING_REPR_HINTS,
         CONST_EVALUATABLE_UNCHECKED,
+        CONST_EVAL_NAN_RESULT,
+        CONST_EVAL_SELECT_MISMATCH,
         CONST_ITEM_MUTATION,
         CONST_PATTERNS_WITHOUT_PARTIAL_EQ,
         DEAD_CODE,
@@ -4523,6 +4525,68 @@
     };
 }
 
+declare_lint! {
+    /// The `const_eval_nan_result` lint detects floating-point operations that produce a NaN
+    /// during compile-time evaluation.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// #![deny(const_eval_nan_result)]
+    /// const NAN: f32 = 0.0 / 0.0;
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Compile-time evaluation always produces the same NaN: the positive quiet NaN without
+    /// payload. At runtime, the sign and payload of a NaN depend on the target and on the inputs
+    /// of the operation, so code that inspects the bits of the NaN may behave differently when
+    /// the operation is evaluated at runtime instead. Other floating-point results are the same
+    /// in both cases, since compile-time evaluation follows the IEEE 754 rounding rules exactly.
+    pub CONST_EVAL_NAN_RESULT,
+    Allow,
+    "floating-point operations producing a NaN during const evaluation, whose bits may differ at runtime",
+}
+
+declare_lint! {
+    /// The `const_eval_select_mismatch` lint detects calls of `const_eval_select` whose
+    /// compile-time and runtime implementations can behave observably differently.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// #![feature(const_eval_select, core_intrinsics)]
+    /// #![allow(internal_features)]
+    /// #![deny(const_eval_select_mismatch)]
+    /// use std::intrinsics::const_eval_select;
+    ///
+    /// const fn compiletime(x: u32) -> u32 { x + 1 }
+    /// const fn runtime(x: u32) -> u32 { x + 2 }
+    ///
+    /// const fn select(x: u32) -> u32 {
+    ///     unsafe { const_eval_select((x,), compiletime, runtime) }
+    /// }
+    ///
+    /// const X: u32 = select(1);
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// `const_eval_select` calls its first function during compile-time evaluation, and its
+    /// second function at runtime. The two functions must behave the same, since code may rely
+    /// on a `const fn` giving the same result at compile-time and at runtime. The compiler
+    /// cannot check this in general, but this lint catches the cases where it can tell that
+    /// they differ: only one of them is `#[track_caller]`, or the runtime implementation is a
+    /// `const fn` as well and returns a different value when both are evaluated at compile-time.
+    pub CONST_EVAL_SELECT_MISMATCH,
+    Warn,
+    "`const_eval_select` implementations that behave differently at compile-time and at runtime",
+}
+
 declare_lint! {
     /// The `private_macro_use` lint detects private macros that are imported
     /// with `#[macro_use]`.
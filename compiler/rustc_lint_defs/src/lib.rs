@@ -318,6 +318,12 @@ pub struct Lint {
     /// `default_level`.
     pub edition_lint_opts: Option<(Edition, Level)>,
 
+    /// The year of a future edition whose migration this lint prepares for, e.g. `2027`.
+    ///
+    /// `-Z preview-edition-migrations=2027` enables all such lints as warnings, so that crates
+    /// can be prepared before the edition is cut.
+    pub edition_migration: Option<u32>,
+
     /// `true` if this lint is reported even inside expansions of external macros.
     pub report_in_external_macro: bool,
 
@@ -329,6 +335,46 @@ pub struct Lint {
     pub feature_gate: Option<Symbol>,
 
     pub crate_level_only: bool,
+
+    /// The options of the lint which can be set with `#![lint_config(lint::option = value)]`.
+    pub config: &'static [LintConfigOption],
+}
+
+/// A configuration option of a lint, e.g. the threshold of a lint about large values.
+#[derive(Copy, Clone, Debug)]
+pub struct LintConfigOption {
+    /// The name of the option, written after the name of the lint in `#![lint_config]`.
+    pub name: &'static str,
+    pub kind: LintConfigKind,
+}
+
+/// The type of the values of a [`LintConfigOption`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LintConfigKind {
+    /// A non-negative integer literal.
+    Int,
+    /// `true` or `false`.
+    Bool,
+    /// A string literal.
+    Str,
+}
+
+impl LintConfigKind {
+    pub fn descr(self) -> &'static str {
+        match self {
+            LintConfigKind::Int => "an integer",
+            LintConfigKind::Bool => "a boolean",
+            LintConfigKind::Str => "a string",
+        }
+    }
+}
+
+/// The value of a [`LintConfigOption`], as set in the source.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, HashStable_Generic)]
+pub enum LintConfigValue {
+    Int(u128),
+    Bool(bool),
+    Str(Symbol),
 }
 
 /// Extra information for a future incompatibility lint.
@@ -468,11 +514,13 @@ pub const fn default_fields_for_macro() -> Self {
             default_level: Level::Forbid,
             desc: "",
             edition_lint_opts: None,
+            edition_migration: None,
             is_loaded: false,
             report_in_external_macro: false,
             future_incompatible: None,
             feature_gate: None,
             crate_level_only: false,
+            config: &[],
         }
     }
 
@@ -487,6 +535,34 @@ pub fn default_level(&self, edition: Edition) -> Level {
             .map(|(_, l)| l)
             .unwrap_or(self.default_level)
     }
+
+    /// The URL of the documentation of the lint, if it is known where it is
+    /// documented. Used to link lint names in the compiler output.
+    pub fn doc_url(&self) -> Option<String> {
+        let name = self.name_lower();
+        match name.split_once("::") {
+            Some(("clippy", name)) => {
+                Some(format!("https://rust-lang.github.io/rust-clippy/master/index.html#{name}"))
+            }
+            Some(("rustdoc", name)) => {
+                Some(format!("https://doc.rust-lang.org/rustdoc/lints.html#{name}"))
+            }
+            Some(_) => None,
+            None if self.is_loaded => None,
+            None => {
+                // The rustc book lists lints by their default level, regardless of editions.
+                let listing = match self.default_level {
+                    Level::Allow | Level::Expect(_) => "allowed-by-default",
+                    Level::Warn | Level::ForceWarn(_) => "warn-by-default",
+                    Level::Deny | Level::Forbid => "deny-by-default",
+                };
+                let anchor = name.replace('_', "-");
+                Some(format!(
+                    "https://doc.rust-lang.org/rustc/lints/listing/{listing}.html#{anchor}"
+                ))
+            }
+        }
+    }
 }
 
 /// Identifies a lint known to the compiler.
@@ -811,6 +887,8 @@ macro_rules! declare_lint {
         $($field:ident : $val:expr),* $(,)*
      }; )?
      $(@edition $lint_edition:ident => $edition_level:ident;)?
+     $(@edition_migration = $migration_edition:literal;)?
+     $(@config = [$($option:ident: $kind:ident),* $(,)?];)?
      $($v:ident),*) => (
         $(#[$attr])*
         $vis static $NAME: &$crate::Lint = &$crate::Lint {
@@ -826,6 +904,11 @@ macro_rules! declare_lint {
                 ..$crate::FutureIncompatibleInfo::default_fields_for_macro()
             }),)?
             $(edition_lint_opts: Some(($crate::Edition::$lint_edition, $crate::$edition_level)),)?
+            $(edition_migration: Some($migration_edition),)?
+            $(config: &[$($crate::LintConfigOption {
+                name: stringify!($option),
+                kind: $crate::LintConfigKind::$kind,
+            }),*],)?
             ..$crate::Lint::default_fields_for_macro()
         };
     );
@@ -43,6 +43,8 @@ enum class LLVMRustCounterMappingRegionKind {
   SkippedRegion = 2,
   GapRegion = 3,
   BranchRegion = 4,
+  MCDCDecisionRegion = 5,
+  MCDCBranchRegion = 6,
 };
 
 static coverage::CounterMappingRegion::RegionKind
@@ -58,15 +60,36 @@ fromRust(LLVMRustCounterMappingRegionKind Kind) {
     return coverage::CounterMappingRegion::GapRegion;
   case LLVMRustCounterMappingRegionKind::BranchRegion:
     return coverage::CounterMappingRegion::BranchRegion;
+#if LLVM_VERSION_GE(18, 0) && LLVM_VERSION_LT(19, 0)
+  case LLVMRustCounterMappingRegionKind::MCDCDecisionRegion:
+    return coverage::CounterMappingRegion::MCDCDecisionRegion;
+  case LLVMRustCounterMappingRegionKind::MCDCBranchRegion:
+    return coverage::CounterMappingRegion::MCDCBranchRegion;
+#else
+  case LLVMRustCounterMappingRegionKind::MCDCDecisionRegion:
+  case LLVMRustCounterMappingRegionKind::MCDCBranchRegion:
+    report_fatal_error("MC/DC coverage regions are only supported with LLVM 18");
+#endif
   }
   report_fatal_error("Bad LLVMRustCounterMappingRegionKind!");
 }
 
+// FFI equivalent of struct `llvm::coverage::CounterMappingRegion::MCDCParameters`
+// of LLVM 18, which was split into decision and branch parameters in LLVM 19.
+struct LLVMRustMCDCParameters {
+  uint32_t BitmapIdx;
+  uint32_t NumConditions;
+  uint32_t ID;
+  uint32_t TrueID;
+  uint32_t FalseID;
+};
+
 // FFI equivalent of struct `llvm::coverage::CounterMappingRegion`
 // https://github.com/rust-lang/llvm-project/blob/ea6fa9c2/llvm/include/llvm/ProfileData/Coverage/CoverageMapping.h#L211-L304
 struct LLVMRustCounterMappingRegion {
   LLVMRustCounter Count;
   LLVMRustCounter FalseCount;
+  LLVMRustMCDCParameters MCDCParameters;
   uint32_t FileID;
   uint32_t ExpandedFileID;
   uint32_t LineStart;
@@ -140,7 +163,12 @@ extern "C" void LLVMRustCoverageWriteMappingToBuffer(
     MappingRegions.emplace_back(
         fromRust(Region.Count), fromRust(Region.FalseCount),
 #if LLVM_VERSION_GE(18, 0) && LLVM_VERSION_LT(19, 0)
-        coverage::CounterMappingRegion::MCDCParameters{},
+        coverage::CounterMappingRegion::MCDCParameters{
+            Region.MCDCParameters.BitmapIdx,
+            Region.MCDCParameters.NumConditions,
+            Region.MCDCParameters.ID,
+            Region.MCDCParameters.TrueID,
+            Region.MCDCParameters.FalseID},
 #endif
         Region.FileID, Region.ExpandedFileID,
         Region.LineStart, Region.ColumnStart, Region.LineEnd, Region.ColumnEnd,
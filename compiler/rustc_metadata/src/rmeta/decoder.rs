@@ -751,8 +751,9 @@ pub(crate) fn list_crate_metadata(
                     )?;
                     writeln!(
                         out,
-                        "compiler_builtins {} needs_allocator {} needs_panic_runtime {} no_builtins {} panic_runtime {} profiler_runtime {}",
+                        "compiler_builtins {} mir_only_rlib {} needs_allocator {} needs_panic_runtime {} no_builtins {} panic_runtime {} profiler_runtime {}",
                         root.compiler_builtins,
+                        root.mir_only_rlib,
                         root.needs_allocator,
                         root.needs_panic_runtime,
                         root.no_builtins,
@@ -1159,6 +1160,11 @@ fn get_debugger_visualizers(self) -> Vec<DebuggerVisualizerFile> {
         self.root.debugger_visualizers.decode(self).collect::<Vec<_>>()
     }
 
+    fn get_used_upstream_items(self, tcx: TyCtxt<'tcx>) -> Option<&'tcx [DefId]> {
+        let items = self.root.used_upstream_items?;
+        Some(tcx.arena.alloc_from_iter(items.decode((self, tcx))))
+    }
+
     /// Iterates over all the stability attributes in the given crate.
     fn get_lib_features(self) -> LibFeatures {
         LibFeatures {
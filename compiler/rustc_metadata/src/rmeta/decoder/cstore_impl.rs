@@ -6,12 +6,13 @@
 
 use rustc_ast as ast;
 use rustc_attr::Deprecation;
+use rustc_data_structures::fx::FxHashSet;
 use rustc_data_structures::sync::Lrc;
 use rustc_hir::def::{CtorKind, DefKind, Res};
 use rustc_hir::def_id::{CrateNum, DefId, DefIdMap, LOCAL_CRATE};
 use rustc_hir::definitions::{DefKey, DefPath, DefPathHash};
 use rustc_middle::arena::ArenaAllocatable;
-use rustc_middle::metadata::ModChild;
+use rustc_middle::metadata::{ImportableItem, ModChild};
 use rustc_middle::middle::exported_symbols::ExportedSymbol;
 use rustc_middle::middle::stability::DeprecationEntry;
 use rustc_middle::query::ExternProviders;
@@ -210,6 +211,7 @@ fn into_args(self) -> (DefId, SimplifiedType) {
     generics_of => { table }
     inferred_outlives_of => { table_defaulted_array }
     super_predicates_of => { table }
+    implied_predicates_of => { table }
     type_of => { table }
     type_alias_is_lazy => { cdata.root.tables.type_alias_is_lazy.get(cdata, def_id.index) }
     variances_of => { table }
@@ -275,20 +277,9 @@ fn into_args(self) -> (DefId, SimplifiedType) {
             .map(|lazy| lazy.decode((cdata, tcx)))
             .process_decoded(tcx, || panic!("{def_id:?} does not have trait_impl_trait_tys")))
     }
-    implied_predicates_of => {
-        cdata
-            .root
-            .tables
-            .implied_predicates_of
-            .get(cdata, def_id.index)
-            .map(|lazy| lazy.decode((cdata, tcx)))
-            .unwrap_or_else(|| {
-                debug_assert_eq!(tcx.def_kind(def_id), DefKind::Trait);
-                tcx.super_predicates_of(def_id)
-            })
-    }
 
     associated_types_for_impl_traits_in_associated_fn => { table_defaulted_array }
+    trait_alias_relaxed_bounds => { table_defaulted_array }
 
     visibility => { cdata.get_visibility(def_id.index) }
     adt_def => { cdata.get_adt_def(def_id.index, tcx) }
@@ -318,6 +309,7 @@ fn into_args(self) -> (DefId, SimplifiedType) {
     panic_in_drop_strategy => { cdata.root.panic_in_drop_strategy }
     extern_crate => { cdata.extern_crate.map(|c| &*tcx.arena.alloc(c)) }
     is_no_builtins => { cdata.root.no_builtins }
+    is_mir_only_rlib => { cdata.root.mir_only_rlib }
     symbol_mangling_version => { cdata.root.symbol_mangling_version }
     reachable_non_generics => {
         let reachable_non_generics = tcx
@@ -367,6 +359,7 @@ fn into_args(self) -> (DefId, SimplifiedType) {
 
     used_crate_source => { Lrc::clone(&cdata.source) }
     debugger_visualizers => { cdata.get_debugger_visualizers() }
+    used_upstream_items => { cdata.get_used_upstream_items(tcx) }
 
     exported_symbols => {
         let syms = cdata.exported_symbols(tcx);
@@ -506,6 +499,49 @@ pub(in crate::rmeta) fn provide(providers: &mut Providers) {
             visible_parent_map
         },
 
+        importable_items: |tcx, cnum| {
+            use std::collections::vec_deque::VecDeque;
+
+            assert_ne!(cnum, LOCAL_CRATE);
+
+            // Like for `visible_parent_map`, a breadth-first search gives us the shortest
+            // path to each item.
+            let mut items = Vec::new();
+            let mut seen = FxHashSet::default();
+            let mut bfs_queue = VecDeque::from([(cnum.as_def_id(), Vec::new())]);
+            while let Some((module, path)) = bfs_queue.pop_front() {
+                for child in tcx.module_children(module).iter() {
+                    if !child.vis.is_public() || child.ident.name == kw::Underscore {
+                        continue;
+                    }
+                    let Some(def_id) = child.res.opt_def_id() else {
+                        continue;
+                    };
+                    // Constructors share the attributes of their struct or variant.
+                    let attrs_def_id = match child.res {
+                        Res::Def(DefKind::Ctor(..), ctor_def_id) => tcx.parent(ctor_def_id),
+                        _ => def_id,
+                    };
+                    if tcx.is_doc_hidden(attrs_def_id) || !seen.insert(child.res) {
+                        continue;
+                    }
+
+                    let mut path = path.clone();
+                    path.push(child.ident.name);
+                    // Do not wander into the modules of other crates re-exported by this one.
+                    if let Res::Def(DefKind::Mod | DefKind::Enum, _) = child.res
+                        && def_id.krate == cnum
+                    {
+                        bfs_queue.push_back((def_id, path.clone()));
+                    }
+                    items.push(ImportableItem { res: child.res, path });
+                }
+            }
+
+            items.sort_by(|a, b| a.name().as_str().cmp(b.name().as_str()));
+            items
+        },
+
         dependency_formats: |tcx, ()| Lrc::new(crate::dependency_format::calculate(tcx)),
         has_global_allocator: |tcx, LocalCrate| CStore::from_tcx(tcx).has_global_allocator(),
         has_alloc_error_handler: |tcx, LocalCrate| CStore::from_tcx(tcx).has_alloc_error_handler(),
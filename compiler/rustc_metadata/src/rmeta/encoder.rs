@@ -665,6 +665,9 @@ macro_rules! stat {
         let debugger_visualizers =
             stat!("debugger-visualizers", || self.encode_debugger_visualizers());
 
+        let used_upstream_items =
+            stat!("used-upstream-items", || self.encode_used_upstream_items());
+
         // Encode exported symbols info. This is prefetched in `encode_metadata`.
         let exported_symbols = stat!("exported-symbols", || {
             self.encode_exported_symbols(tcx.exported_symbols(LOCAL_CRATE))
@@ -704,7 +707,9 @@ macro_rules! stat {
                 has_default_lib_allocator: attr::contains_name(attrs, sym::default_lib_allocator),
                 proc_macro_data,
                 debugger_visualizers,
+                used_upstream_items,
                 compiler_builtins: attr::contains_name(attrs, sym::compiler_builtins),
+                mir_only_rlib: tcx.is_mir_only_rlib(LOCAL_CRATE),
                 needs_allocator: attr::contains_name(attrs, sym::needs_allocator),
                 needs_panic_runtime: attr::contains_name(attrs, sym::needs_panic_runtime),
                 no_builtins: attr::contains_name(attrs, sym::no_builtins),
@@ -1053,7 +1058,9 @@ fn should_encode_mir(
         // Full-fledged functions + closures
         DefKind::AssocFn | DefKind::Fn | DefKind::Closure => {
             let generics = tcx.generics_of(def_id);
+            // MIR-only rlibs leave the codegen of all their functions to downstream crates.
             let opt = tcx.sess.opts.unstable_opts.always_encode_mir
+                || tcx.is_mir_only_rlib(LOCAL_CRATE)
                 || (tcx.sess.opts.output_types.should_codegen()
                     && reachable_set.contains(&def_id)
                     && (generics.requires_monomorphization(tcx)
@@ -1420,6 +1427,7 @@ fn encode_def_ids(&mut self) {
             if let DefKind::Trait = def_kind {
                 record!(self.tables.trait_def[def_id] <- self.tcx.trait_def(def_id));
                 record!(self.tables.super_predicates_of[def_id] <- self.tcx.super_predicates_of(def_id));
+                record!(self.tables.implied_predicates_of[def_id] <- self.tcx.implied_predicates_of(def_id));
 
                 let module_children = self.tcx.module_children_local(local_id);
                 record_array!(self.tables.module_children_non_reexports[def_id] <-
@@ -1429,6 +1437,7 @@ fn encode_def_ids(&mut self) {
                 record!(self.tables.trait_def[def_id] <- self.tcx.trait_def(def_id));
                 record!(self.tables.super_predicates_of[def_id] <- self.tcx.super_predicates_of(def_id));
                 record!(self.tables.implied_predicates_of[def_id] <- self.tcx.implied_predicates_of(def_id));
+                record_defaulted_array!(self.tables.trait_alias_relaxed_bounds[def_id] <- self.tcx.trait_alias_relaxed_bounds(def_id));
             }
             if let DefKind::Trait | DefKind::Impl { .. } = def_kind {
                 let associated_item_def_ids = self.tcx.associated_item_def_ids(def_id);
@@ -1874,6 +1883,14 @@ fn encode_debugger_visualizers(&mut self) -> LazyArray<DebuggerVisualizerFile> {
         )
     }
 
+    fn encode_used_upstream_items(&mut self) -> Option<LazyArray<DefId>> {
+        if self.is_proc_macro {
+            return None;
+        }
+        let items = self.tcx.used_upstream_items(LOCAL_CRATE)?;
+        Some(self.lazy_array(items.iter().copied()))
+    }
+
     fn encode_crate_deps(&mut self) -> LazyArray<CrateDep> {
         empty_proc_macro!(self);
 
@@ -274,6 +274,9 @@ pub(crate) struct CrateRoot {
 
     tables: LazyTables,
     debugger_visualizers: LazyArray<DebuggerVisualizerFile>,
+    /// The items of other crates used by this crate, if it was built with
+    /// `-Z cross-crate-dead-code`.
+    used_upstream_items: Option<LazyArray<DefId>>,
 
     exported_symbols: LazyArray<(ExportedSymbol<'static>, SymbolExportInfo)>,
 
@@ -286,6 +289,7 @@ pub(crate) struct CrateRoot {
     source_map: LazyTable<u32, Option<LazyValue<rustc_span::SourceFile>>>,
 
     compiler_builtins: bool,
+    mir_only_rlib: bool,
     needs_allocator: bool,
     needs_panic_runtime: bool,
     no_builtins: bool,
@@ -390,6 +394,7 @@ fn encode(&self, buf: &mut FileEncoder) -> LazyTables {
     inferred_outlives_of: Table<DefIndex, LazyArray<(ty::Clause<'static>, Span)>>,
     inherent_impls: Table<DefIndex, LazyArray<DefIndex>>,
     associated_types_for_impl_traits_in_associated_fn: Table<DefIndex, LazyArray<DefId>>,
+    trait_alias_relaxed_bounds: Table<DefIndex, LazyArray<(DefId, Span)>>,
     opt_rpitit_info: Table<DefIndex, Option<LazyValue<ty::ImplTraitInTraitData>>>,
     unused_generic_params: Table<DefIndex, UnusedGenericParams>,
     // Reexported names are not associated with individual `DefId`s,
@@ -416,8 +421,6 @@ fn encode(&self, buf: &mut FileEncoder) -> LazyTables {
     explicit_predicates_of: Table<DefIndex, LazyValue<ty::GenericPredicates<'static>>>,
     generics_of: Table<DefIndex, LazyValue<ty::Generics>>,
     super_predicates_of: Table<DefIndex, LazyValue<ty::GenericPredicates<'static>>>,
-    // As an optimization, we only store this for trait aliases,
-    // since it's identical to super_predicates_of for traits.
     implied_predicates_of: Table<DefIndex, LazyValue<ty::GenericPredicates<'static>>>,
     type_of: Table<DefIndex, LazyValue<ty::EarlyBinder<Ty<'static>>>>,
     variances_of: Table<DefIndex, LazyArray<ty::Variance>>,
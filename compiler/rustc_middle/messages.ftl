@@ -59,6 +59,9 @@ middle_erroneous_constant = erroneous constant encountered
 middle_layout_references_error =
     the type has an unknown layout
 
+middle_layout_table =
+    layout of `{$ty}` (size: {$size}, align: {$align}), in bytes:{$table}
+
 middle_limit_invalid =
     `limit` must be a non-negative integer
     .label = {$error_str}
@@ -1,6 +1,7 @@
 use std::fmt;
 
 use rustc_errors::{codes::*, DiagnosticArgName, DiagnosticArgValue, DiagnosticMessage};
+use rustc_errors::{AddToDiagnostic, Diagnostic, LayoutTable, SubdiagnosticMessageOp};
 use rustc_macros::Diagnostic;
 use rustc_span::{Span, Symbol};
 
@@ -121,7 +122,11 @@ pub enum LayoutError<'tcx> {
     Unknown { ty: Ty<'tcx> },
 
     #[diag(middle_values_too_big)]
-    Overflow { ty: Ty<'tcx> },
+    Overflow {
+        ty: Ty<'tcx>,
+        #[subdiagnostic]
+        layout_table: Option<LayoutTableNote<'tcx>>,
+    },
 
     #[diag(middle_cannot_be_normalized)]
     NormalizationFailure { ty: Ty<'tcx>, failure_ty: String },
@@ -133,6 +138,67 @@ pub enum LayoutError<'tcx> {
     ReferencesError,
 }
 
+/// A note with the layout of the fields of a type, see [`crate::ty::layout::layout_table`].
+pub struct LayoutTableNote<'tcx> {
+    pub ty: Ty<'tcx>,
+    pub table: LayoutTable,
+}
+
+impl<'tcx> AddToDiagnostic for LayoutTableNote<'tcx> {
+    fn add_to_diagnostic_with<F: SubdiagnosticMessageOp>(self, diag: &mut Diagnostic, f: F) {
+        diag.arg("ty", self.ty);
+        diag.arg("size", self.table.size.map_or_else(|| "too big".to_string(), |s| s.to_string()));
+        diag.arg("align", self.table.align);
+        diag.arg("table", render_layout_table(&self.table));
+        let msg = f(diag, crate::fluent_generated::middle_layout_table.into());
+        diag.layout_note(msg, self.table);
+    }
+}
+
+/// Renders the fields of `table` as rows of aligned columns, each preceded by a newline, with
+/// extra rows for the padding between and after the fields.
+fn render_layout_table(table: &LayoutTable) -> String {
+    let opt_to_string = |n: Option<u64>| n.map_or_else(|| "?".to_string(), |n| n.to_string());
+    let padding_row = |offset: Option<u64>, size: u64| {
+        [opt_to_string(offset), size.to_string(), String::new(), "(padding)".to_string()]
+    };
+
+    let mut rows = vec![["offset", "size", "align", "field"].map(str::to_string)];
+    let mut end = Some(0);
+    for field in &table.fields {
+        if field.padding > 0 {
+            rows.push(padding_row(field.offset.map(|o| o - field.padding), field.padding));
+        }
+        rows.push([
+            opt_to_string(field.offset),
+            field.size.to_string(),
+            field.align.to_string(),
+            format!("`{}`", field.name),
+        ]);
+        end = field.offset.map(|o| o + field.size);
+    }
+    if let (Some(size), Some(end)) = (table.size, end)
+        && size > end
+    {
+        rows.push(padding_row(Some(end), size - end));
+    }
+
+    let widths: [usize; 3] =
+        std::array::from_fn(|i| rows.iter().map(|row| row[i].len()).max().unwrap());
+    let mut rendered = String::new();
+    for [offset, size, align, field] in rows {
+        let line = format!(
+            "{offset:>w0$}  {size:>w1$}  {align:>w2$}  {field}",
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+        );
+        rendered.push('\n');
+        rendered.push_str(line.trim_end());
+    }
+    rendered
+}
+
 #[derive(Diagnostic)]
 #[diag(middle_adjust_for_foreign_abi_error)]
 pub struct UnsupportedFnAbi {
@@ -34,6 +34,11 @@ pub enum ProjectionKind {
     /// we won't need it.
     Index,
 
+    /// An index like `B[N]` into an array, where `N` is an integer literal known
+    /// to be in bounds. Unlike [`ProjectionKind::Index`], the index is preserved so
+    /// that closures can capture disjoint elements of the same array.
+    ConstantIndex(u64),
+
     /// A subslice covering a range of values like `B[x..y]`.
     Subslice,
 
@@ -6,7 +6,7 @@
 use rustc_hir::{HirId, ItemLocalId};
 use rustc_session::lint::{
     builtin::{self, FORBIDDEN_LINT_GROUPS},
-    FutureIncompatibilityReason, Level, Lint, LintId,
+    FutureIncompatibilityReason, Level, Lint, LintConfigValue, LintId,
 };
 use rustc_session::Session;
 use rustc_span::hygiene::{ExpnKind, MacroKind};
@@ -32,6 +32,10 @@ pub enum LintLevelSource {
     /// The provided `Level` is the level specified on the command line.
     /// (The actual level may be lower due to `--cap-lints`.)
     CommandLine(Symbol, Level),
+
+    /// Lint level was set to `Warn` by `-Z preview-edition-migrations`, as the lint prepares
+    /// the migration to the edition of the given year.
+    EditionMigrationPreview(u32),
 }
 
 impl LintLevelSource {
@@ -40,6 +44,7 @@ pub fn name(&self) -> Symbol {
             LintLevelSource::Default => symbol::kw::Default,
             LintLevelSource::Node { name, .. } => name,
             LintLevelSource::CommandLine(name, _) => name,
+            LintLevelSource::EditionMigrationPreview(_) => symbol::sym::preview_edition_migrations,
         }
     }
 
@@ -48,6 +53,7 @@ pub fn span(&self) -> Span {
             LintLevelSource::Default => DUMMY_SP,
             LintLevelSource::Node { span, .. } => span,
             LintLevelSource::CommandLine(_, _) => DUMMY_SP,
+            LintLevelSource::EditionMigrationPreview(_) => DUMMY_SP,
         }
     }
 }
@@ -169,6 +175,22 @@ pub fn lint_level_at_node(self, lint: &'static Lint, id: HirId) -> (Level, LintL
     }
 }
 
+/// Return type for the `lint_config` query.
+///
+/// This map holds the values of the lint options set by the
+/// `#![lint_config(lint::option = value)]` attributes of the crate.
+#[derive(Default, Debug, HashStable)]
+pub struct LintConfig {
+    pub values: FxIndexMap<(LintId, Symbol), LintConfigValue>,
+}
+
+impl LintConfig {
+    /// Returns the value of the option `option` of `lint`, if it is set.
+    pub fn get(&self, lint: &'static Lint, option: Symbol) -> Option<LintConfigValue> {
+        self.values.get(&(LintId::of(lint), option)).copied()
+    }
+}
+
 /// This struct represents a lint expectation and holds all required information
 /// to emit the `unfulfilled_lint_expectations` lint if it is unfulfilled after
 /// the `LateLintPass` has completed.
@@ -233,6 +255,15 @@ pub fn explain_lint_level_source(
                 ));
             }
         }
+        LintLevelSource::EditionMigrationPreview(edition) => {
+            let hyphen_case_lint_name = name.replace('_', "-");
+            err.note_once(format!(
+                "`-W {hyphen_case_lint_name}` implied by `-Z preview-edition-migrations={edition}`"
+            ));
+            err.help_once(format!(
+                "this lint points out code that needs to change for Rust {edition}"
+            ));
+        }
         LintLevelSource::Node { name: lint_attr_name, span, reason, .. } => {
             if let Some(rationale) = reason {
                 err.note(rationale.to_string());
@@ -354,7 +385,7 @@ fn lint_level_impl(
         // suppressed the lint due to macros.
         err.primary_message(msg);
 
-        err.is_lint(lint.name_lower(), has_future_breakage);
+        err.is_lint(lint.name_lower(), lint.doc_url(), has_future_breakage);
 
         // Lint diagnostics that are covered by the expect level will not be emitted outside
         // the compiler. It is therefore not necessary to add any information for the user.
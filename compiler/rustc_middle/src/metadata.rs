@@ -3,7 +3,7 @@
 use rustc_hir::def::Res;
 use rustc_macros::HashStable;
 use rustc_span::def_id::DefId;
-use rustc_span::symbol::Ident;
+use rustc_span::symbol::{Ident, Symbol};
 use smallvec::SmallVec;
 
 /// A simplified version of `ImportKind` from resolve.
@@ -44,3 +44,20 @@ pub struct ModChild {
     /// Empty if the module child is a proper item.
     pub reexport_chain: SmallVec<[Reexport; 2]>,
 }
+
+/// An item of a crate which other crates can import, see the `importable_items` query.
+#[derive(Debug, HashStable)]
+pub struct ImportableItem {
+    /// Resolution result corresponding to the item.
+    pub res: Res<!>,
+    /// Names of the path leading to the item from the root of its crate, the crate name
+    /// excluded and the name of the item included.
+    pub path: Vec<Symbol>,
+}
+
+impl ImportableItem {
+    /// The name of the item, which ends its path.
+    pub fn name(&self) -> Symbol {
+        *self.path.last().unwrap()
+    }
+}
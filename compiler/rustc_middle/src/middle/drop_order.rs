@@ -0,0 +1,81 @@
+//! The order in which the variables and temporaries of a body are dropped.
+//!
+//! This is derived from the scopes computed by region resolution (see `region.rs`): a variable
+//! is dropped at the end of the scope it is declared in, and a temporary at the end of its
+//! temporary scope, which is usually the enclosing statement. Values dropped at the end of the
+//! same scope are dropped in the reverse order of their creation.
+
+use crate::middle::region::Scope;
+use crate::ty::Ty;
+use rustc_hir::HirId;
+use rustc_macros::HashStable;
+use rustc_span::{Span, Symbol};
+
+/// A value dropped when leaving a scope.
+#[derive(Clone, Debug, HashStable)]
+pub struct ScopedDrop<'tcx> {
+    /// The binding, or the expression whose value is the temporary.
+    pub hir_id: HirId,
+    pub kind: ScopedDropKind,
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+    /// Whether dropping the value runs any code. Values that don't need dropping are still
+    /// recorded, as they are live until the end of their scope all the same.
+    pub needs_drop: bool,
+    /// Whether the destructor of the value may have side effects, like releasing a lock.
+    pub is_significant: bool,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, HashStable)]
+pub enum ScopedDropKind {
+    /// A variable introduced by a binding pattern.
+    Binding(Symbol),
+    /// A temporary holding the value of an expression used as a place, such as the operand of a
+    /// borrow, the receiver of a method taking `&self`, or the scrutinee of a `match`.
+    Temporary,
+}
+
+/// The values dropped when leaving a scope, in the order they are dropped.
+#[derive(Clone, Debug, HashStable)]
+pub struct ScopeDrops<'tcx> {
+    pub scope: Scope,
+    /// Where the values are dropped: the last character of the scope, e.g. the closing brace
+    /// of a block or the semicolon of a statement.
+    pub span: Span,
+    pub drops: Vec<ScopedDrop<'tcx>>,
+}
+
+/// The drop order of a body, and of the closures and coroutines it contains.
+#[derive(Clone, Debug, Default, HashStable)]
+pub struct DropOrder<'tcx> {
+    /// The scopes dropping at least one value, ordered by where they end.
+    pub scopes: Vec<ScopeDrops<'tcx>>,
+}
+
+impl<'tcx> DropOrder<'tcx> {
+    /// Returns the scope at the end of which the temporary holding the value of the expression
+    /// at `span` is dropped, if there is such a temporary.
+    pub fn temporary_scope(&self, span: Span) -> Option<&ScopeDrops<'tcx>> {
+        self.scopes.iter().find(|scope| {
+            scope
+                .drops
+                .iter()
+                .any(|drop| drop.kind == ScopedDropKind::Temporary && drop.span == span)
+        })
+    }
+
+    /// Returns the temporaries created while evaluating the expression at `span` that outlive
+    /// it, together with the scope at the end of which each of them is dropped.
+    pub fn temporaries_outliving(
+        &self,
+        span: Span,
+    ) -> impl Iterator<Item = (&ScopeDrops<'tcx>, &ScopedDrop<'tcx>)> + '_ {
+        self.scopes
+            .iter()
+            .filter(move |scope| !span.contains(scope.span))
+            .flat_map(|scope| scope.drops.iter().map(move |drop| (scope, drop)))
+            .filter(move |(_, drop)| {
+                drop.kind == ScopedDropKind::Temporary && span.contains(drop.span)
+            })
+    }
+}
@@ -1,6 +1,7 @@
 //! Registering limits:
 //! * recursion_limit,
-//! * move_size_limit, and
+//! * move_size_limit,
+//! * future_size_limit, and
 //! * type_length_limit
 //!
 //! There are various parts of the compiler that must impose arbitrary limits
@@ -26,6 +27,12 @@ pub fn provide(providers: &mut Providers) {
             sym::move_size_limit,
             tcx.sess.opts.unstable_opts.move_size_limit.unwrap_or(0),
         ),
+        future_size_limit: get_limit(
+            tcx.hir().krate_attrs(),
+            tcx.sess,
+            sym::future_size_limit,
+            tcx.sess.opts.unstable_opts.future_size_limit.unwrap_or(16384),
+        ),
         type_length_limit: get_limit(
             tcx.hir().krate_attrs(),
             tcx.sess,
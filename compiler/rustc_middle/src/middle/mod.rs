@@ -1,6 +1,7 @@
 pub mod codegen_fn_attrs;
 pub mod debugger_visualizer;
 pub mod dependency_format;
+pub mod drop_order;
 pub mod exported_symbols;
 pub mod lang_items;
 pub mod lib_features {
@@ -158,6 +158,11 @@ pub fn into_kind(self) -> InterpError<'tcx> {
     pub fn kind(&self) -> &InterpError<'tcx> {
         &self.0.kind
     }
+
+    #[inline]
+    pub fn kind_mut(&mut self) -> &mut InterpError<'tcx> {
+        &mut self.0.kind
+    }
 }
 
 fn print_backtrace(backtrace: &Backtrace) {
@@ -386,7 +391,10 @@ fn into_diagnostic_arg(self) -> DiagnosticArgValue {
 
 #[derive(Debug)]
 pub struct ValidationErrorInfo<'tcx> {
-    pub path: Option<String>,
+    /// The projections leading from the validated value to the invalid part, one string each.
+    pub path: Vec<String>,
+    /// Where the invalid part is stored, if it is in memory.
+    pub location: Option<(AllocId, AllocRange)>,
     pub kind: ValidationErrorKind<'tcx>,
 }
 
@@ -456,8 +464,6 @@ pub enum UnsupportedOpInfo {
     /// Free-form case. Only for errors that are never caught!
     // FIXME still use translatable diagnostics
     Unsupported(String),
-    /// Unsized local variables.
-    UnsizedLocal,
     //
     // The variants below are only reachable from CTFE/const prop, miri will never emit them.
     //
@@ -485,6 +491,10 @@ pub enum ResourceExhaustionInfo {
     MemoryExhausted,
     /// The address space (of the target) is full.
     AddressSpaceFull,
+    /// More evaluation steps were taken than allowed by `-Z const-eval-step-limit`.
+    StepLimitReached { limit: usize },
+    /// The live allocations grew bigger than allowed by `-Z const-eval-memory-limit`.
+    MemoryLimitReached { limit: u64, requested: u64 },
 }
 
 /// A trait for machine-specific errors (or other "machine stop" conditions).
@@ -143,9 +143,7 @@ pub fn const_eval_global_id(
         cid: GlobalId<'tcx>,
         span: Option<Span>,
     ) -> EvalToConstValueResult<'tcx> {
-        // Const-eval shouldn't depend on lifetimes at all, so we can erase them, which should
-        // improve caching of queries.
-        let inputs = self.erase_regions(param_env.with_reveal_all_normalized(self).and(cid));
+        let inputs = self.const_eval_query_key(param_env, cid);
         if let Some(span) = span {
             // The query doesn't know where it is being invoked, so we need to fix the span.
             self.at(span).eval_to_const_value_raw(inputs).map_err(|e| e.with_span(span))
@@ -162,9 +160,7 @@ pub fn const_eval_global_id_for_typeck(
         cid: GlobalId<'tcx>,
         span: Option<Span>,
     ) -> EvalToValTreeResult<'tcx> {
-        // Const-eval shouldn't depend on lifetimes at all, so we can erase them, which should
-        // improve caching of queries.
-        let inputs = self.erase_regions(param_env.with_reveal_all_normalized(self).and(cid));
+        let inputs = self.const_eval_query_key(param_env, cid);
         debug!(?inputs);
         if let Some(span) = span {
             // The query doesn't know where it is being invoked, so we need to fix the span.
@@ -173,6 +169,42 @@ pub fn const_eval_global_id_for_typeck(
             self.eval_to_valtree(inputs)
         }
     }
+
+    /// Evaluates a call of the `const fn` `instance`, where `args` is a branch holding the value
+    /// of every argument. Returns `None` if the call has to be evaluated in place instead.
+    #[instrument(skip(self), level = "debug")]
+    pub fn const_eval_fn_call(
+        self,
+        param_env: ty::ParamEnv<'tcx>,
+        instance: ty::Instance<'tcx>,
+        args: ty::ValTree<'tcx>,
+    ) -> Option<ty::ValTree<'tcx>> {
+        let cid = GlobalId { instance, promoted: None };
+        let ty::ParamEnvAnd { param_env, value: cid } = self.const_eval_query_key(param_env, cid);
+        self.eval_const_fn_call(param_env.and((cid.instance, args)))
+    }
+
+    /// Builds the key of the const-eval queries for evaluating `cid` in `param_env`.
+    fn const_eval_query_key(
+        self,
+        param_env: ty::ParamEnv<'tcx>,
+        cid: GlobalId<'tcx>,
+    ) -> ty::ParamEnvAnd<'tcx, GlobalId<'tcx>> {
+        let param_env = param_env.with_reveal_all_normalized(self);
+        // Const-eval shouldn't depend on lifetimes at all, so we can erase them, which should
+        // improve caching of queries.
+        let cid = self.erase_regions(cid);
+        // A constant that does not mention any generic parameters evaluates the same way in every
+        // environment, so we evaluate it without caller bounds. That way, generic code using
+        // e.g. `<u8 as Trait>::CONST` shares a single evaluation (and its allocations) instead
+        // of evaluating the constant again for every caller. Global caller bounds like
+        // `where u8: Trait` can however influence the evaluation, so we keep those environments.
+        if cid.is_global() && !param_env.caller_bounds().iter().any(|clause| clause.is_global()) {
+            param_env.without_caller_bounds().and(cid)
+        } else {
+            self.erase_regions(param_env.and(cid))
+        }
+    }
 }
 
 impl<'tcx> TyCtxtEnsure<'tcx> {
@@ -188,10 +220,8 @@ pub fn const_eval_poly(self, def_id: DefId) {
         let args = GenericArgs::identity_for_item(self.tcx, def_id);
         let instance = ty::Instance::new(def_id, args);
         let cid = GlobalId { instance, promoted: None };
-        let param_env = self.tcx.param_env(def_id).with_reveal_all_normalized(self.tcx);
-        // Const-eval shouldn't depend on lifetimes at all, so we can erase them, which should
-        // improve caching of queries.
-        let inputs = self.tcx.erase_regions(param_env.and(cid));
+        let param_env = self.tcx.param_env(def_id);
+        let inputs = self.tcx.const_eval_query_key(param_env, cid);
         self.eval_to_const_value_raw(inputs)
     }
 }
@@ -157,6 +157,23 @@ fn is_enabled(&self, _sess: &Session) -> bool {
         true
     }
 
+    /// Returns the properties of the body which must hold for this pass to run. The pass manager
+    /// runs the passes establishing the missing ones first.
+    fn requires(&self) -> MirProperties {
+        MirProperties::empty()
+    }
+
+    /// Returns the properties of the body which hold after this pass runs.
+    fn establishes(&self) -> MirProperties {
+        MirProperties::empty()
+    }
+
+    /// Returns the properties of the body which this pass may break. By default, it may break all
+    /// of them.
+    fn invalidates(&self) -> MirProperties {
+        MirProperties::all()
+    }
+
     fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>);
 
     fn is_mir_dump_enabled(&self) -> bool {
@@ -164,6 +181,26 @@ fn is_mir_dump_enabled(&self) -> bool {
     }
 }
 
+/// Properties of a MIR body that some passes rely on, and that other passes may break. See
+/// [`MirPass::requires`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct MirProperties(u8);
+bitflags! {
+    impl MirProperties: u8 {
+        /// No call terminator which may unwind has an edge to a return target with several
+        /// predecessors, so that code can be inserted along that edge. Established by
+        /// `AddCallGuards::CriticalCallEdges`.
+        const NO_CRITICAL_CALL_EDGES = 1 << 0;
+    }
+}
+rustc_data_structures::external_bitflags_debug! { MirProperties }
+
+impl MirProperties {
+    /// The properties which only depend on the control-flow graph. A pass which doesn't change
+    /// the edges between blocks doesn't invalidate them.
+    pub const CFG: MirProperties = MirProperties::NO_CRITICAL_CALL_EDGES;
+}
+
 impl MirPhase {
     /// Gets the index of the current MirPhase within the set of all `MirPhase`s.
     ///
@@ -403,6 +440,12 @@ pub struct Body<'tcx> {
 
     pub tainted_by_errors: Option<ErrorGuaranteed>,
 
+    /// Branch coverage information collected during MIR building, to be used by
+    /// the `InstrumentCoverage` pass.
+    ///
+    /// Only present if branch coverage is enabled and this function is eligible.
+    pub coverage_branch_info: Option<Box<coverage::BranchInfo>>,
+
     /// Per-function coverage information added by the `InstrumentCoverage`
     /// pass, to be used in conjunction with the coverage statements injected
     /// into this body's blocks.
@@ -450,6 +493,7 @@ pub fn new(
             is_polymorphic: false,
             injection_phase: None,
             tainted_by_errors,
+            coverage_branch_info: None,
             function_coverage_info: None,
         };
         body.is_polymorphic = body.has_non_region_param();
@@ -479,6 +523,7 @@ pub fn new_cfg_only(basic_blocks: IndexVec<BasicBlock, BasicBlockData<'tcx>>) ->
             is_polymorphic: false,
             injection_phase: None,
             tainted_by_errors: None,
+            coverage_branch_info: None,
             function_coverage_info: None,
         };
         body.is_polymorphic = body.has_non_region_param();
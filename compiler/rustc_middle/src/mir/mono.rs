@@ -245,6 +245,10 @@ pub struct CodegenUnit<'tcx> {
     items: FxIndexMap<MonoItem<'tcx>, MonoItemData>,
     size_estimate: usize,
     primary: bool,
+    /// Items of this CGU that are emitted as aliases of another item of the
+    /// same CGU instead of being codegened themselves, see
+    /// `-Zdedup-mono-items`.
+    aliases: FxIndexMap<MonoItem<'tcx>, MonoItem<'tcx>>,
     /// True if this is CGU is used to hold code coverage information for dead code,
     /// false otherwise.
     is_code_coverage_dead_code_cgu: bool,
@@ -297,6 +301,7 @@ pub fn new(name: Symbol) -> CodegenUnit<'tcx> {
             items: Default::default(),
             size_estimate: 0,
             primary: false,
+            aliases: Default::default(),
             is_code_coverage_dead_code_cgu: false,
         }
     }
@@ -325,6 +330,22 @@ pub fn items_mut(&mut self) -> &mut FxIndexMap<MonoItem<'tcx>, MonoItemData> {
         &mut self.items
     }
 
+    /// Returns the item that `item` should be emitted as an alias of, if any.
+    pub fn alias_of(&self, item: &MonoItem<'tcx>) -> Option<MonoItem<'tcx>> {
+        self.aliases.get(item).copied()
+    }
+
+    pub fn aliases(&self) -> &FxIndexMap<MonoItem<'tcx>, MonoItem<'tcx>> {
+        &self.aliases
+    }
+
+    /// Records that `alias` is emitted as an alias of `aliasee`. Both items
+    /// must be part of this CGU.
+    pub fn add_alias(&mut self, alias: MonoItem<'tcx>, aliasee: MonoItem<'tcx>) {
+        debug_assert!(self.items.contains_key(&alias) && self.items.contains_key(&aliasee));
+        self.aliases.insert(alias, aliasee);
+    }
+
     pub fn is_code_coverage_dead_code_cgu(&self) -> bool {
         self.is_code_coverage_dead_code_cgu
     }
@@ -430,6 +451,7 @@ impl<'a, 'tcx> HashStable<StableHashingContext<'a>> for CodegenUnit<'tcx> {
     fn hash_stable(&self, hcx: &mut StableHashingContext<'a>, hasher: &mut StableHasher) {
         let CodegenUnit {
             ref items,
+            ref aliases,
             name,
             // The size estimate is not relevant to the hash
             size_estimate: _,
@@ -452,6 +474,21 @@ fn hash_stable(&self, hcx: &mut StableHashingContext<'a>, hasher: &mut StableHas
 
         items.sort_unstable_by_key(|i| i.0);
         items.hash_stable(hcx, hasher);
+
+        let mut aliases: Vec<(Fingerprint, Fingerprint)> = aliases
+            .iter()
+            .map(|(alias, aliasee)| {
+                let mut hasher = StableHasher::new();
+                alias.hash_stable(hcx, &mut hasher);
+                let alias_fingerprint = hasher.finish();
+                let mut hasher = StableHasher::new();
+                aliasee.hash_stable(hcx, &mut hasher);
+                (alias_fingerprint, hasher.finish())
+            })
+            .collect();
+
+        aliases.sort_unstable_by_key(|a| a.0);
+        aliases.hash_stable(hcx, hasher);
     }
 }
 
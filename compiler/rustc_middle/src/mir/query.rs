@@ -7,7 +7,7 @@
 use rustc_errors::ErrorGuaranteed;
 use rustc_hir as hir;
 use rustc_hir::def_id::LocalDefId;
-use rustc_index::bit_set::BitMatrix;
+use rustc_index::bit_set::{BitMatrix, BitSet};
 use rustc_index::{Idx, IndexVec};
 use rustc_span::symbol::Symbol;
 use rustc_span::Span;
@@ -120,6 +120,14 @@ pub struct CoroutineLayout<'tcx> {
     #[type_foldable(identity)]
     #[type_visitable(ignore)]
     pub storage_conflicts: BitMatrix<CoroutineSavedLocal, CoroutineSavedLocal>,
+
+    /// For every upvar which isn't used after a suspension point, the saved locals which may be
+    /// storage-live while it is still used, or `None` for the upvars used after one. An upvar
+    /// which isn't needed once the coroutine is first resumed may overlap in the layout with the
+    /// saved locals it doesn't conflict with, instead of being stored in every variant.
+    #[type_foldable(identity)]
+    #[type_visitable(ignore)]
+    pub upvar_conflicts: IndexVec<FieldIdx, Option<BitSet<CoroutineSavedLocal>>>,
 }
 
 impl Debug for CoroutineLayout<'_> {
@@ -417,3 +425,21 @@ pub struct CoverageIdsInfo {
     /// were removed by MIR optimizations.
     pub max_counter_id: mir::coverage::CounterId,
 }
+
+/// The execution counts of functions read from the instrumentation profile given with
+/// `-C profile-use`, which the MIR inliner uses to tell hot call sites from cold ones.
+///
+/// Used by the `mir_inliner_profile` query.
+#[derive(Clone, Debug, Default, HashStable)]
+pub struct InlinerProfile {
+    /// The highest counter value of each function in the profile, by symbol name.
+    pub execution_counts: FxIndexMap<String, u64>,
+}
+
+impl InlinerProfile {
+    /// Returns how many times the function with the given symbol name was executed, or `None`
+    /// if it isn't part of the profile.
+    pub fn execution_count(&self, symbol_name: &str) -> Option<u64> {
+        self.execution_counts.get(symbol_name).copied()
+    }
+}
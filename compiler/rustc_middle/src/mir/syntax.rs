@@ -691,6 +691,32 @@ pub enum TerminatorKind<'tcx> {
         fn_span: Span,
     },
 
+    /// A guaranteed tail call, as written with `become`.
+    ///
+    /// Roughly speaking, this is a `Call` immediately followed by a `Return`: it evaluates the
+    /// `func` operand and the arguments, ends the execution of the current function, and starts
+    /// execution of the referred to function in its place. The callee then returns directly to
+    /// the caller of the current function, so unlike `Call` this has no `destination`, `target`
+    /// or `unwind`: these are all taken from the current stack frame.
+    ///
+    /// The arguments are evaluated before the current stack frame goes away, so they may not
+    /// refer to the locals of the function. The signature of the callee must match the one of
+    /// the current function, including its ABI.
+    ///
+    /// Not permitted in coroutine bodies, or in cleanup blocks.
+    TailCall {
+        /// The function that’s being called.
+        func: Operand<'tcx>,
+        /// Arguments the function is called with.
+        /// These are owned by the callee, which is free to modify them.
+        /// The span for each arg is also included
+        /// (e.g. `a` and `b` in `become x.foo(a, b)`).
+        args: Vec<Spanned<Operand<'tcx>>>,
+        /// This `Span` is the span of the function, without the dot and receiver
+        /// e.g. `foo(a, b)` in `x.foo(a, b)`
+        fn_span: Span,
+    },
+
     /// Evaluates the operand, which must have type `bool`. If it is not equal to `expected`,
     /// initiates a panic. Initiating a panic corresponds to a `Call` terminator with some
     /// unspecified constant as the function to call, all the operands stored in the `AssertMessage`
@@ -816,6 +842,7 @@ pub const fn name(&self) -> &'static str {
             TerminatorKind::Unreachable => "Unreachable",
             TerminatorKind::Drop { .. } => "Drop",
             TerminatorKind::Call { .. } => "Call",
+            TerminatorKind::TailCall { .. } => "TailCall",
             TerminatorKind::Assert { .. } => "Assert",
             TerminatorKind::Yield { .. } => "Yield",
             TerminatorKind::CoroutineDrop => "CoroutineDrop",
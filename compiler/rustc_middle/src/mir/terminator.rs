@@ -394,6 +394,7 @@ pub fn successors(&self) -> Successors<'_> {
             | UnwindTerminate(_)
             | CoroutineDrop
             | Return
+            | TailCall { .. }
             | Unreachable
             | Call { target: None, unwind: _, .. }
             | InlineAsm { destination: None, unwind: _, .. } => {
@@ -437,6 +438,7 @@ pub fn successors_mut(&mut self) -> SuccessorsMut<'_> {
             | UnwindTerminate(_)
             | CoroutineDrop
             | Return
+            | TailCall { .. }
             | Unreachable
             | Call { target: None, unwind: _, .. }
             | InlineAsm { destination: None, unwind: _, .. } => None.into_iter().chain(&mut []),
@@ -454,6 +456,7 @@ pub fn unwind(&self) -> Option<&UnwindAction> {
             | TerminatorKind::UnwindResume
             | TerminatorKind::UnwindTerminate(_)
             | TerminatorKind::Return
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::Unreachable
             | TerminatorKind::CoroutineDrop
             | TerminatorKind::Yield { .. }
@@ -474,6 +477,7 @@ pub fn unwind_mut(&mut self) -> Option<&mut UnwindAction> {
             | TerminatorKind::UnwindResume
             | TerminatorKind::UnwindTerminate(_)
             | TerminatorKind::Return
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::Unreachable
             | TerminatorKind::CoroutineDrop
             | TerminatorKind::Yield { .. }
@@ -559,9 +563,12 @@ impl<'tcx> TerminatorKind<'tcx> {
     pub fn edges(&self) -> TerminatorEdges<'_, 'tcx> {
         use TerminatorKind::*;
         match *self {
-            Return | UnwindResume | UnwindTerminate(_) | CoroutineDrop | Unreachable => {
-                TerminatorEdges::None
-            }
+            Return
+            | TailCall { .. }
+            | UnwindResume
+            | UnwindTerminate(_)
+            | CoroutineDrop
+            | Unreachable => TerminatorEdges::None,
 
             Goto { target } => TerminatorEdges::Single(target),
 
@@ -181,6 +181,10 @@ impl EraseType for Option<ty::EarlyBinder<ty::ImplTraitHeader<'_>>> {
     type Result = [u8; size_of::<Option<ty::EarlyBinder<ty::ImplTraitHeader<'static>>>>()];
 }
 
+impl EraseType for Option<ty::ValTree<'_>> {
+    type Result = [u8; size_of::<Option<ty::ValTree<'static>>>()];
+}
+
 impl EraseType for Option<ty::EarlyBinder<Ty<'_>>> {
     type Result = [u8; size_of::<Option<ty::EarlyBinder<Ty<'static>>>>()];
 }
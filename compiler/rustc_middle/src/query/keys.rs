@@ -534,6 +534,14 @@ fn default_span(&self, _: TyCtxt<'_>) -> Span {
     }
 }
 
+impl<'tcx> Key for (ty::Instance<'tcx>, ty::ValTree<'tcx>) {
+    type CacheSelector = DefaultCacheSelector<Self>;
+
+    fn default_span(&self, tcx: TyCtxt<'_>) -> Span {
+        self.0.default_span(tcx)
+    }
+}
+
 impl Key for HirId {
     type CacheSelector = DefaultCacheSelector<Self>;
 
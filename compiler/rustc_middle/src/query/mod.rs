@@ -9,7 +9,7 @@
 use crate::dep_graph;
 use crate::infer::canonical::{self, Canonical};
 use crate::lint::LintExpectation;
-use crate::metadata::ModChild;
+use crate::metadata::{ImportableItem, ModChild};
 use crate::middle::codegen_fn_attrs::CodegenFnAttrs;
 use crate::middle::debugger_visualizer::DebuggerVisualizerFile;
 use crate::middle::exported_symbols::{ExportedSymbol, SymbolExportInfo};
@@ -41,8 +41,8 @@
 };
 use crate::traits::specialization_graph;
 use crate::traits::{
-    CodegenObligationError, EvaluationResult, ImplSource, ObjectSafetyViolation, ObligationCause,
-    OverflowError, WellFormedLoc,
+    CodegenObligationError, EvaluationResult, ImplSource, ObjectSafetyExplanation,
+    ObjectSafetyViolation, ObligationCause, OverflowError, WellFormedLoc,
 };
 use crate::ty::fast_reject::SimplifiedType;
 use crate::ty::layout::ValidityRequirement;
@@ -420,6 +420,11 @@
         desc { "computing `#[expect]`ed lints in this crate" }
     }
 
+    query lint_config(_: ()) -> &'tcx rustc_middle::lint::LintConfig {
+        arena_cache
+        desc { "computing the configuration of lints set with `#![lint_config]`" }
+    }
+
     query expn_that_defined(key: DefId) -> rustc_span::ExpnId {
         desc { |tcx| "getting the expansion that defined `{}`", tcx.def_path_str(key) }
         separate_provide_extern
@@ -548,6 +553,18 @@
         }
     }
 
+    /// Returns the places captured by a closure or a coroutine, in the order of its upvars,
+    /// together with how each of them is captured and the uses that caused it.
+    ///
+    /// This is meant for tools, and only exposes types that don't depend on the internals of
+    /// capture analysis.
+    query inferred_closure_captures(key: LocalDefId) -> &'tcx [ty::InferredCapture] {
+        desc {
+            |tcx| "computing the inferred captures of closure `{}`",
+            tcx.def_path_str(key)
+        }
+    }
+
     /// Returns names of captured upvars for closures and coroutines.
     ///
     /// Here are some examples:
@@ -588,6 +605,12 @@
         arena_cache
     }
 
+    /// Returns `false` if coverage instrumentation should be skipped for this
+    /// function, e.g. because it is not fn-like or has `#[coverage(off)]`.
+    query is_eligible_for_coverage(key: LocalDefId) -> bool {
+        desc { |tcx| "checking if `{}` is eligible for coverage instrumentation", tcx.def_path_str(key) }
+    }
+
     /// The `DefId` is the `DefId` of the containing MIR body. Promoteds do not have their own
     /// `DefId`. This function returns all promoteds in the specified body. The body references
     /// promoteds by the `DefId` and the `mir::Promoted` index. This is necessary, because
@@ -679,6 +702,14 @@
         separate_provide_extern
     }
 
+    /// Returns the traits relaxed by `?Trait` bounds written directly on a trait alias,
+    /// e.g. `Sized` for `trait Alias = Foo + ?Sized;`. These are not part of the
+    /// [`implied_predicates_of`](Self::implied_predicates_of) the alias.
+    query trait_alias_relaxed_bounds(key: DefId) -> &'tcx [(DefId, Span)] {
+        desc { |tcx| "computing the relaxed bounds of trait alias `{}`", tcx.def_path_str(key) }
+        separate_provide_extern
+    }
+
     /// The `Option<Ident>` is the name of an associated type. If it is `None`, then this query
     /// returns the full set of predicates. If `Some<Ident>`, then the query returns only the
     /// subset of super-predicates that reference traits that define the given associated type.
@@ -952,14 +983,38 @@
     ///
     /// The second return value maps from ADTs to ignored derived traits (e.g. Debug and Clone) and
     /// their respective impl (i.e., part of the derive macro)
+    ///
+    /// The third return value is the set of items of other crates used by live code, which is
+    /// only collected with `-Z cross-crate-dead-code`.
     query live_symbols_and_ignored_derived_traits(_: ()) -> &'tcx (
         LocalDefIdSet,
-        LocalDefIdMap<Vec<(DefId, DefId)>>
+        LocalDefIdMap<Vec<(DefId, DefId)>>,
+        UnordSet<DefId>
     ) {
         arena_cache
         desc { "finding live symbols in crate" }
     }
 
+    /// The items of other crates used by the live code of a crate, if it was built with
+    /// `-Z cross-crate-dead-code`.
+    query used_upstream_items(_: CrateNum) -> Option<&'tcx [DefId]> {
+        desc { "finding the items of other crates used by a crate" }
+        separate_provide_extern
+    }
+
+    /// The paths of the items of this crate used by the crates built with
+    /// `-Z cross-crate-dead-code`, as read from the usage manifests they wrote. `None` if no
+    /// manifest lists this crate.
+    ///
+    /// NOTE: This query has to be marked `eval_always` because it reads data
+    ///       directly from disk that is not tracked anywhere else. I.e. it
+    ///       represents a genuine input to the query system.
+    query usage_manifests(_: ()) -> &'tcx Option<UnordSet<String>> {
+        arena_cache
+        desc { "loading the usage manifests of the crates using this one" }
+        eval_always
+    }
+
     query check_mod_deathness(key: LocalModDefId) {
         desc { |tcx| "checking deathness of variables in {}", describe_as_module(key, tcx) }
     }
@@ -1051,6 +1106,13 @@
         }
     }
 
+    /// The profile data the MIR inliner uses with `-Z inline-mir-profile-threshold`, if any.
+    query mir_inliner_profile(_: ()) -> &'tcx Option<mir::InlinerProfile> {
+        arena_cache
+        eval_always
+        desc { "reading the profile data used by the MIR inliner" }
+    }
+
     /// Obtain all the calls into other local functions
     query mir_inliner_callees(key: ty::InstanceDef<'tcx>) -> &'tcx [(DefId, GenericArgsRef<'tcx>)] {
         fatal_cycle
@@ -1110,6 +1172,19 @@
         desc { "converting type-level constant value to mir constant value"}
     }
 
+    /// Evaluates a call of a `const fn` whose arguments (a branch holding the value of every
+    /// argument) and return value are plain data, so that all constants making the same call
+    /// share a single evaluation. Returns `None` if the call cannot be evaluated on its own;
+    /// callers then evaluate the call in place, which also reports any errors.
+    ///
+    /// **Do not use this** directly, use `tcx.const_eval_fn_call` instead.
+    query eval_const_fn_call(
+        key: ty::ParamEnvAnd<'tcx, (ty::Instance<'tcx>, ty::ValTree<'tcx>)>
+    ) -> Option<ty::ValTree<'tcx>> {
+        desc { |tcx| "const-evaluating a call of `{}`", key.value.0 }
+        cache_on_disk_if { true }
+    }
+
     /// Destructures array, ADT or tuple constants into the constants
     /// of their fields.
     query destructure_const(key: ty::Const<'tcx>) -> ty::DestructuredConst<'tcx> {
@@ -1150,6 +1225,13 @@
         desc { |tcx| "computing drop scopes for `{}`", tcx.def_path_str(def_id) }
     }
 
+    /// Per-body drop order: the values dropped at the end of each scope, in the order they
+    /// are dropped. Like `region_scope_tree`, this is redirected to the enclosing function
+    /// for closures.
+    query drop_order(def_id: DefId) -> &'tcx crate::middle::drop_order::DropOrder<'tcx> {
+        desc { |tcx| "computing the drop order of `{}`", tcx.def_path_str(def_id) }
+    }
+
     /// Generates a MIR body for the shim.
     query mir_shims(key: ty::InstanceDef<'tcx>) -> &'tcx mir::Body<'tcx> {
         arena_cache
@@ -1320,6 +1402,11 @@
     query object_safety_violations(trait_id: DefId) -> &'tcx [ObjectSafetyViolation] {
         desc { |tcx| "determining object safety of trait `{}`", tcx.def_path_str(trait_id) }
     }
+    /// Returns the same violations as `object_safety_violations`, together with the
+    /// ways each of them could be fixed. This is only meant to be used for diagnostics.
+    query object_safety_explanations(trait_id: DefId) -> &'tcx [ObjectSafetyExplanation] {
+        desc { |tcx| "explaining the object safety violations of trait `{}`", tcx.def_path_str(trait_id) }
+    }
     query check_is_object_safe(trait_id: DefId) -> bool {
         desc { |tcx| "checking if trait `{}` is object safe", tcx.def_path_str(trait_id) }
     }
@@ -1489,6 +1576,13 @@
         desc { "getting whether a crate has `#![no_builtins]`" }
         separate_provide_extern
     }
+    /// Whether the crate is an rlib built with `-Z mir-only-rlibs`, which contains no machine
+    /// code for its functions: each crate using them codegens its own copy from their MIR.
+    query is_mir_only_rlib(_: CrateNum) -> bool {
+        fatal_cycle
+        desc { "checking if the crate is a MIR-only rlib" }
+        separate_provide_extern
+    }
     query symbol_mangling_version(_: CrateNum) -> SymbolManglingVersion {
         fatal_cycle
         desc { "getting a crate's symbol mangling version" }
@@ -1810,6 +1904,13 @@
         arena_cache
         desc { "calculating trimmed def paths" }
     }
+    /// The items of an external crate which are reachable from its root through public modules,
+    /// with the shortest path to each of them, sorted by name. Items hidden with `#[doc(hidden)]`
+    /// are left out. Used by name resolution to suggest similarly named items of other crates.
+    query importable_items(cnum: CrateNum) -> &'tcx Vec<ImportableItem> {
+        arena_cache
+        desc { |tcx| "collecting the importable items of `{}`", tcx.crate_name(cnum) }
+    }
     query missing_extern_crate_item(_: CrateNum) -> bool {
         eval_always
         desc { "seeing if we're missing an `extern crate` item for this crate" }
@@ -2228,6 +2329,12 @@
     query find_field((def_id, ident): (DefId, rustc_span::symbol::Ident)) -> Option<rustc_target::abi::FieldIdx> {
         desc { |tcx| "find the index of maybe nested field `{ident}` in `{}`", tcx.def_path_str(def_id) }
     }
+
+    /// Returns the fields of `self` that an inherent method declares to borrow with the
+    /// unstable `#[view(..)]` attribute, or `None` if the method may use all of `self`.
+    query fn_self_view(def_id: LocalDefId) -> Option<&'tcx [rustc_target::abi::FieldIdx]> {
+        desc { |tcx| "computing the fields of `self` viewed by `{}`", tcx.def_path_str(def_id) }
+    }
 }
 
 rustc_query_append! { define_callbacks! }
@@ -847,35 +847,6 @@ pub fn error_msg(&self) -> Cow<'static, str> {
         }
     }
 
-    pub fn solution(&self) -> ObjectSafetyViolationSolution {
-        match self {
-            ObjectSafetyViolation::SizedSelf(_)
-            | ObjectSafetyViolation::SupertraitSelf(_)
-            | ObjectSafetyViolation::SupertraitNonLifetimeBinder(..) => {
-                ObjectSafetyViolationSolution::None
-            }
-            ObjectSafetyViolation::Method(
-                name,
-                MethodViolationCode::StaticMethod(Some((add_self_sugg, make_sized_sugg))),
-                _,
-            ) => ObjectSafetyViolationSolution::AddSelfOrMakeSized {
-                name: *name,
-                add_self_sugg: add_self_sugg.clone(),
-                make_sized_sugg: make_sized_sugg.clone(),
-            },
-            ObjectSafetyViolation::Method(
-                name,
-                MethodViolationCode::UndispatchableReceiver(Some(span)),
-                _,
-            ) => ObjectSafetyViolationSolution::ChangeToRefSelf(*name, *span),
-            ObjectSafetyViolation::AssocConst(name, _)
-            | ObjectSafetyViolation::GAT(name, _)
-            | ObjectSafetyViolation::Method(name, ..) => {
-                ObjectSafetyViolationSolution::MoveToAnotherTrait(*name)
-            }
-        }
-    }
-
     pub fn spans(&self) -> SmallVec<[Span; 1]> {
         // When `span` comes from a separate crate, it'll be `DUMMY_SP`. Treat it as `None` so
         // diagnostics use a `note` instead of a `span_label`.
@@ -895,22 +866,43 @@ pub fn solution(&self) -> ObjectSafetyViolationSolution {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// An object safety violation together with the ways it could be fixed,
+/// see the `object_safety_explanations` query.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, HashStable)]
+pub struct ObjectSafetyExplanation {
+    pub violation: ObjectSafetyViolation,
+    /// Each solution fixes `violation` on its own. This is empty if there
+    /// is no general fix, e.g. for supertraits referencing `Self`.
+    pub solutions: Vec<ObjectSafetyViolationSolution>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, HashStable, PartialOrd, Ord)]
 pub enum ObjectSafetyViolationSolution {
-    None,
+    /// Turn an associated function into a method or exempt it from object safety.
     AddSelfOrMakeSized {
         name: Symbol,
         add_self_sugg: (String, Span),
         make_sized_sugg: (String, Span),
     },
+    /// Add `where Self: Sized` to a method, which exempts it from object safety.
+    MakeSized {
+        name: Symbol,
+        make_sized_sugg: (String, Span),
+    },
+    /// Replace the `impl Trait` arguments of a method with trait objects, so that
+    /// the method is no longer generic.
+    UseTraitObjectArgs {
+        name: Symbol,
+        suggs: Vec<(Span, String)>,
+    },
     ChangeToRefSelf(Symbol, Span),
+    /// Move the item to another trait, e.g. an extension trait with a blanket impl.
     MoveToAnotherTrait(Symbol),
 }
 
 impl ObjectSafetyViolationSolution {
     pub fn add_to(self, err: &mut Diagnostic) {
         match self {
-            ObjectSafetyViolationSolution::None => {}
             ObjectSafetyViolationSolution::AddSelfOrMakeSized {
                 name,
                 add_self_sugg,
@@ -934,6 +926,23 @@ trait objects"
                     Applicability::MaybeIncorrect,
                 );
             }
+            ObjectSafetyViolationSolution::MakeSized { name, make_sized_sugg } => {
+                err.span_suggestion(
+                    make_sized_sugg.1,
+                    format!("consider constraining `{name}` so it does not apply to trait objects"),
+                    make_sized_sugg.0,
+                    Applicability::MaybeIncorrect,
+                );
+            }
+            ObjectSafetyViolationSolution::UseTraitObjectArgs { name, suggs } => {
+                err.multipart_suggestion(
+                    format!(
+                        "consider using trait objects instead of `impl Trait` arguments of `{name}`"
+                    ),
+                    suggs,
+                    Applicability::MaybeIncorrect,
+                );
+            }
             ObjectSafetyViolationSolution::ChangeToRefSelf(name, span) => {
                 err.span_suggestion(
                     span,
@@ -59,7 +59,9 @@ pub enum OverlapMode {
 
 impl OverlapMode {
     pub fn get(tcx: TyCtxt<'_>, trait_id: DefId) -> OverlapMode {
-        let with_negative_coherence = tcx.features().with_negative_coherence;
+        let features = tcx.features();
+        let with_negative_coherence = features.with_negative_coherence
+            || (features.negative_impls && features.negative_bounds_coherence);
         let strict_coherence = tcx.has_attr(trait_id, sym::rustc_strict_coherence);
 
         if with_negative_coherence {
@@ -118,6 +118,9 @@ pub fn to_symbol(&self) -> Symbol {
                         bug!("Unexpected type {:?} for `Field` projection", ty)
                     }
                 },
+                HirProjectionKind::ConstantIndex(idx) => {
+                    write!(&mut symbol, "__{idx}").unwrap();
+                }
 
                 // Ignore derefs for now, as they are likely caused by
                 // autoderefs that don't appear in the original code.
@@ -207,6 +210,33 @@ fn closure_typeinfo<'tcx>(tcx: TyCtxt<'tcx>, def: LocalDefId) -> ClosureTypeInfo
     ClosureTypeInfo { user_provided_sig, captures, kind_origin }
 }
 
+/// A place captured by a closure or a coroutine, as inferred by capture analysis.
+///
+/// Unlike `CapturedPlace`, this doesn't refer to HIR places or regions, which makes it suitable
+/// for tools that want to show what a closure captures, and why.
+#[derive(Copy, Clone, Debug, HashStable)]
+pub struct InferredCapture {
+    /// The captured place as it would be written in source, e.g. `x`, `x.0.field` or `*x`.
+    pub place: Symbol,
+    /// How the place is captured.
+    pub kind: UpvarCapture,
+    /// The use of the place that caused it to be captured.
+    pub path_span: Span,
+    /// The use of the place that required its capture kind.
+    pub kind_span: Span,
+}
+
+fn inferred_closure_captures<'tcx>(tcx: TyCtxt<'tcx>, def: LocalDefId) -> &'tcx [InferredCapture] {
+    tcx.arena.alloc_from_iter(tcx.closure_captures(def).iter().map(|captured_place| {
+        InferredCapture {
+            place: Symbol::intern(&captured_place.to_string(tcx)),
+            kind: captured_place.info.capture_kind,
+            path_span: captured_place.get_path_span(tcx),
+            kind_span: captured_place.get_capture_kind_span(tcx),
+        }
+    }))
+}
+
 impl<'tcx> TyCtxt<'tcx> {
     pub fn closure_kind_origin(self, def_id: LocalDefId) -> Option<&'tcx (Span, HirPlace<'tcx>)> {
         self.closure_typeinfo(def_id).kind_origin
@@ -325,6 +355,9 @@ pub fn place_to_string_for_capture<'tcx>(tcx: TyCtxt<'tcx>, place: &HirPlace<'tc
                     )
                 }
             },
+            HirProjectionKind::ConstantIndex(idx) => {
+                curr_string = format!("{curr_string}[{idx}]");
+            }
             proj => bug!("{:?} unexpected because it isn't captured", proj),
         }
     }
@@ -416,5 +449,5 @@ pub fn to_mutbl_lossy(self) -> hir::Mutability {
 }
 
 pub fn provide(providers: &mut Providers) {
-    *providers = Providers { closure_typeinfo, ..*providers }
+    *providers = Providers { closure_typeinfo, inferred_closure_captures, ..*providers }
 }
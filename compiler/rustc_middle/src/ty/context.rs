@@ -1332,6 +1332,10 @@ pub fn move_size_limit(self) -> Limit {
         self.limits(()).move_size_limit
     }
 
+    pub fn future_size_limit(self) -> Limit {
+        self.limits(()).future_size_limit
+    }
+
     pub fn all_traits(self) -> impl Iterator<Item = DefId> + 'tcx {
         iter::once(LOCAL_CRATE)
             .chain(self.crates(()).iter().copied())
@@ -2373,6 +2377,9 @@ pub fn provide(providers: &mut Providers) {
         |tcx, LocalCrate| attr::contains_name(tcx.hir().krate_attrs(), sym::panic_runtime);
     providers.is_compiler_builtins =
         |tcx, LocalCrate| attr::contains_name(tcx.hir().krate_attrs(), sym::compiler_builtins);
+    providers.is_mir_only_rlib = |tcx, LocalCrate| {
+        tcx.sess.opts.unstable_opts.mir_only_rlibs && tcx.crate_types() == [CrateType::Rlib]
+    };
     providers.has_panic_handler = |tcx, LocalCrate| {
         // We want to check if the panic handler was defined in this crate
         tcx.lang_items().panic_impl().is_some_and(|did| did.is_local())
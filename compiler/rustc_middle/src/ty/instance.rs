@@ -4,7 +4,7 @@
 use crate::ty::{EarlyBinder, GenericArgs, GenericArgsRef, TypeVisitableExt};
 use rustc_errors::ErrorGuaranteed;
 use rustc_hir as hir;
-use rustc_hir::def::Namespace;
+use rustc_hir::def::{DefKind, Namespace};
 use rustc_hir::def_id::{CrateNum, DefId};
 use rustc_hir::lang_items::LangItem;
 use rustc_index::bit_set::FiniteBitSet;
@@ -284,6 +284,15 @@ pub fn generates_cgu_internal_copy(&self, tcx: TyCtxt<'tcx>) -> bool {
         if let ty::InstanceDef::ThreadLocalShim(..) = *self {
             return false;
         }
+        // The functions of MIR-only rlibs are codegened by every crate using them, like
+        // `#[inline]` functions, so that the copies of different crates don't conflict. Their
+        // statics and the functions with an extern indicator are still codegened in the rlib.
+        if let ty::InstanceDef::Item(def_id) = *self
+            && matches!(tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn | DefKind::Closure)
+            && tcx.is_mir_only_rlib(def_id.krate)
+        {
+            return true;
+        }
         tcx.cross_crate_inlinable(self.def_id())
     }
 
@@ -1,16 +1,18 @@
-use crate::error::UnsupportedFnAbi;
+use crate::error::{LayoutTableNote, UnsupportedFnAbi};
 use crate::middle::codegen_fn_attrs::CodegenFnAttrFlags;
 use crate::query::TyCtxtAt;
 use crate::ty::normalize_erasing_regions::NormalizationError;
+use crate::ty::print::with_no_trimmed_paths;
 use crate::ty::{self, ConstKind, Ty, TyCtxt, TypeVisitableExt};
 use rustc_error_messages::DiagnosticMessage;
 use rustc_errors::{
     DiagCtxt, DiagnosticArgValue, DiagnosticBuilder, EmissionGuarantee, IntoDiagnostic,
-    IntoDiagnosticArg, Level,
+    IntoDiagnosticArg, LayoutTable, LayoutTableField, Level,
 };
 use rustc_hir as hir;
 use rustc_hir::def_id::DefId;
 use rustc_index::IndexVec;
+use rustc_session::code_stats::NicheDecisionInfo;
 use rustc_session::config::OptLevel;
 use rustc_span::symbol::{sym, Symbol};
 use rustc_span::{ErrorGuaranteed, Span, DUMMY_SP};
@@ -221,7 +223,7 @@ pub fn into_diagnostic(self) -> crate::error::LayoutError<'tcx> {
         use LayoutError::*;
         match self {
             Unknown(ty) => E::Unknown { ty },
-            SizeOverflow(ty) => E::Overflow { ty },
+            SizeOverflow(ty) => E::Overflow { ty, layout_table: None },
             NormalizationFailure(ty, e) => {
                 E::NormalizationFailure { ty, failure_ty: e.get_type_for_failure() }
             }
@@ -229,6 +231,187 @@ pub fn into_diagnostic(self) -> crate::error::LayoutError<'tcx> {
             ReferencesError(_) => E::ReferencesError,
         }
     }
+
+    /// Like [`LayoutError::into_diagnostic`], but with `-Z layout-tables`, the error about a type
+    /// too big for the current architecture also gets a note with the layout of its fields.
+    pub fn into_diagnostic_with_layout_table(
+        self,
+        tcx: TyCtxt<'tcx>,
+        param_env: ty::ParamEnv<'tcx>,
+    ) -> crate::error::LayoutError<'tcx> {
+        match self {
+            LayoutError::SizeOverflow(ty) => crate::error::LayoutError::Overflow {
+                ty,
+                layout_table: layout_table(tcx, param_env, ty),
+            },
+            _ => self.into_diagnostic(),
+        }
+    }
+}
+
+/// Computes the layout of the fields of `ty` for the notes of `-Z layout-tables`, or returns
+/// `None` without that flag. `None` is also returned for types other than structs, unions and
+/// tuples, and if the layout of one of the fields can't be computed.
+///
+/// If `ty` is too big for the current architecture, it has no actual layout, so its fields are
+/// laid out in declaration order, until one doesn't fit.
+pub fn layout_table<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    ty: Ty<'tcx>,
+) -> Option<LayoutTableNote<'tcx>> {
+    if !tcx.sess.opts.unstable_opts.layout_tables {
+        return None;
+    }
+
+    let (names, field_tys): (Vec<String>, Vec<Ty<'tcx>>) = match *ty.kind() {
+        ty::Adt(def, args) if !def.is_enum() => def
+            .non_enum_variant()
+            .fields
+            .iter()
+            .map(|field| (field.name.to_string(), field.ty(tcx, args)))
+            .unzip(),
+        ty::Tuple(tys) => tys.iter().enumerate().map(|(i, ty)| (i.to_string(), ty)).unzip(),
+        _ => return None,
+    };
+    let field_layouts = field_tys
+        .into_iter()
+        .map(|ty| tcx.layout_of(param_env.and(ty)).ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    let mut fields = Vec::with_capacity(field_layouts.len());
+    let table = match tcx.layout_of(param_env.and(ty)) {
+        Ok(layout) => {
+            let mut end = Size::ZERO;
+            for i in layout.fields.index_by_increasing_offset() {
+                let offset = layout.fields.offset(i);
+                fields.push(LayoutTableField {
+                    name: names[i].clone(),
+                    offset: Some(offset.bytes()),
+                    size: field_layouts[i].size.bytes(),
+                    align: field_layouts[i].align.abi.bytes(),
+                    padding: offset.bytes().saturating_sub(end.bytes()),
+                });
+                end = cmp::max(end, offset + field_layouts[i].size);
+            }
+            LayoutTable { size: Some(layout.size.bytes()), align: layout.align.abi.bytes(), fields }
+        }
+        Err(_) => {
+            let repr = match ty.kind() {
+                ty::Adt(def, _) => def.repr(),
+                _ => ReprOptions::default(),
+            };
+            let mut align = repr.align.unwrap_or(Align::ONE);
+            let mut offset = Some(Size::ZERO);
+            for (name, field) in std::iter::zip(names, field_layouts) {
+                let field_align = match repr.pack {
+                    Some(pack) => cmp::min(field.align.abi, pack),
+                    None => field.align.abi,
+                };
+                align = cmp::max(align, field_align);
+                let start = offset.map(|offset| offset.align_to(field_align));
+                fields.push(LayoutTableField {
+                    name,
+                    offset: start.map(|start| start.bytes()),
+                    size: field.size.bytes(),
+                    align: field_align.bytes(),
+                    padding: offset
+                        .zip(start)
+                        .map_or(0, |(offset, start)| (start - offset).bytes()),
+                });
+                offset = start.and_then(|start| start.checked_add(field.size, &tcx));
+            }
+            LayoutTable { size: None, align: align.bytes(), fields }
+        }
+    };
+    Some(LayoutTableNote { ty, table })
+}
+
+/// Describes, for `-Z print-niche-decisions`, how the variants of the ADT `layout.ty` are told
+/// apart and which niche it exposes to enclosing types, along with the reasons for both. Returns
+/// `None` for types other than ADTs.
+pub fn niche_decision<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    layout: TyAndLayout<'tcx>,
+) -> Option<NicheDecisionInfo> {
+    let ty::Adt(def, _) = *layout.ty.kind() else {
+        return None;
+    };
+    let repr = def.repr();
+    let variant_name = |index: VariantIdx| def.variant(index).name;
+
+    let tag = match &layout.variants {
+        _ if !def.is_enum() => None,
+        Variants::Single { .. } if layout.abi.is_uninhabited() => {
+            Some("none, the enum is uninhabited".to_string())
+        }
+        Variants::Single { index } => {
+            Some(format!("none, `{}` is the only inhabited variant", variant_name(*index)))
+        }
+        Variants::Multiple { tag, tag_encoding: TagEncoding::Direct, tag_field, .. } => {
+            let reason = if repr.hide_niche() {
+                "niche filling is disabled by `#[repr(no_niche)]`"
+            } else if repr.c() {
+                "niche filling is disabled by `#[repr(C)]`"
+            } else if repr.int.is_some() {
+                "niche filling is disabled by the primitive representation"
+            } else if def
+                .variants()
+                .iter_enumerated()
+                .any(|(i, v)| v.discr != ty::VariantDiscr::Relative(i.as_u32()))
+            {
+                "niche filling is disabled by explicit discriminants"
+            } else {
+                "no variant has a niche that can encode the other variants, \
+                 or the niche filling layout is not smaller"
+            };
+            Some(format!(
+                "`{}` at offset {}, {reason}",
+                tag.primitive().to_int_ty(tcx),
+                layout.fields.offset(*tag_field).bytes(),
+            ))
+        }
+        Variants::Multiple {
+            tag,
+            tag_encoding: TagEncoding::Niche { untagged_variant, niche_variants, niche_start },
+            tag_field,
+            ..
+        } => {
+            let niche_variants = if niche_variants.start() == niche_variants.end() {
+                format!("`{}`", variant_name(*niche_variants.start()))
+            } else {
+                format!(
+                    "`{}` to `{}`",
+                    variant_name(*niche_variants.start()),
+                    variant_name(*niche_variants.end())
+                )
+            };
+            Some(format!(
+                "in the niche of `{}`, a `{}` at offset {} whose values from {niche_start} \
+                 encode {niche_variants}, as the niche filling layout is smaller than the \
+                 tagged one, or as small with a larger niche",
+                variant_name(*untagged_variant),
+                tag.primitive().to_int_ty(tcx),
+                layout.fields.offset(*tag_field).bytes(),
+            ))
+        }
+    };
+
+    let niche = match layout.largest_niche {
+        Some(niche) => format!(
+            "{} unused values of the `{}` at offset {}",
+            niche.available(&tcx),
+            niche.value.to_int_ty(tcx),
+            niche.offset.bytes()
+        ),
+        None if repr.hide_niche() => "none, hidden by `#[repr(no_niche)]`".to_string(),
+        None if def.is_unsafe_cell() => "none, hidden by `UnsafeCell`".to_string(),
+        None if def.is_union() => "none, unions do not expose niches".to_string(),
+        None => "none, no field or tag has unused values".to_string(),
+    };
+
+    let type_description = with_no_trimmed_paths!(format!("{}", layout.ty));
+    Some(NicheDecisionInfo { type_description, tag, niche })
 }
 
 // FIXME: Once the other errors that embed this error have been converted to translatable
@@ -77,7 +77,7 @@
 pub use self::binding::BindingMode::*;
 pub use self::closure::{
     is_ancestor_or_same_capture, place_to_string_for_capture, BorrowKind, CaptureInfo,
-    CapturedPlace, ClosureTypeInfo, MinCaptureInformationMap, MinCaptureList,
+    CapturedPlace, ClosureTypeInfo, InferredCapture, MinCaptureInformationMap, MinCaptureList,
     RootVariableMinCaptureList, UpvarCapture, UpvarId, UpvarPath, CAPTURE_STRUCT_LOCAL,
 };
 pub use self::consts::{
@@ -1504,6 +1504,7 @@ pub fn repr_options_of_def(self, did: DefId) -> ReprOptions {
                     }
                     attr::ReprTransparent => ReprFlags::IS_TRANSPARENT,
                     attr::ReprSimd => ReprFlags::IS_SIMD,
+                    attr::ReprNoNiche => ReprFlags::HIDE_NICHE,
                     attr::ReprInt(i) => {
                         size = Some(match i {
                             attr::IntType::SignedInt(x) => match x {
@@ -1782,13 +1783,23 @@ pub fn has_attr(self, did: impl Into<DefId>, attr: Symbol) -> bool {
         }
     }
 
+    /// Returns `true` if the impl is annotated with `#[diagnostic::do_not_recommend]`,
+    /// and so shouldn't be mentioned by the errors about obligations it gave rise to.
+    pub fn do_not_recommend_impl(self, impl_def_id: DefId) -> bool {
+        self.features().do_not_recommend
+            && self
+                .get_attrs_by_path(impl_def_id, &[sym::diagnostic, sym::do_not_recommend])
+                .next()
+                .is_some()
+    }
+
     /// Returns `true` if this is an `auto trait`.
     pub fn trait_is_auto(self, trait_def_id: DefId) -> bool {
         self.trait_def(trait_def_id).has_auto_impl
     }
 
-    /// Returns `true` if this is coinductive, either because it is
-    /// an auto trait or because it has the `#[rustc_coinductive]` attribute.
+    /// Returns `true` if this is coinductive, either because it is an auto
+    /// trait or because it has the `#[rustc_coinductive]` or `#[coinductive]` attribute.
     pub fn trait_is_coinductive(self, trait_def_id: DefId) -> bool {
         self.trait_def(trait_def_id).is_coinductive
     }
@@ -1992,6 +1992,22 @@ pub fn is_box(self) -> bool {
         }
     }
 
+    /// Tests whether this is a `Box` using the global allocator, i.e. `Box<T>` or
+    /// `Box<T, Global>`.
+    pub fn is_box_global(self, tcx: TyCtxt<'tcx>) -> bool {
+        match self.kind() {
+            Adt(def, args) if def.is_box() => {
+                let Some(alloc) = args.get(1) else {
+                    return true;
+                };
+                alloc.expect_ty().ty_adt_def().is_some_and(|alloc_adt| {
+                    Some(alloc_adt.did()) == tcx.lang_items().global_alloc_ty()
+                })
+            }
+            _ => false,
+        }
+    }
+
     /// Panics if called on any type other than `Box<T>`.
     pub fn boxed_ty(self) -> Ty<'tcx> {
         match self.kind() {
@@ -30,9 +30,9 @@ pub struct TraitDef {
     /// and thus `impl`s of it are allowed to overlap.
     pub is_marker: bool,
 
-    /// If `true`, then this trait has to `#[rustc_coinductive]` attribute or
-    /// is an auto trait. This indicates that trait solver cycles involving an
-    /// `X: ThisTrait` goal are accepted.
+    /// If `true`, then this trait has the `#[rustc_coinductive]` or the unstable
+    /// `#[coinductive]` attribute, or is an auto trait. This indicates that trait
+    /// solver cycles involving an `X: ThisTrait` goal are accepted.
     ///
     /// In the future all traits should be coinductive, but we need a better
     /// formal understanding of what exactly that means and should probably
@@ -61,6 +61,11 @@ pub struct TraitDef {
     /// This only applies to built-in traits, and is marked via
     /// `#[rustc_deny_explicit_impl(implement_via_object = ...)]`.
     pub deny_explicit_impl: bool,
+
+    /// If `true`, then the crate defining this trait enabled both `#![feature(negative_impls)]`
+    /// and `#![feature(negative_bounds_coherence)]`, so selection uses the negative impls
+    /// of this trait to rule out its positive impls, in every crate.
+    pub negative_bounds_coherence: bool,
 }
 
 /// Whether this trait is treated specially by the standard library
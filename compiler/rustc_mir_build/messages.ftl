@@ -6,6 +6,11 @@ mir_build_already_mut_borrowed = cannot borrow value as immutable because it is
 
 mir_build_assoc_const_in_pattern = associated consts cannot be referenced in patterns
 
+mir_build_become_in_closure_or_coroutine = `become` is not allowed in closures and coroutines
+
+mir_build_become_non_call = `become` requires a function call
+    .label = not a function call
+
 mir_build_bindings_with_variant_name =
     pattern binding `{$name}` is named the same as one of the variants of the type `{$ty_path}`
     .suggestion = to match on the variant, qualify the path
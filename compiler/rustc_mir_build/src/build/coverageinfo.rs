@@ -0,0 +1,377 @@
+use std::assert_matches::assert_matches;
+use std::collections::hash_map::Entry;
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_middle::mir::coverage::{
+    BlockMarkerId, BranchInfo, BranchSpan, ConditionId, ConditionInfo, CoverageKind,
+    MCDCBranchSpan, MCDCDecisionSpan,
+};
+use rustc_middle::mir::{self, BasicBlock, UnOp};
+use rustc_middle::thir::{ExprId, ExprKind, LogicalOp, Thir};
+use rustc_middle::ty::TyCtxt;
+use rustc_span::def_id::LocalDefId;
+use rustc_span::Span;
+
+use crate::build::Builder;
+
+/// The number of conditions LLVM supports in a single MC/DC decision.
+/// Decisions with more conditions are instrumented as plain branches.
+const MAX_CONDITIONS_NUM_IN_DECISION: usize = 6;
+
+pub(crate) struct BranchInfoBuilder {
+    /// Maps condition expressions to their enclosing `!`, for better instrumentation.
+    nots: FxHashMap<ExprId, NotInfo>,
+
+    num_block_markers: usize,
+    branch_spans: Vec<BranchSpan>,
+
+    mcdc_branch_spans: Vec<MCDCBranchSpan>,
+    mcdc_decision_spans: Vec<MCDCDecisionSpan>,
+    mcdc_state: Option<MCDCState>,
+}
+
+#[derive(Clone, Copy)]
+struct NotInfo {
+    /// When visiting the associated expression as a branch condition, treat this
+    /// enclosing `!` as the branch condition instead.
+    enclosing_not: ExprId,
+    /// True if the associated expression is nested within an odd number of `!`
+    /// expressions relative to `enclosing_not` (inclusive of `enclosing_not`).
+    is_flipped: bool,
+}
+
+impl BranchInfoBuilder {
+    /// Creates a new branch info builder, but only if branch coverage instrumentation
+    /// is enabled and `def_id` represents a function that is eligible for coverage.
+    pub(crate) fn new_if_enabled(tcx: TyCtxt<'_>, def_id: LocalDefId) -> Option<Self> {
+        if tcx.sess.instrument_coverage_branch() && tcx.is_eligible_for_coverage(def_id) {
+            Some(Self {
+                nots: FxHashMap::default(),
+                num_block_markers: 0,
+                branch_spans: vec![],
+                mcdc_branch_spans: vec![],
+                mcdc_decision_spans: vec![],
+                mcdc_state: MCDCState::new_if_enabled(tcx),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Unary `!` expressions inside an `if` condition are lowered by lowering
+    /// their argument instead, and then reversing the then/else arms of that `if`.
+    ///
+    /// That's awkward for branch coverage instrumentation, so to work around that
+    /// we pre-emptively visit any affected `!` expressions, and record extra
+    /// information that [`Builder::visit_coverage_branch_condition`] can use to
+    /// synthesize branch instrumentation for the enclosing `!`.
+    pub(crate) fn visit_unary_not(&mut self, thir: &Thir<'_>, unary_not: ExprId) {
+        assert_matches!(thir[unary_not].kind, ExprKind::Unary { op: UnOp::Not, .. });
+
+        self.visit_with_not_info(
+            thir,
+            unary_not,
+            // Set `is_flipped: false` for the `!` itself, so that its enclosed
+            // expression will have `is_flipped: true`.
+            NotInfo { enclosing_not: unary_not, is_flipped: false },
+        );
+    }
+
+    fn visit_with_not_info(&mut self, thir: &Thir<'_>, expr_id: ExprId, not_info: NotInfo) {
+        match self.nots.entry(expr_id) {
+            // This expression has already been marked by an enclosing `!`.
+            Entry::Occupied(_) => return,
+            Entry::Vacant(entry) => entry.insert(not_info),
+        };
+
+        match thir[expr_id].kind {
+            ExprKind::Unary { op: UnOp::Not, arg } => {
+                let not_info = NotInfo { is_flipped: !not_info.is_flipped, ..not_info };
+                self.visit_with_not_info(thir, arg, not_info);
+            }
+            ExprKind::Scope { value, .. } => self.visit_with_not_info(thir, value, not_info),
+            ExprKind::Use { source } => self.visit_with_not_info(thir, source, not_info),
+            // All other expressions (including `&&` and `||`) don't need any
+            // special handling of their children, so stop visiting.
+            _ => {}
+        }
+    }
+
+    fn next_block_marker_id(&mut self) -> BlockMarkerId {
+        let id = BlockMarkerId::from_usize(self.num_block_markers);
+        self.num_block_markers += 1;
+        id
+    }
+
+    fn record_branch(
+        &mut self,
+        span: Span,
+        true_marker: BlockMarkerId,
+        false_marker: BlockMarkerId,
+    ) {
+        let condition_info = self
+            .mcdc_state
+            .as_mut()
+            .and_then(|mcdc_state| mcdc_state.take_condition(true_marker, false_marker));
+        let Some(condition_info) = condition_info else {
+            self.branch_spans.push(BranchSpan { span, true_marker, false_marker });
+            return;
+        };
+
+        self.mcdc_branch_spans.push(MCDCBranchSpan {
+            span,
+            condition_info,
+            true_marker,
+            false_marker,
+        });
+
+        let mcdc_state = self.mcdc_state.as_mut().unwrap();
+        if let Some(decision) = mcdc_state.take_finished_decision() {
+            if decision.conditions_num <= MAX_CONDITIONS_NUM_IN_DECISION {
+                self.mcdc_decision_spans.push(decision);
+            } else {
+                let first_branch_idx = mcdc_state.first_branch_idx;
+                self.demote_mcdc_branches(first_branch_idx);
+            }
+        }
+    }
+
+    /// Turns the MC/DC branches recorded since `first_branch_idx` back into plain
+    /// branches, for decisions that cannot be instrumented with MC/DC.
+    fn demote_mcdc_branches(&mut self, first_branch_idx: usize) {
+        let demoted = self.mcdc_branch_spans.drain(first_branch_idx..).map(
+            |MCDCBranchSpan { span, true_marker, false_marker, .. }| BranchSpan {
+                span,
+                true_marker,
+                false_marker,
+            },
+        );
+        self.branch_spans.extend(demoted);
+    }
+
+    fn enter_decision_context(&mut self) {
+        let Some(mcdc_state) = self.mcdc_state.as_mut() else { return };
+        mcdc_state.decision_depth += 1;
+        if mcdc_state.decision_depth == 1 {
+            mcdc_state.first_branch_idx = self.mcdc_branch_spans.len();
+        }
+    }
+
+    fn exit_decision_context(&mut self) {
+        let Some(mcdc_state) = self.mcdc_state.as_mut() else { return };
+        if mcdc_state.decision_depth == 1 && mcdc_state.processing_decision.take().is_some() {
+            // Some conditions of the decision were never evaluated as branches,
+            // e.g. because they are `let` expressions of a let-chain.
+            mcdc_state.condition_stack.clear();
+            let first_branch_idx = mcdc_state.first_branch_idx;
+            self.demote_mcdc_branches(first_branch_idx);
+        }
+        self.mcdc_state.as_mut().unwrap().decision_depth -= 1;
+    }
+
+    pub(crate) fn into_done(self) -> Option<Box<BranchInfo>> {
+        let Self {
+            nots: _,
+            num_block_markers,
+            branch_spans,
+            mcdc_branch_spans,
+            mcdc_decision_spans,
+            mcdc_state: _,
+        } = self;
+
+        if num_block_markers == 0 {
+            assert!(branch_spans.is_empty() && mcdc_branch_spans.is_empty());
+            return None;
+        }
+
+        Some(Box::new(BranchInfo {
+            num_block_markers,
+            branch_spans,
+            mcdc_branch_spans,
+            mcdc_decision_spans,
+        }))
+    }
+}
+
+/// Assigns MC/DC condition IDs to the conditions of the decision being lowered.
+///
+/// The condition bitmap updated by the instrumentation is shared by the whole
+/// function, so only decisions that are not nested within the condition of
+/// another decision are instrumented.
+struct MCDCState {
+    /// Number of `then_else_break` calls currently being lowered.
+    decision_depth: usize,
+    /// Conditions of the current decision that have not been lowered yet, with
+    /// the next one on top.
+    condition_stack: Vec<ConditionInfo>,
+    processing_decision: Option<MCDCDecisionSpan>,
+    /// Index of the first MC/DC branch span of the current decision.
+    first_branch_idx: usize,
+}
+
+impl MCDCState {
+    fn new_if_enabled(tcx: TyCtxt<'_>) -> Option<Self> {
+        tcx.sess.instrument_coverage_mcdc().then(|| Self {
+            decision_depth: 0,
+            condition_stack: vec![],
+            processing_decision: None,
+            first_branch_idx: 0,
+        })
+    }
+
+    /// Splits the condition on top of the stack into the two operands of `op`.
+    ///
+    /// Conditions are lowered in pre-order, so the condition info of an operator
+    /// is always known by the time its operands are visited:
+    /// - For `&&`, both operands are false-linked to the parent's `false_next_id`;
+    ///   the LHS is true-linked to the RHS, and the RHS to the parent's `true_next_id`.
+    /// - For `||`, both operands are true-linked to the parent's `true_next_id`;
+    ///   the LHS is false-linked to the RHS, and the RHS to the parent's `false_next_id`.
+    ///
+    /// The LHS keeps the ID of its parent, and the RHS gets a new one. For
+    /// example, `(A && B) || C` gets the IDs `A = 1`, `B = 3` and `C = 2`.
+    fn record_conditions(&mut self, op: LogicalOp, span: Span, is_flipped: bool) {
+        if self.decision_depth != 1 {
+            return;
+        }
+
+        let decision = self.processing_decision.get_or_insert_with(|| MCDCDecisionSpan {
+            span,
+            conditions_num: 0,
+            end_markers: vec![],
+        });
+
+        let mut parent_condition = self.condition_stack.pop().unwrap_or_default();
+        if is_flipped {
+            // The operation is the argument of a `!`, so its value leads to the
+            // opposite successors of the parent condition.
+            std::mem::swap(&mut parent_condition.true_next_id, &mut parent_condition.false_next_id);
+        }
+
+        let lhs_id = if parent_condition.condition_id == ConditionId::NONE {
+            decision.conditions_num += 1;
+            ConditionId::from_usize(decision.conditions_num)
+        } else {
+            parent_condition.condition_id
+        };
+        decision.conditions_num += 1;
+        let rhs_id = ConditionId::from_usize(decision.conditions_num);
+
+        let (lhs, rhs) = match op {
+            LogicalOp::And => (
+                ConditionInfo {
+                    condition_id: lhs_id,
+                    true_next_id: rhs_id,
+                    false_next_id: parent_condition.false_next_id,
+                },
+                ConditionInfo { condition_id: rhs_id, ..parent_condition },
+            ),
+            LogicalOp::Or => (
+                ConditionInfo {
+                    condition_id: lhs_id,
+                    true_next_id: parent_condition.true_next_id,
+                    false_next_id: rhs_id,
+                },
+                ConditionInfo { condition_id: rhs_id, ..parent_condition },
+            ),
+        };
+
+        // The LHS is lowered first, so it goes on top.
+        self.condition_stack.push(rhs);
+        self.condition_stack.push(lhs);
+    }
+
+    fn take_condition(
+        &mut self,
+        true_marker: BlockMarkerId,
+        false_marker: BlockMarkerId,
+    ) -> Option<ConditionInfo> {
+        if self.decision_depth != 1 {
+            return None;
+        }
+        let condition_info = self.condition_stack.pop()?;
+        let decision = self.processing_decision.as_mut().expect("conditions without a decision");
+
+        // The decision has been evaluated once a condition has no successor.
+        if condition_info.true_next_id == ConditionId::NONE {
+            decision.end_markers.push(true_marker);
+        }
+        if condition_info.false_next_id == ConditionId::NONE {
+            decision.end_markers.push(false_marker);
+        }
+
+        Some(condition_info)
+    }
+
+    fn take_finished_decision(&mut self) -> Option<MCDCDecisionSpan> {
+        if self.condition_stack.is_empty() { self.processing_decision.take() } else { None }
+    }
+}
+
+impl Builder<'_, '_> {
+    /// If branch coverage is enabled, inject marker statements into `then_block`
+    /// and `else_block`, and record their IDs in the table of branch spans.
+    pub(crate) fn visit_coverage_branch_condition(
+        &mut self,
+        mut expr_id: ExprId,
+        mut then_block: BasicBlock,
+        mut else_block: BasicBlock,
+    ) {
+        // Bail out if branch coverage is not enabled for this function.
+        let Some(branch_info) = self.coverage_branch_info.as_ref() else { return };
+
+        // If this condition expression is nested within one or more `!` expressions,
+        // replace it with the enclosing `!` collected by `visit_unary_not`.
+        if let Some(&NotInfo { enclosing_not, is_flipped }) = branch_info.nots.get(&expr_id) {
+            expr_id = enclosing_not;
+            if is_flipped {
+                std::mem::swap(&mut then_block, &mut else_block);
+            }
+        }
+        let source_info = self.source_info(self.thir[expr_id].span);
+
+        // Now that we have `source_info`, we can upgrade to a &mut reference.
+        let branch_info = self.coverage_branch_info.as_mut().expect("upgrading & to &mut");
+
+        let mut inject_branch_marker = |block: BasicBlock| {
+            let id = branch_info.next_block_marker_id();
+
+            let marker_statement = mir::Statement {
+                source_info,
+                kind: mir::StatementKind::Coverage(Box::new(mir::Coverage {
+                    kind: CoverageKind::BlockMarker { id },
+                })),
+            };
+            self.cfg.push(block, marker_statement);
+
+            id
+        };
+
+        let true_marker = inject_branch_marker(then_block);
+        let false_marker = inject_branch_marker(else_block);
+
+        branch_info.record_branch(source_info.span, true_marker, false_marker);
+    }
+
+    /// If MC/DC coverage is enabled, assigns condition IDs to the operands of
+    /// the `&&` or `||` expression `expr_id`, which is part of a decision.
+    pub(crate) fn visit_coverage_branch_operation(&mut self, expr_id: ExprId, op: LogicalOp) {
+        let Some(branch_info) = self.coverage_branch_info.as_mut() else { return };
+        let Some(mcdc_state) = branch_info.mcdc_state.as_mut() else { return };
+
+        let is_flipped = branch_info.nots.get(&expr_id).is_some_and(|not_info| not_info.is_flipped);
+        mcdc_state.record_conditions(op, self.thir[expr_id].span, is_flipped);
+    }
+
+    pub(crate) fn enter_coverage_decision_context(&mut self) {
+        if let Some(branch_info) = self.coverage_branch_info.as_mut() {
+            branch_info.enter_decision_context();
+        }
+    }
+
+    pub(crate) fn exit_coverage_decision_context(&mut self) {
+        if let Some(branch_info) = self.coverage_branch_info.as_mut() {
+            branch_info.exit_decision_context();
+        }
+    }
+}
@@ -1,7 +1,8 @@
+use rustc_ast::InlineAsmOptions;
 use rustc_middle::mir::interpret::Scalar;
 use rustc_middle::mir::tcx::PlaceTy;
 use rustc_middle::ty::cast::mir_cast_kind;
-use rustc_middle::{mir::*, thir::*, ty};
+use rustc_middle::{mir, mir::*, thir, thir::*, ty};
 use rustc_span::source_map::Spanned;
 use rustc_span::Span;
 use rustc_target::abi::{FieldIdx, VariantIdx};
@@ -27,6 +28,20 @@ pub fn parse_statement(&self, expr_id: ExprId) -> PResult<StatementKind<'tcx>> {
             @call(mir_deinit, args) => {
                 Ok(StatementKind::Deinit(Box::new(self.parse_place(args[0])?)))
             },
+            @call(mir_nop, _args) => {
+                Ok(StatementKind::Nop)
+            },
+            @call(mir_place_mention, args) => {
+                Ok(StatementKind::PlaceMention(Box::new(self.parse_place(args[0])?)))
+            },
+            @call(mir_copy_nonoverlapping, args) => {
+                let copy = CopyNonOverlapping {
+                    src: self.parse_operand(args[0])?,
+                    dst: self.parse_operand(args[1])?,
+                    count: self.parse_operand(args[2])?,
+                };
+                Ok(StatementKind::Intrinsic(Box::new(NonDivergingIntrinsic::CopyNonOverlapping(copy))))
+            },
             @call(mir_retag, args) => {
                 Ok(StatementKind::Retag(RetagKind::Default, Box::new(self.parse_place(args[0])?)))
             },
@@ -74,6 +89,9 @@ pub fn parse_terminator(&self, expr_id: ExprId) -> PResult<TerminatorKind<'tcx>>
             @call(mir_call, args) => {
                 self.parse_call(args)
             },
+            @call(mir_inline_asm, args) => {
+                self.parse_inline_asm(args)
+            },
             ExprKind::Match { scrutinee, arms, .. } => {
                 let discr = self.parse_operand(*scrutinee)?;
                 self.parse_match(arms, expr.span).map(|t| TerminatorKind::SwitchInt { discr, targets: t })
@@ -186,6 +204,74 @@ fn parse_call(&self, args: &[ExprId]) -> PResult<TerminatorKind<'tcx>> {
         )
     }
 
+    fn parse_inline_asm(&self, args: &[ExprId]) -> PResult<TerminatorKind<'tcx>> {
+        let target = self.parse_return_to(args[1])?;
+        let unwind = self.parse_unwind_action(args[2])?;
+
+        parse_by_kind!(self, args[0], _, "inline assembly",
+            ExprKind::InlineAsm(box InlineAsmExpr { template, operands, options, line_spans }) => {
+                let operands = operands
+                    .iter()
+                    .map(|op| self.parse_inline_asm_operand(op))
+                    .collect::<PResult<Vec<_>>>()?;
+                Ok(TerminatorKind::InlineAsm {
+                    template: *template,
+                    operands,
+                    options: *options,
+                    line_spans: *line_spans,
+                    destination: if options.contains(InlineAsmOptions::NORETURN) {
+                        None
+                    } else {
+                        Some(target)
+                    },
+                    unwind,
+                })
+            },
+        )
+    }
+
+    fn parse_inline_asm_operand(
+        &self,
+        op: &thir::InlineAsmOperand<'tcx>,
+    ) -> PResult<mir::InlineAsmOperand<'tcx>> {
+        Ok(match *op {
+            thir::InlineAsmOperand::In { reg, expr } => {
+                mir::InlineAsmOperand::In { reg, value: self.parse_operand(expr)? }
+            }
+            thir::InlineAsmOperand::Out { reg, late, expr } => mir::InlineAsmOperand::Out {
+                reg,
+                late,
+                place: expr.map(|expr| self.parse_place(expr)).transpose()?,
+            },
+            thir::InlineAsmOperand::InOut { reg, late, expr } => {
+                let place = self.parse_place(expr)?;
+                mir::InlineAsmOperand::InOut {
+                    reg,
+                    late,
+                    in_value: Operand::Copy(place),
+                    out_place: Some(place),
+                }
+            }
+            thir::InlineAsmOperand::SplitInOut { reg, late, in_expr, out_expr } => {
+                mir::InlineAsmOperand::InOut {
+                    reg,
+                    late,
+                    in_value: self.parse_operand(in_expr)?,
+                    out_place: out_expr.map(|expr| self.parse_place(expr)).transpose()?,
+                }
+            }
+            thir::InlineAsmOperand::Const { value, span } => mir::InlineAsmOperand::Const {
+                value: Box::new(ConstOperand { span, user_ty: None, const_: value }),
+            },
+            thir::InlineAsmOperand::SymFn { value, span } => mir::InlineAsmOperand::SymFn {
+                value: Box::new(ConstOperand { span, user_ty: None, const_: value }),
+            },
+            thir::InlineAsmOperand::SymStatic { def_id } => {
+                mir::InlineAsmOperand::SymStatic { def_id }
+            }
+        })
+    }
+
     fn parse_rvalue(&self, expr_id: ExprId) -> PResult<Rvalue<'tcx>> {
         parse_by_kind!(self, expr_id, expr, "rvalue",
             @call(mir_discriminant, args) => self.parse_place(args[0]).map(Rvalue::Discriminant),
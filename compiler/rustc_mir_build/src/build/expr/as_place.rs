@@ -3,6 +3,8 @@
 use crate::build::expr::category::Category;
 use crate::build::ForGuard::{OutsideGuard, RefWithinGuard};
 use crate::build::{BlockAnd, BlockAndExtension, Builder, Capture, CaptureMap};
+use rustc_ast::LitKind;
+use rustc_data_structures::packed::Pu128;
 use rustc_hir::def_id::LocalDefId;
 use rustc_middle::hir::place::Projection as HirProjection;
 use rustc_middle::hir::place::ProjectionKind as HirProjectionKind;
@@ -76,9 +78,9 @@ pub(in crate::build) struct PlaceBuilder<'tcx> {
 /// Given a list of MIR projections, convert them to list of HIR ProjectionKind.
 /// The projections are truncated to represent a path that might be captured by a
 /// closure/coroutine. This implies the vector returned from this function doesn't contain
-/// ProjectionElems `Downcast`, `ConstantIndex`, `Index`, or `Subslice` because those will never be
-/// part of a path that is captured by a closure. We stop applying projections once we see the first
-/// projection that isn't captured by a closure.
+/// ProjectionElems `Downcast`, `Index`, `Subslice` or `ConstantIndex` counting from the end,
+/// because those will never be part of a path that is captured by a closure. We stop applying
+/// projections once we see the first projection that isn't captured by a closure.
 fn convert_to_hir_projections_and_truncate_for_capture(
     mir_projections: &[PlaceElem<'_>],
 ) -> Vec<HirProjectionKind> {
@@ -103,6 +105,11 @@ fn convert_to_hir_projections_and_truncate_for_capture(
             }
             // These do not affect anything, they just make sure we know the right type.
             ProjectionElem::OpaqueCast(_) | ProjectionElem::Subtype(..) => continue,
+            // Only produced for array elements indexed by an integer literal, which may be
+            // captured separately when `capture_disjoint_array_elements` is enabled.
+            ProjectionElem::ConstantIndex { offset, from_end: false, .. } => {
+                HirProjectionKind::ConstantIndex(*offset)
+            }
             ProjectionElem::Index(..)
             | ProjectionElem::ConstantIndex { .. }
             | ProjectionElem::Subslice { .. } => {
@@ -239,6 +246,9 @@ fn strip_prefix<'a, 'tcx>(
             HirProjectionKind::OpaqueCast => {
                 assert_matches!(iter.next(), Some(ProjectionElem::OpaqueCast(..)));
             }
+            HirProjectionKind::ConstantIndex(..) => {
+                assert_matches!(iter.next(), Some(ProjectionElem::ConstantIndex { .. }));
+            }
             HirProjectionKind::Index | HirProjectionKind::Subslice => {
                 bug!("unexpected projection kind: {:?}", projection);
             }
@@ -601,6 +611,18 @@ fn lower_index_expression(
         let base_place =
             unpack!(block = self.expr_as_place(block, base, mutability, Some(fake_borrow_temps),));
 
+        // Indexing into a captured array with an integer literal must be lowered the same way
+        // as it was categorized in upvar analysis, which may have captured only that element.
+        // The same goes for the captured places themselves when the closure is created.
+        // The index is known to be in bounds, so no bounds check is needed.
+        if let Some((offset, min_length)) = self.constant_array_index(&base_place, base, index) {
+            return block.and(base_place.project(ProjectionElem::ConstantIndex {
+                offset,
+                min_length,
+                from_end: false,
+            }));
+        }
+
         // Making this a *fresh* temporary means we do not have to worry about
         // the index changing later: Nothing will ever change this temporary.
         // The "retagging" transformation (for Stacked Borrows) relies on this.
@@ -623,6 +645,40 @@ fn lower_index_expression(
         block.and(base_place.index(idx))
     }
 
+    /// Returns the offset and the array length if `base` is an array and `index` is an integer
+    /// literal that is in bounds, mirroring how such accesses are categorized in upvar analysis.
+    ///
+    /// This only applies to places within a closure that start off a captured upvar, and to the
+    /// captured places built when the closure is created, which use a `NonHirLiteral` index.
+    fn constant_array_index(
+        &self,
+        base_place: &PlaceBuilder<'tcx>,
+        base: ExprId,
+        index: ExprId,
+    ) -> Option<(u64, u64)> {
+        if !self.tcx.features().capture_disjoint_array_elements {
+            return None;
+        }
+        let ty::Array(_, len) = self.thir[base].ty.kind() else { return None };
+        let len = len.try_eval_target_usize(self.tcx, self.param_env)?;
+
+        let mut index = &self.thir[index];
+        while let ExprKind::Scope { value, .. } = index.kind {
+            index = &self.thir[value];
+        }
+        let offset = match index.kind {
+            ExprKind::Literal { lit, neg: false }
+                if let PlaceBase::Upvar { .. } = base_place.base() =>
+            {
+                let LitKind::Int(Pu128(offset), _) = lit.node else { return None };
+                u64::try_from(offset).ok()?
+            }
+            ExprKind::NonHirLiteral { lit, .. } => lit.try_to_target_usize(self.tcx).ok()?,
+            _ => return None,
+        };
+        (offset < len).then_some((offset, len))
+    }
+
     fn bounds_check(
         &mut self,
         block: BasicBlock,
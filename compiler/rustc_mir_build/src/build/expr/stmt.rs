@@ -1,8 +1,10 @@
 use crate::build::scope::BreakableTarget;
 use crate::build::{BlockAnd, BlockAndExtension, BlockFrame, Builder};
+use crate::errors;
 use rustc_middle::middle::region;
 use rustc_middle::mir::*;
 use rustc_middle::thir::*;
+use rustc_span::source_map::Spanned;
 
 impl<'a, 'tcx> Builder<'a, 'tcx> {
     /// Builds a block of MIR statements to evaluate the THIR `expr`.
@@ -90,10 +92,7 @@ pub(crate) fn stmt_expr(
             ExprKind::Return { value } => {
                 this.break_scope(block, value, BreakableTarget::Return, source_info)
             }
-            // FIXME(explicit_tail_calls): properly lower tail calls here
-            ExprKind::Become { value } => {
-                this.break_scope(block, Some(value), BreakableTarget::Return, source_info)
-            }
+            ExprKind::Become { value } => this.become_tail_call(block, value, source_info),
             _ => {
                 assert!(
                     statement_scope.is_some(),
@@ -146,4 +145,56 @@ pub(crate) fn stmt_expr(
             }
         }
     }
+
+    /// Lowers `become value`, where `value` must be a function call, to a `TailCall` terminator.
+    ///
+    /// If `value` can't be tail called, an error is emitted and it is lowered like `return`.
+    fn become_tail_call(
+        &mut self,
+        block: BasicBlock,
+        value: ExprId,
+        source_info: SourceInfo,
+    ) -> BlockAnd<()> {
+        let thir = self.thir;
+        let value_expr = &thir[value];
+        let ExprKind::Scope { region_scope, lint_level, value: call } = value_expr.kind else {
+            span_bug!(value_expr.span, "`become` of an expression without a scope");
+        };
+        let call_expr = &thir[call];
+        let ExprKind::Call { fun, ref args, from_hir_call: true, fn_span, .. } = call_expr.kind
+        else {
+            self.tcx.dcx().emit_err(errors::BecomeNonCall { span: call_expr.span });
+            return self.break_scope(block, Some(value), BreakableTarget::Return, source_info);
+        };
+        if self.tcx.is_closure_like(self.def_id.to_def_id()) {
+            self.tcx.dcx().emit_err(errors::BecomeInClosureOrCoroutine { span: source_info.span });
+            return self.break_scope(block, Some(value), BreakableTarget::Return, source_info);
+        }
+
+        let value_source_info = self.source_info(value_expr.span);
+        self.in_scope((region_scope, value_source_info), lint_level, |this| {
+            let mut block = block;
+            let func = unpack!(block = this.as_local_operand(block, fun));
+            let args: Vec<_> = args
+                .iter()
+                .map(|&arg| Spanned {
+                    node: unpack!(block = this.as_local_call_operand(block, arg)),
+                    span: thir[arg].span,
+                })
+                .collect();
+
+            this.record_operands_moved(&args);
+
+            debug!("become_tail_call: fn_span={:?}", fn_span);
+
+            unpack!(block = this.break_for_tail_call(block, &args, source_info));
+            this.cfg.terminate(
+                block,
+                source_info,
+                TerminatorKind::TailCall { func, args, fn_span },
+            );
+
+            this.cfg.start_new_block().unit()
+        })
+    }
 }
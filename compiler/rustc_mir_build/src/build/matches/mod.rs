@@ -40,6 +40,28 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
     /// expressions will not be declared. This is for if let guards on arms with
     /// an or pattern, where the guard is lowered multiple times.
     pub(crate) fn then_else_break(
+        &mut self,
+        block: BasicBlock,
+        expr_id: ExprId,
+        temp_scope_override: Option<region::Scope>,
+        break_scope: region::Scope,
+        variable_source_info: SourceInfo,
+        declare_bindings: bool,
+    ) -> BlockAnd<()> {
+        self.enter_coverage_decision_context();
+        let then_block = self.then_else_break_inner(
+            block,
+            expr_id,
+            temp_scope_override,
+            break_scope,
+            variable_source_info,
+            declare_bindings,
+        );
+        self.exit_coverage_decision_context();
+        then_block
+    }
+
+    fn then_else_break_inner(
         &mut self,
         mut block: BasicBlock,
         expr_id: ExprId,
@@ -54,7 +76,8 @@ pub(crate) fn then_else_break(
 
         match expr.kind {
             ExprKind::LogicalOp { op: LogicalOp::And, lhs, rhs } => {
-                let lhs_then_block = unpack!(this.then_else_break(
+                this.visit_coverage_branch_operation(expr_id, LogicalOp::And);
+                let lhs_then_block = unpack!(this.then_else_break_inner(
                     block,
                     lhs,
                     temp_scope_override,
@@ -63,7 +86,7 @@ pub(crate) fn then_else_break(
                     declare_bindings,
                 ));
 
-                let rhs_then_block = unpack!(this.then_else_break(
+                let rhs_then_block = unpack!(this.then_else_break_inner(
                     lhs_then_block,
                     rhs,
                     temp_scope_override,
@@ -75,10 +98,11 @@ pub(crate) fn then_else_break(
                 rhs_then_block.unit()
             }
             ExprKind::LogicalOp { op: LogicalOp::Or, lhs, rhs } => {
+                this.visit_coverage_branch_operation(expr_id, LogicalOp::Or);
                 let local_scope = this.local_scope();
                 let (lhs_success_block, failure_block) =
                     this.in_if_then_scope(local_scope, expr_span, |this| {
-                        this.then_else_break(
+                        this.then_else_break_inner(
                             block,
                             lhs,
                             temp_scope_override,
@@ -87,7 +111,7 @@ pub(crate) fn then_else_break(
                             true,
                         )
                     });
-                let rhs_success_block = unpack!(this.then_else_break(
+                let rhs_success_block = unpack!(this.then_else_break_inner(
                     failure_block,
                     rhs,
                     temp_scope_override,
@@ -99,6 +123,13 @@ pub(crate) fn then_else_break(
                 rhs_success_block.unit()
             }
             ExprKind::Unary { op: UnOp::Not, arg } => {
+                // Improve branch coverage instrumentation by noting conditions
+                // nested within one or more `!` expressions.
+                // (Skipped if branch coverage is not enabled.)
+                if let Some(branch_info) = this.coverage_branch_info.as_mut() {
+                    branch_info.visit_unary_not(this.thir, expr_id);
+                }
+
                 let local_scope = this.local_scope();
                 let (success_block, failure_block) =
                     this.in_if_then_scope(local_scope, expr_span, |this| {
@@ -107,7 +138,7 @@ pub(crate) fn then_else_break(
                         if this.tcx.sess.instrument_coverage() {
                             this.cfg.push_coverage_span_marker(block, this.source_info(expr_span));
                         }
-                        this.then_else_break(
+                        this.then_else_break_inner(
                             block,
                             arg,
                             temp_scope_override,
@@ -122,7 +153,7 @@ pub(crate) fn then_else_break(
             ExprKind::Scope { region_scope, lint_level, value } => {
                 let region_scope = (region_scope, this.source_info(expr_span));
                 this.in_scope(region_scope, lint_level, |this| {
-                    this.then_else_break(
+                    this.then_else_break_inner(
                         block,
                         value,
                         temp_scope_override,
@@ -132,7 +163,7 @@ pub(crate) fn then_else_break(
                     )
                 })
             }
-            ExprKind::Use { source } => this.then_else_break(
+            ExprKind::Use { source } => this.then_else_break_inner(
                 block,
                 source,
                 temp_scope_override,
@@ -160,6 +191,10 @@ pub(crate) fn then_else_break(
                 let else_block = this.cfg.start_new_block();
                 let term = TerminatorKind::if_(operand, then_block, else_block);
 
+                // Record branch coverage info for this condition.
+                // (Does nothing if branch coverage is not enabled.)
+                this.visit_coverage_branch_condition(expr_id, then_block, else_block);
+
                 let source_info = this.source_info(expr_span);
                 this.cfg.terminate(block, source_info, term);
                 this.break_for_else(else_block, break_scope, source_info);
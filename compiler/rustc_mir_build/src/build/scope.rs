@@ -713,6 +713,88 @@ pub(crate) fn break_for_else(
         self.cfg.terminate(block, source_info, TerminatorKind::UnwindResume);
     }
 
+    /// Sets up the drops for a tail call from `block`, with the arguments `args`.
+    ///
+    /// Unlike other early exits, a tail call doesn't go through the drop trees: the callee
+    /// replaces the current function, so everything it owns has to be dropped in `block`,
+    /// before the call. The innermost scope only holds the arguments of the call, which are
+    /// passed on to the callee, so it is skipped. If one of the drops panics, the arguments
+    /// are dropped on the unwind path.
+    pub(crate) fn break_for_tail_call(
+        &mut self,
+        mut block: BasicBlock,
+        args: &[Spanned<Operand<'tcx>>],
+        source_info: SourceInfo,
+    ) -> BlockAnd<()> {
+        let arg_drops: Vec<_> = args
+            .iter()
+            .rev()
+            .filter_map(|arg| match arg.node {
+                Operand::Copy(_) => span_bug!(arg.span, "copy operand in tail call arguments"),
+                Operand::Move(place) => {
+                    let local = place.as_local().unwrap_or_else(|| {
+                        span_bug!(arg.span, "projection in tail call arguments")
+                    });
+                    Some(DropData { source_info, local, kind: DropKind::Value })
+                }
+                Operand::Constant(_) => None,
+            })
+            .collect();
+
+        let Some(outer_scope) = self.scopes.scopes.iter().rev().nth(1).map(|s| s.region_scope)
+        else {
+            span_bug!(source_info.span, "tail call outside of a function body");
+        };
+        let mut unwind_to = self.diverge_cleanup_target(outer_scope, DUMMY_SP);
+        let unwind_drops = &mut self.scopes.unwind_drops;
+
+        // The outermost scope is the call site scope of the function, which doesn't drop
+        // anything.
+        for scope in self.scopes.scopes[1..].iter().rev().skip(1) {
+            for drop_data in scope.drops.iter().rev() {
+                let source_info = drop_data.source_info;
+                let local = drop_data.local;
+
+                match drop_data.kind {
+                    DropKind::Value => {
+                        // See `build_scope_drops`.
+                        debug_assert_eq!(unwind_drops.drops[unwind_to].0.local, drop_data.local);
+                        debug_assert_eq!(unwind_drops.drops[unwind_to].0.kind, drop_data.kind);
+                        unwind_to = unwind_drops.drops[unwind_to].1;
+
+                        let unwind_entry = arg_drops.iter().fold(unwind_to, |drop_idx, &drop| {
+                            unwind_drops.add_drop(drop, drop_idx)
+                        });
+                        unwind_drops.add_entry(block, unwind_entry);
+
+                        let next = self.cfg.start_new_block();
+                        self.cfg.terminate(
+                            block,
+                            source_info,
+                            TerminatorKind::Drop {
+                                place: local.into(),
+                                target: next,
+                                unwind: UnwindAction::Continue,
+                                replace: false,
+                            },
+                        );
+                        block = next;
+                    }
+                    DropKind::Storage => {
+                        // Only temps and vars need their storage dead.
+                        assert!(local.index() > self.arg_count);
+                        self.cfg.push(
+                            block,
+                            Statement { source_info, kind: StatementKind::StorageDead(local) },
+                        );
+                    }
+                }
+            }
+        }
+
+        block.unit()
+    }
+
     fn leave_top_scope(&mut self, block: BasicBlock) -> BasicBlock {
         // If we are emitting a `drop` statement, we need to have the cached
         // diverge cleanup pads ready in case that drop panics.
@@ -1492,6 +1574,7 @@ fn add_entry(cfg: &mut CFG<'tcx>, from: BasicBlock, to: BasicBlock) {
             | TerminatorKind::UnwindResume
             | TerminatorKind::UnwindTerminate(_)
             | TerminatorKind::Return
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::Unreachable
             | TerminatorKind::Yield { .. }
             | TerminatorKind::CoroutineDrop
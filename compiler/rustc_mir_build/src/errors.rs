@@ -941,3 +941,18 @@ pub enum RustcBoxAttrReason {
     #[note(mir_build_missing_box)]
     MissingBox,
 }
+
+#[derive(Diagnostic)]
+#[diag(mir_build_become_non_call)]
+pub struct BecomeNonCall {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(mir_build_become_in_closure_or_coroutine)]
+pub struct BecomeInClosureOrCoroutine {
+    #[primary_span]
+    pub span: Span,
+}
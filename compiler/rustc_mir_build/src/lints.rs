@@ -122,7 +122,9 @@ fn is_recursive_terminator(
         body: &Body<'tcx>,
         terminator: &Terminator<'tcx>,
     ) -> bool {
-        let TerminatorKind::Call { func, args, .. } = &terminator.kind else {
+        let (TerminatorKind::Call { func, args, .. } | TerminatorKind::TailCall { func, args, .. }) =
+            &terminator.kind
+        else {
             return false;
         };
 
@@ -199,6 +201,16 @@ fn node_examined(
             | TerminatorKind::Unreachable
             | TerminatorKind::Yield { .. } => ControlFlow::Break(NonRecursive),
 
+            // A tail call returns control flow to the caller, unless it is a recursive one.
+            TerminatorKind::TailCall { .. } => {
+                let terminator = self.body[bb].terminator();
+                if self.classifier.is_recursive_terminator(self.tcx, self.body, terminator) {
+                    ControlFlow::Continue(())
+                } else {
+                    ControlFlow::Break(NonRecursive)
+                }
+            }
+
             // A diverging InlineAsm is treated as non-recursing
             TerminatorKind::InlineAsm { destination, .. } => {
                 if destination.is_some() {
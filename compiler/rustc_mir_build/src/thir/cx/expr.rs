@@ -1008,6 +1008,12 @@ fn overloaded_place(
         args: Box<[ExprId]>,
         span: Span,
     ) -> ExprKind<'tcx> {
+        if let [receiver] = *args
+            && let Some(place) = self.pure_deref_place(receiver, place_ty)
+        {
+            return place;
+        }
+
         // For an overloaded *x or x[y] expression of type T, the method
         // call returns an &T and we must add the deref so that the types
         // line up (this is because `*x` and `x[y]` represent places):
@@ -1038,6 +1044,65 @@ fn overloaded_place(
         ExprKind::Deref { arg: ref_expr }
     }
 
+    /// Lowers the overloaded deref of a `#[rustc_pure_deref]` smart pointer, given the borrow
+    /// of the smart pointer passed to `Deref::deref` or `DerefMut::deref_mut`, as a built-in
+    /// deref of the reference or `Box` it wraps. Borrowck then sees a reborrow of that field
+    /// instead of a call borrowing the whole smart pointer, so that e.g. disjoint fields of the
+    /// target can be borrowed mutably at the same time.
+    ///
+    /// The attribute is a promise that the impls do nothing more than this.
+    fn pure_deref_place(&mut self, receiver: ExprId, place_ty: Ty<'tcx>) -> Option<ExprKind<'tcx>> {
+        // Keep the scope of the receiver expression, if there is one, around the smart pointer.
+        let (scope, borrow) = match self.thir[receiver].kind {
+            ExprKind::Scope { region_scope, lint_level, value } => {
+                (Some((region_scope, lint_level)), value)
+            }
+            _ => (None, receiver),
+        };
+        let ExprKind::Borrow { borrow_kind, arg } = self.thir[borrow].kind else {
+            return None;
+        };
+        let ty::Adt(adt_def, args) = *self.thir[arg].ty.kind() else {
+            return None;
+        };
+        if !adt_def.is_struct() || !self.tcx.has_attr(adt_def.did(), sym::rustc_pure_deref) {
+            return None;
+        }
+
+        let mutbl = borrow_kind.to_mutbl_lossy();
+        let place_ty = self.tcx.try_normalize_erasing_regions(self.param_env, place_ty).ok()?;
+        let (field, field_ty) =
+            adt_def.non_enum_variant().fields.iter_enumerated().find_map(|(idx, field)| {
+                let field_ty = field.ty(self.tcx, args);
+                let (pointee, pointee_mutbl) = match *field_ty.kind() {
+                    ty::Ref(_, pointee, pointee_mutbl) => (pointee, pointee_mutbl),
+                    _ if field_ty.is_box() => (field_ty.boxed_ty(), hir::Mutability::Mut),
+                    _ => return None,
+                };
+                let pointee =
+                    self.tcx.try_normalize_erasing_regions(self.param_env, pointee).ok()?;
+                (pointee == place_ty && pointee_mutbl >= mutbl).then_some((idx, field_ty))
+            })?;
+
+        let Expr { temp_lifetime, span, .. } = self.thir[arg];
+        let lhs = match scope {
+            Some((region_scope, lint_level)) => self.thir.exprs.push(Expr {
+                temp_lifetime,
+                ty: self.thir[arg].ty,
+                span,
+                kind: ExprKind::Scope { region_scope, lint_level, value: arg },
+            }),
+            None => arg,
+        };
+        let field = self.thir.exprs.push(Expr {
+            temp_lifetime,
+            ty: field_ty,
+            span,
+            kind: ExprKind::Field { lhs, variant_index: FIRST_VARIANT, name: field },
+        });
+        Some(ExprKind::Deref { arg: field })
+    }
+
     fn convert_captured_hir_place(
         &mut self,
         closure_expr: &'tcx hir::Expr<'tcx>,
@@ -1078,6 +1143,19 @@ fn convert_captured_hir_place(
                 HirProjectionKind::OpaqueCast => {
                     ExprKind::Use { source: self.thir.exprs.push(captured_place_expr) }
                 }
+                HirProjectionKind::ConstantIndex(offset) => {
+                    let lit = ScalarInt::try_from_target_usize(offset, self.tcx).unwrap();
+                    let index = Expr {
+                        temp_lifetime,
+                        ty: self.tcx.types.usize,
+                        span: closure_expr.span,
+                        kind: ExprKind::NonHirLiteral { lit, user_ty: None },
+                    };
+                    ExprKind::Index {
+                        lhs: self.thir.exprs.push(captured_place_expr),
+                        index: self.thir.exprs.push(index),
+                    }
+                }
                 HirProjectionKind::Index | HirProjectionKind::Subslice => {
                     // We don't capture these projections, so we can ignore them here
                     continue;
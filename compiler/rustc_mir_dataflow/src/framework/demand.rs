@@ -0,0 +1,121 @@
+//! Demand-driven answers to gen/kill problems on a single element, at a single location.
+//!
+//! Computing the fixpoint of an [`Analysis`](super::Analysis) is the right tool when the state is
+//! needed at many locations, but it costs a pass over the whole body, and a state per block. A
+//! consumer which needs a single bit at a single location, like a diagnostic, can instead search
+//! backward from that location, and stop on each path at the first statement which sets or clears
+//! that bit. This only works for "may" problems: forward problems where the bit is set at a
+//! location if it is set along *some* path reaching it.
+
+use rustc_index::bit_set::BitSet;
+use rustc_middle::mir::{self, BasicBlock, Location, START_BLOCK};
+
+/// The effect of a statement, terminator or edge on the element of an [`OnDemandAnalysis`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DemandEffect {
+    Gen,
+    Kill,
+}
+
+/// A forward gen/kill problem on a single element, whose join is union, which can be answered at
+/// a location without computing the fixpoint for the whole body.
+pub trait OnDemandAnalysis<'tcx> {
+    /// Whether the element is set upon entry to the `START_BLOCK`.
+    fn entry_state(&self, body: &mir::Body<'tcx>) -> bool;
+
+    /// Returns the effect of `statement` on the element, if it has one.
+    fn statement_effect(
+        &self,
+        statement: &mir::Statement<'tcx>,
+        location: Location,
+    ) -> Option<DemandEffect>;
+
+    /// Returns the effect of `terminator` on the element along all of its edges, if it has one.
+    fn terminator_effect(
+        &self,
+        terminator: &mir::Terminator<'tcx>,
+        location: Location,
+    ) -> Option<DemandEffect>;
+
+    /// Returns the effect of the edge of `terminator` to `target` on the element, if it has one.
+    /// It applies after the effect of the terminator itself, e.g. for the return place of a call.
+    fn edge_effect(
+        &self,
+        _terminator: &mir::Terminator<'tcx>,
+        _location: Location,
+        _target: BasicBlock,
+    ) -> Option<DemandEffect> {
+        None
+    }
+
+    /// Returns whether the element is set right before the statement or terminator at `location`
+    /// runs.
+    ///
+    /// This only visits the part of the body which reaches `location` without going through a
+    /// statement affecting the element, and stops at the first path along which it is set.
+    fn is_set_before(&self, body: &mir::Body<'tcx>, location: Location) -> bool {
+        let is_reachable = |block| body.basic_blocks.reverse_postorder().contains(&block);
+        if !is_reachable(location.block) {
+            return false;
+        }
+
+        let data = &body.basic_blocks[location.block];
+        if let Some(effect) = effect_before(self, data, location.block, location.statement_index) {
+            return effect == DemandEffect::Gen;
+        }
+
+        // The blocks whose statements have already been searched.
+        let mut visited = BitSet::new_empty(body.basic_blocks.len());
+        // The blocks whose start is reached without going through an effect.
+        let mut work_list = vec![location.block];
+        while let Some(block) = work_list.pop() {
+            if block == START_BLOCK && self.entry_state(body) {
+                return true;
+            }
+
+            for &pred in &body.basic_blocks.predecessors()[block] {
+                let pred_data = &body.basic_blocks[pred];
+                let edge_effect =
+                    self.edge_effect(pred_data.terminator(), body.terminator_loc(pred), block);
+                let effect = match edge_effect {
+                    Some(effect) => Some(effect),
+                    // The statements of `pred` are the same along all of its edges.
+                    None if !visited.insert(pred) => continue,
+                    None => effect_before(self, pred_data, pred, pred_data.statements.len() + 1),
+                };
+                match effect {
+                    Some(DemandEffect::Gen) if is_reachable(pred) => return true,
+                    Some(_) => {}
+                    None => work_list.push(pred),
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Returns the last effect on the element of the statements and terminator of `block` before the
+/// one at `statement_index`. The terminator is at `statements.len()`, so past it, all of them are
+/// searched.
+fn effect_before<'tcx, A: OnDemandAnalysis<'tcx> + ?Sized>(
+    analysis: &A,
+    data: &mir::BasicBlockData<'tcx>,
+    block: BasicBlock,
+    statement_index: usize,
+) -> Option<DemandEffect> {
+    let num_statements = data.statements.len();
+    if statement_index > num_statements {
+        let location = Location { block, statement_index: num_statements };
+        if let Some(effect) = analysis.terminator_effect(data.terminator(), location) {
+            return Some(effect);
+        }
+    }
+    data.statements[..statement_index.min(num_statements)].iter().enumerate().rev().find_map(
+        |(statement_index, statement)| {
+            analysis.statement_effect(statement, Location { block, statement_index })
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,84 @@
+//! Tests for the backward search of `OnDemandAnalysis::is_set_before`.
+
+use rustc_index::IndexVec;
+use rustc_middle::mir::*;
+use rustc_span::DUMMY_SP;
+
+use crate::impls::MaybeInitializedLocal;
+use crate::OnDemandAnalysis;
+
+const X: Local = Local::from_u32(1);
+const Y: Local = Local::from_u32(2);
+
+/// Creates the following `mir::Body`, where `bb5` is unreachable:
+///
+/// ```text
+/// bb0: StorageLive(_1); switchInt(copy _2) -> [0: bb1, otherwise: bb2]
+/// bb1: _1 = copy _2; goto -> bb3
+/// bb2: goto -> bb3
+/// bb3: drop(_1) -> [return: bb4, unwind continue]
+/// bb4: StorageDead(_1); return
+/// bb5: _1 = copy _2; goto -> bb2
+/// ```
+fn mock_body<'tcx>() -> Body<'tcx> {
+    let source_info = SourceInfo::outermost(DUMMY_SP);
+    let statement = |kind| Statement { source_info, kind };
+    let assign_x = || {
+        statement(StatementKind::Assign(Box::new((
+            Place::from(X),
+            Rvalue::Use(Operand::Copy(Place::from(Y))),
+        ))))
+    };
+
+    let mut blocks = IndexVec::new();
+    let mut block = |statements, kind| {
+        blocks.push(BasicBlockData {
+            statements,
+            terminator: Some(Terminator { source_info, kind }),
+            is_cleanup: false,
+        })
+    };
+
+    let bb = BasicBlock::from_u32;
+    block(
+        vec![statement(StatementKind::StorageLive(X))],
+        TerminatorKind::SwitchInt {
+            discr: Operand::Copy(Place::from(Y)),
+            targets: SwitchTargets::static_if(0, bb(1), bb(2)),
+        },
+    );
+    block(vec![assign_x()], TerminatorKind::Goto { target: bb(3) });
+    block(vec![], TerminatorKind::Goto { target: bb(3) });
+    block(
+        vec![],
+        TerminatorKind::Drop {
+            place: Place::from(X),
+            target: bb(4),
+            unwind: UnwindAction::Continue,
+            replace: false,
+        },
+    );
+    block(vec![statement(StatementKind::StorageDead(X))], TerminatorKind::Return);
+    block(vec![assign_x()], TerminatorKind::Goto { target: bb(2) });
+
+    Body::new_cfg_only(blocks)
+}
+
+#[test]
+fn maybe_initialized_local() {
+    let body = mock_body();
+    let analysis = MaybeInitializedLocal::new(X);
+    let is_init = |block, statement_index| {
+        let location = Location { block: BasicBlock::from_u32(block), statement_index };
+        analysis.is_set_before(&body, location)
+    };
+
+    assert!(!is_init(0, 0));
+    assert!(!is_init(0, 1));
+    assert!(is_init(1, 1));
+    // The assignment in `bb5` doesn't reach `bb2`, as `bb5` itself is unreachable.
+    assert!(!is_init(2, 0));
+    assert!(is_init(3, 0));
+    assert!(!is_init(4, 0));
+    assert!(!is_init(5, 1));
+}
@@ -38,6 +38,7 @@
 use rustc_middle::ty::TyCtxt;
 
 mod cursor;
+mod demand;
 mod direction;
 mod engine;
 pub mod fmt;
@@ -46,6 +47,7 @@
 mod visitor;
 
 pub use self::cursor::ResultsCursor;
+pub use self::demand::{DemandEffect, OnDemandAnalysis};
 pub use self::direction::{Backward, Direction, Forward};
 pub use self::engine::{Engine, Results};
 pub use self::lattice::{JoinSemiLattice, MaybeReachable};
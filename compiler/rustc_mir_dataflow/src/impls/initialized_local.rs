@@ -0,0 +1,140 @@
+use rustc_middle::mir::visit::Visitor;
+use rustc_middle::mir::*;
+
+use crate::framework::{DemandEffect, OnDemandAnalysis};
+
+/// `MaybeInitializedLocal` tracks whether a single local, or a part of it, might be initialized,
+/// without building the move paths of the body.
+///
+/// It is an [`OnDemandAnalysis`], for consumers which only ask about one local at a few locations,
+/// like diagnostics:
+///
+/// ```ignore (cross-crate-imports)
+/// if MaybeInitializedLocal::new(local).is_set_before(body, location) {
+///     // `local` may need to be dropped at `location`.
+/// }
+/// ```
+///
+/// Unlike `MaybeInitializedPlaces`, a move out of a part of the local doesn't deinitialize it, as
+/// the other parts may still be initialized. This makes the answer more conservative when every
+/// part of the local is moved out.
+pub struct MaybeInitializedLocal {
+    local: Local,
+}
+
+impl MaybeInitializedLocal {
+    pub fn new(local: Local) -> Self {
+        MaybeInitializedLocal { local }
+    }
+
+    /// Whether writing to `place` initializes the local, or a part of it.
+    fn is_written_by(&self, place: Place<'_>) -> bool {
+        place.local == self.local && !place.is_indirect()
+    }
+
+    /// Whether the local is moved out as a whole by the operands visited by `visit`.
+    fn is_moved_by(&self, visit: impl FnOnce(&mut MovesLocal)) -> bool {
+        let mut visitor = MovesLocal { local: self.local, moved: false };
+        visit(&mut visitor);
+        visitor.moved
+    }
+}
+
+impl<'tcx> OnDemandAnalysis<'tcx> for MaybeInitializedLocal {
+    fn entry_state(&self, body: &Body<'tcx>) -> bool {
+        body.args_iter().any(|arg| arg == self.local)
+    }
+
+    fn statement_effect(
+        &self,
+        statement: &Statement<'tcx>,
+        location: Location,
+    ) -> Option<DemandEffect> {
+        match &statement.kind {
+            // The rvalue is evaluated before the place is written.
+            StatementKind::Assign(box (place, rvalue)) => {
+                if self.is_written_by(*place) {
+                    Some(DemandEffect::Gen)
+                } else if self.is_moved_by(|v| v.visit_rvalue(rvalue, location)) {
+                    Some(DemandEffect::Kill)
+                } else {
+                    None
+                }
+            }
+            StatementKind::SetDiscriminant { place: box place, .. }
+                if self.is_written_by(*place) =>
+            {
+                Some(DemandEffect::Gen)
+            }
+            StatementKind::Deinit(box place) if place.as_local() == Some(self.local) => {
+                Some(DemandEffect::Kill)
+            }
+            StatementKind::StorageLive(local) | StatementKind::StorageDead(local)
+                if *local == self.local =>
+            {
+                Some(DemandEffect::Kill)
+            }
+            _ => None,
+        }
+    }
+
+    fn terminator_effect(
+        &self,
+        terminator: &Terminator<'tcx>,
+        location: Location,
+    ) -> Option<DemandEffect> {
+        let dropped = match terminator.kind {
+            TerminatorKind::Drop { place, .. } => place.as_local() == Some(self.local),
+            _ => false,
+        };
+        if dropped || self.is_moved_by(|v| v.visit_terminator(terminator, location)) {
+            Some(DemandEffect::Kill)
+        } else {
+            None
+        }
+    }
+
+    fn edge_effect(
+        &self,
+        terminator: &Terminator<'tcx>,
+        _location: Location,
+        target: BasicBlock,
+    ) -> Option<DemandEffect> {
+        let initialized = match &terminator.kind {
+            TerminatorKind::Call { destination, target: Some(return_target), .. } => {
+                *return_target == target && self.is_written_by(*destination)
+            }
+            TerminatorKind::Yield { resume, resume_arg, .. } => {
+                *resume == target && self.is_written_by(*resume_arg)
+            }
+            TerminatorKind::InlineAsm { operands, destination: Some(return_target), .. } => {
+                *return_target == target
+                    && operands.iter().any(|operand| match operand {
+                        InlineAsmOperand::Out { place: Some(place), .. }
+                        | InlineAsmOperand::InOut { out_place: Some(place), .. } => {
+                            self.is_written_by(*place)
+                        }
+                        _ => false,
+                    })
+            }
+            _ => false,
+        };
+        initialized.then_some(DemandEffect::Gen)
+    }
+}
+
+/// Finds the operands moving a local out as a whole.
+struct MovesLocal {
+    local: Local,
+    moved: bool,
+}
+
+impl<'tcx> Visitor<'tcx> for MovesLocal {
+    fn visit_operand(&mut self, operand: &Operand<'tcx>, _: Location) {
+        if let Operand::Move(place) = operand
+            && place.as_local() == Some(self.local)
+        {
+            self.moved = true;
+        }
+    }
+}
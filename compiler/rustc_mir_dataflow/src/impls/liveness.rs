@@ -1,10 +1,14 @@
+use rustc_data_structures::fx::FxHashSet;
 use rustc_index::bit_set::BitSet;
 use rustc_middle::mir::visit::{MutatingUseContext, NonMutatingUseContext, PlaceContext, Visitor};
 use rustc_middle::mir::{
     self, CallReturnPlaces, Local, Location, Place, StatementKind, TerminatorEdges,
 };
 
-use crate::{Analysis, AnalysisDomain, Backward, GenKill, GenKillAnalysis};
+use std::fmt;
+
+use crate::fmt::DebugWithContext;
+use crate::{Analysis, AnalysisDomain, Backward, GenKill, GenKillAnalysis, JoinSemiLattice};
 
 /// A [live-variable dataflow analysis][liveness].
 ///
@@ -216,10 +220,16 @@ fn for_place(place: Place<'_>, context: PlaceContext) -> Option<DefUse> {
 ///
 /// This is basically written for dead store elimination and nothing else.
 ///
+/// Alongside the locals which are live, it tracks the ones which are *read*: live because of a use
+/// other than a `Drop`. A store to one of the `drop_insensitive_stores` is dead as long as its
+/// local isn't read, even if the local is dropped later, as the caller has proven that the drop
+/// glue of the local never looks at the stored value.
+///
 /// All of the caveats of `MaybeLiveLocals` apply.
 #[derive(Clone, Copy)]
 pub struct MaybeTransitiveLiveLocals<'a> {
     always_live: &'a BitSet<Local>,
+    drop_insensitive_stores: &'a FxHashSet<Location>,
 }
 
 impl<'a> MaybeTransitiveLiveLocals<'a> {
@@ -227,20 +237,70 @@ impl<'a> MaybeTransitiveLiveLocals<'a> {
     /// considered live.
     ///
     /// This should include at least all locals that are ever borrowed.
-    pub fn new(always_live: &'a BitSet<Local>) -> Self {
-        MaybeTransitiveLiveLocals { always_live }
+    ///
+    /// The `drop_insensitive_stores` are the locations of the assignments to a part of a local
+    /// which the drop of that local can't observe. It may be empty.
+    pub fn new(
+        always_live: &'a BitSet<Local>,
+        drop_insensitive_stores: &'a FxHashSet<Location>,
+    ) -> Self {
+        MaybeTransitiveLiveLocals { always_live, drop_insensitive_stores }
+    }
+}
+
+/// The domain of [`MaybeTransitiveLiveLocals`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransitiveLiveness {
+    /// The locals which may be used later.
+    pub live: BitSet<Local>,
+    /// The locals which may be used later by something else than a `Drop`. A subset of `live`.
+    pub read: BitSet<Local>,
+}
+
+impl TransitiveLiveness {
+    fn apply(&mut self, mut effect: impl FnMut(&mut BitSet<Local>)) {
+        effect(&mut self.live);
+        effect(&mut self.read);
+    }
+}
+
+impl JoinSemiLattice for TransitiveLiveness {
+    fn join(&mut self, other: &Self) -> bool {
+        let live = self.live.join(&other.live);
+        let read = self.read.join(&other.read);
+        live || read
+    }
+}
+
+impl<C> DebugWithContext<C> for TransitiveLiveness {
+    fn fmt_with(&self, ctxt: &C, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.live.fmt_with(ctxt, f)?;
+        f.write_str(" read: ")?;
+        self.read.fmt_with(ctxt, f)
+    }
+
+    fn fmt_diff_with(&self, old: &Self, ctxt: &C, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.live.fmt_diff_with(&old.live, ctxt, f)?;
+        if self.read != old.read {
+            f.write_str(" read: ")?;
+            self.read.fmt_diff_with(&old.read, ctxt, f)?;
+        }
+        Ok(())
     }
 }
 
 impl<'a, 'tcx> AnalysisDomain<'tcx> for MaybeTransitiveLiveLocals<'a> {
-    type Domain = BitSet<Local>;
+    type Domain = TransitiveLiveness;
     type Direction = Backward;
 
     const NAME: &'static str = "transitive liveness";
 
     fn bottom_value(&self, body: &mir::Body<'tcx>) -> Self::Domain {
         // bottom = not live
-        BitSet::new_empty(body.local_decls.len())
+        TransitiveLiveness {
+            live: BitSet::new_empty(body.local_decls.len()),
+            read: BitSet::new_empty(body.local_decls.len()),
+        }
     }
 
     fn initialize_start_block(&self, _: &mir::Body<'tcx>, _: &mut Self::Domain) {
@@ -273,15 +333,20 @@ fn apply_statement_effect(
             | StatementKind::Nop => None,
         };
         if let Some(destination) = destination {
+            let used_later = if self.drop_insensitive_stores.contains(&location) {
+                &trans.read
+            } else {
+                &trans.live
+            };
             if !destination.is_indirect()
-                && !trans.contains(destination.local)
+                && !used_later.contains(destination.local)
                 && !self.always_live.contains(destination.local)
             {
                 // This store is dead
                 return;
             }
         }
-        TransferFunction(trans).visit_statement(statement, location);
+        trans.apply(|set| TransferFunction(set).visit_statement(statement, location));
     }
 
     fn apply_terminator_effect<'mir>(
@@ -290,7 +355,13 @@ fn apply_terminator_effect<'mir>(
         terminator: &'mir mir::Terminator<'tcx>,
         location: Location,
     ) -> TerminatorEdges<'mir, 'tcx> {
-        TransferFunction(trans).visit_terminator(terminator, location);
+        match terminator.kind {
+            // Dropping a local makes it live, but doesn't read it.
+            mir::TerminatorKind::Drop { place, .. } if !place.is_indirect() => {
+                TransferFunction(&mut trans.live).visit_terminator(terminator, location);
+            }
+            _ => trans.apply(|set| TransferFunction(set).visit_terminator(terminator, location)),
+        }
         terminator.edges()
     }
 
@@ -301,15 +372,18 @@ fn apply_call_return_effect(
         return_places: CallReturnPlaces<'_, 'tcx>,
     ) {
         if let CallReturnPlaces::Yield(resume_place) = return_places {
-            YieldResumeEffect(trans).visit_place(
-                &resume_place,
-                PlaceContext::MutatingUse(MutatingUseContext::Yield),
-                Location::START,
-            )
+            trans.apply(|set| {
+                YieldResumeEffect(set).visit_place(
+                    &resume_place,
+                    PlaceContext::MutatingUse(MutatingUseContext::Yield),
+                    Location::START,
+                )
+            })
         } else {
             return_places.for_each(|place| {
                 if let Some(local) = place.as_local() {
-                    trans.remove(local);
+                    trans.live.remove(local);
+                    trans.read.remove(local);
                 }
             });
         }
@@ -4,6 +4,7 @@
 
 mod borrowed_locals;
 mod initialized;
+mod initialized_local;
 mod liveness;
 mod storage_liveness;
 
@@ -13,7 +14,8 @@
     DefinitelyInitializedPlaces, EverInitializedPlaces, MaybeInitializedPlaces,
     MaybeUninitializedPlaces,
 };
+pub use self::initialized_local::MaybeInitializedLocal;
 pub use self::liveness::MaybeLiveLocals;
-pub use self::liveness::MaybeTransitiveLiveLocals;
 pub use self::liveness::TransferFunction as LivenessTransferFunction;
+pub use self::liveness::{MaybeTransitiveLiveLocals, TransitiveLiveness};
 pub use self::storage_liveness::{MaybeRequiresStorage, MaybeStorageDead, MaybeStorageLive};
@@ -34,14 +34,53 @@ mir_transform_mutation_layout_constrained_borrow_label = borrow of layout constr
 mir_transform_mutation_layout_constrained_borrow_note = references to fields of layout constrained fields lose the constraints. Coupled with interior mutability, the field can be changed to invalid values
 mir_transform_mutation_layout_constrained_label = mutation of layout constrained field
 mir_transform_mutation_layout_constrained_note = mutating layout constrained fields cannot statically be checked for valid values
+mir_transform_not_promoted = this borrow is not promoted to a constant
+mir_transform_not_promoted_depends_on = it depends on this value, which cannot be promoted
+mir_transform_not_promoted_reason = because {$reason ->
+    [not_a_temp] it refers to a variable or argument, not to a temporary
+    [not_assigned_once] it refers to a temporary that is not assigned exactly once, or that is mutated
+    [needs_drop] the value needs to be dropped
+    [has_mut_interior] the value has interior mutability
+    [mut_borrow] only mutable borrows of empty arrays are promoted
+    [borrow_kind] this kind of borrow is never promoted
+    [deref] it dereferences a pointer
+    [downcast] it accesses an enum variant or casts an opaque type
+    [index] it indexes with something other than an in-bounds constant
+    [union_field] it accesses a union field
+    [static] it refers to a static outside of a static
+    [thread_local] it refers to a thread-local static
+    [yield] it is the result of a `yield`
+    [pointer_expose_address] it casts a pointer to an integer
+    [pointer_op] it operates on raw pointers or function pointers
+    [division] it divides by something other than a non-zero constant
+    [box] it allocates a `Box`
+    [raw_pointer] it creates a raw pointer
+    [non_const_fn] it calls a function that is not `const`
+    *[not_rustc_promotable] it calls a `const fn` that is not `#[rustc_promotable]` outside of a const or static
+    }
+
 mir_transform_operation_will_panic = this operation will panic at runtime
 
+mir_transform_promoted = this borrow is promoted to a constant
+
 mir_transform_requires_unsafe = {$details} is unsafe and requires unsafe {$op_in_unsafe_fn_allowed ->
     [true] function or block
     *[false] block
     }
     .not_inherited = items do not inherit unsafety from separate enclosing items
 
+mir_transform_tail_call_c_variadic = tail calls are not allowed from or to c-variadic functions
+
+mir_transform_tail_call_intrinsic = intrinsics cannot be tail called
+
+mir_transform_tail_call_rust_call = tail calls are not allowed from or to functions with the "rust-call" ABI
+
+mir_transform_tail_call_signature_mismatch = mismatched signatures in tail call
+    .note = the caller has the signature `{$caller_ty}`, but the callee has `{$callee_ty}`
+    .help = the callee of a tail call must have exactly the signature of the caller, including its ABI
+
+mir_transform_tail_call_track_caller = tail calls are not allowed from or to `#[track_caller]` functions
+
 mir_transform_target_feature_call_help = in order for the call to be safe, the context requires the following additional target {$missing_target_features_count ->
     [1] feature
     *[count] features
@@ -63,6 +102,12 @@ mir_transform_unaligned_packed_ref = reference to packed field is unaligned
 
 mir_transform_union_access_label = access to union field
 mir_transform_union_access_note = the field may not be properly initialized: using uninitialized data will cause undefined behavior
+
+mir_transform_unknown_mir_pass = unknown MIR pass `{$name}` in `-Zmir-passes`
+
+mir_transform_unreadable_inliner_profile = failed to read the profile data for MIR inlining from `{$path}`: {$error}
+    .note = the MIR inliner reads the profile in the text format of `llvm-profdata merge --text`, from a `.proftext` file next to the one given to `-C profile-use`
+
 mir_transform_unsafe_op_in_unsafe_fn = {$details} is unsafe and requires unsafe block (error E0133)
     .suggestion = consider wrapping the function body in an unsafe block
     .note = an unsafe function restricts its caller, but its body is safe by default
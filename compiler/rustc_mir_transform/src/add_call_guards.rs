@@ -30,6 +30,10 @@ pub enum AddCallGuards {
  */
 
 impl<'tcx> MirPass<'tcx> for AddCallGuards {
+    fn establishes(&self) -> MirProperties {
+        MirProperties::NO_CRITICAL_CALL_EDGES
+    }
+
     fn run_pass(&self, _tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         self.add_call_guards(body);
     }
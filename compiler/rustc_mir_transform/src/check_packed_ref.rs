@@ -1,6 +1,6 @@
 use rustc_middle::mir::visit::{PlaceContext, Visitor};
 use rustc_middle::mir::*;
-use rustc_middle::ty::{self, TyCtxt};
+use rustc_middle::ty::{self, layout, TyCtxt};
 
 use crate::MirLint;
 use crate::{errors, util};
@@ -51,9 +51,18 @@ fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, _location:
                         "builtin derive created an unaligned reference"
                     );
                 } else {
-                    self.tcx
-                        .dcx()
-                        .emit_err(errors::UnalignedPackedRef { span: self.source_info.span });
+                    // The innermost packed type containing the place.
+                    let packed_ty = place
+                        .iter_projections()
+                        .rev()
+                        .map(|(base, _)| base.ty(self.body, self.tcx).ty)
+                        .find(|ty| matches!(ty.kind(), ty::Adt(def, _) if def.repr().packed()));
+                    let layout_table =
+                        packed_ty.and_then(|ty| layout::layout_table(self.tcx, self.param_env, ty));
+                    self.tcx.dcx().emit_err(errors::UnalignedPackedRef {
+                        span: self.source_info.span,
+                        layout_table,
+                    });
                 }
             }
         }
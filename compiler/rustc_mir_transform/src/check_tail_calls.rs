@@ -0,0 +1,81 @@
+use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
+use rustc_middle::mir::*;
+use rustc_middle::ty::{self, Ty, TyCtxt};
+use rustc_span::def_id::DefId;
+use rustc_target::spec::abi::Abi;
+
+use crate::errors;
+use crate::MirLint;
+
+/// Checks that the tail calls of a body, lowered from `become`, can be guaranteed.
+///
+/// The callee of a tail call replaces the caller on the stack, so it must take its arguments
+/// and return its value in the same way: their signatures, including the ABI, must match
+/// exactly. Functions which need something from their caller's frame, like `#[track_caller]`
+/// or c-variadic functions, can't be on either side of a tail call.
+pub struct CheckTailCalls;
+
+impl<'tcx> MirLint<'tcx> for CheckTailCalls {
+    fn run_lint(&self, tcx: TyCtxt<'tcx>, body: &Body<'tcx>) {
+        let caller = body.source.def_id();
+        let param_env = tcx.param_env(caller);
+        for data in body.basic_blocks.iter() {
+            let terminator = data.terminator();
+            let TerminatorKind::TailCall { func, .. } = &terminator.kind else { continue };
+            let span = terminator.source_info.span;
+            let (callee, callee_sig) = match *func.ty(body, tcx).kind() {
+                ty::FnDef(def_id, args) => {
+                    (Some(def_id), tcx.fn_sig(def_id).instantiate(tcx, args))
+                }
+                ty::FnPtr(sig) => (None, sig),
+                ref kind => span_bug!(span, "tail call of a non-function: {kind:?}"),
+            };
+            // Only functions contain tail calls, so this is only queried if the body is one.
+            let caller_sig = tcx.fn_sig(caller).instantiate_identity();
+
+            if let Some(callee) = callee
+                && tcx.intrinsic(callee).is_some()
+            {
+                tcx.dcx().emit_err(errors::TailCallIntrinsic { span });
+                continue;
+            }
+            if is_track_caller(tcx, caller) || callee.is_some_and(|c| is_track_caller(tcx, c)) {
+                tcx.dcx().emit_err(errors::TailCallTrackCaller { span });
+                continue;
+            }
+            if caller_sig.c_variadic() || callee_sig.c_variadic() {
+                tcx.dcx().emit_err(errors::TailCallCVariadic { span });
+                continue;
+            }
+            if caller_sig.abi() == Abi::RustCall || callee_sig.abi() == Abi::RustCall {
+                tcx.dcx().emit_err(errors::TailCallRustCall { span });
+                continue;
+            }
+
+            let caller_ty = fn_ptr_ty(tcx, param_env, caller_sig);
+            let callee_ty = fn_ptr_ty(tcx, param_env, callee_sig);
+            if caller_ty != callee_ty {
+                tcx.dcx().emit_err(errors::TailCallSignatureMismatch {
+                    span,
+                    caller_ty,
+                    callee_ty,
+                });
+            }
+        }
+    }
+}
+
+fn is_track_caller(tcx: TyCtxt<'_>, def_id: DefId) -> bool {
+    tcx.codegen_fn_attrs(def_id).flags.contains(CodegenFnAttrFlags::TRACK_CALLER)
+}
+
+/// Returns the function pointer type with the signature `sig`, normalized and without regions,
+/// so that signatures that are passed and returned the same way compare equal.
+fn fn_ptr_ty<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    sig: ty::PolyFnSig<'tcx>,
+) -> Ty<'tcx> {
+    let sig = tcx.normalize_erasing_late_bound_regions(param_env, sig);
+    Ty::new_fn_ptr(tcx, ty::Binder::dummy(sig))
+}
@@ -4,7 +4,7 @@
 use std::fmt::Debug;
 
 use rustc_const_eval::interpret::{
-    format_interp_error, ImmTy, InterpCx, InterpResult, Projectable, Scalar,
+    format_interp_error, ImmTy, Immediate, InterpCx, InterpResult, OpTy, Projectable, Scalar,
 };
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir::def::DefKind;
@@ -269,6 +269,15 @@ fn eval_constant(&mut self, c: &ConstOperand<'tcx>) -> Option<ImmTy<'tcx>> {
     /// Returns the value, if any, of evaluating `place`.
     #[instrument(level = "trace", skip(self), ret)]
     fn eval_place(&mut self, place: Place<'tcx>) -> Option<ImmTy<'tcx>> {
+        if place.is_indirect_first_projection() {
+            let op = self.eval_indirect_place(place)?;
+            let imm = self.use_ecx(|this| this.ecx.read_immediate_raw(&op))?.right()?;
+            if matches!(*imm, Immediate::Uninit) {
+                return None;
+            }
+            return Some(imm);
+        }
+
         match self.get_const(place)? {
             Value::Immediate(imm) => Some(imm.clone()),
             Value::Aggregate { .. } => None,
@@ -276,6 +285,45 @@ fn eval_place(&mut self, place: Place<'tcx>) -> Option<ImmTy<'tcx>> {
         }
     }
 
+    /// Returns the memory behind `place`, which starts by dereferencing its local.
+    ///
+    /// We do not track the address of locals, so a pointer we know the value of was computed from
+    /// constants, and points to a promoted constant or to a static. `DummyMachine` refuses to read
+    /// mutable statics, and we only follow shared references to `Freeze` types, so what we read
+    /// here is what will be read at runtime.
+    fn eval_indirect_place(&mut self, place: Place<'tcx>) -> Option<OpTy<'tcx>> {
+        let (&PlaceElem::Deref, projection) = place.projection.split_first()? else {
+            return None;
+        };
+
+        let ty = self.local_decls()[place.local].ty;
+        if ty.ref_mutability() != Some(Mutability::Not)
+            || !ty.builtin_deref(true)?.ty.is_freeze(self.tcx, self.param_env)
+        {
+            return None;
+        }
+
+        let pointer = self.get_const(place.local.into())?.immediate()?.clone();
+        let mut op: OpTy<'tcx> = self.use_ecx(|this| this.ecx.deref_pointer(&pointer))?.into();
+        for &elem in projection {
+            let elem = match elem {
+                // We have no call stack to associate a local with a value, so replace the index
+                // with its value.
+                PlaceElem::Index(idx) => {
+                    let idx = self.eval_place(idx.into())?;
+                    let offset = self.use_ecx(|this| this.ecx.read_target_usize(&idx))?;
+                    let min_length = offset.checked_add(1)?;
+                    PlaceElem::ConstantIndex { offset, min_length, from_end: false }
+                }
+                // The pointee of an inner reference may not be `Freeze`.
+                PlaceElem::Deref => return None,
+                elem => elem,
+            };
+            op = self.use_ecx(|this| this.ecx.project(&op, elem))?;
+        }
+        Some(op)
+    }
+
     /// Returns the value, if any, of evaluating `op`. Calls upon `eval_constant`
     /// or `eval_place`, depending on the variant of `Operand` used.
     fn eval_operand(&mut self, op: &Operand<'tcx>) -> Option<ImmTy<'tcx>> {
@@ -606,13 +654,18 @@ fn eval_rvalue(&mut self, rvalue: &Rvalue<'tcx>, dest: &Place<'tcx>) -> Option<(
             }
 
             Len(place) => {
-                let len = match self.get_const(place)? {
-                    Value::Immediate(src) => src.len(&self.ecx).ok()?,
-                    Value::Aggregate { fields, .. } => fields.len() as u64,
-                    Value::Uninit => match place.ty(self.local_decls(), self.tcx).ty.kind() {
-                        ty::Array(_, n) => n.try_eval_target_usize(self.tcx, self.param_env)?,
-                        _ => return None,
-                    },
+                let len = if place.is_indirect_first_projection() {
+                    let src = self.eval_indirect_place(place)?;
+                    src.len(&self.ecx).ok()?
+                } else {
+                    match self.get_const(place)? {
+                        Value::Immediate(src) => src.len(&self.ecx).ok()?,
+                        Value::Aggregate { fields, .. } => fields.len() as u64,
+                        Value::Uninit => match place.ty(self.local_decls(), self.tcx).ty.kind() {
+                            ty::Array(_, n) => n.try_eval_target_usize(self.tcx, self.param_env)?,
+                            _ => return None,
+                        },
+                    }
                 };
                 ImmTy::from_scalar(Scalar::from_target_usize(len, self), layout).into()
             }
@@ -666,13 +719,18 @@ fn eval_rvalue(&mut self, rvalue: &Rvalue<'tcx>, dest: &Place<'tcx>) -> Option<(
             },
 
             Discriminant(place) => {
-                let variant = match self.get_const(place)? {
-                    Value::Immediate(op) => {
-                        let op = op.clone();
-                        self.use_ecx(|this| this.ecx.read_discriminant(&op))?
+                let variant = if place.is_indirect_first_projection() {
+                    let op = self.eval_indirect_place(place)?;
+                    self.use_ecx(|this| this.ecx.read_discriminant(&op))?
+                } else {
+                    match self.get_const(place)? {
+                        Value::Immediate(op) => {
+                            let op = op.clone();
+                            self.use_ecx(|this| this.ecx.read_discriminant(&op))?
+                        }
+                        Value::Aggregate { variant, .. } => *variant,
+                        Value::Uninit => return None,
                     }
-                    Value::Aggregate { variant, .. } => *variant,
-                    Value::Uninit => return None,
                 };
                 let imm = self.use_ecx(|this| {
                     this.ecx.discriminant_for_variant(
@@ -808,6 +866,7 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
             | TerminatorKind::FalseEdge { .. }
             | TerminatorKind::FalseUnwind { .. }
             | TerminatorKind::Call { .. }
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::InlineAsm { .. } => {}
         }
 
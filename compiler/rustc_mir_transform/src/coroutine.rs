@@ -58,7 +58,7 @@
 use crate::errors;
 use crate::pass_manager as pm;
 use crate::simplify;
-use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::pluralize;
 use rustc_hir as hir;
 use rustc_hir::lang_items::LangItem;
@@ -71,10 +71,11 @@
 use rustc_middle::ty::InstanceDef;
 use rustc_middle::ty::{self, Ty, TyCtxt};
 use rustc_mir_dataflow::impls::{
-    MaybeBorrowedLocals, MaybeLiveLocals, MaybeRequiresStorage, MaybeStorageLive,
+    MaybeBorrowedLocals, MaybeInitializedLocal, MaybeLiveLocals, MaybeRequiresStorage,
+    MaybeStorageLive,
 };
 use rustc_mir_dataflow::storage::always_storage_live_locals;
-use rustc_mir_dataflow::Analysis;
+use rustc_mir_dataflow::{Analysis, OnDemandAnalysis};
 use rustc_span::def_id::{DefId, LocalDefId};
 use rustc_span::symbol::sym;
 use rustc_span::Span;
@@ -751,6 +752,11 @@ struct LivenessInfo {
     /// For every suspending block, the locals which are storage-live across
     /// that suspension point.
     storage_liveness: IndexVec<BasicBlock, Option<BitSet<Local>>>,
+
+    /// For every upvar which is not used after a suspension point, the set of
+    /// saved locals that are storage-live while it is still used. See
+    /// `CoroutineLayout::upvar_conflicts`.
+    upvar_conflicts: IndexVec<FieldIdx, Option<BitSet<CoroutineSavedLocal>>>,
 }
 
 /// Computes which locals have to be stored in the state-machine for the
@@ -761,11 +767,20 @@ struct LivenessInfo {
 ///   case none exist, the local is considered to be always live.
 /// - a local has to be stored if it is either directly used after the
 ///   the suspend point, or if it is live and has been previously borrowed.
+///
+/// If `only_initialized` is set, locals which are definitely uninitialized at a
+/// suspension point and have never been borrowed are not considered live across
+/// it, even if their storage has to be kept. This is what the witness types of
+/// the coroutine are computed from: such a local holds no value across the
+/// suspension point, so e.g. a `MutexGuard` which has been moved out of before an
+/// `.await` does not make the future `!Send`, even if it would be dropped later on.
+/// Borrowed locals are kept, since they may be filled again through a pointer.
 fn locals_live_across_suspend_points<'tcx>(
     tcx: TyCtxt<'tcx>,
     body: &Body<'tcx>,
     always_live_locals: &BitSet<Local>,
     movable: bool,
+    only_initialized: bool,
 ) -> LivenessInfo {
     // Calculate when MIR locals have live storage. This gives us an upper bound of their
     // lifetimes.
@@ -838,6 +853,23 @@ fn locals_live_across_suspend_points<'tcx>(
             // The coroutine argument is ignored.
             live_locals.remove(SELF_ARG);
 
+            if only_initialized {
+                // `MaybeInitializedLocal` does not see writes through pointers, so a borrowed
+                // local may have been filled again behind its back.
+                borrowed_locals_cursor.seek_before_primary_effect(loc);
+                let borrowed_locals = borrowed_locals_cursor.get();
+                let uninit_locals: Vec<_> = live_locals
+                    .iter()
+                    .filter(|&local| {
+                        !borrowed_locals.contains(local)
+                            && !MaybeInitializedLocal::new(local).is_set_before(body, loc)
+                    })
+                    .collect();
+                for local in uninit_locals {
+                    live_locals.remove(local);
+                }
+            }
+
             debug!("loc = {:?}, live_locals = {:?}", loc, live_locals);
 
             // Add the locals live at this suspension point to the set of locals which live across
@@ -859,10 +891,17 @@ fn locals_live_across_suspend_points<'tcx>(
         .map(|live_here| saved_locals.renumber_bitset(live_here))
         .collect();
 
-    let storage_conflicts = compute_storage_conflicts(
+    let upvar_count = match *body.local_decls[SELF_ARG].ty.kind() {
+        ty::Coroutine(_, args) => args.as_coroutine().upvar_tys().len(),
+        _ => 0,
+    };
+    let upvar_liveness = UpvarLiveness::compute(body, upvar_count);
+
+    let (storage_conflicts, upvar_conflicts) = compute_storage_conflicts(
         body,
         &saved_locals,
         always_live_locals.clone(),
+        &upvar_liveness,
         requires_storage_cursor.into_results(),
     );
 
@@ -872,6 +911,7 @@ fn locals_live_across_suspend_points<'tcx>(
         source_info_at_suspension_points,
         storage_conflicts,
         storage_liveness: storage_liveness_map,
+        upvar_conflicts,
     }
 }
 
@@ -920,16 +960,111 @@ fn deref(&self) -> &Self::Target {
     }
 }
 
+/// Where the upvars of a coroutine are used, through the coroutine argument.
+///
+/// Upvars are never assigned once the coroutine is created, so an upvar is live
+/// wherever it may still be used later on. An upvar which is not live after any
+/// suspension point is only needed while the coroutine is first resumed.
+struct UpvarLiveness {
+    /// For every upvar, the blocks at the end of which it is live, or `None` for
+    /// the upvars used after a suspension point.
+    live_out: IndexVec<FieldIdx, Option<BitSet<BasicBlock>>>,
+
+    /// For every upvar and block, the index of the last statement in the block
+    /// which uses the upvar.
+    last_use: IndexVec<FieldIdx, IndexVec<BasicBlock, Option<usize>>>,
+}
+
+impl UpvarLiveness {
+    fn compute(body: &Body<'_>, upvar_count: usize) -> Self {
+        let mut visitor = UpvarUseVisitor {
+            last_use: IndexVec::from_elem_n(
+                IndexVec::from_elem(None, &body.basic_blocks),
+                upvar_count,
+            ),
+        };
+        visitor.visit_body(body);
+        let last_use = visitor.last_use;
+
+        let live_out = last_use
+            .iter()
+            .map(|uses| {
+                let mut live_out = BitSet::new_empty(body.basic_blocks.len());
+                let mut worklist: Vec<_> =
+                    uses.iter_enumerated().filter_map(|(bb, u)| u.map(|_| bb)).collect();
+                while let Some(block) = worklist.pop() {
+                    for &pred in &body.basic_blocks.predecessors()[block] {
+                        if live_out.insert(pred) {
+                            worklist.push(pred);
+                        }
+                    }
+                }
+
+                // An upvar live across a suspension point has to be kept in
+                // every state of the coroutine.
+                let live_across_suspension = live_out.iter().any(|block| {
+                    matches!(body[block].terminator().kind, TerminatorKind::Yield { .. })
+                });
+                (!live_across_suspension).then_some(live_out)
+            })
+            .collect();
+
+        UpvarLiveness { live_out, last_use }
+    }
+
+    /// Returns the upvars which are not used after a suspension point, and are
+    /// live at `loc`.
+    fn live_at(&self, loc: Location) -> impl Iterator<Item = FieldIdx> + '_ {
+        self.live_out.iter_enumerated().filter_map(move |(upvar, live_out)| {
+            let live_out = live_out.as_ref()?;
+            let used_later =
+                self.last_use[upvar][loc.block].is_some_and(|idx| loc.statement_index <= idx);
+            (used_later || live_out.contains(loc.block)).then_some(upvar)
+        })
+    }
+}
+
+/// Finds the last use of every upvar in each block.
+struct UpvarUseVisitor {
+    last_use: IndexVec<FieldIdx, IndexVec<BasicBlock, Option<usize>>>,
+}
+
+impl<'tcx> Visitor<'tcx> for UpvarUseVisitor {
+    fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, location: Location) {
+        if place.local != SELF_ARG || !context.is_use() {
+            return;
+        }
+
+        let idx = Some(location.statement_index);
+        match place.projection.first() {
+            Some(ProjectionElem::Field(upvar, _)) => self.last_use[*upvar][location.block] = idx,
+            // A use of the whole coroutine uses all of its upvars.
+            _ => {
+                for uses in self.last_use.iter_mut() {
+                    uses[location.block] = idx;
+                }
+            }
+        }
+    }
+}
+
 /// For every saved local, looks for which locals are StorageLive at the same
 /// time. Generates a bitset for every local of all the other locals that may be
 /// StorageLive simultaneously with that local. This is used in the layout
 /// computation; see `CoroutineLayout` for more.
+///
+/// Also returns, for every upvar which is not used after a suspension point, the
+/// saved locals which are StorageLive while the upvar is still live.
 fn compute_storage_conflicts<'mir, 'tcx>(
     body: &'mir Body<'tcx>,
     saved_locals: &CoroutineSavedLocals,
     always_live_locals: BitSet<Local>,
+    upvar_liveness: &UpvarLiveness,
     mut requires_storage: rustc_mir_dataflow::Results<'tcx, MaybeRequiresStorage<'mir, 'tcx>>,
-) -> BitMatrix<CoroutineSavedLocal, CoroutineSavedLocal> {
+) -> (
+    BitMatrix<CoroutineSavedLocal, CoroutineSavedLocal>,
+    IndexVec<FieldIdx, Option<BitSet<CoroutineSavedLocal>>>,
+) {
     assert_eq!(body.local_decls.len(), saved_locals.domain_size());
 
     debug!("compute_storage_conflicts({:?})", body.span);
@@ -946,11 +1081,23 @@ fn compute_storage_conflicts<'mir, 'tcx>(
         saved_locals: saved_locals,
         local_conflicts: BitMatrix::from_row_n(&ineligible_locals, body.local_decls.len()),
         eligible_storage_live: BitSet::new_empty(body.local_decls.len()),
+        upvar_liveness,
+        // Locals that are always live conflict with every upvar too.
+        upvar_conflicts: upvar_liveness
+            .live_out
+            .iter()
+            .map(|live_out| live_out.as_ref().map(|_| ineligible_locals.clone()))
+            .collect(),
     };
 
     requires_storage.visit_reachable_with(body, &mut visitor);
 
     let local_conflicts = visitor.local_conflicts;
+    let upvar_conflicts = visitor
+        .upvar_conflicts
+        .iter()
+        .map(|conflicts| conflicts.as_ref().map(|c| saved_locals.renumber_bitset(c)))
+        .collect();
 
     // Compress the matrix using only stored locals (Local -> CoroutineSavedLocal).
     //
@@ -973,7 +1120,7 @@ fn compute_storage_conflicts<'mir, 'tcx>(
             }
         }
     }
-    storage_conflicts
+    (storage_conflicts, upvar_conflicts)
 }
 
 struct StorageConflictVisitor<'mir, 'tcx, 's> {
@@ -984,6 +1131,8 @@ struct StorageConflictVisitor<'mir, 'tcx, 's> {
     local_conflicts: BitMatrix<Local, Local>,
     // We keep this bitset as a buffer to avoid reallocating memory.
     eligible_storage_live: BitSet<Local>,
+    upvar_liveness: &'s UpvarLiveness,
+    upvar_conflicts: IndexVec<FieldIdx, Option<BitSet<Local>>>,
 }
 
 impl<'mir, 'tcx, R> rustc_mir_dataflow::ResultsVisitor<'mir, 'tcx, R>
@@ -1026,6 +1175,12 @@ fn apply_state(&mut self, flow_state: &BitSet<Local>, loc: Location) {
             self.local_conflicts.union_row_with(&self.eligible_storage_live, local);
         }
 
+        for upvar in self.upvar_liveness.live_at(loc) {
+            if let Some(conflicts) = &mut self.upvar_conflicts[upvar] {
+                conflicts.union(&self.eligible_storage_live);
+            }
+        }
+
         if self.eligible_storage_live.count() > 1 {
             trace!("at {:?}, eligible_storage_live={:?}", loc, self.eligible_storage_live);
         }
@@ -1046,6 +1201,7 @@ fn compute_layout<'tcx>(
         source_info_at_suspension_points,
         storage_conflicts,
         storage_liveness,
+        upvar_conflicts,
     } = liveness;
 
     // Gather live local types and their indices.
@@ -1132,6 +1288,7 @@ fn compute_layout<'tcx>(
         variant_fields,
         variant_source_info,
         storage_conflicts,
+        upvar_conflicts,
     };
     debug!(?layout);
 
@@ -1372,6 +1529,7 @@ fn can_unwind<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>) -> bool {
             // These may unwind.
             TerminatorKind::Drop { .. }
             | TerminatorKind::Call { .. }
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::InlineAsm { .. }
             | TerminatorKind::Assert { .. } => return true,
         }
@@ -1582,7 +1740,8 @@ pub(crate) fn mir_coroutine_witnesses<'tcx>(
     // The witness simply contains all locals live across suspend points.
 
     let always_live_locals = always_storage_live_locals(body);
-    let liveness_info = locals_live_across_suspend_points(tcx, body, &always_live_locals, movable);
+    let liveness_info =
+        locals_live_across_suspend_points(tcx, body, &always_live_locals, movable, true);
 
     // Extract locals which are live across suspension point into `layout`
     // `remap` gives a mapping from local indices onto coroutine struct indices
@@ -1689,9 +1848,9 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         let always_live_locals = always_storage_live_locals(body);
 
         let liveness_info =
-            locals_live_across_suspend_points(tcx, body, &always_live_locals, movable);
+            locals_live_across_suspend_points(tcx, body, &always_live_locals, movable, false);
 
-        if tcx.sess.opts.unstable_opts.validate_mir {
+        if tcx.sess.opts.unstable_opts.validate_mir.is_enabled() {
             let mut vis = EnsureCoroutineFieldAssignmentsNeverAlias {
                 assigned_local: None,
                 saved_locals: &liveness_info.saved_locals,
@@ -1886,6 +2045,7 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
             TerminatorKind::InlineAsm { .. } => {}
 
             TerminatorKind::Call { .. }
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::Goto { .. }
             | TerminatorKind::SwitchInt { .. }
             | TerminatorKind::UnwindResume
@@ -1901,8 +2061,14 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
     }
 }
 
+/// Lints the values of `#[must_not_suspend]` types which are held across a suspension point.
+///
+/// The layout is the one the witness types are computed from, so a value is only linted if it
+/// would also make the coroutine `!Send`: values which have been moved out of before the
+/// suspension point are not. Each value is linted once, at the first suspension point it is
+/// held across, even if other values of the same type are held across other ones.
 fn check_suspend_tys<'tcx>(tcx: TyCtxt<'tcx>, layout: &CoroutineLayout<'tcx>, body: &Body<'tcx>) {
-    let mut linted_tys = FxHashSet::default();
+    let mut linted_locals = BitSet::new_empty(layout.field_tys.len());
 
     // We want a user-facing param-env.
     let param_env = tcx.param_env(body.source.def_id());
@@ -1915,7 +2081,7 @@ fn check_suspend_tys<'tcx>(tcx: TyCtxt<'tcx>, layout: &CoroutineLayout<'tcx>, bo
             let decl = &layout.field_tys[local];
             debug!(?decl);
 
-            if !decl.ignore_for_traits && linted_tys.insert(decl.ty) {
+            if !decl.ignore_for_traits && linted_locals.insert(local) {
                 let Some(hir_id) = decl.source_info.scope.lint_root(&body.source_scopes) else {
                     continue;
                 };
@@ -1,7 +1,11 @@
 use rustc_data_structures::captures::Captures;
 use rustc_data_structures::fx::FxHashSet;
+use rustc_index::IndexVec;
+use rustc_middle::mir::coverage::{
+    BlockMarkerId, BranchSpan, CoverageKind, MCDCBranchSpan, MCDCDecisionSpan,
+};
 use rustc_middle::mir::{
-    self, AggregateKind, FakeReadCause, Rvalue, Statement, StatementKind, Terminator,
+    self, AggregateKind, BasicBlock, FakeReadCause, Rvalue, Statement, StatementKind, Terminator,
     TerminatorKind,
 };
 use rustc_span::{ExpnKind, MacroKind, Span, Symbol};
@@ -9,6 +13,7 @@
 use crate::coverage::graph::{
     BasicCoverageBlock, BasicCoverageBlockData, CoverageGraph, START_BCB,
 };
+use crate::coverage::spans::{BcbMapping, BcbMappingKind};
 use crate::coverage::ExtractedHirInfo;
 
 /// Traverses the MIR body to produce an initial collection of coverage-relevant
@@ -172,8 +177,6 @@ fn is_closure_like(statement: &Statement<'_>) -> bool {
 /// If the MIR `Statement` has a span contributive to computing coverage spans,
 /// return it; otherwise return `None`.
 fn filtered_statement_span(statement: &Statement<'_>) -> Option<Span> {
-    use mir::coverage::CoverageKind;
-
     match statement.kind {
         // These statements have spans that are often outside the scope of the executed source code
         // for their parent `BasicBlock`.
@@ -218,9 +221,15 @@ fn filtered_statement_span(statement: &Statement<'_>) -> Option<Span> {
             Some(statement.source_info.span)
         }
 
+        // Block markers are used for branch coverage, so ignore them here.
+        StatementKind::Coverage(box mir::Coverage { kind: CoverageKind::BlockMarker { .. } }) => None,
+
         StatementKind::Coverage(box mir::Coverage {
             // These coverage statements should not exist prior to coverage instrumentation.
-            kind: CoverageKind::CounterIncrement { .. } | CoverageKind::ExpressionUsed { .. }
+            kind: CoverageKind::CounterIncrement { .. }
+                | CoverageKind::ExpressionUsed { .. }
+                | CoverageKind::CondBitmapUpdate { .. }
+                | CoverageKind::TestVectorBitmapUpdate { .. }
         }) => bug!("Unexpected coverage statement found during coverage instrumentation: {statement:?}"),
     }
 }
@@ -243,7 +252,8 @@ fn filtered_terminator_span(terminator: &Terminator<'_>) -> Option<Span> {
         | TerminatorKind::Goto { .. } => None,
 
         // Call `func` operand can have a more specific span when part of a chain of calls
-        | TerminatorKind::Call { ref func, .. } => {
+        | TerminatorKind::Call { ref func, .. }
+        | TerminatorKind::TailCall { ref func, .. } => {
             let mut span = terminator.source_info.span;
             if let mir::Operand::Constant(box constant) = func {
                 if constant.span.lo() > span.lo() {
@@ -266,6 +276,92 @@ fn filtered_terminator_span(terminator: &Terminator<'_>) -> Option<Span> {
     }
 }
 
+/// Converts the branch information recorded during MIR building into branch
+/// mappings, and MC/DC condition and decision mappings if MC/DC is enabled.
+pub(super) fn extract_branch_mappings(
+    mir_body: &mir::Body<'_>,
+    body_span: Span,
+    basic_coverage_blocks: &CoverageGraph,
+) -> Vec<BcbMapping> {
+    let Some(branch_info) = mir_body.coverage_branch_info.as_deref() else {
+        return vec![];
+    };
+
+    let mut block_markers = IndexVec::<BlockMarkerId, Option<BasicBlock>>::from_elem_n(
+        None,
+        branch_info.num_block_markers,
+    );
+
+    // Fill out the mapping from block marker IDs to their enclosing blocks.
+    for (bb, data) in mir_body.basic_blocks.iter_enumerated() {
+        for statement in &data.statements {
+            if let StatementKind::Coverage(coverage) = &statement.kind
+                && let CoverageKind::BlockMarker { id } = coverage.kind
+            {
+                block_markers[id] = Some(bb);
+            }
+        }
+    }
+
+    let bcb_from_marker =
+        |marker: BlockMarkerId| basic_coverage_blocks.bcb_from_bb(block_markers[marker]?);
+
+    // For now, ignore any branch span that was introduced by expansion. This
+    // makes things like assert macros less noisy.
+    let unexpand_branch_span = |raw_span: Span| {
+        if !raw_span.ctxt().outer_expn_data().is_root() {
+            return None;
+        }
+        let (span, _) = unexpand_into_body_span_with_visible_macro(raw_span, body_span)?;
+        Some(span)
+    };
+
+    let branch_mappings = branch_info.branch_spans.iter().filter_map(
+        |&BranchSpan { span: raw_span, true_marker, false_marker }| {
+            let span = unexpand_branch_span(raw_span)?;
+            let true_bcb = bcb_from_marker(true_marker)?;
+            let false_bcb = bcb_from_marker(false_marker)?;
+
+            Some(BcbMapping { kind: BcbMappingKind::Branch { true_bcb, false_bcb }, span })
+        },
+    );
+
+    let mcdc_branch_mappings = branch_info.mcdc_branch_spans.iter().filter_map(
+        |&MCDCBranchSpan { span: raw_span, condition_info, true_marker, false_marker }| {
+            let span = unexpand_branch_span(raw_span)?;
+            let true_bcb = bcb_from_marker(true_marker)?;
+            let false_bcb = bcb_from_marker(false_marker)?;
+
+            let kind = BcbMappingKind::MCDCBranch { true_bcb, false_bcb, condition_info };
+            Some(BcbMapping { kind, span })
+        },
+    );
+
+    let mut next_bitmap_idx = 0;
+    let mcdc_decision_mappings = branch_info.mcdc_decision_spans.iter().filter_map(
+        |MCDCDecisionSpan { span: raw_span, conditions_num, end_markers }| {
+            let span = unexpand_branch_span(*raw_span)?;
+            let end_bcbs =
+                end_markers.iter().map(|&marker| bcb_from_marker(marker)).collect::<Option<_>>()?;
+            let conditions_num = *conditions_num as u16;
+
+            let bitmap_idx = next_bitmap_idx;
+            next_bitmap_idx += test_vector_bitmap_bytes(conditions_num);
+
+            let kind = BcbMappingKind::MCDCDecision { end_bcbs, bitmap_idx, conditions_num };
+            Some(BcbMapping { kind, span })
+        },
+    );
+
+    branch_mappings.chain(mcdc_branch_mappings).chain(mcdc_decision_mappings).collect()
+}
+
+/// Number of bytes needed for the test vector bitmap of a decision with the
+/// given number of conditions, which has one bit per possible test vector.
+pub(super) fn test_vector_bitmap_bytes(conditions_num: u16) -> u32 {
+    (1_u32 << conditions_num).div_ceil(8)
+}
+
 /// Returns an extrapolated span (pre-expansion[^1]) corresponding to a range
 /// within the function's body source. This span is guaranteed to be contained
 /// within, or equal to, the `body_span`. If the extrapolated span is not
@@ -11,11 +11,17 @@
 //!     number of iterations of dest prop between the first and second application of this transform
 //!     will still not cause any further changes.
 //!
+//! From `-Zmir-opt-level=3`, an assignment to a field of a local is also removed if the local is
+//! only dropped afterwards, when its drop can't observe the assigned value: the value itself needs
+//! no drop, and none of the types it is nested in has a `Drop` impl. Drop impls, even with
+//! `#[may_dangle]` parameters, receive the whole value and may read any of its fields.
+//!
 
 use crate::util::is_within_packed;
+use rustc_data_structures::fx::FxHashSet;
 use rustc_middle::mir::visit::Visitor;
 use rustc_middle::mir::*;
-use rustc_middle::ty::TyCtxt;
+use rustc_middle::ty::{self, TyCtxt};
 use rustc_mir_dataflow::debuginfo::debuginfo_locals;
 use rustc_mir_dataflow::impls::{
     borrowed_locals, LivenessTransferFunction, MaybeTransitiveLiveLocals,
@@ -34,7 +40,13 @@ pub fn eliminate<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
     let mut always_live = debuginfo_locals(body);
     always_live.union(&borrowed_locals);
 
-    let mut live = MaybeTransitiveLiveLocals::new(&always_live)
+    let drop_insensitive_stores = if tcx.sess.mir_opt_level() >= 3 {
+        drop_insensitive_stores(tcx, body)
+    } else {
+        FxHashSet::default()
+    };
+
+    let mut live = MaybeTransitiveLiveLocals::new(&always_live, &drop_insensitive_stores)
         .into_engine(tcx, body)
         .iterate_to_fixpoint()
         .into_results_cursor(body);
@@ -58,7 +70,7 @@ pub fn eliminate<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
                     // Do not skip the transformation if the local is in debuginfo, as we do
                     // not really lose any information for this purpose.
                     && !borrowed_locals.contains(place.local)
-                    && !state.contains(place.local)
+                    && !state.live.contains(place.local)
                     // If `place` is a projection of a disaligned field in a packed ADT,
                     // the move may be codegened as a pointer to that field.
                     // Using that disaligned pointer may trigger UB in the callee,
@@ -69,7 +81,7 @@ pub fn eliminate<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
                 }
 
                 // Account that `arg` is read from, so we don't promote another argument to a move.
-                LivenessTransferFunction(&mut state).visit_operand(arg, loc);
+                LivenessTransferFunction(&mut state.live).visit_operand(arg, loc);
             }
         }
 
@@ -86,7 +98,13 @@ pub fn eliminate<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
                 | StatementKind::Deinit(box place) => {
                     if !place.is_indirect() && !always_live.contains(place.local) {
                         live.seek_before_primary_effect(loc);
-                        if !live.get().contains(place.local) {
+                        let state = live.get();
+                        let used_later = if drop_insensitive_stores.contains(&loc) {
+                            &state.read
+                        } else {
+                            &state.live
+                        };
+                        if !used_later.contains(place.local) {
                             patch.push(loc);
                         }
                     }
@@ -125,6 +143,34 @@ pub fn eliminate<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
     }
 }
 
+/// Returns the locations of the assignments to a part of a local which the drop of that local
+/// can't observe.
+fn drop_insensitive_stores<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>) -> FxHashSet<Location> {
+    let param_env = tcx.param_env_reveal_all_normalized(body.source.def_id());
+    let mut stores = FxHashSet::default();
+    for (block, data) in body.basic_blocks.iter_enumerated() {
+        for (statement_index, statement) in data.statements.iter().enumerate() {
+            if let StatementKind::Assign(box (place, _)) = statement.kind
+                && !place.projection.is_empty()
+                && place.iter_projections().all(|(base, elem)| {
+                    // The drop glue of a struct or tuple only drops its fields, which don't
+                    // overlap, but a `Drop` impl may read any of them.
+                    let fields_unobserved = match base.ty(body, tcx).ty.kind() {
+                        ty::Tuple(_) | ty::Closure(..) => true,
+                        ty::Adt(adt, _) => adt.is_struct() && !adt.has_dtor(tcx),
+                        _ => false,
+                    };
+                    matches!(elem, ProjectionElem::Field(..)) && fields_unobserved
+                })
+                && !place.ty(body, tcx).ty.needs_drop(tcx, param_env)
+            {
+                stores.insert(Location { block, statement_index });
+            }
+        }
+    }
+    stores
+}
+
 pub enum DeadStoreElimination {
     Initial,
     Final,
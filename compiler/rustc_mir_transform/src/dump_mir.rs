@@ -5,7 +5,7 @@
 
 use crate::MirPass;
 use rustc_middle::mir::write_mir_pretty;
-use rustc_middle::mir::Body;
+use rustc_middle::mir::{Body, MirProperties};
 use rustc_middle::ty::TyCtxt;
 use rustc_session::config::{OutFileName, OutputType};
 
@@ -16,6 +16,10 @@ fn name(&self) -> &'static str {
         self.0
     }
 
+    fn invalidates(&self) -> MirProperties {
+        MirProperties::empty()
+    }
+
     fn run_pass(&self, _tcx: TyCtxt<'tcx>, _body: &mut Body<'tcx>) {}
 }
 
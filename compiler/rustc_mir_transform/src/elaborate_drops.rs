@@ -47,6 +47,11 @@
 pub struct ElaborateDrops;
 
 impl<'tcx> MirPass<'tcx> for ElaborateDrops {
+    // The drop flags of the return places of calls are set at the start of their return targets.
+    fn requires(&self) -> MirProperties {
+        MirProperties::NO_CRITICAL_CALL_EDGES
+    }
+
     #[instrument(level = "trace", skip(self, tcx, body))]
     fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         debug!("elaborate_drops({:?} @ {:?})", body.source, body.span);
@@ -1,17 +1,20 @@
 use std::borrow::Cow;
+use std::path::PathBuf;
 
 use rustc_errors::{
     codes::*, Applicability, DecorateLint, DiagCtxt, DiagnosticArgValue, DiagnosticBuilder,
     DiagnosticMessage, EmissionGuarantee, IntoDiagnostic, Level,
 };
 use rustc_macros::{Diagnostic, LintDiagnostic, Subdiagnostic};
+use rustc_middle::error::LayoutTableNote;
 use rustc_middle::mir::{AssertKind, UnsafetyViolationDetails};
-use rustc_middle::ty::TyCtxt;
+use rustc_middle::ty::{Ty, TyCtxt};
 use rustc_session::lint::{self, Lint};
 use rustc_span::def_id::DefId;
 use rustc_span::Span;
 
 use crate::fluent_generated as fluent;
+use crate::promote_consts::UnpromotableReason;
 
 #[derive(LintDiagnostic)]
 pub(crate) enum ConstMutate {
@@ -37,9 +40,11 @@ pub(crate) enum ConstMutate {
 #[note]
 #[note(mir_transform_note_ub)]
 #[help]
-pub(crate) struct UnalignedPackedRef {
+pub(crate) struct UnalignedPackedRef<'tcx> {
     #[primary_span]
     pub span: Span,
+    #[subdiagnostic]
+    pub layout_table: Option<LayoutTableNote<'tcx>>,
 }
 
 #[derive(LintDiagnostic)]
@@ -255,6 +260,20 @@ pub(crate) struct FnItemRef {
     pub ident: String,
 }
 
+#[derive(Diagnostic)]
+#[diag(mir_transform_unreadable_inliner_profile)]
+#[note]
+pub(crate) struct UnreadableInlinerProfile {
+    pub path: PathBuf,
+    pub error: String,
+}
+
+#[derive(Diagnostic)]
+#[diag(mir_transform_unknown_mir_pass)]
+pub(crate) struct UnknownMirPass {
+    pub name: String,
+}
+
 pub(crate) struct MustNotSupend<'tcx, 'a> {
     pub tcx: TyCtxt<'tcx>,
     pub yield_sp: Span,
@@ -290,3 +309,73 @@ pub(crate) struct MustNotSuspendReason {
     pub span: Span,
     pub reason: String,
 }
+
+#[derive(Diagnostic)]
+#[diag(mir_transform_tail_call_signature_mismatch)]
+#[note]
+#[help]
+pub(crate) struct TailCallSignatureMismatch<'tcx> {
+    #[primary_span]
+    pub span: Span,
+    pub caller_ty: Ty<'tcx>,
+    pub callee_ty: Ty<'tcx>,
+}
+
+#[derive(Diagnostic)]
+#[diag(mir_transform_tail_call_intrinsic)]
+pub(crate) struct TailCallIntrinsic {
+    #[primary_span]
+    pub span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(mir_transform_tail_call_track_caller)]
+pub(crate) struct TailCallTrackCaller {
+    #[primary_span]
+    pub span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(mir_transform_tail_call_c_variadic)]
+pub(crate) struct TailCallCVariadic {
+    #[primary_span]
+    pub span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(mir_transform_tail_call_rust_call)]
+pub(crate) struct TailCallRustCall {
+    #[primary_span]
+    pub span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(mir_transform_promoted)]
+pub(crate) struct Promoted {
+    #[primary_span]
+    pub span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(mir_transform_not_promoted)]
+pub(crate) struct NotPromoted {
+    #[primary_span]
+    pub span: Span,
+    #[subdiagnostic]
+    pub depends_on: Vec<NotPromotedDependsOn>,
+    #[subdiagnostic]
+    pub reason: NotPromotedReason,
+}
+
+#[derive(Subdiagnostic)]
+#[note(mir_transform_not_promoted_depends_on)]
+pub(crate) struct NotPromotedDependsOn {
+    #[primary_span]
+    pub span: Span,
+}
+
+#[derive(Subdiagnostic)]
+#[note(mir_transform_not_promoted_reason)]
+pub(crate) struct NotPromotedReason {
+    pub reason: UnpromotableReason,
+}
@@ -23,6 +23,7 @@
 use std::ops::{Range, RangeFrom};
 
 pub(crate) mod cycle;
+pub(crate) mod profile;
 
 const TOP_DOWN_DEPTH_LIMIT: usize = 5;
 
@@ -83,10 +84,22 @@ fn inline<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) -> bool {
 
     let param_env = tcx.param_env_reveal_all_normalized(def_id);
 
+    // The profile only has counts for the instances that were codegened.
+    let caller_execution_count = if let Some(profile) = tcx.mir_inliner_profile(())
+        && let DefKind::Fn | DefKind::AssocFn = tcx.def_kind(def_id)
+        && !tcx.generics_of(def_id).requires_monomorphization(tcx)
+    {
+        let instance = Instance::mono(tcx, def_id.to_def_id());
+        profile.execution_count(tcx.symbol_name(instance).name)
+    } else {
+        None
+    };
+
     let mut this = Inliner {
         tcx,
         param_env,
         codegen_fn_attrs: tcx.codegen_fn_attrs(def_id),
+        caller_execution_count,
         history: Vec::new(),
         changed: false,
     };
@@ -100,6 +113,8 @@ struct Inliner<'tcx> {
     param_env: ParamEnv<'tcx>,
     /// Caller codegen attributes.
     codegen_fn_attrs: &'tcx CodegenFnAttrs,
+    /// How many times the caller was executed according to the profile data, if known.
+    caller_execution_count: Option<u64>,
     /// Stack of inlined instances.
     /// We only check the `DefId` and not the args because we want to
     /// avoid inlining cases of polymorphic recursion.
@@ -474,6 +489,16 @@ fn check_mir_body(
     ) -> Result<(), &'static str> {
         let tcx = self.tcx;
 
+        // MC/DC instrumentation keeps the conditions of a decision in a local of
+        // the instrumented function, which does not exist in the caller.
+        if callee_body
+            .function_coverage_info
+            .as_ref()
+            .is_some_and(|info| info.mcdc_bitmap_bytes > 0)
+        {
+            return Err("callee has MC/DC instrumentation");
+        }
+
         let mut threshold = if cross_crate_inlinable {
             self.tcx.sess.opts.unstable_opts.inline_mir_hint_threshold.unwrap_or(100)
         } else {
@@ -486,6 +511,18 @@ fn check_mir_body(
         if callee_body.basic_blocks.len() <= 3 {
             threshold += threshold / 4;
         }
+
+        // With profile data, inlining is only worth growing the caller for call sites that are
+        // executed often, and those are worth a larger callee than the heuristics alone allow.
+        if let Some(profile_threshold) = tcx.sess.opts.unstable_opts.inline_mir_profile_threshold
+            && let Some(execution_count) = self.callsite_execution_count(callsite)
+        {
+            if execution_count < profile_threshold {
+                debug!("NOT inlining {:?} [executed {} times]", callsite, execution_count);
+                return Err("call site is cold according to profile data");
+            }
+            threshold *= 2;
+        }
         debug!("    final inline threshold = {}", threshold);
 
         // FIXME: Give a bonus to functions with only a single caller
@@ -527,6 +564,9 @@ fn check_mir_body(
                 // inline-asm is detected. LLVM will still possibly do an inline later on
                 // if the no-attribute function ends up with the same instruction set anyway.
                 return Err("Cannot move inline-asm across instruction sets");
+            } else if let TerminatorKind::TailCall { .. } = term.kind {
+                // The tail call would replace the caller, instead of returning to it.
+                return Err("callee contains tail calls");
             } else {
                 work_list.extend(term.successors())
             }
@@ -546,6 +586,19 @@ fn check_mir_body(
         }
     }
 
+    /// How many times the call site was executed according to the profile data, if known.
+    ///
+    /// The profile only has counts per function, so this is estimated as the smaller of the
+    /// counts of the caller and of the callee.
+    fn callsite_execution_count(&self, callsite: &CallSite<'tcx>) -> Option<u64> {
+        let profile = self.tcx.mir_inliner_profile(()).as_ref()?;
+        if callsite.callee.args.has_param() {
+            return None;
+        }
+        let callee_count = profile.execution_count(self.tcx.symbol_name(callsite.callee).name)?;
+        Some(self.caller_execution_count.map_or(callee_count, |count| count.min(callee_count)))
+    }
+
     fn inline_call(
         &self,
         caller_body: &mut Body<'tcx>,
@@ -976,6 +1029,9 @@ fn visit_terminator(&mut self, terminator: &mut Terminator<'tcx>, loc: Location)
 
         match terminator.kind {
             TerminatorKind::CoroutineDrop | TerminatorKind::Yield { .. } => bug!(),
+            TerminatorKind::TailCall { .. } => {
+                bug!("callees with tail calls should not have been inlined")
+            }
             TerminatorKind::Goto { ref mut target } => {
                 *target = self.map_block(*target);
             }
@@ -0,0 +1,112 @@
+//! Reads the execution counts of functions from the instrumentation profile given with
+//! `-C profile-use`, for the profile-guided cost model of the MIR inliner.
+//!
+//! LLVM consumes the profile in the binary, indexed format produced by `llvm-profdata merge`,
+//! which we cannot read ourselves. The inliner instead reads the text form of that profile, as
+//! produced by `llvm-profdata merge --text`, from a `.proftext` file next to the indexed one.
+//! A text profile given directly to `-C profile-use` is read as well.
+
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_middle::mir::InlinerProfile;
+use rustc_middle::ty::TyCtxt;
+
+use std::fs;
+
+use crate::errors;
+
+pub(crate) fn mir_inliner_profile(tcx: TyCtxt<'_>, (): ()) -> Option<InlinerProfile> {
+    tcx.sess.opts.unstable_opts.inline_mir_profile_threshold?;
+    let profile_use = tcx.sess.opts.cg.profile_use.as_ref()?;
+
+    let path = match fs::read(profile_use) {
+        Ok(contents) if std::str::from_utf8(&contents).is_ok() => profile_use.clone(),
+        _ => profile_use.with_extension("proftext"),
+    };
+    let result = fs::read_to_string(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|contents| parse_text_profile(&contents));
+    match result {
+        Ok(profile) => Some(profile),
+        Err(error) => {
+            tcx.dcx().emit_warn(errors::UnreadableInlinerProfile { path, error });
+            None
+        }
+    }
+}
+
+/// Parses a profile in the text format of `llvm-profdata`, where each function is a record like
+///
+/// ```text
+/// _RNvCs1234_4main3foo
+/// # Func Hash:
+/// 1234
+/// # Num Counters:
+/// 2
+/// # Counter Values:
+/// 100
+/// 3
+/// ```
+///
+/// The first counter of a function counts how often it was entered, but the other counters can
+/// be higher, e.g. for loops, so the highest one is used as the execution count of the function.
+fn parse_text_profile(contents: &str) -> Result<InlinerProfile, String> {
+    /// The part of a record expected next. Outside of a record, `name` is the last line seen.
+    enum State<'a> {
+        Name { name: Option<&'a str> },
+        Hash { name: &'a str },
+        NumCountersHeader { name: &'a str },
+        NumCounters { name: &'a str },
+        CounterValuesHeader { name: &'a str, num_counters: usize },
+        CounterValues { name: &'a str, remaining: usize, max: u64 },
+    }
+
+    let mut execution_counts = FxIndexMap::default();
+    let mut state = State::Name { name: None };
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        // Flags like `:ir` or `:fe`, which only appear at the start of the file.
+        if line.is_empty() || line.starts_with(':') {
+            continue;
+        }
+        let malformed = |what: &str| format!("expected {what} on line {}", index + 1);
+
+        state = match state {
+            State::Name { name: Some(name) } if line == "# Func Hash:" => State::Hash { name },
+            State::Name { .. } => State::Name { name: (!line.starts_with('#')).then_some(line) },
+            State::Hash { name } => {
+                line.parse::<u64>().map_err(|_| malformed("a function hash"))?;
+                State::NumCountersHeader { name }
+            }
+            State::NumCountersHeader { name } if line == "# Num Counters:" => {
+                State::NumCounters { name }
+            }
+            State::NumCountersHeader { .. } => return Err(malformed("`# Num Counters:`")),
+            State::NumCounters { name } => {
+                let num_counters = line.parse().map_err(|_| malformed("a number of counters"))?;
+                State::CounterValuesHeader { name, num_counters }
+            }
+            State::CounterValuesHeader { name, num_counters } if line == "# Counter Values:" => {
+                State::CounterValues { name, remaining: num_counters, max: 0 }
+            }
+            State::CounterValuesHeader { .. } => return Err(malformed("`# Counter Values:`")),
+            State::CounterValues { name, remaining, max } => {
+                let count: u64 = line.parse().map_err(|_| malformed("a counter value"))?;
+                let remaining = remaining.saturating_sub(1);
+                State::CounterValues { name, remaining, max: max.max(count) }
+            }
+        };
+
+        if let State::CounterValues { name, remaining: 0, max } = state {
+            // Functions with internal linkage are prefixed with the name of their file.
+            let name = name.rsplit_once(';').map_or(name, |(_, name)| name);
+            let count = execution_counts.entry(name.to_owned()).or_insert(0);
+            *count = max.max(*count);
+            state = State::Name { name: None };
+        }
+    }
+
+    if execution_counts.is_empty() {
+        return Err("no function records found".to_owned());
+    }
+    Ok(InlinerProfile { execution_counts })
+}
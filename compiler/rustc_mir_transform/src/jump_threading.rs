@@ -266,7 +266,7 @@ fn find_opportunity(
             let term = self.body.basic_blocks[pred].terminator();
             match term.kind {
                 TerminatorKind::SwitchInt { ref discr, ref targets } => {
-                    self.process_switch_int(discr, targets, bb, &mut state);
+                    self.process_switch_int(pred, discr, targets, bb, &mut state);
                     self.find_opportunity(pred, state, cost, depth + 1);
                 }
                 _ => self.recurse_through_terminator(pred, &state, &cost, depth),
@@ -579,6 +579,7 @@ fn recurse_through_terminator(
             | TerminatorKind::UnwindTerminate(_)
             | TerminatorKind::Return
             | TerminatorKind::Unreachable
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::CoroutineDrop => bug!("{term:?} has no terminators"),
             // Disallowed during optimizations.
             TerminatorKind::FalseEdge { .. }
@@ -608,6 +609,7 @@ fn recurse_through_terminator(
     #[instrument(level = "trace", skip(self))]
     fn process_switch_int(
         &mut self,
+        switch_bb: BasicBlock,
         discr: &Operand<'tcx>,
         targets: &SwitchTargets,
         target_bb: BasicBlock,
@@ -619,39 +621,157 @@ fn process_switch_int(
         let discr = discr.place()?;
         let discr_ty = discr.ty(self.body, self.tcx).ty;
         let discr_layout = self.ecx.layout_of(discr_ty).ok()?;
-        let conditions = state.try_get(discr.as_ref(), self.map)?;
 
-        if let Some((value, _)) = targets.iter().find(|&(_, target)| target == target_bb) {
+        let (value, polarity) = if let Some((value, _)) =
+            targets.iter().find(|&(_, target)| target == target_bb)
+        {
             let value = ScalarInt::try_from_uint(value, discr_layout.size)?;
             debug_assert_eq!(targets.iter().filter(|&(_, target)| target == target_bb).count(), 1);
 
             // We are inside `target_bb`. Since we have a single predecessor, we know we passed
             // through the `SwitchInt` before arriving here. Therefore, we know that
-            // `discr == value`. If one condition can be fulfilled by `discr == value`,
-            // that's an opportunity.
-            for c in conditions.iter_matches(value) {
-                debug!(?target_bb, ?c.target, "register");
-                self.opportunities.push(ThreadingOpportunity { chain: vec![], target: c.target });
-            }
+            // `discr == value`.
+            (value, Polarity::Eq)
         } else if let Some((value, _, else_bb)) = targets.as_static_if()
             && target_bb == else_bb
         {
             let value = ScalarInt::try_from_uint(value, discr_layout.size)?;
 
             // We only know that `discr != value`. That's much weaker information than
-            // the equality we had in the previous arm. All we can conclude is that
-            // the replacement condition `discr != value` can be threaded, and nothing else.
-            for c in conditions.iter() {
-                if c.value == value && c.polarity == Polarity::Ne {
-                    debug!(?target_bb, ?c.target, "register");
-                    self.opportunities
-                        .push(ThreadingOpportunity { chain: vec![], target: c.target });
+            // the equality we had in the previous arm.
+            (value, Polarity::Ne)
+        } else {
+            return None;
+        };
+
+        let (value, polarity) = normalize_bool_fact(discr_ty, value, polarity);
+        if let Some(discr_idx) = self.map.find(discr.as_ref()) {
+            self.register_known_fact(discr_idx, value, polarity, state);
+        }
+        self.process_known_fact(switch_bb, discr, value, polarity, state)
+    }
+
+    /// We know that `place == value` (or `place != value`, depending on `polarity`) at the end of
+    /// `bb`. Follow the statements of `bb` that computed `place` from other places: copies,
+    /// discriminant reads and comparisons against constants. The conditions on those places
+    /// that are fulfilled by the derived facts are threading opportunities.
+    ///
+    /// This allows to thread `if let` chains that read the discriminant of the same enum
+    /// several times, as each read goes through a fresh temporary.
+    #[instrument(level = "trace", skip(self, state))]
+    fn process_known_fact(
+        &mut self,
+        bb: BasicBlock,
+        mut place: Place<'tcx>,
+        mut value: ScalarInt,
+        mut polarity: Polarity,
+        state: &State<ConditionSet<'a>>,
+    ) -> Option<!> {
+        // Locals that are written to after the statement we are looking at.
+        let mut mutated = FxHashSet::default();
+
+        for stmt in self.body.basic_blocks[bb].statements.iter().rev() {
+            let Some((lhs, _)) = self.mutated_statement(stmt) else { continue };
+            if lhs != place {
+                // A partial write to `place` invalidates what we know about it.
+                if lhs.local == place.local {
+                    return None;
                 }
+                mutated.insert(lhs.local);
+                continue;
             }
+
+            let StatementKind::Assign(box (_, ref rhs)) = stmt.kind else { return None };
+            let source = match *rhs {
+                Rvalue::Use(Operand::Copy(source)) | Rvalue::CopyForDeref(source) => source,
+                Rvalue::Discriminant(source) => {
+                    if source.is_indirect() || mutated.contains(&source.local) {
+                        return None;
+                    }
+                    let discr = self.map.find_discr(source.as_ref())?;
+                    return self.register_known_fact(discr, value, polarity, state);
+                }
+                // We know `place == b`. We found `place = Eq(source, C)`.
+                // Derive `source == C` or `source != C`.
+                Rvalue::BinaryOp(
+                    op @ (BinOp::Eq | BinOp::Ne),
+                    box (Operand::Copy(source), Operand::Constant(ref constant))
+                    | box (Operand::Constant(ref constant), Operand::Copy(source)),
+                ) => {
+                    debug_assert_eq!(polarity, Polarity::Eq);
+                    let equals = match op {
+                        BinOp::Eq => ScalarInt::TRUE,
+                        _ => ScalarInt::FALSE,
+                    };
+                    polarity = if value == equals { Polarity::Eq } else { Polarity::Ne };
+                    value =
+                        constant.const_.normalize(self.tcx, self.param_env).try_to_scalar_int()?;
+                    source
+                }
+                // We know `place == b`. We found `place = Not(source)`, so `source == !b`.
+                Rvalue::UnaryOp(UnOp::Not, Operand::Copy(source))
+                    if source.ty(self.body, self.tcx).ty.is_bool() =>
+                {
+                    debug_assert_eq!(polarity, Polarity::Eq);
+                    value = ScalarInt::from(!value.try_to_bool().ok()?);
+                    source
+                }
+                _ => return None,
+            };
+
+            if source.is_indirect() || mutated.contains(&source.local) {
+                return None;
+            }
+            let source_ty = source.ty(self.body, self.tcx).ty;
+            (value, polarity) = normalize_bool_fact(source_ty, value, polarity);
+            let source_idx = self.map.find(source.as_ref())?;
+            self.register_known_fact(source_idx, value, polarity, state);
+            place = source;
         }
 
         None
     }
+
+    /// Register the conditions on `place` that are fulfilled by knowing that `place == value`
+    /// (or `place != value`, depending on `polarity`).
+    fn register_known_fact(
+        &mut self,
+        place: PlaceIndex,
+        value: ScalarInt,
+        polarity: Polarity,
+        state: &State<ConditionSet<'a>>,
+    ) -> Option<!> {
+        let conditions = state.try_get_idx(place, self.map)?;
+        for c in conditions.iter() {
+            let fulfilled = match polarity {
+                Polarity::Eq => c.matches(value),
+                // Knowing `place != value` is much weaker information. All we can conclude is
+                // that the replacement condition `place != value` can be threaded.
+                Polarity::Ne => c.value == value && c.polarity == Polarity::Ne,
+            };
+            if fulfilled {
+                debug!(?place, ?c.target, "register");
+                self.opportunities.push(ThreadingOpportunity { chain: vec![], target: c.target });
+            }
+        }
+        None
+    }
+}
+
+/// A boolean which is not equal to `value` is equal to `!value`.
+fn normalize_bool_fact(
+    ty: ty::Ty<'_>,
+    value: ScalarInt,
+    polarity: Polarity,
+) -> (ScalarInt, Polarity) {
+    if ty.is_bool()
+        && polarity == Polarity::Ne
+        && let Ok(value) = value.try_to_bool()
+    {
+        (ScalarInt::from(!value), Polarity::Eq)
+    } else {
+        (value, polarity)
+    }
 }
 
 struct OpportunitySet {
@@ -45,6 +45,7 @@
 #[macro_use]
 mod pass_manager;
 
+pub use pass_manager::MirPassStore;
 use pass_manager::{self as pm, Lint, MirLint, WithMinOptLevel};
 
 mod abort_unwinding_calls;
@@ -53,6 +54,7 @@
 mod add_retag;
 mod check_const_item_mutation;
 mod check_packed_ref;
+mod check_tail_calls;
 pub mod check_unsafety;
 mod remove_place_mention;
 // This pass is public to allow external drivers to perform MIR cleanup
@@ -88,9 +90,11 @@
 mod lower_intrinsics;
 mod lower_slice_len;
 mod match_branches;
+mod merge_switch_chains;
 mod multiple_return_terminators;
 mod normalize_array_len;
 mod nrvo;
+mod outline_panics;
 mod prettify;
 mod promote_consts;
 mod ref_prop;
@@ -137,12 +141,18 @@ pub fn provide(providers: &mut Providers) {
         is_ctfe_mir_available: |tcx, did| is_mir_available(tcx, did),
         mir_callgraph_reachable: inline::cycle::mir_callgraph_reachable,
         mir_inliner_callees: inline::cycle::mir_inliner_callees,
+        mir_inliner_profile: inline::profile::mir_inliner_profile,
         promoted_mir,
         deduced_param_attrs: deduce_param_attrs::deduced_param_attrs,
         ..*providers
     };
 }
 
+/// Whether `func` is a `const fn` item.
+fn is_const_fn_item(tcx: TyCtxt<'_>, func: &Operand<'_>) -> bool {
+    func.const_fn_def().is_some_and(|(def_id, _)| tcx.is_const_fn_raw(def_id))
+}
+
 fn remap_mir_for_const_eval_select<'tcx>(
     tcx: TyCtxt<'tcx>,
     mut body: Body<'tcx>,
@@ -160,7 +170,10 @@ fn remap_mir_for_const_eval_select<'tcx>(
                 fn_span,
                 ..
             } if let ty::FnDef(def_id, _) = *const_.ty().kind()
-                && matches!(tcx.intrinsic(def_id), Some(sym::const_eval_select)) =>
+                && matches!(tcx.intrinsic(def_id), Some(sym::const_eval_select))
+                // If the runtime implementation is a `const fn` as well, const-eval handles the
+                // call itself, so that it can compare the two implementations.
+                && (context == hir::Constness::NotConst || !is_const_fn_item(tcx, &args[2].node)) =>
             {
                 let [tupled_args, called_in_const, called_at_rt]: [_; 3] =
                     std::mem::take(args).try_into().unwrap();
@@ -301,6 +314,7 @@ fn mir_const(tcx: TyCtxt<'_>, def: LocalDefId) -> &Steal<Body<'_>> {
             // MIR-level lints.
             &Lint(check_packed_ref::CheckPackedRef),
             &Lint(check_const_item_mutation::CheckConstItemMutation),
+            &Lint(check_tail_calls::CheckTailCalls),
             &Lint(function_item_references::FunctionItemReferences),
             // If this is an async closure's output coroutine, generate
             // by-move and by-mut bodies if needed. We do this first so
@@ -560,8 +574,10 @@ fn o1<T>(x: T) -> WithMinOptLevel<T> {
         WithMinOptLevel(1, x)
     }
 
-    // The main optimizations that we do on MIR.
-    pm::run_passes(
+    // The main optimizations that we do on MIR. Each pass declares the properties of the body it
+    // requires and invalidates, and the pass manager re-establishes the missing ones, so that
+    // `-Zmir-passes` and `-Zmir-enable-passes` can reorder and enable passes safely.
+    pm::run_optimization_passes(
         tcx,
         body,
         &[
@@ -571,6 +587,9 @@ fn o1<T>(x: T) -> WithMinOptLevel<T> {
             // Has to be done before inlining, otherwise actual call will be almost always inlined.
             // Also simple, so can just do first
             &lower_slice_len::LowerSliceLenCalls,
+            // Has to be done before inlining as well, so that the constructors of `fmt::Arguments`
+            // are still called.
+            &outline_panics::OutlinePanics,
             // Perform inlining, which may add a lot of code.
             &inline::Inline,
             // Code from other crates may have storage markers, so this needs to happen after inlining.
@@ -601,6 +620,7 @@ fn o1<T>(x: T) -> WithMinOptLevel<T> {
             &const_debuginfo::ConstDebugInfo,
             &o1(simplify_branches::SimplifyConstCondition::AfterConstProp),
             &jump_threading::JumpThreading,
+            &merge_switch_chains::MergeSwitchChains,
             &early_otherwise_branch::EarlyOtherwiseBranch,
             &simplify_comparison_integral::SimplifyComparisonIntegral,
             &dest_prop::DestinationPropagation,
@@ -622,7 +642,6 @@ fn o1<T>(x: T) -> WithMinOptLevel<T> {
             // Dump the end result for testing and debugging purposes.
             &dump_mir::Marker("PreCodegen"),
         ],
-        Some(MirPhase::Runtime(RuntimePhase::Optimized)),
     );
 }
 
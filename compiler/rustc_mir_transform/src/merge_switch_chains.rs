@@ -0,0 +1,145 @@
+use rustc_data_structures::fx::FxHashSet;
+use rustc_middle::mir::*;
+use rustc_middle::ty::TyCtxt;
+
+use super::simplify::simplify_cfg;
+
+/// This pass merges a `SwitchInt` into the `SwitchInt` on the same value that reaches it through
+/// its `otherwise` edge. Nested `match`es on the same value, which are common in hand-written
+/// state machines and parsers, are lowered to such chains:
+///
+/// ```ignore (MIR)
+/// bb0: {
+///     _2 = discriminant(_1);
+///     switchInt(move _2) -> [0: bb1, 1: bb2, otherwise: bb3];
+/// }
+///
+/// bb3: {
+///     _3 = discriminant(_1);
+///     switchInt(move _3) -> [1: bb4, 2: bb5, otherwise: bb6];
+/// }
+/// ```
+///
+/// This is turned into a single multi-way switch, which codegen can lower to a jump table:
+///
+/// ```ignore (MIR)
+/// bb0: {
+///     _2 = discriminant(_1);
+///     switchInt(move _2) -> [0: bb1, 1: bb2, 2: bb5, otherwise: bb6];
+/// }
+/// ```
+///
+/// The values of the second switch that the first one already handles cannot reach it, so they
+/// are dropped. The second switch must be the only thing its block does, and the block must not
+/// be reachable in any other way.
+pub struct MergeSwitchChains;
+
+impl<'tcx> MirPass<'tcx> for MergeSwitchChains {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.mir_opt_level() >= 2
+    }
+
+    fn run_pass(&self, _: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        trace!("Running MergeSwitchChains on {:?}", body.source);
+
+        let mut merged = false;
+        for bb in body.basic_blocks.indices() {
+            // The new `otherwise` edge can lead to yet another switch on the same value.
+            while let Some(next) = chained_switch(body, bb) {
+                trace!("merging the switch of {:?} into the switch of {:?}", next, bb);
+                merge_switches(body, bb, next);
+                merged = true;
+            }
+        }
+
+        if merged {
+            // Remove the blocks of the merged switches, which are now unreachable.
+            simplify_cfg(body);
+        }
+    }
+}
+
+/// The value a `SwitchInt` switches on.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum SwitchedValue<'tcx> {
+    /// A copy of a place.
+    Place(Place<'tcx>),
+    /// The discriminant of a place, read into a temporary moved into the switch.
+    Discriminant(Place<'tcx>),
+}
+
+/// Returns what the `SwitchInt` terminating `data` switches on, if it is a value that we can
+/// tell is the same when switched on again.
+fn switched_value<'tcx>(data: &BasicBlockData<'tcx>) -> Option<SwitchedValue<'tcx>> {
+    let TerminatorKind::SwitchInt { discr, .. } = &data.terminator().kind else { return None };
+    match *discr {
+        Operand::Copy(place) => Some(SwitchedValue::Place(place)),
+        Operand::Move(place) => {
+            let local = place.as_local()?;
+            // The discriminant must be read by the last statement of the block, so that nothing
+            // can modify the place in between.
+            let last = data.statements.iter().rev().find(|statement| !is_storage(statement))?;
+            match &last.kind {
+                StatementKind::Assign(box (lhs, Rvalue::Discriminant(place)))
+                    if lhs.as_local() == Some(local) =>
+                {
+                    Some(SwitchedValue::Discriminant(*place))
+                }
+                _ => None,
+            }
+        }
+        Operand::Constant(_) => None,
+    }
+}
+
+fn is_storage(statement: &Statement<'_>) -> bool {
+    matches!(
+        statement.kind,
+        StatementKind::StorageLive(_) | StatementKind::StorageDead(_) | StatementKind::Nop
+    )
+}
+
+/// Returns the block that the `otherwise` edge of the switch terminating `bb` leads to, if that
+/// block only switches again on the same value.
+fn chained_switch(body: &Body<'_>, bb: BasicBlock) -> Option<BasicBlock> {
+    let data = &body.basic_blocks[bb];
+    let value = switched_value(data)?;
+    let TerminatorKind::SwitchInt { targets, .. } = &data.terminator().kind else { return None };
+
+    let next = targets.otherwise();
+    if next == bb || body.basic_blocks.predecessors()[next].iter().any(|&pred| pred != bb) {
+        return None;
+    }
+    let next_data = &body.basic_blocks[next];
+    if next_data.is_cleanup != data.is_cleanup || switched_value(next_data)? != value {
+        return None;
+    }
+
+    // Besides storage statements, the block may only read the discriminant it switches on.
+    let expected_statements = match value {
+        SwitchedValue::Place(_) => 0,
+        SwitchedValue::Discriminant(_) => 1,
+    };
+    let statements = next_data.statements.iter().filter(|statement| !is_storage(statement));
+    (statements.count() == expected_statements).then_some(next)
+}
+
+/// Merges the switch terminating `next` into the switch terminating `bb`, leaving `next`
+/// unreachable.
+fn merge_switches(body: &mut Body<'_>, bb: BasicBlock, next: BasicBlock) {
+    let TerminatorKind::SwitchInt { targets: next_targets, .. } =
+        &body.basic_blocks[next].terminator().kind
+    else {
+        bug!("expected a `SwitchInt` terminating {next:?}");
+    };
+    let next_targets = next_targets.clone();
+
+    let TerminatorKind::SwitchInt { targets, .. } =
+        &mut body.basic_blocks_mut()[bb].terminator_mut().kind
+    else {
+        bug!("expected a `SwitchInt` terminating {bb:?}");
+    };
+    let handled: FxHashSet<u128> = targets.iter().map(|(value, _)| value).collect();
+    let unhandled = next_targets.iter().filter(|(value, _)| !handled.contains(value));
+    *targets = SwitchTargets::new(targets.iter().chain(unhandled), next_targets.otherwise());
+}
@@ -0,0 +1,187 @@
+//! Moves the construction of the `fmt::Arguments` of panics out of the panicking function.
+//!
+//! `panic!("...")` and `panic!("{}", x)` are lowered to a call of `Arguments::new_const` or
+//! `Arguments::new_v1`, whose result is passed to `panic_fmt`:
+//!
+//! ```ignore (MIR)
+//! bb0: {
+//!     _2 = Arguments::<'_>::new_v1(move _3, move _4) -> [return: bb1, unwind continue];
+//! }
+//! bb1: {
+//!     _1 = panic_fmt(move _2) -> unwind continue;
+//! }
+//! ```
+//!
+//! Building the `Arguments` takes a stack slot and a couple of stores at every panic site. This
+//! pass replaces such sequences by a single call of a cold function of `core`, which builds the
+//! `Arguments` itself, so that this code only exists once:
+//!
+//! ```ignore (MIR)
+//! bb0: {
+//!     _1 = panic_fmt_v1(move _3, move _4) -> unwind continue;
+//! }
+//! ```
+//!
+//! Panics with a single literal piece call the `panic` lang item instead. Panics with formatting
+//! options, built by `Arguments::new_v1_formatted`, are left alone.
+
+use rustc_hir::def_id::DefId;
+use rustc_middle::mir::*;
+use rustc_middle::ty::adjustment::PointerCoercion;
+use rustc_middle::ty::{self, TyCtxt};
+use rustc_span::source_map::Spanned;
+use rustc_span::sym;
+
+pub struct OutlinePanics;
+
+impl<'tcx> MirPass<'tcx> for OutlinePanics {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.opts.unstable_opts.mir_outline_panics && sess.mir_opt_level() > 0
+    }
+
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        let lang_items = tcx.lang_items();
+        let (Some(panic_fmt), Some(format_arguments)) =
+            (lang_items.panic_fmt(), lang_items.format_arguments())
+        else {
+            return;
+        };
+
+        // The outlined functions panic the same way, don't make them call themselves.
+        let def_id = body.source.def_id();
+        if Some(def_id) == lang_items.panic_fn() || Some(def_id) == lang_items.panic_fmt_v1() {
+            return;
+        }
+
+        let outlined: Vec<_> = body
+            .basic_blocks
+            .indices()
+            .filter_map(|bb| {
+                let terminator = outline_panic(tcx, body, bb, panic_fmt, format_arguments)?;
+                Some((bb, terminator))
+            })
+            .collect();
+
+        // The blocks calling `panic_fmt` become unreachable, which `SimplifyCfg` cleans up.
+        let basic_blocks = body.basic_blocks_mut();
+        for (bb, terminator) in outlined {
+            *basic_blocks[bb].terminator_mut() = terminator;
+        }
+    }
+}
+
+/// If `bb` builds the `Arguments` of a panic, returns the terminator calling the outlined
+/// panic function instead.
+fn outline_panic<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    body: &Body<'tcx>,
+    bb: BasicBlock,
+    panic_fmt: DefId,
+    format_arguments: DefId,
+) -> Option<Terminator<'tcx>> {
+    let data = &body.basic_blocks[bb];
+    let TerminatorKind::Call { func, args, destination: arguments, target: Some(target), .. } =
+        &data.terminator().kind
+    else {
+        return None;
+    };
+    let ty::FnDef(constructor, _) = *func.ty(body, tcx).kind() else { return None };
+    let impl_def_id = tcx.impl_of_method(constructor)?;
+    let self_ty = tcx.type_of(impl_def_id).instantiate_identity();
+    if self_ty.ty_adt_def()?.did() != format_arguments {
+        return None;
+    }
+
+    // The `Arguments` must be passed straight to `panic_fmt`, which must not be reachable in
+    // any other way.
+    let arguments = arguments.as_local()?;
+    if body.basic_blocks.predecessors()[*target].len() != 1 {
+        return None;
+    }
+    let panic_data = &body.basic_blocks[*target];
+    if !panic_data.statements.iter().all(|statement| {
+        matches!(
+            statement.kind,
+            StatementKind::StorageLive(_) | StatementKind::StorageDead(_) | StatementKind::Nop
+        )
+    }) {
+        return None;
+    }
+    let panic_terminator = panic_data.terminator();
+    let TerminatorKind::Call {
+        func: panic_func,
+        args: panic_args,
+        destination,
+        target: None,
+        unwind,
+        call_source,
+        fn_span,
+    } = &panic_terminator.kind
+    else {
+        return None;
+    };
+    let ty::FnDef(panic_fn, _) = *panic_func.ty(body, tcx).kind() else { return None };
+    if panic_fn != panic_fmt
+        || !matches!(&panic_args[..], [arg] if arg.node.place() == Some(Place::from(arguments)))
+    {
+        return None;
+    }
+
+    let (outlined_fn, outlined_args) = match tcx.item_name(constructor) {
+        sym::new_v1 => (tcx.lang_items().panic_fmt_v1()?, args.clone()),
+        sym::new_const => {
+            // `panic` takes the only piece by itself.
+            let [pieces] = &args[..] else { return None };
+            let pieces_local = pieces.node.place()?.as_local()?;
+            if !has_single_piece(tcx, body, data, pieces_local) {
+                return None;
+            }
+            let piece = Place::from(pieces_local).project_deeper(
+                &[
+                    ProjectionElem::Deref,
+                    ProjectionElem::ConstantIndex { offset: 0, min_length: 1, from_end: false },
+                ],
+                tcx,
+            );
+            let arg = Spanned { node: Operand::Copy(piece), span: pieces.span };
+            (tcx.lang_items().panic_fn()?, vec![arg])
+        }
+        _ => return None,
+    };
+
+    Some(Terminator {
+        source_info: panic_terminator.source_info,
+        kind: TerminatorKind::Call {
+            func: Operand::function_handle(tcx, outlined_fn, [], *fn_span),
+            args: outlined_args,
+            destination: *destination,
+            target: None,
+            unwind: *unwind,
+            call_source: *call_source,
+            fn_span: *fn_span,
+        },
+    })
+}
+
+/// Whether `pieces` is assigned in `data` from an array with a single element.
+fn has_single_piece<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    body: &Body<'tcx>,
+    data: &BasicBlockData<'tcx>,
+    pieces: Local,
+) -> bool {
+    let assignment = data.statements.iter().rev().find_map(|statement| match &statement.kind {
+        StatementKind::Assign(box (place, rvalue)) if place.as_local() == Some(pieces) => {
+            Some(rvalue)
+        }
+        _ => None,
+    });
+    let Some(Rvalue::Cast(CastKind::PointerCoercion(PointerCoercion::Unsize), array, _)) =
+        assignment
+    else {
+        return false;
+    };
+    let Some(array_ty) = array.ty(body, tcx).builtin_deref(true) else { return false };
+    let ty::Array(_, len) = *array_ty.ty.kind() else { return false };
+    len.try_eval_target_usize(tcx, tcx.param_env(body.source.def_id())) == Some(1)
+}
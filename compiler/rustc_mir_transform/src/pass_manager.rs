@@ -1,8 +1,11 @@
-use rustc_middle::mir::{self, Body, MirPhase, RuntimePhase};
+use std::any::Any;
+
+use rustc_data_structures::sync::{DynSend, DynSync, Lrc};
+use rustc_middle::mir::{self, Body, MirPhase, MirProperties, RuntimePhase};
 use rustc_middle::ty::TyCtxt;
-use rustc_session::Session;
+use rustc_session::{MirPassStoreMarker, Session};
 
-use crate::{lint::lint_body, validate, MirPass};
+use crate::{add_call_guards, errors, lint::lint_body, validate, MirPass};
 
 /// Just like `MirPass`, except it cannot mutate `Body`.
 pub trait MirLint<'tcx> {
@@ -38,6 +41,10 @@ fn is_enabled(&self, sess: &Session) -> bool {
         self.0.is_enabled(sess)
     }
 
+    fn invalidates(&self) -> MirProperties {
+        MirProperties::empty()
+    }
+
     fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         self.0.run_lint(tcx, body)
     }
@@ -61,11 +68,108 @@ fn is_enabled(&self, sess: &Session) -> bool {
         sess.mir_opt_level() >= self.0 as usize
     }
 
+    fn requires(&self) -> MirProperties {
+        self.1.requires()
+    }
+
+    fn establishes(&self) -> MirProperties {
+        self.1.establishes()
+    }
+
+    fn invalidates(&self) -> MirProperties {
+        self.1.invalidates()
+    }
+
     fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         self.1.run_pass(tcx, body)
     }
 }
 
+/// A pass registered with [`MirPassStore::register_optimization_pass`].
+struct RegisteredPass {
+    after: Option<&'static str>,
+    pass: Box<dyn for<'tcx> MirPass<'tcx> + DynSend + DynSync>,
+}
+
+/// The MIR passes registered by the driver, through the `register_mir_passes` callback of
+/// `rustc_interface::Config`. The session holds it, see [`Session::mir_pass_store`].
+#[derive(Default)]
+pub struct MirPassStore {
+    registered: Vec<RegisteredPass>,
+}
+
+impl MirPassStoreMarker for MirPassStore {}
+
+impl MirPassStore {
+    /// Registers a pass to run with the optimizations of `optimized_mir`, right after the first
+    /// optimization named `after`, or after all of them if it is `None`. The pass can also be
+    /// named in `-Zmir-passes` and `-Zmir-enable-passes`.
+    ///
+    /// This is meant for drivers experimenting with passes out of tree.
+    pub fn register_optimization_pass(
+        &mut self,
+        after: Option<&'static str>,
+        pass: Box<dyn for<'tcx> MirPass<'tcx> + DynSend + DynSync>,
+    ) {
+        self.registered.push(RegisteredPass { after, pass });
+    }
+}
+
+/// Extract the [`MirPassStore`] from [`Session`].
+///
+/// This function exists because [`Session::mir_pass_store`] is type-erased.
+fn unerased_mir_pass_store(sess: &Session) -> &MirPassStore {
+    let store: &Lrc<_> = sess.mir_pass_store.as_ref().unwrap();
+    let store: &dyn Any = &**store;
+    store.downcast_ref().unwrap()
+}
+
+/// Runs the optimization `passes` on `body`, with the registered passes inserted among them, or,
+/// with `-Zmir-passes`, the passes it names instead, in its order.
+pub fn run_optimization_passes<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    body: &mut Body<'tcx>,
+    passes: &[&dyn MirPass<'tcx>],
+) {
+    let mut all_passes = passes.to_vec();
+    for RegisteredPass { after, pass } in &unerased_mir_pass_store(tcx.sess).registered {
+        let index = after
+            .and_then(|after| all_passes.iter().position(|p| p.name() == after))
+            .map_or(all_passes.len(), |index| index + 1);
+        all_passes.insert(index, &**pass);
+    }
+
+    let passes = match &tcx.sess.opts.unstable_opts.mir_passes {
+        Some(names) => names
+            .iter()
+            .map(|name| match all_passes.iter().find(|p| p.name() == name) {
+                Some(pass) => *pass,
+                None => tcx.dcx().emit_fatal(errors::UnknownMirPass { name: name.clone() }),
+            })
+            .collect(),
+        None => all_passes,
+    };
+    run_passes(tcx, body, &passes, Some(MirPhase::Runtime(RuntimePhase::Optimized)));
+}
+
+/// Returns the pass which establishes `property`.
+fn establishing_pass<'tcx>(property: MirProperties) -> &'static dyn MirPass<'tcx> {
+    match property {
+        MirProperties::NO_CRITICAL_CALL_EDGES => &add_call_guards::CriticalCallEdges,
+        _ => bug!("no pass establishes {property:?}"),
+    }
+}
+
+/// Returns the properties that the bodies must have in `phase`.
+fn phase_properties(phase: MirPhase) -> MirProperties {
+    match phase {
+        // Calls are codegened to instructions which terminate a block, like LLVM's `invoke`, so
+        // the code writing their return place has to go to a block of its own.
+        MirPhase::Runtime(RuntimePhase::Optimized) => MirProperties::NO_CRITICAL_CALL_EDGES,
+        MirPhase::Built | MirPhase::Analysis(_) | MirPhase::Runtime(_) => MirProperties::empty(),
+    }
+}
+
 /// Run the sequence of passes without validating the MIR after each pass. The MIR is still
 /// validated at the end.
 pub fn run_passes_no_validate<'tcx>(
@@ -116,45 +220,24 @@ fn run_passes_inner<'tcx>(
     let overridden_passes = &tcx.sess.opts.unstable_opts.mir_enable_passes;
     trace!(?overridden_passes);
 
-    let prof_arg = tcx.sess.prof.enabled().then(|| format!("{:?}", body.source.def_id()));
-
     if !body.should_skip() {
-        let validate = validate_each & tcx.sess.opts.unstable_opts.validate_mir;
-        let lint = tcx.sess.opts.unstable_opts.lint_mir;
+        let mut run = PassRun {
+            prof_arg: tcx.sess.prof.enabled().then(|| format!("{:?}", body.source.def_id())),
+            validate: validate_each & tcx.sess.opts.unstable_opts.validate_mir.is_enabled(),
+            lint: tcx.sess.opts.unstable_opts.lint_mir,
+            properties: MirProperties::empty(),
+        };
 
         for pass in passes {
-            let name = pass.name();
-
             if !should_run_pass(tcx, *pass) {
                 continue;
             };
 
-            let dump_enabled = pass.is_mir_dump_enabled();
-
-            if dump_enabled {
-                dump_mir_for_pass(tcx, body, name, false);
-            }
-
-            if let Some(prof_arg) = &prof_arg {
-                tcx.sess
-                    .prof
-                    .generic_activity_with_arg(pass.profiler_name(), &**prof_arg)
-                    .run(|| pass.run_pass(tcx, body));
-            } else {
-                pass.run_pass(tcx, body);
-            }
-
-            if dump_enabled {
-                dump_mir_for_pass(tcx, body, name, true);
-            }
-            if validate {
-                validate_body(tcx, body, format!("after pass {name}"));
-            }
-            if lint {
-                lint_body(tcx, body, format!("after pass {name}"));
-            }
+            run.run_pass(tcx, body, *pass);
+        }
 
-            body.pass_count += 1;
+        if let Some(new_phase) = phase_change {
+            run.establish(tcx, body, phase_properties(new_phase));
         }
     }
 
@@ -168,9 +251,10 @@ fn run_passes_inner<'tcx>(
 
         dump_mir_for_phase_change(tcx, body);
 
-        let validate =
-            (validate_each & tcx.sess.opts.unstable_opts.validate_mir & !body.should_skip())
-                || new_phase == MirPhase::Runtime(RuntimePhase::Optimized);
+        let validate = (validate_each
+            & tcx.sess.opts.unstable_opts.validate_mir.is_enabled()
+            & !body.should_skip())
+            || new_phase == MirPhase::Runtime(RuntimePhase::Optimized);
         let lint = tcx.sess.opts.unstable_opts.lint_mir & !body.should_skip();
         if validate {
             validate_body(tcx, body, format!("after phase change to {}", new_phase.name()));
@@ -192,6 +276,74 @@ fn run_passes_inner<'tcx>(
     }
 }
 
+/// The state of the pass manager while it runs passes on a body.
+struct PassRun {
+    prof_arg: Option<String>,
+    validate: bool,
+    lint: bool,
+    /// The properties of the body established by the passes run so far, and not invalidated
+    /// since.
+    properties: MirProperties,
+}
+
+impl PassRun {
+    /// Runs `pass` on `body`, after the passes establishing the properties it requires.
+    fn run_pass<'tcx>(
+        &mut self,
+        tcx: TyCtxt<'tcx>,
+        body: &mut Body<'tcx>,
+        pass: &dyn MirPass<'tcx>,
+    ) {
+        self.establish(tcx, body, pass.requires());
+
+        let name = pass.name();
+        let dump_enabled = pass.is_mir_dump_enabled();
+
+        if dump_enabled {
+            dump_mir_for_pass(tcx, body, name, false);
+        }
+
+        if let Some(prof_arg) = &self.prof_arg {
+            tcx.sess
+                .prof
+                .generic_activity_with_arg(pass.profiler_name(), &**prof_arg)
+                .run(|| pass.run_pass(tcx, body));
+        } else {
+            pass.run_pass(tcx, body);
+        }
+
+        if dump_enabled {
+            dump_mir_for_pass(tcx, body, name, true);
+        }
+        if self.validate {
+            validate_body(tcx, body, format!("after pass {name}"));
+        }
+        if self.lint {
+            lint_body(tcx, body, format!("after pass {name}"));
+        }
+
+        body.pass_count += 1;
+        self.properties = (self.properties - pass.invalidates()) | pass.establishes();
+    }
+
+    /// Runs the passes establishing the `required` properties which don't hold, even if they are
+    /// disabled.
+    fn establish<'tcx>(
+        &mut self,
+        tcx: TyCtxt<'tcx>,
+        body: &mut Body<'tcx>,
+        required: MirProperties,
+    ) {
+        for property in (required - self.properties).iter() {
+            // A pass run for an earlier property may have established this one too.
+            if !self.properties.contains(property) {
+                trace!(?property, "establishing required property");
+                self.run_pass(tcx, body, establishing_pass(property));
+            }
+        }
+    }
+}
+
 pub fn validate_body<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>, when: String) {
     validate::Validator { when, mir_phase: body.phase }.run_pass(tcx, body);
 }
@@ -21,6 +21,11 @@ fn is_enabled(&self, _session: &Session) -> bool {
         false
     }
 
+    // Permuting the blocks or the locals keeps the edges between blocks as they are.
+    fn invalidates(&self) -> MirProperties {
+        MirProperties::all() - MirProperties::CFG
+    }
+
     fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         let rpo: IndexVec<BasicBlock, BasicBlock> =
             body.basic_blocks.reverse_postorder().iter().copied().collect();
@@ -49,6 +54,11 @@ fn is_enabled(&self, _session: &Session) -> bool {
         false
     }
 
+    // Permuting the blocks or the locals keeps the edges between blocks as they are.
+    fn invalidates(&self) -> MirProperties {
+        MirProperties::all() - MirProperties::CFG
+    }
+
     fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         let mut finder =
             LocalFinder { map: IndexVec::new(), seen: BitSet::new_empty(body.local_decls.len()) };
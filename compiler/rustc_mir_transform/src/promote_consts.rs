@@ -25,10 +25,14 @@
 use rustc_span::source_map::Spanned;
 
 use std::assert_matches::assert_matches;
+use std::borrow::Cow;
 use std::cell::Cell;
 use std::{cmp, iter, mem};
 
 use rustc_const_eval::transform::check_consts::{qualifs, ConstCx};
+use rustc_errors::{DiagnosticArgValue, IntoDiagnosticArg};
+
+use crate::errors;
 
 /// A `MirPass` for promotion.
 ///
@@ -58,7 +62,14 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         let ccx = ConstCx::new(tcx, body);
         let (mut temps, all_candidates) = collect_temps_and_candidates(&ccx);
 
-        let promotable_candidates = validate_candidates(&ccx, &mut temps, &all_candidates);
+        let candidates = validate_candidates(&ccx, &mut temps, &all_candidates);
+        if tcx.sess.opts.unstable_opts.explain_promotion {
+            explain_promotion(&ccx, &candidates);
+        }
+        let promotable_candidates = candidates
+            .into_iter()
+            .filter_map(|(candidate, promotable)| promotable.is_ok().then_some(candidate))
+            .collect();
 
         let promoted = promote_candidates(body, tcx, temps, promotable_candidates);
         self.promoted_fragments.set(promoted);
@@ -175,6 +186,8 @@ fn collect_temps_and_candidates<'tcx>(
 struct Validator<'a, 'tcx> {
     ccx: &'a ConstCx<'a, 'tcx>,
     temps: &'a mut IndexSlice<Local, TempState>,
+    /// Why the temps we found to be `TempState::Unpromotable` cannot be promoted.
+    unpromotable: IndexVec<Local, Option<Unpromotable>>,
 }
 
 impl<'a, 'tcx> std::ops::Deref for Validator<'a, 'tcx> {
@@ -185,7 +198,84 @@ fn deref(&self) -> &Self::Target {
     }
 }
 
-struct Unpromotable;
+/// Why a candidate, or a temp it depends on, cannot be promoted.
+///
+/// A temp cannot be promoted if the value assigned to it cannot be, which may in turn be because
+/// of another temp, and so on: this forms a tree whose leaf is the root cause.
+#[derive(Clone, Debug)]
+enum Unpromotable {
+    /// The value assigned to this temp cannot be promoted.
+    Temp(Local, Box<Unpromotable>),
+    Reason(UnpromotableReason),
+}
+
+impl From<UnpromotableReason> for Unpromotable {
+    fn from(reason: UnpromotableReason) -> Self {
+        Unpromotable::Reason(reason)
+    }
+}
+
+/// The root cause of an `Unpromotable`.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum UnpromotableReason {
+    /// It is a variable or argument, not a temp.
+    NotATemp,
+    /// It is a temp that is not assigned exactly once, or used in an unsupported way.
+    NotAssignedOnce,
+    NeedsDrop,
+    HasMutInterior,
+    /// Mutable borrows are only promoted for `&mut []`.
+    MutBorrow,
+    /// Fake borrows and closure captures.
+    BorrowKind,
+    Deref,
+    /// Downcasts to enum variants and casts of opaque types.
+    Downcast,
+    /// Indexing with anything but an in-bounds constant.
+    Index,
+    UnionField,
+    /// Accessing a static outside of a static.
+    Static,
+    ThreadLocal,
+    Yield,
+    PointerExposeAddress,
+    /// Operations on raw pointers and function pointers.
+    PointerOp,
+    /// Division by anything but a non-zero constant.
+    Division,
+    Box,
+    RawPointer,
+    NonConstFn,
+    /// Calls to `const fn`s without `#[rustc_promotable]` outside of consts and statics.
+    NotRustcPromotable,
+}
+
+impl IntoDiagnosticArg for UnpromotableReason {
+    fn into_diagnostic_arg(self) -> DiagnosticArgValue {
+        DiagnosticArgValue::Str(Cow::Borrowed(match self {
+            UnpromotableReason::NotATemp => "not_a_temp",
+            UnpromotableReason::NotAssignedOnce => "not_assigned_once",
+            UnpromotableReason::NeedsDrop => "needs_drop",
+            UnpromotableReason::HasMutInterior => "has_mut_interior",
+            UnpromotableReason::MutBorrow => "mut_borrow",
+            UnpromotableReason::BorrowKind => "borrow_kind",
+            UnpromotableReason::Deref => "deref",
+            UnpromotableReason::Downcast => "downcast",
+            UnpromotableReason::Index => "index",
+            UnpromotableReason::UnionField => "union_field",
+            UnpromotableReason::Static => "static",
+            UnpromotableReason::ThreadLocal => "thread_local",
+            UnpromotableReason::Yield => "yield",
+            UnpromotableReason::PointerExposeAddress => "pointer_expose_address",
+            UnpromotableReason::PointerOp => "pointer_op",
+            UnpromotableReason::Division => "division",
+            UnpromotableReason::Box => "box",
+            UnpromotableReason::RawPointer => "raw_pointer",
+            UnpromotableReason::NonConstFn => "non_const_fn",
+            UnpromotableReason::NotRustcPromotable => "not_rustc_promotable",
+        }))
+    }
+}
 
 impl<'tcx> Validator<'_, 'tcx> {
     fn validate_candidate(&mut self, candidate: Candidate) -> Result<(), Unpromotable> {
@@ -203,7 +293,7 @@ fn validate_candidate(&mut self, candidate: Candidate) -> Result<(), Unpromotabl
         // We do not check all the projections (they do not get promoted anyway),
         // but we do stay away from promoting anything involving a dereference.
         if place.projection.contains(&ProjectionElem::Deref) {
-            return Err(Unpromotable);
+            return Err(UnpromotableReason::Deref.into());
         }
 
         Ok(())
@@ -232,14 +322,21 @@ fn qualif_local<Q: qualifs::Qualif>(&mut self, local: Local) -> bool {
     }
 
     fn validate_local(&mut self, local: Local) -> Result<(), Unpromotable> {
-        let TempState::Defined { location: loc, uses, valid } = self.temps[local] else {
-            return Err(Unpromotable);
+        let (loc, uses, valid) = match self.temps[local] {
+            TempState::Defined { location, uses, valid } => (location, uses, valid),
+            TempState::Undefined => return Err(UnpromotableReason::NotATemp.into()),
+            TempState::Unpromotable | TempState::PromotedOut => {
+                return Err(match &self.unpromotable[local] {
+                    Some(unpromotable) => unpromotable.clone(),
+                    None => UnpromotableReason::NotAssignedOnce.into(),
+                });
+            }
         };
 
         // We cannot promote things that need dropping, since the promoted value would not get
         // dropped.
         if self.qualif_local::<qualifs::NeedsDrop>(local) {
-            return Err(Unpromotable);
+            return Err(Unpromotable::Temp(local, Box::new(UnpromotableReason::NeedsDrop.into())));
         }
 
         if valid.is_ok() {
@@ -261,7 +358,7 @@ fn validate_local(&mut self, local: Local) -> Result<(), Unpromotable> {
                 }
                 Right(terminator) => match &terminator.kind {
                     TerminatorKind::Call { func, args, .. } => self.validate_call(func, args),
-                    TerminatorKind::Yield { .. } => Err(Unpromotable),
+                    TerminatorKind::Yield { .. } => Err(UnpromotableReason::Yield.into()),
                     kind => {
                         span_bug!(terminator.source_info.span, "{:?} not promotable", kind);
                     }
@@ -269,9 +366,13 @@ fn validate_local(&mut self, local: Local) -> Result<(), Unpromotable> {
             }
         };
 
-        self.temps[local] = match ok {
+        let ok = ok.map_err(|unpromotable| Unpromotable::Temp(local, Box::new(unpromotable)));
+        self.temps[local] = match &ok {
             Ok(()) => TempState::Defined { location: loc, uses, valid: Ok(()) },
-            Err(_) => TempState::Unpromotable,
+            Err(unpromotable) => {
+                self.unpromotable[local] = Some(unpromotable.clone());
+                TempState::Unpromotable
+            }
         };
 
         ok
@@ -291,7 +392,7 @@ fn validate_place(&mut self, place: PlaceRef<'tcx>) -> Result<(), Unpromotable>
 
             // Never recurse.
             ProjectionElem::OpaqueCast(..) | ProjectionElem::Downcast(..) => {
-                return Err(Unpromotable);
+                return Err(UnpromotableReason::Downcast.into());
             }
 
             ProjectionElem::Deref => {
@@ -315,7 +416,7 @@ fn validate_place(&mut self, place: PlaceRef<'tcx>) -> Result<(), Unpromotable>
                 {
                     // Recurse.
                 } else {
-                    return Err(Unpromotable);
+                    return Err(UnpromotableReason::Deref.into());
                 }
             }
             ProjectionElem::Index(local) => {
@@ -334,7 +435,7 @@ fn validate_place(&mut self, place: PlaceRef<'tcx>) -> Result<(), Unpromotable>
                     self.validate_local(local)?;
                     // Recurse.
                 } else {
-                    return Err(Unpromotable);
+                    return Err(UnpromotableReason::Index.into());
                 }
             }
 
@@ -342,7 +443,7 @@ fn validate_place(&mut self, place: PlaceRef<'tcx>) -> Result<(), Unpromotable>
                 let base_ty = place_base.ty(self.body, self.tcx).ty;
                 if base_ty.is_union() {
                     // No promotion of union field accesses.
-                    return Err(Unpromotable);
+                    return Err(UnpromotableReason::UnionField.into());
                 }
             }
         }
@@ -366,12 +467,12 @@ fn validate_operand(&mut self, operand: &Operand<'tcx>) -> Result<(), Unpromotab
                     // promotion inside statics as well.
                     let is_static = matches!(self.const_kind, Some(hir::ConstContext::Static(_)));
                     if !is_static {
-                        return Err(Unpromotable);
+                        return Err(UnpromotableReason::Static.into());
                     }
 
                     let is_thread_local = self.tcx.is_thread_local_static(def_id);
                     if is_thread_local {
-                        return Err(Unpromotable);
+                        return Err(UnpromotableReason::ThreadLocal.into());
                     }
                 }
 
@@ -385,13 +486,13 @@ fn validate_ref(&mut self, kind: BorrowKind, place: &Place<'tcx>) -> Result<(),
             // Reject these borrow types just to be safe.
             // FIXME(RalfJung): could we allow them? Should we? No point in it until we have a usecase.
             BorrowKind::Fake | BorrowKind::Mut { kind: MutBorrowKind::ClosureCapture } => {
-                return Err(Unpromotable);
+                return Err(UnpromotableReason::BorrowKind.into());
             }
 
             BorrowKind::Shared => {
                 let has_mut_interior = self.qualif_local::<qualifs::HasMutInterior>(place.local);
                 if has_mut_interior {
-                    return Err(Unpromotable);
+                    return Err(UnpromotableReason::HasMutInterior.into());
                 }
             }
 
@@ -406,10 +507,10 @@ fn validate_ref(&mut self, kind: BorrowKind, place: &Place<'tcx>) -> Result<(),
                 if let ty::Array(_, len) = ty.kind() {
                     match len.try_eval_target_usize(self.tcx, self.param_env) {
                         Some(0) => {}
-                        _ => return Err(Unpromotable),
+                        _ => return Err(UnpromotableReason::MutBorrow.into()),
                     }
                 } else {
-                    return Err(Unpromotable);
+                    return Err(UnpromotableReason::MutBorrow.into());
                 }
             }
         }
@@ -431,10 +532,12 @@ fn validate_rvalue(&mut self, rvalue: &Rvalue<'tcx>) -> Result<(), Unpromotable>
                 self.validate_place(place.as_ref())?
             }
 
-            Rvalue::ThreadLocalRef(_) => return Err(Unpromotable),
+            Rvalue::ThreadLocalRef(_) => return Err(UnpromotableReason::ThreadLocal.into()),
 
             // ptr-to-int casts are not possible in consts and thus not promotable
-            Rvalue::Cast(CastKind::PointerExposeAddress, _, _) => return Err(Unpromotable),
+            Rvalue::Cast(CastKind::PointerExposeAddress, _, _) => {
+                return Err(UnpromotableReason::PointerExposeAddress.into());
+            }
 
             // all other casts including int-to-ptr casts are fine, they just use the integer value
             // at pointer type.
@@ -449,7 +552,7 @@ fn validate_rvalue(&mut self, rvalue: &Rvalue<'tcx>) -> Result<(), Unpromotable>
                 NullOp::DebugAssertions => {}
             },
 
-            Rvalue::ShallowInitBox(_, _) => return Err(Unpromotable),
+            Rvalue::ShallowInitBox(_, _) => return Err(UnpromotableReason::Box.into()),
 
             Rvalue::UnaryOp(op, operand) => {
                 match op {
@@ -476,7 +579,7 @@ fn validate_rvalue(&mut self, rvalue: &Rvalue<'tcx>) -> Result<(), Unpromotable>
                             | BinOp::Gt
                             | BinOp::Offset
                     ));
-                    return Err(Unpromotable);
+                    return Err(UnpromotableReason::PointerOp.into());
                 }
 
                 match op {
@@ -490,8 +593,9 @@ fn validate_rvalue(&mut self, rvalue: &Rvalue<'tcx>) -> Result<(), Unpromotable>
                                 _ => None,
                             };
                             match const_val {
-                                Some(x) if x != 0 => {}        // okay
-                                _ => return Err(Unpromotable), // value not known or 0 -- not okay
+                                Some(x) if x != 0 => {} // okay
+                                // value not known or 0 -- not okay
+                                _ => return Err(UnpromotableReason::Division.into()),
                             }
                         }
                     }
@@ -532,7 +636,7 @@ fn validate_rvalue(&mut self, rvalue: &Rvalue<'tcx>) -> Result<(), Unpromotable>
                         return self.validate_place(place_base);
                     }
                 }
-                return Err(Unpromotable);
+                return Err(UnpromotableReason::RawPointer.into());
             }
 
             Rvalue::Ref(_, kind, place) => {
@@ -582,7 +686,7 @@ fn validate_call(
                 // Never promote runtime `const fn` calls of
                 // functions without `#[rustc_promotable]`.
                 if !self.tcx.is_promotable_const_fn(def_id) {
-                    return Err(Unpromotable);
+                    return Err(UnpromotableReason::NotRustcPromotable.into());
                 }
             }
         }
@@ -592,7 +696,7 @@ fn validate_call(
             _ => false,
         };
         if !is_const_fn {
-            return Err(Unpromotable);
+            return Err(UnpromotableReason::NonConstFn.into());
         }
 
         self.validate_operand(callee)?;
@@ -605,20 +709,49 @@ fn validate_call(
 }
 
 // FIXME(eddyb) remove the differences for promotability in `static`, `const`, `const fn`.
+/// Decides which candidates can be promoted, and why the others cannot be.
 fn validate_candidates(
     ccx: &ConstCx<'_, '_>,
     temps: &mut IndexSlice<Local, TempState>,
     candidates: &[Candidate],
-) -> Vec<Candidate> {
-    let mut validator = Validator { ccx, temps };
+) -> Vec<(Candidate, Result<(), Unpromotable>)> {
+    let unpromotable = IndexVec::from_elem(None, &ccx.body.local_decls);
+    let mut validator = Validator { ccx, temps, unpromotable };
 
     candidates
         .iter()
-        .copied()
-        .filter(|&candidate| validator.validate_candidate(candidate).is_ok())
+        .map(|&candidate| (candidate, validator.validate_candidate(candidate)))
         .collect()
 }
 
+/// Explains for each candidate whether it gets promoted, and if not, why (`-Zexplain-promotion`).
+fn explain_promotion(ccx: &ConstCx<'_, '_>, candidates: &[(Candidate, Result<(), Unpromotable>)]) {
+    let dcx = ccx.tcx.dcx();
+    for (candidate, promotable) in candidates {
+        let span = ccx.body.source_info(candidate.location).span;
+        let Err(mut unpromotable) = promotable.as_ref() else {
+            dcx.emit_note(errors::Promoted { span });
+            continue;
+        };
+        let mut depends_on = vec![];
+        let reason = loop {
+            match unpromotable {
+                Unpromotable::Temp(local, inner) => {
+                    let span = ccx.body.local_decls[*local].source_info.span;
+                    depends_on.push(errors::NotPromotedDependsOn { span });
+                    unpromotable = inner;
+                }
+                Unpromotable::Reason(reason) => break *reason,
+            }
+        };
+        dcx.emit_note(errors::NotPromoted {
+            span,
+            depends_on,
+            reason: errors::NotPromotedReason { reason },
+        });
+    }
+}
+
 struct Promoter<'a, 'tcx> {
     tcx: TyCtxt<'tcx>,
     source: &'a mut Body<'tcx>,
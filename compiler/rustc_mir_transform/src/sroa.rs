@@ -50,7 +50,8 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
 /// Identify all locals that are not eligible for SROA.
 ///
 /// There are 3 cases:
-/// - the aggregated local is used or passed to other code (function parameters and arguments);
+/// - the aggregated local is used or passed to other code (the return place, arguments that are
+///   used as a whole, or all arguments if the start block can be jumped back to);
 /// - the locals is a union or an enum;
 /// - the local's address is taken, and thus the relative addresses of the fields are observable to
 ///   client code.
@@ -90,7 +91,16 @@ fn escaping_locals<'tcx>(
     };
 
     let mut set = BitSet::new_empty(body.local_decls.len());
-    set.insert_range(RETURN_PLACE..=Local::from_usize(body.arg_count));
+    set.insert(RETURN_PLACE);
+    // Arguments are split by moving their fields out at the start of the body, which must only
+    // happen once.
+    if !body.basic_blocks.predecessors()[START_BLOCK].is_empty() {
+        set.insert_range(RETURN_PLACE..=Local::from_usize(body.arg_count));
+    }
+    // The tupled arguments of "rust-call" functions are untupled by codegen.
+    if let Some(spread_arg) = body.spread_arg {
+        set.insert(spread_arg);
+    }
     for (local, decl) in body.local_decls().iter_enumerated() {
         if excluded.contains(local) || is_excluded_ty(decl.ty) {
             set.insert(local);
@@ -230,12 +240,30 @@ fn replace_flattened_locals<'tcx>(
         return all_dead_locals;
     }
 
+    // Split arguments keep their value on entry, so they are not dead: their fields are moved
+    // into the replacement locals before anything else happens.
+    let mut patch = MirPatch::new(body);
+    let mut split_args = BitSet::new_empty(replacements.fragments.len());
+    for arg in body.args_iter() {
+        let Some(final_locals) = replacements.place_fragments(arg.into()) else { continue };
+        for (field, ty, new_local) in final_locals {
+            let rplace = tcx.mk_place_field(arg.into(), field, ty);
+            let rvalue = Rvalue::Use(Operand::Move(rplace));
+            patch.add_statement(
+                START_BLOCK.start_location(),
+                StatementKind::Assign(Box::new((new_local.into(), rvalue))),
+            );
+        }
+        all_dead_locals.remove(arg);
+        split_args.insert(arg);
+    }
+
     let mut visitor = ReplacementVisitor {
         tcx,
         local_decls: &body.local_decls,
         replacements: &replacements,
         all_dead_locals,
-        patch: MirPatch::new(body),
+        patch,
     };
     for (bb, data) in body.basic_blocks.as_mut_preserves_cfg().iter_enumerated_mut() {
         visitor.visit_basic_block_data(bb, data);
@@ -247,8 +275,10 @@ fn replace_flattened_locals<'tcx>(
         visitor.visit_user_type_annotation(index, annotation);
     }
     visitor.expand_var_debug_info(&mut body.var_debug_info);
-    let ReplacementVisitor { patch, all_dead_locals, .. } = visitor;
+    let ReplacementVisitor { patch, mut all_dead_locals, .. } = visitor;
     patch.apply(body);
+    // Split arguments must not be split again.
+    all_dead_locals.union(&split_args);
     all_dead_locals
 }
 
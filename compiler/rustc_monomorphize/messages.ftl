@@ -4,6 +4,9 @@ monomorphize_consider_type_length_limit =
 monomorphize_couldnt_dump_mono_stats =
     unexpected error occurred while dumping monomorphization stats: {$error}
 
+monomorphize_couldnt_dump_vtable_layouts =
+    unexpected error occurred while dumping vtable layouts: {$error}
+
 monomorphize_encountered_error_while_instantiating =
     the above error was encountered while instantiating `{$formatted_item}`
 
@@ -164,7 +164,7 @@
 //! this is not implemented however: a mono item will be produced
 //! regardless of whether it is actually needed or not.
 
-use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexSet};
 use rustc_data_structures::sync::{par_for_each_in, MTLock, MTLockRef};
 use rustc_hir as hir;
 use rustc_hir::def::DefKind;
@@ -296,6 +296,7 @@ pub fn collect_crate_mono_items(
 #[instrument(skip(tcx, mode), level = "debug")]
 fn collect_roots(tcx: TyCtxt<'_>, mode: MonoItemCollectionMode) -> Vec<MonoItem<'_>> {
     debug!("collecting roots");
+
     let mut roots = Vec::new();
 
     {
@@ -846,7 +847,8 @@ fn visit_terminator(&mut self, terminator: &mir::Terminator<'tcx>, location: Loc
         };
 
         match terminator.kind {
-            mir::TerminatorKind::Call { ref func, ref args, ref fn_span, .. } => {
+            mir::TerminatorKind::Call { ref func, ref args, ref fn_span, .. }
+            | mir::TerminatorKind::TailCall { ref func, ref args, ref fn_span } => {
                 let callee_ty = func.ty(self.body, tcx);
                 let callee_ty = self.monomorphize(callee_ty);
                 self.check_fn_args_move_size(callee_ty, args, *fn_span, location);
@@ -1501,6 +1503,61 @@ fn collect_used_items<'tcx>(
     .visit_body(body);
 }
 
+/// Finds the vtables that the given mono items construct through unsizing casts,
+/// as `(concrete type, trait object type)` pairs in first-encountered order. This
+/// mirrors the vtable handling in `MirUsedCollector::visit_rvalue` and is only
+/// used to dump vtable layouts, so it does not look into constants.
+pub fn collect_vtables<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    items: impl Iterator<Item = MonoItem<'tcx>>,
+) -> FxIndexSet<(Ty<'tcx>, Ty<'tcx>)> {
+    let mut vtables = FxIndexSet::default();
+    for item in items {
+        let MonoItem::Fn(instance) = item else { continue };
+        let body = tcx.instance_mir(instance.def);
+        VtableCollector { tcx, body, instance, vtables: &mut vtables }.visit_body(body);
+    }
+    vtables
+}
+
+struct VtableCollector<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    body: &'a mir::Body<'tcx>,
+    instance: Instance<'tcx>,
+    vtables: &'a mut FxIndexSet<(Ty<'tcx>, Ty<'tcx>)>,
+}
+
+impl<'a, 'tcx> MirVisitor<'tcx> for VtableCollector<'a, 'tcx> {
+    fn visit_rvalue(&mut self, rvalue: &mir::Rvalue<'tcx>, location: Location) {
+        if let mir::Rvalue::Cast(
+            mir::CastKind::PointerCoercion(PointerCoercion::Unsize) | mir::CastKind::DynStar,
+            ref operand,
+            target_ty,
+        ) = *rvalue
+        {
+            let span = self.body.source_info(location).span;
+            let monomorphize = |ty| {
+                self.instance.instantiate_mir_and_normalize_erasing_regions(
+                    self.tcx,
+                    ty::ParamEnv::reveal_all(),
+                    ty::EarlyBinder::bind(ty),
+                )
+            };
+            let target_ty = monomorphize(target_ty);
+            let source_ty = monomorphize(operand.ty(self.body, self.tcx));
+            let (source_ty, target_ty) =
+                find_vtable_types_for_unsizing(self.tcx.at(span), source_ty, target_ty);
+            if (target_ty.is_trait() && !source_ty.is_trait())
+                || (target_ty.is_dyn_star() && !source_ty.is_dyn_star())
+            {
+                self.vtables.insert((source_ty, target_ty));
+            }
+        }
+
+        self.super_rvalue(rvalue, location);
+    }
+}
+
 #[instrument(skip(tcx, output), level = "debug")]
 fn collect_const_value<'tcx>(
     tcx: TyCtxt<'tcx>,
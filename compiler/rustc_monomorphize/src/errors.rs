@@ -86,6 +86,12 @@ pub struct CouldntDumpMonoStats {
     pub error: String,
 }
 
+#[derive(Diagnostic)]
+#[diag(monomorphize_couldnt_dump_vtable_layouts)]
+pub struct CouldntDumpVtableLayouts {
+    pub error: String,
+}
+
 #[derive(Diagnostic)]
 #[diag(monomorphize_encountered_error_while_instantiating)]
 pub struct EncounteredErrorWhileInstantiating {
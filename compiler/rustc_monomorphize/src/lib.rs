@@ -1,4 +1,5 @@
 #![feature(array_windows)]
+#![feature(box_patterns)]
 #![feature(is_sorted)]
 #![allow(rustc::potential_query_instability)]
 
@@ -96,9 +96,10 @@
 use std::collections::hash_map::Entry;
 use std::fs::{self, File};
 use std::io::{BufWriter, Write};
+use std::ops::ControlFlow;
 use std::path::{Path, PathBuf};
 
-use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap};
 use rustc_data_structures::sync;
 use rustc_hir::def::DefKind;
 use rustc_hir::def_id::{DefId, DefIdSet, LOCAL_CRATE};
@@ -115,10 +116,15 @@
 use rustc_session::config::{DumpMonoStatsFormat, SwitchWithOptPath};
 use rustc_session::CodegenUnits;
 use rustc_span::symbol::Symbol;
+use rustc_trait_selection::traits::vtable::{prepare_vtable_segments, VtblSegment};
 
 use crate::collector::UsageMap;
 use crate::collector::{self, MonoItemCollectionMode};
-use crate::errors::{CouldntDumpMonoStats, SymbolAlreadyDefined, UnknownCguCollectionMode};
+use crate::errors::{
+    CouldntDumpMonoStats, CouldntDumpVtableLayouts, SymbolAlreadyDefined, UnknownCguCollectionMode,
+};
+
+mod dedup;
 
 struct PartitioningCx<'a, 'tcx> {
     tcx: TyCtxt<'tcx>,
@@ -165,6 +171,15 @@ fn partition<'tcx, I>(
         debug_dump(tcx, "MERGE", &codegen_units);
     }
 
+    // Turn structurally identical instantiations into aliases of a single
+    // definition. This moves items between CGUs, so it has to happen before
+    // internalization.
+    if tcx.sess.opts.unstable_opts.dedup_mono_items {
+        let _prof_timer = tcx.prof.generic_activity("cgu_partitioning_dedup_mono_items");
+        dedup::deduplicate_mono_items(cx, &mut codegen_units);
+        debug_dump(tcx, "DEDUP", &codegen_units);
+    }
+
     // Make as many symbols "internal" as possible, so LLVM has more freedom to
     // optimize.
     if !tcx.sess.link_dead_code() {
@@ -1007,7 +1022,13 @@ fn debug_dump<'a, 'tcx: 'a>(tcx: TyCtxt<'tcx>, label: &str, cgus: &[CodegenUnit<
                 let symbol_name = item.symbol_name(tcx).name;
                 let symbol_hash_start = symbol_name.rfind('h');
                 let symbol_hash = symbol_hash_start.map_or("<no hash>", |i| &symbol_name[i..]);
-                let kind = if !data.inlined { "root" } else { "inlined" };
+                let kind = if cgu.alias_of(item).is_some() {
+                    "alias"
+                } else if !data.inlined {
+                    "root"
+                } else {
+                    "inlined"
+                };
                 let size = data.size_estimate;
                 let _ = with_no_trimmed_paths!(writeln!(
                     s,
@@ -1156,6 +1177,15 @@ fn collect_and_partition_mono_items(tcx: TyCtxt<'_>, (): ()) -> (&DefIdSet, &[Co
         }
     }
 
+    // Output the layout of every vtable built by this crate
+    if let SwitchWithOptPath::Enabled(ref path) = tcx.sess.opts.unstable_opts.dump_vtable_layouts {
+        if let Err(err) =
+            dump_vtable_layouts(tcx, items.iter().copied(), path, tcx.crate_name(LOCAL_CRATE))
+        {
+            tcx.dcx().emit_fatal(CouldntDumpVtableLayouts { error: err.to_string() });
+        }
+    }
+
     if tcx.sess.opts.unstable_opts.print_mono_items.is_some() {
         let mut item_to_cgus: FxHashMap<_, Vec<_>> = Default::default();
 
@@ -1288,6 +1318,136 @@ struct MonoItem {
     Ok(())
 }
 
+/// Outputs the entries of every vtable constructed by the given mono items as JSON,
+/// including the vptr slots used for upcasting and the slot at which the methods of
+/// each (super)trait start, to a file in the given output directory.
+fn dump_vtable_layouts<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    mono_items: impl Iterator<Item = MonoItem<'tcx>>,
+    output_directory: &Option<PathBuf>,
+    crate_name: Symbol,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let output_directory = if let Some(ref directory) = output_directory {
+        fs::create_dir_all(directory)?;
+        directory
+    } else {
+        Path::new(".")
+    };
+
+    let filename = format!("{crate_name}.vtable_layouts.json");
+    let output_path = output_directory.join(&filename);
+    let file = File::create(&output_path)?;
+    let file = BufWriter::new(file);
+
+    #[derive(serde::Serialize)]
+    struct VtableEntry {
+        slot: usize,
+        kind: &'static str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        item: Option<String>,
+    }
+
+    #[derive(serde::Serialize)]
+    struct TraitSlots {
+        name: String,
+        first_method_slot: Option<usize>,
+        vptr_slot: Option<usize>,
+    }
+
+    #[derive(serde::Serialize)]
+    struct VtableLayout {
+        trait_object: String,
+        concrete_type: String,
+        entries: Vec<VtableEntry>,
+        traits: Vec<TraitSlots>,
+    }
+
+    let mut layouts: Vec<_> = collector::collect_vtables(tcx, mono_items)
+        .into_iter()
+        .map(|(concrete_ty, trait_object_ty)| {
+            let principal = match trait_object_ty.kind() {
+                ty::Dynamic(data, ..) => data.principal(),
+                _ => bug!("expected a trait object type, found `{trait_object_ty}`"),
+            };
+            let vtable_entries = match principal {
+                Some(principal) => tcx.vtable_entries(principal.with_self_ty(tcx, concrete_ty)),
+                None => TyCtxt::COMMON_VTABLE_ENTRIES,
+            };
+
+            // The trait owning each method slot, following the segments the vtable is built from.
+            // The instances in the slots can't tell, as those of `Fn*` vtables are closure bodies
+            // and shims rather than trait methods.
+            let mut slot_traits = vec![None; vtable_entries.len()];
+            if let Some(principal) = principal {
+                let mut slot = TyCtxt::COMMON_VTABLE_ENTRIES.len();
+                let trait_ref = principal.with_self_ty(tcx, concrete_ty);
+                prepare_vtable_segments::<()>(tcx, trait_ref, |segment| {
+                    if let VtblSegment::TraitOwnEntries { trait_ref, emit_vptr } = segment {
+                        let own = tcx.own_existential_vtable_entries(trait_ref.def_id()).len();
+                        slot_traits[slot..slot + own].fill(Some(trait_ref.def_id()));
+                        slot += own + emit_vptr as usize;
+                    }
+                    ControlFlow::Continue(())
+                });
+            }
+
+            let mut traits: FxIndexMap<DefId, TraitSlots> = Default::default();
+            let new_trait_slots = |def_id| TraitSlots {
+                name: with_no_trimmed_paths!(tcx.def_path_str(def_id)),
+                first_method_slot: None,
+                vptr_slot: None,
+            };
+
+            let entries = vtable_entries
+                .iter()
+                .enumerate()
+                .map(|(slot, entry)| {
+                    let (kind, item) = match *entry {
+                        ty::VtblEntry::MetadataDropInPlace => ("drop_in_place", None),
+                        ty::VtblEntry::MetadataSize => ("size", None),
+                        ty::VtblEntry::MetadataAlign => ("align", None),
+                        ty::VtblEntry::Vacant => ("vacant", None),
+                        ty::VtblEntry::Method(instance) => {
+                            let Some(trait_def_id) = slot_traits[slot] else {
+                                bug!("vtable slot {slot} of `{trait_object_ty}` has no trait")
+                            };
+                            traits
+                                .entry(trait_def_id)
+                                .or_insert_with(|| new_trait_slots(trait_def_id))
+                                .first_method_slot
+                                .get_or_insert(slot);
+                            ("method", Some(with_no_trimmed_paths!(instance.to_string())))
+                        }
+                        ty::VtblEntry::TraitVPtr(trait_ref) => {
+                            let trait_def_id = trait_ref.def_id();
+                            traits
+                                .entry(trait_def_id)
+                                .or_insert_with(|| new_trait_slots(trait_def_id))
+                                .vptr_slot = Some(slot);
+                            ("vptr", Some(with_no_trimmed_paths!(trait_ref.to_string())))
+                        }
+                    };
+                    VtableEntry { slot, kind, item }
+                })
+                .collect();
+
+            VtableLayout {
+                trait_object: with_no_trimmed_paths!(trait_object_ty.to_string()),
+                concrete_type: with_no_trimmed_paths!(concrete_ty.to_string()),
+                entries,
+                traits: traits.into_values().collect(),
+            }
+        })
+        .collect();
+    layouts.sort_by(|a, b| {
+        (&a.trait_object, &a.concrete_type).cmp(&(&b.trait_object, &b.concrete_type))
+    });
+
+    serde_json::to_writer(file, &layouts)?;
+
+    Ok(())
+}
+
 pub fn provide(providers: &mut Providers) {
     providers.collect_and_partition_mono_items = collect_and_partition_mono_items;
 
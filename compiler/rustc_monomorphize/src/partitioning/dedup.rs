@@ -0,0 +1,414 @@
+//! Deduplication of structurally identical monomorphizations
+//! (`-Zdedup-mono-items`).
+//!
+//! Many instantiations of a generic function end up with exactly the same
+//! machine code, e.g. `Vec::<u32>::push` and `Vec::<i32>::push`: both bodies
+//! come from the same generic MIR and only move values of the same size and
+//! alignment around. This pass finds such instantiations after partitioning
+//! and keeps only one definition of them. The other instantiations are moved
+//! into the codegen unit of that definition and recorded as aliases of it, so
+//! the backend can emit them as symbol aliases instead of codegening the same
+//! body again.
+//!
+//! Two instantiations of the same generic function are considered identical if
+//! everything that codegen derives from the generic arguments is the same:
+//!
+//! - the layouts of all locals and of all places that are accessed, ignoring
+//!   the signedness of integers, which only matters for operations that
+//!   interpret values,
+//! - the exact types of operands of arithmetic, comparisons and casts, as well
+//!   as of indirect callees,
+//! - the values of all constants,
+//! - how arguments and the return value are passed, and
+//! - the functions they call and drop, which must in turn be identical.
+//!
+//! The last point is what makes this a fixpoint computation: we start with
+//! classes of instances whose bodies look the same locally, and then keep
+//! splitting classes whose members call into different classes until nothing
+//! changes anymore.
+//!
+//! Note that this makes function pointers of different instantiations compare
+//! equal, which is fine as Rust does not guarantee function pointers to be
+//! unique.
+
+use rustc_data_structures::fx::{FxHashMap, FxIndexMap};
+use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
+use rustc_middle::mir::mono::{CodegenUnit, Linkage, MonoItem};
+use rustc_middle::mir::visit::{PlaceContext, Visitor};
+use rustc_middle::mir::{
+    self, ConstValue, Location, NonDivergingIntrinsic, NullOp, PlaceRef, Rvalue, Statement,
+    StatementKind, Terminator, TerminatorKind,
+};
+use rustc_middle::ty::{self, Instance, InstanceDef, Ty, TyCtxt, TypeFoldable};
+use rustc_target::abi::call::{Conv, PassMode};
+use rustc_target::abi::{Abi, FieldIdx, Layout, LayoutS, Niche, Primitive, Scalar, VariantIdx};
+
+use super::PartitioningCx;
+
+/// A fact about an instantiated body that codegen depends on. Two
+/// instantiations of the same generic body produce the same code if they
+/// agree on all facts, in order, and on the functions they reference.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+enum Fact<'tcx> {
+    /// The layout of a local or place, with integer signedness erased.
+    Layout(Layout<'tcx>),
+    /// A type whose exact identity matters, e.g. the type of an operand of
+    /// an arithmetic operation.
+    Ty(Ty<'tcx>),
+    /// The value of a constant.
+    Const(ConstValue<'tcx>),
+    /// How an argument or the return value is passed. The pass mode includes
+    /// the argument attributes, so integers that the caller sign- or
+    /// zero-extends are told apart here.
+    Arg(Layout<'tcx>, PassMode),
+    /// A drop of a value that has no drop glue.
+    NoDrop,
+}
+
+/// Everything codegen derives from the generic arguments of an instance.
+struct Shape<'tcx> {
+    facts: Vec<Fact<'tcx>>,
+    /// Functions called, reified or dropped by the body, in visiting order.
+    refs: Vec<Instance<'tcx>>,
+}
+
+struct Candidate<'tcx> {
+    cgu_index: usize,
+    instance: Instance<'tcx>,
+    shape: Shape<'tcx>,
+}
+
+pub(super) fn deduplicate_mono_items<'tcx>(
+    cx: &PartitioningCx<'_, 'tcx>,
+    codegen_units: &mut [CodegenUnit<'tcx>],
+) {
+    let tcx = cx.tcx;
+
+    // Coverage instrumentation and CFI both attach per-instance information
+    // to function definitions, which an alias cannot carry.
+    if tcx.sess.instrument_coverage()
+        || tcx.sess.is_sanitizer_cfi_enabled()
+        || tcx.sess.is_sanitizer_kcfi_enabled()
+    {
+        return;
+    }
+
+    let mut layouts = FxHashMap::default();
+    let mut candidates = Vec::new();
+    for (cgu_index, cgu) in codegen_units.iter().enumerate() {
+        for (item, data) in cgu.items() {
+            // Aliases of items in a comdat could be discarded together with
+            // it in favor of another copy that doesn't define them.
+            if data.inlined || data.linkage != Linkage::External {
+                continue;
+            }
+            let MonoItem::Fn(instance) = *item else { continue };
+            if !is_candidate(tcx, instance) {
+                continue;
+            }
+            if let Some(shape) = compute_shape(tcx, &mut layouts, instance) {
+                candidates.push(Candidate { cgu_index, instance, shape });
+            }
+        }
+    }
+
+    let classes = compute_classes(&candidates);
+
+    let mut members: FxIndexMap<usize, Vec<usize>> = FxIndexMap::default();
+    for (candidate, &class) in classes.iter().enumerate() {
+        members.entry(class).or_default().push(candidate);
+    }
+
+    for members in members.values().filter(|members| members.len() > 1) {
+        // Pick the definition to keep in a way that does not depend on the
+        // order in which the items were placed.
+        let canonical = members
+            .iter()
+            .copied()
+            .min_by_key(|&i| tcx.symbol_name(candidates[i].instance).name)
+            .unwrap();
+        let aliasee = MonoItem::Fn(candidates[canonical].instance);
+        let target_cgu = candidates[canonical].cgu_index;
+
+        for &i in members.iter().filter(|&&i| i != canonical) {
+            let alias = MonoItem::Fn(candidates[i].instance);
+            let mut data =
+                codegen_units[candidates[i].cgu_index].items_mut().shift_remove(&alias).unwrap();
+            // An alias does not need any code of its own.
+            data.size_estimate = 1;
+            codegen_units[target_cgu].items_mut().insert(alias, data);
+            codegen_units[target_cgu].add_alias(alias, aliasee);
+        }
+    }
+
+    for cgu in codegen_units.iter_mut() {
+        cgu.compute_size_estimate();
+    }
+}
+
+fn is_candidate<'tcx>(tcx: TyCtxt<'tcx>, instance: Instance<'tcx>) -> bool {
+    let InstanceDef::Item(def_id) = instance.def else { return false };
+
+    // Instances of non-generic functions are unique anyway.
+    if instance.args.non_erasable_generics(tcx, def_id).next().is_none() {
+        return false;
+    }
+
+    let attrs = tcx.codegen_fn_attrs(def_id);
+    !attrs.flags.contains(CodegenFnAttrFlags::NAKED) && attrs.link_section.is_none()
+}
+
+/// Splits the candidates into classes of identical instances, returning the
+/// class of each candidate.
+fn compute_classes<'tcx>(candidates: &[Candidate<'tcx>]) -> Vec<usize> {
+    let index: FxHashMap<Instance<'tcx>, usize> =
+        candidates.iter().enumerate().map(|(i, candidate)| (candidate.instance, i)).collect();
+
+    // Start out with classes of instances of the same body that agree on all
+    // local facts.
+    let mut keys = FxHashMap::default();
+    let mut classes: Vec<usize> = candidates
+        .iter()
+        .map(|candidate| {
+            let next = keys.len();
+            *keys.entry((candidate.instance.def, &candidate.shape.facts)).or_insert(next)
+        })
+        .collect();
+    let mut num_classes = keys.len();
+
+    // Then split classes until all members of a class reference the same
+    // classes. References to instances that are no candidates have to match
+    // exactly. Every round only ever splits classes, so if the number of
+    // classes stays the same, we have reached the fixpoint.
+    loop {
+        let mut keys = FxHashMap::default();
+        let refined: Vec<usize> = candidates
+            .iter()
+            .zip(&classes)
+            .map(|(candidate, &class)| {
+                let refs: Vec<Result<usize, Instance<'tcx>>> = candidate
+                    .shape
+                    .refs
+                    .iter()
+                    .map(|referenced| index.get(referenced).map(|&i| classes[i]).ok_or(*referenced))
+                    .collect();
+                let next = keys.len();
+                *keys.entry((class, refs)).or_insert(next)
+            })
+            .collect();
+
+        let done = keys.len() == num_classes;
+        classes = refined;
+        num_classes = keys.len();
+        if done {
+            return classes;
+        }
+    }
+}
+
+/// Computes the shape of `instance`, or `None` if it should not be
+/// deduplicated.
+fn compute_shape<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    layouts: &mut FxHashMap<Ty<'tcx>, Option<Layout<'tcx>>>,
+    instance: Instance<'tcx>,
+) -> Option<Shape<'tcx>> {
+    let param_env = ty::ParamEnv::reveal_all();
+    let fn_abi = tcx.fn_abi_of_instance(param_env.and((instance, ty::List::empty()))).ok()?;
+    if fn_abi.conv != Conv::Rust || fn_abi.c_variadic {
+        return None;
+    }
+
+    let body = tcx.instance_mir(instance.def);
+    let mut builder = ShapeBuilder {
+        tcx,
+        param_env,
+        instance,
+        body,
+        layouts,
+        shape: Shape { facts: Vec::new(), refs: Vec::new() },
+        failed: false,
+    };
+
+    for arg in fn_abi.args.iter().chain([&fn_abi.ret]) {
+        let layout = builder.erased_layout(arg.layout.ty)?;
+        builder.shape.facts.push(Fact::Arg(layout, arg.mode.clone()));
+    }
+
+    for local_decl in body.local_decls.iter() {
+        builder.push_layout(local_decl.ty);
+    }
+    for (bb, data) in body.basic_blocks.iter_enumerated() {
+        builder.visit_basic_block_data(bb, data);
+    }
+
+    if builder.failed {
+        None
+    } else {
+        Some(builder.shape)
+    }
+}
+
+struct ShapeBuilder<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    instance: Instance<'tcx>,
+    body: &'tcx mir::Body<'tcx>,
+    layouts: &'a mut FxHashMap<Ty<'tcx>, Option<Layout<'tcx>>>,
+    shape: Shape<'tcx>,
+    failed: bool,
+}
+
+impl<'a, 'tcx> ShapeBuilder<'a, 'tcx> {
+    fn monomorphize<T>(&self, value: T) -> T
+    where
+        T: TypeFoldable<TyCtxt<'tcx>>,
+    {
+        self.instance.instantiate_mir_and_normalize_erasing_regions(
+            self.tcx,
+            self.param_env,
+            ty::EarlyBinder::bind(value),
+        )
+    }
+
+    /// Returns the layout of the already monomorphized `ty` with the
+    /// signedness of all integers in its ABI erased.
+    fn erased_layout(&mut self, ty: Ty<'tcx>) -> Option<Layout<'tcx>> {
+        let tcx = self.tcx;
+        let param_env = self.param_env;
+        *self.layouts.entry(ty).or_insert_with(|| {
+            let layout = tcx.layout_of(param_env.and(ty)).ok()?;
+            Some(tcx.mk_layout(erase_signedness(*layout)))
+        })
+    }
+
+    /// Records the layout of the generic type `ty`.
+    fn push_layout(&mut self, ty: Ty<'tcx>) {
+        let ty = self.monomorphize(ty);
+        match self.erased_layout(ty) {
+            Some(layout) => self.shape.facts.push(Fact::Layout(layout)),
+            None => self.failed = true,
+        }
+    }
+
+    /// Records the exact identity of the generic type `ty`.
+    fn push_ty(&mut self, ty: Ty<'tcx>) {
+        let ty = self.monomorphize(ty);
+        self.shape.facts.push(Fact::Ty(ty));
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ShapeBuilder<'a, 'tcx> {
+    fn visit_place(&mut self, place: &mir::Place<'tcx>, _: PlaceContext, _: Location) {
+        // The layout of the local itself has already been recorded, and so
+        // have the layouts of all locals used as indices.
+        for i in 1..=place.projection.len() {
+            let prefix = PlaceRef { local: place.local, projection: &place.projection[..i] };
+            self.push_layout(prefix.ty(self.body, self.tcx).ty);
+        }
+    }
+
+    fn visit_constant(&mut self, constant: &mir::ConstOperand<'tcx>, _: Location) {
+        let const_ = self.monomorphize(constant.const_);
+        if let ty::FnDef(def_id, args) = *const_.ty().kind() {
+            match Instance::resolve(self.tcx, self.param_env, def_id, args) {
+                Ok(Some(instance)) => self.shape.refs.push(instance),
+                _ => self.failed = true,
+            }
+            return;
+        }
+
+        match const_.eval(self.tcx, self.param_env, None) {
+            Ok(val) => {
+                self.push_layout(constant.const_.ty());
+                self.shape.facts.push(Fact::Const(val));
+            }
+            Err(_) => self.failed = true,
+        }
+    }
+
+    fn visit_rvalue(&mut self, rvalue: &Rvalue<'tcx>, location: Location) {
+        match *rvalue {
+            Rvalue::BinaryOp(_, box (ref lhs, ref rhs))
+            | Rvalue::CheckedBinaryOp(_, box (ref lhs, ref rhs)) => {
+                self.push_ty(lhs.ty(self.body, self.tcx));
+                self.push_ty(rhs.ty(self.body, self.tcx));
+            }
+            Rvalue::UnaryOp(_, ref operand) => self.push_ty(operand.ty(self.body, self.tcx)),
+            Rvalue::Cast(_, ref operand, target_ty) => {
+                self.push_ty(operand.ty(self.body, self.tcx));
+                self.push_ty(target_ty);
+            }
+            Rvalue::NullaryOp(NullOp::OffsetOf(_), ty) => self.push_ty(ty),
+            Rvalue::NullaryOp(_, ty) | Rvalue::ShallowInitBox(_, ty) => self.push_layout(ty),
+            _ => {}
+        }
+        self.super_rvalue(rvalue, location);
+    }
+
+    fn visit_statement(&mut self, statement: &Statement<'tcx>, location: Location) {
+        if let StatementKind::Intrinsic(box NonDivergingIntrinsic::CopyNonOverlapping(ref copy)) =
+            statement.kind
+        {
+            // Only the size of the copied values matters.
+            let src_ty = self.monomorphize(copy.src.ty(self.body, self.tcx));
+            match src_ty.builtin_deref(true) {
+                Some(pointee) => self.push_layout(pointee.ty),
+                None => self.failed = true,
+            }
+        }
+        self.super_statement(statement, location);
+    }
+
+    fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location) {
+        match terminator.kind {
+            TerminatorKind::Drop { ref place, .. } => {
+                let ty = self.monomorphize(place.ty(self.body, self.tcx).ty);
+                let instance = Instance::resolve_drop_in_place(self.tcx, ty);
+                if let InstanceDef::DropGlue(_, None) = instance.def {
+                    self.shape.facts.push(Fact::NoDrop);
+                } else {
+                    self.shape.refs.push(instance);
+                }
+            }
+            TerminatorKind::Call { ref func, .. } if func.constant().is_none() => {
+                self.push_ty(func.ty(self.body, self.tcx));
+            }
+            TerminatorKind::InlineAsm { .. } => self.failed = true,
+            _ => {}
+        }
+        self.super_terminator(terminator, location);
+    }
+}
+
+/// Erases the signedness of all integers in the ABI of `layout`. The layouts
+/// of the variants of an enum are kept as they are, as the signedness of the
+/// tag determines how the discriminant is computed from it.
+fn erase_signedness(layout: &LayoutS<FieldIdx, VariantIdx>) -> LayoutS<FieldIdx, VariantIdx> {
+    fn erase_primitive(primitive: Primitive) -> Primitive {
+        match primitive {
+            Primitive::Int(integer, _) => Primitive::Int(integer, false),
+            primitive => primitive,
+        }
+    }
+
+    fn erase_scalar(scalar: Scalar) -> Scalar {
+        match scalar {
+            Scalar::Initialized { value, valid_range } => {
+                Scalar::Initialized { value: erase_primitive(value), valid_range }
+            }
+            Scalar::Union { value } => Scalar::Union { value: erase_primitive(value) },
+        }
+    }
+
+    let abi = match layout.abi {
+        Abi::Scalar(scalar) => Abi::Scalar(erase_scalar(scalar)),
+        Abi::ScalarPair(a, b) => Abi::ScalarPair(erase_scalar(a), erase_scalar(b)),
+        Abi::Vector { element, count } => Abi::Vector { element: erase_scalar(element), count },
+        abi @ (Abi::Uninhabited | Abi::Aggregate { .. }) => abi,
+    };
+    let largest_niche =
+        layout.largest_niche.map(|niche| Niche { value: erase_primitive(niche.value), ..niche });
+
+    LayoutS { abi, largest_niche, ..layout.clone() }
+}
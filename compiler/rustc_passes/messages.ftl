@@ -29,6 +29,10 @@ passes_attr_application_struct =
     attribute should be applied to a struct
     .label = not a struct
 
+passes_attr_application_struct_enum =
+    attribute should be applied to a struct or enum
+    .label = not a struct or enum
+
 passes_attr_application_struct_enum_function_method_union =
     attribute should be applied to a struct, enum, function, associated function, or union
     .label = not a struct, enum, function, associated function, or union
@@ -349,6 +353,9 @@ passes_ignored_derived_impls =
 passes_implied_feature_not_exist =
     feature `{$implied_by}` implying `{$feature}` does not exist
 
+passes_incorrect_blocking_location =
+    `#[diagnostic::blocking]` can only be placed on functions
+
 passes_incorrect_do_not_recommend_location =
     `#[do_not_recommend]` can only be placed on trait implementations
 
@@ -450,6 +457,10 @@ passes_macro_export_on_decl_macro =
 passes_macro_use =
     `#[{$name}]` only has an effect on `extern crate` and modules
 
+passes_malformed_blocking =
+    malformed `#[diagnostic::blocking]` attribute
+    .help = the only supported argument is `alternative = "path::to::function"`
+
 passes_maybe_string_interpolation = you might have meant to use string interpolation in this string literal
 passes_missing_const_err =
     attributes `#[rustc_const_unstable]` and `#[rustc_const_stable]` require the function or method to be `const`
@@ -642,6 +653,10 @@ passes_rustc_lint_opt_ty =
     `#[rustc_lint_opt_ty]` should be applied to a struct
     .label = not a struct
 
+passes_rustc_pure_deref =
+    `#[rustc_pure_deref]` should be applied to a struct
+    .label = not a struct
+
 passes_rustc_safe_intrinsic =
     attribute should be applied to intrinsic functions
     .label = not an intrinsic function
@@ -782,6 +797,12 @@ passes_unused_variable_try_prefix = unused variable: `{$name}`
     .suggestion = if this is intentional, prefix it with an underscore
 
 
+passes_usage_manifest_unreadable =
+    couldn't read the usage manifests in {$path}: {$error}
+
+passes_usage_manifest_unwritable =
+    couldn't write the usage manifest {$path}: {$error}
+
 passes_used_compiler_linker =
     `used(compiler)` and `used(linker)` can't be used together
 
@@ -798,3 +819,7 @@ passes_useless_stability =
     this stability annotation is useless
     .label = useless stability annotation
     .item = the stability attribute annotates this item
+
+passes_view_invalid_target =
+    `#[view]` should be applied to an inherent method
+    .label = not an inherent method
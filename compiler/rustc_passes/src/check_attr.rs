@@ -118,6 +118,9 @@ fn check_attributes(
             if attr.path_matches(&[sym::diagnostic, sym::on_unimplemented]) {
                 self.check_diagnostic_on_unimplemented(attr.span, hir_id, target);
             }
+            if attr.path_matches(&[sym::diagnostic, sym::blocking]) {
+                self.check_diagnostic_blocking(attr, hir_id, target);
+            }
             match attr.name_or_empty() {
                 sym::do_not_recommend => self.check_do_not_recommend(attr.span, target),
                 sym::inline => self.check_inline(hir_id, attr, span, target),
@@ -169,14 +172,17 @@ fn check_attributes(
                 sym::rustc_lint_opt_deny_field_access => {
                     self.check_rustc_lint_opt_deny_field_access(attr, span, target)
                 }
+                sym::rustc_pure_deref => self.check_rustc_pure_deref(attr, span, target),
                 sym::rustc_clean
                 | sym::rustc_dirty
                 | sym::rustc_if_this_changed
                 | sym::rustc_then_this_would_need => self.check_rustc_dirty_clean(attr),
                 sym::rustc_coinductive
+                | sym::coinductive
                 | sym::rustc_must_implement_one_of
                 | sym::rustc_deny_explicit_impl
                 | sym::const_trait => self.check_must_be_applied_to_trait(attr, span, target),
+                sym::view => self.check_view(attr, span, target),
                 sym::cmse_nonsecure_entry => {
                     self.check_cmse_nonsecure_entry(hir_id, attr, span, target)
                 }
@@ -302,6 +308,42 @@ fn check_do_not_recommend(&self, attr_span: Span, target: Target) -> bool {
         }
     }
 
+    /// Checks if `#[diagnostic::blocking]` is applied to a function, and that its only argument
+    /// is `alternative = "path"`.
+    fn check_diagnostic_blocking(&self, attr: &Attribute, hir_id: HirId, target: Target) {
+        if !matches!(target, Target::Fn | Target::Method(_) | Target::ForeignFn) {
+            self.tcx.emit_node_span_lint(
+                UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES,
+                hir_id,
+                attr.span,
+                errors::IncorrectBlockingLocation,
+            );
+            return;
+        }
+
+        let Some(items) = attr.meta_item_list() else {
+            if !attr.is_word() {
+                self.tcx.emit_node_span_lint(
+                    UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES,
+                    hir_id,
+                    attr.span,
+                    errors::MalformedBlocking,
+                );
+            }
+            return;
+        };
+        for item in items {
+            if !item.has_name(sym::alternative) || item.value_str().is_none() {
+                self.tcx.emit_node_span_lint(
+                    UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES,
+                    hir_id,
+                    item.span(),
+                    errors::MalformedBlocking,
+                );
+            }
+        }
+    }
+
     /// Checks if `#[diagnostic::on_unimplemented]` is applied to a trait definition
     fn check_diagnostic_on_unimplemented(&self, attr_span: Span, hir_id: HirId, target: Target) {
         if !matches!(target, Target::Trait) {
@@ -1665,6 +1707,17 @@ fn check_rustc_lint_opt_deny_field_access(
         }
     }
 
+    /// Checks that the `#[rustc_pure_deref]` attribute is only applied to a struct.
+    fn check_rustc_pure_deref(&self, attr: &Attribute, span: Span, target: Target) -> bool {
+        match target {
+            Target::Struct => true,
+            _ => {
+                self.dcx().emit_err(errors::RustcPureDeref { attr_span: attr.span, span });
+                false
+            }
+        }
+    }
+
     /// Checks that the dep-graph debugging attributes are only present when the query-dep-graph
     /// option is passed to the compiler.
     fn check_rustc_dirty_clean(&self, attr: &Attribute) -> bool {
@@ -1690,6 +1743,18 @@ fn check_must_be_applied_to_trait(&self, attr: &Attribute, span: Span, target: T
         }
     }
 
+    /// Checks if `#[view]` is applied to an inherent method.
+    fn check_view(&self, attr: &Attribute, span: Span, target: Target) -> bool {
+        match target {
+            Target::Method(MethodKind::Inherent) => true,
+            _ => {
+                self.dcx()
+                    .emit_err(errors::ViewInvalidTarget { attr_span: attr.span, defn_span: span });
+                false
+            }
+        }
+    }
+
     /// Checks if `#[link_section]` is applied to a function or static.
     fn check_link_section(&self, hir_id: HirId, attr: &Attribute, span: Span, target: Target) {
         match target {
@@ -1843,6 +1908,27 @@ fn check_repr(
                         continue;
                     }
                 }
+                sym::no_niche => {
+                    if !self.tcx.features().no_niche {
+                        feature_err(
+                            &self.tcx.sess,
+                            sym::no_niche,
+                            hint.span(),
+                            "`repr(no_niche)` is experimental",
+                        )
+                        .emit();
+                    }
+
+                    match target {
+                        Target::Struct | Target::Enum => continue,
+                        _ => {
+                            self.dcx().emit_err(errors::AttrApplication::StructEnum {
+                                hint_span: hint.span(),
+                                span,
+                            });
+                        }
+                    }
+                }
                 sym::simd => {
                     is_simd = true;
                     if target != Target::Struct {
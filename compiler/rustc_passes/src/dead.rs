@@ -2,29 +2,36 @@
 // All reachable symbols are live, code called from live code is live, code with certain lint
 // expectations such as `#[expect(unused)]` and `#[expect(dead_code)]` is live, and everything else
 // is dead.
+//
+// With `-Z cross-crate-dead-code=<dir>`, the public items of private modules are not live just
+// because other crates can reach them through a re-export: the crates using this one record the
+// items they use in usage manifests in `<dir>`, and the items none of them use are dead unless
+// this crate uses them. See `write_usage_manifests`.
 
 use hir::def_id::{LocalDefIdMap, LocalDefIdSet};
 use hir::ItemKind;
+use rustc_data_structures::fx::FxIndexMap;
 use rustc_data_structures::unord::UnordSet;
 use rustc_errors::MultiSpan;
 use rustc_hir as hir;
 use rustc_hir::def::{CtorOf, DefKind, Res};
-use rustc_hir::def_id::{DefId, LocalDefId, LocalModDefId};
+use rustc_hir::def_id::{CrateNum, DefId, LocalDefId, LocalModDefId, CRATE_DEF_ID, LOCAL_CRATE};
 use rustc_hir::intravisit::{self, Visitor};
 use rustc_hir::{Node, PatKind, TyKind};
 use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
 use rustc_middle::middle::privacy::Level;
-use rustc_middle::query::Providers;
+use rustc_middle::query::{LocalCrate, Providers};
 use rustc_middle::ty::{self, TyCtxt, Visibility};
 use rustc_session::lint;
 use rustc_session::lint::builtin::DEAD_CODE;
 use rustc_span::symbol::{sym, Symbol};
 use rustc_target::abi::FieldIdx;
-use std::mem;
+use std::path::Path;
+use std::{fs, io, iter, mem};
 
 use crate::errors::{
     ChangeFieldsToBeOfUnitType, IgnoredDerivedImpls, MultipleDeadCodes, ParentInfo,
-    UselessAssignment,
+    UsageManifestUnreadable, UsageManifestUnwritable, UselessAssignment,
 };
 
 // Any local node that may call something in its body block should be
@@ -68,6 +75,8 @@ struct MarkSymbolVisitor<'tcx> {
     // and the span of their respective impl (i.e., part of the derive
     // macro)
     ignored_derived_traits: LocalDefIdMap<Vec<(DefId, DefId)>>,
+    // the items of other crates used by live code, with `-Z cross-crate-dead-code`
+    used_upstream_items: Option<UnordSet<DefId>>,
 }
 
 impl<'tcx> MarkSymbolVisitor<'tcx> {
@@ -86,6 +95,8 @@ fn check_def_id(&mut self, def_id: DefId) {
                 self.worklist.push((def_id, ComesFromAllowExpect::No));
             }
             self.live_symbols.insert(def_id);
+        } else if let Some(used_upstream_items) = &mut self.used_upstream_items {
+            used_upstream_items.insert(def_id);
         }
     }
 
@@ -93,6 +104,8 @@ fn insert_def_id(&mut self, def_id: DefId) {
         if let Some(def_id) = def_id.as_local() {
             debug_assert!(!should_explore(self.tcx, def_id));
             self.live_symbols.insert(def_id);
+        } else if let Some(used_upstream_items) = &mut self.used_upstream_items {
+            used_upstream_items.insert(def_id);
         }
     }
 
@@ -742,6 +755,7 @@ fn create_and_seed_worklist(
     tcx: TyCtxt<'_>,
 ) -> (Vec<(LocalDefId, ComesFromAllowExpect)>, LocalDefIdMap<LocalDefId>) {
     let effective_visibilities = &tcx.effective_visibilities(());
+    let unused_by_other_crates = unused_by_other_crates(tcx);
     // see `MarkSymbolVisitor::struct_constructors`
     let mut struct_constructors = Default::default();
     let mut worklist = effective_visibilities
@@ -750,6 +764,10 @@ fn create_and_seed_worklist(
             effective_vis
                 .is_public_at_level(Level::Reachable)
                 .then_some(id)
+                .filter(|&id| {
+                    unused_by_other_crates.is_empty()
+                        || !unused_by_other_crates.contains(&workspace_owner(tcx, id))
+                })
                 .map(|id| (id, ComesFromAllowExpect::No))
         })
         // Seed entry point
@@ -775,10 +793,85 @@ fn create_and_seed_worklist(
     (worklist, struct_constructors)
 }
 
+/// The public items of private modules which other crates can reach through a re-export, but
+/// which none of the crates that wrote a usage manifest for this one uses.
+fn unused_by_other_crates(tcx: TyCtxt<'_>) -> LocalDefIdSet {
+    let Some(used) = tcx.usage_manifests(()) else {
+        return Default::default();
+    };
+
+    let mut candidates = vec![];
+    let mut used_owners = LocalDefIdSet::default();
+    for (&id, effective_vis) in tcx.effective_visibilities(()).iter() {
+        if !effective_vis.is_public_at_level(Level::Reachable) {
+            continue;
+        }
+        // Using a method or a field of a type is enough to keep the whole type live.
+        let owner = workspace_owner(tcx, id);
+        if used.contains(&manifest_path(tcx, id.to_def_id())) {
+            used_owners.insert(owner);
+        } else if owner == id && is_public_in_private_module(tcx, id) {
+            candidates.push(id);
+        }
+    }
+    candidates.into_iter().filter(|id| !used_owners.contains(id)).collect()
+}
+
+/// The item of a module which `id` is part of, e.g. the struct of a field. The items of an
+/// inherent impl are part of the type of the impl.
+fn workspace_owner(tcx: TyCtxt<'_>, mut id: LocalDefId) -> LocalDefId {
+    loop {
+        match tcx.opt_local_parent(id) {
+            Some(parent) if tcx.def_kind(parent) != DefKind::Mod => id = parent,
+            _ => break,
+        }
+    }
+    if let DefKind::Impl { of_trait: false } = tcx.def_kind(id)
+        && let ty::Adt(adt, _) = tcx.type_of(id).instantiate_identity().kind()
+        && let Some(adt_id) = adt.did().as_local()
+    {
+        return adt_id;
+    }
+    id
+}
+
+/// Whether `id` is a public item declared in a module which isn't public, so that other crates
+/// can only use it through a re-export.
+fn is_public_in_private_module(tcx: TyCtxt<'_>, id: LocalDefId) -> bool {
+    if !matches!(
+        tcx.def_kind(id),
+        DefKind::Fn
+            | DefKind::Const
+            | DefKind::Static(_)
+            | DefKind::Struct
+            | DefKind::Enum
+            | DefKind::Union
+            | DefKind::Trait
+            | DefKind::TyAlias
+    ) || !tcx.local_visibility(id).is_public()
+    {
+        return false;
+    }
+    let mut module = tcx.local_parent(id);
+    while module != CRATE_DEF_ID {
+        if !tcx.local_visibility(module).is_public() {
+            return true;
+        }
+        module = tcx.local_parent(module);
+    }
+    false
+}
+
+/// The path identifying `def_id` in the usage manifests. Unlike `DefId`s, it is the same in
+/// every compilation session.
+fn manifest_path(tcx: TyCtxt<'_>, def_id: DefId) -> String {
+    tcx.def_path(def_id).to_string_no_crate_verbose()
+}
+
 fn live_symbols_and_ignored_derived_traits(
     tcx: TyCtxt<'_>,
     (): (),
-) -> (LocalDefIdSet, LocalDefIdMap<Vec<(DefId, DefId)>>) {
+) -> (LocalDefIdSet, LocalDefIdMap<Vec<(DefId, DefId)>>, UnordSet<DefId>) {
     let (worklist, struct_constructors) = create_and_seed_worklist(tcx);
     let mut symbol_visitor = MarkSymbolVisitor {
         worklist,
@@ -791,9 +884,99 @@ fn live_symbols_and_ignored_derived_traits(
         ignore_variant_stack: vec![],
         struct_constructors,
         ignored_derived_traits: Default::default(),
+        used_upstream_items: tcx
+            .sess
+            .opts
+            .unstable_opts
+            .cross_crate_dead_code
+            .is_some()
+            .then(UnordSet::default),
     };
     symbol_visitor.mark_live_symbols();
-    (symbol_visitor.live_symbols, symbol_visitor.ignored_derived_traits)
+    (
+        symbol_visitor.live_symbols,
+        symbol_visitor.ignored_derived_traits,
+        symbol_visitor.used_upstream_items.unwrap_or_default(),
+    )
+}
+
+fn used_upstream_items(tcx: TyCtxt<'_>, _: LocalCrate) -> Option<&[DefId]> {
+    tcx.sess.opts.unstable_opts.cross_crate_dead_code.as_ref()?;
+    let (_, _, used_upstream_items) = tcx.live_symbols_and_ignored_derived_traits(());
+    // Sort the items, so that the metadata doesn't depend on the order they were found in.
+    let items = tcx.with_stable_hashing_context(|hcx| used_upstream_items.to_sorted(&hcx, true));
+    Some(tcx.arena.alloc_from_iter(items.into_iter().copied()))
+}
+
+fn usage_manifests(tcx: TyCtxt<'_>, (): ()) -> Option<UnordSet<String>> {
+    let dir = tcx.sess.opts.unstable_opts.cross_crate_dead_code.as_ref()?;
+    let crate_dir = dir.join(tcx.crate_name(LOCAL_CRATE).as_str());
+    match read_usage_manifests(&crate_dir) {
+        Ok(used) => used,
+        // No crate using this one has been built yet.
+        Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+        Err(error) => {
+            tcx.dcx().emit_err(UsageManifestUnreadable { path: &crate_dir, error });
+            None
+        }
+    }
+}
+
+fn read_usage_manifests(crate_dir: &Path) -> io::Result<Option<UnordSet<String>>> {
+    let mut used = None;
+    for entry in fs::read_dir(crate_dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "usage") {
+            let manifest = fs::read_to_string(&path)?;
+            used.get_or_insert_with(UnordSet::default).extend(manifest.lines().map(str::to_owned));
+        }
+    }
+    Ok(used)
+}
+
+/// Writes the usage manifests of a crate built with `-Z cross-crate-dead-code=<dir>` which
+/// other crates can't depend on, such as an executable.
+///
+/// For each crate it depends on which was built with the same flag, `<dir>/<crate>/<leaf>.usage`
+/// lists the paths of the items of that crate which are used by this one, or by the crates in
+/// between, one per line. They are read back when that crate is built again.
+pub fn write_usage_manifests(tcx: TyCtxt<'_>) {
+    let Some(dir) = &tcx.sess.opts.unstable_opts.cross_crate_dead_code else {
+        return;
+    };
+    // Crates with metadata record the items they use there instead, for the crates using them.
+    if tcx.crate_types().iter().any(|crate_type| crate_type.has_metadata()) {
+        return;
+    }
+
+    let mut manifests: FxIndexMap<CrateNum, Vec<String>> = tcx
+        .crates(())
+        .iter()
+        .filter(|&&cnum| tcx.used_upstream_items(cnum).is_some())
+        .map(|&cnum| (cnum, vec![]))
+        .collect();
+    let users: Vec<_> = iter::once(LOCAL_CRATE).chain(manifests.keys().copied()).collect();
+    for user in users {
+        for &def_id in tcx.used_upstream_items(user).unwrap_or_default() {
+            if let Some(paths) = manifests.get_mut(&def_id.krate) {
+                paths.push(manifest_path(tcx, def_id));
+            }
+        }
+    }
+
+    let leaf = tcx.crate_name(LOCAL_CRATE);
+    for (cnum, mut paths) in manifests {
+        paths.sort_unstable();
+        paths.dedup();
+        let manifest: String = paths.iter().map(|path| format!("{path}\n")).collect();
+
+        let crate_dir = dir.join(tcx.crate_name(cnum).as_str());
+        let path = crate_dir.join(format!("{leaf}.usage"));
+        if let Err(error) = fs::create_dir_all(&crate_dir).and_then(|()| fs::write(&path, manifest))
+        {
+            tcx.dcx().emit_err(UsageManifestUnwritable { path: &path, error });
+        }
+    }
 }
 
 struct DeadItem {
@@ -1006,7 +1189,7 @@ fn is_live_code(&self, def_id: LocalDefId) -> bool {
 }
 
 fn check_mod_deathness(tcx: TyCtxt<'_>, module: LocalModDefId) {
-    let (live_symbols, ignored_derived_traits) = tcx.live_symbols_and_ignored_derived_traits(());
+    let (live_symbols, ignored_derived_traits, _) = tcx.live_symbols_and_ignored_derived_traits(());
     let mut visitor = DeadVisitor { tcx, live_symbols, ignored_derived_traits };
 
     let module_items = tcx.hir_module_items(module);
@@ -1100,6 +1283,11 @@ fn check_mod_deathness(tcx: TyCtxt<'_>, module: LocalModDefId) {
 }
 
 pub(crate) fn provide(providers: &mut Providers) {
-    *providers =
-        Providers { live_symbols_and_ignored_derived_traits, check_mod_deathness, ..*providers };
+    *providers = Providers {
+        live_symbols_and_ignored_derived_traits,
+        check_mod_deathness,
+        used_upstream_items,
+        usage_manifests,
+        ..*providers
+    };
 }
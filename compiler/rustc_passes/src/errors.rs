@@ -25,6 +25,15 @@ pub struct IncorrectDoNotRecommendLocation {
     pub span: Span,
 }
 
+#[derive(LintDiagnostic)]
+#[diag(passes_incorrect_blocking_location)]
+pub struct IncorrectBlockingLocation;
+
+#[derive(LintDiagnostic)]
+#[diag(passes_malformed_blocking)]
+#[help]
+pub struct MalformedBlocking;
+
 #[derive(LintDiagnostic)]
 #[diag(passes_outer_crate_level_attr)]
 pub struct OuterCrateLevelAttr;
@@ -130,6 +139,15 @@ pub struct AttrShouldBeAppliedToTrait {
     pub defn_span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(passes_view_invalid_target)]
+pub struct ViewInvalidTarget {
+    #[primary_span]
+    pub attr_span: Span,
+    #[label]
+    pub defn_span: Span,
+}
+
 #[derive(LintDiagnostic)]
 #[diag(passes_target_feature_on_statement)]
 pub struct TargetFeatureOnStatement;
@@ -612,6 +630,20 @@ pub struct DebugVisualizerUnreadable<'a> {
     pub error: Error,
 }
 
+#[derive(Diagnostic)]
+#[diag(passes_usage_manifest_unreadable)]
+pub struct UsageManifestUnreadable<'a> {
+    pub path: &'a Path,
+    pub error: Error,
+}
+
+#[derive(Diagnostic)]
+#[diag(passes_usage_manifest_unwritable)]
+pub struct UsageManifestUnwritable<'a> {
+    pub path: &'a Path,
+    pub error: Error,
+}
+
 #[derive(Diagnostic)]
 #[diag(passes_rustc_allow_const_fn_unstable)]
 pub struct RustcAllowConstFnUnstable {
@@ -778,6 +810,15 @@ pub struct RustcLintOptDenyFieldAccess {
     pub span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(passes_rustc_pure_deref)]
+pub struct RustcPureDeref {
+    #[primary_span]
+    pub attr_span: Span,
+    #[label]
+    pub span: Span,
+}
+
 #[derive(Diagnostic)]
 #[diag(passes_collapse_debuginfo)]
 pub struct CollapseDebuginfo {
@@ -1435,6 +1476,13 @@ pub enum AttrApplication {
         #[label]
         span: Span,
     },
+    #[diag(passes_attr_application_struct_enum, code = E0517)]
+    StructEnum {
+        #[primary_span]
+        hint_span: Span,
+        #[label]
+        span: Span,
+    },
     #[diag(passes_attr_application_struct_union, code = E0517)]
     StructUnion {
         #[primary_span]
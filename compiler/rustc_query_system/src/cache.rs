@@ -24,6 +24,10 @@ fn default() -> Self {
 }
 
 impl<Key, Value> Cache<Key, Value> {
+    pub fn len(&self) -> usize {
+        self.hashmap.borrow().len()
+    }
+
     /// Actually frees the underlying memory in contrast to what stdlib containers do on `clear`
     pub fn clear(&self) {
         *self.hashmap.borrow_mut() = Default::default();
@@ -168,8 +168,16 @@ pub(crate) fn report_errors(&mut self, krate: &Crate) {
     }
 
     fn report_with_use_injections(&mut self, krate: &Crate) {
-        for UseError { mut err, candidates, def_id, instead, suggestion, path, is_call } in
-            self.use_injections.drain(..)
+        for UseError {
+            mut err,
+            candidates,
+            similar_candidates,
+            def_id,
+            instead,
+            suggestion,
+            path,
+            is_call,
+        } in self.use_injections.drain(..)
         {
             let (span, found_use) = if let Some(def_id) = def_id.as_local() {
                 UsePlacementFinder::check(krate, self.def_id_to_node_id[def_id])
@@ -193,6 +201,9 @@ fn report_with_use_injections(&mut self, krate: &Crate) {
             } else if let Some((span, msg, sugg, appl)) = suggestion {
                 err.span_suggestion_verbose(span, msg, sugg, appl);
                 err.emit();
+            } else if !similar_candidates.is_empty() {
+                show_similar_candidates(&mut err, span, &similar_candidates, found_use, &path);
+                err.emit();
             } else if let [segment] = path.as_slice()
                 && is_call
             {
@@ -1397,6 +1408,69 @@ pub(crate) fn lookup_import_candidates<FilterFn>(
         suggestions
     }
 
+    /// When name resolution fails and no entity with the expected name can be imported, this
+    /// method looks up the public items of the crates of the extern prelude whose name is the
+    /// closest to it, which the user may have meant to import instead.
+    pub(crate) fn lookup_similar_import_candidates<FilterFn>(
+        &mut self,
+        lookup_ident: Ident,
+        namespace: Namespace,
+        filter_fn: FilterFn,
+    ) -> Vec<ImportSuggestion>
+    where
+        FilterFn: Fn(Res) -> bool,
+    {
+        // Only 2018 edition paths can name the crates of the extern prelude in imports.
+        if !lookup_ident.span.at_least_rust_2018() {
+            return Vec::new();
+        }
+
+        let mut candidates = Vec::new();
+        for ident in self.extern_prelude.clone().into_keys() {
+            // Skip the injected `extern crate std`, as for `lookup_import_candidates`.
+            if ident.span.from_expansion() {
+                continue;
+            }
+            let Some(crate_id) = self.crate_loader(|c| c.maybe_process_path_extern(ident.name))
+            else {
+                continue;
+            };
+            for item in self.tcx.importable_items(crate_id) {
+                let res = item.res.expect_non_local();
+                if item.name() == lookup_ident.name
+                    || res.ns() != Some(namespace)
+                    || !filter_fn(res)
+                {
+                    continue;
+                }
+                let mut segments = thin_vec![ast::PathSegment::from_ident(ident)];
+                segments.extend(
+                    item.path
+                        .iter()
+                        .map(|&name| ast::PathSegment::from_ident(Ident::with_dummy_span(name))),
+                );
+                candidates.push((
+                    item.name(),
+                    ImportSuggestion {
+                        did: res.opt_def_id(),
+                        descr: res.descr(),
+                        path: Path { span: rustc_span::DUMMY_SP, segments, tokens: None },
+                        accessible: true,
+                        doc_visible: true,
+                        via_import: false,
+                        note: None,
+                    },
+                ));
+            }
+        }
+
+        let names = candidates.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+        let Some(best) = find_best_match_for_name(&names, lookup_ident.name, None) else {
+            return Vec::new();
+        };
+        candidates.into_iter().filter(|(name, _)| *name == best).map(|(_, sugg)| sugg).collect()
+    }
+
     pub(crate) fn unresolved_macro_suggestions(
         &mut self,
         err: &mut Diagnostic,
@@ -2908,6 +2982,69 @@ fn show_candidates(
     }
 }
 
+/// Suggests importing one of the items of other crates found by
+/// `lookup_similar_import_candidates`, in place of the single segment `path` which failed to
+/// resolve.
+fn show_similar_candidates(
+    err: &mut Diagnostic,
+    // This is `None` if all placement locations are inside expansions
+    use_placement_span: Option<Span>,
+    candidates: &[ImportSuggestion],
+    found_use: FoundUse,
+    path: &[Segment],
+) {
+    let mut candidates =
+        candidates.iter().map(|c| (pprust::path_to_string(&c.path), c)).collect::<Vec<_>>();
+    // Candidates are produced by iterating through a hash map, so make sure they are ordered.
+    candidates.sort_by(|a, b| a.0.cmp(&b.0));
+    candidates.dedup_by(|a, b| a.0 == b.0);
+
+    let mut msg = if let [(_, candidate)] = &candidates[..] {
+        let [krate, segments @ ..] = &candidate.path.segments[..] else {
+            bug!("the path of an item of another crate starts with its crate name")
+        };
+        let path = segments.iter().map(|s| s.ident.to_string()).collect::<Vec<_>>().join("::");
+        format!(
+            "there is a similarly named {} `{path}` in crate `{}`; consider importing it",
+            candidate.descr, krate.ident,
+        )
+    } else {
+        "there are similarly named items in other crates; consider importing one of them"
+            .to_string()
+    };
+
+    let (Some(span), [segment]) = (use_placement_span, path) else {
+        msg.push(':');
+        for (path_string, _) in candidates {
+            msg.push('\n');
+            msg.push_str(&path_string);
+        }
+        err.help(msg);
+        return;
+    };
+    // All the candidates have the same name, which is the closest to the one that failed to
+    // resolve.
+    let name = candidates[0].1.path.segments.last().unwrap().ident;
+    // Produce an additional newline to separate the new use statement from the directly
+    // following item.
+    let additional_newline = if let FoundUse::No = found_use { "\n" } else { "" };
+    err.span_suggestions_with_style(
+        span,
+        msg,
+        candidates
+            .into_iter()
+            .map(|(path_string, _)| format!("use {path_string};\n{additional_newline}")),
+        Applicability::MaybeIncorrect,
+        SuggestionStyle::ShowAlways,
+    );
+    err.span_suggestion_verbose(
+        segment.ident.span,
+        format!("and refer to it as `{name}`"),
+        name.to_string(),
+        Applicability::MaybeIncorrect,
+    );
+}
+
 #[derive(Debug)]
 struct UsePlacementFinder {
     target_module: NodeId,
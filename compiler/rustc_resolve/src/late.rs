@@ -3733,7 +3733,7 @@ fn smart_resolve_path_fragment(
         let Finalize { node_id, path_span, .. } = finalize;
         let report_errors = |this: &mut Self, res: Option<Res>| {
             if this.should_report_errs() {
-                let (err, candidates) =
+                let (err, candidates, similar_candidates) =
                     this.smart_resolve_report_errors(path, None, path_span, source, res);
 
                 let def_id = this.parent_scope.module.nearest_parent_mod();
@@ -3766,6 +3766,7 @@ fn smart_resolve_path_fragment(
                 let ue = UseError {
                     err,
                     candidates,
+                    similar_candidates,
                     def_id,
                     instead,
                     suggestion,
@@ -3793,7 +3794,7 @@ fn smart_resolve_path_fragment(
                 _ => return Some(parent_err),
             };
 
-            let (mut err, candidates) = this.smart_resolve_report_errors(
+            let (mut err, candidates, similar_candidates) = this.smart_resolve_report_errors(
                 prefix_path,
                 following_seg,
                 path_span,
@@ -3842,7 +3843,7 @@ fn append_result<T, E>(res1: &mut Result<Vec<T>, E>, res2: Result<Vec<T>, E>) {
             let def_id = this.parent_scope.module.nearest_parent_mod();
 
             if this.should_report_errs() {
-                if candidates.is_empty() {
+                if candidates.is_empty() && similar_candidates.is_empty() {
                     if path.len() == 2 && prefix_path.len() == 1 {
                         // Delay to check whether methond name is an associated function or not
                         // ```
@@ -3865,6 +3866,7 @@ fn append_result<T, E>(res1: &mut Result<Vec<T>, E>, res2: Result<Vec<T>, E>) {
                     this.r.use_injections.push(UseError {
                         err,
                         candidates,
+                        similar_candidates,
                         def_id,
                         instead: false,
                         suggestion: None,
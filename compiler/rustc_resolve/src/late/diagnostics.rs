@@ -417,6 +417,8 @@ pub(crate) fn smart_resolve_partial_mod_path_errors(
 
     /// Handles error reporting for `smart_resolve_path_fragment` function.
     /// Creates base error and amends it with one short label and possibly some longer helps/notes.
+    /// Also returns the candidates for importing the path, and when there are none, similarly
+    /// named items of other crates which could be imported instead.
     pub(crate) fn smart_resolve_report_errors(
         &mut self,
         path: &[Segment],
@@ -424,7 +426,7 @@ pub(crate) fn smart_resolve_report_errors(
         span: Span,
         source: PathSource<'_>,
         res: Option<Res>,
-    ) -> (DiagnosticBuilder<'tcx>, Vec<ImportSuggestion>) {
+    ) -> (DiagnosticBuilder<'tcx>, Vec<ImportSuggestion>, Vec<ImportSuggestion>) {
         debug!(?res, ?source);
         let base_error = self.make_base_error(path, span, source, res);
 
@@ -449,7 +451,7 @@ pub(crate) fn smart_resolve_report_errors(
         if self.suggest_pattern_match_with_let(&mut err, source, span) {
             // Fallback label.
             err.span_label(base_error.span, base_error.fallback_label);
-            return (err, Vec::new());
+            return (err, Vec::new(), Vec::new());
         }
 
         self.suggest_self_or_self_ref(&mut err, path, span);
@@ -457,7 +459,7 @@ pub(crate) fn smart_resolve_report_errors(
         if self.suggest_self_ty(&mut err, source, path, span)
             || self.suggest_self_value(&mut err, source, path, span)
         {
-            return (err, Vec::new());
+            return (err, Vec::new(), Vec::new());
         }
 
         let (found, mut candidates) = self.try_lookup_name_relaxed(
@@ -470,7 +472,7 @@ pub(crate) fn smart_resolve_report_errors(
             &base_error,
         );
         if found {
-            return (err, candidates);
+            return (err, candidates, Vec::new());
         }
 
         if self.suggest_shadowed(&mut err, source, path, following_seg, span) {
@@ -479,7 +481,21 @@ pub(crate) fn smart_resolve_report_errors(
         }
 
         let mut fallback = self.suggest_trait_and_bounds(&mut err, source, res, span, &base_error);
-        fallback |= self.suggest_typo(&mut err, source, path, following_seg, span, &base_error);
+        let no_typo = self.suggest_typo(&mut err, source, path, following_seg, span, &base_error);
+        fallback |= no_typo;
+
+        // Without anything better to suggest, look for a similarly named item in another crate.
+        let similar_candidates = if let [segment] = path
+            && no_typo
+            && res.is_none()
+            && candidates.is_empty()
+        {
+            self.r.lookup_similar_import_candidates(segment.ident, source.namespace(), |res| {
+                source.is_expected(res)
+            })
+        } else {
+            Vec::new()
+        };
 
         if fallback {
             // Fallback label.
@@ -491,7 +507,7 @@ pub(crate) fn smart_resolve_report_errors(
             self.r.find_cfg_stripped(&mut err, &path.last().unwrap().ident.name, module);
         }
 
-        (err, candidates)
+        (err, candidates, similar_candidates)
     }
 
     fn detect_assoc_type_constraint_meant_as_path(
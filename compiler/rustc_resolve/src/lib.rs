@@ -736,6 +736,8 @@ struct UseError<'a> {
     err: DiagnosticBuilder<'a>,
     /// Candidates which user could `use` to access the missing type.
     candidates: Vec<ImportSuggestion>,
+    /// Similarly named items of other crates, suggested when there are no `candidates`.
+    similar_candidates: Vec<ImportSuggestion>,
     /// The `DefId` of the module to place the use-statements in.
     def_id: DefId,
     /// Whether the diagnostic should say "instead" (as in `consider importing ... instead`).
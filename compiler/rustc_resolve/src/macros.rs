@@ -600,6 +600,10 @@ fn smart_resolve_macro_path(
             && path.segments.len() >= 2
             && path.segments[0].ident.name == sym::diagnostic
             && path.segments[1].ident.name != sym::on_unimplemented
+            && !(path.segments[1].ident.name == sym::do_not_recommend
+                && self.tcx.features().do_not_recommend)
+            && !(path.segments[1].ident.name == sym::blocking
+                && self.tcx.features().diagnostic_blocking)
         {
             self.tcx.sess.parse_sess.buffer_lint(
                 UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES,
@@ -4,6 +4,7 @@
 use rustc_span::Symbol;
 use rustc_target::abi::{Align, Size};
 use std::cmp;
+use std::time::Duration;
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct VariantInfo {
@@ -63,6 +64,9 @@ pub struct TypeSizeInfo {
     pub overall_size: u64,
     pub packed: bool,
     pub opt_discr_size: Option<u64>,
+    /// For coroutines, the size of the upvars which are only stored in the
+    /// unresumed state, overlapping with the fields of the other states.
+    pub overlapping_upvars_size: Option<u64>,
     pub variants: Vec<VariantInfo>,
 }
 
@@ -85,10 +89,83 @@ pub struct VTableSizeInfo {
     pub upcasting_cost_percent: f64,
 }
 
+/// The niche decisions made for the layout of an ADT, as printed by
+/// `-Zprint-niche-decisions`.
+#[derive(PartialEq, Eq, Hash, Debug)]
+pub struct NicheDecisionInfo {
+    pub type_description: String,
+    /// How the variants of an enum are told apart, and why. `None` for
+    /// structs and unions.
+    pub tag: Option<String>,
+    /// The niche the type exposes to enclosing types, if any, and why.
+    pub niche: String,
+}
+
+/// The trait solver caches tracked by `-Zselection-cache-stats`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum SolverCacheKind {
+    /// The selection cache of the old solver, both global and per inference context.
+    Selection,
+    /// The evaluation cache of the old solver, both global and per inference context.
+    Evaluation,
+    /// The global cache of the new solver.
+    NextSolverGlobal,
+    /// The provisional cache used by the new solver while handling cycles.
+    NextSolverProvisional,
+}
+
+impl SolverCacheKind {
+    const ALL: [SolverCacheKind; 4] = [
+        SolverCacheKind::Selection,
+        SolverCacheKind::Evaluation,
+        SolverCacheKind::NextSolverGlobal,
+        SolverCacheKind::NextSolverProvisional,
+    ];
+
+    fn descr(self) -> &'static str {
+        match self {
+            SolverCacheKind::Selection => "old solver selection cache",
+            SolverCacheKind::Evaluation => "old solver evaluation cache",
+            SolverCacheKind::NextSolverGlobal => "new solver global cache",
+            SolverCacheKind::NextSolverProvisional => "new solver provisional cache",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Default, Debug)]
+struct SolverCacheCounts {
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+/// An obligation which has been evaluated without hitting the cache.
+#[derive(Clone, Debug)]
+pub struct SolverObligationInfo {
+    pub next_solver: bool,
+    pub obligation: String,
+    pub time: Duration,
+    pub depth: usize,
+}
+
+/// How many of the most expensive obligations are printed by `-Zselection-cache-stats`.
+const SOLVER_STATS_TOP_N: usize = 10;
+
+#[derive(Default)]
+struct SolverStats {
+    caches: FxHashMap<SolverCacheKind, SolverCacheCounts>,
+    /// The `SOLVER_STATS_TOP_N` slowest obligations, sorted by decreasing time.
+    slowest: Vec<SolverObligationInfo>,
+    /// The `SOLVER_STATS_TOP_N` deepest obligations, sorted by decreasing depth.
+    deepest: Vec<SolverObligationInfo>,
+}
+
 #[derive(Default)]
 pub struct CodeStats {
     type_sizes: Lock<FxHashSet<TypeSizeInfo>>,
     vtable_sizes: Lock<FxHashMap<DefId, VTableSizeInfo>>,
+    niche_decisions: Lock<FxHashSet<NicheDecisionInfo>>,
+    solver_stats: Lock<SolverStats>,
 }
 
 impl CodeStats {
@@ -100,6 +177,7 @@ pub fn record_type_size<S: ToString>(
         overall_size: Size,
         packed: bool,
         opt_discr_size: Option<Size>,
+        overlapping_upvars_size: Option<Size>,
         mut variants: Vec<VariantInfo>,
     ) {
         // Sort variants so the largest ones are shown first. A stable sort is
@@ -117,6 +195,7 @@ pub fn record_type_size<S: ToString>(
             overall_size: overall_size.bytes(),
             packed,
             opt_discr_size: opt_discr_size.map(|s| s.bytes()),
+            overlapping_upvars_size: overlapping_upvars_size.map(|s| s.bytes()),
             variants,
         };
         self.type_sizes.borrow_mut().insert(info);
@@ -130,6 +209,70 @@ pub fn record_vtable_size(&self, trait_did: DefId, trait_name: &str, info: VTabl
         );
     }
 
+    pub fn record_niche_decision(&self, info: NicheDecisionInfo) {
+        self.niche_decisions.borrow_mut().insert(info);
+    }
+
+    pub fn record_solver_cache_lookup(&self, cache: SolverCacheKind, hit: bool) {
+        let mut stats = self.solver_stats.lock();
+        let counts = stats.caches.entry(cache).or_default();
+        if hit {
+            counts.hits += 1;
+        } else {
+            counts.misses += 1;
+        }
+    }
+
+    pub fn record_solver_cache_evictions(&self, cache: SolverCacheKind, count: usize) {
+        self.solver_stats.lock().caches.entry(cache).or_default().evictions += count as u64;
+    }
+
+    pub fn record_solver_obligation(&self, info: SolverObligationInfo) {
+        fn insert_top_n<K: Ord>(
+            infos: &mut Vec<SolverObligationInfo>,
+            info: &SolverObligationInfo,
+            key: impl Fn(&SolverObligationInfo) -> K,
+        ) {
+            let idx = infos.partition_point(|other| key(other) >= key(info));
+            if idx < SOLVER_STATS_TOP_N {
+                infos.insert(idx, info.clone());
+                infos.truncate(SOLVER_STATS_TOP_N);
+            }
+        }
+
+        let mut stats = self.solver_stats.lock();
+        insert_top_n(&mut stats.slowest, &info, |info| info.time);
+        insert_top_n(&mut stats.deepest, &info, |info| info.depth);
+    }
+
+    pub fn print_solver_stats(&self) {
+        let stats = self.solver_stats.lock();
+        for cache in SolverCacheKind::ALL {
+            let SolverCacheCounts { hits, misses, evictions } =
+                stats.caches.get(&cache).copied().unwrap_or_default();
+            let lookups = hits + misses;
+            let hit_rate = if lookups == 0 { 0.0 } else { hits as f64 / lookups as f64 * 100.0 };
+            eprintln!(
+                "selection-cache-stats: {}: {hits} hits, {misses} misses ({hit_rate:.1}% hit rate), \
+                {evictions} evictions",
+                cache.descr(),
+            );
+        }
+
+        let print_obligations = |title: &str, infos: &[SolverObligationInfo]| {
+            eprintln!("selection-cache-stats: {title}:");
+            for SolverObligationInfo { next_solver, obligation, time, depth } in infos {
+                let solver = if *next_solver { "new" } else { "old" };
+                eprintln!(
+                    "selection-cache-stats:     {time:>12?}  depth {depth:>3}  ({solver} solver) \
+                    `{obligation}`"
+                );
+            }
+        };
+        print_obligations("slowest obligations", &stats.slowest);
+        print_obligations("deepest obligations", &stats.deepest);
+    }
+
     pub fn print_type_sizes(&self) {
         let type_sizes = self.type_sizes.borrow();
         // We will soon sort, so the initial order does not matter.
@@ -154,6 +297,14 @@ pub fn print_type_sizes(&self) {
                 0
             };
 
+            if let Some(overlapping_size) = info.overlapping_upvars_size
+                && overlapping_size > 0
+            {
+                println!(
+                    "print-type-size {indent}upvars overlapping other states: {overlapping_size} bytes"
+                );
+            }
+
             // We start this at discr_size (rather than 0) because
             // things like C-enums do not have variants but we still
             // want the max_variant_size at the end of the loop below
@@ -256,4 +407,20 @@ pub fn print_vtable_sizes(&self, crate_name: Symbol) {
             );
         }
     }
+
+    pub fn print_niche_decisions(&self) {
+        let niche_decisions = self.niche_decisions.borrow();
+        // We will soon sort, so the initial order does not matter.
+        #[allow(rustc::potential_query_instability)]
+        let mut sorted: Vec<_> = niche_decisions.iter().collect();
+        sorted.sort_by_key(|info| &info.type_description);
+
+        for NicheDecisionInfo { type_description, tag, niche } in sorted {
+            println!("print-niche-decision type: `{type_description}`");
+            if let Some(tag) = tag {
+                println!("print-niche-decision     tag: {tag}");
+            }
+            println!("print-niche-decision     niche: {niche}");
+        }
+    }
 }
@@ -159,6 +159,18 @@ pub enum InstrumentCoverage {
     Off,
 }
 
+/// Settings for `-Z coverage-options` flag.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CoverageOptions {
+    /// `-Z coverage-options=branch`, instrument the true and false arms of
+    /// each branch condition.
+    pub branch: bool,
+    /// `-Z coverage-options=mcdc`, additionally instrument the conditions of
+    /// each boolean decision for modified condition/decision coverage.
+    /// Implies `branch`.
+    pub mcdc: bool,
+}
+
 /// Settings for `-Z instrument-xray` flag.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub struct InstrumentXRay {
@@ -526,6 +538,10 @@ pub enum ErrorOutputType {
         /// human output.
         json_rendered: HumanReadableErrorType,
     },
+    /// A SARIF log, for the consumption of code scanning tools.
+    Sarif,
+    /// GitHub Actions workflow commands, which annotate the code diagnostics point at.
+    Github,
 }
 
 impl Default for ErrorOutputType {
@@ -1135,6 +1151,7 @@ pub fn dcx_flags(&self, can_emit_warnings: bool) -> DiagCtxtFlags {
             eagerly_emit_delayed_bugs: self.eagerly_emit_delayed_bugs,
             macro_backtrace: self.macro_backtrace,
             deduplicate_diagnostics: self.deduplicate_diagnostics,
+            collapse_cascaded_errors: !self.show_cascaded_errors,
             track_diagnostics: self.track_diagnostics,
         }
     }
@@ -1966,6 +1983,8 @@ pub fn parse_error_format(
             }
             Some("json") => ErrorOutputType::Json { pretty: false, json_rendered },
             Some("pretty-json") => ErrorOutputType::Json { pretty: true, json_rendered },
+            Some("sarif") => ErrorOutputType::Sarif,
+            Some("github") => ErrorOutputType::Github,
             Some("short") => ErrorOutputType::HumanReadable(HumanReadableErrorType::Short(color)),
 
             Some(arg) => {
@@ -2038,6 +2057,12 @@ fn check_error_format_stability(
         {
             early_dcx.early_fatal("`--error-format=human-annotate-rs` is unstable");
         }
+        if let ErrorOutputType::Sarif = error_format {
+            early_dcx.early_fatal("`--error-format=sarif` is unstable");
+        }
+        if let ErrorOutputType::Github = error_format {
+            early_dcx.early_fatal("`--error-format=github` is unstable");
+        }
     }
 }
 
@@ -2684,6 +2709,10 @@ pub fn build_session_options(early_dcx: &mut EarlyDiagCtxt, matches: &getopts::M
         early_dcx.early_fatal("options `-C profile-generate` and `-C profile-use` are exclusive");
     }
 
+    if unstable_opts.inline_mir_profile_threshold.is_some() && cg.profile_use.is_none() {
+        early_dcx.early_fatal("option `-Z inline-mir-profile-threshold` requires `-C profile-use`");
+    }
+
     if unstable_opts.profile_sample_use.is_some()
         && (cg.profile_generate.enabled() || cg.profile_use.is_some())
     {
@@ -3209,12 +3238,13 @@ pub enum WasiExecModel {
 /// how the hash should be calculated when adding a new command-line argument.
 pub(crate) mod dep_tracking {
     use super::{
-        BranchProtection, CFGuard, CFProtection, CollapseMacroDebuginfo, CrateType, DebugInfo,
-        DebugInfoCompression, ErrorOutputType, FunctionReturn, InliningThreshold,
-        InstrumentCoverage, InstrumentXRay, LinkerPluginLto, LocationDetail, LtoCli,
-        NextSolverConfig, OomStrategy, OptLevel, OutFileName, OutputType, OutputTypes, Polonius,
-        RemapPathScopeComponents, ResolveDocLinks, SourceFileHashAlgorithm, SplitDwarfKind,
-        SwitchWithOptPath, SymbolManglingVersion, WasiExecModel,
+        BranchProtection, CFGuard, CFProtection, CandidatePreference, CollapseMacroDebuginfo,
+        ConstEvalBacktrace, CoverageOptions, CrateType, DebugInfo, DebugInfoCompression,
+        ErrorOutputType, FunctionReturn, InliningThreshold, InstrumentCoverage, InstrumentXRay,
+        LinkerPluginLto, LocationDetail, LtoCli, NextSolverConfig, OomStrategy, OptLevel,
+        OutFileName, OutputType, OutputTypes, Polonius, RemapPathScopeComponents, ResolveDocLinks,
+        SourceFileHashAlgorithm, SplitDwarfKind, SwitchWithOptPath, SymbolManglingVersion,
+        WasiExecModel,
     };
     use crate::lint;
     use crate::utils::NativeLib;
@@ -3284,6 +3314,7 @@ fn hash(
         CodeModel,
         TlsModel,
         InstrumentCoverage,
+        CoverageOptions,
         InstrumentXRay,
         CrateType,
         MergeFunctions,
@@ -3294,6 +3325,7 @@ fn hash(
         DebugInfo,
         DebugInfoCompression,
         CollapseMacroDebuginfo,
+        ConstEvalBacktrace,
         UnstableFeatures,
         NativeLib,
         SanitizerSet,
@@ -3321,6 +3353,7 @@ fn hash(
         Polonius,
         InliningThreshold,
         FunctionReturn,
+        CandidatePreference,
     );
 
     impl<T1, T2> DepTrackingHash for (T1, T2)
@@ -3476,6 +3509,46 @@ pub enum CollapseMacroDebuginfo {
     Yes = 3,
 }
 
+/// `-Z borrowck-graphviz-errors` values, selecting how the outlives-constraint path behind a
+/// region error is rendered.
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum ConstraintPathFormat {
+    /// A graphviz `digraph` of the constraints on the path, labelled with their spans.
+    Graphviz,
+    /// One note per constraint on the path, pointing at the span it originates from.
+    Text,
+}
+
+/// `-Z const-eval-backtrace` values, controlling how much of the evaluation backtrace is shown
+/// in const-eval errors.
+#[derive(Clone, Copy, PartialEq, Hash, Debug, Default)]
+pub enum ConstEvalBacktrace {
+    /// Hide the frames of `#[track_caller]` functions, collapse repeated frames, and don't show
+    /// a backtrace made of a single frame.
+    #[default]
+    Short,
+    /// Show every frame.
+    Full,
+}
+
+/// `-Z const-eval-ub-report` values, selecting the format of the report added to const-eval
+/// errors about invalid values.
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum ConstEvalUbReport {
+    /// Show the bytes around the invalid value, with the invalid bytes marked.
+    Human,
+    /// Emit the report as a JSON object.
+    Json,
+}
+
+/// `-Z dump-borrowck` values, selecting the format the region inference context of each body
+/// is dumped in.
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum DumpBorrowckFormat {
+    /// One JSON document per body, meant to be consumed by external tools.
+    Json,
+}
+
 /// Which format to use for `-Z dump-mono-stats`
 #[derive(Clone, Copy, PartialEq, Hash, Debug)]
 pub enum DumpMonoStatsFormat {
@@ -3521,6 +3594,34 @@ pub fn is_next_enabled(&self) -> bool {
     }
 }
 
+/// `-Zvalidate-mir` values.
+#[derive(Clone, Copy, PartialEq, Hash, Debug, Default)]
+pub enum MirValidation {
+    /// The default value: MIR is not validated.
+    #[default]
+    Off,
+
+    /// Check the structural invariants of MIR after each pass. Historical value for
+    /// `-Zvalidate-mir`.
+    Basic,
+
+    /// Also run dataflow analyses, to check that no statement reads a local which is definitely
+    /// uninitialized, or uses a local which definitely has no storage.
+    Full,
+}
+
+impl MirValidation {
+    /// Returns whether MIR is validated after each pass.
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, MirValidation::Off)
+    }
+
+    /// Returns whether the dataflow-sensitive invariants are checked.
+    pub fn is_full(&self) -> bool {
+        matches!(self, MirValidation::Full)
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Hash, Debug)]
 pub enum InliningThreshold {
     Always,
@@ -3534,6 +3635,23 @@ fn default() -> Self {
     }
 }
 
+/// The different settings that the `-Zcandidate-preference` flag can have.
+///
+/// These change how the old trait solver winnows multiple applicable candidates
+/// and only exist to experiment with alternative preference rules.
+#[derive(Clone, Copy, PartialEq, Hash, Debug, Default)]
+pub enum CandidatePreference {
+    /// The preference rules used by default.
+    #[default]
+    Default,
+
+    /// Always prefer where-clauses over other candidates, even if they are global.
+    WhereClause,
+
+    /// Never prefer builtin candidates without nested obligations over where-clauses.
+    NoBuiltinOverParam,
+}
+
 /// The different settings that the `-Zfunction-return` flag can have.
 #[derive(Clone, Copy, PartialEq, Hash, Debug, Default)]
 pub enum FunctionReturn {
@@ -392,9 +392,14 @@ mod desc {
     pub const parse_strip: &str = "either `none`, `debuginfo`, or `symbols`";
     pub const parse_linker_flavor: &str = ::rustc_target::spec::LinkerFlavorCli::one_of();
     pub const parse_optimization_fuel: &str = "crate=integer";
+    pub const parse_constraint_path_format: &str = "either `graphviz` (default) or `text`";
+    pub const parse_const_eval_backtrace: &str = "either `short` (default) or `full`";
+    pub const parse_const_eval_ub_report: &str = "either `human` (default) or `json`";
+    pub const parse_dump_borrowck: &str = "`json`";
     pub const parse_dump_mono_stats: &str = "`markdown` (default) or `json`";
     pub const parse_instrument_coverage: &str =
         "`all` (default), `branch`, `except-unused-generics`, `except-unused-functions`, or `off`";
+    pub const parse_coverage_options: &str = "a comma-separated list of `branch` or `mcdc`";
     pub const parse_instrument_xray: &str = "either a boolean (`yes`, `no`, `on`, `off`, etc), or a comma separated list of settings: `always` or `never` (mutually exclusive), `ignore-loops`, `instruction-threshold=N`, `skip-entry`, `skip-exit`";
     pub const parse_unpretty: &str = "`string` or `string=string`";
     pub const parse_treat_err_as_bug: &str = "either no value or a non-negative number";
@@ -436,6 +441,10 @@ mod desc {
         "either a boolean (`yes`, `no`, `on`, `off`, etc), or a non-negative number";
     pub const parse_llvm_module_flag: &str = "<key>:<type>:<value>:<behavior>. Type must currently be `u32`. Behavior should be one of (`error`, `warning`, `require`, `override`, `append`, `appendunique`, `max`, `min`)";
     pub const parse_function_return: &str = "`keep` or `thunk-extern`";
+    pub const parse_candidate_preference: &str =
+        "one of: `default`, `where-clause`, or `no-builtin-over-param`";
+    pub const parse_mir_validation: &str =
+        "either a boolean (`yes`, `no`, `on`, `off`, etc), or `full`";
 }
 
 mod parse {
@@ -882,6 +891,53 @@ pub(crate) fn parse_time_passes_format(slot: &mut TimePassesFormat, v: Option<&s
         }
     }
 
+    pub(crate) fn parse_constraint_path_format(
+        slot: &mut Option<ConstraintPathFormat>,
+        v: Option<&str>,
+    ) -> bool {
+        *slot = match v {
+            None | Some("graphviz") => Some(ConstraintPathFormat::Graphviz),
+            Some("text") => Some(ConstraintPathFormat::Text),
+            _ => return false,
+        };
+        true
+    }
+
+    pub(crate) fn parse_const_eval_backtrace(
+        slot: &mut ConstEvalBacktrace,
+        v: Option<&str>,
+    ) -> bool {
+        *slot = match v {
+            Some("short") => ConstEvalBacktrace::Short,
+            Some("full") => ConstEvalBacktrace::Full,
+            _ => return false,
+        };
+        true
+    }
+
+    pub(crate) fn parse_const_eval_ub_report(
+        slot: &mut Option<ConstEvalUbReport>,
+        v: Option<&str>,
+    ) -> bool {
+        *slot = match v {
+            None | Some("human") => Some(ConstEvalUbReport::Human),
+            Some("json") => Some(ConstEvalUbReport::Json),
+            _ => return false,
+        };
+        true
+    }
+
+    pub(crate) fn parse_dump_borrowck(
+        slot: &mut Option<DumpBorrowckFormat>,
+        v: Option<&str>,
+    ) -> bool {
+        *slot = match v {
+            Some("json") => Some(DumpBorrowckFormat::Json),
+            _ => return false,
+        };
+        true
+    }
+
     pub(crate) fn parse_dump_mono_stats(slot: &mut DumpMonoStatsFormat, v: Option<&str>) -> bool {
         match v {
             None => true,
@@ -929,6 +985,22 @@ pub(crate) fn parse_instrument_coverage(
         true
     }
 
+    pub(crate) fn parse_coverage_options(slot: &mut CoverageOptions, v: Option<&str>) -> bool {
+        let Some(v) = v else { return true };
+
+        for option in v.split(',') {
+            match option {
+                "branch" => slot.branch = true,
+                "mcdc" => {
+                    slot.branch = true;
+                    slot.mcdc = true;
+                }
+                _ => return false,
+            }
+        }
+        true
+    }
+
     pub(crate) fn parse_instrument_xray(
         slot: &mut Option<InstrumentXRay>,
         v: Option<&str>,
@@ -1388,6 +1460,33 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         }
         true
     }
+
+    pub(crate) fn parse_candidate_preference(
+        slot: &mut CandidatePreference,
+        v: Option<&str>,
+    ) -> bool {
+        match v {
+            Some("default") => *slot = CandidatePreference::Default,
+            Some("where-clause") => *slot = CandidatePreference::WhereClause,
+            Some("no-builtin-over-param") => *slot = CandidatePreference::NoBuiltinOverParam,
+            _ => return false,
+        }
+        true
+    }
+
+    pub(crate) fn parse_mir_validation(slot: &mut MirValidation, v: Option<&str>) -> bool {
+        if v == Some("full") {
+            *slot = MirValidation::Full;
+            return true;
+        }
+
+        let mut bool_arg = false;
+        if !parse_bool(&mut bool_arg, v) {
+            return false;
+        }
+        *slot = if bool_arg { MirValidation::Basic } else { MirValidation::Off };
+        true
+    }
 }
 
 options! {
@@ -1545,10 +1644,21 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
     binary_dep_depinfo: bool = (false, parse_bool, [TRACKED],
         "include artifacts (sysroot, crate dependencies) used during compilation in dep-info \
         (default: no)"),
+    borrowck_graphviz_errors: Option<ConstraintPathFormat> = (None,
+        parse_constraint_path_format, [UNTRACKED],
+        "attach the outlives-constraint path behind borrowck region errors to them, \
+        rendered as `graphviz` (default) or `text`"),
+    borrowck_skip_trivial_bodies: bool = (true, parse_bool, [UNTRACKED],
+        "skip the dataflow analyses and region inference of bodies without borrows, drops or \
+        generics, that the borrow checker trivially accepts (default: yes)"),
     box_noalias: bool = (true, parse_bool, [TRACKED],
         "emit noalias metadata for box (default: yes)"),
     branch_protection: Option<BranchProtection> = (None, parse_branch_protection, [TRACKED],
         "set options for branch target identification and pointer authentication on AArch64"),
+    candidate_preference: CandidatePreference = (CandidatePreference::default(),
+        parse_candidate_preference, [TRACKED],
+        "change how the old trait solver prefers between multiple applicable candidates \
+        (`default`, `where-clause`, or `no-builtin-over-param`) (default: `default`)"),
     cf_protection: CFProtection = (CFProtection::None, parse_cfprotection, [TRACKED],
         "instrument control-flow architecture protection"),
     codegen_backend: Option<String> = (None, parse_opt_string, [TRACKED],
@@ -1558,8 +1668,26 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         "set option to collapse debuginfo for macros"),
     combine_cgu: bool = (false, parse_bool, [TRACKED],
         "combine CGUs into a single one"),
+    const_eval_backtrace: ConstEvalBacktrace = (ConstEvalBacktrace::Short,
+        parse_const_eval_backtrace, [TRACKED],
+        "how much of the evaluation backtrace to show in const-eval errors, like `RUST_BACKTRACE` \
+        (`short` (default) or `full`)"),
+    const_eval_memory_limit: Option<u64> = (None, parse_opt_number, [TRACKED],
+        "abort the compile-time evaluation of an item with an error once its live allocations \
+        exceed this many bytes (default: no limit)"),
+    const_eval_step_limit: Option<usize> = (None, parse_opt_number, [TRACKED],
+        "abort the compile-time evaluation of an item with an error once it has taken this many \
+        steps, i.e. loop iterations and function calls (default: no limit)"),
+    const_eval_ub_report: Option<ConstEvalUbReport> = (None, parse_const_eval_ub_report, [UNTRACKED],
+        "when const-eval finds an invalid value, also report the memory around it and the path \
+        to it (`human` (default) or `json`)"),
+    coverage_options: CoverageOptions = (CoverageOptions::default(), parse_coverage_options, [TRACKED],
+        "control details of coverage instrumentation"),
     crate_attr: Vec<String> = (Vec::new(), parse_string_push, [TRACKED],
         "inject the given attribute in the crate"),
+    cross_crate_dead_code: Option<PathBuf> = (None, parse_opt_pathbuf, [TRACKED],
+        "record which items of other crates are used, and report the public items \
+        of private modules that no crate using the given manifest directory uses (default: no)"),
     cross_crate_inline_threshold: InliningThreshold = (InliningThreshold::Sometimes(100), parse_inlining_threshold, [TRACKED],
         "threshold to allow cross crate inlining of functions"),
     debug_info_for_profiling: bool = (false, parse_bool, [TRACKED],
@@ -1569,19 +1697,45 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
     debuginfo_compression: DebugInfoCompression = (DebugInfoCompression::None, parse_debuginfo_compression, [TRACKED],
         "compress debug info sections (none, zlib, zstd, default: none)"),
     deduplicate_diagnostics: bool = (true, parse_bool, [UNTRACKED],
-        "deduplicate identical diagnostics (default: yes)"),
+        "deduplicate identical diagnostics, including those only differing by the macro \
+        expansion they come from (default: yes)"),
+    dedup_mono_items: bool = (false, parse_bool, [TRACKED],
+        "merge monomorphizations whose instantiated MIR and layouts are identical into a single \
+        definition, emitting aliases for the others (default: no)"),
     default_hidden_visibility: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "overrides the `default_hidden_visibility` setting of the target"),
     dep_info_omit_d_target: bool = (false, parse_bool, [TRACKED],
         "in dep-info output, omit targets for tracking dependencies of the dep-info files \
         themselves (default: no)"),
+    diagnostic_theme: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
+        "use the colors of the given TOML theme file for the human readable diagnostics, \
+        instead of the one named by `RUSTC_COLOR_THEME`"),
+    diagnostic_type_aliases: bool = (true, parse_bool, [UNTRACKED],
+        "refer to long types mentioned several times by a diagnostic with a short alias, \
+        spelled out once in a note (default: yes)"),
     direct_access_external_data: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "Direct or use GOT indirect to reference external data symbols"),
     dual_proc_macros: bool = (false, parse_bool, [TRACKED],
         "load proc macros for both target and host, but only link to the target (default: no)"),
+    dump_borrowck: Option<DumpBorrowckFormat> = (None, parse_dump_borrowck, [UNTRACKED],
+        "dump the region variables, outlives constraints and inferred region values of each \
+        borrow-checked body into `-Z dump-borrowck-dir` (`json`)"),
+    dump_borrowck_dir: String = ("borrowck_dump".to_string(), parse_string, [UNTRACKED],
+        "the directory the region inference contexts are dumped into (default: `borrowck_dump`)"),
+    dump_closure_captures: SwitchWithOptPath = (SwitchWithOptPath::Disabled,
+        parse_switch_with_opt_path, [UNTRACKED],
+        "output the places captured by every closure and coroutine, and the uses causing each \
+        capture, as JSON"),
     dump_dep_graph: bool = (false, parse_bool, [UNTRACKED],
         "dump the dependency graph to $RUST_DEP_GRAPH (default: /tmp/dep_graph.gv) \
         (default: no)"),
+    dump_drop_order: SwitchWithOptPath = (SwitchWithOptPath::Disabled,
+        parse_switch_with_opt_path, [UNTRACKED],
+        "output the values dropped at the end of each scope of every body, in the order they \
+        are dropped, as JSON"),
+    dump_intercrate_ambiguities: SwitchWithOptPath = (SwitchWithOptPath::Disabled,
+        parse_switch_with_opt_path, [UNTRACKED],
+        "output the intercrate ambiguity causes of every pair of overlapping impls as JSON"),
     dump_mir: Option<String> = (None, parse_opt_string, [UNTRACKED],
         "dump MIR state to file.
         `val` is used to select which passes and functions to dump. For example:
@@ -1603,6 +1757,9 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         "output statistics about monomorphization collection"),
     dump_mono_stats_format: DumpMonoStatsFormat = (DumpMonoStatsFormat::Markdown, parse_dump_mono_stats, [UNTRACKED],
         "the format to use for -Z dump-mono-stats (`markdown` (default) or `json`)"),
+    dump_vtable_layouts: SwitchWithOptPath = (SwitchWithOptPath::Disabled,
+        parse_switch_with_opt_path, [UNTRACKED],
+        "output the layout of every vtable built by the crate as JSON"),
     dwarf_version: Option<u32> = (None, parse_opt_number, [TRACKED],
         "version of DWARF debug information to emit (default: 2 or 4, depending on platform)"),
     dylib_lto: bool = (false, parse_bool, [UNTRACKED],
@@ -1616,6 +1773,12 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         "emit a section containing stack size metadata (default: no)"),
     emit_thin_lto: bool = (true, parse_bool, [TRACKED],
         "emit the bc module with thin LTO info (default: yes)"),
+    explain_obligation: bool = (false, parse_bool, [UNTRACKED],
+        "explain which impls were considered for unsatisfied trait obligations \
+        and which of their where-clauses do not hold (default: no)"),
+    explain_promotion: bool = (false, parse_bool, [UNTRACKED],
+        "explain which borrows are promoted to constants, and why the others are not \
+        (default: no)"),
     export_executable_symbols: bool = (false, parse_bool, [TRACKED],
         "export symbols from executables, as if they were dynamic libraries"),
     extra_const_ub_checks: bool = (false, parse_bool, [TRACKED],
@@ -1637,6 +1800,8 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         "whether each function should go in its own section"),
     future_incompat_test: bool = (false, parse_bool, [UNTRACKED],
         "forces all lints to be future incompatible, used for internal testing (default: no)"),
+    future_size_limit: Option<usize> = (None, parse_opt_number, [TRACKED],
+        "the size at which the `large_future` lint starts to be emitted (default: 16384)"),
     graphviz_dark_mode: bool = (false, parse_bool, [UNTRACKED],
         "use dark-themed colors in graphviz output (default: no)"),
     graphviz_font: String = ("Courier, monospace".to_string(), parse_string, [UNTRACKED],
@@ -1667,6 +1832,9 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         "enable MIR inlining (default: no)"),
     inline_mir_hint_threshold: Option<usize> = (None, parse_opt_number, [TRACKED],
         "inlining threshold for functions with inline hint (default: 100)"),
+    inline_mir_profile_threshold: Option<u64> = (None, parse_opt_number, [TRACKED],
+        "use the `-C profile-use` data to only MIR-inline call sites executed at least this many \
+        times, favoring them over the size heuristics (default: no)"),
     inline_mir_threshold: Option<usize> = (None, parse_opt_number, [TRACKED],
         "a default MIR inlining threshold (default: 50)"),
     input_stats: bool = (false, parse_bool, [UNTRACKED],
@@ -1683,16 +1851,23 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
          `=skip-entry`
          `=skip-exit`
          Multiple options can be combined with commas."),
+    json_explanations: bool = (false, parse_bool, [UNTRACKED],
+        "embed the long-form explanation of error codes, and the extended help `-Z teach` \
+        would add, in JSON diagnostics (default: no)"),
     layout_seed: Option<u64> = (None, parse_opt_number, [TRACKED],
         "seed layout randomization"),
+    layout_tables: bool = (false, parse_bool, [UNTRACKED],
+        "annotate the errors about too big types and unaligned packed fields with the layout \
+        of the fields of the type involved (default: no)"),
     link_directives: bool = (true, parse_bool, [TRACKED],
         "honor #[link] directives in the compiled crate (default: yes)"),
     link_native_libraries: bool = (true, parse_bool, [UNTRACKED],
         "link native libraries in the linker invocation (default: yes)"),
     link_only: bool = (false, parse_bool, [TRACKED],
         "link the `.rlink` file generated by `-Z no-link` (default: no)"),
-    lint_mir: bool = (false, parse_bool, [UNTRACKED],
-        "lint MIR before and after each transformation"),
+    lint_mir: bool = (false, parse_bool, [TRACKED],
+        "lint MIR before and after each transformation, and look through the MIR of callees \
+        in the `blocking_in_async` lint (default: no)"),
     llvm_module_flag: Vec<(String, u32, String)> = (Vec::new(), parse_llvm_module_flag, [TRACKED],
         "a list of module flags to pass to LLVM (space separated)"),
     llvm_plugins: Vec<String> = (Vec::new(), parse_list, [TRACKED],
@@ -1729,9 +1904,20 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
     mir_keep_place_mention: bool = (false, parse_bool, [TRACKED],
         "keep place mention MIR statements, interpreted e.g., by miri; implies -Zmir-opt-level=0 \
         (default: no)"),
+    mir_only_rlibs: bool = (false, parse_bool, [TRACKED],
+        "only emit the optimized MIR of the functions of rlibs and no machine code, leaving \
+        their monomorphization and codegen to the crates using them (default: no)"),
+    mir_outline_panics: bool = (false, parse_bool, [TRACKED],
+        "move the construction of the `fmt::Arguments` of panics out of the panicking function, \
+        into shared cold functions of `core` (default: no)"),
     #[rustc_lint_opt_deny_field_access("use `Session::mir_opt_level` instead of this field")]
     mir_opt_level: Option<usize> = (None, parse_opt_number, [TRACKED],
         "MIR optimization level (0-4; default: 1 in non optimized builds and 2 in optimized builds)"),
+    mir_passes: Option<Vec<String>> = (None, parse_opt_comma_list, [TRACKED],
+        "use like `-Zmir-passes=Inline,GVN,AddCallGuards`. Replaces the optimizations of \
+        `optimized_mir` with the specified passes, in the specified order. The passes still \
+        need to be enabled, and the ones establishing what the others require run as needed \
+        (default: the usual optimizations)"),
     move_size_limit: Option<usize> = (None, parse_opt_number, [TRACKED],
         "the size at which the `large_assignments` lint starts to be emitted"),
     mutable_noalias: bool = (true, parse_bool, [TRACKED],
@@ -1766,6 +1952,8 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         "do not use unique names for text and data sections when -Z function-sections is used"),
     normalize_docs: bool = (false, parse_bool, [TRACKED],
         "normalize associated items in rustdoc when generating documentation"),
+    obligation_fuel: Option<usize> = (None, parse_opt_number, [TRACKED],
+        "abort proving a single root obligation after this many fulfillment steps"),
     oom: OomStrategy = (OomStrategy::Abort, parse_oom_strategy, [TRACKED],
         "panic strategy for out-of-memory handling"),
     osx_rpath_install_name: bool = (false, parse_bool, [TRACKED],
@@ -1794,6 +1982,8 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         "use a more precise version of drop elaboration for matches on enums (default: yes). \
         This results in better codegen, but has caused miscompilations on some tier 2 platforms. \
         See #77382 and #74551."),
+    preview_edition_migrations: Option<u32> = (None, parse_opt_number, [TRACKED],
+        "enable the lints preparing the migration to the edition of the given year as warnings"),
     #[rustc_lint_opt_deny_field_access("use `Session::print_codegen_stats` instead of this field")]
     print_codegen_stats: bool = (false, parse_bool, [UNTRACKED],
         "print codegen statistics (default: no)"),
@@ -1803,6 +1993,8 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         "print the LLVM optimization passes being run (default: no)"),
     print_mono_items: Option<String> = (None, parse_opt_string, [UNTRACKED],
         "print the result of the monomorphization collection pass"),
+    print_niche_decisions: bool = (false, parse_bool, [UNTRACKED],
+        "print which niche, if any, the layout of each ADT encountered uses, and why (default: no)"),
     print_type_sizes: bool = (false, parse_bool, [UNTRACKED],
         "print layout information for each type encountered (default: no)"),
     print_vtable_sizes: bool = (false, parse_bool, [UNTRACKED],
@@ -1853,6 +2045,9 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
     saturating_float_casts: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "make float->int casts UB-free: numbers outside the integer type's range are clipped to \
         the max/min integer respectively, and NaN is mapped to 0 (default: yes)"),
+    selection_cache_stats: bool = (false, parse_bool, [UNTRACKED],
+        "print hit, miss and eviction counts of the trait solver caches and the most expensive \
+        obligations at the end of compilation (default: no)"),
     self_profile: SwitchWithOptPath = (SwitchWithOptPath::Disabled,
         parse_switch_with_opt_path, [UNTRACKED],
         "run the self profiler and output the raw event data"),
@@ -1868,10 +2063,16 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         for example: `-Z self-profile-events=default,query-keys`
         all options: none, all, default, generic-activity, query-provider, query-cache-hit
                      query-blocked, incr-cache-load, incr-result-hashing, query-keys, function-args, args, llvm, artifact-sizes"),
+    share_const_fn_calls: bool = (false, parse_bool, [TRACKED],
+        "evaluate calls of `const fn`s with plain-data arguments once, and share the result \
+        between all constants making the same call (default: no)"),
     share_generics: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "make the current crate share its generic instantiations"),
     shell_argfiles: bool = (false, parse_bool, [UNTRACKED],
         "allow argument files to be specified with POSIX \"shell-style\" argument quoting"),
+    show_cascaded_errors: bool = (false, parse_bool, [UNTRACKED],
+        "show every error caused by the same unresolved name, instead of collapsing the \
+        repeated ones into a count (default: no)"),
     show_span: Option<String> = (None, parse_opt_string, [TRACKED],
         "show spans for compiler debugging (expr|pat|ty)"),
     simulate_remapped_rust_src_base: Option<PathBuf> = (None, parse_opt_pathbuf, [TRACKED],
@@ -1990,8 +2191,9 @@ pub(crate) fn parse_function_return(slot: &mut FunctionReturn, v: Option<&str>)
         "use legacy .ctors section for initializers rather than .init_array"),
     use_sync_unwind: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "Generate sync unwind tables instead of async unwind tables (default: no)"),
-    validate_mir: bool = (false, parse_bool, [UNTRACKED],
-        "validate MIR after each transformation"),
+    validate_mir: MirValidation = (MirValidation::Off, parse_mir_validation, [UNTRACKED],
+        "validate MIR after each transformation; `full` also checks that locals are initialized \
+        and have storage when they are used (default: no)"),
     #[rustc_lint_opt_deny_field_access("use `Session::verbose_internals` instead of this field")]
     verbose_internals: bool = (false, parse_bool, [TRACKED_NO_CRATE_HASH],
         "in general, enable more debug printouts (default: no)"),
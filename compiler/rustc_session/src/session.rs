@@ -18,9 +18,12 @@
     AtomicU64, DynSend, DynSync, Lock, Lrc, MappedReadGuard, ReadGuard, RwLock,
 };
 use rustc_errors::annotate_snippet_emitter_writer::AnnotateSnippetEmitter;
+use rustc_errors::color_theme::ColorTheme;
 use rustc_errors::emitter::{DynEmitter, HumanEmitter, HumanReadableErrorType};
+use rustc_errors::github::GithubEmitter;
 use rustc_errors::json::JsonEmitter;
 use rustc_errors::registry::Registry;
+use rustc_errors::sarif::SarifEmitter;
 use rustc_errors::{
     codes::*, fallback_fluent_bundle, DiagCtxt, DiagnosticBuilder, DiagnosticMessage,
     ErrorGuaranteed, FatalAbort, FluentBundle, IntoDiagnostic, LazyFallbackBundle, TerminalUrl,
@@ -124,6 +127,9 @@ pub struct Limits {
     /// The size at which the `large_assignments` lint starts
     /// being emitted.
     pub move_size_limit: Limit,
+    /// The size at which the `large_future` lint starts
+    /// being emitted.
+    pub future_size_limit: Limit,
     /// The maximum length of types during monomorphization.
     pub type_length_limit: Limit,
 }
@@ -137,6 +143,8 @@ pub struct CompilerIO {
 
 pub trait LintStoreMarker: Any + DynSync + DynSend {}
 
+pub trait MirPassStoreMarker: Any + DynSync + DynSend {}
+
 /// Represents the data associated with a compilation
 /// session for a single crate.
 pub struct Session {
@@ -174,6 +182,9 @@ pub struct Session {
     /// Should be set if any lints are registered in `lint_store`.
     pub registered_lints: bool,
 
+    /// This only ever stores a `MirPassStore` but we don't want a dependency on that type here.
+    pub mir_pass_store: Option<Lrc<dyn MirPassStoreMarker>>,
+
     /// Cap lint level specified by a driver specifically.
     pub driver_lint_caps: FxHashMap<lint::LintId, lint::Level>,
 
@@ -361,6 +372,11 @@ pub fn instrument_coverage(&self) -> bool {
 
     pub fn instrument_coverage_branch(&self) -> bool {
         self.opts.cg.instrument_coverage() == InstrumentCoverage::Branch
+            || (self.instrument_coverage() && self.opts.unstable_opts.coverage_options.branch)
+    }
+
+    pub fn instrument_coverage_mcdc(&self) -> bool {
+        self.instrument_coverage() && self.opts.unstable_opts.coverage_options.mcdc
     }
 
     pub fn instrument_coverage_except_unused_generics(&self) -> bool {
@@ -949,6 +965,29 @@ pub fn should_prefer_remapped_for_split_debuginfo_paths(&self) -> bool {
     }
 }
 
+/// Loads the colors of the human readable diagnostics from the file given to
+/// `-Z diagnostic-theme`, or on nightly from the one named by `RUSTC_COLOR_THEME`.
+fn load_color_theme(early_dcx: &EarlyDiagCtxt, sopts: &config::Options) -> ColorTheme {
+    let path = match &sopts.unstable_opts.diagnostic_theme {
+        Some(path) => path.clone(),
+        None => match env::var_os("RUSTC_COLOR_THEME") {
+            Some(path) if !path.is_empty() && sopts.unstable_features.is_nightly_build() => {
+                PathBuf::from(path)
+            }
+            _ => return ColorTheme::default(),
+        },
+    };
+    std::fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|src| ColorTheme::parse(&src))
+        .unwrap_or_else(|e| {
+            early_dcx.early_fatal(format!(
+                "failed to load the diagnostic theme `{}`: {e}",
+                path.display()
+            ))
+        })
+}
+
 // JUSTIFICATION: part of session construction
 #[allow(rustc::bad_opt_access)]
 fn default_emitter(
@@ -957,6 +996,7 @@ fn default_emitter(
     source_map: Lrc<SourceMap>,
     bundle: Option<Lrc<FluentBundle>>,
     fallback_bundle: LazyFallbackBundle,
+    color_theme: ColorTheme,
 ) -> Box<DynEmitter> {
     let macro_backtrace = sopts.unstable_opts.macro_backtrace;
     let track_diagnostics = sopts.unstable_opts.track_diagnostics;
@@ -996,6 +1036,8 @@ fn default_emitter(
                     .macro_backtrace(macro_backtrace)
                     .track_diagnostics(track_diagnostics)
                     .terminal_url(terminal_url)
+                    .type_aliases(sopts.unstable_opts.diagnostic_type_aliases)
+                    .color_theme(color_theme)
                     .ignored_directories_in_source_blocks(
                         sopts.unstable_opts.ignore_directory_in_diagnostics_source_blocks.clone(),
                     );
@@ -1015,11 +1057,19 @@ fn default_emitter(
                 track_diagnostics,
                 terminal_url,
             )
+            .type_aliases(sopts.unstable_opts.diagnostic_type_aliases)
+            .explanations(sopts.unstable_opts.json_explanations)
             .ui_testing(sopts.unstable_opts.ui_testing)
             .ignored_directories_in_source_blocks(
                 sopts.unstable_opts.ignore_directory_in_diagnostics_source_blocks.clone(),
             ),
         ),
+        config::ErrorOutputType::Sarif => {
+            Box::new(SarifEmitter::stderr(source_map, bundle, fallback_bundle))
+        }
+        config::ErrorOutputType::Github => {
+            Box::new(GithubEmitter::stderr(source_map, bundle, fallback_bundle))
+        }
     }
 }
 
@@ -1083,7 +1133,9 @@ pub fn build_session(
         fluent_resources,
         sopts.unstable_opts.translate_directionality_markers,
     );
-    let emitter = default_emitter(&sopts, registry, source_map.clone(), bundle, fallback_bundle);
+    let color_theme = load_color_theme(&early_dcx, &sopts);
+    let emitter =
+        default_emitter(&sopts, registry, source_map.clone(), bundle, fallback_bundle, color_theme);
 
     let mut dcx = DiagCtxt::with_emitter(emitter)
         .with_flags(sopts.unstable_opts.dcx_flags(can_emit_warnings));
@@ -1168,6 +1220,7 @@ pub fn build_session(
         jobserver: jobserver::client(),
         lint_store: None,
         registered_lints: false,
+        mir_pass_store: None,
         driver_lint_caps,
         ctfe_backtrace,
         miri_unleashed_features: Lock::new(Default::default()),
@@ -1497,6 +1550,8 @@ fn mk_emitter(output: ErrorOutputType) -> Box<DynEmitter> {
             false,
             TerminalUrl::No,
         )),
+        config::ErrorOutputType::Sarif => Box::new(SarifEmitter::basic(None, fallback_bundle)),
+        config::ErrorOutputType::Github => Box::new(GithubEmitter::basic(None, fallback_bundle)),
     };
     emitter
 }
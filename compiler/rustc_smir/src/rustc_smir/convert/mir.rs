@@ -642,6 +642,10 @@ fn stable(&self, tables: &mut Tables<'_>) -> Self::T {
                 destination: destination.map(|d| d.as_usize()),
                 unwind: unwind.stable(tables),
             },
+            mir::TerminatorKind::TailCall { func, args, fn_span: _ } => TerminatorKind::TailCall {
+                func: func.stable(tables),
+                args: args.iter().map(|arg| arg.node.stable(tables)).collect(),
+            },
             mir::TerminatorKind::Yield { .. }
             | mir::TerminatorKind::CoroutineDrop
             | mir::TerminatorKind::FalseEdge { .. }
@@ -316,6 +316,7 @@
         TryCaptureGeneric,
         TryCapturePrintable,
         TryFrom,
+        TryFromIntError,
         TryInto,
         Ty,
         TyCtxt,
@@ -377,6 +378,7 @@
         allow_fail,
         allow_internal_unsafe,
         allow_internal_unstable,
+        alternative,
         alu32,
         always,
         and,
@@ -463,6 +465,7 @@
         bitxor_assign,
         black_box,
         block,
+        blocking,
         bool,
         borrowck_graphviz_format,
         borrowck_graphviz_postflow,
@@ -486,6 +489,7 @@
         call_mut,
         call_once,
         caller_location,
+        capture_disjoint_array_elements,
         capture_disjoint_fields,
         cause,
         cdylib,
@@ -532,6 +536,8 @@
         cmpxchg16b_target_feature,
         cmse_nonsecure_entry,
         coerce_unsized,
+        coinductive,
+        coinductive_traits,
         cold,
         collapse_debuginfo,
         column,
@@ -568,6 +574,7 @@
         const_format_args,
         const_generics,
         const_generics_defaults,
+        const_heap,
         const_if_match,
         const_impl_trait,
         const_in_array_repeat_expressions,
@@ -665,6 +672,7 @@
         deref_method,
         deref_mut,
         deref_mut_method,
+        deref_patterns_borrowck,
         deref_target,
         derive,
         derive_const,
@@ -672,6 +680,7 @@
         destruct,
         destructuring_assignment,
         diagnostic,
+        diagnostic_blocking,
         diagnostic_namespace,
         direct,
         discriminant_kind,
@@ -826,6 +835,7 @@
         fsub_fast,
         fundamental,
         future,
+        future_size_limit,
         future_trait,
         gdb_script_file,
         ge,
@@ -842,6 +852,7 @@
         generic_const_items,
         generic_param_attrs,
         get_context,
+        global_alloc_ty,
         global_allocator,
         global_asm,
         globs,
@@ -963,6 +974,7 @@
         link_section,
         linkage,
         linker,
+        lint_config,
         lint_reasons,
         literal,
         load,
@@ -1041,16 +1053,20 @@
         mir_cast_transmute,
         mir_checked,
         mir_copy_for_deref,
+        mir_copy_nonoverlapping,
         mir_debuginfo,
         mir_deinit,
         mir_discriminant,
         mir_drop,
         mir_field,
         mir_goto,
+        mir_inline_asm,
         mir_len,
         mir_make_place,
         mir_move,
+        mir_nop,
         mir_offset,
+        mir_place_mention,
         mir_retag,
         mir_return,
         mir_return_to,
@@ -1102,6 +1118,7 @@
         neg,
         negate_unsigned,
         negative_bounds,
+        negative_bounds_coherence,
         negative_impls,
         neon,
         never,
@@ -1136,6 +1153,7 @@
         no_link,
         no_main,
         no_mangle,
+        no_niche,
         no_sanitize,
         no_stack_check,
         no_start,
@@ -1192,6 +1210,7 @@
         panic_bounds_check,
         panic_cannot_unwind,
         panic_fmt,
+        panic_fmt_v1,
         panic_handler,
         panic_impl,
         panic_implementation,
@@ -1243,6 +1262,7 @@
         prelude,
         prelude_import,
         preserves_flags,
+        preview_edition_migrations,
         prfchw_target_feature,
         print_macro,
         println_macro,
@@ -1454,6 +1474,7 @@
         rustc_private,
         rustc_proc_macro_decls,
         rustc_promotable,
+        rustc_pure_deref,
         rustc_reallocator,
         rustc_regions,
         rustc_reservation_impl,
@@ -1632,6 +1653,7 @@
         suggestion,
         sym,
         sync,
+        sync_unsafe_cell,
         t32,
         target,
         target_abi,
@@ -1785,6 +1807,8 @@
         vec_macro,
         version,
         vfp2,
+        view,
+        view_types,
         vis,
         visible_private_types,
         volatile,
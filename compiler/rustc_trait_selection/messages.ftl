@@ -24,6 +24,10 @@ trait_selection_dump_vtable_entries = vtable entries for `{$trait_ref}`: {$entri
 trait_selection_empty_on_clause_in_rustc_on_unimplemented = empty `on`-clause in `#[rustc_on_unimplemented]`
     .label = empty on-clause here
 
+trait_selection_exceeded_obligation_fuel = trait solving exceeded fuel while proving `{$predicate}`
+    .label = proving this took more than {$fuel} steps
+    .note = the fuel for each root obligation is set by `-Zobligation-fuel`
+
 trait_selection_ignored_diagnostic_option = `{$option_name}` is ignored due to previous definition of `{$option_name}`
     .other_label = `{$option_name}` is first declared here
     .label = `{$option_name}` is already declared here
@@ -34,9 +38,13 @@ trait_selection_invalid_on_clause_in_rustc_on_unimplemented = invalid `on`-claus
     .label = invalid on-clause here
 
 trait_selection_malformed_on_unimplemented_attr = malformed `on_unimplemented` attribute
-    .help = only `message`, `note` and `label` are allowed as options
+    .help = only `message`, `note`, `label` and `if` are allowed as options
     .label = invalid option found here
 
+trait_selection_malformed_on_unimplemented_condition = malformed condition in `on_unimplemented` attribute
+    .help = conditions compare `Self` or a generic parameter of the trait to a string, like `if(Self = "u8", ...)`, and can be combined with `any(...)`, `all(...)` and `not(...)`
+    .label = invalid condition found here
+
 trait_selection_missing_options_for_on_unimplemented_attr = missing options for `on_unimplemented` attribute
     .help = at least one of the `message`, `note` and `label` options are expected
 
@@ -49,10 +57,18 @@ trait_selection_negative_positive_conflict = found both positive and negative im
     .positive_implementation_here = positive implementation here
     .positive_implementation_in_crate = positive implementation in crate `{$positive_impl_cname}`
 
+trait_selection_non_const_closure = expected a `const` closure, found a non-const closure
+    .label = expected due to this
+    .closure_label = this closure is not `const`
+    .suggestion = consider making the closure `const`
+
 trait_selection_no_value_in_rustc_on_unimplemented = this attribute must have a valid value
     .label = expected value here
     .note = eg `#[rustc_on_unimplemented(message="foo")]`
 
+trait_selection_trait_alias_recursion_limit_reached = reached the recursion limit while expanding trait alias `{$alias}`
+    .help = consider increasing the recursion limit by adding a `#![recursion_limit = "{$suggested_limit}"]` attribute to your crate (`{$crate_name}`)
+
 trait_selection_trait_has_no_impls = this trait has no implementations, consider adding one
 
 trait_selection_ty_alias_overflow = in case this is a recursive type alias, consider using a struct, enum, or union instead
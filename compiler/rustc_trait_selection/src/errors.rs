@@ -24,6 +24,28 @@ pub struct UnableToConstructConstantValue<'a> {
     pub unevaluated: ty::UnevaluatedConst<'a>,
 }
 
+#[derive(Diagnostic)]
+#[diag(trait_selection_exceeded_obligation_fuel)]
+#[note]
+pub struct ExceededObligationFuel<'tcx> {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    pub predicate: ty::Predicate<'tcx>,
+    pub fuel: usize,
+}
+
+#[derive(Diagnostic)]
+#[diag(trait_selection_trait_alias_recursion_limit_reached, code = E0798)]
+#[help]
+pub struct TraitAliasRecursionLimitReached {
+    #[primary_span]
+    pub span: Span,
+    pub alias: String,
+    pub suggested_limit: rustc_session::Limit,
+    pub crate_name: Symbol,
+}
+
 #[derive(Diagnostic)]
 #[diag(trait_selection_empty_on_clause_in_rustc_on_unimplemented, code = E0232)]
 pub struct EmptyOnClauseInOnUnimplemented {
@@ -124,6 +146,18 @@ fn add_to_diagnostic_with<F: SubdiagnosticMessageOp>(self, diag: &mut Diagnostic
     }
 }
 
+#[derive(Diagnostic)]
+#[diag(trait_selection_non_const_closure, code = E0277)]
+pub struct NonConstClosure {
+    #[primary_span]
+    #[label]
+    pub span: Span,
+    #[label(trait_selection_closure_label)]
+    pub closure_span: Span,
+    #[suggestion(code = "const ", style = "verbose", applicability = "maybe-incorrect")]
+    pub suggestion: Span,
+}
+
 #[derive(Diagnostic)]
 #[diag(trait_selection_closure_kind_mismatch, code = E0525)]
 pub struct ClosureKindMismatch {
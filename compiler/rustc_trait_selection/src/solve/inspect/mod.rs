@@ -5,3 +5,6 @@
 
 mod analyse;
 pub use analyse::*;
+
+mod summary;
+pub use summary::*;
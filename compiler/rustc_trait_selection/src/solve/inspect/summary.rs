@@ -0,0 +1,137 @@
+//! An owned and serializable summary of a proof tree.
+//!
+//! The proof trees produced by the solver borrow from the `TyCtxt` and
+//! contain canonical inference variables, which makes them unsuitable
+//! for consumers outside of the compiler, e.g. rust-analyzer or
+//! semver-checking tools. This module walks a proof tree using the
+//! [`ProofTreeVisitor`] API and lowers it into plain data which only
+//! contains printed goals and results.
+//!
+//! The summary is lossy in the same way [`InspectGoal::candidates`] is:
+//! candidates which did not have any effect on the final result may be
+//! missing and the nesting of goals is cut off after a fixed depth.
+use std::ops::ControlFlow;
+
+use rustc_infer::infer::InferCtxt;
+use rustc_middle::traits::query::NoSolution;
+use rustc_middle::traits::solve::{inspect, CandidateSource, Certainty, Goal, MaybeCause};
+use rustc_middle::ty;
+
+use super::{InspectCandidate, InspectGoal, ProofTreeInferCtxtExt, ProofTreeVisitor};
+
+/// The result of evaluating a goal or candidate, see [`Certainty`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encodable_Generic, Decodable_Generic)]
+pub enum CertaintySummary {
+    /// The goal definitely holds.
+    Yes,
+    /// The goal may hold, but the solver was not able to prove it due to ambiguity.
+    Ambiguous,
+    /// The goal may hold, but the solver gave up due to overflow.
+    Overflow,
+    /// The goal definitely does not hold.
+    NoSolution,
+}
+
+impl From<Result<Certainty, NoSolution>> for CertaintySummary {
+    fn from(result: Result<Certainty, NoSolution>) -> CertaintySummary {
+        match result {
+            Ok(Certainty::Yes) => CertaintySummary::Yes,
+            Ok(Certainty::Maybe(MaybeCause::Ambiguity)) => CertaintySummary::Ambiguous,
+            Ok(Certainty::Maybe(MaybeCause::Overflow)) => CertaintySummary::Overflow,
+            Err(NoSolution) => CertaintySummary::NoSolution,
+        }
+    }
+}
+
+/// A goal which has been evaluated by the solver, together with all
+/// candidates which have been considered to prove it.
+#[derive(Debug, Clone, PartialEq, Eq, Encodable_Generic, Decodable_Generic)]
+pub struct GoalSummary {
+    /// The predicate of the goal, printed after resolving inference variables.
+    pub predicate: String,
+    pub result: CertaintySummary,
+    pub candidates: Vec<CandidateSummary>,
+}
+
+/// A single way to prove a goal, e.g. a user-written impl or a where-clause.
+#[derive(Debug, Clone, PartialEq, Eq, Encodable_Generic, Decodable_Generic)]
+pub struct CandidateSummary {
+    /// A human-readable description of the candidate, e.g. `impl Clone for Vec<T>`
+    /// or `where-clause #0`.
+    pub kind: String,
+    pub result: CertaintySummary,
+    /// The goals which have to hold for this candidate to apply.
+    pub nested_goals: Vec<GoalSummary>,
+}
+
+struct SummaryBuilder {
+    goals: Vec<GoalSummary>,
+}
+
+impl<'tcx> ProofTreeVisitor<'tcx> for SummaryBuilder {
+    type BreakTy = !;
+
+    fn visit_goal(&mut self, goal: &InspectGoal<'_, 'tcx>) -> ControlFlow<!> {
+        let infcx = goal.infcx();
+        let candidates = goal
+            .candidates()
+            .iter()
+            .map(|candidate| {
+                let mut nested = SummaryBuilder { goals: vec![] };
+                candidate.visit_nested(&mut nested);
+                CandidateSummary {
+                    kind: describe_candidate(infcx, candidate),
+                    result: candidate.result().into(),
+                    nested_goals: nested.goals,
+                }
+            })
+            .collect();
+        self.goals.push(GoalSummary {
+            predicate: infcx.resolve_vars_if_possible(goal.goal().predicate).to_string(),
+            result: goal.result().into(),
+            candidates,
+        });
+        ControlFlow::Continue(())
+    }
+}
+
+fn describe_candidate<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    candidate: &InspectCandidate<'_, 'tcx>,
+) -> String {
+    match candidate.kind() {
+        inspect::ProbeKind::TraitCandidate { source, result: _ } => match source {
+            CandidateSource::Impl(def_id) => {
+                let trait_ref = infcx.tcx.impl_trait_ref(def_id).map(|r| r.instantiate_identity());
+                match trait_ref {
+                    Some(trait_ref) => format!(
+                        "impl {} for {}",
+                        trait_ref.print_only_trait_path(),
+                        trait_ref.self_ty()
+                    ),
+                    None => infcx.tcx.def_path_str(def_id),
+                }
+            }
+            CandidateSource::BuiltinImpl(_) => "builtin impl".to_string(),
+            CandidateSource::ParamEnv(idx) => format!("where-clause #{idx}"),
+            CandidateSource::AliasBound => "alias bound".to_string(),
+        },
+        inspect::ProbeKind::MiscCandidate { name, result: _ } => name.to_string(),
+        inspect::ProbeKind::Root { result: _ } => "root".to_string(),
+        kind => format!("{kind:?}"),
+    }
+}
+
+#[extension(pub trait ProofTreeSummaryExt<'tcx>)]
+impl<'tcx> InferCtxt<'tcx> {
+    /// Evaluates `goal` with the new solver and returns an owned summary of
+    /// its proof tree, see the [module docs](self) for more details.
+    ///
+    /// This does not constrain any inference variables of `self`.
+    fn evaluate_goal_summary(&self, goal: Goal<'tcx, ty::Predicate<'tcx>>) -> GoalSummary {
+        assert!(self.next_trait_solver(), "proof trees require the new trait solver");
+        let mut builder = SummaryBuilder { goals: vec![] };
+        self.visit_proof_tree(goal, &mut builder);
+        builder.goals.pop().unwrap()
+    }
+}
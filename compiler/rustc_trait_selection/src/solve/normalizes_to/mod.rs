@@ -1,4 +1,4 @@
-use crate::traits::{check_args_compatible, specialization_graph};
+use crate::traits::{check_args_compatible_explained, specialization_graph};
 
 use super::assembly::{self, structural_traits, Candidate};
 use super::{EvalCtxt, GoalSource};
@@ -194,7 +194,7 @@ fn consider_impl_candidate(
                 return ecx.evaluate_added_goals_and_make_canonical_response(Certainty::AMBIGUOUS);
             };
 
-            let error_response = |ecx: &mut EvalCtxt<'_, 'tcx>, reason| {
+            let error_response = |ecx: &mut EvalCtxt<'_, 'tcx>, reason: String| {
                 let guar = tcx.dcx().span_delayed_bug(tcx.def_span(assoc_def.item.def_id), reason);
                 let error_term = match assoc_def.item.kind {
                     ty::AssocKind::Const => ty::Const::new_error(
@@ -217,7 +217,7 @@ fn consider_impl_candidate(
             };
 
             if !assoc_def.item.defaultness(tcx).has_value() {
-                return error_response(ecx, "missing value for assoc item in impl");
+                return error_response(ecx, "missing value for assoc item in impl".to_string());
             }
 
             // Getting the right args here is complex, e.g. given:
@@ -239,10 +239,14 @@ fn consider_impl_candidate(
                 assoc_def.defining_node,
             );
 
-            if !check_args_compatible(tcx, assoc_def.item, args) {
+            if let Err(incompatibility) = check_args_compatible_explained(tcx, assoc_def.item, args)
+            {
                 return error_response(
                     ecx,
-                    "associated item has mismatched generic item arguments",
+                    format!(
+                        "associated item has mismatched generic item arguments: {}",
+                        incompatibility.description(tcx)
+                    ),
                 );
             }
 
@@ -382,6 +386,7 @@ fn consider_builtin_async_fn_trait_candidates(
             structural_traits::extract_tupled_inputs_and_output_from_async_callable(
                 tcx,
                 goal.predicate.self_ty(),
+                goal.predicate.trait_def_id(tcx),
                 goal_kind,
                 env_region,
             )?;
@@ -9,9 +9,12 @@
 use rustc_middle::traits::solve::CacheData;
 use rustc_middle::traits::solve::{CanonicalInput, Certainty, EvaluationCache, QueryResult};
 use rustc_middle::ty;
+use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::TyCtxt;
+use rustc_session::code_stats::{SolverCacheKind, SolverObligationInfo};
 use rustc_session::Limit;
 use std::mem;
+use std::time::Instant;
 
 rustc_index::newtype_index! {
     #[orderable]
@@ -247,16 +250,19 @@ fn tag_cycle_participants(
         }
     }
 
+    /// Returns the number of removed provisional results.
     fn clear_dependent_provisional_results(
         provisional_cache: &mut FxHashMap<CanonicalInput<'tcx>, ProvisionalCacheEntry<'tcx>>,
         head: StackDepth,
-    ) {
+    ) -> usize {
+        let mut removed = 0;
         #[allow(rustc::potential_query_instability)]
         provisional_cache.retain(|_, entry| {
-            entry.with_coinductive_stack.take_if(|p| p.head == head);
-            entry.with_inductive_stack.take_if(|p| p.head == head);
+            removed += entry.with_coinductive_stack.take_if(|p| p.head == head).is_some() as usize;
+            removed += entry.with_inductive_stack.take_if(|p| p.head == head).is_some() as usize;
             !entry.is_empty()
         });
+        removed
     }
 
     /// Probably the most involved method of the whole solver.
@@ -310,8 +316,10 @@ pub(super) fn with_new_goal(
             }
 
             self.on_cache_hit(reached_depth, encountered_overflow);
+            record_cache_lookup(tcx, SolverCacheKind::NextSolverGlobal, true);
             return result;
         }
+        record_cache_lookup(tcx, SolverCacheKind::NextSolverGlobal, false);
 
         // Check whether the goal is in the provisional cache.
         // The provisional result may rely on the path to its cycle roots,
@@ -340,6 +348,7 @@ pub(super) fn with_new_goal(
                 HasBeenUsed::empty(),
                 entry.head,
             );
+            record_cache_lookup(tcx, SolverCacheKind::NextSolverProvisional, true);
             return entry.result;
         } else if let Some(stack_depth) = cache_entry.stack_depth {
             debug!("encountered cycle with depth {stack_depth:?}");
@@ -374,6 +383,7 @@ pub(super) fn with_new_goal(
             };
         } else {
             // No entry, we push this goal on the stack and try to prove it.
+            record_cache_lookup(tcx, SolverCacheKind::NextSolverProvisional, false);
             let depth = self.stack.next_index();
             let entry = StackEntry {
                 input,
@@ -388,6 +398,8 @@ pub(super) fn with_new_goal(
             cache_entry.stack_depth = Some(depth);
         }
 
+        let start_time = tcx.sess.opts.unstable_opts.selection_cache_stats.then(Instant::now);
+
         // This is for global caching, so we properly track query dependencies.
         // Everything that affects the `result` should be performed within this
         // `with_anon_task` closure.
@@ -417,10 +429,16 @@ pub(super) fn with_new_goal(
 
                     // Start by clearing all provisional cache entries which depend on this
                     // the current goal.
-                    Self::clear_dependent_provisional_results(
+                    let removed = Self::clear_dependent_provisional_results(
                         &mut self.provisional_cache,
                         self.stack.next_index(),
                     );
+                    if tcx.sess.opts.unstable_opts.selection_cache_stats {
+                        tcx.sess.code_stats.record_solver_cache_evictions(
+                            SolverCacheKind::NextSolverProvisional,
+                            removed,
+                        );
+                    }
 
                     // Check whether we reached a fixpoint, either because the final result
                     // is equal to the provisional result of the previous iteration, or because
@@ -456,6 +474,15 @@ pub(super) fn with_new_goal(
                 (current_entry, result)
             });
 
+        if let Some(start_time) = start_time {
+            tcx.sess.code_stats.record_solver_obligation(SolverObligationInfo {
+                next_solver: true,
+                obligation: with_no_trimmed_paths!(input.value.goal.predicate.to_string()),
+                time: start_time.elapsed(),
+                depth: self.stack.next_index().as_usize(),
+            });
+        }
+
         let proof_tree = inspect.finalize_evaluation(tcx);
 
         // We're now done with this goal. In case this goal is involved in a larger cycle
@@ -506,3 +533,9 @@ fn response_no_constraints(
         Ok(super::response_no_constraints_raw(tcx, goal.max_universe, goal.variables, certainty))
     }
 }
+
+fn record_cache_lookup(tcx: TyCtxt<'_>, cache: SolverCacheKind, hit: bool) {
+    if tcx.sess.opts.unstable_opts.selection_cache_stats {
+        tcx.sess.code_stats.record_solver_cache_lookup(cache, hit);
+    }
+}
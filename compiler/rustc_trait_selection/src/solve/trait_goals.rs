@@ -323,6 +323,7 @@ fn consider_builtin_async_fn_trait_candidates(
             structural_traits::extract_tupled_inputs_and_output_from_async_callable(
                 tcx,
                 goal.predicate.self_ty(),
+                goal.predicate.def_id(),
                 goal_kind,
                 // This region doesn't matter because we're throwing away the coroutine type
                 tcx.lifetimes.re_static,
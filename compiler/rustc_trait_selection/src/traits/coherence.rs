@@ -28,12 +28,16 @@
 use rustc_middle::traits::specialization_graph::OverlapMode;
 use rustc_middle::traits::DefiningAnchor;
 use rustc_middle::ty::fast_reject::{DeepRejectCtxt, TreatParams};
+use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::visit::{TypeVisitable, TypeVisitableExt};
-use rustc_middle::ty::{self, Ty, TyCtxt, TypeSuperVisitable, TypeVisitor};
+use rustc_middle::ty::{self, GenericArgsRef, Ty, TyCtxt, TypeSuperVisitable, TypeVisitor};
 use rustc_span::symbol::sym;
 use rustc_span::DUMMY_SP;
 use std::fmt::Debug;
+use std::fs::{self, File};
+use std::io::BufWriter;
 use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
 
 /// Whether we do the orphan check relative to this crate or
 /// to some remote crate.
@@ -51,6 +55,9 @@ pub enum Conflict {
 
 pub struct OverlapResult<'tcx> {
     pub impl_header: ty::ImplHeader<'tcx>,
+    /// The generic arguments of the second impl after unifying it with the first one.
+    /// Together with the arguments of `impl_header`, this is a witness of the overlap.
+    pub other_impl_args: GenericArgsRef<'tcx>,
     pub intercrate_ambiguity_causes: FxIndexSet<IntercrateAmbiguityCause<'tcx>>,
 
     /// `true` if the overlap might've been permitted before the shift
@@ -255,13 +262,19 @@ fn overlap<'tcx>(
         .any(|c| c.0.involves_placeholders());
 
     let mut impl_header = infcx.resolve_vars_if_possible(impl1_header);
+    let other_impl_args = infcx.resolve_vars_if_possible(impl2_header.impl_args);
 
     // Deeply normalize the impl header for diagnostics, ignoring any errors if this fails.
     if infcx.next_trait_solver() {
         impl_header = deeply_normalize_for_diagnostics(&infcx, param_env, impl_header);
     }
 
-    Some(OverlapResult { impl_header, intercrate_ambiguity_causes, involves_placeholder })
+    Some(OverlapResult {
+        impl_header,
+        other_impl_args,
+        intercrate_ambiguity_causes,
+        involves_placeholder,
+    })
 }
 
 #[instrument(level = "debug", skip(infcx), ret)]
@@ -1097,3 +1110,141 @@ fn search_ambiguity_causes<'tcx>(
 ) {
     infcx.visit_proof_tree(goal, &mut AmbiguityCausesVisitor { causes });
 }
+
+/// Outputs the intercrate ambiguity causes of every pair of overlapping impls of
+/// a trait implemented in this crate as JSON, to a file in the given output directory.
+///
+/// Every cause is reported together with the reasoning behind it: whether the trait
+/// is `#[fundamental]`, and which fundamental types wrap the self type.
+pub fn dump_intercrate_ambiguities(
+    tcx: TyCtxt<'_>,
+    output_directory: &Option<PathBuf>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let output_directory = if let Some(ref directory) = output_directory {
+        fs::create_dir_all(directory)?;
+        directory
+    } else {
+        Path::new(".")
+    };
+
+    let filename = format!("{}.intercrate_ambiguities.json", tcx.crate_name(LOCAL_CRATE));
+    let output_path = output_directory.join(&filename);
+    let file = File::create(&output_path)?;
+    let file = BufWriter::new(file);
+
+    #[derive(serde::Serialize)]
+    struct Cause {
+        kind: &'static str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trait_ref: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        self_ty: Option<String>,
+        trait_is_fundamental: bool,
+        fundamental_self_ty_wrappers: Vec<String>,
+        message: String,
+    }
+
+    #[derive(serde::Serialize)]
+    struct Overlap {
+        trait_name: String,
+        impl1: String,
+        impl2: String,
+        causes: Vec<Cause>,
+    }
+
+    let impl_header = |impl_def_id| {
+        super::specialize::to_pretty_impl_header(tcx, impl_def_id)
+            .unwrap_or_else(|| tcx.def_path_str(impl_def_id))
+    };
+
+    let mut overlaps = vec![];
+    for (&trait_def_id, local_impls) in tcx.all_local_trait_impls(()) {
+        let overlap_mode = OverlapMode::get(tcx, trait_def_id);
+        for &impl1_def_id in local_impls {
+            for impl2_def_id in tcx.all_impls(trait_def_id) {
+                // Only look at every pair of local impls once.
+                if impl2_def_id.as_local().is_some_and(|impl2_def_id| {
+                    impl2_def_id.local_def_index >= impl1_def_id.local_def_index
+                }) {
+                    continue;
+                }
+
+                let Some(overlap) = overlapping_impls(
+                    tcx,
+                    impl1_def_id.to_def_id(),
+                    impl2_def_id,
+                    SkipLeakCheck::default(),
+                    overlap_mode,
+                ) else {
+                    continue;
+                };
+                if overlap.intercrate_ambiguity_causes.is_empty() {
+                    continue;
+                }
+
+                let causes = overlap
+                    .intercrate_ambiguity_causes
+                    .iter()
+                    .map(|cause| {
+                        let message = cause.intercrate_ambiguity_hint();
+                        let (kind, trait_ref, self_ty) = match *cause {
+                            IntercrateAmbiguityCause::DownstreamCrate { trait_ref, self_ty } => {
+                                ("downstream_crate", Some(trait_ref), self_ty)
+                            }
+                            IntercrateAmbiguityCause::UpstreamCrateUpdate {
+                                trait_ref,
+                                self_ty,
+                            } => ("upstream_crate_update", Some(trait_ref), self_ty),
+                            IntercrateAmbiguityCause::ReservationImpl { .. } => {
+                                ("reservation_impl", None, None)
+                            }
+                        };
+
+                        let mut fundamental_self_ty_wrappers = vec![];
+                        let mut ty = trait_ref.map(|trait_ref| trait_ref.self_ty());
+                        while let Some(outer) = ty {
+                            ty = match *outer.kind() {
+                                ty::Ref(_, inner, mutbl) => {
+                                    fundamental_self_ty_wrappers
+                                        .push(mutbl.ref_prefix_str().trim_end().to_string());
+                                    Some(inner)
+                                }
+                                ty::Adt(def, args) if def.is_fundamental() => {
+                                    fundamental_self_ty_wrappers
+                                        .push(with_no_trimmed_paths!(tcx.def_path_str(def.did())));
+                                    args.types().next()
+                                }
+                                _ => None,
+                            };
+                        }
+
+                        with_no_trimmed_paths!(Cause {
+                            kind,
+                            trait_ref: trait_ref.map(|trait_ref| trait_ref.to_string()),
+                            self_ty: self_ty.map(|self_ty| self_ty.to_string()),
+                            trait_is_fundamental: trait_ref.is_some_and(|trait_ref| {
+                                tcx.has_attr(trait_ref.def_id, sym::fundamental)
+                            }),
+                            fundamental_self_ty_wrappers,
+                            message,
+                        })
+                    })
+                    .collect();
+
+                overlaps.push(with_no_trimmed_paths!(Overlap {
+                    trait_name: tcx.def_path_str(trait_def_id),
+                    impl1: impl_header(impl1_def_id.to_def_id()),
+                    impl2: impl_header(impl2_def_id),
+                    causes,
+                }));
+            }
+        }
+    }
+    overlaps.sort_by(|a, b| {
+        (&a.trait_name, &a.impl1, &a.impl2).cmp(&(&b.trait_name, &b.impl1, &b.impl2))
+    });
+
+    serde_json::to_writer(file, &overlaps)?;
+
+    Ok(())
+}
@@ -3,7 +3,7 @@
 use rustc_ast::AttrArgs;
 use rustc_ast::AttrArgsEq;
 use rustc_ast::AttrKind;
-use rustc_ast::{Attribute, MetaItem, NestedMetaItem};
+use rustc_ast::{Attribute, MetaItem, MetaItemKind, NestedMetaItem};
 use rustc_attr as attr;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::{codes::*, struct_span_code_err, ErrorGuaranteed};
@@ -323,6 +323,14 @@ fn new(span: Span) -> Self {
     }
 }
 
+#[derive(LintDiagnostic)]
+#[diag(trait_selection_malformed_on_unimplemented_condition)]
+#[help]
+pub struct MalformedOnUnimplementedConditionLint {
+    #[label]
+    pub span: Span,
+}
+
 #[derive(LintDiagnostic)]
 #[diag(trait_selection_missing_options_for_on_unimplemented_attr)]
 #[help]
@@ -391,6 +399,24 @@ fn parse(
         let condition = if is_root {
             None
         } else {
+            if is_diagnostic_namespace_variant {
+                let condition = item_iter.clone().next().and_then(|item| item.meta_item());
+                if let Err(malformed) = condition
+                    .map_or(Err(span), |cond| Self::validate_condition(tcx, item_def_id, cond))
+                {
+                    // The directive is also parsed by the downstream crates reporting
+                    // errors about the trait, which shouldn't be warned about it again.
+                    if let Some(item_def_id) = item_def_id.as_local() {
+                        tcx.emit_node_span_lint(
+                            UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES,
+                            tcx.local_def_id_to_hir_id(item_def_id),
+                            malformed,
+                            MalformedOnUnimplementedConditionLint { span: malformed },
+                        );
+                    }
+                    return Ok(None);
+                }
+            }
             let cond = item_iter
                 .next()
                 .ok_or_else(|| tcx.dcx().emit_err(EmptyOnClauseInOnUnimplemented { span }))?
@@ -465,6 +491,17 @@ fn parse(
                     };
                     continue;
                 }
+            } else if item.has_name(kw::If) && is_root && is_diagnostic_namespace_variant {
+                if let Some(items) = item.meta_item_list() {
+                    // A malformed condition was already linted against, and only
+                    // disables this directive.
+                    match Self::parse(tcx, item_def_id, items, item.span(), false, true) {
+                        Ok(Some(subcommand)) => subcommands.push(subcommand),
+                        Ok(None) => {}
+                        Err(reported) => errored = Some(reported),
+                    };
+                    continue;
+                }
             } else if item.has_name(sym::append_const_msg)
                 && append_const_msg.is_none()
                 && !is_diagnostic_namespace_variant
@@ -506,6 +543,43 @@ fn parse(
         }
     }
 
+    /// Checks the condition of an `if` directive of `#[diagnostic::on_unimplemented]`,
+    /// returning the span of the first malformed part. Only `any`, `all` and `not`
+    /// predicates over comparisons of `Self` or a generic parameter of the trait with a
+    /// string are allowed, unlike the conditions of `#[rustc_on_unimplemented]` which can
+    /// test any of the flags computed in `on_unimplemented_note`.
+    fn validate_condition(
+        tcx: TyCtxt<'tcx>,
+        item_def_id: DefId,
+        cond: &MetaItem,
+    ) -> Result<(), Span> {
+        match &cond.kind {
+            MetaItemKind::List(items)
+                if cond.has_name(sym::any)
+                    || cond.has_name(sym::all)
+                    || (cond.has_name(sym::not) && items.len() == 1) =>
+            {
+                for item in items.iter() {
+                    let item = item.meta_item().ok_or(item.span())?;
+                    Self::validate_condition(tcx, item_def_id, item)?;
+                }
+                Ok(())
+            }
+            MetaItemKind::NameValue(lit)
+                if lit.kind.is_str()
+                    && cond.ident().is_some_and(|ident| {
+                        tcx.generics_of(item_def_id).params.iter().any(|param| {
+                            param.name == ident.name
+                                && !matches!(param.kind, GenericParamDefKind::Lifetime)
+                        })
+                    }) =>
+            {
+                Ok(())
+            }
+            _ => Err(cond.span),
+        }
+    }
+
     pub fn of_item(tcx: TyCtxt<'tcx>, item_def_id: DefId) -> Result<Option<Self>, ErrorGuaranteed> {
         if let Some(attr) = tcx.get_attr(item_def_id, sym::rustc_on_unimplemented) {
             return Self::parse_attribute(attr, false, tcx, item_def_id);
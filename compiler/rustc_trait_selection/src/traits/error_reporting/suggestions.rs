@@ -7,14 +7,14 @@
 
 use crate::errors;
 use crate::infer::InferCtxt;
-use crate::traits::{ImplDerivedObligationCause, NormalizeExt, ObligationCtxt};
+use crate::traits::{supertrait_def_ids, ImplDerivedObligationCause, NormalizeExt, ObligationCtxt};
 
 use hir::def::CtorOf;
 use rustc_data_structures::fx::FxHashSet;
 use rustc_data_structures::stack::ensure_sufficient_stack;
 use rustc_errors::{
     codes::*, pluralize, struct_span_code_err, Applicability, Diagnostic, DiagnosticBuilder,
-    MultiSpan, Style, SuggestionStyle,
+    MacroCallSite, MultiSpan, Style, SuggestionStyle,
 };
 use rustc_hir as hir;
 use rustc_hir::def::{DefKind, Res};
@@ -29,6 +29,7 @@
 use rustc_middle::hir::map;
 use rustc_middle::traits::IsConstable;
 use rustc_middle::ty::error::TypeError::{self, Sorts};
+use rustc_middle::ty::fast_reject::{DeepRejectCtxt, TreatParams};
 use rustc_middle::ty::{
     self, suggest_arbitrary_trait_bound, suggest_constraining_type_param, AdtKind, GenericArgs,
     InferTy, IsSuggestable, ToPredicate, Ty, TyCtxt, TypeAndMut, TypeFoldable, TypeFolder,
@@ -236,6 +237,72 @@ pub fn suggest_restriction<'tcx>(
     }
 }
 
+/// Suggests adding the trait of `trait_pred`, a bound on `Self` that the default body of
+/// the trait method `item_id` needs, as a supertrait of the trait, unless the trait
+/// already has it as a (transitive) supertrait.
+fn suggest_supertrait<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    item_id: LocalDefId,
+    err: &mut Diagnostic,
+    trait_pred: ty::PolyTraitPredicate<'tcx>,
+) {
+    let tcx = infcx.tcx;
+    let trait_def_id = tcx.local_parent(item_id);
+    let hir::Node::Item(hir::Item {
+        ident,
+        kind: hir::ItemKind::Trait(_, _, hir_generics, bounds, _),
+        span,
+        ..
+    }) = tcx.hir_node_by_def_id(trait_def_id)
+    else {
+        return;
+    };
+    let required_def_id = trait_pred.def_id();
+    if span.from_expansion()
+        || tcx.lang_items().sized_trait() == Some(required_def_id)
+        || supertrait_def_ids(tcx, trait_def_id.to_def_id()).any(|def_id| def_id == required_def_id)
+    {
+        return;
+    }
+    // The bound can only be moved onto the trait if it does not mention any generic
+    // parameters of the method itself.
+    let trait_param_count = tcx.generics_of(trait_def_id).count() as u32;
+    if trait_pred.has_escaping_bound_vars()
+        || !trait_pred.is_suggestable(tcx, false)
+        || trait_pred.skip_binder().trait_ref.args.iter().any(|arg| {
+            arg.walk().any(|arg| match arg.unpack() {
+                ty::GenericArgKind::Type(ty) => {
+                    matches!(*ty.kind(), ty::Param(param) if param.index >= trait_param_count)
+                }
+                ty::GenericArgKind::Lifetime(re) => {
+                    matches!(*re, ty::ReEarlyParam(param) if param.index >= trait_param_count)
+                }
+                ty::GenericArgKind::Const(ct) => {
+                    matches!(ct.kind(), ty::ConstKind::Param(param) if param.index >= trait_param_count)
+                }
+            })
+        })
+    {
+        return;
+    }
+
+    let trait_path = trait_pred.print_modifiers_and_trait_path();
+    let (sp, suggestion) = match bounds {
+        [.., last] => (last.span().shrink_to_hi(), format!(" + {trait_path}")),
+        [] if hir_generics.params.is_empty() => {
+            (ident.span.shrink_to_hi(), format!(": {trait_path}"))
+        }
+        [] => (hir_generics.span.shrink_to_hi(), format!(": {trait_path}")),
+    };
+    // Adding a supertrait affects every implementor of the trait, and may make it object unsafe.
+    err.span_suggestion_verbose(
+        sp,
+        format!("or consider adding `{trait_path}` as a supertrait of `{ident}`"),
+        suggestion,
+        Applicability::MaybeIncorrect,
+    );
+}
+
 #[extension(pub trait TypeErrCtxtExt<'tcx>)]
 impl<'tcx> TypeErrCtxt<'_, 'tcx> {
     fn suggest_restricting_param_bound(
@@ -285,7 +352,7 @@ fn suggest_restricting_param_bound(
 
                 hir::Node::TraitItem(hir::TraitItem {
                     generics,
-                    kind: hir::TraitItemKind::Fn(..),
+                    kind: hir::TraitItemKind::Fn(_, trait_fn),
                     ..
                 }) if self_ty == self.tcx.types.self_param => {
                     assert!(param_ty);
@@ -294,6 +361,14 @@ fn suggest_restricting_param_bound(
                         self.tcx, body_id, generics, "`Self`", err, None, projection, trait_pred,
                         None,
                     );
+                    // A default method body may instead need the bound on every implementor.
+                    if let hir::TraitFn::Provided(fn_body) = trait_fn
+                        && projection.is_none()
+                        && let Some(span) = err.span.primary_span()
+                        && self.tcx.hir().body(*fn_body).value.span.contains(span)
+                    {
+                        suggest_supertrait(self, body_id, err, trait_pred);
+                    }
                     return;
                 }
 
@@ -1160,10 +1235,25 @@ fn suggest_add_reference_to_arg(
         poly_trait_pred: ty::PolyTraitPredicate<'tcx>,
         has_custom_message: bool,
     ) -> bool {
-        let span = obligation.cause.span;
+        let mut span = obligation.cause.span;
+        // Whether the argument is the output of a macro, in which case `span` is the invocation.
+        let mut from_macro = false;
 
         let code = match obligation.cause.code() {
-            ObligationCauseCode::FunctionArgumentObligation { parent_code, .. } => parent_code,
+            ObligationCauseCode::FunctionArgumentObligation {
+                parent_code,
+                arg_hir_id,
+                call_hir_id,
+            } => {
+                if let Some(call_site) = MacroCallSite::find(
+                    self.tcx.hir().span(*arg_hir_id),
+                    self.tcx.hir().span(*call_hir_id),
+                ) {
+                    span = call_site.call_site();
+                    from_macro = true;
+                }
+                parent_code
+            }
             c @ ObligationCauseCode::ItemObligation(_)
             | c @ ObligationCauseCode::ExprItemObligation(..) => c,
             c if matches!(
@@ -1318,23 +1408,26 @@ fn suggest_add_reference_to_arg(
                         // Issue #104961, we need to add parentheses properly for compound expressions
                         // for example, `x.starts_with("hi".to_string() + "you")`
                         // should be `x.starts_with(&("hi".to_string() + "you"))`
-                        let Some(body_id) =
-                            self.tcx.hir().maybe_body_owned_by(obligation.cause.body_id)
-                        else {
-                            return false;
-                        };
-                        let body = self.tcx.hir().body(body_id);
-                        let mut expr_finder = FindExprBySpan::new(span);
-                        expr_finder.visit_expr(body.value);
-                        let Some(expr) = expr_finder.result else {
-                            return false;
-                        };
-                        let needs_parens = match expr.kind {
-                            // parenthesize if needed (Issue #46756)
-                            hir::ExprKind::Cast(_, _) | hir::ExprKind::Binary(_, _, _) => true,
-                            // parenthesize borrows of range literals (Issue #54505)
-                            _ if is_range_literal(expr) => true,
-                            _ => false,
+                        // A macro invocation never needs them.
+                        let needs_parens = !from_macro && {
+                            let Some(body_id) =
+                                self.tcx.hir().maybe_body_owned_by(obligation.cause.body_id)
+                            else {
+                                return false;
+                            };
+                            let body = self.tcx.hir().body(body_id);
+                            let mut expr_finder = FindExprBySpan::new(span);
+                            expr_finder.visit_expr(body.value);
+                            let Some(expr) = expr_finder.result else {
+                                return false;
+                            };
+                            match expr.kind {
+                                // parenthesize if needed (Issue #46756)
+                                hir::ExprKind::Cast(_, _) | hir::ExprKind::Binary(_, _, _) => true,
+                                // parenthesize borrows of range literals (Issue #54505)
+                                _ if is_range_literal(expr) => true,
+                                _ => false,
+                            }
                         };
 
                         let span = if needs_parens { span } else { span.shrink_to_lo() };
@@ -1727,8 +1820,8 @@ fn return_type_span(&self, obligation: &PredicateObligation<'tcx>) -> Option<Spa
     }
 
     /// If all conditions are met to identify a returned `dyn Trait`, suggest using `impl Trait` if
-    /// applicable and signal that the error has been expanded appropriately and needs to be
-    /// emitted.
+    /// a single type implementing the trait is returned, as well as boxing the returned values,
+    /// and signal that the error has been expanded appropriately and needs to be emitted.
     fn suggest_impl_trait(
         &self,
         err: &mut Diagnostic,
@@ -1738,7 +1831,8 @@ fn suggest_impl_trait(
         let ObligationCauseCode::SizedReturnType = obligation.cause.code() else {
             return false;
         };
-        let ty::Dynamic(_, _, ty::Dyn) = trait_pred.self_ty().skip_binder().kind() else {
+        let dyn_ty = trait_pred.self_ty().skip_binder();
+        let ty::Dynamic(_, _, ty::Dyn) = dyn_ty.kind() else {
             return false;
         };
 
@@ -1747,26 +1841,38 @@ fn suggest_impl_trait(
         err.children.clear();
 
         let span = obligation.cause.span;
-        if let Ok(snip) = self.tcx.sess.source_map().span_to_snippet(span)
-            && snip.starts_with("dyn ")
-        {
-            err.span_suggestion(
-                span.with_hi(span.lo() + BytePos(4)),
-                "return an `impl Trait` instead of a `dyn Trait`, \
-                if all returned values are the same type",
-                "impl ",
-                Applicability::MaybeIncorrect,
-            );
-        }
-
         let body = self.tcx.hir().body(self.tcx.hir().body_owned_by(obligation.cause.body_id));
 
         let mut visitor = ReturnsVisitor::default();
         visitor.visit_body(body);
 
+        // `impl Trait` is only an option if all the returned values have the same type, values
+        // of type `!` being compatible with any of them, and if that type implements the trait.
+        let returned_ty = self.typeck_results.as_ref().and_then(|typeck_results| {
+            let mut tys = visitor
+                .returns
+                .iter()
+                .filter_map(|expr| typeck_results.node_type_opt(expr.hir_id))
+                .map(|ty| self.resolve_vars_if_possible(ty))
+                .filter(|ty| !ty.is_never());
+            let ty = tys.next()?;
+            (!ty.has_infer() && !ty.references_error() && tys.all(|other| other == ty))
+                .then_some(ty)
+        });
+        let returned_ty = returned_ty.filter(|&ty| {
+            self.tcx.lang_items().unsize_trait().is_some_and(|unsize| {
+                self.predicate_must_hold_modulo_regions(&Obligation::new(
+                    self.tcx,
+                    ObligationCause::dummy(),
+                    obligation.param_env,
+                    ty::TraitRef::new(self.tcx, unsize, [ty, dyn_ty]),
+                ))
+            })
+        });
+
         let mut sugg =
             vec![(span.shrink_to_lo(), "Box<".to_string()), (span.shrink_to_hi(), ">".to_string())];
-        sugg.extend(visitor.returns.into_iter().flat_map(|expr| {
+        sugg.extend(visitor.returns.iter().flat_map(|expr| {
             let span =
                 expr.span.find_ancestor_in_same_ctxt(obligation.cause.span).unwrap_or(expr.span);
             if !span.can_be_used_for_suggestions() {
@@ -1790,11 +1896,27 @@ fn suggest_impl_trait(
             }
         }));
 
-        err.multipart_suggestion(
-            "box the return type, and wrap all of the returned values in `Box::new`",
-            sugg,
-            Applicability::MaybeIncorrect,
-        );
+        err.ranked_suggestions(|err| {
+            if let Some(returned_ty) = returned_ty
+                && let Ok(snip) = self.tcx.sess.source_map().span_to_snippet(span)
+                && snip.starts_with("dyn ")
+            {
+                err.span_suggestion(
+                    span.with_hi(span.lo() + BytePos(4)),
+                    format!(
+                        "return an `impl Trait` instead of a `dyn Trait`, as all the returned \
+                        values are of type `{returned_ty}`"
+                    ),
+                    "impl ",
+                    Applicability::MaybeIncorrect,
+                );
+            }
+            err.multipart_suggestion(
+                "box the return type, and wrap all of the returned values in `Box::new`",
+                sugg,
+                Applicability::MaybeIncorrect,
+            );
+        });
 
         true
     }
@@ -2575,6 +2697,20 @@ fn note_obligation_cause_for_async_await(
                 interior_span,
                 format!("has type `{target_ty}` which {trait_explanation}"),
             );
+            // If the value is a temporary, point at where it is dropped, as it may not be obvious
+            // that it lives that long.
+            if !interior_span.from_expansion()
+                && let Some(coroutine_did) = outer_coroutine
+                && coroutine_did.is_local()
+                && let Some(drop_scope) =
+                    self.tcx.drop_order(coroutine_did).temporary_scope(interior_span)
+                && drop_scope.span.lo() >= yield_span.hi()
+            {
+                span.push_span_label(
+                    drop_scope.span,
+                    format!("the temporary {snippet} is dropped here, after the {await_or_yield}"),
+                );
+            }
             err.span_note(
                 span,
                 format!("{future_or_coroutine} {trait_explanation} as this value is used across {an_await_or_yield}"),
@@ -2656,6 +2792,38 @@ fn note_obligation_cause_for_async_await(
         );
     }
 
+    /// Finds the local field of `def` through which `predicate`, a builtin bound
+    /// on one of the constituent types of the ADT, was required, so that failing
+    /// auto trait bounds can point at every field along the way.
+    fn constituent_field(
+        &self,
+        def: ty::AdtDef<'tcx>,
+        args: ty::GenericArgsRef<'tcx>,
+        predicate: ty::Predicate<'tcx>,
+    ) -> Option<(Span, Ty<'tcx>)> {
+        let tcx = self.tcx;
+        let trait_pred = predicate.as_clause()?.as_trait_clause()?;
+        let constituent_ty =
+            tcx.erase_regions(self.resolve_vars_if_possible(trait_pred.skip_binder().self_ty()));
+        let fields: Vec<_> = def
+            .all_fields()
+            .filter(|field| field.did.is_local())
+            .map(|field| (tcx.def_span(field.did), tcx.erase_regions(field.ty(tcx, args))))
+            .collect();
+        // Normalizing here could itself overflow, so fields whose type still
+        // mentions a projection are only matched up to unification.
+        let drcx = DeepRejectCtxt { treat_obligation_params: TreatParams::AsCandidateKey };
+        fields
+            .iter()
+            .find(|&&(_, field_ty)| field_ty == constituent_ty)
+            .or_else(|| {
+                fields.iter().find(|&&(_, field_ty)| {
+                    field_ty.has_projections() && drcx.types_may_unify(constituent_ty, field_ty)
+                })
+            })
+            .map(|&(span, _)| (span, constituent_ty))
+    }
+
     fn note_obligation_cause_code<T>(
         &self,
         body_id: LocalDefId,
@@ -3168,8 +3336,20 @@ fn note_obligation_cause_code<T>(
                         let ty_str = tcx.short_ty_string(ty, &mut file);
                         let msg = format!("required because it appears within the type `{ty_str}`");
                         match ty.kind() {
-                            ty::Adt(def, _) => match tcx.opt_item_ident(def.did()) {
-                                Some(ident) => err.span_note(ident.span, msg),
+                            ty::Adt(def, args) => match tcx.opt_item_ident(def.did()) {
+                                Some(ident) => {
+                                    let mut span = MultiSpan::from_span(ident.span);
+                                    if let Some((field_span, field_ty)) =
+                                        self.constituent_field(*def, args, predicate)
+                                    {
+                                        let field_ty = tcx.short_ty_string(field_ty, &mut file);
+                                        span.push_span_label(
+                                            field_span,
+                                            format!("this field has type `{field_ty}`"),
+                                        );
+                                    }
+                                    err.span_note(span, msg)
+                                }
                                 None => err.note(msg),
                             },
                             ty::Alias(ty::Opaque, ty::AliasTy { def_id, .. }) => {
@@ -3297,10 +3477,18 @@ fn note_obligation_cause_code<T>(
                             of_trait.as_ref().map(|t| t.path.span.ctxt().outer_expn_data().kind),
                             Some(ExpnKind::Macro(MacroKind::Derive, _))
                         ) {
-                            spans.push_span_label(
-                                data.span,
-                                "unsatisfied trait bound introduced in this `derive` macro",
-                            );
+                            if data.span.from_expansion() {
+                                spans.push_span_label(
+                                    data.span,
+                                    "unsatisfied trait bound introduced in this `derive` macro",
+                                );
+                            } else {
+                                // The bound was narrowed down to the field it was derived for.
+                                spans.push_span_label(
+                                    data.span,
+                                    "unsatisfied trait bound introduced by this field",
+                                );
+                            }
                         } else if !data.span.is_dummy() && !data.span.overlaps(self_ty.span) {
                             spans.push_span_label(
                                 data.span,
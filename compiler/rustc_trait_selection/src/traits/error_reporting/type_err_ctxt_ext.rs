@@ -4,6 +4,7 @@
 use super::suggestions::{get_explanation_based_on_obligation, TypeErrCtxtExt as _};
 use crate::errors::{
     AsyncClosureNotFn, ClosureFnMutLabel, ClosureFnOnceLabel, ClosureKindMismatch,
+    ExceededObligationFuel, NonConstClosure,
 };
 use crate::infer::error_reporting::{TyCategory, TypeAnnotationNeeded as ErrorCode};
 use crate::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
@@ -11,6 +12,7 @@
 use crate::infer::{self, InferCtxt};
 use crate::traits::error_reporting::infer_ctxt_ext::InferCtxtExt;
 use crate::traits::error_reporting::{ambiguity, ambiguity::Ambiguity::*};
+use crate::traits::explain::{InferCtxtExplainExt, ObligationExplanation};
 use crate::traits::query::evaluate_obligation::InferCtxtExt as _;
 use crate::traits::specialize::to_pretty_impl_header;
 use crate::traits::NormalizeExt;
@@ -369,6 +371,8 @@ fn report_selection_error(
                     return self.report_const_param_not_wf(ty, &obligation).emit();
                 }
 
+                self.apply_do_not_recommend(&mut obligation);
+
                 let bound_predicate = obligation.predicate.kind();
                 match bound_predicate.skip_binder() {
                     ty::PredicateKind::Clause(ty::ClauseKind::Trait(trait_predicate)) => {
@@ -692,6 +696,9 @@ fn report_selection_error(
                         }
 
                         self.explain_hrtb_projection(&mut err, trait_predicate, obligation.param_env, &obligation.cause);
+                        if tcx.sess.opts.unstable_opts.explain_obligation {
+                            self.note_obligation_explanation(&mut err, &obligation, root_obligation);
+                        }
                         self.suggest_desugaring_async_fn_in_trait(&mut err, trait_ref);
 
                         // Return early if the trait is Debug or Display and the invocation
@@ -752,8 +759,8 @@ fn report_selection_error(
                     }
 
                     ty::PredicateKind::ObjectSafe(trait_def_id) => {
-                        let violations = self.tcx.object_safety_violations(trait_def_id);
-                        report_object_safety_error(self.tcx, span, None, trait_def_id, violations)
+                        let explanations = self.tcx.object_safety_explanations(trait_def_id);
+                        report_object_safety_error(self.tcx, span, None, trait_def_id, explanations)
                     }
 
                     ty::PredicateKind::Clause(ty::ClauseKind::WellFormed(ty)) => {
@@ -860,8 +867,8 @@ fn report_selection_error(
             ),
 
             TraitNotObjectSafe(did) => {
-                let violations = self.tcx.object_safety_violations(did);
-                report_object_safety_error(self.tcx, span, None, did, violations)
+                let explanations = self.tcx.object_safety_explanations(did);
+                report_object_safety_error(self.tcx, span, None, did, explanations)
             }
 
             SelectionError::NotConstEvaluatable(NotConstEvaluatable::MentionsInfer) => {
@@ -1427,6 +1434,11 @@ fn report_fulfillment_error(&self, error: &FulfillmentError<'tcx>) -> ErrorGuara
                 diag.emit()
             }
             FulfillmentErrorCode::Cycle(ref cycle) => self.report_overflow_obligation_cycle(cycle),
+            FulfillmentErrorCode::ExceededFuel => self.dcx().emit_err(ExceededObligationFuel {
+                span: error.root_obligation.cause.span,
+                predicate: self.resolve_vars_if_possible(error.root_obligation.predicate),
+                fuel: self.tcx.sess.opts.unstable_opts.obligation_fuel.unwrap_or_default(),
+            }),
         }
     }
 
@@ -1786,6 +1798,7 @@ fn find_similar_impl_candidates(
                 let imp = self.tcx.impl_trait_header(def_id).unwrap().skip_binder();
                 if imp.polarity == ty::ImplPolarity::Negative
                     || !self.tcx.is_user_visible_dep(def_id.krate)
+                    || self.tcx.do_not_recommend_impl(def_id)
                 {
                     return None;
                 }
@@ -1967,6 +1980,7 @@ fn report_similar_impl_candidates(
             let mut impl_candidates: Vec<_> = self
                 .tcx
                 .all_impls(def_id)
+                .filter(|&def_id| !self.tcx.do_not_recommend_impl(def_id))
                 // Ignore automatically derived impls and `!Trait` impls.
                 .filter_map(|def_id| self.tcx.impl_trait_header(def_id))
                 .map(ty::EarlyBinder::instantiate_identity)
@@ -2074,6 +2088,27 @@ fn report_similar_impl_candidates_for_root_obligation(
     }
 
     /// Gets the parent trait chain start
+    /// Reports the obligations which arose from an impl annotated with
+    /// `#[diagnostic::do_not_recommend]` as the obligation that impl was used for,
+    /// so that the error doesn't point users at the bounds of the impl.
+    fn apply_do_not_recommend(&self, obligation: &mut PredicateObligation<'tcx>) {
+        let mut base_cause = obligation.cause.code().clone();
+        loop {
+            if let ObligationCauseCode::ImplDerivedObligation(ref c) = base_cause
+                && self.tcx.do_not_recommend_impl(c.impl_or_alias_def_id)
+            {
+                let code = (*c.derived.parent_code).clone();
+                obligation.cause.map_code(|_| code);
+                obligation.predicate = c.derived.parent_trait_pred.to_predicate(self.tcx);
+            }
+            if let Some((parent_cause, _parent_pred)) = base_cause.parent() {
+                base_cause = parent_cause.clone();
+            } else {
+                break;
+            }
+        }
+    }
+
     fn get_parent_trait_ref(
         &self,
         code: &ObligationCauseCode<'tcx>,
@@ -2715,6 +2750,68 @@ fn fold_ty(&mut self, ty: Ty<'tcx>) -> Ty<'tcx> {
         })
     }
 
+    /// Adds notes explaining which impls have been considered for the failed
+    /// obligation and which of their where-clauses do not hold, recursively.
+    ///
+    /// We prefer explaining the root obligation, as that is the one the user
+    /// wrote, and its explanation contains the explanation for `obligation`.
+    fn note_obligation_explanation(
+        &self,
+        err: &mut Diagnostic,
+        obligation: &PredicateObligation<'tcx>,
+        root_obligation: &PredicateObligation<'tcx>,
+    ) {
+        let Some(explanation) = self
+            .explain_unsatisfied_obligation(root_obligation)
+            .or_else(|| self.explain_unsatisfied_obligation(obligation))
+        else {
+            return;
+        };
+        self.note_obligation_explanation_recur(err, &explanation);
+    }
+
+    fn note_obligation_explanation_recur(
+        &self,
+        err: &mut Diagnostic,
+        explanation: &ObligationExplanation<'tcx>,
+    ) {
+        let tcx = self.tcx;
+        for candidate in &explanation.candidates {
+            if candidate.unsatisfied_where_clauses.is_empty() {
+                continue;
+            }
+
+            let impl_trait_ref = tcx
+                .impl_trait_ref(candidate.impl_def_id)
+                .unwrap()
+                .instantiate(tcx, candidate.impl_args);
+            let mut span = MultiSpan::from_span(tcx.def_span(candidate.impl_def_id));
+            for where_clause in &candidate.unsatisfied_where_clauses {
+                span.push_span_label(
+                    where_clause.span,
+                    format!("`{}` does not hold", where_clause.clause),
+                );
+            }
+            err.span_note(
+                span,
+                format!(
+                    "the impl `impl {} for {}` was considered for `{}`, but {} where-clause{} not hold",
+                    impl_trait_ref.print_only_trait_path(),
+                    impl_trait_ref.self_ty(),
+                    explanation.predicate.skip_binder(),
+                    if candidate.unsatisfied_where_clauses.len() == 1 { "its" } else { "some of its" },
+                    if candidate.unsatisfied_where_clauses.len() == 1 { " does" } else { "s do" },
+                ),
+            );
+
+            for where_clause in &candidate.unsatisfied_where_clauses {
+                if let Some(explanation) = &where_clause.explanation {
+                    self.note_obligation_explanation_recur(err, explanation);
+                }
+            }
+        }
+    }
+
     fn note_obligation_cause(&self, err: &mut Diagnostic, obligation: &PredicateObligation<'tcx>) {
         // First, attempt to add note to this error with an async-await-specific
         // message, and fall back to regular note otherwise.
@@ -3321,6 +3418,22 @@ fn report_signature_mismatch_error(
             return Err(self.dcx().span_delayed_bug(span, "already_reported"));
         }
 
+        // If the signatures only differ in their host effect, a non-const closure was
+        // used where a const one is expected.
+        if let ty::Closure(..) = found_trait_ty.kind()
+            && let Some(found_span) = found_span
+            && let Some(idx) = self.tcx.generics_of(expected_trait_ref.def_id()).host_effect_index
+            && iter::zip(found_trait_ref.skip_binder().args, expected_trait_ref.skip_binder().args)
+                .enumerate()
+                .all(|(i, (found, expected))| i == idx || found == expected)
+        {
+            return Ok(self.dcx().create_err(NonConstClosure {
+                span,
+                closure_span: found_span,
+                suggestion: found_span.shrink_to_lo(),
+            }));
+        }
+
         let mut not_tupled = false;
 
         let found = match found_trait_ref.skip_binder().args.type_at(1).kind() {
@@ -0,0 +1,173 @@
+//! Computes structured explanations for why a trait obligation does not hold.
+//!
+//! Given a failed obligation `T: Trait`, we look at every impl of `Trait`
+//! whose header unifies with the obligation and check which of its
+//! where-clauses do not hold. For each unsatisfied where-clause which is
+//! itself a trait predicate, we recursively explain why it doesn't hold,
+//! up to a fixed depth.
+//!
+//! This is used by the fulfillment error reporting when `-Zexplain-obligation`
+//! is enabled and is also available to other consumers, e.g. tooling.
+
+use rustc_hir::def_id::DefId;
+use rustc_infer::infer::{DefineOpaqueTypes, InferCtxt};
+use rustc_infer::traits::{Obligation, ObligationCause, PredicateObligation};
+use rustc_middle::ty::{self, GenericArgsRef, TypeVisitableExt};
+use rustc_span::{Span, DUMMY_SP};
+
+use crate::traits::query::evaluate_obligation::InferCtxtExt as _;
+
+/// We stop explaining nested where-clauses after this many levels to avoid
+/// both overflow and overwhelming the user with notes.
+const MAX_EXPLANATION_DEPTH: usize = 3;
+
+/// Why a trait predicate does not hold.
+#[derive(Debug, Clone)]
+pub struct ObligationExplanation<'tcx> {
+    pub predicate: ty::PolyTraitPredicate<'tcx>,
+    /// All impls whose header unifies with `predicate`. These are
+    /// the impls which would apply if their where-clauses held.
+    pub candidates: Vec<ImplCandidateExplanation<'tcx>>,
+}
+
+/// An impl which has been considered while trying to prove a predicate.
+#[derive(Debug, Clone)]
+pub struct ImplCandidateExplanation<'tcx> {
+    pub impl_def_id: DefId,
+    /// The arguments of the impl after unifying its header with the predicate.
+    /// Generic parameters of the impl which could not be inferred are kept as is.
+    pub impl_args: GenericArgsRef<'tcx>,
+    pub unsatisfied_where_clauses: Vec<UnsatisfiedWhereClause<'tcx>>,
+}
+
+/// A where-clause of an impl which does not hold.
+#[derive(Debug, Clone)]
+pub struct UnsatisfiedWhereClause<'tcx> {
+    pub clause: ty::Clause<'tcx>,
+    pub span: Span,
+    /// Why this where-clause does not hold, if it is a trait predicate and
+    /// we did not yet reach the maximum depth.
+    pub explanation: Option<ObligationExplanation<'tcx>>,
+}
+
+#[extension(pub trait InferCtxtExplainExt<'tcx>)]
+impl<'tcx> InferCtxt<'tcx> {
+    /// Explains why `obligation` does not hold. Returns `None` if the obligation
+    /// is not a trait predicate.
+    ///
+    /// This does not check whether `obligation` actually fails, so any explanation
+    /// for an obligation which holds is meaningless.
+    fn explain_unsatisfied_obligation(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+    ) -> Option<ObligationExplanation<'tcx>> {
+        let bound_predicate = obligation.predicate.kind();
+        let ty::PredicateKind::Clause(ty::ClauseKind::Trait(trait_pred)) =
+            bound_predicate.skip_binder()
+        else {
+            return None;
+        };
+        let trait_pred = self.resolve_vars_if_possible(bound_predicate.rebind(trait_pred));
+        Some(explain_trait_predicate(self, obligation.param_env, trait_pred, 0))
+    }
+}
+
+fn explain_trait_predicate<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    predicate: ty::PolyTraitPredicate<'tcx>,
+    depth: usize,
+) -> ObligationExplanation<'tcx> {
+    let tcx = infcx.tcx;
+    let mut candidates = vec![];
+    tcx.for_each_relevant_impl(
+        predicate.def_id(),
+        predicate.skip_binder().self_ty(),
+        |impl_def_id| {
+            if tcx.impl_polarity(impl_def_id) != ty::ImplPolarity::Positive {
+                return;
+            }
+
+            if let Some(candidate) = infcx
+                .probe(|_| explain_impl_candidate(infcx, param_env, predicate, impl_def_id, depth))
+            {
+                candidates.push(candidate);
+            }
+        },
+    );
+
+    ObligationExplanation { predicate, candidates }
+}
+
+fn explain_impl_candidate<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    predicate: ty::PolyTraitPredicate<'tcx>,
+    impl_def_id: DefId,
+    depth: usize,
+) -> Option<ImplCandidateExplanation<'tcx>> {
+    let tcx = infcx.tcx;
+    let cause = ObligationCause::dummy();
+    let predicate = infcx.enter_forall_and_leak_universe(predicate);
+    let impl_args = infcx.fresh_args_for_item(DUMMY_SP, impl_def_id);
+    let impl_trait_ref = tcx.impl_trait_ref(impl_def_id).unwrap().instantiate(tcx, impl_args);
+    let ok = infcx
+        .at(&cause, param_env)
+        .eq(DefineOpaqueTypes::No, predicate.trait_ref, impl_trait_ref)
+        .ok()?;
+    if ok.obligations.iter().any(|obligation| !infcx.predicate_may_hold(obligation)) {
+        return None;
+    }
+
+    // The explanation outlives this probe, so we must not leak any inference
+    // variables from it. Whatever we weren't able to infer stays generic.
+    let printable_args = printable_impl_args(infcx, impl_def_id, impl_args);
+    let predicates = tcx.predicates_of(impl_def_id);
+    let unsatisfied_where_clauses = std::iter::zip(
+        predicates.instantiate(tcx, impl_args),
+        predicates.instantiate(tcx, printable_args),
+    )
+    .filter_map(|((clause, _), (printable_clause, span))| {
+        let obligation = Obligation::new(tcx, cause.clone(), param_env, clause);
+        if infcx.predicate_may_hold(&obligation) {
+            return None;
+        }
+
+        let explanation = clause.as_trait_clause().filter(|_| depth < MAX_EXPLANATION_DEPTH).map(
+            |trait_clause| {
+                let trait_clause = infcx.resolve_vars_if_possible(trait_clause);
+                let mut explanation =
+                    explain_trait_predicate(infcx, param_env, trait_clause, depth + 1);
+                explanation.predicate = printable_clause.as_trait_clause().unwrap();
+                explanation
+            },
+        );
+        Some(UnsatisfiedWhereClause { clause: printable_clause, span, explanation })
+    })
+    .collect();
+
+    Some(ImplCandidateExplanation {
+        impl_def_id,
+        impl_args: printable_args,
+        unsatisfied_where_clauses,
+    })
+}
+
+/// Resolves the inference variables in `impl_args`, replacing all arguments
+/// which still contain inference variables with the generic parameters of the impl.
+fn printable_impl_args<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    impl_def_id: DefId,
+    impl_args: GenericArgsRef<'tcx>,
+) -> GenericArgsRef<'tcx> {
+    let tcx = infcx.tcx;
+    let identity_args = ty::GenericArgs::identity_for_item(tcx, impl_def_id);
+    let impl_args = infcx.resolve_vars_if_possible(impl_args);
+    tcx.mk_args_from_iter(std::iter::zip(impl_args, identity_args).map(|(arg, identity_arg)| {
+        let arg = tcx.fold_regions(arg, |r, _| match *r {
+            ty::ReVar(_) => tcx.lifetimes.re_erased,
+            _ => r,
+        });
+        if arg.has_infer() || arg.has_placeholders() { identity_arg } else { arg }
+    }))
+}
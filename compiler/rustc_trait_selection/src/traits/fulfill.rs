@@ -1,18 +1,24 @@
+use crate::infer::canonical::OriginalQueryValues;
 use crate::infer::{InferCtxt, TyOrConstInferVar};
 use crate::traits::error_reporting::TypeErrCtxtExt;
 use crate::traits::normalize::normalize_with_depth_to;
 use rustc_data_structures::captures::Captures;
-use rustc_data_structures::obligation_forest::ProcessResult;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap};
 use rustc_data_structures::obligation_forest::{Error, ForestObligation, Outcome};
 use rustc_data_structures::obligation_forest::{ObligationForest, ObligationProcessor};
+use rustc_data_structures::obligation_forest::{ObligationTreeId, ProcessResult};
+use rustc_data_structures::sync::par_map;
 use rustc_infer::infer::DefineOpaqueTypes;
 use rustc_infer::traits::ProjectionCacheKey;
 use rustc_infer::traits::{PolyTraitObligation, SelectionError, TraitEngine};
+use rustc_middle::infer::canonical::Canonical;
 use rustc_middle::mir::interpret::ErrorHandled;
 use rustc_middle::ty::abstract_const::NotConstEvaluatable;
 use rustc_middle::ty::error::{ExpectedFound, TypeError};
 use rustc_middle::ty::GenericArgsRef;
-use rustc_middle::ty::{self, Binder, Const, TypeVisitableExt};
+use rustc_middle::ty::{self, Binder, Const, TyCtxt, TypeVisitableExt};
+use rustc_span::Span;
+use std::iter;
 use std::marker::PhantomData;
 
 use super::const_evaluatable;
@@ -20,9 +26,9 @@
 use super::select::SelectionContext;
 use super::wf;
 use super::EvaluationResult;
-use super::PredicateObligation;
 use super::Unimplemented;
 use super::{FulfillmentError, FulfillmentErrorCode};
+use super::{Obligation, ObligationCause, PredicateObligation, TraitQueryMode};
 
 use crate::traits::project::PolyProjectionObligation;
 use crate::traits::project::ProjectionCacheKeyExt as _;
@@ -59,6 +65,14 @@ pub struct FulfillmentContext<'tcx> {
     /// gets rolled back. Because of this we explicitly check that we only
     /// use the context in exactly this snapshot.
     usable_in_snapshot: usize,
+
+    /// The number of fulfillment steps taken for each tree of obligations so
+    /// far, only tracked with `-Zobligation-fuel`.
+    fuel_consumed: FxHashMap<ObligationTreeId, usize>,
+
+    /// The obligations which have already been tried to be proven on the thread
+    /// pool of `-Z threads`, only tracked with more than one thread.
+    attempted_in_parallel: FxHashSet<ty::ParamEnvAnd<'tcx, ty::Predicate<'tcx>>>,
 }
 
 #[derive(Clone, Debug)]
@@ -86,7 +100,104 @@ pub(super) fn new(infcx: &InferCtxt<'tcx>) -> FulfillmentContext<'tcx> {
         FulfillmentContext {
             predicates: ObligationForest::new(),
             usable_in_snapshot: infcx.num_open_snapshots(),
+            fuel_consumed: Default::default(),
+            attempted_in_parallel: Default::default(),
+        }
+    }
+
+    /// Tries to prove the pending trait and projection obligations on the thread pool of
+    /// `-Z threads`, before they are processed one by one on the shared `InferCtxt`, whose tables
+    /// are not `Sync`.
+    ///
+    /// The obligations are partitioned by the inference variables they use. Each partition is
+    /// canonicalized and processed in a fresh inference context, which only needs the `TyCtxt`.
+    /// A partition only counts as proven if this did not constrain any of its inference variables
+    /// or regions, so that the proof holds whatever they end up being inferred to. `select` then
+    /// treats the returned obligations as done, and processes all other ones, reporting errors in
+    /// the order of the forest, like without threads.
+    ///
+    /// An obligation is only attempted again once it changed, i.e. once one of its inference
+    /// variables has been resolved.
+    fn prove_independent_obligations_in_parallel(
+        &mut self,
+        infcx: &InferCtxt<'tcx>,
+    ) -> FxHashSet<ty::ParamEnvAnd<'tcx, ty::Predicate<'tcx>>> {
+        let tcx = infcx.tcx;
+        let keys: Vec<_> = self
+            .predicates
+            .map_pending_obligations(|pending| {
+                let obligation = &pending.obligation;
+                matches!(
+                    obligation.predicate.kind().skip_binder(),
+                    ty::PredicateKind::Clause(
+                        ty::ClauseKind::Trait(_) | ty::ClauseKind::Projection(_)
+                    )
+                )
+                .then(|| {
+                    let predicate = infcx.resolve_vars_if_possible(obligation.predicate);
+                    (obligation.cause.span, obligation.param_env.and(predicate))
+                })
+            })
+            .into_iter()
+            .flatten()
+            .filter(|&(_, key)| self.attempted_in_parallel.insert(key))
+            .collect();
+        if keys.len() < 2 {
+            return FxHashSet::default();
+        }
+
+        // Union the obligations which share an inference variable.
+        fn find(parents: &mut [usize], mut idx: usize) -> usize {
+            while parents[idx] != idx {
+                parents[idx] = parents[parents[idx]];
+                idx = parents[idx];
+            }
+            idx
+        }
+        let mut parents: Vec<usize> = (0..keys.len()).collect();
+        let mut first_user = FxHashMap::default();
+        for (idx, (_, key)) in keys.iter().enumerate() {
+            let args = match key.value.kind().skip_binder() {
+                ty::PredicateKind::Clause(ty::ClauseKind::Trait(pred)) => pred.trait_ref.args,
+                ty::PredicateKind::Clause(ty::ClauseKind::Projection(pred)) => {
+                    tcx.mk_args_from_iter(pred.projection_ty.args.iter().chain([pred.term.into()]))
+                }
+                _ => unreachable!(),
+            };
+            for var in args
+                .iter()
+                .flat_map(|arg| arg.walk())
+                .filter_map(TyOrConstInferVar::maybe_from_generic_arg)
+            {
+                let other = *first_user.entry(var).or_insert(idx);
+                let (root, other_root) = (find(&mut parents, idx), find(&mut parents, other));
+                parents[other_root] = root;
+            }
         }
+
+        // Keep the partitions in the order of their first obligation.
+        let mut partitions: FxIndexMap<usize, Vec<usize>> = FxIndexMap::default();
+        for idx in 0..keys.len() {
+            partitions.entry(find(&mut parents, idx)).or_default().push(idx);
+        }
+        let partitions: Vec<Vec<usize>> = partitions.into_values().collect();
+        let goals: Vec<_> = partitions
+            .iter()
+            .map(|partition| {
+                let mut orig_values = OriginalQueryValues::default();
+                let obligations: Vec<_> = partition.iter().map(|&idx| keys[idx].1).collect();
+                (keys[partition[0]].0, infcx.canonicalize_query(obligations, &mut orig_values))
+            })
+            .collect();
+
+        let proven: Vec<bool> =
+            par_map(goals, |(span, goal)| prove_canonical_obligations(tcx, span, &goal));
+
+        iter::zip(partitions, proven)
+            .filter(|&(_, proven)| proven)
+            .flat_map(|(partition, _)| partition)
+            .map(|idx| keys[idx].1)
+            .collect()
     }
 
     /// Attempts to select obligations using `selcx`.
@@ -94,9 +205,38 @@ fn select(&mut self, selcx: SelectionContext<'_, 'tcx>) -> Vec<FulfillmentError<
         let span = debug_span!("select", obligation_forest_size = ?self.predicates.len());
         let _enter = span.enter();
 
+        // The partitions proven on the thread pool use canonical query mode, and must not recurse.
+        let proven_in_parallel = if selcx.tcx().sess.threads() > 1
+            && !selcx.is_intercrate()
+            && selcx.query_mode() == TraitQueryMode::Standard
+        {
+            self.prove_independent_obligations_in_parallel(selcx.infcx)
+        } else {
+            FxHashSet::default()
+        };
+
         // Process pending obligations.
-        let outcome: Outcome<_, _> =
-            self.predicates.process_obligations(&mut FulfillProcessor { selcx });
+        let fuel = selcx.tcx().sess.opts.unstable_opts.obligation_fuel;
+        let outcome: Outcome<_, _> = self.predicates.process_obligations(&mut FulfillProcessor {
+            selcx,
+            fuel,
+            fuel_consumed: &mut self.fuel_consumed,
+            proven_in_parallel: &proven_in_parallel,
+        });
+
+        if let Some(fuel) = fuel {
+            // The roots of the trees which ran out of fuel were reported as errors, drop what is
+            // left of them. Then forget about the trees which are done.
+            let exhausted: Vec<_> = self
+                .fuel_consumed
+                .iter()
+                .filter(|&(_, &consumed)| consumed > fuel)
+                .map(|(&tree, _)| tree)
+                .collect();
+            self.predicates.remove_trees(&exhausted);
+            let remaining = self.predicates.remaining_trees();
+            self.fuel_consumed.retain(|tree, _| remaining.contains(tree));
+        }
 
         // FIXME: if we kept the original cache key, we could mark projection
         // obligations as complete for the projection cache here.
@@ -205,6 +345,10 @@ fn pending_obligations(&self) -> Vec<PredicateObligation<'tcx>> {
 
 struct FulfillProcessor<'a, 'tcx> {
     selcx: SelectionContext<'a, 'tcx>,
+    fuel: Option<usize>,
+    fuel_consumed: &'a mut FxHashMap<ObligationTreeId, usize>,
+    /// The obligations which have already been proven on the thread pool of `-Z threads`.
+    proven_in_parallel: &'a FxHashSet<ty::ParamEnvAnd<'tcx, ty::Predicate<'tcx>>>,
 }
 
 fn mk_pending(os: Vec<PredicateObligation<'_>>) -> Vec<PendingPredicateObligation<'_>> {
@@ -309,6 +453,10 @@ fn process_obligation(
 
         let obligation = &pending_obligation.obligation;
 
+        if self.proven_in_parallel.contains(&obligation.param_env.and(obligation.predicate)) {
+            return ProcessResult::Changed(vec![]);
+        }
+
         let infcx = self.selcx.infcx;
 
         if obligation.predicate.has_projections() {
@@ -670,6 +818,28 @@ fn process_obligation(
         }
     }
 
+    fn process_obligation_in_tree(
+        &mut self,
+        pending_obligation: &mut PendingPredicateObligation<'tcx>,
+        tree: ObligationTreeId,
+    ) -> ProcessResult<PendingPredicateObligation<'tcx>, FulfillmentErrorCode<'tcx>> {
+        if let Some(fuel) = self.fuel {
+            let consumed = self.fuel_consumed.entry(tree).or_default();
+            *consumed += 1;
+            if *consumed > fuel {
+                debug!(?pending_obligation, "exceeded obligation fuel");
+                // Only report the root once. The rest of its tree is stalled
+                // until `select` drops it.
+                return if *consumed == fuel + 1 {
+                    ProcessResult::Error(FulfillmentErrorCode::ExceededFuel)
+                } else {
+                    ProcessResult::Unchanged
+                };
+            }
+        }
+        self.process_obligation(pending_obligation)
+    }
+
     #[inline(never)]
     fn process_backedge<'c, I>(
         &mut self,
@@ -831,3 +1001,27 @@ fn to_fulfillment_error<'tcx>(
     let root_obligation = iter.next_back().map_or_else(|| obligation.clone(), |e| e.obligation);
     FulfillmentError::new(obligation, error.error, root_obligation)
 }
+
+/// Processes a partition of obligations in a fresh inference context, see
+/// `FulfillmentContext::prove_independent_obligations_in_parallel`. Returns whether all of them
+/// hold without constraining their inference variables or regions.
+fn prove_canonical_obligations<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    span: Span,
+    goal: &Canonical<'tcx, Vec<ty::ParamEnvAnd<'tcx, ty::Predicate<'tcx>>>>,
+) -> bool {
+    let (ref infcx, obligations, var_values) = tcx.infer_ctxt().build_with_canonical(span, goal);
+    let mut fulfill_cx = FulfillmentContext::new(infcx);
+    for ty::ParamEnvAnd { param_env, value } in obligations {
+        let obligation =
+            Obligation::new(tcx, ObligationCause::dummy_with_span(span), param_env, value);
+        fulfill_cx.register_predicate_obligation(infcx, obligation);
+    }
+    // In canonical query mode, overflow is returned as an error instead of being reported.
+    let selcx = SelectionContext::with_query_mode(infcx, TraitQueryMode::Canonical);
+    fulfill_cx.select(selcx).is_empty()
+        && fulfill_cx.predicates.len() == 0
+        && infcx.canonicalize_response(var_values).value.is_identity()
+        && infcx.take_registered_region_obligations().is_empty()
+        && infcx.take_and_reset_region_constraints().is_empty()
+}
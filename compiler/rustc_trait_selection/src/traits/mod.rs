@@ -7,6 +7,7 @@
 pub mod const_evaluatable;
 mod engine;
 pub mod error_reporting;
+pub mod explain;
 mod fulfill;
 pub mod misc;
 pub mod normalize;
@@ -41,12 +42,14 @@
 use std::fmt::Debug;
 use std::ops::ControlFlow;
 
+pub use self::coherence::dump_intercrate_ambiguities;
 pub use self::coherence::{add_placeholder_note, orphan_check, overlapping_impls};
 pub use self::coherence::{OrphanCheckErr, OverlapResult};
 pub use self::engine::{ObligationCtxt, TraitEngineExt};
 pub use self::fulfill::{FulfillmentContext, PendingPredicateObligation};
-pub use self::normalize::NormalizeExt;
+pub use self::normalize::{DeeplyNormalizeExt, NormalizeExt};
 pub use self::object_safety::astconv_object_safety_violations;
+pub use self::object_safety::explain_object_safety_violation;
 pub use self::object_safety::is_vtable_safe_method;
 pub use self::object_safety::object_safety_violations_for_assoc_item;
 pub use self::object_safety::ObjectSafetyViolation;
@@ -61,12 +64,18 @@
 pub use self::structural_match::search_for_structural_match_violation;
 pub use self::structural_normalize::StructurallyNormalizeExt;
 pub use self::util::elaborate;
+pub(crate) use self::util::{async_closure_trait_ref_and_return_type, TupleArgumentsFlag};
 pub use self::util::{
-    check_args_compatible, supertrait_def_ids, supertraits, transitive_bounds,
-    transitive_bounds_that_define_assoc_item, SupertraitDefIds,
+    check_args_compatible, check_args_compatible_explained, derived_impl_clause_field,
+    elaborate_with_provenance, supertrait_def_ids, supertrait_def_ids_bfs, supertraits,
+    transitive_bounds, transitive_bounds_that_define_assoc_item, ArgsIncompatibility,
+    ElaborationInfo, SupertraitDefIds, SupertraitDefIdsBfs,
 };
 pub use self::util::{expand_trait_aliases, TraitAliasExpander};
-pub use self::util::{get_vtable_index_of_object_method, impl_item_is_final, upcast_choices};
+pub use self::util::{
+    get_vtable_index_of_object_method, impl_item_is_final, upcast_choices,
+    upcast_choices_with_spans,
+};
 pub use self::util::{with_replaced_escaping_bound_vars, BoundVarReplacer, PlaceholderReplacer};
 
 pub use rustc_infer::traits::*;
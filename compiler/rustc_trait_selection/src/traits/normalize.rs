@@ -1,8 +1,11 @@
 //! Deeply normalize types using the old trait solver.
 use rustc_data_structures::stack::ensure_sufficient_stack;
 use rustc_infer::infer::at::At;
-use rustc_infer::infer::InferOk;
+use rustc_infer::infer::canonical::query_response::make_query_region_constraints;
+use rustc_infer::infer::canonical::QueryRegionConstraints;
+use rustc_infer::infer::{InferCtxt, InferOk};
 use rustc_infer::traits::PredicateObligation;
+use rustc_infer::traits::TraitEngineExt as _;
 use rustc_infer::traits::{FulfillmentError, Normalized, Obligation, TraitEngine};
 use rustc_middle::traits::{ObligationCause, ObligationCauseCode, Reveal};
 use rustc_middle::ty::{self, Ty, TyCtxt, TypeFolder};
@@ -10,6 +13,7 @@
 
 use super::error_reporting::TypeErrCtxtExt;
 use super::SelectionContext;
+use super::TraitEngineExt as _;
 use super::{project, with_replaced_escaping_bound_vars, BoundVarReplacer, PlaceholderReplacer};
 
 #[extension(pub trait NormalizeExt<'tcx>)]
@@ -61,6 +65,50 @@ fn deeply_normalize<T: TypeFoldable<TyCtxt<'tcx>>>(
     }
 }
 
+#[extension(pub trait DeeplyNormalizeExt<'tcx>)]
+impl<'tcx> InferCtxt<'tcx> {
+    /// Deeply normalizes `value`, proves all the obligations this requires, and
+    /// returns the normalized value together with the region constraints that
+    /// proving them generated.
+    ///
+    /// Like `scrape_region_constraints`, this takes *all* region constraints out of
+    /// the inference context, so it must only be used by callers which scrape them
+    /// after each operation, such as MIR type-checking. It expects no region
+    /// obligations to be registered on entry.
+    fn deeply_normalize_with_constraints<T: TypeFoldable<TyCtxt<'tcx>>>(
+        &self,
+        cause: &ObligationCause<'tcx>,
+        param_env: ty::ParamEnv<'tcx>,
+        value: T,
+    ) -> Result<(T, QueryRegionConstraints<'tcx>), Vec<FulfillmentError<'tcx>>> {
+        let pre_obligations = self.take_registered_region_obligations();
+        assert!(
+            pre_obligations.is_empty(),
+            "deeply_normalize_with_constraints: incoming region obligations = {pre_obligations:#?}",
+        );
+
+        let value = self.commit_if_ok(|_| {
+            let mut fulfill_cx = <dyn TraitEngine<'tcx>>::new(self);
+            let value = self.at(cause, param_env).deeply_normalize(value, &mut *fulfill_cx)?;
+            let errors = fulfill_cx.select_all_or_error(self);
+            if errors.is_empty() { Ok(value) } else { Err(errors) }
+        })?;
+        let value = self.resolve_vars_if_possible(value);
+
+        let region_obligations = self.take_registered_region_obligations();
+        let region_constraint_data = self.take_and_reset_region_constraints();
+        let constraints = make_query_region_constraints(
+            self.tcx,
+            region_obligations.iter().map(|r_o| {
+                let sup_type = self.resolve_vars_if_possible(r_o.sup_type);
+                (sup_type, r_o.sub_region, r_o.origin.to_constraint_category())
+            }),
+            &region_constraint_data,
+        );
+        Ok((value, constraints))
+    }
+}
+
 /// As `normalize`, but with a custom depth.
 pub(crate) fn normalize_with_depth<'a, 'b, 'tcx, T>(
     selcx: &'a mut SelectionContext<'b, 'tcx>,
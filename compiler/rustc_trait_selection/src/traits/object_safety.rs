@@ -8,7 +8,7 @@
 //!   - not reference the erased type `Self` except for in this receiver;
 //!   - not have generic type parameters.
 
-use super::elaborate;
+use super::{elaborate, elaborate_with_provenance};
 
 use crate::infer::TyCtxtInferExt;
 use crate::traits::query::evaluate_obligation::InferCtxtExt;
@@ -30,7 +30,10 @@
 use std::iter;
 use std::ops::ControlFlow;
 
-pub use crate::traits::{MethodViolationCode, ObjectSafetyViolation};
+pub use crate::traits::{
+    MethodViolationCode, ObjectSafetyExplanation, ObjectSafetyViolation,
+    ObjectSafetyViolationSolution,
+};
 
 /// Returns the object safety violations that affect
 /// astconv -- currently, `Self` in supertraits. This is needed
@@ -62,6 +65,135 @@ fn object_safety_violations(tcx: TyCtxt<'_>, trait_def_id: DefId) -> &'_ [Object
     )
 }
 
+fn object_safety_explanations(
+    tcx: TyCtxt<'_>,
+    trait_def_id: DefId,
+) -> &'_ [ObjectSafetyExplanation] {
+    debug_assert!(tcx.generics_of(trait_def_id).has_self);
+
+    tcx.arena.alloc_from_iter(
+        tcx.object_safety_violations(trait_def_id)
+            .iter()
+            .map(|violation| explain_object_safety_violation(tcx, trait_def_id, violation.clone())),
+    )
+}
+
+/// Computes the ways in which `violation` of the trait `trait_def_id` (or one of its
+/// supertraits) could be fixed. We only suggest changes to local items.
+///
+/// Unlike the `object_safety_explanations` query, this can be used while lowering
+/// trait object types, as long as `violation` itself has been computed without
+/// causing cycles.
+pub fn explain_object_safety_violation(
+    tcx: TyCtxt<'_>,
+    trait_def_id: DefId,
+    violation: ObjectSafetyViolation,
+) -> ObjectSafetyExplanation {
+    let solutions = match violation {
+        ObjectSafetyViolation::SizedSelf(_)
+        | ObjectSafetyViolation::SupertraitSelf(_)
+        | ObjectSafetyViolation::SupertraitNonLifetimeBinder(_) => vec![],
+        ObjectSafetyViolation::Method(
+            name,
+            MethodViolationCode::StaticMethod(Some((ref add_self_sugg, ref make_sized_sugg))),
+            _,
+        ) => vec![ObjectSafetyViolationSolution::AddSelfOrMakeSized {
+            name,
+            add_self_sugg: add_self_sugg.clone(),
+            make_sized_sugg: make_sized_sugg.clone(),
+        }],
+        ObjectSafetyViolation::Method(
+            name,
+            MethodViolationCode::UndispatchableReceiver(Some(span)),
+            _,
+        ) => vec![ObjectSafetyViolationSolution::ChangeToRefSelf(name, span)],
+        ObjectSafetyViolation::Method(name, ref code, _) => {
+            let mut solutions = vec![];
+            if let Some(hir::Node::TraitItem(
+                item @ hir::TraitItem { kind: hir::TraitItemKind::Fn(..), .. },
+            )) = find_local_assoc_item(tcx, trait_def_id, name, ty::AssocKind::Fn)
+            {
+                if let MethodViolationCode::Generic = code
+                    && let Some(suggs) = trait_object_args_suggestion(tcx, item.generics)
+                {
+                    solutions
+                        .push(ObjectSafetyViolationSolution::UseTraitObjectArgs { name, suggs });
+                }
+                let generics = item.generics;
+                solutions.push(ObjectSafetyViolationSolution::MakeSized {
+                    name,
+                    make_sized_sugg: (
+                        format!("{} Self: Sized", generics.add_where_or_trailing_comma()),
+                        generics.tail_span_for_predicate_suggestion(),
+                    ),
+                });
+            }
+            solutions.push(ObjectSafetyViolationSolution::MoveToAnotherTrait(name));
+            solutions
+        }
+        ObjectSafetyViolation::AssocConst(name, _) | ObjectSafetyViolation::GAT(name, _) => {
+            vec![ObjectSafetyViolationSolution::MoveToAnotherTrait(name)]
+        }
+    };
+
+    ObjectSafetyExplanation { violation, solutions }
+}
+
+/// Looks up the HIR node of an associated item of `trait_def_id` or of its supertraits.
+fn find_local_assoc_item(
+    tcx: TyCtxt<'_>,
+    trait_def_id: DefId,
+    name: Symbol,
+    kind: ty::AssocKind,
+) -> Option<hir::Node<'_>> {
+    traits::supertrait_def_ids(tcx, trait_def_id)
+        .filter_map(|def_id| {
+            tcx.associated_items(def_id)
+                .filter_by_name_unhygienic(name)
+                .find(|item| item.kind == kind)
+        })
+        .find_map(|item| tcx.hir().get_if_local(item.def_id))
+}
+
+/// If all generic parameters of a method are `impl Trait` arguments with object safe
+/// bounds, suggests to replace them with trait objects, e.g. `x: impl Debug` with
+/// `x: &dyn Debug`.
+fn trait_object_args_suggestion(
+    tcx: TyCtxt<'_>,
+    generics: &hir::Generics<'_>,
+) -> Option<Vec<(Span, String)>> {
+    let sm = tcx.sess.source_map();
+    let mut suggs = vec![];
+    for param in generics.params {
+        match param.kind {
+            hir::GenericParamKind::Lifetime { .. } => {}
+            hir::GenericParamKind::Type { synthetic: true, .. } => {
+                // Trait objects can only be used if all bounds are object safe.
+                let object_safe_bounds = generics
+                    .bounds_for_param(param.def_id)
+                    .flat_map(|pred| pred.bounds)
+                    .filter_map(|bound| bound.trait_ref()?.trait_def_id())
+                    .all(|def_id| tcx.check_is_object_safe(def_id));
+                if !object_safe_bounds {
+                    return None;
+                }
+                let snippet = sm.span_to_snippet(param.span).ok()?;
+                let bounds = snippet.strip_prefix("impl ")?;
+                let sugg = if bounds.contains('+') {
+                    format!("&(dyn {bounds})")
+                } else {
+                    format!("&dyn {bounds}")
+                };
+                suggs.push((param.span, sugg));
+            }
+            hir::GenericParamKind::Type { .. } | hir::GenericParamKind::Const { .. } => {
+                return None;
+            }
+        }
+    }
+    (!suggs.is_empty()).then_some(suggs)
+}
+
 fn check_is_object_safe(tcx: TyCtxt<'_>, trait_def_id: DefId) -> bool {
     let violations = tcx.object_safety_violations(trait_def_id);
 
@@ -192,7 +324,11 @@ fn lint_object_unsafe_trait(
             );
             if node.is_some() {
                 // Only provide the help if its a local trait, otherwise it's not
-                violation.solution().add_to(err);
+                let explanation =
+                    explain_object_safety_violation(tcx, trait_def_id, violation.clone());
+                for solution in explanation.solutions {
+                    solution.add_to(err);
+                }
             }
         },
     );
@@ -242,6 +378,9 @@ fn sized_trait_bound_spans<'tcx>(
                     .flatten()
                     // Fetch spans for supertraits that are `Sized`: `trait T: Super`.
                     .chain(sized_trait_bound_spans(tcx, bounds))
+                    // Fetch spans for the bounds of those supertraits through which they are
+                    // `Sized`: `trait Super: Sized`.
+                    .chain(sized_supertrait_chain_spans(tcx, trait_def_id))
                     .collect::<SmallVec<[Span; 1]>>(),
             ),
             _ => None,
@@ -249,6 +388,32 @@ fn sized_trait_bound_spans<'tcx>(
         .unwrap_or_else(SmallVec::new)
 }
 
+/// Returns the spans of the bounds in the supertraits of `trait_def_id` through which one of its
+/// own bounds implies `Self: Sized`, if they are local.
+fn sized_supertrait_chain_spans(tcx: TyCtxt<'_>, trait_def_id: DefId) -> Vec<Span> {
+    let Some(sized_def_id) = tcx.lang_items().sized_trait() else {
+        return vec![];
+    };
+    let bounds = tcx.super_predicates_of(trait_def_id).predicates.iter().copied();
+    elaborate_with_provenance(tcx, bounds)
+        .filter_only_self()
+        .find(|info| match info.clause().kind().skip_binder() {
+            ty::ClauseKind::Trait(trait_pred) => {
+                trait_pred.def_id() == sized_def_id && trait_pred.self_ty().is_param(0)
+            }
+            _ => false,
+        })
+        .map(|info| {
+            // The first bound is one of the trait's own, which the caller already points at.
+            info.path[1..]
+                .iter()
+                .map(|&(_, span)| span)
+                .filter(|&span| !tcx.sess.source_map().is_imported(span))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 fn predicates_reference_self(
     tcx: TyCtxt<'_>,
     trait_def_id: DefId,
@@ -926,6 +1091,7 @@ pub fn contains_illegal_impl_trait_in_trait<'tcx>(
 pub fn provide(providers: &mut Providers) {
     *providers = Providers {
         object_safety_violations,
+        object_safety_explanations,
         check_is_object_safe,
         generics_require_sized_self,
         ..*providers
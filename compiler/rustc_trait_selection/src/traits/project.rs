@@ -2,7 +2,7 @@
 
 use std::ops::ControlFlow;
 
-use super::check_args_compatible;
+use super::check_args_compatible_explained;
 use super::specialization_graph;
 use super::translate_args;
 use super::util;
@@ -1588,8 +1588,14 @@ fn confirm_closure_candidate<'cx, 'tcx>(
 ) -> Progress<'tcx> {
     let tcx = selcx.tcx();
     let self_ty = selcx.infcx.shallow_resolve(obligation.predicate.self_ty());
+    let mut fn_host_effect = tcx.consts.true_;
     let closure_sig = match *self_ty.kind() {
-        ty::Closure(_, args) => args.as_closure().sig(),
+        ty::Closure(def_id, args) => {
+            let trait_ref = obligation.predicate.trait_ref(tcx);
+            fn_host_effect =
+                util::closure_host_effect(tcx, def_id, args, trait_ref.def_id, trait_ref.args);
+            args.as_closure().sig()
+        }
 
         // Construct a "normal" `FnOnce` signature for coroutine-closure. This is
         // basically duplicated with the `AsyncFnOnce::CallOnce` confirmation, but
@@ -1670,8 +1676,7 @@ fn confirm_closure_candidate<'cx, 'tcx>(
         obligation,
         closure_sig,
         util::TupleArgumentsFlag::No,
-        // FIXME(effects): This doesn't handle const closures correctly!
-        selcx.tcx().consts.true_,
+        fn_host_effect,
     )
     .with_addl_obligations(nested)
     .with_addl_obligations(obligations)
@@ -1802,76 +1807,37 @@ fn confirm_async_closure_candidate<'cx, 'tcx>(
             args.coroutine_closure_sig()
                 .rebind(ty::ProjectionPredicate { projection_ty, term: term.into() })
         }
-        ty::FnDef(..) | ty::FnPtr(..) => {
-            let bound_sig = self_ty.fn_sig(tcx);
-            let sig = bound_sig.skip_binder();
-
-            let term = match item_name {
-                sym::CallOnceFuture | sym::CallMutFuture | sym::CallFuture => sig.output(),
-                sym::Output => {
-                    let future_trait_def_id = tcx.require_lang_item(LangItem::Future, None);
-                    let future_output_def_id = tcx
-                        .associated_items(future_trait_def_id)
-                        .filter_by_name_unhygienic(sym::Output)
-                        .next()
-                        .unwrap()
-                        .def_id;
-                    Ty::new_projection(tcx, future_output_def_id, [sig.output()])
-                }
-                name => bug!("no such associated type: {name}"),
-            };
-            let projection_ty = match item_name {
-                sym::CallOnceFuture | sym::Output => ty::AliasTy::new(
-                    tcx,
-                    obligation.predicate.def_id,
-                    [self_ty, Ty::new_tup(tcx, sig.inputs())],
-                ),
-                sym::CallMutFuture | sym::CallFuture => ty::AliasTy::new(
-                    tcx,
-                    obligation.predicate.def_id,
-                    [
-                        ty::GenericArg::from(self_ty),
-                        Ty::new_tup(tcx, sig.inputs()).into(),
-                        env_region.into(),
-                    ],
-                ),
-                name => bug!("no such associated type: {name}"),
+        ty::FnDef(..) | ty::FnPtr(..) | ty::Closure(..) => {
+            let (sig, tuple_arguments) = match *self_ty.kind() {
+                ty::Closure(_, args) => (args.as_closure().sig(), util::TupleArgumentsFlag::No),
+                _ => (self_ty.fn_sig(tcx), util::TupleArgumentsFlag::Yes),
             };
-
-            bound_sig.rebind(ty::ProjectionPredicate { projection_ty, term: term.into() })
-        }
-        ty::Closure(_, args) => {
-            let args = args.as_closure();
-            let bound_sig = args.sig();
-            let sig = bound_sig.skip_binder();
-
-            let term = match item_name {
-                sym::CallOnceFuture | sym::CallMutFuture | sym::CallFuture => sig.output(),
-                sym::Output => {
-                    let future_trait_def_id = tcx.require_lang_item(LangItem::Future, None);
-                    let future_output_def_id = tcx
-                        .associated_items(future_trait_def_id)
-                        .filter_by_name_unhygienic(sym::Output)
-                        .next()
-                        .unwrap()
-                        .def_id;
-                    Ty::new_projection(tcx, future_output_def_id, [sig.output()])
-                }
-                name => bug!("no such associated type: {name}"),
-            };
-            let projection_ty = match item_name {
-                sym::CallOnceFuture | sym::Output => {
-                    ty::AliasTy::new(tcx, obligation.predicate.def_id, [self_ty, sig.inputs()[0]])
-                }
-                sym::CallMutFuture | sym::CallFuture => ty::AliasTy::new(
-                    tcx,
-                    obligation.predicate.def_id,
-                    [ty::GenericArg::from(self_ty), sig.inputs()[0].into(), env_region.into()],
-                ),
-                name => bug!("no such associated type: {name}"),
-            };
-
-            bound_sig.rebind(ty::ProjectionPredicate { projection_ty, term: term.into() })
+            util::async_closure_trait_ref_and_return_type(
+                tcx,
+                obligation.predicate.trait_def_id(tcx),
+                self_ty,
+                sig,
+                tuple_arguments,
+            )
+            .map_bound(|(trait_ref, future_ty, future_output_ty)| {
+                let term = match item_name {
+                    sym::CallOnceFuture | sym::CallMutFuture | sym::CallFuture => future_ty,
+                    sym::Output => future_output_ty,
+                    name => bug!("no such associated type: {name}"),
+                };
+                let projection_ty = match item_name {
+                    sym::CallOnceFuture | sym::Output => {
+                        ty::AliasTy::new(tcx, obligation.predicate.def_id, trait_ref.args)
+                    }
+                    sym::CallMutFuture | sym::CallFuture => ty::AliasTy::new(
+                        tcx,
+                        obligation.predicate.def_id,
+                        trait_ref.args.iter().chain([env_region.into()]),
+                    ),
+                    name => bug!("no such associated type: {name}"),
+                };
+                ty::ProjectionPredicate { projection_ty, term: term.into() }
+            })
         }
         _ => bug!("expected callable type for AsyncFn candidate"),
     };
@@ -2034,11 +2000,14 @@ fn confirm_impl_candidate<'cx, 'tcx>(
     } else {
         ty.map_bound(|ty| ty.into())
     };
-    if !check_args_compatible(tcx, assoc_ty.item, args) {
+    if let Err(incompatibility) = check_args_compatible_explained(tcx, assoc_ty.item, args) {
         let err = Ty::new_error_with_message(
             tcx,
             obligation.cause.span,
-            "impl item and trait item have different parameters",
+            format!(
+                "impl item and trait item have different parameters: {}",
+                incompatibility.description(tcx)
+            ),
         );
         Progress { term: err.into(), obligations: nested }
     } else {
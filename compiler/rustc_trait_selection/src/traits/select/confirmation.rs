@@ -873,8 +873,15 @@ fn confirm_closure_candidate(
         // type/region parameters.
         let self_ty = self.infcx.shallow_resolve(obligation.self_ty().skip_binder());
         let trait_ref = match *self_ty.kind() {
-            ty::Closure(_, args) => {
-                self.closure_trait_ref_unnormalized(obligation, args, self.tcx().consts.true_)
+            ty::Closure(def_id, args) => {
+                let fn_host_effect = util::closure_host_effect(
+                    self.tcx(),
+                    def_id,
+                    args,
+                    obligation.predicate.def_id(),
+                    obligation.predicate.skip_binder().trait_ref.args,
+                );
+                self.closure_trait_ref_unnormalized(obligation, args, fn_host_effect)
             }
             ty::CoroutineClosure(_, args) => {
                 args.as_coroutine_closure().coroutine_closure_sig().map_bound(|sig| {
@@ -914,43 +921,30 @@ fn confirm_async_closure_candidate(
                 });
                 (trait_ref, args.kind_ty())
             }
-            ty::FnDef(..) | ty::FnPtr(..) => {
-                let sig = self_ty.fn_sig(tcx);
-                let trait_ref = sig.map_bound(|sig| {
-                    ty::TraitRef::new(
-                        self.tcx(),
-                        obligation.predicate.def_id(),
-                        [self_ty, Ty::new_tup(tcx, sig.inputs())],
-                    )
-                });
-                // We must additionally check that the return type impls `Future`.
-                let future_trait_def_id = tcx.require_lang_item(LangItem::Future, None);
-                nested.push(obligation.with(
+            ty::FnDef(..) | ty::FnPtr(..) | ty::Closure(..) => {
+                let (sig, tuple_arguments) = match *self_ty.kind() {
+                    ty::Closure(_, args) => (args.as_closure().sig(), util::TupleArgumentsFlag::No),
+                    _ => (self_ty.fn_sig(tcx), util::TupleArgumentsFlag::Yes),
+                };
+                let trait_ref_and_future = util::async_closure_trait_ref_and_return_type(
                     tcx,
-                    sig.map_bound(|sig| {
-                        ty::TraitRef::new(tcx, future_trait_def_id, [sig.output()])
-                    }),
-                ));
-                (trait_ref, Ty::from_closure_kind(tcx, ty::ClosureKind::Fn))
-            }
-            ty::Closure(_, args) => {
-                let sig = args.as_closure().sig();
-                let trait_ref = sig.map_bound(|sig| {
-                    ty::TraitRef::new(
-                        self.tcx(),
-                        obligation.predicate.def_id(),
-                        [self_ty, sig.inputs()[0]],
-                    )
-                });
+                    obligation.predicate.def_id(),
+                    self_ty,
+                    sig,
+                    tuple_arguments,
+                );
                 // We must additionally check that the return type impls `Future`.
                 let future_trait_def_id = tcx.require_lang_item(LangItem::Future, None);
                 nested.push(obligation.with(
                     tcx,
-                    sig.map_bound(|sig| {
-                        ty::TraitRef::new(tcx, future_trait_def_id, [sig.output()])
+                    trait_ref_and_future.map_bound(|(_, future_ty, _)| {
+                        ty::TraitRef::new(tcx, future_trait_def_id, [future_ty])
                     }),
                 ));
-                (trait_ref, Ty::from_closure_kind(tcx, ty::ClosureKind::Fn))
+                (
+                    trait_ref_and_future.map_bound(|(trait_ref, ..)| trait_ref),
+                    Ty::from_closure_kind(tcx, ty::ClosureKind::Fn),
+                )
             }
             _ => bug!("expected callable type for AsyncFn candidate"),
         };
@@ -45,6 +45,8 @@
 use rustc_middle::ty::GenericArgsRef;
 use rustc_middle::ty::{self, EarlyBinder, PolyProjectionPredicate, ToPolyTraitRef, ToPredicate};
 use rustc_middle::ty::{Ty, TyCtxt, TypeFoldable, TypeVisitableExt};
+use rustc_session::code_stats::{SolverCacheKind, SolverObligationInfo};
+use rustc_session::config::CandidatePreference;
 use rustc_span::symbol::sym;
 use rustc_span::Symbol;
 
@@ -53,6 +55,7 @@
 use std::fmt::{self, Display};
 use std::iter;
 use std::ops::ControlFlow;
+use std::time::Instant;
 
 pub use rustc_middle::traits::select::*;
 use rustc_middle::ty::print::with_no_trimmed_paths;
@@ -287,6 +290,10 @@ pub fn is_intercrate(&self) -> bool {
         self.infcx.intercrate
     }
 
+    pub fn query_mode(&self) -> TraitQueryMode {
+        self.query_mode
+    }
+
     ///////////////////////////////////////////////////////////////////////////
     // Selection
     //
@@ -467,6 +474,7 @@ fn candidate_from_obligation_no_cache<'o>(
         // candidate which assumes $0 == int, one that assumes `$0 ==
         // usize`, etc. This spells an ambiguity.
 
+        let candidates = self.filter_impls_with_negative_impls(stack, candidates)?;
         let mut candidates = self.filter_impls(candidates, stack.obligation);
 
         // If there is more than one candidate, first winnow them down
@@ -1055,6 +1063,8 @@ fn evaluate_trait_predicate_recursively<'o>(
             return Ok(cycle_result);
         }
 
+        let start_time =
+            self.tcx().sess.opts.unstable_opts.selection_cache_stats.then(Instant::now);
         let (result, dep_node) = self.in_task(|this| {
             let mut result = this.evaluate_stack(&stack)?;
 
@@ -1100,6 +1110,15 @@ fn evaluate_trait_predicate_recursively<'o>(
             Ok::<_, OverflowError>(result)
         });
 
+        if let Some(start_time) = start_time {
+            self.tcx().sess.code_stats.record_solver_obligation(SolverObligationInfo {
+                next_solver: false,
+                obligation: with_no_trimmed_paths!(fresh_trait_pred.to_string()),
+                time: start_time.elapsed(),
+                depth: obligation.recursion_depth,
+            });
+        }
+
         let result = result?;
 
         if !result.must_apply_modulo_regions() {
@@ -1309,12 +1328,15 @@ fn check_evaluation_cache(
         }
 
         let tcx = self.tcx();
-        if self.can_use_global_caches(param_env) {
-            if let Some(res) = tcx.evaluation_cache.get(&(param_env, trait_pred), tcx) {
-                return Some(res);
-            }
-        }
-        self.infcx.evaluation_cache.get(&(param_env, trait_pred), tcx)
+        let result = if self.can_use_global_caches(param_env)
+            && let Some(res) = tcx.evaluation_cache.get(&(param_env, trait_pred), tcx)
+        {
+            Some(res)
+        } else {
+            self.infcx.evaluation_cache.get(&(param_env, trait_pred), tcx)
+        };
+        self.record_cache_lookup(SolverCacheKind::Evaluation, result.is_some());
+        result
     }
 
     fn insert_evaluation_cache(
@@ -1432,6 +1454,100 @@ fn filter_impls(
         result
     }
 
+    /// If the crate defining the trait enabled `#![feature(negative_bounds_coherence)]` and
+    /// `#![feature(negative_impls)]`, uses negative impls to rule out positive impl candidates.
+    /// If `impl !Trait for T` applies, then no positive impl of `Trait` can apply to `T` either,
+    /// and an impl with a where-clause whose negation holds can never apply.
+    ///
+    /// This depends on the trait rather than on the current crate, so that all crates agree
+    /// on which impls apply.
+    #[instrument(level = "debug", skip(self, stack))]
+    fn filter_impls_with_negative_impls<'o>(
+        &mut self,
+        stack: &TraitObligationStack<'o, 'tcx>,
+        candidates: Vec<SelectionCandidate<'tcx>>,
+    ) -> Result<Vec<SelectionCandidate<'tcx>>, SelectionError<'tcx>> {
+        let tcx = self.tcx();
+        let obligation = stack.obligation;
+        if !tcx.trait_def(obligation.predicate.def_id()).negative_bounds_coherence
+            || self.is_intercrate()
+            || obligation.polarity() != ty::ImplPolarity::Positive
+        {
+            return Ok(candidates);
+        }
+
+        let is_positive_impl = |candidate: &SelectionCandidate<'tcx>| {
+            matches!(*candidate, ImplCandidate(def_id)
+                if tcx.impl_polarity(def_id) == ty::ImplPolarity::Positive)
+        };
+        if !candidates.iter().any(is_positive_impl) {
+            return Ok(candidates);
+        }
+
+        let negated = obligation.predicate.map_bound(|predicate| ty::TraitPredicate {
+            polarity: ty::ImplPolarity::Negative,
+            ..predicate
+        });
+        if self
+            .evaluate_nested_predicate(stack, negated.to_predicate(tcx))?
+            .must_apply_modulo_regions()
+        {
+            debug!("ruled out all positive impls using a negative impl");
+            return Ok(candidates.into_iter().filter(|c| !is_positive_impl(c)).collect());
+        }
+
+        let mut result = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            if let ImplCandidate(impl_def_id) = candidate
+                && is_positive_impl(&candidate)
+                && self.impl_has_negated_where_clause(stack, impl_def_id)?
+            {
+                debug!(?impl_def_id, "ruled out impl using a negative impl");
+                continue;
+            }
+            result.push(candidate);
+        }
+        Ok(result)
+    }
+
+    /// Whether the negation of one of the where-clauses of `impl_def_id` holds,
+    /// after matching it against the obligation of `stack`.
+    fn impl_has_negated_where_clause<'o>(
+        &mut self,
+        stack: &TraitObligationStack<'o, 'tcx>,
+        impl_def_id: DefId,
+    ) -> Result<bool, OverflowError> {
+        let tcx = self.tcx();
+        self.infcx.probe(|_| {
+            let impl_trait_header = tcx.impl_trait_header(impl_def_id).unwrap();
+            let Ok(args) = self.match_impl(impl_def_id, impl_trait_header, stack.obligation) else {
+                return Ok(false);
+            };
+            for (clause, _) in tcx.predicates_of(impl_def_id).instantiate(tcx, args.value) {
+                let Some(negated) = clause.as_predicate().flip_polarity(tcx) else { continue };
+                if self.evaluate_nested_predicate(stack, negated)?.must_apply_modulo_regions() {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        })
+    }
+
+    fn evaluate_nested_predicate<'o>(
+        &mut self,
+        stack: &TraitObligationStack<'o, 'tcx>,
+        predicate: ty::Predicate<'tcx>,
+    ) -> Result<EvaluationResult, OverflowError> {
+        let obligation = Obligation::with_depth(
+            self.tcx(),
+            stack.obligation.cause.clone(),
+            stack.obligation.recursion_depth + 1,
+            stack.obligation.param_env,
+            predicate,
+        );
+        self.evaluation_probe(|this| this.evaluate_predicate_recursively(stack.list(), obligation))
+    }
+
     /// filter_reservation_impls filter reservation impl for any goal as ambiguous
     #[instrument(level = "debug", skip(self))]
     fn filter_reservation_impls(
@@ -1519,12 +1635,22 @@ fn check_candidate_cache(
         let tcx = self.tcx();
         let pred = cache_fresh_trait_pred.skip_binder();
 
-        if self.can_use_global_caches(param_env) {
-            if let Some(res) = tcx.selection_cache.get(&(param_env, pred), tcx) {
-                return Some(res);
-            }
+        let result = if self.can_use_global_caches(param_env)
+            && let Some(res) = tcx.selection_cache.get(&(param_env, pred), tcx)
+        {
+            Some(res)
+        } else {
+            self.infcx.selection_cache.get(&(param_env, pred), tcx)
+        };
+        self.record_cache_lookup(SolverCacheKind::Selection, result.is_some());
+        result
+    }
+
+    fn record_cache_lookup(&self, cache: SolverCacheKind, hit: bool) {
+        let sess = self.tcx().sess;
+        if sess.opts.unstable_opts.selection_cache_stats {
+            sess.code_stats.record_solver_cache_lookup(cache, hit);
         }
-        self.infcx.selection_cache.get(&(param_env, pred), tcx)
     }
 
     /// Determines whether can we safely cache the result
@@ -1783,6 +1909,35 @@ fn drop_if(should_drop: bool) -> DropVictim {
 /// type variables and then we also attempt to evaluate recursive
 /// bounds to see if they are satisfied.
 impl<'tcx> SelectionContext<'_, 'tcx> {
+    /// Applies the alternative preference rules selected with `-Zcandidate-preference`,
+    /// returning `None` if the default rules should be used for this pair of candidates.
+    fn candidate_preference_override(
+        &self,
+        victim: &EvaluatedCandidate<'tcx>,
+        other: &EvaluatedCandidate<'tcx>,
+    ) -> Option<DropVictim> {
+        match self.tcx().sess.opts.unstable_opts.candidate_preference {
+            CandidatePreference::Default => None,
+            CandidatePreference::WhereClause => match (&other.candidate, &victim.candidate) {
+                (ParamCandidate(_), ParamCandidate(_)) => None,
+                (ParamCandidate(_), _) => Some(DropVictim::Yes),
+                (_, ParamCandidate(_)) => Some(DropVictim::No),
+                _ => None,
+            },
+            CandidatePreference::NoBuiltinOverParam => {
+                match (&other.candidate, &victim.candidate) {
+                    (ParamCandidate(_), BuiltinCandidate { has_nested: false }) => {
+                        Some(DropVictim::Yes)
+                    }
+                    (BuiltinCandidate { has_nested: false }, ParamCandidate(_)) => {
+                        Some(DropVictim::No)
+                    }
+                    _ => None,
+                }
+            }
+        }
+    }
+
     /// Returns `DropVictim::Yes` if `victim` should be dropped in favor of
     /// `other`. Generally speaking we will drop duplicate
     /// candidates and prefer where-clause candidates.
@@ -1799,6 +1954,10 @@ fn candidate_should_be_dropped_in_favor_of(
             return DropVictim::Yes;
         }
 
+        if let Some(drop_victim) = self.candidate_preference_override(victim, other) {
+            return drop_victim;
+        }
+
         // Check if a bound would previously have been removed when normalizing
         // the param_env so that it can be given the lowest priority. See
         // #50825 for the motivation for this.
@@ -2735,11 +2894,23 @@ fn impl_or_trait_obligations(
         // obligation will normalize to `<$0 as Iterator>::Item = $1` and
         // `$1: Copy`, so we must ensure the obligations are emitted in
         // that order.
-        let predicates = tcx.predicates_of(def_id);
-        assert_eq!(predicates.parent, None);
-        let predicates = predicates.instantiate_own(tcx, args);
+        let generic_predicates = tcx.predicates_of(def_id);
+        assert_eq!(generic_predicates.parent, None);
+        let predicates = generic_predicates.instantiate_own(tcx, args);
+        // The bounds of a derived impl all point at the `derive`, so point at the
+        // field each of them was generated for instead.
+        let is_derived = def_id.is_local() && tcx.is_builtin_derived(def_id);
         let mut obligations = Vec::with_capacity(predicates.len());
-        for (index, (predicate, span)) in predicates.into_iter().enumerate() {
+        for (index, (predicate, mut span)) in predicates.into_iter().enumerate() {
+            if is_derived
+                && let Some((field_span, _)) = util::derived_impl_clause_field(
+                    tcx,
+                    def_id,
+                    generic_predicates.predicates[index].0,
+                )
+            {
+                span = field_span;
+            }
             let cause =
                 if Some(parent_trait_pred.def_id()) == tcx.lang_items().coerce_unsized_trait() {
                     cause.clone()
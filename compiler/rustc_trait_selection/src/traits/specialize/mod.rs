@@ -20,10 +20,10 @@
     self, coherence, FutureCompatOverlapErrorKind, ObligationCause, ObligationCtxt,
 };
 use rustc_data_structures::fx::FxIndexSet;
-use rustc_errors::{codes::*, DelayDm, Diagnostic};
+use rustc_errors::{codes::*, Applicability, DelayDm, Diagnostic};
 use rustc_hir::def_id::{DefId, LocalDefId};
 use rustc_middle::ty::{self, ImplSubject, Ty, TyCtxt, TypeVisitableExt};
-use rustc_middle::ty::{GenericArgs, GenericArgsRef};
+use rustc_middle::ty::{GenericArgKind, GenericArgs, GenericArgsRef};
 use rustc_session::lint::builtin::COHERENCE_LEAK_CHECK;
 use rustc_session::lint::builtin::ORDER_DEPENDENT_TRAIT_OBJECTS;
 use rustc_span::{sym, ErrorGuaranteed, Span, DUMMY_SP};
@@ -35,6 +35,10 @@
 #[derive(Debug)]
 pub struct OverlapError<'tcx> {
     pub with_impl: DefId,
+    /// The generic arguments of `with_impl` and of the impl it overlaps with
+    /// for which both impls apply.
+    pub with_impl_args: GenericArgsRef<'tcx>,
+    pub impl_args: GenericArgsRef<'tcx>,
     pub trait_ref: ty::TraitRef<'tcx>,
     pub self_ty: Option<Ty<'tcx>>,
     pub intercrate_ambiguity_causes: FxIndexSet<IntercrateAmbiguityCause<'tcx>>,
@@ -238,10 +242,10 @@ fn fulfill_implication<'tcx>(
 
     let source_trait = ImplSubject::Trait(source_trait_ref);
 
-    let selcx = &mut SelectionContext::new(infcx);
+    let selcx = SelectionContext::new(infcx);
     let target_args = infcx.fresh_args_for_item(DUMMY_SP, target_impl);
     let (target_trait, obligations) =
-        util::impl_subject_and_oblig(selcx, param_env, target_impl, target_args, error_cause);
+        util::impl_subject_and_oblig(&selcx, param_env, target_impl, target_args, error_cause);
 
     // do the impls unify? If not, no specialization.
     let Ok(InferOk { obligations: more_obligations, .. }) = infcx
@@ -398,6 +402,7 @@ fn report_conflicting_impls<'tcx>(
     fn decorate<'tcx>(
         tcx: TyCtxt<'tcx>,
         overlap: &OverlapError<'tcx>,
+        impl_def_id: LocalDefId,
         impl_span: Span,
         err: &mut Diagnostic,
     ) {
@@ -428,6 +433,10 @@ fn decorate<'tcx>(
             }
         }
 
+        label_overlap_witness(tcx, overlap.with_impl, overlap.with_impl_args, err);
+        label_overlap_witness(tcx, impl_def_id.to_def_id(), overlap.impl_args, err);
+        suggest_negative_impls_for_overlap(tcx, overlap, impl_def_id, err);
+
         for cause in &overlap.intercrate_ambiguity_causes {
             cause.add_intercrate_ambiguity_hint(err);
         }
@@ -456,7 +465,7 @@ fn decorate<'tcx>(
             {
                 let mut err = tcx.dcx().struct_span_err(impl_span, msg);
                 err.code(E0119);
-                decorate(tcx, &overlap, impl_span, &mut err);
+                decorate(tcx, &overlap, impl_def_id, impl_span, &mut err);
                 err.emit()
             } else {
                 tcx.dcx().span_delayed_bug(impl_span, "impl should have failed the orphan check")
@@ -474,7 +483,7 @@ fn decorate<'tcx>(
                 impl_span,
                 msg,
                 |err| {
-                    decorate(tcx, &overlap, impl_span, err);
+                    decorate(tcx, &overlap, impl_def_id, impl_span, err);
                 },
             );
             Ok(())
@@ -482,6 +491,112 @@ fn decorate<'tcx>(
     }
 }
 
+/// Points out how the generic parameters of a local impl have to be instantiated
+/// for it to overlap with another impl. Parameters which are left unconstrained
+/// by the other impl are not mentioned.
+fn label_overlap_witness<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    impl_def_id: DefId,
+    impl_args: GenericArgsRef<'tcx>,
+    err: &mut Diagnostic,
+) {
+    if !impl_def_id.is_local() {
+        return;
+    }
+
+    for param in &tcx.generics_of(impl_def_id).params {
+        let arg = impl_args[param.index as usize];
+        let unconstrained = match arg.unpack() {
+            GenericArgKind::Lifetime(_) => true,
+            GenericArgKind::Type(ty) => ty.is_ty_or_numeric_infer(),
+            GenericArgKind::Const(ct) => matches!(ct.kind(), ty::ConstKind::Infer(_)),
+        };
+        if !unconstrained {
+            err.span_label(
+                tcx.def_span(param.def_id),
+                format!("`{}` unifies with `{arg}`", param.name),
+            );
+        }
+    }
+}
+
+/// With `#![feature(with_negative_coherence)]` or `#![feature(negative_bounds_coherence)]`,
+/// coherence may rely on negative impls to show that two impls are disjoint. If one of the
+/// overlapping impls has a where-clause which would hold for a local type, suggest adding a
+/// negative impl for that type.
+fn suggest_negative_impls_for_overlap<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    overlap: &OverlapError<'tcx>,
+    impl_def_id: LocalDefId,
+    err: &mut Diagnostic,
+) {
+    let features = tcx.features();
+    if !(features.with_negative_coherence || features.negative_bounds_coherence)
+        || !features.negative_impls
+    {
+        return;
+    }
+
+    let mut suggestions = FxIndexSet::default();
+    for (def_id, args) in
+        [(overlap.with_impl, overlap.with_impl_args), (impl_def_id.to_def_id(), overlap.impl_args)]
+    {
+        for (clause, _) in tcx.predicates_of(def_id).instantiate(tcx, args) {
+            let Some(trait_pred) = clause.as_trait_clause() else { continue };
+            let trait_pred = trait_pred.skip_binder();
+            if trait_pred.polarity != ty::ImplPolarity::Positive
+                || tcx.lang_items().sized_trait() == Some(trait_pred.def_id())
+                || trait_pred.trait_ref.has_infer()
+                || trait_pred.trait_ref.has_escaping_bound_vars()
+            {
+                continue;
+            }
+            if let ty::Adt(def, _) = trait_pred.self_ty().kind()
+                && def.did().is_local()
+                && !has_positive_impl_for_adt(tcx, trait_pred.def_id(), trait_pred.self_ty(), *def)
+            {
+                suggestions.insert(format!(
+                    "impl !{} for {} {{}}",
+                    trait_pred.trait_ref.print_only_trait_path(),
+                    trait_pred.self_ty(),
+                ));
+            }
+        }
+    }
+
+    if !suggestions.is_empty() {
+        err.span_suggestion_verbose(
+            tcx.source_span(impl_def_id).shrink_to_hi(),
+            "consider opting out of the where-clauses which cause the overlap",
+            suggestions
+                .into_iter()
+                .map(|suggestion| format!("\n\n{suggestion}"))
+                .collect::<String>(),
+            Applicability::MaybeIncorrect,
+        );
+    }
+}
+
+/// Whether `trait_def_id` already has a positive impl for the ADT `def`, in which case
+/// suggesting a negative impl for it would only lead to E0751.
+fn has_positive_impl_for_adt<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    trait_def_id: DefId,
+    self_ty: Ty<'tcx>,
+    def: ty::AdtDef<'tcx>,
+) -> bool {
+    let mut found = false;
+    tcx.for_each_relevant_impl(trait_def_id, self_ty, |impl_def_id| {
+        if tcx.impl_polarity(impl_def_id) == ty::ImplPolarity::Positive
+            && let ty::Adt(impl_def, _) = tcx.type_of(impl_def_id).instantiate_identity().kind()
+            && *impl_def == def
+        {
+            found = true;
+        }
+    });
+    found
+}
+
 /// Recovers the "impl X for Y" signature from `impl_def_id` and returns it as a
 /// string.
 pub(crate) fn to_pretty_impl_header(tcx: TyCtxt<'_>, impl_def_id: DefId) -> Option<String> {
@@ -96,6 +96,8 @@ fn insert(
 
                 OverlapError {
                     with_impl: possible_sibling,
+                    with_impl_args: overlap.impl_header.impl_args,
+                    impl_args: overlap.other_impl_args,
                     trait_ref,
                     // Only report the `Self` type if it has at least
                     // some outer concrete shell; otherwise, it's
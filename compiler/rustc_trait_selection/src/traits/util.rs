@@ -1,15 +1,19 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 
 use super::NormalizeExt;
-use super::{ObligationCause, PredicateObligation, SelectionContext};
+use super::{Obligation, ObligationCause, PredicateObligation, SelectionContext};
+use crate::errors::TraitAliasRecursionLimitReached;
 use rustc_data_structures::fx::FxHashSet;
-use rustc_errors::Diagnostic;
-use rustc_hir::def_id::DefId;
+use rustc_errors::{Diagnostic, ErrorGuaranteed};
+use rustc_hir as hir;
+use rustc_hir::def_id::{DefId, LOCAL_CRATE};
+use rustc_hir::LangItem;
 use rustc_infer::infer::{InferCtxt, InferOk};
 use rustc_middle::ty::GenericArgsRef;
 use rustc_middle::ty::{self, ImplSubject, ToPredicate, Ty, TyCtxt, TypeVisitableExt};
 use rustc_middle::ty::{TypeFoldable, TypeFolder, TypeSuperFoldable};
-use rustc_span::Span;
+use rustc_session::Limit;
+use rustc_span::{sym, Span};
 use smallvec::SmallVec;
 
 pub use rustc_infer::traits::util::*;
@@ -25,21 +29,27 @@
 /// `trait Bar = Read + Write`, then the bounds would expand to
 /// `Read + Write + Sync + Send`.
 /// Expansion is done via a DFS (depth-first search), and the `visited` field
-/// is used to avoid cycles.
+/// is used to avoid cycles. Chains of trait aliases that are deeper than the
+/// recursion limit of the crate are reported as an error and not expanded further.
 pub struct TraitAliasExpander<'tcx> {
     tcx: TyCtxt<'tcx>,
     stack: Vec<TraitAliasExpansionInfo<'tcx>>,
+    recursion_limit: Limit,
+    tainted_by_errors: Option<ErrorGuaranteed>,
 }
 
 /// Stores information about the expansion of a trait via a path of zero or more trait aliases.
 #[derive(Debug, Clone)]
 pub struct TraitAliasExpansionInfo<'tcx> {
     pub path: SmallVec<[(ty::PolyTraitRef<'tcx>, Span); 4]>,
+    /// The `?Trait` bounds of all trait aliases in `path`, e.g. `Sized` when
+    /// expanding `trait Alias = Foo + ?Sized;`.
+    pub relaxed_bounds: SmallVec<[(DefId, Span); 1]>,
 }
 
 impl<'tcx> TraitAliasExpansionInfo<'tcx> {
     fn new(trait_ref: ty::PolyTraitRef<'tcx>, span: Span) -> Self {
-        Self { path: smallvec![(trait_ref, span)] }
+        Self { path: smallvec![(trait_ref, span)], relaxed_bounds: SmallVec::new() }
     }
 
     /// Adds diagnostic labels to `diag` for the expansion path of a trait through all intermediate
@@ -78,11 +88,24 @@ pub fn bottom(&self) -> &(ty::PolyTraitRef<'tcx>, Span) {
         self.path.first().unwrap()
     }
 
-    fn clone_and_push(&self, trait_ref: ty::PolyTraitRef<'tcx>, span: Span) -> Self {
+    /// Returns whether any trait alias in the expansion path relaxes the given trait,
+    /// e.g. `Sized` for `trait Alias = Foo + ?Sized;`.
+    pub fn relaxes(&self, def_id: DefId) -> bool {
+        self.relaxed_bounds.iter().any(|&(relaxed, _)| relaxed == def_id)
+    }
+
+    fn clone_and_push(
+        &self,
+        trait_ref: ty::PolyTraitRef<'tcx>,
+        span: Span,
+        relaxed_bounds: &[(DefId, Span)],
+    ) -> Self {
         let mut path = self.path.clone();
         path.push((trait_ref, span));
+        let mut relaxed_bounds_of_path = self.relaxed_bounds.clone();
+        relaxed_bounds_of_path.extend_from_slice(relaxed_bounds);
 
-        Self { path }
+        Self { path, relaxed_bounds: relaxed_bounds_of_path }
     }
 }
 
@@ -92,10 +115,27 @@ pub fn expand_trait_aliases<'tcx>(
 ) -> TraitAliasExpander<'tcx> {
     let items: Vec<_> =
         trait_refs.map(|(trait_ref, span)| TraitAliasExpansionInfo::new(trait_ref, span)).collect();
-    TraitAliasExpander { tcx, stack: items }
+    TraitAliasExpander {
+        tcx,
+        stack: items,
+        recursion_limit: tcx.recursion_limit(),
+        tainted_by_errors: None,
+    }
 }
 
 impl<'tcx> TraitAliasExpander<'tcx> {
+    /// Overrides the crate's recursion limit as the maximum depth of trait aliases to expand.
+    pub fn with_recursion_limit(mut self, recursion_limit: Limit) -> Self {
+        self.recursion_limit = recursion_limit;
+        self
+    }
+
+    /// Returns `Some` if the expansion was aborted because of an error, in which case
+    /// the yielded traits are incomplete.
+    pub fn tainted_by_errors(&self) -> Option<ErrorGuaranteed> {
+        self.tainted_by_errors
+    }
+
     /// If `item` is a trait alias and its predicate has not yet been visited, then expands `item`
     /// to the definition, pushes the resulting expansion onto `self.stack`, and returns `false`.
     /// Otherwise, immediately returns `true` if `item` is a regular trait, or `false` if it is a
@@ -126,14 +166,31 @@ fn expand(&mut self, item: &TraitAliasExpansionInfo<'tcx>) -> bool {
             return false;
         }
 
-        // Get components of trait alias.
+        // Deep chains of trait aliases are not cyclic, but expanding them is still unbounded.
+        if !self.recursion_limit.value_within_limit(item.path.len()) {
+            let mut diag = tcx.dcx().create_err(TraitAliasRecursionLimitReached {
+                span: item.top().1,
+                alias: tcx.def_path_str(item.bottom().0.def_id()),
+                suggested_limit: self.recursion_limit * 2,
+                crate_name: tcx.crate_name(LOCAL_CRATE),
+            });
+            item.label_with_exp_info(&mut diag, "recursion limit reached here", "expanded");
+            self.tainted_by_errors = Some(diag.emit());
+            // Don't report the same chain again for the remaining bounds.
+            self.stack.clear();
+            return false;
+        }
+
+        // Get components of trait alias. Its relaxed bounds are not part of its
+        // predicates, so we carry them along with each component instead.
         let predicates = tcx.implied_predicates_of(trait_ref.def_id());
-        debug!(?predicates);
+        let relaxed_bounds = tcx.trait_alias_relaxed_bounds(trait_ref.def_id());
+        debug!(?predicates, ?relaxed_bounds);
 
         let items = predicates.predicates.iter().rev().filter_map(|(pred, span)| {
-            pred.instantiate_supertrait(tcx, &trait_ref)
-                .as_trait_clause()
-                .map(|trait_ref| item.clone_and_push(trait_ref.map_bound(|t| t.trait_ref), *span))
+            pred.instantiate_supertrait(tcx, &trait_ref).as_trait_clause().map(|trait_ref| {
+                item.clone_and_push(trait_ref.map_bound(|t| t.trait_ref), *span, relaxed_bounds)
+            })
         });
         debug!("expand_trait_aliases: items={:?}", items.clone().collect::<Vec<_>>());
 
@@ -197,6 +254,49 @@ fn next(&mut self) -> Option<DefId> {
     }
 }
 
+///////////////////////////////////////////////////////////////////////////
+// Breadth-first iterator over supertraits, tracking the path to each one
+///////////////////////////////////////////////////////////////////////////
+
+/// Like [`SupertraitDefIds`], but visits the supertraits in breadth-first order
+/// and yields, for each supertrait, its depth and the spans of the supertrait
+/// bounds which lead from the original trait to it.
+///
+/// As the traversal is breadth-first, the path to each supertrait is one of the
+/// shortest ones. The original trait itself is yielded first, with a depth of
+/// zero and an empty path.
+pub struct SupertraitDefIdsBfs<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    queue: VecDeque<(DefId, usize, Vec<Span>)>,
+    visited: FxHashSet<DefId>,
+}
+
+pub fn supertrait_def_ids_bfs(tcx: TyCtxt<'_>, trait_def_id: DefId) -> SupertraitDefIdsBfs<'_> {
+    SupertraitDefIdsBfs {
+        tcx,
+        queue: [(trait_def_id, 0, vec![])].into(),
+        visited: Some(trait_def_id).into_iter().collect(),
+    }
+}
+
+impl Iterator for SupertraitDefIdsBfs<'_> {
+    type Item = (DefId, usize, Vec<Span>);
+
+    fn next(&mut self) -> Option<(DefId, usize, Vec<Span>)> {
+        let (def_id, depth, path) = self.queue.pop_front()?;
+        let predicates = self.tcx.super_predicates_of(def_id);
+        for &(pred, span) in predicates.predicates {
+            let Some(trait_ref) = pred.as_trait_clause() else { continue };
+            if self.visited.insert(trait_ref.def_id()) {
+                let mut path = path.clone();
+                path.push(span);
+                self.queue.push_back((trait_ref.def_id(), depth + 1, path));
+            }
+        }
+        Some((def_id, depth, path))
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////
 // Other
 ///////////////////////////////////////////////////////////////////////////
@@ -204,29 +304,37 @@ fn next(&mut self) -> Option<DefId> {
 /// Instantiate all bound parameters of the impl subject with the given args,
 /// returning the resulting subject and all obligations that arise.
 /// The obligations are closed under normalization.
+///
+/// Only the subject is normalized eagerly. The where-clauses of the impl are
+/// normalized lazily while iterating over the returned obligations, which
+/// avoids normalizing them at all if the caller bails out early, e.g.
+/// because the subject does not unify.
 pub fn impl_subject_and_oblig<'a, 'tcx>(
-    selcx: &mut SelectionContext<'a, 'tcx>,
+    selcx: &SelectionContext<'a, 'tcx>,
     param_env: ty::ParamEnv<'tcx>,
     impl_def_id: DefId,
     impl_args: GenericArgsRef<'tcx>,
-    cause: impl Fn(usize, Span) -> ObligationCause<'tcx>,
-) -> (ImplSubject<'tcx>, impl Iterator<Item = PredicateObligation<'tcx>>) {
-    let subject = selcx.tcx().impl_subject(impl_def_id);
-    let subject = subject.instantiate(selcx.tcx(), impl_args);
-
-    let InferOk { value: subject, obligations: normalization_obligations1 } =
-        selcx.infcx.at(&ObligationCause::dummy(), param_env).normalize(subject);
-
-    let predicates = selcx.tcx().predicates_of(impl_def_id);
-    let predicates = predicates.instantiate(selcx.tcx(), impl_args);
-    let InferOk { value: predicates, obligations: normalization_obligations2 } =
-        selcx.infcx.at(&ObligationCause::dummy(), param_env).normalize(predicates);
-    let impl_obligations = super::predicates_for_generics(cause, param_env, predicates);
-
+    cause: impl Fn(usize, Span) -> ObligationCause<'tcx> + 'a,
+) -> (ImplSubject<'tcx>, impl Iterator<Item = PredicateObligation<'tcx>> + 'a) {
+    let tcx = selcx.tcx();
+    let infcx = selcx.infcx;
+    let subject = tcx.impl_subject(impl_def_id);
+    let subject = subject.instantiate(tcx, impl_args);
+
+    let InferOk { value: subject, obligations: normalization_obligations } =
+        infcx.at(&ObligationCause::dummy(), param_env).normalize(subject);
+
+    let predicates = tcx.predicates_of(impl_def_id);
+    let predicates = predicates.instantiate(tcx, impl_args);
     let impl_obligations =
-        impl_obligations.chain(normalization_obligations1).chain(normalization_obligations2);
+        predicates.into_iter().enumerate().flat_map(move |(idx, (clause, span))| {
+            let InferOk { value: clause, obligations } =
+                infcx.at(&ObligationCause::dummy(), param_env).normalize(clause);
+            let obligation = Obligation::new(tcx, cause(idx, span), param_env, clause);
+            obligations.into_iter().chain(std::iter::once(obligation))
+        });
 
-    (subject, impl_obligations)
+    (subject, normalization_obligations.into_iter().chain(impl_obligations))
 }
 
 /// Casts a trait reference into a reference to one of its super
@@ -244,6 +352,42 @@ pub fn upcast_choices<'tcx>(
     supertraits(tcx, source_trait_ref).filter(|r| r.def_id() == target_trait_def_id).collect()
 }
 
+/// Like [`upcast_choices`], but also returns the span of the supertrait bound
+/// through which each choice is reached, so that ambiguous upcasts can point
+/// at every candidate. Returns nothing when no upcast is needed.
+pub fn upcast_choices_with_spans<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    source_trait_ref: ty::PolyTraitRef<'tcx>,
+    target_trait_def_id: DefId,
+) -> Vec<(ty::PolyTraitRef<'tcx>, Span)> {
+    if source_trait_ref.def_id() == target_trait_def_id {
+        return vec![];
+    }
+
+    let mut choices = vec![];
+    let mut visited = PredicateSet::new(tcx);
+    visited.insert(source_trait_ref.to_predicate(tcx));
+    let mut queue = VecDeque::from([source_trait_ref]);
+    while let Some(trait_ref) = queue.pop_front() {
+        for &(clause, span) in tcx.super_predicates_of(trait_ref.def_id()).predicates {
+            let Some(super_trait_ref) =
+                clause.instantiate_supertrait(tcx, &trait_ref).as_trait_clause()
+            else {
+                continue;
+            };
+            let super_trait_ref = super_trait_ref.map_bound(|pred| pred.trait_ref);
+            if !visited.insert(super_trait_ref.to_predicate(tcx)) {
+                continue;
+            }
+            if super_trait_ref.def_id() == target_trait_def_id {
+                choices.push((super_trait_ref, span));
+            }
+            queue.push_back(super_trait_ref);
+        }
+    }
+    choices
+}
+
 /// Given an upcast trait object described by `object`, returns the
 /// index of the method `method_def_id` (which should be part of
 /// `object.upcast_trait_ref`) within the vtable for `object`.
@@ -290,6 +434,62 @@ pub fn closure_trait_ref_and_return_type<'tcx>(
     sig.map_bound(|sig| (trait_ref, sig.output()))
 }
 
+/// Like [`closure_trait_ref_and_return_type`], but for the `AsyncFn*` traits, which a
+/// callable only implements if it returns a future. Returns the trait ref, the future
+/// returned by the callable, and the output of that future.
+pub fn async_closure_trait_ref_and_return_type<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    fn_trait_def_id: DefId,
+    self_ty: Ty<'tcx>,
+    sig: ty::PolyFnSig<'tcx>,
+    tuple_arguments: TupleArgumentsFlag,
+) -> ty::Binder<'tcx, (ty::TraitRef<'tcx>, Ty<'tcx>, Ty<'tcx>)> {
+    assert!(!self_ty.has_escaping_bound_vars());
+    let future_trait_def_id = tcx.require_lang_item(LangItem::Future, None);
+    let future_output_def_id = tcx
+        .associated_items(future_trait_def_id)
+        .filter_by_name_unhygienic(sym::Output)
+        .next()
+        .unwrap()
+        .def_id;
+    sig.map_bound(|sig| {
+        let arguments_tuple = match tuple_arguments {
+            TupleArgumentsFlag::No => sig.inputs()[0],
+            TupleArgumentsFlag::Yes => Ty::new_tup(tcx, sig.inputs()),
+        };
+        let trait_ref = ty::TraitRef::new(tcx, fn_trait_def_id, [self_ty, arguments_tuple]);
+        let future_output_ty = Ty::new_projection(tcx, future_output_def_id, [sig.output()]);
+        (trait_ref, sig.output(), future_output_ty)
+    })
+}
+
+/// Returns the host effect with which the closure `closure_def_id` implements the `Fn*`
+/// trait `fn_trait_def_id`, given the arguments of that trait in the goal.
+///
+/// Non-const closures can only be called at runtime. Const closures can be called in
+/// the same contexts as the item they are defined in, which is any context if that
+/// item does not have a host effect param itself.
+pub fn closure_host_effect<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    closure_def_id: DefId,
+    closure_args: GenericArgsRef<'tcx>,
+    fn_trait_def_id: DefId,
+    trait_args: GenericArgsRef<'tcx>,
+) -> ty::Const<'tcx> {
+    if tcx.constness(closure_def_id) != hir::Constness::Const {
+        return tcx.consts.true_;
+    }
+
+    let parent_args = closure_args.as_closure().parent_args();
+    if let Some(idx) = tcx.generics_of(tcx.typeck_root_def_id(closure_def_id)).host_effect_index {
+        return parent_args[idx].expect_const();
+    }
+
+    tcx.generics_of(fn_trait_def_id)
+        .host_effect_index
+        .map_or(tcx.consts.true_, |idx| trait_args.const_at(idx))
+}
+
 pub fn coroutine_trait_ref_and_outputs<'tcx>(
     tcx: TyCtxt<'tcx>,
     fn_trait_def_id: DefId,
@@ -339,51 +539,141 @@ pub fn impl_item_is_final(tcx: TyCtxt<'_>, assoc_item: &ty::AssocItem) -> bool {
         && tcx.defaultness(assoc_item.container_id(tcx)).is_final()
 }
 
+/// Given a where-clause of an impl produced by a builtin `derive`, returns the span
+/// and type of the field of the deriving ADT that the where-clause was generated for.
+///
+/// All bounds of a derived impl point at the `derive` attribute, which is not helpful
+/// when one of them fails. This narrows the bounded type down to the first field whose
+/// type is exactly the bounded type, or failing that, the first field mentioning it.
+pub fn derived_impl_clause_field<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    impl_def_id: DefId,
+    clause: ty::Clause<'tcx>,
+) -> Option<(Span, Ty<'tcx>)> {
+    if !tcx.is_builtin_derived(impl_def_id) {
+        return None;
+    }
+    let bounded_ty = clause.as_trait_clause()?.self_ty().no_bound_vars()?;
+    let ty::Adt(adt_def, args) = *tcx.type_of(impl_def_id).instantiate_identity().kind() else {
+        return None;
+    };
+
+    let fields: Vec<_> =
+        adt_def.all_fields().map(|field| (tcx.def_span(field.did), field.ty(tcx, args))).collect();
+    fields
+        .iter()
+        .find(|&&(_, field_ty)| field_ty == bounded_ty)
+        .or_else(|| {
+            fields
+                .iter()
+                .find(|&&(_, field_ty)| field_ty.walk().any(|arg| arg == bounded_ty.into()))
+        })
+        .copied()
+}
+
 pub enum TupleArgumentsFlag {
     Yes,
     No,
 }
 
+/// Why the args of an associated item are incompatible with its generics,
+/// see [`check_args_compatible_explained`].
+#[derive(Debug, Clone)]
+pub enum ArgsIncompatibility<'tcx> {
+    /// The number of args does not match the number of generic parameters of `def_id`,
+    /// including the parameters of its parents.
+    Arity { def_id: DefId, expected: usize, found: usize },
+    /// The arg for the own generic parameter of `def_id` at `index` is of a different
+    /// kind than the parameter, e.g. a lifetime was provided for a type parameter.
+    Kind { def_id: DefId, index: u32, param: ty::GenericParamDefKind, arg: ty::GenericArg<'tcx> },
+    /// The args for the generics of the parent of an item are incompatible.
+    Parent(Box<ArgsIncompatibility<'tcx>>),
+}
+
+impl<'tcx> ArgsIncompatibility<'tcx> {
+    pub fn description(&self, tcx: TyCtxt<'tcx>) -> String {
+        match self {
+            ArgsIncompatibility::Arity { def_id, expected, found } => format!(
+                "`{}` expects {expected} generic args including those of its parents, found {found}",
+                tcx.def_path_str(*def_id),
+            ),
+            ArgsIncompatibility::Kind { def_id, index, param, arg } => format!(
+                "generic parameter #{index} of `{}` is a {} parameter, found {} arg `{arg}`",
+                tcx.def_path_str(*def_id),
+                param.descr(),
+                match arg.unpack() {
+                    ty::GenericArgKind::Type(_) => "type",
+                    ty::GenericArgKind::Lifetime(_) => "lifetime",
+                    ty::GenericArgKind::Const(_) => "constant",
+                },
+            ),
+            ArgsIncompatibility::Parent(inner) => {
+                format!("mismatched parent generics: {}", inner.description(tcx))
+            }
+        }
+    }
+}
+
 // Verify that the trait item and its implementation have compatible args lists
 pub fn check_args_compatible<'tcx>(
     tcx: TyCtxt<'tcx>,
     assoc_item: ty::AssocItem,
     args: ty::GenericArgsRef<'tcx>,
 ) -> bool {
+    check_args_compatible_explained(tcx, assoc_item, args).is_ok()
+}
+
+/// Like [`check_args_compatible`], but returns which generic parameter mismatched and how.
+pub fn check_args_compatible_explained<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    assoc_item: ty::AssocItem,
+    args: ty::GenericArgsRef<'tcx>,
+) -> Result<(), ArgsIncompatibility<'tcx>> {
     fn check_args_compatible_inner<'tcx>(
         tcx: TyCtxt<'tcx>,
+        def_id: DefId,
         generics: &'tcx ty::Generics,
         args: &'tcx [ty::GenericArg<'tcx>],
-    ) -> bool {
+    ) -> Result<(), ArgsIncompatibility<'tcx>> {
         if generics.count() != args.len() {
-            return false;
+            return Err(ArgsIncompatibility::Arity {
+                def_id,
+                expected: generics.count(),
+                found: args.len(),
+            });
         }
 
         let (parent_args, own_args) = args.split_at(generics.parent_count);
 
-        if let Some(parent) = generics.parent
-            && let parent_generics = tcx.generics_of(parent)
-            && !check_args_compatible_inner(tcx, parent_generics, parent_args)
-        {
-            return false;
+        if let Some(parent) = generics.parent {
+            let parent_generics = tcx.generics_of(parent);
+            check_args_compatible_inner(tcx, parent, parent_generics, parent_args)
+                .map_err(|err| ArgsIncompatibility::Parent(Box::new(err)))?;
         }
 
-        for (param, arg) in std::iter::zip(&generics.params, own_args) {
+        for (param, &arg) in std::iter::zip(&generics.params, own_args) {
             match (&param.kind, arg.unpack()) {
                 (ty::GenericParamDefKind::Type { .. }, ty::GenericArgKind::Type(_))
                 | (ty::GenericParamDefKind::Lifetime, ty::GenericArgKind::Lifetime(_))
                 | (ty::GenericParamDefKind::Const { .. }, ty::GenericArgKind::Const(_)) => {}
-                _ => return false,
+                _ => {
+                    return Err(ArgsIncompatibility::Kind {
+                        def_id,
+                        index: param.index,
+                        param: param.kind.clone(),
+                        arg,
+                    });
+                }
             }
         }
 
-        true
+        Ok(())
     }
 
     let generics = tcx.generics_of(assoc_item.def_id);
     // Chop off any additional args (RPITIT) args
     let args = &args[0..generics.count().min(args.len())];
-    check_args_compatible_inner(tcx, generics, args)
+    check_args_compatible_inner(tcx, assoc_item.def_id, generics, args)
 }
 
 /// Executes `f` on `value` after replacing all escaping bound variables with placeholders
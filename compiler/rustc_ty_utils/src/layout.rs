@@ -5,7 +5,7 @@
 use rustc_middle::mir::{CoroutineLayout, CoroutineSavedLocal};
 use rustc_middle::query::Providers;
 use rustc_middle::ty::layout::{
-    IntegerExt, LayoutCx, LayoutError, LayoutOf, TyAndLayout, MAX_SIMD_LANES,
+    niche_decision, IntegerExt, LayoutCx, LayoutError, LayoutOf, TyAndLayout, MAX_SIMD_LANES,
 };
 use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::{self, AdtDef, EarlyBinder, GenericArgsRef, Ty, TyCtxt, TypeVisitableExt};
@@ -68,6 +68,11 @@ fn layout_of<'tcx>(
         record_layout_for_printing(&cx, layout);
     }
 
+    // Likewise for `-Zprint-niche-decisions`.
+    if cx.tcx.sess.opts.unstable_opts.print_niche_decisions {
+        record_niche_decision_for_printing(&cx, layout);
+    }
+
     sanity_check_layout(&cx, &layout);
 
     Ok(layout)
@@ -632,12 +637,21 @@ enum SavedLocalEligibility {
 //
 // Also included in the layout are the upvars and the discriminant.
 // These are included as fields on the "outer" layout; they are not part
-// of any variant.
+// of any variant. Most upvars are stored in the prefix, but the ones which
+// are no longer used once the coroutine is first resumed are stored after
+// it, where they overlap with the variant fields, like the fields of an
+// extra variant for the unresumed state.
 
-/// Compute the eligibility and assignment of each local.
+/// Compute the eligibility and assignment of each local, and the upvars which
+/// overlap with the variant fields.
 fn coroutine_saved_local_eligibility(
     info: &CoroutineLayout<'_>,
-) -> (BitSet<CoroutineSavedLocal>, IndexVec<CoroutineSavedLocal, SavedLocalEligibility>) {
+    upvar_count: usize,
+) -> (
+    BitSet<CoroutineSavedLocal>,
+    IndexVec<CoroutineSavedLocal, SavedLocalEligibility>,
+    BitSet<FieldIdx>,
+) {
     use SavedLocalEligibility::*;
 
     let mut assignments: IndexVec<CoroutineSavedLocal, SavedLocalEligibility> =
@@ -701,6 +715,17 @@ fn coroutine_saved_local_eligibility(
         }
     }
 
+    // The upvars which are not used after a suspension point can overlap with
+    // the eligible locals they don't conflict with. They are then stored as if
+    // they were the fields of the unresumed variant.
+    let mut overlapping_upvars = BitSet::new_empty(upvar_count);
+    for (upvar, conflicts) in info.upvar_conflicts.iter_enumerated() {
+        let Some(conflicts) = conflicts else { continue };
+        if conflicts.iter().all(|local| ineligible_locals.contains(local)) {
+            overlapping_upvars.insert(upvar);
+        }
+    }
+
     // Count the number of variants in use. If only one of them, then it is
     // impossible to overlap any locals in our layout. In this case it's
     // always better to make the remaining locals ineligible, so we can
@@ -713,11 +738,15 @@ fn coroutine_saved_local_eligibility(
                 used_variants.insert(*idx);
             }
         }
+        if !overlapping_upvars.is_empty() {
+            used_variants.insert(VariantIdx::from_usize(ty::CoroutineArgs::UNRESUMED));
+        }
         if used_variants.count() < 2 {
             for assignment in assignments.iter_mut() {
                 *assignment = Ineligible(None);
             }
             ineligible_locals.insert_all();
+            overlapping_upvars.clear();
         }
     }
 
@@ -728,8 +757,9 @@ fn coroutine_saved_local_eligibility(
         }
     }
     debug!("coroutine saved local assignments: {:?}", assignments);
+    debug!("coroutine overlapping upvars: {:?}", overlapping_upvars);
 
-    (ineligible_locals, assignments)
+    (ineligible_locals, assignments, overlapping_upvars)
 }
 
 /// Compute the full coroutine layout.
@@ -746,12 +776,9 @@ fn coroutine_layout<'tcx>(
     let Some(info) = tcx.coroutine_layout(def_id) else {
         return Err(error(cx, LayoutError::Unknown(ty)));
     };
-    let (ineligible_locals, assignments) = coroutine_saved_local_eligibility(info);
-
-    // Build a prefix layout, including "promoting" all ineligible
-    // locals as part of the prefix. We compute the layout of all of
-    // these fields at once to get optimal packing.
     let tag_index = args.as_coroutine().prefix_tys().len();
+    let (ineligible_locals, assignments, overlapping_upvars) =
+        coroutine_saved_local_eligibility(info, tag_index);
 
     // `info.variant_fields` already accounts for the reserved variants, so no need to add them.
     let max_discr = (info.variant_fields.len() - 1) as u128;
@@ -762,16 +789,24 @@ fn coroutine_layout<'tcx>(
     };
     let tag_layout = cx.tcx.mk_layout(LayoutS::scalar(cx, tag));
 
+    // Build a prefix layout, including "promoting" all ineligible
+    // locals as part of the prefix. We compute the layout of all of
+    // these fields at once to get optimal packing.
     let promoted_layouts = ineligible_locals.iter().map(|local| {
         let field_ty = instantiate_field(info.field_tys[local].ty);
         let uninit_ty = Ty::new_maybe_uninit(tcx, field_ty);
         Ok(cx.spanned_layout_of(uninit_ty, info.field_tys[local].source_info.span)?.layout)
     });
-    let prefix_layouts = args
+    let upvar_layouts = args
         .as_coroutine()
         .prefix_tys()
         .iter()
         .map(|ty| Ok(cx.layout_of(ty)?.layout))
+        .try_collect::<IndexVec<FieldIdx, _>>()?;
+    let prefix_layouts = upvar_layouts
+        .iter_enumerated()
+        .filter(|(upvar, _)| !overlapping_upvars.contains(*upvar))
+        .map(|(_, &layout)| Ok(layout))
         .chain(iter::once(Ok(tag_layout)))
         .chain(promoted_layouts)
         .try_collect::<IndexVec<_, _>>()?;
@@ -783,7 +818,37 @@ fn coroutine_layout<'tcx>(
         StructKind::AlwaysSized,
     )?;
 
-    let (prefix_size, prefix_align) = (prefix.size, prefix.align);
+    let prefix_align = prefix.align;
+
+    // The variants start right after the last field of the prefix rather than
+    // at its size, so that their fields can use its trailing padding.
+    let prefix_end = prefix_layouts
+        .iter_enumerated()
+        .map(|(i, layout)| prefix.fields.offset(i.as_usize()) + layout.size())
+        .max()
+        .unwrap_or(Size::ZERO);
+    let univariant_after_prefix = |fields: &IndexSlice<FieldIdx, Layout<'tcx>>| -> Result<
+        LayoutS<FieldIdx, VariantIdx>,
+        &'tcx LayoutError<'tcx>,
+    > {
+        let mut st = univariant_uninterned(
+            cx,
+            ty,
+            fields,
+            &ReprOptions::default(),
+            StructKind::Prefixed(prefix_end, Align::ONE),
+        )?;
+        st.align = st.align.max(prefix_align);
+        st.unadjusted_abi_align = st.align.abi;
+        st.size = st.size.align_to(st.align.abi);
+        Ok(st)
+    };
+
+    // The overlapping upvars are laid out like the fields of the unresumed
+    // variant, although they remain fields of the "outer" layout.
+    let unresumed_upvars = univariant_after_prefix(
+        &overlapping_upvars.iter().map(|upvar| upvar_layouts[upvar]).collect::<IndexVec<_, _>>(),
+    )?;
 
     // Split the prefix layout into the "outer" fields (upvars and
     // discriminant) and the "promoted" fields. Promoted fields will
@@ -796,7 +861,7 @@ fn coroutine_layout<'tcx>(
 
             // "a" (`0..b_start`) and "b" (`b_start..`) correspond to
             // "outer" and "promoted" fields respectively.
-            let b_start = FieldIdx::from_usize(tag_index + 1);
+            let b_start = FieldIdx::from_usize(tag_index - overlapping_upvars.count() + 1);
             let offsets_b = IndexVec::from_raw(offsets.raw.split_off(b_start.as_usize()));
             let offsets_a = offsets;
 
@@ -822,8 +887,38 @@ fn coroutine_layout<'tcx>(
         _ => bug!(),
     };
 
-    let mut size = prefix.size;
-    let mut align = prefix.align;
+    // Put the overlapping upvars back among the "outer" fields, at their offset
+    // in the unresumed variant.
+    let outer_fields = if overlapping_upvars.is_empty() {
+        outer_fields
+    } else {
+        let (
+            FieldsShape::Arbitrary { offsets: prefix_offsets, .. },
+            FieldsShape::Arbitrary { offsets: unresumed_offsets, .. },
+        ) = (outer_fields, &unresumed_upvars.fields)
+        else {
+            bug!()
+        };
+        let mut prefix_offsets = prefix_offsets.into_iter();
+        let mut unresumed_offsets = unresumed_offsets.iter().copied();
+        let offsets: IndexVec<FieldIdx, Size> = (0..=tag_index)
+            .map(|i| {
+                if i < tag_index && overlapping_upvars.contains(FieldIdx::from_usize(i)) {
+                    unresumed_offsets.next().unwrap()
+                } else {
+                    prefix_offsets.next().unwrap()
+                }
+            })
+            .collect();
+
+        let mut inverse_memory_index: IndexVec<u32, FieldIdx> = offsets.indices().collect();
+        inverse_memory_index.raw.sort_by_key(|&i| offsets[i]);
+        let memory_index = inverse_memory_index.invert_bijective_mapping();
+        FieldsShape::Arbitrary { offsets, memory_index }
+    };
+
+    let mut size = prefix.size.max(unresumed_upvars.size);
+    let mut align = prefix.align.max(unresumed_upvars.align);
     let variants = info
         .variant_fields
         .iter_enumerated()
@@ -842,17 +937,20 @@ fn coroutine_layout<'tcx>(
                     Ty::new_maybe_uninit(tcx, field_ty)
                 });
 
-            let mut variant = univariant_uninterned(
-                cx,
-                ty,
+            let mut variant = univariant_after_prefix(
                 &variant_only_tys
                     .map(|ty| Ok(cx.layout_of(ty)?.layout))
                     .try_collect::<IndexVec<_, _>>()?,
-                &ReprOptions::default(),
-                StructKind::Prefixed(prefix_size, prefix_align.abi),
             )?;
             variant.variants = Variants::Single { index };
 
+            // The unresumed variant also holds the overlapping upvars.
+            if index.as_usize() == ty::CoroutineArgs::UNRESUMED {
+                variant.size = variant.size.max(unresumed_upvars.size);
+                variant.align = variant.align.max(unresumed_upvars.align);
+                variant.unadjusted_abi_align = variant.align.abi;
+            }
+
             let FieldsShape::Arbitrary { offsets, memory_index } = variant.fields else {
                 bug!();
             };
@@ -911,7 +1009,10 @@ fn coroutine_layout<'tcx>(
 
     size = size.align_to(align.abi);
 
-    let abi = if prefix.abi.is_uninhabited() || variants.iter().all(|v| v.abi.is_uninhabited()) {
+    let abi = if prefix.abi.is_uninhabited()
+        || unresumed_upvars.abi.is_uninhabited()
+        || variants.iter().all(|v| v.abi.is_uninhabited())
+    {
         Abi::Uninhabited
     } else {
         Abi::Aggregate { sized: true }
@@ -936,6 +1037,20 @@ fn coroutine_layout<'tcx>(
     Ok(layout)
 }
 
+fn record_niche_decision_for_printing<'tcx>(
+    cx: &LayoutCx<'tcx, TyCtxt<'tcx>>,
+    layout: TyAndLayout<'tcx>,
+) {
+    // As with `-Zprint-type-sizes`, only the layouts of the final codegen session are reported.
+    if layout.ty.has_non_region_param() || !cx.param_env.caller_bounds().is_empty() {
+        return;
+    }
+
+    if let Some(info) = niche_decision(cx.tcx, layout) {
+        cx.tcx.sess.code_stats.record_niche_decision(info);
+    }
+}
+
 fn record_layout_for_printing<'tcx>(cx: &LayoutCx<'tcx, TyCtxt<'tcx>>, layout: TyAndLayout<'tcx>) {
     // Ignore layouts that are done with non-empty environments or
     // non-monomorphic layouts, as the user only wants to see the stuff
@@ -945,7 +1060,7 @@ fn record_layout_for_printing<'tcx>(cx: &LayoutCx<'tcx, TyCtxt<'tcx>>, layout: T
     }
 
     // (delay format until we actually need it)
-    let record = |kind, packed, opt_discr_size, variants| {
+    let record = |kind, packed, opt_discr_size, overlapping_upvars_size, variants| {
         let type_desc = with_no_trimmed_paths!(format!("{}", layout.ty));
         cx.tcx.sess.code_stats.record_type_size(
             kind,
@@ -954,6 +1069,7 @@ fn record_layout_for_printing<'tcx>(cx: &LayoutCx<'tcx, TyCtxt<'tcx>>, layout: T
             layout.size,
             packed,
             opt_discr_size,
+            overlapping_upvars_size,
             variants,
         );
     };
@@ -964,20 +1080,26 @@ fn record_layout_for_printing<'tcx>(cx: &LayoutCx<'tcx, TyCtxt<'tcx>>, layout: T
             let adt_kind = adt_def.adt_kind();
             let adt_packed = adt_def.repr().pack.is_some();
             let (variant_infos, opt_discr_size) = variant_info_for_adt(cx, layout, adt_def);
-            record(adt_kind.into(), adt_packed, opt_discr_size, variant_infos);
+            record(adt_kind.into(), adt_packed, opt_discr_size, None, variant_infos);
         }
 
         ty::Coroutine(def_id, args) => {
             debug!("print-type-size t: `{:?}` record coroutine", layout.ty);
             // Coroutines always have a begin/poisoned/end state with additional suspend points
-            let (variant_infos, opt_discr_size) =
+            let (variant_infos, opt_discr_size, overlapping_upvars_size) =
                 variant_info_for_coroutine(cx, layout, def_id, args);
-            record(DataTypeKind::Coroutine, false, opt_discr_size, variant_infos);
+            record(
+                DataTypeKind::Coroutine,
+                false,
+                opt_discr_size,
+                Some(overlapping_upvars_size),
+                variant_infos,
+            );
         }
 
         ty::Closure(..) => {
             debug!("print-type-size t: `{:?}` record closure", layout.ty);
-            record(DataTypeKind::Closure, false, None, vec![]);
+            record(DataTypeKind::Closure, false, None, None, vec![]);
         }
 
         _ => {
@@ -1062,17 +1184,23 @@ fn variant_info_for_coroutine<'tcx>(
     layout: TyAndLayout<'tcx>,
     def_id: DefId,
     args: ty::GenericArgsRef<'tcx>,
-) -> (Vec<VariantInfo>, Option<Size>) {
+) -> (Vec<VariantInfo>, Option<Size>, Size) {
     use itertools::Itertools;
 
     let Variants::Multiple { tag, ref tag_encoding, tag_field, .. } = layout.variants else {
-        return (vec![], None);
+        return (vec![], None, Size::ZERO);
     };
 
     let coroutine = cx.tcx.optimized_mir(def_id).coroutine_layout().unwrap();
     let upvar_names = cx.tcx.closure_saved_names_of_captured_variables(def_id);
+    let (_, _, overlapping_upvars) =
+        coroutine_saved_local_eligibility(coroutine, upvar_names.len());
 
+    // The upvars stored in the prefix are shown in every variant, and the
+    // overlapping ones only in the unresumed variant.
     let mut upvars_size = Size::ZERO;
+    let mut prefix_upvars_size = Size::ZERO;
+    let mut overlapping_upvars_size = Size::ZERO;
     let upvar_fields: Vec<_> = args
         .as_coroutine()
         .upvar_tys()
@@ -1083,13 +1211,20 @@ fn variant_info_for_coroutine<'tcx>(
             let field_layout = layout.field(cx, field_idx);
             let offset = layout.fields.offset(field_idx);
             upvars_size = upvars_size.max(offset + field_layout.size);
-            FieldInfo {
+            let overlapping = overlapping_upvars.contains(FieldIdx::from_usize(field_idx));
+            if overlapping {
+                overlapping_upvars_size += field_layout.size;
+            } else {
+                prefix_upvars_size = prefix_upvars_size.max(offset + field_layout.size);
+            }
+            let info = FieldInfo {
                 kind: FieldKind::Upvar,
                 name: *name,
                 offset: offset.bytes(),
                 size: field_layout.size.bytes(),
                 align: field_layout.align.abi.bytes(),
-            }
+            };
+            (overlapping, info)
         })
         .collect();
 
@@ -1097,6 +1232,7 @@ fn variant_info_for_coroutine<'tcx>(
         .variant_fields
         .iter_enumerated()
         .map(|(variant_idx, variant_def)| {
+            let unresumed = variant_idx.as_usize() == ty::CoroutineArgs::UNRESUMED;
             let variant_layout = layout.for_variant(cx, variant_idx);
             let mut variant_size = Size::ZERO;
             let fields = variant_def
@@ -1118,12 +1254,17 @@ fn variant_info_for_coroutine<'tcx>(
                         align: field_layout.align.abi.bytes(),
                     }
                 })
-                .chain(upvar_fields.iter().copied())
+                .chain(
+                    upvar_fields
+                        .iter()
+                        .filter(|&&(overlapping, _)| unresumed || !overlapping)
+                        .map(|&(_, info)| info),
+                )
                 .collect();
 
             // If the variant has no state-specific fields, then it's the size of the upvars.
             if variant_size == Size::ZERO {
-                variant_size = upvars_size;
+                variant_size = if unresumed { upvars_size } else { prefix_upvars_size };
             }
 
             // This `if` deserves some explanation.
@@ -1172,5 +1313,6 @@ fn variant_info_for_coroutine<'tcx>(
             TagEncoding::Direct => Some(tag.size(cx)),
             _ => None,
         },
+        overlapping_upvars_size,
     )
 }
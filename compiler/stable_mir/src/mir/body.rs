@@ -173,6 +173,12 @@ pub enum TerminatorKind {
         target: Option<BasicBlockIdx>,
         unwind: UnwindAction,
     },
+    /// A call that replaces the frame of the current function, which returns what the callee
+    /// returns. Its arguments are owned by the callee.
+    TailCall {
+        func: Operand,
+        args: Vec<Operand>,
+    },
     Assert {
         cond: Operand,
         expected: bool,
@@ -215,6 +221,7 @@ pub fn successors(&self) -> Successors {
             | Abort
             | Unreachable
             | Call { target: None, unwind: _, .. }
+            | TailCall { .. }
             | InlineAsm { destination: None, unwind: _, .. } => {
                 vec![]
             }
@@ -229,6 +236,7 @@ pub fn unwind(&self) -> Option<&UnwindAction> {
             | TerminatorKind::Unreachable
             | TerminatorKind::Resume
             | TerminatorKind::Abort
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::SwitchInt { .. } => None,
             TerminatorKind::Call { ref unwind, .. }
             | TerminatorKind::Assert { ref unwind, .. }
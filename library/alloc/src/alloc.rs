@@ -50,6 +50,7 @@
 #[unstable(feature = "allocator_api", issue = "32838")]
 #[derive(Copy, Clone, Default, Debug)]
 #[cfg(not(test))]
+#[lang = "global_alloc_ty"]
 pub struct Global;
 
 #[cfg(test)]
@@ -213,7 +213,8 @@ impl<T> Box<T> {
     #[stable(feature = "rust1", since = "1.0.0")]
     #[must_use]
     #[rustc_diagnostic_item = "box_new"]
-    pub fn new(x: T) -> Self {
+    #[rustc_const_unstable(feature = "const_heap", issue = "79597")]
+    pub const fn new(x: T) -> Self {
         #[rustc_box]
         Box::new(x)
     }
@@ -1179,12 +1180,16 @@ pub const fn allocator(b: &Self) -> &A {
     /// assert_eq!(*static_ref, [4, 2, 3]);
     /// ```
     #[stable(feature = "box_leak", since = "1.26.0")]
+    #[rustc_const_unstable(feature = "const_box", issue = "92521")]
     #[inline]
-    pub fn leak<'a>(b: Self) -> &'a mut T
+    pub const fn leak<'a>(b: Self) -> &'a mut T
     where
         A: 'a,
     {
-        unsafe { &mut *mem::ManuallyDrop::new(b).0.as_ptr() }
+        let b = mem::ManuallyDrop::new(b);
+        // SAFETY: `ManuallyDrop<Self>` has the same layout as `Self`. We read the pointer through
+        // a cast rather than `Deref`, which cannot be used in a `const fn`.
+        unsafe { &mut *(*ptr::addr_of!(b).cast::<Self>()).0.as_ptr() }
     }
 
     /// Converts a `Box<T>` into a `Pin<Box<T>>`. If `T` does not implement [`Unpin`], then
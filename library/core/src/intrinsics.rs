@@ -2458,7 +2458,9 @@ pub const fn unlikely(b: bool) -> bool {
     ///
     /// `arg` will be the tupled arguments that will be passed to either one of
     /// the two functions, therefore, both functions must accept the same type of
-    /// arguments. Both functions must return RET.
+    /// arguments. Both functions must return RET. In fact, the two functions must
+    /// have identical signatures, including lifetimes; only whether they are
+    /// `unsafe` may differ.
     ///
     /// # Safety
     ///
@@ -2498,6 +2500,11 @@ pub const fn unlikely(b: bool) -> bool {
     /// `unreachable_unchecked` is actually being reached. The bug is in *crate A*,
     /// which violates the principle that a `const fn` must behave the same at
     /// compile-time and at run-time. The unsafe code in crate B is fine.
+    ///
+    /// The compiler cannot check this in general, but the `const_eval_select_mismatch`
+    /// lint warns if only one of the two functions is `#[track_caller]`, or if the
+    /// runtime function is a `const fn` as well and returns a different value than
+    /// the compile-time function when both are evaluated at compile-time.
     #[rustc_const_unstable(feature = "const_eval_select", issue = "none")]
     pub fn const_eval_select<ARG: Tuple, F, G, RET>(
         arg: ARG,
@@ -226,6 +226,7 @@
 //! #### Statements
 //!  - Assign statements work via normal Rust assignment.
 //!  - [`Retag`], [`StorageLive`], [`StorageDead`], [`Deinit`] statements have an associated function.
+//!  - [`Nop`], [`PlaceMention`] and [`CopyNonOverlapping`] statements have an associated function.
 //!
 //! #### Rvalues
 //!
@@ -245,6 +246,8 @@
 //!       otherwise branch.
 //!  - [`Call`] has an associated function as well, with special syntax:
 //!    `Call(ret_val = function(arg1, arg2, ...), ReturnTo(next_block), UnwindContinue())`.
+//!  - [`InlineAsm`] takes an `asm!` invocation in the same way:
+//!    `InlineAsm(asm!("...", in(reg) x), ReturnTo(next_block), UnwindUnreachable())`.
 
 #![unstable(
     feature = "custom_mir",
@@ -353,11 +356,38 @@ fn Call(call: (), goto: ReturnToArg, unwind_action: UnwindActionArg)
     /// A terminator that resumes the unwinding.
     fn UnwindResume()
 );
+define!("mir_inline_asm",
+    /// Execute inline assembly.
+    ///
+    /// The first argument must be an `asm!` invocation. Its operands must be places or
+    /// operands, just like the arguments of [`Call`].
+    ///
+    /// The second argument must be of the form `ReturnTo(bb)`, where `bb` is the basic block that
+    /// will be jumped to after the assembly returns. It is ignored if the assembly has the
+    /// `noreturn` option.
+    ///
+    /// The third argument describes what happens on unwind, see [`Call`]. It should be
+    /// [`UnwindUnreachable`] unless the assembly has the `may_unwind` option.
+    fn InlineAsm<T>(asm: T, goto: ReturnToArg, unwind_action: UnwindActionArg)
+);
 
 define!("mir_storage_live", fn StorageLive<T>(local: T));
 define!("mir_storage_dead", fn StorageDead<T>(local: T));
 define!("mir_assume", fn Assume(operand: bool));
 define!("mir_deinit", fn Deinit<T>(place: T));
+define!("mir_nop", fn Nop());
+define!(
+    "mir_place_mention",
+    /// Evaluates a place without reading from it, like `let _ = place;`.
+    fn PlaceMention<T>(place: T)
+);
+define!(
+    "mir_copy_nonoverlapping",
+    /// Copies `count * size_of::<T>()` bytes from `src` to `dst`, which must not overlap.
+    ///
+    /// This is the statement `ptr::copy_nonoverlapping` is lowered to.
+    fn CopyNonOverlapping<T>(src: *const T, dst: *mut T, count: usize)
+);
 define!("mir_checked", fn Checked<T>(binop: T) -> (T, bool));
 define!("mir_len", fn Len<T>(place: T) -> usize);
 define!("mir_copy_for_deref", fn CopyForDeref<T>(place: T) -> T);
@@ -72,6 +72,18 @@ pub const fn panic_fmt(fmt: fmt::Arguments<'_>) -> ! {
     unsafe { panic_impl(&pi) }
 }
 
+/// Like `panic_fmt`, but takes the pieces and arguments of the `fmt::Arguments`
+/// separately. Calls to `panic_fmt` are rewritten to calls to this function by
+/// `-Zmir-outline-panics`, so that the caller doesn't need to build the
+/// `fmt::Arguments` itself.
+#[cfg_attr(not(feature = "panic_immediate_abort"), inline(never), cold)]
+#[cfg_attr(feature = "panic_immediate_abort", inline)]
+#[track_caller]
+#[cfg_attr(not(bootstrap), lang = "panic_fmt_v1")]
+pub fn panic_fmt_v1<'a>(pieces: &'a [&'static str], args: &'a [fmt::rt::Argument<'a>]) -> ! {
+    panic_fmt(fmt::Arguments::new_v1(pieces, args));
+}
+
 /// Like `panic_fmt`, but for non-unwinding panics.
 ///
 /// Has to be a separate function so that it can carry the `rustc_nounwind` attribute.
@@ -256,7 +268,8 @@ fn panic_in_cleanup() -> ! {
     panic_nounwind_nobacktrace("panic in a destructor during cleanup")
 }
 
-/// This function is used instead of panic_fmt in const eval.
+/// This function is used instead of panic_fmt in const eval, for messages without arguments.
+/// Const eval formats messages with arguments itself.
 #[lang = "const_panic_fmt"]
 #[rustc_const_unstable(feature = "panic_internals", issue = "none")]
 pub const fn const_panic_fmt(fmt: fmt::Arguments<'_>) -> ! {
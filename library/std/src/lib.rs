@@ -283,6 +283,7 @@
 #![feature(const_trait_impl)]
 #![feature(decl_macro)]
 #![feature(deprecated_suggestion)]
+#![feature(diagnostic_namespace)]
 #![feature(doc_cfg)]
 #![feature(doc_cfg_hide)]
 #![feature(doc_masked)]
@@ -311,6 +312,7 @@
 #![feature(type_alias_impl_trait)]
 #![feature(utf8_chunks)]
 // tidy-alphabetical-end
+#![cfg_attr(not(bootstrap), feature(diagnostic_blocking))]
 //
 // Library features (core):
 // tidy-alphabetical-start
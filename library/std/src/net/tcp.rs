@@ -153,6 +153,7 @@ impl TcpStream {
     /// }
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), diagnostic::blocking)]
     pub fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<TcpStream> {
         super::each_addr(addr, net_imp::TcpStream::connect).map(TcpStream)
     }
@@ -768,6 +769,7 @@ impl TcpListener {
     /// let socket = TcpListener::bind("127.0.0.1:0").unwrap();
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), diagnostic::blocking)]
     pub fn bind<A: ToSocketAddrs>(addr: A) -> io::Result<TcpListener> {
         super::each_addr(addr, net_imp::TcpListener::bind).map(TcpListener)
     }
@@ -825,6 +827,7 @@ pub fn try_clone(&self) -> io::Result<TcpListener> {
     /// }
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[cfg_attr(not(bootstrap), diagnostic::blocking)]
     pub fn accept(&self) -> io::Result<(TcpStream, SocketAddr)> {
         // On WASM, `TcpStream` is uninhabited (as it's unsupported) and so
         // the `a` variable here is technically unused.
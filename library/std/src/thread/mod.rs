@@ -865,6 +865,7 @@ pub fn sleep_ms(ms: u32) {
 /// assert!(now.elapsed() >= ten_millis);
 /// ```
 #[stable(feature = "thread_sleep", since = "1.4.0")]
+#[cfg_attr(not(bootstrap), diagnostic::blocking)]
 pub fn sleep(dur: Duration) {
     imp::Thread::sleep(dur)
 }
@@ -2,7 +2,9 @@
 use rustc_data_structures::sync::Lrc;
 use rustc_data_structures::unord::UnordSet;
 use rustc_errors::emitter::{DynEmitter, HumanEmitter};
+use rustc_errors::github::GithubEmitter;
 use rustc_errors::json::JsonEmitter;
+use rustc_errors::sarif::SarifEmitter;
 use rustc_errors::{codes::*, TerminalUrl};
 use rustc_feature::UnstableFeatures;
 use rustc_hir::def::Res;
@@ -147,6 +149,7 @@ pub(crate) fn new_dcx(
                     .teach(unstable_opts.teach)
                     .diagnostic_width(diagnostic_width)
                     .track_diagnostics(unstable_opts.track_diagnostics)
+                    .type_aliases(unstable_opts.diagnostic_type_aliases)
                     .ui_testing(unstable_opts.ui_testing),
             )
         }
@@ -167,9 +170,23 @@ pub(crate) fn new_dcx(
                     unstable_opts.track_diagnostics,
                     TerminalUrl::No,
                 )
+                .type_aliases(unstable_opts.diagnostic_type_aliases)
+                .explanations(unstable_opts.json_explanations)
                 .ui_testing(unstable_opts.ui_testing),
             )
         }
+        ErrorOutputType::Sarif => {
+            let source_map = source_map.unwrap_or_else(|| {
+                Lrc::new(source_map::SourceMap::new(source_map::FilePathMapping::empty()))
+            });
+            Box::new(SarifEmitter::stderr(source_map, None, fallback_bundle))
+        }
+        ErrorOutputType::Github => {
+            let source_map = source_map.unwrap_or_else(|| {
+                Lrc::new(source_map::SourceMap::new(source_map::FilePathMapping::empty()))
+            });
+            Box::new(GithubEmitter::stderr(source_map, None, fallback_bundle))
+        }
     };
 
     rustc_errors::DiagCtxt::with_emitter(emitter).with_flags(unstable_opts.dcx_flags(true))
@@ -267,6 +284,7 @@ pub(crate) fn create_config(
         parse_sess_created: None,
         hash_untracked_state: None,
         register_lints: Some(Box::new(crate::lint::register_lints)),
+        register_mir_passes: None,
         override_queries: Some(|_sess, providers| {
             // We do not register late module lints, so this only runs `MissingDoc`.
             // Most lints will require typechecking, so just don't run them.
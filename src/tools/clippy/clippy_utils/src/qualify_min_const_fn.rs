@@ -309,6 +309,7 @@ fn check_terminator<'tcx>(
         TerminatorKind::CoroutineDrop | TerminatorKind::Yield { .. } => {
             Err((span, "const fn coroutines are unstable".into()))
         },
+        TerminatorKind::TailCall { .. } => Err((span, "tail calls are not allowed in const fn".into())),
         TerminatorKind::Call {
             func,
             args,
@@ -1003,7 +1003,7 @@ fn borrow(&mut self, cmt: &PlaceWithHirId<'tcx>, _: HirId, _: ty::BorrowKind) {
                         // the index is dropped so we can't get it to build the suggestion,
                         // so the span is set-up again to get more code, using `span.hi()` (i.e.: `foo[x]`)
                         // instead of `span.lo()` (i.e.: `foo`)
-                        ProjectionKind::Index => {
+                        ProjectionKind::Index | ProjectionKind::ConstantIndex(_) => {
                             let start_span = Span::new(self.next_pos, span.hi(), span.ctxt(), None);
                             start_snip = snippet_with_applicability(self.cx, start_span, "..", &mut self.applicability);
                             replacement_str.clear();
@@ -84,10 +84,19 @@ struct DiagnosticCode {
     code: String,
 }
 
+/// Keeps the diagnostics of `output`, without the suggestions which are lower ranked
+/// alternatives to another one, as rustfix would apply all of them.
 pub fn rustfix_diagnostics_only(output: &str) -> String {
     output
         .lines()
         .filter(|line| line.starts_with('{') && serde_json::from_str::<Diagnostic>(line).is_ok())
+        .map(|line| {
+            let mut diagnostic: serde_json::Value = serde_json::from_str(line).unwrap();
+            if let Some(children) = diagnostic["children"].as_array_mut() {
+                children.retain(|child| child["alternative"]["rank"].as_u64().unwrap_or(0) == 0);
+            }
+            diagnostic.to_string()
+        })
         .collect()
 }
 
@@ -294,7 +294,7 @@ pub fn report_error<'tcx, 'mir>(
             ResourceExhaustion(_) => "resource exhaustion",
             Unsupported(
                 // We list only the ones that can actually happen.
-                UnsupportedOpInfo::Unsupported(_) | UnsupportedOpInfo::UnsizedLocal,
+                UnsupportedOpInfo::Unsupported(_),
             ) => "unsupported operation",
             InvalidProgram(
                 // We list only the ones that can actually happen.
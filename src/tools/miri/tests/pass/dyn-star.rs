@@ -10,6 +10,7 @@
 fn main() {
     make_dyn_star();
     method();
+    by_value_method();
     box_();
     dispatch_on_pin_mut();
     dyn_star_to_dyn();
@@ -64,6 +65,21 @@ fn make_and_invoke_dyn_star(i: usize) -> usize {
     assert_eq!(make_and_invoke_dyn_star(42), 42);
 }
 
+fn by_value_method() {
+    trait Foo {
+        fn into_usize(self) -> usize;
+    }
+
+    impl Foo for usize {
+        fn into_usize(self) -> usize {
+            self
+        }
+    }
+
+    let dyn_i: dyn* Foo = 42usize;
+    assert_eq!(dyn_i.into_usize(), 42);
+}
+
 fn box_() {
     fn make_dyn_star() -> dyn* Display {
         Box::new(42) as dyn* Display
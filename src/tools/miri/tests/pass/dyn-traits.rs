@@ -1,3 +1,6 @@
+#![feature(unsized_locals)]
+#![allow(incomplete_features)]
+
 fn ref_box_dyn() {
     struct Struct(i32);
 
@@ -72,9 +75,6 @@ fn new(w: Box<dyn MyTrait + 'static>) -> Whatever {
     assert!(unsafe { DROPPED });
 }
 
-// Disabled for now: unsized locals are not supported,
-// their current MIR encoding is just not great.
-/*
 fn unsized_dyn() {
     pub trait Foo {
         fn foo(self) -> String;
@@ -139,9 +139,10 @@ fn foo(mut self) -> String {
     let x = Box::new(|| "hello".to_owned()) as Box<dyn FnMut() -> String>;
     assert_eq!(&x.foo() as &str, "hello");
 }
-*/
 
 fn main() {
     ref_box_dyn();
     box_box_trait();
+    unsized_dyn();
+    unsized_dyn_autoderef();
 }
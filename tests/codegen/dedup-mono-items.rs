@@ -0,0 +1,50 @@
+// Checks that `-Zdedup-mono-items` emits instantiations with identical code
+// as aliases of a single definition, and keeps the others apart.
+//
+// compile-flags: -Z dedup-mono-items -C opt-level=0 -C codegen-units=1
+// ignore-wasm
+
+#![crate_type = "lib"]
+
+use std::ops::Div;
+
+// `swap_pair::<u32>` and `swap_pair::<i32>` only move values around, so one is
+// an alias of the other. The division in `halve` is signed for `i32` and
+// unsigned for `u32`, so both instantiations have to be defined.
+
+// CHECK-NOT: halve{{.*}} = {{.*}}alias
+// CHECK: swap_pair{{.*}} = {{.*}}alias {{.*}}swap_pair
+// CHECK-NOT: halve{{.*}} = {{.*}}alias
+
+// CHECK-DAG: define {{.*}}swap_pair
+// CHECK-DAG: define {{.*}}halve
+// CHECK-DAG: define {{.*}}halve
+// CHECK-NOT: define {{.*}}swap_pair
+
+fn swap_pair<T: Copy>(pair: (T, T)) -> (T, T) {
+    (pair.1, pair.0)
+}
+
+fn halve<T: Div<Output = T> + From<u8>>(x: T) -> T {
+    x / T::from(2)
+}
+
+#[no_mangle]
+pub fn swap_u32(pair: (u32, u32)) -> (u32, u32) {
+    swap_pair(pair)
+}
+
+#[no_mangle]
+pub fn swap_i32(pair: (i32, i32)) -> (i32, i32) {
+    swap_pair(pair)
+}
+
+#[no_mangle]
+pub fn halve_u32(x: u32) -> u32 {
+    halve(x)
+}
+
+#[no_mangle]
+pub fn halve_i32(x: i32) -> i32 {
+    halve(x)
+}
@@ -0,0 +1,30 @@
+// compile-flags: -C no-prepopulate-passes
+// only-x86_64
+
+#![crate_type = "lib"]
+#![allow(incomplete_features)]
+#![feature(explicit_tail_calls)]
+
+// CHECK-LABEL: @count_down
+#[no_mangle]
+pub fn count_down(n: u32) -> u32 {
+    if n == 0 {
+        return 0;
+    }
+    // CHECK: [[RET:%.*]] = musttail call noundef i32 @count_down(i32 noundef %{{.*}})
+    // CHECK-NEXT: ret i32 [[RET]]
+    become count_down(n - 1)
+}
+
+// CHECK-LABEL: @forward_indirect
+#[no_mangle]
+pub fn forward_indirect(n: u32, a: [u64; 8]) -> u64 {
+    if n == 0 {
+        return a[0];
+    }
+    // The argument is forwarded through the memory the caller received its own argument in.
+    // CHECK: call void @llvm.memcpy{{.*}}(ptr align 8 %a, ptr align 8 %{{.*}}, i64 64, i1 false)
+    // CHECK: [[RET:%.*]] = musttail call noundef i64 @forward_indirect(i32 noundef %{{.*}}, ptr {{.*}}%a)
+    // CHECK-NEXT: ret i64 [[RET]]
+    become forward_indirect(n - 1, [a[0] + 1; 8])
+}
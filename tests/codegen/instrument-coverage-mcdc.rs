@@ -0,0 +1,37 @@
+// Test that `-Zcoverage-options=mcdc` allocates a test vector bitmap for each
+// function with a decision of 2 to 6 conditions, sized after its conditions.
+
+// needs-profiler-support
+// min-llvm-version: 18
+// ignore-llvm-version: 19 - 99
+// compile-flags: -Cinstrument-coverage -Zcoverage-options=mcdc
+
+// CHECK-NOT: @__profbm_single_condition
+// CHECK-NOT: @__profbm_too_many_conditions
+// CHECK-DAG: @__profbm_two_conditions = {{.*}}global [1 x i8] zeroinitializer
+// CHECK-DAG: @__profbm_four_conditions = {{.*}}global [2 x i8] zeroinitializer
+// CHECK-NOT: @__profbm_single_condition
+// CHECK-NOT: @__profbm_too_many_conditions
+
+#![crate_type = "lib"]
+
+#[no_mangle]
+pub fn single_condition(a: bool) -> u32 {
+    if a { 1 } else { 0 }
+}
+
+#[no_mangle]
+pub fn two_conditions(a: bool, b: bool) -> u32 {
+    if a && b { 1 } else { 0 }
+}
+
+#[no_mangle]
+pub fn four_conditions(a: bool, b: bool, c: bool, d: bool) -> u32 {
+    if a && (b || !c) && d { 1 } else { 0 }
+}
+
+// Decisions with more than 6 conditions are only instrumented as branches.
+#[no_mangle]
+pub fn too_many_conditions(a: [bool; 7]) -> u32 {
+    if a[0] && a[1] && a[2] && a[3] && a[4] && a[5] && a[6] { 1 } else { 0 }
+}
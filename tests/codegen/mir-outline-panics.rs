@@ -0,0 +1,27 @@
+// Checks that `-Zmir-outline-panics` calls the cold functions of `core` which
+// build the `fmt::Arguments` of a panic, instead of building it at the call site.
+//
+// compile-flags: -O -Z mir-outline-panics
+// edition:2021
+
+#![crate_type = "lib"]
+
+// CHECK-LABEL: @literal
+#[no_mangle]
+pub fn literal(x: bool) {
+    // CHECK-NOT: panic_fmt
+    // CHECK: call void @{{.*}}4core9panicking5panic17h
+    if x {
+        panic!("literal");
+    }
+}
+
+// CHECK-LABEL: @formatted
+#[no_mangle]
+pub fn formatted(x: u32) {
+    // CHECK-NOT: 9panic_fmt17h
+    // CHECK: call void @{{.*}}4core9panicking12panic_fmt_v117h
+    if x > 10 {
+        panic!("{x} is too large");
+    }
+}
@@ -0,0 +1,61 @@
+// compile-flags: --crate-type=lib
+// edition:2021
+// only-x86_64
+#![feature(custom_mir, core_intrinsics, asm_unwind)]
+#![allow(unreachable_code)]
+use core::arch::asm;
+use core::intrinsics::mir::*;
+
+// CHECK-LABEL: fn operands(
+// CHECK:       bb0: {
+// CHECK-NEXT:  asm!("mov {0}, {1}", out(reg) _0, in(reg) _1,
+// CHECK-SAME:  -> [return: bb1, unwind unreachable];
+#[custom_mir(dialect = "runtime", phase = "optimized")]
+pub unsafe fn operands(x: u64) -> u64 {
+    mir!(
+        {
+            InlineAsm(
+                asm!("mov {0}, {1}", out(reg) RET, in(reg) x, options(pure, nomem, nostack)),
+                ReturnTo(bb1),
+                UnwindUnreachable(),
+            )
+        }
+        bb1 = {
+            Return()
+        }
+    )
+}
+
+// CHECK-LABEL: fn may_unwind(
+// CHECK:       bb0: {
+// CHECK-NEXT:  asm!("", options(MAY_UNWIND)) -> [return: bb1, unwind: bb2];
+#[custom_mir(dialect = "runtime", phase = "optimized")]
+pub unsafe fn may_unwind() {
+    mir!(
+        {
+            InlineAsm(asm!("", options(may_unwind)), ReturnTo(bb1), UnwindCleanup(bb2))
+        }
+        bb1 = {
+            Return()
+        }
+        bb2 (cleanup) = {
+            UnwindResume()
+        }
+    )
+}
+
+// CHECK-LABEL: fn no_return(
+// CHECK:       bb0: {
+// CHECK-NEXT:  asm!("ud2", options(NORETURN))
+// CHECK-NOT:   return:
+#[custom_mir(dialect = "runtime", phase = "optimized")]
+pub unsafe fn no_return() {
+    mir!(
+        {
+            InlineAsm(asm!("ud2", options(noreturn)), ReturnTo(bb1), UnwindUnreachable())
+        }
+        bb1 = {
+            Unreachable()
+        }
+    )
+}
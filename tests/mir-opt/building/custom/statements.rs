@@ -0,0 +1,34 @@
+// compile-flags: --crate-type=lib
+// edition:2021
+#![feature(custom_mir, core_intrinsics)]
+use core::intrinsics::mir::*;
+
+// CHECK-LABEL: fn nop_and_mention(
+// CHECK:       bb0: {
+// CHECK-NEXT:  nop;
+// CHECK-NEXT:  PlaceMention((*_1));
+// CHECK-NEXT:  return;
+#[custom_mir(dialect = "runtime", phase = "optimized")]
+pub fn nop_and_mention(x: &i32) {
+    mir!(
+        {
+            Nop();
+            PlaceMention(*x);
+            Return()
+        }
+    )
+}
+
+// CHECK-LABEL: fn copy_nonoverlapping(
+// CHECK:       bb0: {
+// CHECK-NEXT:  copy_nonoverlapping(dst = _2, src = _1, count = _3);
+// CHECK-NEXT:  return;
+#[custom_mir(dialect = "runtime", phase = "optimized")]
+pub unsafe fn copy_nonoverlapping(src: *const u8, dst: *mut u8, count: usize) {
+    mir!(
+        {
+            CopyNonOverlapping(src, dst, count);
+            Return()
+        }
+    )
+}
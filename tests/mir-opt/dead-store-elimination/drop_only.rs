@@ -0,0 +1,83 @@
+// Check that, from `-Zmir-opt-level=3`, an assignment to a field of a local is removed when the
+// local is only dropped afterwards, if the drop can't observe the assigned value.
+//
+// needs-unwind
+// unit-test: DeadStoreElimination-initial
+// compile-flags: -Zmir-opt-level=3
+
+#![feature(core_intrinsics, custom_mir)]
+use std::intrinsics::mir::*;
+
+struct Loud(i32);
+
+impl Drop for Loud {
+    fn drop(&mut self) {
+        println!("{}", self.0);
+    }
+}
+
+// EMIT_MIR drop_only.plain_field.DeadStoreElimination-initial.diff
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+fn plain_field(s: String) {
+    // CHECK-LABEL: fn plain_field(
+    // CHECK-NOT: {{_.*}} = const 5_i32;
+    // CHECK-NOT: ({{_.*}}.1: i32) =
+    // CHECK: drop(
+    mir!(
+        let pair: (String, i32);
+        let n: i32;
+        {
+            pair = (Move(s), 0);
+            n = 5;
+            pair.1 = n;
+            Drop(pair, ReturnTo(ret), UnwindContinue())
+        }
+        ret = {
+            Return()
+        }
+    )
+}
+
+// EMIT_MIR drop_only.field_of_drop_impl.DeadStoreElimination-initial.diff
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+fn field_of_drop_impl(l: Loud) {
+    // CHECK-LABEL: fn field_of_drop_impl(
+    // CHECK: ({{_.*}}.0: i32) = const 5_i32;
+    // CHECK: drop(
+    mir!(
+        let loud: Loud;
+        {
+            loud = Move(l);
+            loud.0 = 5;
+            Drop(loud, ReturnTo(ret), UnwindContinue())
+        }
+        ret = {
+            Return()
+        }
+    )
+}
+
+// EMIT_MIR drop_only.field_needing_drop.DeadStoreElimination-initial.diff
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+fn field_needing_drop(s: String, t: String) {
+    // CHECK-LABEL: fn field_needing_drop(
+    // CHECK: ({{_.*}}.0: std::string::String) = move {{_.*}};
+    // CHECK: drop(
+    mir!(
+        let pair: (String, i32);
+        {
+            pair = (Move(s), 0);
+            pair.0 = Move(t);
+            Drop(pair, ReturnTo(ret), UnwindContinue())
+        }
+        ret = {
+            Return()
+        }
+    )
+}
+
+fn main() {
+    plain_field(String::new());
+    field_of_drop_impl(Loud(0));
+    field_needing_drop(String::new(), String::new());
+}
@@ -514,6 +514,57 @@ fn assume(a: u8, b: bool) -> u8 {
     )
 }
 
+/// Verify that we thread through a switch on a copy of the discriminant of the same enum.
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+fn chained_if_let(x: Option<u8>) -> u8 {
+    // CHECK-LABEL: fn chained_if_let(
+    // CHECK: bb0: {
+    // CHECK-NEXT: [[a:_.*]] = discriminant(_1);
+    // CHECK-NEXT: switchInt([[a]]) -> [1: bb1, otherwise: bb2];
+    // CHECK: bb1: {
+    // CHECK-NEXT: goto -> bb6;
+    // CHECK: bb2: {
+    // CHECK-NEXT: goto -> bb3;
+    // CHECK: bb3: {
+    // CHECK-NEXT: [[b:_.*]] = discriminant(_1);
+    // CHECK-NEXT: goto -> bb5;
+    // CHECK: bb4: {
+    // CHECK-NEXT: _0 = const 5_u8;
+    // CHECK-NEXT: return;
+    // CHECK: bb5: {
+    // CHECK-NEXT: _0 = const 13_u8;
+    // CHECK-NEXT: return;
+    // CHECK: bb6: {
+    // CHECK-NEXT: [[b]] = discriminant(_1);
+    // CHECK-NEXT: goto -> bb4;
+    mir!(
+        let a: isize;
+        let b: isize;
+        {
+            a = Discriminant(x);
+            match a { 1 => bb1, _ => bb2 }
+        }
+        bb1 = {
+            Goto(bb3)
+        }
+        bb2 = {
+            Goto(bb3)
+        }
+        bb3 = {
+            b = Discriminant(x);
+            match b { 1 => bb4, _ => bb5 }
+        }
+        bb4 = {
+            RET = 5;
+            Return()
+        }
+        bb5 = {
+            RET = 13;
+            Return()
+        }
+    )
+}
+
 fn main() {
     // CHECK-LABEL: fn main(
     too_complex(Ok(0));
@@ -528,6 +579,7 @@ fn main() {
     disappearing_bb(7);
     aggregate(7);
     assume(7, false);
+    chained_if_let(Some(7));
 }
 
 // EMIT_MIR jump_threading.too_complex.JumpThreading.diff
@@ -542,3 +594,4 @@ fn main() {
 // EMIT_MIR jump_threading.disappearing_bb.JumpThreading.diff
 // EMIT_MIR jump_threading.aggregate.JumpThreading.diff
 // EMIT_MIR jump_threading.assume.JumpThreading.diff
+// EMIT_MIR jump_threading.chained_if_let.JumpThreading.diff
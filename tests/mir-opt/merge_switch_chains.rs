@@ -0,0 +1,148 @@
+// unit-test: MergeSwitchChains
+
+#![feature(custom_mir)]
+#![feature(core_intrinsics)]
+
+use std::intrinsics::mir::*;
+
+enum E {
+    A,
+    B,
+    C,
+    D,
+}
+
+// EMIT_MIR merge_switch_chains.discriminant.MergeSwitchChains.diff
+#[custom_mir(dialect = "runtime")]
+pub fn discriminant(e: E) -> usize {
+    // CHECK-LABEL: fn discriminant(
+    // CHECK: bb0: {
+    // CHECK: [[discr:_[0-9]+]] = discriminant(_1);
+    // CHECK: switchInt(move [[discr]]) -> [0: bb1, 1: bb2, 2: bb3, otherwise: bb4];
+    // CHECK-NOT: switchInt
+    mir!(
+        let a: isize;
+        let b: isize;
+        {
+            a = Discriminant(e);
+            match Move(a) {
+                0 => bb1,
+                1 => bb2,
+                _ => bb5,
+            }
+        }
+        bb1 = {
+            RET = 0;
+            Return()
+        }
+        bb2 = {
+            RET = 1;
+            Return()
+        }
+        bb3 = {
+            RET = 2;
+            Return()
+        }
+        bb4 = {
+            RET = 3;
+            Return()
+        }
+        bb5 = {
+            b = Discriminant(e);
+            match Move(b) {
+                1 => bb6,
+                2 => bb3,
+                _ => bb4,
+            }
+        }
+        bb6 = {
+            RET = 4;
+            Return()
+        }
+    )
+}
+
+// EMIT_MIR merge_switch_chains.integer.MergeSwitchChains.diff
+#[custom_mir(dialect = "runtime")]
+pub fn integer(x: u8) -> usize {
+    // CHECK-LABEL: fn integer(
+    // CHECK: bb0: {
+    // CHECK: switchInt(_1) -> [1: bb1, 2: bb2, 3: bb3, otherwise: bb4];
+    // CHECK-NOT: switchInt
+    mir!(
+        {
+            match x {
+                1 => bb1,
+                2 => bb2,
+                _ => bb5,
+            }
+        }
+        bb1 = {
+            RET = 0;
+            Return()
+        }
+        bb2 = {
+            RET = 1;
+            Return()
+        }
+        bb3 = {
+            RET = 2;
+            Return()
+        }
+        bb4 = {
+            RET = 3;
+            Return()
+        }
+        bb5 = {
+            match x {
+                3 => bb3,
+                _ => bb4,
+            }
+        }
+    )
+}
+
+// EMIT_MIR merge_switch_chains.reassigned.MergeSwitchChains.diff
+#[custom_mir(dialect = "runtime")]
+pub fn reassigned(x: u8) -> usize {
+    // Check that the switches are not merged if the value is modified in between.
+    // CHECK-LABEL: fn reassigned(
+    // CHECK: switchInt(_1) -> [1: bb1, otherwise: bb3];
+    // CHECK: switchInt(_1) -> [2: bb2, otherwise: bb4];
+    mir!(
+        {
+            match x {
+                1 => bb1,
+                _ => bb3,
+            }
+        }
+        bb1 = {
+            RET = 0;
+            Return()
+        }
+        bb2 = {
+            RET = 1;
+            Return()
+        }
+        bb3 = {
+            x = 2;
+            match x {
+                2 => bb2,
+                _ => bb4,
+            }
+        }
+        bb4 = {
+            RET = 2;
+            Return()
+        }
+    )
+}
+
+fn main() {
+    assert_eq!(discriminant(E::A), 0);
+    assert_eq!(discriminant(E::C), 2);
+    assert_eq!(discriminant(E::D), 3);
+    assert_eq!(integer(3), 2);
+    assert_eq!(integer(4), 3);
+    assert_eq!(reassigned(3), 1);
+}
@@ -191,6 +191,27 @@ fn ref_copies(x: &Foo) {
     let u = y.c;
 }
 
+/// Check that arguments only used through their fields are destructured at the start of the
+/// function.
+fn arguments(x: Foo) -> u8 {
+    // CHECK-LABEL: fn arguments(
+
+    // CHECK: [[arg:_[0-9]+]]: Foo) ->
+    // CHECK: [[byte:_[0-9]+]]: u8;
+    // CHECK: [[unit:_[0-9]+]]: ();
+    // CHECK: [[str:_[0-9]+]]: &str;
+    // CHECK: [[opt_isize:_[0-9]+]]: std::option::Option<isize>;
+
+    // CHECK: bb0: {
+    // CHECK: [[byte]] = move ([[arg]].0: u8);
+    // CHECK: [[unit]] = move ([[arg]].1: ());
+    // CHECK: [[str]] = move ([[arg]].2: &str);
+    // CHECK: [[opt_isize]] = move ([[arg]].3: std::option::Option<isize>);
+    // CHECK-NOT: [[arg]]
+    // CHECK: _0 = [[byte]];
+    x.a
+}
+
 /// Check that deaggregated assignments from constants are placed after the constant's
 /// assignment. Also check that copying field accesses from the copy of the constant are
 /// reassigned to copy from the constant.
@@ -222,6 +243,7 @@ fn main() {
     escaping();
     copies(Foo { a: 5, b: (), c: "a", d: Some(-4) });
     ref_copies(&Foo { a: 5, b: (), c: "a", d: Some(-4) });
+    arguments(Foo { a: 5, b: (), c: "a", d: Some(-4) });
     constant();
 }
 
@@ -233,4 +255,5 @@ fn main() {
 // EMIT_MIR structs.escaping.ScalarReplacementOfAggregates.diff
 // EMIT_MIR structs.copies.ScalarReplacementOfAggregates.diff
 // EMIT_MIR structs.ref_copies.ScalarReplacementOfAggregates.diff
+// EMIT_MIR structs.arguments.ScalarReplacementOfAggregates.diff
 // EMIT_MIR structs.constant.ScalarReplacementOfAggregates.diff
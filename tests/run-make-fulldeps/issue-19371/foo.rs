@@ -59,6 +59,7 @@ fn compile(code: String, output: PathBuf, sysroot: PathBuf) {
         parse_sess_created: None,
         hash_untracked_state: None,
         register_lints: None,
+        register_mir_passes: None,
         override_queries: None,
         make_codegen_backend: None,
         registry: rustc_driver::diagnostics_registry(),
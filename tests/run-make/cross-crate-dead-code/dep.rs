@@ -0,0 +1,27 @@
+#![crate_type = "rlib"]
+
+mod inner {
+    pub fn used_by_main() {}
+
+    pub fn used_by_middle() {}
+
+    pub fn used_here() {}
+
+    pub fn unused() {}
+
+    pub struct Config {
+        pub verbose: bool,
+    }
+
+    impl Config {
+        pub fn new() -> Config {
+            Config { verbose: false }
+        }
+    }
+}
+
+pub use inner::{unused, used_by_main, used_by_middle, used_here, Config};
+
+pub fn api() {
+    used_here();
+}
@@ -0,0 +1,9 @@
+extern crate dep;
+extern crate middle;
+
+fn main() {
+    dep::used_by_main();
+    dep::api();
+    middle::middle();
+    let _ = dep::Config::new();
+}
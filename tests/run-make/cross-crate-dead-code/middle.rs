@@ -0,0 +1,7 @@
+#![crate_type = "rlib"]
+
+extern crate dep;
+
+pub fn middle() {
+    dep::used_by_middle();
+}
@@ -0,0 +1,3 @@
+pub fn first<'a>(x: &'a u32, _y: &u32) -> &'a u32 {
+    x
+}
@@ -0,0 +1,16 @@
+pub struct Point {
+    pub x: i32,
+    pub y: String,
+}
+
+pub fn consume(p: Point) -> impl FnOnce() -> String {
+    move || p.y
+}
+
+pub fn mutate(p: &mut Point) {
+    let mut c = || {
+        println!("{}", p.x);
+        p.x += 1;
+    };
+    c();
+}
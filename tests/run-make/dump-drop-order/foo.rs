@@ -0,0 +1,15 @@
+pub struct Noisy(pub &'static str);
+
+impl Drop for Noisy {
+    fn drop(&mut self) {}
+}
+
+pub fn make(name: &'static str) -> Noisy {
+    Noisy(name)
+}
+
+pub fn scopes() {
+    let a = make("a");
+    let b = String::new();
+    make("temporary").0.len();
+}
@@ -0,0 +1,9 @@
+pub trait Foo {}
+
+pub trait Bar {}
+impl<T: Foo> Bar for T {}
+impl<T> Bar for &T {}
+
+pub trait Baz {}
+impl<T: std::fmt::Display> Baz for T {}
+impl Baz for Vec<u8> {}
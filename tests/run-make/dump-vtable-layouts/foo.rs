@@ -0,0 +1,39 @@
+pub trait First {
+    fn first(&self) -> u8;
+}
+
+pub trait Second {
+    fn second(&self) -> u8;
+}
+
+pub trait Sub: First + Second {
+    fn sub(&self) -> u8;
+}
+
+pub struct Bar;
+
+impl First for Bar {
+    fn first(&self) -> u8 {
+        1
+    }
+}
+
+impl Second for Bar {
+    fn second(&self) -> u8 {
+        2
+    }
+}
+
+impl Sub for Bar {
+    fn sub(&self) -> u8 {
+        3
+    }
+}
+
+pub fn bar() -> &'static dyn Sub {
+    &Bar
+}
+
+pub fn closure(offset: u8) -> Box<dyn Fn(u8) -> u8> {
+    Box::new(move |x| x + offset)
+}
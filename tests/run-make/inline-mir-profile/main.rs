@@ -0,0 +1,25 @@
+#[no_mangle]
+pub fn cold_function(c: u8) -> u8 {
+    c.wrapping_add(1)
+}
+
+#[no_mangle]
+pub fn hot_function(c: u8) -> u8 {
+    c.wrapping_mul(3)
+}
+
+fn main() {
+    let arg = std::env::args().skip(1).next().unwrap();
+
+    let mut sum = 0u8;
+    for i in 0..100_000 {
+        let some_value = arg.as_bytes()[i % arg.len()];
+        if some_value == b'!' {
+            // This branch is never taken at runtime
+            sum = sum.wrapping_add(cold_function(some_value));
+        } else {
+            sum = sum.wrapping_add(hot_function(some_value));
+        }
+    }
+    println!("{sum}");
+}
@@ -0,0 +1,11 @@
+#![crate_type = "rlib"]
+
+extern crate upstream;
+
+pub fn first() -> String {
+    upstream::upstream_function("first")
+}
+
+pub fn greeting() -> *const &'static str {
+    &upstream::GREETING
+}
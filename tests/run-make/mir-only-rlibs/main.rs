@@ -0,0 +1,12 @@
+extern crate first;
+extern crate second;
+extern crate upstream;
+
+fn main() {
+    assert_eq!(upstream::upstream_function("world"), "hello, WORLD");
+    assert_eq!(upstream::upstream_generic(42), "hello, 42");
+    assert_eq!(first::first(), "hello, FIRST");
+    assert_eq!(second::second(), "hello, SECOND");
+    assert_eq!(first::greeting(), &upstream::GREETING as *const _);
+    assert_eq!(second::greeting(), &upstream::GREETING as *const _);
+}
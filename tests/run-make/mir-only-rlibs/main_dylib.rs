@@ -0,0 +1,8 @@
+extern crate shared;
+extern crate upstream;
+
+fn main() {
+    assert_eq!(upstream::upstream_function("world"), "hello, WORLD");
+    assert_eq!(shared::shared(), "hello, SHARED");
+    assert_eq!(shared::greeting(), &upstream::GREETING as *const _);
+}
@@ -0,0 +1,11 @@
+#![crate_type = "rlib"]
+
+extern crate upstream;
+
+pub fn second() -> String {
+    upstream::upstream_function("second")
+}
+
+pub fn greeting() -> *const &'static str {
+    &upstream::GREETING
+}
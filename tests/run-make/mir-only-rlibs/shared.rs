@@ -0,0 +1,11 @@
+#![crate_type = "dylib"]
+
+extern crate upstream;
+
+pub fn shared() -> String {
+    upstream::upstream_function("shared")
+}
+
+pub fn greeting() -> *const &'static str {
+    &upstream::GREETING
+}
@@ -0,0 +1,15 @@
+#![crate_type = "rlib"]
+
+pub static GREETING: &str = "hello";
+
+pub fn upstream_function(name: &str) -> String {
+    format!("{GREETING}, {}", private_function(name))
+}
+
+pub fn upstream_generic<T: std::fmt::Debug>(value: T) -> String {
+    upstream_function(&format!("{value:?}"))
+}
+
+fn private_function(name: &str) -> String {
+    name.to_uppercase()
+}
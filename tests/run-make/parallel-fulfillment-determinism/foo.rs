@@ -0,0 +1,23 @@
+// The obligations below are partitioned by the inference variables they use. Some partitions hold
+// without constraining their inference variables, others only once those are inferred, and others
+// fail.
+
+struct NotClone;
+
+fn clone<T: Clone>(_: T) {}
+fn copy<T: Copy>(_: T) {}
+
+fn main() {
+    let mut v = Vec::new();
+    clone(&v);
+    copy(String::new());
+    let mut w = None;
+    copy(w);
+    clone(NotClone);
+    v.push(1u8);
+    w = Some(2u16);
+    copy((v.len(), w));
+    clone(Some(NotClone));
+    clone((vec![v], [w; 4]));
+    copy(&mut 0u32);
+}
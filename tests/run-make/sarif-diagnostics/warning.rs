@@ -0,0 +1,4 @@
+pub fn foo() -> u32 {
+    let mut x = 1;
+    x
+}
@@ -0,0 +1,117 @@
+// run-pass
+// Test that tools can get an owned summary of the proof tree of a goal from the new solver.
+
+// ignore-stage1
+// ignore-cross-compile
+// ignore-remote
+// ignore-windows-gnu mingw has troubles with linking https://github.com/rust-lang/rust/pull/116837
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_infer;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_trait_selection;
+
+use std::io::Write;
+
+use rustc_driver::Compilation;
+use rustc_hir::def::DefKind;
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_interface::interface;
+use rustc_interface::Queries;
+use rustc_middle::traits::solve::Goal;
+use rustc_middle::ty::{self, Ty, TyCtxt};
+use rustc_trait_selection::solve::inspect::{CertaintySummary, GoalSummary, ProofTreeSummaryExt};
+
+struct Callbacks;
+
+impl rustc_driver::Callbacks for Callbacks {
+    fn after_analysis<'tcx>(
+        &mut self,
+        _compiler: &interface::Compiler,
+        queries: &'tcx Queries<'tcx>,
+    ) -> Compilation {
+        queries.global_ctxt().unwrap().enter(check_summaries);
+        Compilation::Stop
+    }
+}
+
+fn check_summaries(tcx: TyCtxt<'_>) {
+    let find = |kind, name| {
+        tcx.hir_crate_items(())
+            .definitions()
+            .map(|def_id| def_id.to_def_id())
+            .find(|&def_id| tcx.def_kind(def_id) == kind && tcx.item_name(def_id).as_str() == name)
+            .unwrap()
+    };
+    let trait_def_id = find(DefKind::Trait, "Trait");
+    let wrapper = tcx.adt_def(find(DefKind::Struct, "Wrapper"));
+    let local = Ty::new_adt(tcx, tcx.adt_def(find(DefKind::Struct, "Local")), ty::List::empty());
+
+    let summary = |ty: Ty<'_>| {
+        let self_ty = Ty::new_adt(tcx, wrapper, tcx.mk_args(&[ty.into()]));
+        let trait_ref = ty::TraitRef::new(tcx, trait_def_id, [self_ty]);
+        let infcx = tcx.infer_ctxt().with_next_trait_solver(true).build();
+        infcx.evaluate_goal_summary(Goal::new(tcx, ty::ParamEnv::empty(), trait_ref))
+    };
+    let impl_candidate_nested_goal = |summary: &GoalSummary, self_ty: &str| {
+        let candidate = summary
+            .candidates
+            .iter()
+            .find(|candidate| candidate.kind == "impl Trait for Wrapper<T>")
+            .unwrap();
+        assert_eq!(candidate.result, summary.result);
+        candidate
+            .nested_goals
+            .iter()
+            .find(|goal| {
+                goal.predicate.starts_with(&format!("{self_ty}: "))
+                    && goal.predicate.ends_with("Copy")
+            })
+            .unwrap()
+            .result
+    };
+
+    let holds = summary(tcx.types.u8);
+    assert_eq!(holds.predicate, "Wrapper<u8>: Trait");
+    assert_eq!(holds.result, CertaintySummary::Yes);
+    assert_eq!(impl_candidate_nested_goal(&holds, "u8"), CertaintySummary::Yes);
+
+    let fails = summary(local);
+    assert_eq!(fails.predicate, "Wrapper<Local>: Trait");
+    assert_eq!(fails.result, CertaintySummary::NoSolution);
+    assert_eq!(impl_candidate_nested_goal(&fails, "Local"), CertaintySummary::NoSolution);
+}
+
+fn main() {
+    let path = "proof_tree_summary_input.rs";
+    generate_input(&path).unwrap();
+    let args = vec![
+        "rustc".to_string(),
+        "--crate-type=lib".to_string(),
+        "--crate-name".to_string(),
+        "input".to_string(),
+        path.to_string(),
+    ];
+    rustc_driver::catch_fatal_errors(|| rustc_driver::RunCompiler::new(&args, &mut Callbacks).run())
+        .unwrap()
+        .unwrap();
+}
+
+fn generate_input(path: &str) -> std::io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    write!(
+        file,
+        r#"
+        pub trait Trait {{}}
+        pub struct Wrapper<T>(T);
+        pub struct Local;
+
+        impl<T: Copy> Trait for Wrapper<T> {{}}
+    "#
+    )?;
+    Ok(())
+}
@@ -0,0 +1,90 @@
+// run-pass
+//! Test that tail calls are lowered to stable MIR.
+
+// ignore-stage1
+// ignore-cross-compile
+// ignore-remote
+// ignore-windows-gnu mingw has troubles with linking https://github.com/rust-lang/rust/pull/116837
+// edition: 2021
+
+#![feature(rustc_private)]
+#![feature(assert_matches)]
+#![feature(control_flow_enum)]
+
+#[macro_use]
+extern crate rustc_smir;
+extern crate rustc_driver;
+extern crate rustc_interface;
+extern crate stable_mir;
+
+use mir::{mono::Instance, TerminatorKind::*};
+use rustc_smir::rustc_internal;
+use stable_mir::ty::{RigidTy, TyKind};
+use stable_mir::*;
+use std::io::Write;
+use std::ops::ControlFlow;
+
+const CRATE_NAME: &str = "input";
+
+/// This function uses the Stable MIR APIs to get information about the test crate.
+fn test_stable_mir() -> ControlFlow<()> {
+    let items = stable_mir::all_local_items();
+    let countdown = items.iter().find(|item| item.name() == "input::countdown").unwrap();
+    let body = Instance::try_from(*countdown).unwrap().body().unwrap();
+
+    let tail_calls: Vec<_> = body
+        .blocks
+        .iter()
+        .map(|bb| &bb.terminator)
+        .filter(|term| matches!(term.kind, TailCall { .. }))
+        .collect();
+    assert_eq!(tail_calls.len(), 1, "Expected one tail call");
+
+    let TailCall { func, args } = &tail_calls[0].kind else { unreachable!() };
+    assert_eq!(args.len(), 1);
+    let TyKind::RigidTy(RigidTy::FnDef(def, _)) = func.ty(body.locals()).unwrap().kind() else {
+        unreachable!()
+    };
+    assert_eq!(def.name(), "input::countdown");
+    assert!(tail_calls[0].successors().is_empty());
+    assert!(tail_calls[0].kind.unwind().is_none());
+
+    let mut pretty = Vec::new();
+    body.dump(&mut pretty).unwrap();
+    assert!(String::from_utf8(pretty).unwrap().contains("become "));
+    ControlFlow::Continue(())
+}
+
+/// This test will generate and analyze a dummy crate using the stable mir.
+/// For that, it will first write the dummy crate into a file.
+/// Then it will create a `StableMir` using custom arguments and then
+/// it will run the compiler.
+fn main() {
+    let path = "tail_calls_input.rs";
+    generate_input(&path).unwrap();
+    let args = vec![
+        "rustc".to_string(),
+        "-Cpanic=abort".to_string(),
+        "--crate-type=lib".to_string(),
+        "--crate-name".to_string(),
+        CRATE_NAME.to_string(),
+        path.to_string(),
+    ];
+    run!(args, test_stable_mir).unwrap();
+}
+
+fn generate_input(path: &str) -> std::io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    write!(
+        file,
+        r#"
+    #![feature(explicit_tail_calls)]
+    #![allow(incomplete_features)]
+
+    pub fn countdown(n: u32) -> u32 {{
+        if n == 0 {{ 0 }} else {{ become countdown(n - 1) }}
+    }}
+    "#
+    )?;
+    Ok(())
+}
@@ -0,0 +1,9 @@
+#![feature(associated_type_bounds)]
+
+pub trait Bar: Super<SuperAssoc: Bound> {}
+
+pub trait Super {
+    type SuperAssoc;
+}
+
+pub trait Bound {}
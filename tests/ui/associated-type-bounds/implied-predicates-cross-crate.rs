@@ -0,0 +1,16 @@
+//@ aux-build:implied-predicates.rs
+//@ check-pass
+
+// Make sure that the associated type bounds on a supertrait are implied
+// by the trait when it is used from another crate.
+
+extern crate implied_predicates;
+use implied_predicates::Bar;
+
+fn assert_bound<T: implied_predicates::Bound>() {}
+
+fn foo<T: Bar>() {
+    assert_bound::<T::SuperAssoc>();
+}
+
+fn main() {}
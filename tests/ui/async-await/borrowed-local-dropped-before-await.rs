@@ -0,0 +1,33 @@
+//@ edition:2021
+//@ check-pass
+//@ compile-flags: --crate-type lib
+
+// Values which have been moved out of before an await are not held across it,
+// even though they are dropped at the end of their scope.
+
+use std::sync::Mutex;
+
+async fn fut() {}
+
+async fn guard_dropped_before_await(m: &Mutex<u32>) {
+    let guard = m.lock().unwrap();
+    drop(guard);
+    fut().await;
+}
+
+async fn guard_moved_on_all_paths(m: &Mutex<u32>, flag: bool) {
+    let guard = m.lock().unwrap();
+    if flag {
+        drop(guard);
+    } else {
+        std::mem::forget(guard);
+    }
+    fut().await;
+}
+
+fn assert_send(_: impl Send) {}
+
+pub fn pass_assert(m: &Mutex<u32>) {
+    assert_send(guard_dropped_before_await(m));
+    assert_send(guard_moved_on_all_paths(m, true));
+}
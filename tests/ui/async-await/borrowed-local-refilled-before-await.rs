@@ -0,0 +1,24 @@
+//@ edition:2021
+//@ compile-flags: --crate-type lib
+
+// A borrowed value which has been moved out of before an await may have been
+// put back through a pointer, so it is still considered held across the await.
+
+use std::sync::{Mutex, MutexGuard};
+
+async fn fut() {}
+
+async fn guard_refilled_through_pointer(m: &Mutex<u32>) {
+    let mut guard = m.lock().unwrap();
+    let ptr: *mut MutexGuard<'_, u32> = &mut guard;
+    let moved = guard;
+    unsafe { ptr.write(moved) };
+    fut().await;
+}
+
+fn assert_send(_: impl Send) {}
+
+pub fn fail_assert(m: &Mutex<u32>) {
+    assert_send(guard_refilled_through_pointer(m));
+    //~^ ERROR future cannot be sent between threads safely
+}
@@ -10,7 +10,9 @@ async fn use_future(fut: impl std::future::Future<Output = ()>) {
 fn main() {
     let actual = std::mem::size_of_val(
         &use_future(use_future(use_future(use_future(use_future(test([0; 16])))))));
+    // The future passed to `use_future` only overlaps with the future it awaits, instead of being
+    // stored next to it, so each level only adds its discriminant.
     // Not using an exact number in case it slightly changes over different commits
-    let expected = 550;
-    assert!(actual > expected, "expected: >{expected}, actual: {actual}");
+    let expected = 50;
+    assert!(actual < expected, "expected: <{expected}, actual: {actual}");
 }
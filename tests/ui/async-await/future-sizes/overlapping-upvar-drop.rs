@@ -0,0 +1,83 @@
+//@ edition: 2021
+//@ run-pass
+#![feature(noop_waker)]
+
+// Checks that arguments which overlap with the locals of the later states of an `async fn` are
+// dropped exactly once, whichever state the future is dropped in.
+
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll, Waker};
+
+static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+struct Counted([u8; 64]);
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        DROPS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            Poll::Pending
+        }
+    }
+}
+
+async fn held_across_awaits(arg: Counted) {
+    YieldOnce(false).await;
+    assert_eq!(arg.0, [7; 64]);
+    YieldOnce(false).await;
+}
+
+async fn dropped_before_await(arg: Counted) {
+    drop(arg);
+    let buf = [1u8; 64];
+    YieldOnce(false).await;
+    assert_eq!(buf, [1; 64]);
+}
+
+async fn moved_into_awaitee(arg: Counted) {
+    async move {
+        YieldOnce(false).await;
+        assert_eq!(arg.0, [7; 64]);
+    }
+    .await;
+    let buf = [2u8; 64];
+    YieldOnce(false).await;
+    assert_eq!(buf, [2; 64]);
+}
+
+fn check<F: Future<Output = ()>>(name: &str, make: impl Fn(Counted) -> F) {
+    // Drop the future before it is polled, in each of its suspended states, and once it is done.
+    for polls in 0..4 {
+        DROPS.store(0, Ordering::SeqCst);
+        {
+            let mut fut = pin!(make(Counted([7; 64])));
+            let mut cx = Context::from_waker(Waker::noop());
+            for _ in 0..polls {
+                if fut.as_mut().poll(&mut cx).is_ready() {
+                    break;
+                }
+            }
+        }
+        assert_eq!(DROPS.load(Ordering::SeqCst), 1, "{name} after {polls} polls");
+    }
+}
+
+fn main() {
+    check("held_across_awaits", held_across_awaits);
+    check("dropped_before_await", dropped_before_await);
+    check("moved_into_awaitee", moved_into_awaitee);
+}
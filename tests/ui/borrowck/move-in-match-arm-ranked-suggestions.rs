@@ -0,0 +1,30 @@
+// Check that when a value is moved in one arm of a `match` and used after it, the ways to avoid
+// the move are suggested from the cheapest to the most expensive one.
+
+use std::rc::Rc;
+
+#[derive(Clone)]
+enum Shape {
+    Named(String),
+    Point(i32, i32),
+    Rect(i32, i32, i32, i32),
+}
+
+fn small_value(token: Option<Rc<str>>) {
+    match token {
+        Some(s) => drop(s),
+        None => {}
+    }
+    drop(token); //~ ERROR use of partially moved value: `token`
+}
+
+fn many_other_bindings(shape: Shape) {
+    match shape {
+        Shape::Named(name) => drop(name),
+        Shape::Point(x, y) => drop((x, y)),
+        Shape::Rect(x, y, w, h) => drop((x, y, w, h)),
+    }
+    drop(shape); //~ ERROR use of partially moved value: `shape`
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+// Tests that `#[rustc_pure_deref]` is only accepted on structs, and that conflicting borrows
+// of the same place through a pure deref are still errors.
+
+#![feature(deref_patterns_borrowck)]
+
+use std::ops::{Deref, DerefMut};
+
+struct State {
+    a: u32,
+    b: u32,
+}
+
+#[rustc_pure_deref]
+struct Guard<'a, T> {
+    value: &'a mut T,
+}
+
+impl<T> Deref for Guard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T> DerefMut for Guard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value
+    }
+}
+
+#[rustc_pure_deref] //~ ERROR `#[rustc_pure_deref]` should be applied to a struct
+fn not_a_struct() {}
+
+fn same_field(guard: &mut Guard<'_, State>) {
+    let a = &mut guard.a;
+    let b = &mut guard.a; //~ ERROR cannot borrow `guard.value.a` as mutable more than once at a time
+    use_mut(a, b);
+}
+
+fn disjoint_fields(guard: &mut Guard<'_, State>) {
+    let a = &mut guard.a;
+    let b = &mut guard.b;
+    use_mut(a, b);
+}
+
+fn use_mut(_: &mut u32, _: &mut u32) {}
+
+fn main() {}
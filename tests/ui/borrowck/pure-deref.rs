@@ -0,0 +1,74 @@
+//@ run-pass
+
+// Tests that borrowck sees through the `Deref` and `DerefMut` impls of `#[rustc_pure_deref]`
+// smart pointers, so that disjoint fields of their target can be borrowed at the same time.
+
+#![feature(deref_patterns_borrowck)]
+
+use std::ops::{Deref, DerefMut};
+
+struct State {
+    items: Vec<u32>,
+    total: u32,
+}
+
+#[rustc_pure_deref]
+struct Guard<'a, T> {
+    value: &'a mut T,
+    generation: u32,
+}
+
+impl<T> Deref for Guard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T> DerefMut for Guard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value
+    }
+}
+
+#[rustc_pure_deref]
+struct Owned<T>(Box<T>);
+
+impl<T> Deref for Owned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Owned<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+fn sum(guard: &mut Guard<'_, State>) {
+    let items = &guard.items;
+    let total = &mut guard.total;
+    for item in items {
+        *total += item;
+    }
+}
+
+fn main() {
+    let mut state = State { items: vec![1, 2, 3], total: 0 };
+    let mut guard = Guard { value: &mut state, generation: 0 };
+    sum(&mut guard);
+    guard.generation += 1;
+    assert_eq!(guard.total, 6);
+    assert_eq!(guard.generation, 1);
+
+    let mut owned = Owned(Box::new(State { items: vec![4], total: 0 }));
+    let items = &mut owned.items;
+    let total = &mut owned.total;
+    items.push(5);
+    *total = items.iter().sum();
+    assert_eq!(owned.total, 9);
+}
@@ -0,0 +1,12 @@
+//@ run-rustfix
+
+fn main() {
+    let mut v = vec![1, 2, 3];
+    v.push(v.pop().unwrap());
+    //~^ ERROR cannot borrow `v` as mutable more than once at a time
+}
+
+fn arg_is_taken(mut v: Vec<usize>, arg: usize) {
+    v.insert(arg, v.pop().unwrap());
+    //~^ ERROR cannot borrow `v` as mutable more than once at a time
+}
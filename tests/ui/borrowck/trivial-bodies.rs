@@ -0,0 +1,25 @@
+// Bodies without borrows, drops or generics skip most of borrowck. Check that the errors they
+// can still contain are reported.
+
+struct NoDrop(u32);
+
+fn use_after_move(x: NoDrop) -> (NoDrop, NoDrop) {
+    (x, x) //~ ERROR use of moved value: `x`
+}
+
+fn assign_twice() -> u32 {
+    let x;
+    x = 1;
+    x = 2; //~ ERROR cannot assign twice to immutable variable `x`
+    x
+}
+
+fn possibly_uninit(c: bool) -> u32 {
+    let x;
+    if c {
+        x = 1;
+    }
+    x //~ ERROR used binding `x` is possibly-uninitialized
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+// Tests that borrows restricted to the view of a method still conflict with accesses to
+// the fields in the view.
+
+#![feature(view_types)]
+
+struct Ui {
+    widgets: Vec<u32>,
+    log: Vec<String>,
+}
+
+impl Ui {
+    #[view(widgets)]
+    fn widgets_mut(&mut self) -> &mut Vec<u32> {
+        &mut self.widgets
+    }
+
+    #[view(widgets)]
+    fn first_widget(&self) -> Option<&u32> {
+        self.widgets.first()
+    }
+}
+
+fn main() {
+    let mut ui = Ui { widgets: vec![], log: vec![] };
+
+    let widgets = ui.widgets_mut();
+    ui.log.push(String::new());
+    ui.first_widget(); //~ ERROR cannot borrow `ui` as immutable because it is also borrowed as mutable
+    ui.widgets.clear(); //~ ERROR cannot borrow `ui.widgets` as mutable more than once at a time
+    widgets.push(0);
+}
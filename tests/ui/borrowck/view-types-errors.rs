@@ -0,0 +1,32 @@
+// Tests the errors for invalid uses of `#[view]` and for methods using `self` outside of
+// their view.
+
+#![feature(view_types)]
+
+struct Ui {
+    widgets: Vec<u32>,
+    log: Vec<String>,
+}
+
+impl Ui {
+    #[view(log)]
+    fn log(&mut self, msg: &str) {
+        self.widgets.clear(); //~ ERROR field `widgets` is not part of the view of `self`
+        self.log.push(msg.to_string());
+        self.helper(); //~ ERROR `self` can only be used to access the fields listed in `#[view]`
+    }
+
+    fn helper(&self) {}
+
+    #[view(missing)] //~ ERROR no field `missing` on type `Ui`
+    fn missing(&self) {}
+
+    #[view(log)]
+    //~^ ERROR `#[view]` can only be applied to methods taking `self` by reference to a struct
+    fn by_value(self) {}
+}
+
+#[view(log)] //~ ERROR `#[view]` should be applied to an inherent method
+fn free_function(_: &mut Ui) {}
+
+fn main() {}
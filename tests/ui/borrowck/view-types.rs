@@ -0,0 +1,51 @@
+//@ run-pass
+
+// Tests that methods declaring the fields of `self` they borrow with `#[view]` can be
+// called while other fields of the receiver are borrowed.
+
+#![feature(view_types)]
+
+struct Ui {
+    widgets: Vec<u32>,
+    log: Vec<String>,
+    frame: u64,
+}
+
+impl Ui {
+    #[view(widgets)]
+    fn widgets_mut(&mut self) -> &mut Vec<u32> {
+        &mut self.widgets
+    }
+
+    #[view(log, frame)]
+    fn log(&mut self, msg: &str) {
+        self.log.push(format!("{}: {msg}", self.frame));
+    }
+
+    #[view(frame)]
+    fn frame(&self) -> u64 {
+        (*self).frame
+    }
+
+    fn update(&mut self) {
+        let widgets = self.widgets_mut();
+        self.log("update");
+        widgets.push(1);
+        self.frame += 1;
+    }
+}
+
+fn main() {
+    let mut ui = Ui { widgets: vec![], log: vec![], frame: 0 };
+
+    let widgets = ui.widgets_mut();
+    ui.log("start");
+    let frame = ui.frame();
+    widgets.push(0);
+
+    ui.update();
+    assert_eq!(frame, 0);
+    assert_eq!(ui.widgets, [0, 1]);
+    assert_eq!(ui.log, ["0: start", "0: update"]);
+    assert_eq!(ui.frame(), 1);
+}
@@ -0,0 +1,21 @@
+// Check the suggestions for building a fat pointer out of a thin one (E0607).
+
+trait Foo {}
+impl Foo for u8 {}
+
+fn main() {
+    let p = core::ptr::null::<u8>();
+    let _ = p as *const [u8];
+    //~^ ERROR cannot cast thin pointer `*const u8` to fat pointer `*const [u8]`
+
+    let m = core::ptr::null_mut::<u8>();
+    let _ = m as *mut [u8];
+    //~^ ERROR cannot cast thin pointer `*mut u8` to fat pointer `*mut [u8]`
+
+    let q = core::ptr::null::<()>();
+    let _ = q as *const [u16];
+    //~^ ERROR cannot cast thin pointer `*const ()` to fat pointer `*const [u16]`
+
+    let _ = p as *mut dyn Foo;
+    //~^ ERROR cannot cast thin pointer `*const u8` to fat pointer `*mut dyn Foo`
+}
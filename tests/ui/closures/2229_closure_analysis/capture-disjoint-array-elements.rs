@@ -0,0 +1,30 @@
+//@ edition:2021
+#![feature(rustc_attrs)]
+#![feature(capture_disjoint_array_elements)]
+
+// Ensure that array elements indexed by integer literals are captured disjointly
+// when `capture_disjoint_array_elements` is enabled.
+fn main() {
+    let mut m = [1, 2, 3, 4, 5];
+
+    let mut c = #[rustc_capture_analysis]
+    //~^ ERROR: attributes on expressions are experimental
+    //~| NOTE: see issue #15701 <https://github.com/rust-lang/rust/issues/15701>
+    //~| NOTE: this compiler was built on YYYY-MM-DD; consider upgrading it if it is out of date
+    || {
+    //~^ ERROR: First Pass analysis includes:
+    //~| ERROR: Min Capture analysis includes:
+        m[0] += 10;
+        //~^ NOTE: Capturing m[ConstantIndex(0)] -> MutBorrow
+        //~| NOTE: Min Capture m[ConstantIndex(0)] -> MutBorrow
+        m[1] += 40;
+        //~^ NOTE: Capturing m[ConstantIndex(1)] -> MutBorrow
+        //~| NOTE: Min Capture m[ConstantIndex(1)] -> MutBorrow
+    };
+
+    // `c` only captures `m[0]` and `m[1]`, therefore another closure can mutate `m[2]`.
+    let mut c2 = || m[2] += 1;
+
+    c();
+    c2();
+}
@@ -0,0 +1,15 @@
+//@ edition:2021
+
+// Test that array elements indexed by integer literals are only captured
+// disjointly when `capture_disjoint_array_elements` is enabled.
+
+fn main() {
+    let mut arr = [1, 2];
+
+    let mut c1 = || arr[0] += 1;
+    let mut c2 = || arr[1] += 1;
+    //~^ ERROR: cannot borrow `arr` as mutable more than once at a time
+
+    c1();
+    c2();
+}
@@ -0,0 +1,25 @@
+//@ edition:2021
+//@ run-pass
+
+// Tests that closures using different elements of the same array, indexed by
+// integer literals, can be used at the same time.
+
+#![feature(capture_disjoint_array_elements)]
+
+fn main() {
+    let mut arr = [String::from("a"), String::from("b"), String::from("c")];
+
+    let mut c1 = || arr[0].push('1');
+    let mut c2 = || arr[1].push('2');
+    let c3 = || arr[2].len();
+
+    c1();
+    c2();
+    assert_eq!(c3(), 1);
+    assert_eq!(arr, ["a1", "b2", "c"]);
+
+    // Non-`Copy` elements can't be moved out of the array, so the whole array is moved.
+    let strings = [String::from("x"), String::from("y")];
+    let c = move || strings[1].clone();
+    assert_eq!(c(), "y");
+}
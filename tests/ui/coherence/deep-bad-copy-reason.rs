@@ -9,6 +9,7 @@ pub struct ListS<T> {
     len: usize,
     data: [T; 0],
     opaque: OpaqueListContents,
+    //~^ NOTE: this field has type `OpaqueListContents`
 }
 
 pub struct Interned<'a, T>(&'a T);
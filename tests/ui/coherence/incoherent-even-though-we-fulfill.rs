@@ -14,9 +14,11 @@ trait Foo {}
 // added upstream.
 impl<T> Foo for T where (): Mirror<Assoc = T> {}
 //~^ NOTE first implementation here
+//~| NOTE `T` unifies with `()`
 impl<T> Foo for T where T: Iterator {}
 //~^ ERROR conflicting implementations of trait `Foo` for type `()`
 //~| NOTE conflicting implementation for `()`
+//~| NOTE `T` unifies with `()`
 //~| NOTE upstream crates may add a new impl of trait `std::iter::Iterator` for type `()` in future versions
 
 fn main() {}
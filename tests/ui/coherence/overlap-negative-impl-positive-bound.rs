@@ -0,0 +1,17 @@
+// Check that we don't suggest a negative impl for a local type which already
+// has a positive impl of the same trait, as that would conflict with it.
+
+#![feature(negative_impls, with_negative_coherence)]
+
+trait Bound {}
+
+trait Foo {}
+struct Local;
+
+impl Bound for Local {}
+
+impl<T: Bound> Foo for T {}
+impl Foo for Local {}
+//~^ ERROR conflicting implementations of trait `Foo` for type `Local`
+
+fn main() {}
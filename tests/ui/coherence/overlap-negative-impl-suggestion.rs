@@ -0,0 +1,18 @@
+// Check that we suggest a negative impl to make overlapping impls
+// disjoint when coherence is able to rely on negative impls.
+
+//@ run-rustfix
+
+#![feature(negative_impls, with_negative_coherence, auto_traits)]
+#![allow(dead_code)]
+
+auto trait Bound {}
+
+trait Foo {}
+struct Local;
+
+impl<T: Bound> Foo for T {}
+impl Foo for Local {}
+//~^ ERROR conflicting implementations of trait `Foo` for type `Local`
+
+fn main() {}
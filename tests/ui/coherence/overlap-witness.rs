@@ -0,0 +1,14 @@
+// Check that we point out how the generic parameters of the overlapping
+// impls are instantiated for them to overlap.
+
+trait Foo {}
+impl<T: Copy> Foo for T {}
+impl Foo for u8 {}
+//~^ ERROR conflicting implementations of trait `Foo` for type `u8`
+
+trait Bar<A> {}
+impl<T, const N: usize> Bar<[T; N]> for T {}
+impl<U: Copy> Bar<[Option<U>; 3]> for Option<U> {}
+//~^ ERROR conflicting implementations of trait `Bar<[Option<_>; 3]>` for type `Option<_>`
+
+fn main() {}
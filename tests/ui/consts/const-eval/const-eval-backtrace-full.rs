@@ -0,0 +1,17 @@
+//@ compile-flags: -Z const-eval-backtrace=full
+#![crate_type = "lib"]
+
+// With `-Z const-eval-backtrace=full`, the frames of `#[track_caller]` functions are part of the
+// backtrace, and repeated frames aren't collapsed.
+
+#[track_caller]
+const fn div(x: u32) -> u32 {
+    1 / x
+}
+
+const fn countdown(n: u32) -> u32 {
+    if n == 0 { div(n) } else { countdown(n - 1) }
+    //~^ ERROR evaluation of constant value failed
+}
+
+const X: u32 = countdown(3);
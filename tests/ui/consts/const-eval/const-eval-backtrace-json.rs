@@ -0,0 +1,11 @@
+//@ compile-flags: --error-format pretty-json -Zunstable-options
+//@ error-pattern: evaluation of constant value failed
+#![crate_type = "lib"]
+
+// The notes showing the const-eval backtrace describe their frame in the JSON output.
+
+const fn div(x: u32) -> u32 {
+    1 / x
+}
+
+const X: u32 = div(0);
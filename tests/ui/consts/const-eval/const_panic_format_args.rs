@@ -0,0 +1,31 @@
+//@ edition:2021
+// Panic messages with `Display` and `Debug` arguments are formatted at compile time.
+#![crate_type = "lib"]
+
+const X: i32 = 5;
+
+const A: () = panic!("x = {X}");
+//~^ ERROR evaluation of constant value failed
+
+const B: () = {
+    let x = -3i8;
+    let b = true;
+    panic!("{x} {b} {:?} {:?} {}", 'c', "s\n", 1.5f32)
+    //~^ ERROR evaluation of constant value failed
+};
+
+const fn check(n: u32) -> u32 {
+    if n > 10 {
+        panic!("{n} is too large");
+        //~^ ERROR evaluation of constant value failed
+    }
+    n
+}
+
+const D: u32 = check(11);
+
+const E: () = panic!("{0} and {0}", X);
+//~^ ERROR evaluation of constant value failed
+
+const F: () = panic!("{X:>4}");
+//~^ ERROR evaluation of constant value failed
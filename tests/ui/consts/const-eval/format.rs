@@ -1,13 +1,17 @@
 const fn failure() {
+    // `Debug` and `Display` arguments can be formatted in const panic messages.
     panic!("{:?}", 0);
+}
+
+const fn unsupported_trait() {
+    panic!("{:x}", 0);
     //~^ ERROR cannot call non-const formatting macro in constant functions
-    //~| ERROR cannot call non-const fn `Arguments::<'_>::new_v1` in constant functions
 }
 
 const fn print() {
     println!("{:?}", 0);
-    //~^ ERROR cannot call non-const formatting macro in constant functions
-    //~| ERROR cannot call non-const fn `Arguments::<'_>::new_v1` in constant functions
+    //~^ ERROR `Argument::<'a>::new_debug` is not yet stable as a const fn
+    //~| ERROR `Arguments::<'a>::new_v1` is not yet stable as a const fn
     //~| ERROR cannot call non-const fn `_print` in constant functions
 }
 
@@ -0,0 +1,23 @@
+//@ run-pass
+#![feature(const_heap)]
+#![feature(const_box)]
+#![feature(const_mut_refs)]
+
+// Boxes created during const eval can be dropped again, which frees their allocation...
+const fn boxed_sum(a: i32, b: i32) -> i32 {
+    let a = Box::new(a);
+    let b = Box::new(b);
+    *a + *b
+}
+
+const SUM: i32 = boxed_sum(20, 22);
+
+// ... or leaked into the final value, as long as only shared references point to them.
+const LEAKED: &i32 = &*Box::leak(Box::new(5));
+const LEAKED_ARRAY: &[u8; 3] = &*Box::leak(Box::new([1, 2, 3]));
+
+fn main() {
+    assert_eq!(SUM, 42);
+    assert_eq!(*LEAKED, 5);
+    assert_eq!(LEAKED_ARRAY, &[1, 2, 3]);
+}
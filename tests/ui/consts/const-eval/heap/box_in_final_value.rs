@@ -0,0 +1,12 @@
+#![feature(const_heap)]
+#![feature(const_box)]
+#![feature(const_mut_refs)]
+
+const BOX: Box<i32> = Box::new(5);
+//~^ error: mutable pointer in final value of constant
+
+// Leaking does not help if the leaked memory can still be mutated.
+const LEAKED_PTR: *mut i32 = Box::leak(Box::new(5));
+//~^ error: mutable pointer in final value of constant
+
+fn main() {}
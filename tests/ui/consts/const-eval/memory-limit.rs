@@ -0,0 +1,22 @@
+//@ compile-flags: -Z const-eval-memory-limit=1024
+// Checks that `-Z const-eval-memory-limit` aborts the evaluation of an item once its live
+// allocations grow too big, and that allocations which were freed don't count towards it.
+
+#![allow(unused)]
+
+const fn small(n: usize) -> u8 {
+    let bytes = [1u8; 512];
+    bytes[n]
+}
+
+const SMALL: u8 = small(0) + small(1) + small(2);
+
+const fn big() -> u8 {
+    let bytes = [1u8; 2048];
+    //~^ ERROR evaluation of constant value failed
+    bytes[0]
+}
+
+const BIG: u8 = big();
+
+fn main() {}
@@ -0,0 +1,22 @@
+//! The bits of NaNs produced during const eval are deterministic, but they may differ from what
+//! the same operation produces at runtime, which the `const_eval_nan_result` lint points out.
+#![feature(const_float_bits_conv)]
+#![deny(const_eval_nan_result)]
+
+const ZERO_BY_ZERO: f32 = 0.0 / 0.0;
+//~^ ERROR this floating-point operation produces a NaN during const evaluation
+
+const INF_MINUS_INF: f64 = f64::INFINITY - f64::INFINITY;
+//~^ ERROR this floating-point operation produces a NaN during const evaluation
+
+// NaN inputs lose their payload.
+const PROPAGATED: f64 = f64::from_bits(0x7ff8_0000_0000_0001) + 1.0;
+//~^ ERROR this floating-point operation produces a NaN during const evaluation
+
+// Results that are not NaN do not depend on where they are computed.
+const FINE: f64 = 0.1 + 0.2;
+
+#[allow(const_eval_nan_result)]
+const ALLOWED: f32 = 0.0 / 0.0;
+
+fn main() {}
@@ -0,0 +1,38 @@
+//@ compile-flags: -Zshare-const-fn-calls -Zconst-eval-step-limit=5 -Zconst-eval-memory-limit=1024
+// Checks that calls of `const fn`s that would otherwise be shared between constants are evaluated
+// in place if `-Z const-eval-step-limit` or `-Z const-eval-memory-limit` is set, so that their
+// steps and allocations count towards the limits of the constant making the call.
+
+#![allow(unused, long_running_const_eval)]
+
+const fn id(x: u32) -> u32 {
+    x
+}
+
+const fn call_id() -> u32 {
+    id(0);
+    id(1);
+    id(2);
+    id(3);
+    id(4); //~ ERROR evaluation of constant value failed
+    0
+}
+
+const STEPS: u32 = call_id();
+
+const fn half() -> u8 {
+    let bytes = [2u8; 600];
+    //~^ ERROR evaluation of constant value failed
+    let [first, ..] = bytes;
+    first
+}
+
+const fn both() -> u8 {
+    let bytes = [1u8; 600];
+    let [first, ..] = bytes;
+    first ^ half()
+}
+
+const MEMORY: u8 = both();
+
+fn main() {}
@@ -0,0 +1,88 @@
+//@ run-pass
+//@ compile-flags: -Z share-const-fn-calls
+// Calls of `const fn`s with plain-data arguments and return values are evaluated once and shared
+// between all constants making the same call. Check that this gives the same results as
+// evaluating every call in place.
+
+#![feature(const_caller_location, const_location_fields)]
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Shape {
+    Square(u32),
+    Rect { w: u32, h: u32 },
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Stats {
+    area: u64,
+    is_square: bool,
+}
+
+const fn crc_table(poly: u32) -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ poly } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+// Recursive calls are evaluated in place while evaluating the outermost call.
+const fn fib(n: u64) -> u64 {
+    if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+}
+
+const fn stats(shape: Shape) -> Stats {
+    match shape {
+        Shape::Square(s) => Stats { area: s as u64 * s as u64, is_square: true },
+        Shape::Rect { w, h } => Stats { area: w as u64 * h as u64, is_square: w == h },
+    }
+}
+
+// Calls with references are evaluated in place.
+const fn sum(values: &[u32]) -> u32 {
+    let mut sum: u32 = 0;
+    let mut i = 0;
+    while i < values.len() {
+        sum = sum.wrapping_add(values[i]);
+        i += 1;
+    }
+    sum
+}
+
+// Calls that may observe their caller are evaluated in place.
+#[track_caller]
+const fn line() -> u32 {
+    std::panic::Location::caller().line()
+}
+
+const CRC_A: [u32; 256] = crc_table(0xEDB88320);
+const CRC_B: u32 = crc_table(0xEDB88320)[1];
+const CRC_C: u32 = crc_table(0x82F63B78)[1];
+const FIB: (u64, u64) = (fib(20), fib(20));
+const SQUARE: Stats = stats(Shape::Square(3));
+const RECT: Stats = stats(Shape::Rect { w: 2, h: 5 });
+const SUM: u32 = sum(&[1, 2, 3]) + sum(&CRC_A);
+const LINES: (u32, u32) = (
+    line(),
+    line(),
+);
+
+fn main() {
+    assert_eq!(CRC_A[1], 0x77073096);
+    assert_eq!(CRC_B, 0x77073096);
+    assert_eq!(CRC_C, 0xF26B8303);
+    assert_eq!(CRC_A, crc_table(0xEDB88320));
+    assert_eq!(FIB, (6765, 6765));
+    assert_eq!(SQUARE, Stats { area: 9, is_square: true });
+    assert_eq!(RECT, Stats { area: 10, is_square: false });
+    assert_eq!(SUM, 6u32.wrapping_add(CRC_A.iter().fold(0u32, |a, b| a.wrapping_add(*b))));
+    assert_eq!(LINES.0 + 1, LINES.1);
+}
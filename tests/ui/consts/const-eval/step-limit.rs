@@ -0,0 +1,20 @@
+//@ compile-flags: -Z const-eval-step-limit=5
+// Checks that `-Z const-eval-step-limit` aborts the evaluation of an item with a hard error,
+// even if the `long_running_const_eval` lint is allowed.
+
+#![allow(unused, long_running_const_eval)]
+
+const fn foo() {}
+
+const fn call_foo() -> u32 {
+    foo();
+    foo();
+    foo();
+    foo();
+    foo(); //~ ERROR evaluation of constant value failed
+    0
+}
+
+const X: u32 = call_foo();
+
+fn main() {}
@@ -0,0 +1,14 @@
+//@ compile-flags: -Zconst-eval-ub-report
+// Check that `-Zconst-eval-ub-report` describes where an invalid value is stored.
+use std::mem;
+
+#[repr(C)]
+struct Flags {
+    id: u32,
+    flags: [bool; 4],
+}
+
+const BAD_FLAG: Flags = unsafe { mem::transmute([1u8, 0, 0, 0, 1, 0, 3, 1]) };
+//~^ ERROR it is undefined behavior to use this value
+
+fn main() {}
@@ -0,0 +1,40 @@
+//@ run-pass
+//@ compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(const_heap, const_trait_impl, dyn_star, unsized_locals)]
+#![allow(incomplete_features)]
+
+// Check that CTFE supports unsized locals and by-value method calls on `dyn Trait` and `dyn*`.
+
+#[const_trait]
+trait Foo {
+    fn foo(self) -> usize;
+}
+
+struct A(usize);
+
+impl const Foo for A {
+    fn foo(self) -> usize {
+        self.0
+    }
+}
+
+impl const Foo for usize {
+    fn foo(self) -> usize {
+        self
+    }
+}
+
+const UNSIZED_LOCAL: usize = {
+    let x = *(Box::new(A(42)) as Box<dyn Foo>);
+    x.foo()
+};
+
+const DYN_STAR: usize = {
+    let x: dyn* Foo = 42usize;
+    x.foo()
+};
+
+fn main() {
+    assert_eq!(UNSIZED_LOCAL, 42);
+    assert_eq!(DYN_STAR, 42);
+}
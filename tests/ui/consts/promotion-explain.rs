@@ -0,0 +1,21 @@
+//@ compile-flags: -Zexplain-promotion
+//@ check-pass
+// Check the explanations `-Zexplain-promotion` gives for promoting borrows, or not.
+#![crate_type = "lib"]
+
+pub fn promoted() -> &'static i32 {
+    &42
+}
+
+pub fn argument(x: i32) -> i32 {
+    let r = &x;
+    *r
+}
+
+const fn make() -> i32 {
+    1
+}
+
+pub fn call() {
+    let _r = &[make()];
+}
@@ -0,0 +1,22 @@
+// Check that an unsatisfied bound of a derived impl points at the field
+// it was derived for, rather than only at the `derive` attribute.
+
+struct NotClone;
+
+#[derive(Clone)]
+struct Wrapper<T, U> {
+    a: T,
+    b: Vec<U>,
+    c: u8,
+}
+
+fn needs_clone<C: Clone>(_: C) {}
+
+fn main() {
+    needs_clone(Wrapper { a: 1u8, b: vec![NotClone], c: 0 });
+    //~^ ERROR the trait bound `Wrapper<u8, NotClone>: Clone` is not satisfied
+
+    let w = Wrapper { a: NotClone, b: vec![1u8], c: 0 };
+    let _ = w.clone();
+    //~^ ERROR the method `clone` exists for struct `Wrapper<NotClone, u8>`, but its trait bounds were not satisfied
+}
@@ -0,0 +1,7 @@
+// Check that a malformed `-Z diagnostic-theme` file is reported, along with where it is wrong.
+
+//@ ignore-windows
+//@ compile-flags: -Z diagnostic-theme={{src-base}}/diagnostic-flags/diagnostic-theme-malformed.toml
+//@ error-pattern: failed to load the diagnostic theme
+
+fn main() {}
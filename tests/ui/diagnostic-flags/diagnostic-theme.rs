@@ -0,0 +1,11 @@
+// Check that `-Z diagnostic-theme` replaces the default colors of the elements it sets, and only
+// those.
+
+//@ ignore-windows
+//@ compile-flags: --error-format=human --color always
+//@ compile-flags: -Z diagnostic-theme={{src-base}}/diagnostic-flags/diagnostic-theme.toml
+//@ error-pattern: cannot find value `x` in this scope
+
+fn main() {
+    x;
+}
@@ -0,0 +1,6 @@
+//@ check-pass
+//@ compile-flags: -Zterminal-urls=yes
+fn main() {
+    let FooBar = 0; //~ WARN variable `FooBar` should have a snake case name
+    let _ = FooBar;
+}
@@ -0,0 +1,14 @@
+//@ compile-flags: -Zwrite-long-types-to-disk=no
+// Long types mentioned several times by a diagnostic are referred to by an
+// alias, which is spelled out once in a note.
+
+type A = (u8, u16, u32, u64, i8, i16, i32, i64);
+type B = (A, A, A);
+type C = (B, B, B);
+
+fn add(x: C, y: C) {
+    let _ = x + y;
+    //~^ ERROR cannot add
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+//@ check-pass
+#![feature(diagnostic_namespace)]
+
+#[diagnostic::blocking]
+//~^ WARN unknown diagnostic attribute
+fn wait() {
+}
+
+fn main() {
+}
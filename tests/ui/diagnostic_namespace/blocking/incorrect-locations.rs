@@ -0,0 +1,40 @@
+//@ check-pass
+#![feature(diagnostic_namespace, diagnostic_blocking)]
+
+#[diagnostic::blocking]
+//~^ WARN `#[diagnostic::blocking]` can only be placed on functions
+struct Struct;
+
+#[diagnostic::blocking]
+//~^ WARN `#[diagnostic::blocking]` can only be placed on functions
+trait Trait {
+    #[diagnostic::blocking]
+    fn wait(&self);
+}
+
+#[diagnostic::blocking]
+//~^ WARN `#[diagnostic::blocking]` can only be placed on functions
+impl Struct {
+    #[diagnostic::blocking(alternative = "Struct::wait_async")]
+    fn wait(&self) {}
+}
+
+#[diagnostic::blocking = "sleep"]
+//~^ WARN malformed `#[diagnostic::blocking]` attribute
+fn sleep() {}
+
+#[diagnostic::blocking(alternative)]
+//~^ WARN malformed `#[diagnostic::blocking]` attribute
+fn read() {}
+
+#[diagnostic::blocking(alternative = "write_async", note = "slow")]
+//~^ WARN malformed `#[diagnostic::blocking]` attribute
+fn write() {}
+
+extern "C" {
+    #[diagnostic::blocking]
+    fn poll();
+}
+
+fn main() {
+}
@@ -0,0 +1,36 @@
+//@ revisions: current next
+//@[next] compile-flags: -Znext-solver
+#![feature(diagnostic_namespace, do_not_recommend)]
+
+// The bounds of impls annotated with `#[diagnostic::do_not_recommend]` aren't
+// mentioned when reporting the obligations they gave rise to, however deep in
+// the chain of impls they are. The new solver only reports the root obligation.
+
+pub trait Root {}
+
+pub trait Middle {}
+
+pub trait Leaf {}
+
+impl Leaf for i32 {}
+
+#[diagnostic::do_not_recommend]
+impl<T: Leaf> Middle for T {}
+
+impl<T: Middle> Root for T {}
+
+#[diagnostic::do_not_recommend]
+impl<T: Root> Root for Vec<T> {}
+
+fn needs_root<T: Root>(_: T) {}
+fn needs_middle<T: Middle>(_: T) {}
+
+fn main() {
+    needs_middle(1u8);
+    //~^ ERROR the trait bound `u8: Middle` is not satisfied
+    needs_root(1u8);
+    //[current]~^ ERROR the trait bound `u8: Middle` is not satisfied
+    //[next]~^^ ERROR the trait bound `u8: Root` is not satisfied
+    needs_root(vec![1u8]);
+    //~^ ERROR the trait bound `Vec<u8>: Root` is not satisfied
+}
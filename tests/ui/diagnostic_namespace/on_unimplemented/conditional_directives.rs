@@ -0,0 +1,23 @@
+#![feature(diagnostic_namespace)]
+
+#[diagnostic::on_unimplemented(
+    if(Self = "u8", message = "bytes cannot be converted", note = "try a wider integer"),
+    if(any(Self = "i32", T = "bool"), label = "an `i32` or a `bool` is involved"),
+    if(not(all(Self = "u8", T = "u8")), note = "the types are not both `u8`"),
+    message = "cannot convert `{Self}` into `{T}`",
+    note = "implement `Convert<{T}>` for `{Self}`",
+)]
+trait Convert<T> {}
+
+fn convert<T: Convert<U>, U>() {}
+
+fn main() {
+    convert::<u8, u8>();
+    //~^ ERROR bytes cannot be converted
+    convert::<i32, u8>();
+    //~^ ERROR cannot convert `i32` into `u8`
+    convert::<u16, bool>();
+    //~^ ERROR cannot convert `u16` into `bool`
+    convert::<u16, u32>();
+    //~^ ERROR cannot convert `u16` into `u32`
+}
@@ -1,7 +1,7 @@
 #![feature(diagnostic_namespace)]
 
 #[diagnostic::on_unimplemented(
-    if(Self = "()"),
+    on(Self = "()"),
     //~^WARN malformed `on_unimplemented` attribute
     //~|WARN malformed `on_unimplemented` attribute
     message = "custom message",
@@ -0,0 +1,34 @@
+#![feature(diagnostic_namespace)]
+
+#[diagnostic::on_unimplemented(
+    if(crate_local, note = "not a comparison"),
+    //~^ WARN malformed condition in `on_unimplemented` attribute
+    //~| WARN malformed condition in `on_unimplemented` attribute
+    if(Self = 1, note = "not a string"),
+    //~^ WARN malformed condition in `on_unimplemented` attribute
+    //~| WARN malformed condition in `on_unimplemented` attribute
+    if(U = "u8", note = "not a parameter of the trait"),
+    //~^ WARN malformed condition in `on_unimplemented` attribute
+    //~| WARN malformed condition in `on_unimplemented` attribute
+    if(any(Self = "u8", from_desugaring = "QuestionMark"), note = "unknown flag"),
+    //~^ WARN malformed condition in `on_unimplemented` attribute
+    //~| WARN malformed condition in `on_unimplemented` attribute
+    if(not(Self = "u8", T = "u8"), note = "more than one negated condition"),
+    //~^ WARN malformed condition in `on_unimplemented` attribute
+    //~| WARN malformed condition in `on_unimplemented` attribute
+    if(),
+    //~^ WARN malformed condition in `on_unimplemented` attribute
+    //~| WARN malformed condition in `on_unimplemented` attribute
+    if(Self = "u8", if(T = "u8", note = "nested")),
+    //~^ WARN malformed `on_unimplemented` attribute
+    //~| WARN malformed `on_unimplemented` attribute
+    message = "cannot convert `{Self}` into `{T}`",
+)]
+trait Convert<T> {}
+
+fn convert<T: Convert<U>, U>() {}
+
+fn main() {
+    convert::<u8, u8>();
+    //~^ ERROR cannot convert `u8` into `u8`
+}
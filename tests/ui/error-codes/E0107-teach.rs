@@ -0,0 +1,8 @@
+//@ compile-flags: -Z teach
+
+struct Foo<'a>(&'a str);
+
+fn foo(_: Foo<'static, 'static>) {}
+//~^ ERROR struct takes 1 lifetime argument but 2 lifetime arguments were supplied
+
+fn main() {}
@@ -0,0 +1,9 @@
+//@ compile-flags: --error-format pretty-json -Zunstable-options -Zjson-explanations
+//@ error-pattern: more than one relaxed default bound
+
+// With `-Zjson-explanations`, JSON diagnostics embed the explanation of their error code,
+// along with the extended help `-Zteach` would add to them.
+
+struct S<T: ?Sized + ?Sized>(Box<T>);
+
+fn main() {}
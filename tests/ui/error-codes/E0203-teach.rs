@@ -0,0 +1,6 @@
+//@ compile-flags: -Z teach
+
+struct S<T: ?Sized + ?Sized>(Box<T>);
+//~^ ERROR type parameter has more than one relaxed default bound
+
+fn main() {}
@@ -0,0 +1,6 @@
+//@ compile-flags: -Z teach
+
+fn main() {
+    let v = core::ptr::null::<u8>();
+    v as *const [u8]; //~ ERROR E0607
+}
@@ -0,0 +1,19 @@
+#![allow(incomplete_features)]
+#![feature(explicit_tail_calls)]
+
+fn _f0(x: u32) -> u32 {
+    become x + 1; //~ error: `become` requires a function call
+}
+
+fn _f1() -> u32 {
+    let g = || -> u32 {
+        become _g1(); //~ error: `become` is not allowed in closures and coroutines
+    };
+    g()
+}
+
+fn _g1() -> u32 {
+    0
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+//@ run-pass
+//@ only-x86_64
+#![allow(incomplete_features)]
+#![feature(explicit_tail_calls)]
+
+use std::cell::RefCell;
+
+struct Logged<'a>(u32, &'a RefCell<Vec<u32>>);
+
+impl Drop for Logged<'_> {
+    fn drop(&mut self) {
+        self.1.borrow_mut().push(self.0);
+    }
+}
+
+// The locals of the caller are dropped before the callee runs.
+fn count_down(n: u32, log: &RefCell<Vec<u32>>) {
+    let _logged = Logged(n, log);
+    if n == 0 {
+        return;
+    }
+    become count_down(n - 1, log);
+}
+
+// Without tail calls, these would overflow the stack.
+fn sum(n: u64, acc: u64) -> u64 {
+    if n == 0 {
+        return acc;
+    }
+    become sum(n - 1, acc + n)
+}
+
+fn sum_indirect(n: u64, acc: [u64; 8]) -> u64 {
+    if n == 0 {
+        return acc.iter().sum();
+    }
+    become sum_indirect(n - 1, [acc[0] + n, acc[1], acc[2], acc[3], acc[4], acc[5], acc[6], 1])
+}
+
+fn main() {
+    let log = RefCell::new(Vec::new());
+    count_down(3, &log);
+    assert_eq!(*log.borrow(), [3, 2, 1, 0]);
+
+    assert_eq!(sum(10_000_000, 0), 50_000_005_000_000);
+    assert_eq!(sum_indirect(10_000_000, [0; 8]), 50_000_005_000_001);
+}
@@ -0,0 +1,34 @@
+#![allow(incomplete_features)]
+#![feature(explicit_tail_calls)]
+
+fn _f0(x: u32) -> u32 {
+    become _g0(x as u16); //~ error: mismatched signatures in tail call
+}
+
+fn _g0(x: u16) -> u32 {
+    x as u32
+}
+
+fn _f1(x: u32) -> u32 {
+    become _g1(x); //~ error: mismatched signatures in tail call
+}
+
+extern "C" fn _g1(x: u32) -> u32 {
+    x
+}
+
+#[track_caller]
+fn _f2() {
+    become _g2(); //~ error: tail calls are not allowed from or to `#[track_caller]` functions
+}
+
+fn _g2() {}
+
+fn _f3() {
+    become _g3(); //~ error: tail calls are not allowed from or to `#[track_caller]` functions
+}
+
+#[track_caller]
+fn _g3() {}
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[coinductive]
+//~^ ERROR the `#[coinductive]` attribute is an experimental feature
+trait Marker {}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use std::ops::Deref;
+
+#[rustc_pure_deref] //~ ERROR the `#[rustc_pure_deref]` attribute is an experimental feature
+struct Guard<'a, T>(&'a T);
+
+impl<T> Deref for Guard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.0
+    }
+}
+
+fn main() {}
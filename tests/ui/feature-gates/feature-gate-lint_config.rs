@@ -0,0 +1,4 @@
+#![lint_config(large_future::max_size = 1024)]
+//~^ ERROR the `#[lint_config]` attribute is an experimental feature
+
+fn main() {}
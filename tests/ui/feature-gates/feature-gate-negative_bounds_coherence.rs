@@ -0,0 +1,14 @@
+#![feature(negative_impls)]
+
+trait Display {}
+trait Debug {}
+impl<T: Display> !Debug for T {}
+
+trait Show {}
+
+impl<T: Display> Show for T {}
+
+impl<T: Debug> Show for T {}
+//~^ ERROR conflicting implementations of trait `Show` [E0119]
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[repr(no_niche)] //~ ERROR `repr(no_niche)` is experimental
+pub struct Handle(&'static u8);
+
+fn main() {}
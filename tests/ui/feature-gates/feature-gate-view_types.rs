@@ -0,0 +1,13 @@
+struct Foo {
+    a: u32,
+    b: u32,
+}
+
+impl Foo {
+    #[view(a)] //~ ERROR the `#[view]` attribute is an experimental feature
+    fn a(&mut self) -> &mut u32 {
+        &mut self.a
+    }
+}
+
+fn main() {}
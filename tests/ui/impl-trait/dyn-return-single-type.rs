@@ -0,0 +1,15 @@
+// Suggest returning an `impl Trait` when all the returned values have the same type, values of
+// type `!` aside.
+
+trait Trait {}
+impl Trait for u32 {}
+
+fn single(x: Option<u32>) -> dyn Trait { //~ ERROR E0746
+    match x {
+        Some(0) => return 1u32,
+        Some(x) => x,
+        None => panic!(),
+    }
+}
+
+fn main() {}
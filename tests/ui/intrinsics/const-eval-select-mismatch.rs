@@ -0,0 +1,46 @@
+//@ check-pass
+// The two implementations passed to `const_eval_select` are expected to behave the same. The
+// `const_eval_select_mismatch` lint points out where they observably do not.
+
+#![feature(const_eval_select)]
+#![feature(core_intrinsics)]
+
+use std::intrinsics::const_eval_select;
+
+#[track_caller]
+const fn located() -> u32 {
+    0
+}
+
+fn not_located() -> u32 {
+    0
+}
+
+const fn caller() -> u32 {
+    unsafe { const_eval_select((), located, not_located) }
+    //~^ WARN only one of the functions passed to `const_eval_select` is `#[track_caller]`
+}
+
+const fn compiletime(x: u32) -> u32 {
+    x + 1
+}
+
+const fn runtime(x: u32) -> u32 {
+    x + 2
+}
+
+const fn select(x: u32) -> u32 {
+    unsafe { const_eval_select((x,), compiletime, runtime) }
+    //~^ WARN `compiletime` and `runtime` return different values for the same arguments
+}
+
+const fn same(x: u32) -> u32 {
+    x + 1
+}
+
+// Both implementations are only compared when they are called during const eval.
+const X: u32 = select(1);
+const Y: u32 = unsafe { const_eval_select((1,), compiletime, same) };
+const Z: u32 = caller();
+
+fn main() {}
@@ -0,0 +1,22 @@
+// The functions passed to `const_eval_select` must agree on lifetimes as well, as the caller
+// only sees one signature.
+
+#![feature(const_eval_select)]
+#![feature(core_intrinsics)]
+
+use std::intrinsics::const_eval_select;
+
+const fn compiletime<'a>(x: &'a u8) -> &'a u8 {
+    x
+}
+
+fn runtime(x: &'static u8) -> &'static u8 {
+    x
+}
+
+const fn select(x: &'static u8) -> &'static u8 {
+    unsafe { const_eval_select((x,), compiletime, runtime) }
+    //~^ ERROR the functions passed to `const_eval_select` must have identical signatures
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+//@ build-fail
+//@ compile-flags: --target i686-unknown-linux-gnu --crate-type lib -Z layout-tables
+//@ needs-llvm-components: x86
+#![feature(no_core, lang_items)]
+#![allow(internal_features)]
+#![no_std]
+#![no_core]
+
+pub struct Example {
+    small: u8,
+    big: [u32; 0x1fffffff],
+    rest: [u16; 0x10],
+}
+
+pub fn lib(_x: Example) {} //~ ERROR too big for the current architecture
+
+#[lang = "sized"]
+pub trait Sized {}
+#[lang = "copy"]
+pub trait Copy: Sized {}
@@ -0,0 +1,35 @@
+//@ run-pass
+// Checks that `#[repr(no_niche)]` hides the niches of a type from enclosing enums,
+// and keeps an enum it is applied to from storing its tag in a niche.
+
+#![feature(no_niche)]
+
+use std::mem::size_of;
+
+#[repr(no_niche)]
+struct Ref(&'static u8);
+
+#[repr(no_niche)]
+struct Flag(bool);
+
+#[repr(no_niche)]
+enum Tagged {
+    A(bool),
+    B,
+}
+
+fn main() {
+    assert_eq!(size_of::<Ref>(), size_of::<&u8>());
+    assert_eq!(size_of::<Option<Ref>>(), 2 * size_of::<&u8>());
+    assert_eq!(size_of::<Flag>(), 1);
+    assert_eq!(size_of::<Option<Flag>>(), 2);
+
+    // Without the attribute, `B` would be stored in the niche of the `bool`,
+    // and `Option<Tagged>` in the unused values of the tag.
+    assert_eq!(size_of::<Tagged>(), 2);
+    assert_eq!(size_of::<Option<Tagged>>(), 3);
+
+    let tagged = [Tagged::A(true), Tagged::A(false), Tagged::B];
+    assert!(matches!(tagged, [Tagged::A(true), Tagged::A(false), Tagged::B]));
+    assert!(matches!(Some(Flag(false)), Some(Flag(false))));
+}
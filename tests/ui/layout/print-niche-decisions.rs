@@ -0,0 +1,36 @@
+//@ compile-flags: -Z print-niche-decisions --crate-type lib
+//@ build-pass
+//@ ignore-pass
+// ^-- needed because `--pass check` does not emit the output needed.
+
+#![feature(no_niche)]
+#![allow(dead_code)]
+
+pub enum MyOption<T> {
+    None,
+    Some(T),
+}
+
+pub struct Flag(bool);
+
+#[repr(no_niche)]
+pub struct HiddenFlag(bool);
+
+#[repr(u8)]
+pub enum Int {
+    A(bool),
+    B,
+}
+
+#[repr(no_niche)]
+pub enum NoNiche {
+    A(bool),
+    B,
+}
+
+pub fn test() {
+    let _a: MyOption<Flag> = MyOption::None;
+    let _b: MyOption<HiddenFlag> = MyOption::None;
+    let _c: Int = Int::B;
+    let _d: NoNiche = NoNiche::B;
+}
@@ -0,0 +1,34 @@
+//@ edition: 2021
+//@ compile-flags: -Zlint-mir
+#![feature(diagnostic_namespace, diagnostic_blocking)]
+#![deny(blocking_in_async)]
+
+#[diagnostic::blocking(alternative = "flush_async")]
+fn flush() {}
+
+async fn flush_async() {}
+
+fn save() {
+    flush();
+}
+
+fn save_twice() {
+    save();
+    save();
+}
+
+fn recurse(n: u32) {
+    if n > 0 {
+        recurse(n - 1);
+    }
+}
+
+async fn run() {
+    save_twice();
+    //~^ ERROR call to blocking function `save_twice` in an `async` body
+    recurse(3);
+    flush();
+    //~^ ERROR call to blocking function `flush` in an `async` body
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+//@ edition: 2021
+#![feature(diagnostic_namespace, diagnostic_blocking)]
+#![deny(blocking_in_async)]
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[diagnostic::blocking(alternative = "wait_async")]
+fn wait() {}
+
+async fn wait_async() {}
+
+fn helper() {
+    std::thread::sleep(Duration::from_millis(1));
+}
+
+async fn run(counter: &Mutex<u32>) -> std::io::Result<()> {
+    let config = std::fs::read_to_string("config.toml")?;
+    //~^ ERROR call to blocking function `std::fs::read_to_string` in an `async` body
+    *counter.lock().unwrap() += config.len() as u32;
+    //~^ ERROR call to blocking function `std::sync::Mutex::<T>::lock` in an `async` body
+    wait();
+    //~^ ERROR call to blocking function `wait` in an `async` body
+    wait_async().await;
+
+    // Only visible with `-Z lint-mir`.
+    helper();
+
+    // Closures may run on a thread that is allowed to block.
+    let read = || std::fs::read("data.bin");
+    read()?;
+
+    async {
+        std::thread::sleep(Duration::from_millis(1));
+        //~^ ERROR call to blocking function `std::thread::sleep` in an `async` body
+    }
+    .await;
+    Ok(())
+}
+
+fn sync(counter: &Mutex<u32>) {
+    *counter.lock().unwrap() += 1;
+    wait();
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+// Tests the `drop_order_dependence` lint, which reports the temporaries of the tail expression of
+// a block that are dropped after a local variable of that block.
+
+#![deny(drop_order_dependence)]
+
+use std::cell::RefCell;
+use std::sync::Mutex;
+
+fn body_tail(lock: &Mutex<Vec<u32>>, cell: &RefCell<Vec<u32>>) -> usize {
+    let guard = lock.lock().unwrap();
+    guard.len() + cell.borrow().len()
+    //~^ ERROR this temporary in the tail expression of a block is dropped after `guard`
+}
+
+fn nested_block(lock: &Mutex<u32>, cell: &RefCell<u32>) -> u32 {
+    let value = {
+        let guard = lock.lock().unwrap();
+        *guard + *cell.borrow()
+        //~^ ERROR this temporary in the tail expression of a block is dropped after `guard`
+    };
+    value
+}
+
+fn bound_tail(lock: &Mutex<Vec<u32>>, cell: &RefCell<Vec<u32>>) -> usize {
+    let guard = lock.lock().unwrap();
+    let len = cell.borrow().len();
+    guard.len() + len
+}
+
+fn insignificant_local(cell: &RefCell<Vec<u32>>) -> usize {
+    let values = vec![1, 2, 3];
+    values.len() + cell.borrow().len()
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+// Tests setting the size limit of the `large_future` lint with `#![lint_config]`, which takes
+// precedence over `#![future_size_limit]`.
+
+//@ edition:2021
+//@ check-pass
+
+#![feature(future_size_limit, lint_config)]
+#![future_size_limit = "4096"]
+#![lint_config(large_future::max_size = 1024)]
+#![warn(large_future)]
+
+async fn ready() {}
+
+async fn big() -> usize {
+    //~^ WARN this future is 2050 bytes, which is larger than the limit of 1024 bytes
+    let buf = [1u8; 2048];
+    ready().await;
+    buf.iter().map(|&b| b as usize).sum()
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+//@ edition:2021
+//@ check-pass
+//@ run-rustfix
+
+#![feature(future_size_limit)]
+#![future_size_limit = "1024"]
+#![warn(large_future)]
+#![allow(dead_code)]
+
+async fn ready() {}
+
+async fn big() -> usize {
+    //~^ WARN this future is 2050 bytes, which is larger than the limit of 1024 bytes
+    let buf = [1u8; 2048];
+    ready().await;
+    buf.iter().map(|&b| b as usize).sum()
+}
+
+async fn caller() -> usize {
+    //~^ WARN this future is 2051 bytes, which is larger than the limit of 1024 bytes
+    big().await
+}
+
+async fn boxed() -> usize {
+    Box::pin(big()).await
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+// Tests the validation of the options set with `#![lint_config]`.
+
+#![feature(lint_config)]
+#![lint_config(large_future::max_size = 1024)]
+#![lint_config(large_future::max_size = 2048)]
+//~^ ERROR `large_future::max_size` is configured more than once
+#![lint_config(large_future::max_size = "big")]
+//~^ ERROR invalid value for `large_future::max_size`
+#![lint_config(large_future::threshold = 1)]
+//~^ ERROR lint `large_future` has no configuration option `threshold`
+#![lint_config(no_such_lint::max_size = 1)]
+//~^ ERROR unknown lint in `lint_config`: `no_such_lint`
+#![lint_config(unused::max_size = 1)]
+//~^ ERROR unknown lint in `lint_config`: `unused`
+#![lint_config(max_size = 1)]
+//~^ ERROR malformed `lint_config` attribute input
+#![lint_config(large_future)]
+//~^ ERROR malformed `lint_config` attribute input
+
+fn main() {}
@@ -0,0 +1,46 @@
+//@ run-rustfix
+//@ check-pass
+//@ edition:2021
+#![warn(lossy_integer_cast)]
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::num::TryFromIntError;
+
+fn narrow(len: usize) -> Result<u16, TryFromIntError> {
+    Ok(len as u16)
+    //~^ WARN casting `usize` to `u16` with `as` may truncate the value or change its sign
+}
+
+fn sign(x: i32, y: u64) -> Result<(u32, i64), Box<dyn Error>> {
+    Ok((x as u32, y as i64))
+    //~^ WARN casting `i32` to `u32`
+    //~| WARN casting `u64` to `i64`
+}
+
+fn no_result(x: i64) -> u8 {
+    x as u8
+    //~^ WARN casting `i64` to `u8`
+}
+
+fn other_error(x: i64) -> Result<u8, std::fmt::Error> {
+    Ok(x as u8)
+    //~^ WARN casting `i64` to `u8`
+}
+
+fn closure(x: i64) -> Result<u8, TryFromIntError> {
+    let f = || x as u8;
+    //~^ WARN casting `i64` to `u8`
+    Ok(f())
+}
+
+fn lossless(a: u8, b: u32, c: usize, d: i16) -> (u32, i64, u64, isize) {
+    (a as u32, b as i64, c as u64, d as isize)
+}
+
+// Casts of literals, like `-1i32 as u32`, are deliberate.
+fn literals() -> (u8, u32) {
+    (300u16 as u8, -1i32 as u32)
+}
+
+fn main() {}
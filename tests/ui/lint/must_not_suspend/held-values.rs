@@ -0,0 +1,36 @@
+//@ edition:2018
+#![feature(must_not_suspend)]
+#![deny(must_not_suspend)]
+
+#[must_not_suspend]
+struct Guard(u8);
+
+impl Guard {
+    fn peek(&self) -> u8 {
+        self.0
+    }
+}
+
+async fn other() {}
+
+// Each value is linted once, at the first suspension point it is held across.
+pub async fn several() {
+    let first = Guard(1); //~ ERROR `Guard` held across
+    other().await;
+    drop(first);
+    let second = Guard(2); //~ ERROR `Guard` held across
+    other().await;
+    other().await;
+    drop(second);
+}
+
+// Values moved out of before a suspension point are not held across it, even if they have been
+// borrowed before.
+pub async fn moved_out() {
+    let guard = Guard(3);
+    guard.peek();
+    drop(guard);
+    other().await;
+}
+
+fn main() {}
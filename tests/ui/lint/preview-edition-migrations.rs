@@ -0,0 +1,19 @@
+//@ check-pass
+//@ edition:2021
+//@ compile-flags: -Zpreview-edition-migrations=2024
+// `-Z preview-edition-migrations` enables the lints preparing the migration to an edition as
+// warnings, unless they are allowed in the crate.
+
+#![crate_type = "lib"]
+
+unsafe fn unsf() {}
+
+unsafe fn foo() {
+    unsf();
+    //~^ WARN call to unsafe function `unsf` is unsafe and requires unsafe block
+}
+
+#[allow(unsafe_op_in_unsafe_fn)]
+unsafe fn allowed() {
+    unsf();
+}
@@ -0,0 +1,49 @@
+//@ run-rustfix
+
+#![deny(redundant_bounds)]
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+
+fn supertrait<T: Ord + PartialOrd>(_: T) {}
+//~^ ERROR bound `PartialOrd` is redundant
+
+fn transitive<T: PartialEq + Ord>(_: T) {}
+//~^ ERROR bound `PartialEq` is redundant
+
+fn where_clause<T: Copy>(_: T)
+where
+    T: Clone,
+    //~^ ERROR bound `Clone` is redundant
+    T: Debug,
+{
+}
+
+fn duplicate<T: Debug>(_: T)
+where
+    T: Debug,
+    //~^ ERROR bound `Debug` is redundant
+{
+}
+
+fn impl_trait(_: impl Eq + PartialEq) {}
+//~^ ERROR bound `PartialEq` is redundant
+
+trait Tr {
+    fn method<T: Eq + PartialEq>(_: T);
+    //~^ ERROR bound `PartialEq` is redundant
+}
+
+struct S<T: Clone + Copy>(T);
+//~^ ERROR bound `Clone` is redundant
+
+fn not_redundant<T: PartialOrd + Debug>(_: T) {}
+
+trait A {}
+trait B: A {}
+trait C: B {}
+
+fn local_chain<T: C + A>(_: T) {}
+//~^ ERROR bound `A` is redundant
+
+fn main() {}
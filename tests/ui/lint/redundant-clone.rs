@@ -0,0 +1,43 @@
+//@ check-pass
+//@ run-rustfix
+
+#![warn(perf)]
+#![allow(unused)]
+
+use std::rc::Rc;
+
+#[derive(Clone, Copy)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn copy(n: u32, r: &u32, point: Point) {
+    let _ = n.clone();
+    //~^ WARN call to `.clone()` on `u32`, which implements `Copy`
+    let _ = r.clone();
+    //~^ WARN call to `.clone()` on `u32`, which implements `Copy`
+    let _ = point.clone().x;
+    //~^ WARN call to `.clone()` on `Point`, which implements `Copy`
+    let _ = (n + 1).clone();
+    //~^ WARN call to `.clone()` on `u32`, which implements `Copy`
+}
+
+fn borrowed(s: &String, v: Vec<u8>, rc: Rc<String>) {
+    let _ = s.clone().len();
+    //~^ WARN redundant clone of `String`
+    let _ = v.clone().iter().count();
+    //~^ WARN redundant clone of `Vec<u8>`
+    let _ = rc.clone().as_str().len();
+    //~^ WARN redundant clone of `Rc<String>`
+}
+
+fn not_redundant(s: &String, mut v: Vec<u8>) {
+    // The clone is moved.
+    let owned = s.clone();
+    let _ = s.clone().into_bytes();
+    // The clone is mutated.
+    v.clone().push(1);
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+// Tests the `temporary_lifetime` lints, which report the temporaries of `if let` scrutinees and
+// `let ... else` initializers with a significant drop that outlive them.
+
+#![deny(temporary_lifetime)]
+
+use std::sync::Mutex;
+
+fn if_let(queue: &Mutex<Vec<u32>>) -> u32 {
+    let value = if let Some(value) = queue.lock().unwrap().pop() {
+        //~^ ERROR this temporary in the scrutinee of `if let` lives until after the `else` block
+        value
+    } else {
+        0
+    };
+    value
+}
+
+fn if_let_without_else(queue: &Mutex<Vec<u32>>) {
+    if let Some(value) = queue.lock().unwrap().pop() {
+        println!("{value}");
+    }
+}
+
+fn if_let_insignificant(values: &Vec<Vec<u32>>) -> usize {
+    if let Some(first) = values.clone().first() { first.len() } else { 0 }
+}
+
+fn let_else(queue: &Mutex<Vec<u32>>) -> u32 {
+    let Some(value) = queue.lock().unwrap().pop() else {
+        //~^ ERROR this temporary in the initializer of `let ... else` lives until after the `else` block
+        return 0;
+    };
+    value
+}
+
+fn let_else_extended(queue: &Mutex<Option<u32>>) -> u32 {
+    let Some(value) = &*queue.lock().unwrap() else {
+        //~^ ERROR this temporary in the initializer of `let ... else` has its lifetime extended to the end of the enclosing block
+        return 0;
+    };
+    *value
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+//@ build-fail
+
+// Check that the lint reads indices through references to statics and promoted constants.
+
+static TABLE: [usize; 3] = [1, 2, 3];
+
+fn main() {
+    let xs = [0u8; 2];
+    let _ = xs[TABLE[2]]; //~ ERROR: this operation will panic at runtime [unconditional_panic]
+    let promoted: &[usize; 2] = &[4, 5];
+    let _ = xs[promoted[1]]; //~ ERROR: this operation will panic at runtime [unconditional_panic]
+}
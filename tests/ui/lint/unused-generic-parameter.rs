@@ -0,0 +1,43 @@
+//@ check-pass
+//@ run-rustfix
+
+#![warn(unused_generic_parameter)]
+#![allow(dead_code)]
+
+fn zero<T>() -> u32 {
+    //~^ WARN generic parameter `T` is never used
+    0
+}
+
+fn first<A, B, const N: usize>(a: A) -> A {
+    //~^ WARN generic parameters `B`, `N` are never used
+    a
+}
+
+// Parameters used in the body or in a bound with a used parameter are fine.
+fn size<T>() -> usize {
+    std::mem::size_of::<T>()
+}
+
+fn convert<T, U: Into<T>>(_u: U) {}
+
+struct S;
+
+impl S {
+    fn method<T: Clone>(&self) {}
+    //~^ WARN generic parameter `T` is never used
+}
+
+struct Buf<const N: usize> {
+    //~^ WARN generic parameter `N` is never used
+    len: usize,
+}
+
+fn main() {
+    zero::<u8>();
+    first::<u8, u16, 3>(1);
+    size::<u8>();
+    convert::<u64, u32>(1);
+    S.method::<u8>();
+    let _ = Buf::<4> { len: 0 };
+}
@@ -0,0 +1,17 @@
+//@ compile-flags: -Zdeduplicate-diagnostics=yes
+
+// Diagnostics which only differ by the macro expansion they come from are only
+// shown once, along with how many times they were emitted.
+
+macro_rules! m {
+    () => {
+        let _x: u8 = 256;
+        //~^ ERROR literal out of range for `u8`
+    };
+}
+
+fn main() {
+    m!();
+    m!();
+    m!();
+}
@@ -0,0 +1,22 @@
+// Checks that `-Zmir-passes` can run the optimizations in another order, and that the passes
+// establishing what codegen requires run even when they aren't named.
+
+//@ run-pass
+//@ compile-flags: -Zmir-opt-level=2 -Zmir-passes=GVN,SimplifyCfg-final,Inline -Zvalidate-mir
+//@ needs-unwind
+
+fn may_panic(x: u32) -> u32 {
+    if x > 10 { panic!() }
+    x
+}
+
+fn main() {
+    let mut v = Vec::new();
+    for i in 0..3 {
+        match std::panic::catch_unwind(|| may_panic(i * 6)) {
+            Ok(x) => v.push(x),
+            Err(_) => v.push(0),
+        }
+    }
+    assert_eq!(v, [0, 6, 0]);
+}
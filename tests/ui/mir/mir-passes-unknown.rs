@@ -0,0 +1,6 @@
+// Checks that `-Zmir-passes` rejects the names which don't match any optimization pass.
+
+//@ build-fail
+//@ compile-flags: -Zmir-passes=GVN,NotAPass
+
+fn main() {}
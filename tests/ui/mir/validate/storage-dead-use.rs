@@ -0,0 +1,23 @@
+// Check that full validation rejects a use of a local after its storage is dead.
+//
+//@ build-fail
+//@ compile-flags: --crate-type=lib -Zvalidate-mir=full
+//@ failure-status: 101
+//@ dont-check-compiler-stderr
+//@ error-pattern: use of local _2, which has no storage here
+#![feature(custom_mir, core_intrinsics)]
+use core::intrinsics::mir::*;
+
+#[custom_mir(dialect = "runtime", phase = "optimized")]
+pub fn f(a: i32) -> i32 {
+    mir!(
+        let x: i32;
+        {
+            StorageLive(x);
+            x = a;
+            StorageDead(x);
+            RET = x;
+            Return()
+        }
+    )
+}
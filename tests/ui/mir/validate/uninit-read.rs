@@ -0,0 +1,20 @@
+// Check that full validation rejects a read of a local which is never initialized.
+//
+//@ build-fail
+//@ compile-flags: --crate-type=lib -Zvalidate-mir=full
+//@ failure-status: 101
+//@ dont-check-compiler-stderr
+//@ error-pattern: read of _1, whose local is uninitialized here
+#![feature(custom_mir, core_intrinsics)]
+use core::intrinsics::mir::*;
+
+#[custom_mir(dialect = "runtime", phase = "optimized")]
+pub fn f() -> i32 {
+    mir!(
+        let x: i32;
+        {
+            RET = x;
+            Return()
+        }
+    )
+}
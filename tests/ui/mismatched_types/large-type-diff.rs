@@ -0,0 +1,20 @@
+// Only the path to the differences is shown for types too large to compare at a glance.
+
+use std::collections::HashMap;
+
+struct Alpha;
+struct Beta;
+
+type Large<T> = HashMap<String, Vec<(u32, String, Option<Box<T>>, [u8; 4], fn(u8) -> u16, (i8,))>>;
+
+fn alpha() -> Large<Alpha> {
+    HashMap::new()
+}
+
+fn main() {
+    let _: Large<Beta> = alpha();
+    //~^ ERROR mismatched types
+    let _: HashMap<(u8, u16, u32, u64), (i8, i16, i32, i64, Option<Beta>, String)> =
+        HashMap::<(u8, u16, u32, u64), (i8, i16, i32, i64, Option<Alpha>, String)>::new();
+    //~^ ERROR mismatched types
+}
@@ -0,0 +1,12 @@
+// Check that the suggestion to clone a moved value of a type parameter points at
+// the bound which implies `Clone` through supertraits.
+
+trait Super: Clone {}
+
+trait Sub: Super {}
+
+fn dup<T: Sub>(x: T) -> (T, T) {
+    (x, x) //~ ERROR use of moved value: `x`
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+// Checks that `-Z borrowck-graphviz-errors=text` points at each outlives constraint on the path
+// that leads to a region error.
+
+//@ compile-flags: -Z borrowck-graphviz-errors=text
+
+fn missing_subset<'a, 'b>(x: &'a u32, y: &'b u32) -> &'a u32 {
+    y //~ ERROR lifetime may not live long enough
+}
+
+fn main() {}
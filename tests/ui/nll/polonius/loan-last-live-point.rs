@@ -0,0 +1,16 @@
+// Check that, with polonius, "does not live long enough" errors point at where the borrow is last
+// live, and at the branches taken to get there from where it is invalidated.
+//@ compile-flags: -Z polonius
+
+fn conditional_use(cond: bool) {
+    let r;
+    {
+        let x = 0;
+        r = &x; //~ ERROR `x` does not live long enough
+    }
+    if cond {
+        let _y = *r;
+    }
+}
+
+fn main() {}
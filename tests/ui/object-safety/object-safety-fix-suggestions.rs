@@ -0,0 +1,15 @@
+// Check that every object safety violation of a local trait gets a suggestion
+// on how to fix it.
+
+use std::fmt::Debug;
+trait Foo {
+    fn generic(&self, x: impl Debug);
+    fn ret(&self) -> Self;
+    fn multiple(&self, x: impl Debug + Send, y: impl AsRef<str>);
+    fn not_object_safe_arg(&self, x: impl Clone);
+    fn stat();
+    const C: u8;
+}
+fn f(_: &dyn Foo) {}
+//~^ ERROR the trait `Foo` cannot be made into an object
+fn main() {}
@@ -0,0 +1,15 @@
+// Check that when a trait cannot be made into an object because one of its
+// supertraits requires `Self: Sized`, we point at every supertrait bound
+// through which it is required.
+
+trait Base: Sized {}
+trait Middle: Base {}
+trait Top: Middle {}
+
+fn make_top<T: Top>(t: &T) -> &dyn Top {
+    //~^ ERROR E0038
+    t
+    //~^ ERROR E0038
+}
+
+fn main() {}
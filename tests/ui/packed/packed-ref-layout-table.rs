@@ -0,0 +1,15 @@
+//@ compile-flags: -Z layout-tables
+
+#![allow(dead_code)]
+
+#[repr(packed)]
+struct Packed {
+    a: u8,
+    b: u32,
+    c: u16,
+}
+
+fn main() {
+    let p = Packed { a: 1, b: 2, c: 3 };
+    let _ = &p.b; //~ ERROR reference to packed field is unaligned
+}
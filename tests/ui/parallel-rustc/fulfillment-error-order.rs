@@ -0,0 +1,18 @@
+//@ compile-flags: -Z threads=16
+// Obligations are proven on the thread pool, but their errors are still reported in the order of
+// the obligations.
+
+struct NotClone;
+
+fn clone<T: Clone>() {}
+fn copy<T: Copy>() {}
+
+fn main() {
+    clone::<u8>();
+    copy::<String>();
+    //~^ ERROR the trait bound `String: Copy` is not satisfied
+    clone::<Vec<u8>>();
+    clone::<NotClone>();
+    //~^ ERROR the trait bound `NotClone: Clone` is not satisfied
+    copy::<(u8, u16)>();
+}
@@ -0,0 +1,19 @@
+//@ compile-flags: -Z print-type-sizes --crate-type lib
+//@ edition:2021
+//@ build-pass
+//@ ignore-pass
+
+// Checks that an upvar which is moved out before the first suspension point is only stored in
+// the unresumed state, overlapping with the locals of the other states.
+
+#![allow(dropping_copy_types)]
+
+async fn wait() {}
+
+pub async fn test(arg: [u8; 1024]) {
+    let buf = [0u8; 2048];
+    wait().await;
+    drop(buf);
+    wait().await;
+    drop(arg);
+}
@@ -0,0 +1,11 @@
+#![feature(no_niche)]
+
+#[repr(no_niche)] //~ ERROR should be applied to a struct or enum
+pub union CantHideThis {
+    a: u8,
+}
+
+#[repr(no_niche)] //~ ERROR should be applied to a struct or enum
+fn cant_hide_this() {}
+
+fn main() {}
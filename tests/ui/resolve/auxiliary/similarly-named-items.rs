@@ -0,0 +1,10 @@
+pub mod collections {
+    pub struct OrderedMap;
+
+    pub trait Container {}
+}
+
+pub fn make_widget() {}
+
+#[doc(hidden)]
+pub struct HiddenThing;
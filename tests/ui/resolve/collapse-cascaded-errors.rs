@@ -0,0 +1,18 @@
+// Errors about the same unresolved name are only reported once by default.
+//@ compile-flags: -Zshow-cascaded-errors=no
+
+struct S {
+    a: Missing, //~ ERROR cannot find type `Missing` in this scope
+    b: Missing,
+    c: Vec<Missing>,
+}
+
+fn f(x: Missing) -> Missing {
+    x
+}
+
+fn g() -> Absent { //~ ERROR cannot find type `Absent` in this scope
+    missing_fn() //~ ERROR cannot find function `missing_fn` in this scope
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+//@ edition:2018
+//@ aux-crate:similarly_named_items=similarly-named-items.rs
+
+fn check<T: Containr>(_: T) {}
+//~^ ERROR cannot find trait `Containr` in this scope
+
+fn main() {
+    let _: OrderedMpa;
+    //~^ ERROR cannot find type `OrderedMpa` in this scope
+    make_widgt();
+    //~^ ERROR cannot find function `make_widgt` in this scope
+    let _ = HiddenThin;
+    //~^ ERROR cannot find value `HiddenThin` in this scope
+}
@@ -0,0 +1,70 @@
+//@ check-pass
+// Check that const closures implement the `Fn*` traits in const contexts, and
+// that they can still be used at runtime.
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items, unboxed_closures, auto_traits, rustc_attrs)]
+#![feature(const_trait_impl, effects, const_closures)]
+#![allow(incomplete_features, internal_features)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+#[lang = "copy"]
+trait Copy {}
+#[lang = "tuple_trait"]
+trait Tuple {}
+#[lang = "receiver"]
+trait Receiver {}
+impl<T: ?Sized> Receiver for &T {}
+impl<T: ?Sized> Receiver for &mut T {}
+#[lang = "freeze"]
+unsafe auto trait Freeze {}
+
+#[const_trait]
+#[lang = "fn"]
+#[rustc_paren_sugar]
+trait Fn<Args: Tuple>: ~const FnMut<Args> {
+    extern "rust-call" fn call(&self, args: Args) -> Self::Output;
+}
+
+#[const_trait]
+#[lang = "fn_mut"]
+#[rustc_paren_sugar]
+trait FnMut<Args: Tuple>: ~const FnOnce<Args> {
+    extern "rust-call" fn call_mut(&mut self, args: Args) -> Self::Output;
+}
+
+#[const_trait]
+#[lang = "fn_once"]
+#[rustc_paren_sugar]
+trait FnOnce<Args: Tuple> {
+    #[lang = "fn_once_output"]
+    type Output;
+
+    extern "rust-call" fn call_once(self, args: Args) -> Self::Output;
+}
+
+const fn call<F: ~const Fn<(), Output = u8>>(f: &F) -> u8 {
+    f()
+}
+
+const fn in_const_fn() -> u8 {
+    call(&const || 3)
+}
+
+const IN_CONST: u8 = call(&const || 3);
+
+#[const_trait]
+trait Answer {
+    fn answer() -> u8;
+}
+
+const fn with_const_bound<T: ~const Answer>() -> u8 {
+    call(&const || T::answer())
+}
+
+fn at_runtime() -> u8 {
+    call(&const || 3);
+    call(&|| 4)
+}
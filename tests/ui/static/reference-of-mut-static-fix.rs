@@ -0,0 +1,17 @@
+// Check that the `static_mut_refs` suggestions can be applied automatically.
+
+//@ run-rustfix
+//@ check-pass
+
+static mut COUNTER: u32 = 0;
+
+fn main() {
+    unsafe {
+        let r = &mut COUNTER;
+        //~^ WARN creating a mutable reference to mutable static is discouraged [static_mut_refs]
+        *r += 1;
+        let ref v = COUNTER;
+        //~^ WARN creating a shared reference to mutable static is discouraged [static_mut_refs]
+        let _ = *v;
+    }
+}
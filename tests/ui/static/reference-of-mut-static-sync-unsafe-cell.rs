@@ -0,0 +1,12 @@
+// With `SyncUnsafeCell` available, `static_mut_refs` also suggests using it instead of `static mut`.
+
+#![feature(sync_unsafe_cell)]
+#![deny(static_mut_refs)]
+
+fn main() {
+    static mut COUNTER: u32 = 0;
+    unsafe {
+        let _r = &mut COUNTER;
+        //~^ ERROR creating a mutable reference to mutable static is discouraged [static_mut_refs]
+    }
+}
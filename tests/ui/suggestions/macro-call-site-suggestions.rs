@@ -0,0 +1,43 @@
+// Suggestions about the output of a macro are made on the macro invocation.
+
+macro_rules! string {
+    () => {
+        String::new()
+    };
+}
+
+macro_rules! name {
+    () => {
+        "name"
+    };
+}
+
+macro_rules! parse {
+    () => {
+        "1".parse::<u32>()
+    };
+}
+
+trait Tr {}
+impl Tr for &Vec<u8> {}
+
+fn takes_ref(_: &String) {}
+fn takes_slice(_: &[u8]) {}
+fn takes_string(_: String) {}
+fn takes_u32(_: u32) {}
+fn needs_tr<T: Tr>(_: T) {}
+
+fn main() {
+    takes_ref(format!("{}", 1));
+    //~^ ERROR mismatched types
+    takes_ref(string!());
+    //~^ ERROR mismatched types
+    takes_slice(vec![1]);
+    //~^ ERROR mismatched types
+    needs_tr(vec![1u8]);
+    //~^ ERROR the trait bound `Vec<u8>: Tr` is not satisfied
+    takes_string(name!());
+    //~^ ERROR mismatched types
+    takes_u32(parse!());
+    //~^ ERROR mismatched types
+}
@@ -0,0 +1,17 @@
+//@ compile-flags: --error-format pretty-json -Zunstable-options
+//@ error-pattern: mismatched types
+
+// Alternative fixes for the same type mismatch are ranked in the JSON output,
+// including the ones which aren't shown to humans, like casting with `as`.
+
+fn widen(x: u16) -> u32 {
+    x
+}
+
+fn narrow(x: u32) -> u16 {
+    x
+}
+
+fn main() {
+    let _: i32 = &5;
+}
@@ -0,0 +1,23 @@
+//@ run-rustfix
+// Check that we suggest adding a supertrait when the default body of a
+// trait method requires a bound on `Self`.
+
+#![allow(dead_code)]
+
+trait Foo {
+    fn dup(&self) {
+        takes_clone(self);
+        //~^ ERROR the trait bound `Self: Clone` is not satisfied
+    }
+}
+
+trait Bar<T>: std::fmt::Debug {
+    fn dup(&self) {
+        takes_clone(self);
+        //~^ ERROR the trait bound `Self: Clone` is not satisfied
+    }
+}
+
+fn takes_clone<T: Clone + ?Sized>(_: &T) {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+#![feature(trait_alias)]
+
+pub trait Foo {}
+impl Foo for [u8] {}
+
+pub trait MaybeFoo = Foo + ?Sized;
@@ -0,0 +1,16 @@
+//@ check-pass
+//@ aux-build:maybe_bound.rs
+
+// Test that the `?Sized` bound of a trait alias is also respected across crates.
+
+#![feature(trait_alias)]
+
+extern crate maybe_bound;
+
+use maybe_bound::MaybeFoo;
+
+fn maybe_foo<T: MaybeFoo>(_: &T) {}
+
+fn main() {
+    maybe_foo::<[u8]>(&[][..]);
+}
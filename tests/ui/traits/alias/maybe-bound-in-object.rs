@@ -0,0 +1,16 @@
+// Test that trait aliases which relax a bound other than `Sized` cannot be used
+// in trait objects, just like `dyn Trait + ?Trait`.
+
+#![feature(trait_alias)]
+
+trait Foo {}
+
+trait MaybeSend = Foo + ?Send;
+trait Nested = MaybeSend;
+
+fn direct(_: &dyn MaybeSend) {}
+//~^ ERROR `?Trait` is not permitted in trait object types
+fn nested(_: Box<dyn Nested>) {}
+//~^ ERROR `?Trait` is not permitted in trait object types
+
+fn main() {}
@@ -0,0 +1,40 @@
+//@ check-pass
+
+// Test that a `?Sized` bound of a trait alias removes the implicit `Sized` bound
+// of type parameters bounded by that alias.
+
+#![feature(trait_alias)]
+
+trait Foo {}
+impl Foo for [u8] {}
+impl Foo for str {}
+impl Foo for u8 {}
+
+trait S = ?Sized;
+trait MaybeFoo = Foo + ?Sized;
+
+// Nest a couple of levels deep:
+trait _0 = MaybeFoo;
+trait _1 = _0 + Send;
+
+fn only_maybe<T: S>(_: &T) {}
+fn maybe_foo<T: MaybeFoo>(_: &T) {}
+fn nested<T: _1>(_: &T) {}
+fn in_where_clause<T>(_: &T)
+where
+    T: MaybeFoo,
+{
+}
+
+// The alias also works with `dyn`, as trait objects are never `Sized`.
+fn object(x: &dyn MaybeFoo) -> &dyn MaybeFoo {
+    x
+}
+
+fn main() {
+    only_maybe::<[u8]>(&[][..]);
+    maybe_foo::<[u8]>(&[][..]);
+    nested::<str>("");
+    in_where_clause::<str>("");
+    object(&0u8);
+}
@@ -0,0 +1,16 @@
+// Check that chains of trait aliases deeper than the recursion limit are
+// reported instead of being expanded indefinitely.
+
+#![feature(trait_alias)]
+#![recursion_limit = "4"]
+
+trait A0 = A1;
+trait A1 = A2;
+trait A2 = A3;
+trait A3 = A4;
+//~^ ERROR reached the recursion limit while expanding trait alias `A0`
+trait A4 = Send;
+
+fn foo(_: &dyn A0) {}
+
+fn main() {}
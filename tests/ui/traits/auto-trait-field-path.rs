@@ -0,0 +1,36 @@
+// Check that a failing auto trait bound points at the field through which
+// each enclosing type contains the offending one.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+struct Wrapper<T> {
+    inner: T,
+    count: usize,
+}
+
+enum Kind {
+    A(u8),
+    B(Wrapper<Rc<u8>>),
+}
+
+struct Big {
+    a: u32,
+    b: String,
+    kind: Kind,
+    c: Vec<u8>,
+}
+
+struct Cache {
+    cells: Vec<Cell<u8>>,
+}
+
+fn is_send<T: Send>() {}
+fn is_sync<T: Sync>() {}
+
+fn main() {
+    is_send::<Big>();
+    //~^ ERROR `Rc<u8>` cannot be sent between threads safely
+    is_sync::<Cache>();
+    //~^ ERROR `Cell<u8>` cannot be shared between threads safely
+}
@@ -0,0 +1,31 @@
+// Check that `-Zcandidate-preference` changes which candidate is preferred
+// when both a global where-clause and an impl or builtin candidate apply.
+// Preferring the where-clause here requires the region to be `'static`.
+
+//@ revisions: default where_clause no_builtin_over_param
+//@[default] check-pass
+//@[default] compile-flags: -Zcandidate-preference=default
+//@[where_clause] compile-flags: -Zcandidate-preference=where-clause
+//@[no_builtin_over_param] compile-flags: -Zcandidate-preference=no-builtin-over-param
+
+fn copy<T: Copy>(x: T) -> T {
+    x
+}
+
+fn impl_candidate<'a>(x: &'a u8)
+where
+    &'static u8: Copy,
+{
+    copy(x);
+    //[where_clause]~^ ERROR lifetime may not live long enough
+}
+
+fn builtin_candidate<'a>(x: fn() -> &'a u8)
+where
+    fn() -> &'static u8: Copy,
+{
+    copy(x);
+    //[where_clause,no_builtin_over_param]~^ ERROR lifetime may not live long enough
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![feature(coinductive_traits)]
+#![allow(incomplete_features)]
+
+#[coinductive]
+trait WithItem {
+    fn item(&self);
+    //~^ ERROR coinductive traits cannot have associated items
+}
+
+#[coinductive]
+//~^ ERROR attribute should be applied to a trait
+struct NotATrait;
+
+fn main() {}
@@ -0,0 +1,14 @@
+// Without `#[coinductive]`, the same cycle overflows.
+
+struct List {
+    _next: Option<Box<List>>,
+}
+
+trait Marker {}
+
+impl<T: Marker> Marker for Option<T> {}
+impl<T: Marker> Marker for Box<T> {}
+impl Marker for List where Option<Box<List>>: Marker {}
+//~^ ERROR overflow evaluating the requirement `List: Marker`
+
+fn main() {}
@@ -0,0 +1,26 @@
+//@ revisions: current next
+//@[next] compile-flags: -Znext-solver
+//@ check-pass
+
+// A `#[coinductive]` marker trait can be implemented for a recursive type
+// through where-clauses that cycle back to the type itself.
+
+#![feature(coinductive_traits)]
+#![allow(incomplete_features)]
+
+#[coinductive]
+trait Marker {}
+
+struct List {
+    _next: Option<Box<List>>,
+}
+
+impl<T: Marker> Marker for Option<T> {}
+impl<T: Marker> Marker for Box<T> {}
+impl Marker for List where Option<Box<List>>: Marker {}
+
+fn is_marker<T: Marker>() {}
+
+fn main() {
+    is_marker::<List>();
+}
@@ -0,0 +1,25 @@
+#![feature(coinductive_traits)]
+#![allow(incomplete_features)]
+
+// Supertraits and where-clauses would be implied by every bound on a
+// coinductive trait without ever being checked, so they are rejected.
+
+trait Super {}
+
+#[coinductive]
+trait WithSupertrait: Super {}
+//~^ ERROR coinductive traits cannot have supertraits
+
+#[coinductive]
+trait WithWhereClause<T>
+where
+    T: Super,
+    //~^ ERROR coinductive traits cannot have where-clauses
+{
+}
+
+#[coinductive]
+trait WithInlineBound<T: Super> {}
+//~^ ERROR coinductive traits cannot have where-clauses
+
+fn main() {}
@@ -0,0 +1,22 @@
+//@ compile-flags: -Zexplain-obligation
+
+trait Foo {}
+trait Bar {}
+
+struct Wrapper<T>(T);
+struct NotBar;
+
+impl<T: Bar> Foo for Wrapper<T> {}
+impl<T: Foo> Bar for Option<T> {}
+impl Bar for u32 {}
+
+fn needs_foo<T: Foo>() {}
+
+fn main() {
+    needs_foo::<Wrapper<NotBar>>();
+    //~^ ERROR the trait bound `NotBar: Bar` is not satisfied
+    needs_foo::<Wrapper<Option<Wrapper<NotBar>>>>();
+    //~^ ERROR the trait bound `NotBar: Bar` is not satisfied
+    needs_foo::<Vec<u32>>();
+    //~^ ERROR the trait bound `Vec<u32>: Foo` is not satisfied
+}
@@ -0,0 +1,13 @@
+#![feature(negative_impls)]
+#![feature(negative_bounds_coherence)]
+
+pub trait Marker {}
+pub trait Trait {}
+
+pub struct S;
+impl !Marker for S {}
+
+impl<T: Marker> Trait for T {}
+impl !Trait for S {}
+
+pub fn require<T: Trait>() {}
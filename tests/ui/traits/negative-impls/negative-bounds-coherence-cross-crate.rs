@@ -0,0 +1,10 @@
+//@ aux-build:negative-bounds-coherence-aux.rs
+// Check that negative impls rule out impl candidates in crates which don't enable
+// `negative_bounds_coherence`, if the crate defining the trait does.
+
+extern crate negative_bounds_coherence_aux as aux;
+
+fn main() {
+    aux::require::<aux::S>();
+    //~^ ERROR the trait bound `S: Trait` is not satisfied
+}
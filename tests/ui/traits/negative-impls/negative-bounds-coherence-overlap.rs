@@ -0,0 +1,43 @@
+//@ check-pass
+
+// With `negative_bounds_coherence`, a negative impl shows that these blanket
+// impls are disjoint, and selection rules out the one it negates.
+
+#![feature(negative_impls)]
+#![feature(negative_bounds_coherence)]
+
+trait Display {}
+trait Debug {}
+impl<T: Display> !Debug for T {}
+
+trait Show {
+    fn show(&self) -> u8;
+}
+
+impl<T: Display> Show for T {
+    fn show(&self) -> u8 {
+        1
+    }
+}
+
+impl<T: Debug> Show for T {
+    fn show(&self) -> u8 {
+        2
+    }
+}
+
+struct A;
+impl Display for A {}
+
+struct B;
+impl Debug for B {}
+
+fn generic<T: Display>(t: T) -> u8 {
+    t.show()
+}
+
+fn main() {
+    let _ = A.show();
+    let _ = B.show();
+    let _ = generic(A);
+}
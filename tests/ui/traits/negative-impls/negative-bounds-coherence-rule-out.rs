@@ -0,0 +1,22 @@
+// Check that a negative impl rules out positive impl candidates in selection,
+// so that the error points at the negated trait rather than at the where-clause
+// of a blanket impl which never applies.
+
+#![feature(negative_impls)]
+#![feature(negative_bounds_coherence)]
+
+trait Marker {}
+trait Trait {}
+
+struct S;
+impl !Marker for S {}
+
+impl<T: Marker> Trait for T {}
+impl !Trait for S {}
+
+fn require<T: Trait>() {}
+
+fn main() {
+    require::<S>();
+    //~^ ERROR the trait bound `S: Trait` is not satisfied
+}
@@ -0,0 +1,28 @@
+// Check that `-Zobligation-fuel` limits the number of fulfillment steps taken
+// for each root obligation, and reports the root when it runs out.
+
+//@ revisions: low high
+//@[low] compile-flags: -Zobligation-fuel=3
+//@[high] compile-flags: -Zobligation-fuel=100
+//@[high] check-pass
+
+trait Foo {}
+
+impl Foo for () {}
+impl<T: Foo> Foo for Box<T> {}
+
+fn require<T: Foo>() {}
+
+fn nested<T: Foo>() {
+    require::<Box<Box<Box<Box<Box<T>>>>>>();
+    //[low]~^ ERROR trait solving exceeded fuel while proving `Box<Box<Box<Box<Box<T>>>>>: Foo`
+}
+
+fn shallow<T: Foo>() {
+    require::<Box<T>>();
+}
+
+fn main() {
+    nested::<()>();
+    shallow::<()>();
+}
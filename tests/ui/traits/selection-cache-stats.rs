@@ -0,0 +1,19 @@
+//@ revisions: current next
+//@[next] compile-flags: -Znext-solver
+//@ compile-flags: -Zselection-cache-stats
+//@ check-pass
+//@ normalize-stderr-test "\d+ (hits|misses|evictions)" -> "N $1"
+//@ normalize-stderr-test "\([\d.]+% hit rate\)" -> "(N% hit rate)"
+//@ normalize-stderr-test "(selection-cache-stats:  +.*\n)+" -> ""
+
+// Check that `-Zselection-cache-stats` reports the trait solver caches at the end of compilation.
+
+trait Trait {}
+
+impl<T: Clone> Trait for Vec<T> {}
+
+fn requires_trait<T: Trait>() {}
+
+fn main() {
+    requires_trait::<Vec<u8>>();
+}
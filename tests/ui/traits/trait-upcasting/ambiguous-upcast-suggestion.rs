@@ -0,0 +1,28 @@
+// Check that we point at every supertrait bound an ambiguous upcast could go
+// through, and suggest casting to each of the candidates.
+
+#![feature(trait_upcasting)]
+
+trait Super<T> {
+    fn get(&self) -> T;
+}
+
+trait Mid: Super<u32> {}
+
+trait Sub: Super<u8> + Super<u16> + Mid {}
+
+fn by_ref(x: &dyn Sub) {
+    let _: &dyn Super<_> = x;
+    //~^ ERROR mismatched types
+}
+
+fn boxed(x: Option<Box<dyn Sub + Send>>) {
+    let _: Box<dyn Super<_> + Send> = x.unwrap();
+    //~^ ERROR mismatched types
+}
+
+fn not_ambiguous(x: &dyn Sub) -> &dyn Super<u8> {
+    x
+}
+
+fn main() {}
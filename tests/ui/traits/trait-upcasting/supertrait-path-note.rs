@@ -0,0 +1,14 @@
+// Check that the trait upcasting feature error points at the chain of
+// supertrait bounds leading to the target trait.
+
+trait A: B + Send {}
+trait B: Sync + C {}
+trait C: D {}
+trait D {}
+
+fn upcast(x: &dyn A) -> &dyn D {
+    x
+    //~^ ERROR cannot cast `dyn A` to `dyn D`, trait upcasting coercion is experimental
+}
+
+fn main() {}